@@ -0,0 +1,20 @@
+use ark_groth16_bench::{
+    ARK_GROTH16_BENCH_PROPERTIES, export_artifacts, num_constraints, prepare_sha256,
+    preprocessing_size, proof_size, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::ArkGroth16,
+    None,
+    "sha256_mem_ark_groth16",
+    ARK_GROTH16_BENCH_PROPERTIES,
+    prepare_sha256,
+    num_constraints,
+    prove,
+    verify,
+    preprocessing_size,
+    proof_size,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,5 @@
+use ark_groth16_bench::{prepare_sha256, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_sha256, prove, verify);
+}
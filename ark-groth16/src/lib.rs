@@ -0,0 +1,212 @@
+//! SHA-256 proved with arkworks' Groth16 over BN254, natively in Rust.
+//!
+//! The circuit is the SHA-256 gadget of `ark-crypto-primitives`, synthesized with `ark-r1cs-std`
+//! and proved with `ark-groth16`. It proves the same statement with the same protocol and curve
+//! as the `circom` crate, without circom's compiler, witness generator or rapidsnark, so the two
+//! separate Groth16 itself from the cost of that tooling.
+
+use ark_bn254::{Bn254, Fr};
+use ark_crypto_primitives::crh::sha256::constraints::Sha256Gadget;
+use ark_ff::ToConstraintField;
+use ark_groth16::{
+    Groth16, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey, prepare_verifying_key,
+};
+use ark_r1cs_std::{eq::EqGadget, uint8::UInt8};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use rand::rngs::OsRng;
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::witness_size::{ConstraintCount, WitnessSize};
+
+pub const ARK_GROTH16_BENCH_PROPERTIES: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("Groth16"),
+    field_curve: Cow::Borrowed("Bn254"),
+    iop: Cow::Borrowed("Groth16"),
+    pcs: None,
+    arithm: Cow::Borrowed("R1CS"),
+    is_zk: true,
+    is_zkvm: false,
+    security_bits: 128, // Bn254 curve
+    is_pq: false,
+    is_maintained: true, // https://github.com/arkworks-rs/groth16
+    is_audited: AuditStatus::NotAudited,
+    isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("0.5.0")),
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
+};
+
+/// Hashes `message`, a private witness, and checks the result against the public `digest`.
+#[derive(Clone)]
+pub struct Sha256Circuit {
+    message: Vec<u8>,
+    digest: Vec<u8>,
+}
+
+impl ConstraintSynthesizer<Fr> for Sha256Circuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let message = UInt8::new_witness_vec(cs.clone(), &self.message)?;
+        // Packed into field elements of 31 bytes, like `ToConstraintField` for the verifier.
+        let digest = UInt8::new_input_vec(cs, &self.digest)?;
+        Sha256Gadget::digest(&message)?.0.enforce_equal(&digest)
+    }
+}
+
+pub struct PreparedSha256 {
+    circuit: Sha256Circuit,
+    pk: ProvingKey<Bn254>,
+    pvk: PreparedVerifyingKey<Bn254>,
+    public_inputs: Vec<Fr>,
+    num_constraints: usize,
+    num_variables: usize,
+}
+
+/// Synthesizes the circuit once to count it, then runs the circuit-specific setup.
+pub fn prepare_sha256(input_size: usize) -> Result<PreparedSha256, BenchError> {
+    let (message, digest) = utils::generate_sha256_input(input_size);
+    let public_inputs = digest
+        .as_slice()
+        .to_field_elements()
+        .ok_or_else(|| BenchError::setup("digest does not pack into field elements"))?;
+    let circuit = Sha256Circuit { message, digest };
+
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    circuit
+        .clone()
+        .generate_constraints(cs.clone())
+        .map_err(BenchError::setup)?;
+    if !cs.is_satisfied().map_err(BenchError::setup)? {
+        return Err(BenchError::setup(
+            "the digest is not the hash of the message",
+        ));
+    }
+
+    let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(circuit.clone(), &mut OsRng)
+        .map_err(BenchError::setup)?;
+    Ok(PreparedSha256 {
+        circuit,
+        pk,
+        pvk: prepare_verifying_key(&vk),
+        public_inputs,
+        num_constraints: cs.num_constraints(),
+        num_variables: cs.num_instance_variables() + cs.num_witness_variables(),
+    })
+}
+
+pub fn prove(prepared: &PreparedSha256) -> Result<Proof<Bn254>, BenchError> {
+    Groth16::<Bn254>::prove(&prepared.pk, prepared.circuit.clone(), &mut OsRng)
+        .map_err(BenchError::prove)
+}
+
+pub fn verify(prepared: &PreparedSha256, proof: &Proof<Bn254>) -> Result<(), BenchError> {
+    let valid =
+        Groth16::<Bn254>::verify_with_processed_vk(&prepared.pvk, &prepared.public_inputs, proof)
+            .map_err(BenchError::verify)?;
+    if !valid {
+        return Err(BenchError::verify("proof rejected"));
+    }
+    Ok(())
+}
+
+/// R1CS constraints, with the instance and witness variables as the witness.
+pub fn num_constraints(prepared: &PreparedSha256) -> ConstraintCount {
+    ConstraintCount::with_witness(
+        prepared.num_constraints,
+        WitnessSize::new(prepared.num_variables as u64, 32),
+    )
+}
+
+/// Size of the compressed proving key, which contains the verifying key.
+pub fn preprocessing_size(prepared: &PreparedSha256) -> usize {
+    prepared.pk.compressed_size()
+}
+
+/// Size of the compressed proof.
+pub fn proof_size(proof: &Proof<Bn254>) -> usize {
+    proof.compressed_size()
+}
+
+fn compressed(value: &impl CanonicalSerialize) -> Result<Vec<u8>, BenchError> {
+    let mut bytes = Vec::new();
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(BenchError::setup)?;
+    Ok(bytes)
+}
+
+/// The proof, the verifying key and the public inputs, each compressed with `ark-serialize`.
+pub fn export_artifacts(
+    prepared: &PreparedSha256,
+    proof: &Proof<Bn254>,
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: compressed(proof)?,
+        verification_key: compressed(&prepared.pvk.vk)?,
+        public_inputs: compressed(&prepared.public_inputs)?,
+        encoding: "ark-compressed",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`].
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let vk = VerifyingKey::<Bn254>::deserialize_compressed(verification_key)?;
+    let proof = Proof::<Bn254>::deserialize_compressed(proof)?;
+    let public_inputs = Vec::<Fr>::deserialize_compressed(public_inputs)?;
+    let valid = Groth16::<Bn254>::verify(&vk, &public_inputs, &proof)?;
+    anyhow::ensure!(valid, "Groth16 proof rejected");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ark_groth16_sha256_rejects_a_wrong_digest() {
+        let mut prepared = prepare_sha256(128).unwrap();
+        let proof = prove(&prepared).unwrap();
+        verify(&prepared, &proof).unwrap();
+
+        let mut digest = prepared.circuit.digest.clone();
+        digest[0] ^= 1;
+        prepared.public_inputs = digest.as_slice().to_field_elements().unwrap();
+        assert!(matches!(
+            verify(&prepared, &proof),
+            Err(BenchError::Verify { .. })
+        ));
+    }
+
+    #[test]
+    fn ark_groth16_exported_artifacts_verify() {
+        let mut prepared = prepare_sha256(128).unwrap();
+        let proof = prove(&prepared).unwrap();
+        let artifacts = export_artifacts(&prepared, &proof).unwrap();
+        verify_artifacts(
+            &artifacts.proof,
+            &artifacts.verification_key,
+            &artifacts.public_inputs,
+        )
+        .unwrap();
+
+        prepared.public_inputs[0] += Fr::from(1u64);
+        let tampered = export_artifacts(&prepared, &proof).unwrap();
+        assert!(
+            verify_artifacts(
+                &artifacts.proof,
+                &artifacts.verification_key,
+                &tampered.public_inputs,
+            )
+            .is_err()
+        );
+    }
+}
@@ -14,20 +14,8 @@ set -euo pipefail
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 WORKSPACE_ROOT_PATH="${SCRIPT_DIR}/circuits"
 
-# Update circuit to use INPUT_SIZE for the input parameter only
-CIRCUIT_SOURCE="${WORKSPACE_ROOT_PATH}/hash/keccak256/src/main.nr"
-if [[ -f "$CIRCUIT_SOURCE" ]]; then
-  # Replace the input array length and the keccak generic arity in `fn main(...)`
-  sed -E -i.bak \
-    -e "s/(fn[[:space:]]+main\\([[:space:]]*input:[[:space:]]*\\[u8;)[[:space:]]*[0-9]+/\\1 ${INPUT_SIZE}/" \
-    -e "s/(keccak256::<)[[:space:]]*[0-9]+/\\1${INPUT_SIZE}/" \
-    "$CIRCUIT_SOURCE"
-  # Remove backup file if it exists
-  rm -f "${CIRCUIT_SOURCE}.bak"
-else
-  echo "Error: Circuit source file not found: $CIRCUIT_SOURCE" >&2
-  exit 1
-fi
+# Generate the circuit entry point for INPUT_SIZE
+"$UTILS_BIN" noir-main keccak256 -n "$INPUT_SIZE" > "${WORKSPACE_ROOT_PATH}/hash/keccak256/src/main.nr"
 
 cd "$WORKSPACE_ROOT_PATH"
 nargo compile --workspace --silence-warnings --skip-brillig-constraints-check
@@ -0,0 +1,77 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+# Required env vars:
+# - UTILS_BIN: path to utils binary
+# - INPUT_SIZE: number of signers
+# - STATE_JSON: output JSON file path
+
+: "${UTILS_BIN:?UTILS_BIN is required}"
+: "${INPUT_SIZE:?INPUT_SIZE is required}"
+: "${STATE_JSON:?STATE_JSON is required}"
+
+####   Compile circuits   ####
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+WORKSPACE_ROOT_PATH="${SCRIPT_DIR}/circuits"
+
+# Generate the circuit entry point for INPUT_SIZE signers
+"$UTILS_BIN" noir-main multisig -n "$INPUT_SIZE" > "${WORKSPACE_ROOT_PATH}/multisig/src/main.nr"
+
+cd "$WORKSPACE_ROOT_PATH"
+nargo compile --workspace --silence-warnings --skip-brillig-constraints-check
+cd ../..
+
+CIRCUIT_PATH="${WORKSPACE_ROOT_PATH}/target/multisig.json"
+
+####   Generate input(Prover.toml)   ####
+# Digest, threshold, then x, y and signature of each signer, one per line
+mapfile -t lines < <("$UTILS_BIN" multisig -n "$INPUT_SIZE")
+
+if (( ${#lines[@]} != 2 + 3 * INPUT_SIZE )); then
+  echo "prepare.sh: generator output malformed" >&2
+  exit 2
+fi
+
+# Function: print hex string "0a1b..." as the byte array "[10, 27, ...]"
+hex_to_array() {
+  local hex="$1" i
+  printf "["
+  for (( i = 0; i < ${#hex}; i += 2 )); do
+    (( i == 0 )) || printf ", "
+    printf "%d" "0x${hex:i:2}"
+  done
+  printf "]"
+}
+
+# Function: print every third line starting at line $2 as a TOML array of byte arrays
+print_column() {
+  local label="$1" first="$2" i
+  printf "%s = [" "$label"
+  for (( i = 0; i < INPUT_SIZE; i++ )); do
+    (( i == 0 )) || printf ", "
+    hex_to_array "${lines[first + 3 * i]}"
+  done
+  printf "]\n"
+}
+
+CIRCUIT_MEMBER_DIR="${WORKSPACE_ROOT_PATH}/multisig"
+TOML_PATH="${CIRCUIT_MEMBER_DIR}/Prover_${INPUT_SIZE}.toml"
+
+{
+  print_column "public_keys_x" 2
+  print_column "public_keys_y" 3
+  print_column "signatures" 4
+  printf "message_hash = %s\n" "$(hex_to_array "${lines[0]}")"
+  printf "threshold = %d\n" "${lines[1]}"
+} > "$TOML_PATH"
+
+####    Create STATE JSON    ####
+JQ_PROG='{"workspace-root-path":$workspace, "circuit-path":$circuit, "toml-path":$toml, "input-size":$len, "benchmark-name":$bench}'
+
+jq -nc \
+  --arg workspace "$WORKSPACE_ROOT_PATH" \
+  --arg circuit "$CIRCUIT_PATH" \
+  --arg toml "$TOML_PATH" \
+  --argjson len "$INPUT_SIZE" \
+  --arg bench "multisig" \
+  "$JQ_PROG" > "$STATE_JSON"
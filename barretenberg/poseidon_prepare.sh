@@ -8,19 +8,8 @@ set -euo pipefail
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 WORKSPACE_ROOT_PATH="${SCRIPT_DIR}/circuits"
 
-# Update circuit to use INPUT_SIZE
-CIRCUIT_SOURCE="${WORKSPACE_ROOT_PATH}/hash/poseidon/src/main.nr"
-if [[ -f "$CIRCUIT_SOURCE" ]]; then
-  # Replace hash_N function name and array size
-  sed -E -i.bak \
-    -e "s/hash_[0-9]+/hash_${INPUT_SIZE}/g" \
-    -e "s/\[Field;[[:space:]]*[0-9]+\]/[Field; ${INPUT_SIZE}]/g" \
-    "$CIRCUIT_SOURCE"
-  rm -f "${CIRCUIT_SOURCE}.bak"
-else
-  echo "Error: Circuit source file not found: $CIRCUIT_SOURCE" >&2
-  exit 1
-fi
+# Generate the circuit entry point for INPUT_SIZE
+"$UTILS_BIN" noir-main poseidon -n "$INPUT_SIZE" > "${WORKSPACE_ROOT_PATH}/hash/poseidon/src/main.nr"
 
 cd "$WORKSPACE_ROOT_PATH"
 nargo compile --workspace --silence-warnings --skip-brillig-constraints-check
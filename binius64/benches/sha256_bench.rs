@@ -21,7 +21,6 @@ utils::define_benchmark_harness!(
                 exact_len: true,
             },
         )
-        .expect("Failed to prepare sha256 circuit for prove/verify")
     },
     |(_, _, cs, _, _, _)| { cs.n_and_constraints() + cs.n_mul_constraints() },
     |(_verifier, prover, _cs, sha256_circuit, compiled_circuit, input_size)| {
@@ -31,7 +30,6 @@ utils::define_benchmark_harness!(
             ParallelCompressionAdaptor<StdCompression>,
             Sha256Circuit,
         >(prover, compiled_circuit, sha256_circuit, *input_size)
-        .expect("Failed to prove sha256 circuit")
     },
     |(verifier, _prover, _cs, _sha256_circuit, _compiled_circuit, _input_size),
      (proof, pub_witness)| {
@@ -40,7 +38,6 @@ utils::define_benchmark_harness!(
             pub_witness,
             proof,
         )
-        .expect("Failed to verify sha256 circuit")
     },
     |(_verifier, _prover, cs, _sha256_circuit, _compiled_circuit, _input_size)| {
         let mut buf: Vec<u8> = Vec::new();
@@ -48,5 +45,8 @@ utils::define_benchmark_harness!(
             .expect("Failed to serialize constraint system into byte array");
         buf.len()
     },
-    |(proof, _pub_witness)| proof.len()
+    |(proof, _pub_witness)| proof.len(),
+    export_artifacts =
+        |(_verifier, _prover, cs, _sha256_circuit, _compiled_circuit, _input_size),
+         (proof, pub_witness)| { binius64::export_artifacts(cs, proof, pub_witness) }
 );
@@ -1,37 +1,34 @@
-use anyhow::Result;
 use binius_prover::hash::parallel_compression::ParallelCompressionAdaptor;
 use binius_verifier::hash::{StdCompression, StdDigest};
 use binius64::circuits::sha256::Sha256Params;
-use binius64::{circuits::Sha256Circuit, prepare, prove};
-use clap::Parser;
-
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size parameter
-    #[arg(long)]
-    input_size: usize,
-}
+use binius64::{circuits::Sha256Circuit, prepare, prove, verify};
 
 fn main() {
-    let args = Args::parse();
-
-    sha256_mem(args.input_size).expect("Failed to run prove process");
-}
-
-fn sha256_mem(input_size: usize) -> Result<()> {
-    let (_verifier, prover, _cs, sha256_circuit, compiled_circuit, input_size) =
-        prepare::<Sha256Circuit>(
-            input_size,
-            Sha256Params {
-                max_len_bytes: Some(input_size),
-                exact_len: true,
-            },
-        )?;
-    let _ = prove::<
-        StdDigest,
-        StdCompression,
-        ParallelCompressionAdaptor<StdCompression>,
-        Sha256Circuit,
-    >(&prover, &compiled_circuit, &sha256_circuit, input_size)?;
-    Ok(())
+    utils::bench::mem::run_with_verify(
+        |input_size| {
+            prepare::<Sha256Circuit>(
+                input_size,
+                Sha256Params {
+                    max_len_bytes: Some(input_size),
+                    exact_len: true,
+                },
+            )
+        },
+        |(_verifier, prover, _cs, sha256_circuit, compiled_circuit, input_size)| {
+            prove::<
+                StdDigest,
+                StdCompression,
+                ParallelCompressionAdaptor<StdCompression>,
+                Sha256Circuit,
+            >(prover, compiled_circuit, sha256_circuit, *input_size)
+        },
+        |(verifier, _prover, _cs, _sha256_circuit, _compiled_circuit, _input_size),
+         (proof, pub_witness)| {
+            verify::<StdDigest, StdCompression, ParallelCompressionAdaptor<StdCompression>>(
+                verifier,
+                pub_witness,
+                proof,
+            )
+        },
+    );
 }
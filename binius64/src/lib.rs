@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
-use ::utils::harness::{AuditStatus, BenchProperties};
-use anyhow::Result;
+use ::utils::artifacts::ExportedArtifacts;
+use ::utils::harness::{AuditStatus, BenchError, BenchProperties};
+use anyhow::ensure;
 use binius_core::{Word, constraint_system::ConstraintSystem};
 use binius_frontend::{Circuit, CircuitBuilder};
 use binius_prover::{
@@ -11,10 +12,11 @@ use binius_prover::{
         parallel_compression::{ParallelCompressionAdaptor, ParallelPseudoCompression},
     },
 };
+use binius_utils::serialization::{DeserializeBytes, SerializeBytes};
 use binius_verifier::{
     Verifier,
     config::StdChallenger,
-    hash::{PseudoCompressionFunction, StdCompression},
+    hash::{PseudoCompressionFunction, StdCompression, StdDigest},
     transcript::{ProverTranscript, VerifierTranscript},
 };
 use sha2::digest::{Digest, FixedOutputReset, Output, core_api::BlockSizeUser};
@@ -37,23 +39,31 @@ pub const BINIUS64_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     is_maintained: true,
     is_audited: AuditStatus::NotAudited,
     isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("6fe99c5")), // pinned rev
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
 };
 
+/// Reed-Solomon code rate of the proofs, `log2(1 / rate)`.
+const LOG_INV_RATE: usize = 1;
+
 /// Setup the prover and verifier and use SHA256 for Merkle tree compression.
 /// Providing the `key_collection` skips expensive key collection building.
 fn setup(
     cs: ConstraintSystem,
     log_inv_rate: usize,
     key_collection: Option<KeyCollection>,
-) -> Result<(StdVerifier, StdProver)> {
+) -> Result<(StdVerifier, StdProver), BenchError> {
     let parallel_compression = ParallelCompressionAdaptor::new(StdCompression::default());
     let compression = parallel_compression.compression().clone();
-    let verifier = Verifier::setup(cs, log_inv_rate, compression)?;
+    let verifier = Verifier::setup(cs, log_inv_rate, compression).map_err(BenchError::setup)?;
     let prover = if let Some(key_collection) = key_collection {
-        Prover::setup_with_key_collection(verifier.clone(), parallel_compression, key_collection)?
+        Prover::setup_with_key_collection(verifier.clone(), parallel_compression, key_collection)
     } else {
-        Prover::setup(verifier.clone(), parallel_compression)?
-    };
+        Prover::setup(verifier.clone(), parallel_compression)
+    }
+    .map_err(BenchError::setup)?;
     Ok((verifier, prover))
 }
 
@@ -61,21 +71,18 @@ fn setup(
 pub fn prepare<CT: CircuitTrait>(
     input_size: usize,
     params: CT::Params,
-) -> Result<(StdVerifier, StdProver, ConstraintSystem, CT, Circuit, usize)> {
-    // Extract common arguments
-    let log_inv_rate = 1;
-
+) -> Result<(StdVerifier, StdProver, ConstraintSystem, CT, Circuit, usize), BenchError> {
     // Build the circuit
     let mut builder = CircuitBuilder::new();
 
-    let circuit = CT::build(params, &mut builder)?;
+    let circuit = CT::build(params, &mut builder).map_err(BenchError::setup)?;
     let compiled_circuit = builder.build();
 
     // Set up prover and verifier
     let cs = compiled_circuit.constraint_system().clone();
 
     // Using SHA256 compression for Merkle tree
-    let (verifier, prover) = setup(cs.clone(), log_inv_rate as usize, None)?;
+    let (verifier, prover) = setup(cs.clone(), LOG_INV_RATE, None)?;
     Ok((verifier, prover, cs, circuit, compiled_circuit, input_size))
 }
 
@@ -84,7 +91,7 @@ pub fn prove<D, C, PC, CT>(
     compiled_circuit: &Circuit,
     circuit: &CT,
     instance: CT::Instance,
-) -> Result<(Vec<u8>, Vec<Word>)>
+) -> Result<(Vec<u8>, Vec<Word>), BenchError>
 where
     D: ParallelDigest + Digest + BlockSizeUser,
     D::Digest: BlockSizeUser + FixedOutputReset,
@@ -93,24 +100,36 @@ where
     CT: CircuitTrait,
 {
     // Population of the input to the witness and then evaluating the circuit.
-    let mut filler = compiled_circuit.new_witness_filler();
-    circuit.populate_witness(instance, &mut filler)?; // input population
-    compiled_circuit.populate_wire_witness(&mut filler)?; // circuit evaluation
-    let witness = filler.into_value_vec();
+    let witness = tracing::info_span!("generate_witness").in_scope(|| {
+        let mut filler = compiled_circuit.new_witness_filler();
+        circuit
+            .populate_witness(instance, &mut filler)
+            .map_err(BenchError::prove)?; // input population
+        compiled_circuit
+            .populate_wire_witness(&mut filler)
+            .map_err(BenchError::prove)?; // circuit evaluation
+        Ok::<_, BenchError>(filler.into_value_vec())
+    })?;
 
     let pub_witness = witness.public().to_vec();
 
     // Prove
     let challenger = StdChallenger::default();
     let mut prover_transcript = ProverTranscript::new(challenger);
-    prover.prove(witness, &mut prover_transcript)?;
+    prover
+        .prove(witness, &mut prover_transcript)
+        .map_err(BenchError::prove)?;
 
     let proof = prover_transcript.finalize();
 
     Ok((proof, pub_witness))
 }
 
-pub fn verify<D, C, PC>(verifier: &Verifier<D, C>, pub_witness: &[Word], proof: &[u8]) -> Result<()>
+pub fn verify<D, C, PC>(
+    verifier: &Verifier<D, C>,
+    pub_witness: &[Word],
+    proof: &[u8],
+) -> Result<(), BenchError>
 where
     D: ParallelDigest + Digest + BlockSizeUser,
     D::Digest: BlockSizeUser + FixedOutputReset,
@@ -119,8 +138,56 @@ where
 {
     let challenger = StdChallenger::default();
     let mut verifier_transcript = VerifierTranscript::new(challenger, proof.to_vec());
-    verifier.verify(pub_witness, &mut verifier_transcript)?;
-    verifier_transcript.finalize()?;
+    verifier
+        .verify(pub_witness, &mut verifier_transcript)
+        .map_err(BenchError::verify)?;
+    verifier_transcript.finalize().map_err(BenchError::verify)?;
+
+    Ok(())
+}
+
+/// The proof, the constraint system as the verification key (the verifier is set up from it),
+/// and the public witness as little-endian words.
+pub fn export_artifacts(
+    cs: &ConstraintSystem,
+    proof: &[u8],
+    pub_witness: &[Word],
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut verification_key = Vec::new();
+    cs.serialize(&mut verification_key)
+        .map_err(BenchError::setup)?;
+    Ok(ExportedArtifacts {
+        proof: proof.to_vec(),
+        verification_key,
+        public_inputs: pub_witness
+            .iter()
+            .flat_map(|word| word.0.to_le_bytes())
+            .collect(),
+        encoding: "binius-serialize+le-words",
+    })
+}
 
+/// Verifies artifacts exported with [`export_artifacts`], with a verifier set up from the
+/// exported constraint system.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let cs = ConstraintSystem::deserialize(verification_key)?;
+    let verifier: StdVerifier = Verifier::setup(cs, LOG_INV_RATE, StdCompression::default())?;
+    ensure!(
+        public_inputs.len() % 8 == 0,
+        "public witness is not a sequence of 64-bit words"
+    );
+    let pub_witness: Vec<Word> = public_inputs
+        .chunks_exact(8)
+        .map(|word| Word(u64::from_le_bytes(word.try_into().expect("8-byte chunk"))))
+        .collect();
+    verify::<StdDigest, StdCompression, ParallelCompressionAdaptor<StdCompression>>(
+        &verifier,
+        &pub_witness,
+        proof,
+    )?;
     Ok(())
 }
@@ -1,4 +1,4 @@
-use cairo_m::{compile_program, prepare, prove, verify};
+use cairo_m::{compile_program, export_artifacts, prepare, prove, verify};
 use cairo_m_common::{InputValue, Program};
 use cairo_m_prover::{adapter::import_from_runner_output, public_data::PublicData};
 use cairo_m_runner::run_cairo_program;
@@ -56,5 +56,6 @@ utils::define_benchmark_harness!(
                 .expect("Failed to import runner output for proof generation");
 
         PublicData::new(&prover_input).clock.0 as u64
-    }
+    },
+    export_artifacts = |_, proof, _| export_artifacts(proof)
 );
@@ -0,0 +1,5 @@
+//! Verifies artifacts exported by the SHA256 benchmark, for the `verifiers` crate.
+
+fn main() -> anyhow::Result<()> {
+    utils::artifacts::verify_artifacts_main(cairo_m::verify_artifacts)
+}
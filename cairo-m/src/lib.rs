@@ -7,7 +7,9 @@ use cairo_m_prover::{
 use cairo_m_runner::run_cairo_program;
 use std::fs;
 use stwo_prover::core::vcs::blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher};
+use utils::artifacts::ExportedArtifacts;
 use utils::generate_sha256_input;
+use utils::harness::BenchError;
 
 /// Compile the Cairo-M SHA256 program from source.
 pub fn compile_program() -> Program {
@@ -50,7 +52,7 @@ fn prepare_sha256_input(msg: &[u8]) -> Vec<u32> {
 pub fn prepare(
     input_size: usize,
     compiled_program: &Program,
-) -> (Program, (String, Vec<InputValue>)) {
+) -> Result<(Program, (String, Vec<InputValue>)), BenchError> {
     // Generate input using sha2_input
     let (input_bytes, _digest) = generate_sha256_input(2048);
 
@@ -70,13 +72,13 @@ pub fn prepare(
         InputValue::Number(num_chunks as i64),
     ];
 
-    (compiled_program.clone(), (entrypoint_name, runner_inputs))
+    Ok((compiled_program.clone(), (entrypoint_name, runner_inputs)))
 }
 
 pub fn prove(
     compiled_program: &Program,
     inputs: (&str, &[InputValue]),
-) -> Proof<Blake2sMerkleHasher> {
+) -> Result<Proof<Blake2sMerkleHasher>, BenchError> {
     let (entrypoint_name, runner_inputs) = inputs;
 
     // Run/Execute the program
@@ -86,7 +88,7 @@ pub fn prove(
         runner_inputs,
         Default::default(),
     )
-    .expect("failed to run cairo program");
+    .map_err(BenchError::prove)?;
 
     // Proof Generation
     let segment = runner_output
@@ -95,21 +97,48 @@ pub fn prove(
         .clone()
         .into_iter()
         .next()
-        .unwrap();
+        .ok_or_else(|| BenchError::prove("the runner produced no segment"))?;
 
     let mut prover_input =
         import_from_runner_output(segment, runner_output.public_address_ranges.clone())
-            .expect("Failed to import runner output for proof generation");
+            .map_err(BenchError::prove)?;
 
     let pcs_config = REGULAR_96_BITS;
 
     prove_cairo_m::<Blake2sMerkleChannel>(&mut prover_input, Some(pcs_config))
-        .expect("failed to generate proof")
+        .map_err(BenchError::prove)
 }
 
-pub fn verify(proof: &Proof<Blake2sMerkleHasher>) {
+pub fn verify(proof: &Proof<Blake2sMerkleHasher>) -> Result<(), BenchError> {
     let pcs_config = REGULAR_96_BITS;
 
     verify_cairo_m::<Blake2sMerkleChannel>(proof.clone(), Some(pcs_config))
-        .expect("failed to verify proof");
+        .map_err(BenchError::verify)
+}
+
+/// The proof in JSON, which carries its public data: `verify_cairo_m` checks it at the fixed PCS
+/// configuration alone, so there is no verification key nor separate public inputs.
+pub fn export_artifacts(
+    proof: &Proof<Blake2sMerkleHasher>,
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: serde_json::to_vec(proof).map_err(BenchError::prove)?,
+        verification_key: Vec::new(),
+        public_inputs: Vec::new(),
+        encoding: "cairo-m-json",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`].
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        verification_key.is_empty() && public_inputs.is_empty(),
+        "Cairo-M proofs have no verification key nor public inputs"
+    );
+    verify(&serde_json::from_slice(proof)?)?;
+    Ok(())
 }
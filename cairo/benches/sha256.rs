@@ -0,0 +1,21 @@
+use cairo::{
+    cairo_bench_properties, execution_cycles, export_artifacts, prepare_sha256, preprocessing_size,
+    proof_size, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::Cairo,
+    None,
+    "sha256_mem_cairo",
+    cairo_bench_properties(),
+    prepare_sha256,
+    |_prepared| 0,
+    prove,
+    |prepared, proof| verify(prepared, proof),
+    preprocessing_size,
+    |proof| proof_size(proof),
+    execution_cycles,
+    export_artifacts = |prepared: &_, proof: &Vec<u8>| export_artifacts(prepared, proof)
+);
@@ -0,0 +1,23 @@
+// Poseidon hash of the field elements `program_input["inputs"]` (decimal strings), written to
+// the output.
+%builtins output poseidon
+
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.builtin_poseidon.poseidon import poseidon_hash_many
+from starkware.cairo.common.cairo_builtins import PoseidonBuiltin
+
+func main{output_ptr: felt*, poseidon_ptr: PoseidonBuiltin*}() {
+    alloc_locals;
+    local n: felt;
+    let (elements: felt*) = alloc();
+    %{
+        inputs = [int(x) % PRIME for x in program_input["inputs"]]
+        ids.n = len(inputs)
+        segments.write_arg(ids.elements, inputs)
+    %}
+
+    let (hash) = poseidon_hash_many(n, elements);
+    assert [output_ptr] = hash;
+    let output_ptr = output_ptr + 1;
+    return ();
+}
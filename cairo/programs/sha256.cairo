@@ -0,0 +1,32 @@
+// SHA-256 of `program_input["message"]` (hex), with the digest written to the output as eight
+// big-endian 32-bit words.
+%builtins output range_check bitwise
+
+from starkware.cairo.common.alloc import alloc
+from starkware.cairo.common.cairo_builtins import BitwiseBuiltin
+from starkware.cairo.common.cairo_sha256.sha256_utils import finalize_sha256, sha256
+from starkware.cairo.common.memcpy import memcpy
+
+func main{output_ptr: felt*, range_check_ptr, bitwise_ptr: BitwiseBuiltin*}() {
+    alloc_locals;
+    local n_bytes: felt;
+    let (data: felt*) = alloc();
+    %{
+        message = bytes.fromhex(program_input["message"])
+        ids.n_bytes = len(message)
+        padded = message + bytes(-len(message) % 4)
+        segments.write_arg(
+            ids.data,
+            [int.from_bytes(padded[i : i + 4], "big") for i in range(0, len(padded), 4)],
+        )
+    %}
+
+    let (local sha256_ptr_start: felt*) = alloc();
+    let sha256_ptr = sha256_ptr_start;
+    let (digest: felt*) = sha256{sha256_ptr=sha256_ptr}(data, n_bytes);
+    finalize_sha256(sha256_ptr_start=sha256_ptr_start, sha256_ptr_end=sha256_ptr);
+
+    memcpy(output_ptr, digest, 8);
+    let output_ptr = output_ptr + 8;
+    return ();
+}
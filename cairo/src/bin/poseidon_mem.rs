@@ -0,0 +1,7 @@
+use cairo::{prepare_poseidon, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_poseidon, prove, |prepared, proof| {
+        verify(prepared, proof)
+    });
+}
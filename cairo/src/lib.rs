@@ -0,0 +1,492 @@
+//! Cairo 0 programs proved with StarkWare's Stone prover, run as subprocesses.
+//!
+//! `prepare` compiles the program with `cairo-compile` and runs it in proof mode with `cairo-run`
+//! (cairo-lang), which writes the trace, the memory and the public and private AIR inputs.
+//! `prove` runs `cpu_air_prover` on them and `verify` runs `cpu_air_verifier` on the proof. Like
+//! the zkVMs, the programs report their Cairo VM steps as execution cycles.
+
+use anyhow::{Context, ensure};
+use num_bigint::BigInt;
+use serde_json::{Value, json};
+use starknet_crypto::{FieldElement, poseidon_hash_many};
+use std::fs;
+use std::path::{Path, PathBuf};
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{AuditStatus, BenchError, BenchProperties, BenchTarget};
+use utils::security::{FriParams, STARK252_BITS};
+
+/// Layout with the output, range-check, bitwise and Poseidon builtins the programs use.
+const LAYOUT: &str = "recursive_with_poseidon";
+
+/// Stone's example parameters: blowup 16, 18 queries and 24 grinding bits.
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 4,
+    queries: 18,
+    pow_bits: 24,
+    field_bits: STARK252_BITS,
+};
+
+/// Degree bound of the last FRI layer, which is sent in the clear.
+const LAST_LAYER_DEGREE_BOUND: u64 = 64;
+/// Layers folded per FRI step.
+const FRI_STEP: u64 = 4;
+/// `log2` of the trace rows per Cairo step.
+const LOG_ROWS_PER_STEP: u64 = 4;
+
+pub fn cairo_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Stone",
+        "Stark252",
+        "STARK",
+        Some("FRI"),
+        "AIR",
+        false, // Stone does not mask the trace
+        true,  // Cairo VM
+        FRI_PARAMS.conjectured_security_bits(),
+        true, // hash-based PCS
+        true, // https://github.com/starkware-libs/stone-prover
+        AuditStatus::NotAudited,
+        Some("Cairo"),
+    )
+}
+
+/// A program run in proof mode, with the prover inputs in `dir`.
+pub struct PreparedCairo {
+    pub dir: PathBuf,
+    pub program: PathBuf,
+    /// Cairo VM steps before proof mode padded them to a power of two.
+    pub steps: u64,
+}
+
+impl PreparedCairo {
+    fn file(&self, name: &str) -> PathBuf {
+        self.dir.join(name)
+    }
+}
+
+/// Runs a cairo-lang or Stone tool, returning its stdout.
+fn run(tool: &str, args: &[&str]) -> Result<String, BenchError> {
+    let output = utils::sandbox::command(tool)
+        .args(args)
+        .output()
+        .map_err(|e| BenchError::tool(tool, e))?;
+    if !output.status.success() {
+        return Err(BenchError::tool(
+            tool,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn path_str(path: &Path) -> Result<&str, BenchError> {
+    path.to_str()
+        .ok_or_else(|| BenchError::setup(format!("{} is not UTF-8", path.display())))
+}
+
+/// Compiles `programs/{name}.cairo` and runs it on `input` in proof mode, in a work directory
+/// of its own under `target/cairo`. Returns the prepared run and the program output.
+fn run_program(
+    name: &str,
+    input_size: usize,
+    input: &Value,
+) -> Result<(PreparedCairo, Vec<String>), BenchError> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = manifest_dir
+        .join("../target/cairo")
+        .join(format!("{name}_{input_size}"));
+    fs::create_dir_all(&dir)?;
+    let dir = dir.canonicalize()?;
+
+    let source = manifest_dir.join("programs").join(format!("{name}.cairo"));
+    let program = dir.join("program.json");
+    run(
+        "cairo-compile",
+        &[
+            path_str(&source)?,
+            "--output",
+            path_str(&program)?,
+            "--proof_mode",
+        ],
+    )?;
+
+    let prepared = PreparedCairo {
+        dir,
+        program,
+        steps: 0,
+    };
+    fs::write(prepared.file("input.json"), input.to_string())?;
+    let stdout = run(
+        "cairo-run",
+        &[
+            "--program",
+            path_str(&prepared.program)?,
+            "--layout",
+            LAYOUT,
+            "--proof_mode",
+            "--program_input",
+            path_str(&prepared.file("input.json"))?,
+            "--air_public_input",
+            path_str(&prepared.file("public_input.json"))?,
+            "--air_private_input",
+            path_str(&prepared.file("private_input.json"))?,
+            "--trace_file",
+            path_str(&prepared.file("trace.bin"))?,
+            "--memory_file",
+            path_str(&prepared.file("memory.bin"))?,
+            "--print_output",
+            "--print_info",
+        ],
+    )?;
+    let steps = parse_steps(&stdout)
+        .ok_or_else(|| BenchError::tool("cairo-run", "no step count in its output"))?;
+
+    let public_input: Value =
+        serde_json::from_slice(&fs::read(prepared.file("public_input.json"))?)
+            .map_err(BenchError::setup)?;
+    let padded_steps = public_input["n_steps"]
+        .as_u64()
+        .ok_or_else(|| BenchError::setup("public input has no n_steps"))?;
+    fs::write(
+        prepared.file("params.json"),
+        prover_parameters(padded_steps).to_string(),
+    )?;
+
+    Ok((PreparedCairo { steps, ..prepared }, parse_output(&stdout)))
+}
+
+/// Stone parameters for a trace of `padded_steps` steps. The FRI steps have to reduce the trace
+/// degree to [`LAST_LAYER_DEGREE_BOUND`]: `log2(last_layer_degree_bound) + sum(fri_step_list)
+/// = log2(n_steps) + 4`.
+fn prover_parameters(padded_steps: u64) -> Value {
+    let mut remaining = u64::from(padded_steps.ilog2()) + LOG_ROWS_PER_STEP
+        - u64::from(LAST_LAYER_DEGREE_BOUND.ilog2());
+    // The first layer is committed without folding.
+    let mut fri_step_list = vec![0];
+    while remaining > 0 {
+        let step = remaining.min(FRI_STEP);
+        fri_step_list.push(step);
+        remaining -= step;
+    }
+    json!({
+        "field": "PrimeField0",
+        "stark": {
+            "fri": {
+                "fri_step_list": fri_step_list,
+                "last_layer_degree_bound": LAST_LAYER_DEGREE_BOUND,
+                "n_queries": FRI_PARAMS.queries,
+                "proof_of_work_bits": FRI_PARAMS.pow_bits,
+            },
+            "log_n_cosets": FRI_PARAMS.log_blowup,
+        },
+        "use_extension_field": false,
+    })
+}
+
+/// Steps from `Number of steps: 16384 (originally, 9017).`, before padding if it was padded.
+fn parse_steps(stdout: &str) -> Option<u64> {
+    let line = stdout
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Number of steps:"))?;
+    let numbers: Vec<u64> = line
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse().ok())
+        .collect();
+    numbers.last().copied()
+}
+
+/// The Stark252 prime, `2^251 + 17 * 2^192 + 1`.
+fn stark_prime() -> BigInt {
+    (BigInt::from(1) << 251) + (BigInt::from(17) << 192) + 1
+}
+
+/// A field element from a decimal integer of the program input or of `cairo-run`'s output, which
+/// prints the elements above half the prime as negative.
+fn felt(value: &str) -> Option<FieldElement> {
+    let prime = stark_prime();
+    let value = (value.parse::<BigInt>().ok()? % &prime + &prime) % &prime;
+    FieldElement::from_byte_slice_be(&value.to_bytes_be().1).ok()
+}
+
+/// Values printed under `Program output:`.
+fn parse_output(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .skip_while(|line| line.trim() != "Program output:")
+        .skip(1)
+        .map(str::trim)
+        .take_while(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+pub fn prepare_sha256(input_size: usize) -> Result<PreparedCairo, BenchError> {
+    let (message, digest) = utils::generate_sha256_input(input_size);
+    let (prepared, output) = run_program(
+        BenchTarget::Sha256.as_str(),
+        input_size,
+        &json!({ "message": hex::encode(message) }),
+    )?;
+    let actual: Vec<u8> = output
+        .iter()
+        .map(|word| word.parse::<u32>().map(u32::to_be_bytes))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| BenchError::tool("cairo-run", format!("unexpected digest word: {e}")))?
+        .concat();
+    if actual != digest {
+        return Err(BenchError::wrong_output(digest, actual));
+    }
+    Ok(prepared)
+}
+
+pub fn prepare_poseidon(input_size: usize) -> Result<PreparedCairo, BenchError> {
+    let inputs = utils::generate_poseidon_input_strings(input_size);
+    let elements = inputs
+        .iter()
+        .map(|input| felt(input))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| BenchError::setup("Poseidon input is not an integer"))?;
+    let expected = poseidon_hash_many(&elements);
+    let (prepared, output) = run_program(
+        BenchTarget::Poseidon.as_str(),
+        input_size,
+        &json!({ "inputs": inputs }),
+    )?;
+    let actual = match output.as_slice() {
+        [hash] => felt(hash)
+            .ok_or_else(|| BenchError::tool("cairo-run", format!("unexpected hash: {hash}")))?,
+        _ => {
+            return Err(BenchError::tool(
+                "cairo-run",
+                format!("expected the hash as the only output, got {output:?}"),
+            ));
+        }
+    };
+    if actual != expected {
+        return Err(BenchError::wrong_output(
+            expected.to_bytes_be(),
+            actual.to_bytes_be(),
+        ));
+    }
+    Ok(prepared)
+}
+
+/// The proof JSON written by `cpu_air_prover`.
+pub fn prove(prepared: &PreparedCairo) -> Result<Vec<u8>, BenchError> {
+    let proof = prepared.file("proof.json");
+    let config = Path::new(env!("CARGO_MANIFEST_DIR")).join("cpu_air_prover_config.json");
+    run(
+        "cpu_air_prover",
+        &[
+            "--out_file",
+            path_str(&proof)?,
+            "--private_input_file",
+            path_str(&prepared.file("private_input.json"))?,
+            "--public_input_file",
+            path_str(&prepared.file("public_input.json"))?,
+            "--prover_config_file",
+            path_str(&config)?,
+            "--parameter_file",
+            path_str(&prepared.file("params.json"))?,
+        ],
+    )?;
+    Ok(fs::read(proof)?)
+}
+
+/// The program and the output in the public memory of a Stone public input. In proof mode the
+/// program is loaded below the execution segment; its cells are what a verifier such as SHARP
+/// hashes into the program hash. The output cells are those of the output segment.
+fn public_statement(public_input: &Value) -> Option<(Vec<&Value>, Vec<&Value>)> {
+    let segments = &public_input["memory_segments"];
+    let execution = segments["execution"]["begin_addr"].as_u64()?;
+    let output =
+        segments["output"]["begin_addr"].as_u64()?..segments["output"]["stop_ptr"].as_u64()?;
+    let mut cells = public_input["public_memory"]
+        .as_array()?
+        .iter()
+        .map(|cell| Some((cell["address"].as_u64()?, &cell["value"])))
+        .collect::<Option<Vec<_>>>()?;
+    cells.sort_by_key(|(address, _)| *address);
+    let program = cells
+        .iter()
+        .filter(|(address, _)| *address < execution)
+        .map(|(_, value)| *value)
+        .collect();
+    let output = cells
+        .iter()
+        .filter(|(address, _)| output.contains(address))
+        .map(|(_, value)| *value)
+        .collect();
+    Some((program, output))
+}
+
+/// Checks that the proof is about the prepared run, comparing the program and the output of its
+/// public input with those `cairo-run` wrote, then runs `cpu_air_verifier`.
+pub fn verify(prepared: &PreparedCairo, proof: &[u8]) -> Result<(), BenchError> {
+    let proof_json: Value = serde_json::from_slice(proof).map_err(BenchError::verify)?;
+    let (program, output) = public_statement(&proof_json["public_input"])
+        .ok_or_else(|| BenchError::verify("proof has no Cairo public input"))?;
+    let expected: Value = serde_json::from_slice(&fs::read(prepared.file("public_input.json"))?)
+        .map_err(BenchError::setup)?;
+    let (expected_program, expected_output) = public_statement(&expected)
+        .ok_or_else(|| BenchError::setup("public_input.json has no public memory"))?;
+    BenchError::check(program == expected_program, "proof is for another program")?;
+    BenchError::check(
+        output == expected_output,
+        "proof has another program output",
+    )?;
+
+    run_verifier(&prepared.file("verified_proof.json"), proof)
+}
+
+/// Writes the proof to `path` and runs `cpu_air_verifier` on it.
+fn run_verifier(path: &Path, proof: &[u8]) -> Result<(), BenchError> {
+    fs::write(path, proof)?;
+    run("cpu_air_verifier", &["--in_file", path_str(path)?])
+        .map(drop)
+        .map_err(BenchError::verify)
+}
+
+/// The proof JSON, which carries its public input. Stone has no verification key: the program
+/// cells of the prepared run's public memory stand for it and its output cells are the public
+/// inputs, both as JSON arrays.
+pub fn export_artifacts(
+    prepared: &PreparedCairo,
+    proof: &[u8],
+) -> Result<ExportedArtifacts, BenchError> {
+    let public_input: Value =
+        serde_json::from_slice(&fs::read(prepared.file("public_input.json"))?)
+            .map_err(BenchError::setup)?;
+    let (program, output) = public_statement(&public_input)
+        .ok_or_else(|| BenchError::setup("public_input.json has no public memory"))?;
+    Ok(ExportedArtifacts {
+        proof: proof.to_vec(),
+        verification_key: serde_json::to_vec(&program).map_err(BenchError::setup)?,
+        public_inputs: serde_json::to_vec(&output).map_err(BenchError::setup)?,
+        encoding: "stone-json",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`]: checks the program and the output of
+/// the proof's public input against them, then runs `cpu_air_verifier`.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let proof_json: Value = serde_json::from_slice(proof)?;
+    let (program, output) =
+        public_statement(&proof_json["public_input"]).context("proof has no Cairo public input")?;
+    let expected_program: Vec<Value> = serde_json::from_slice(verification_key)?;
+    let expected_output: Vec<Value> = serde_json::from_slice(public_inputs)?;
+    ensure!(
+        program.into_iter().eq(&expected_program),
+        "proof is for another program"
+    );
+    ensure!(
+        output.into_iter().eq(&expected_output),
+        "proof has another program output"
+    );
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/cairo/artifacts");
+    fs::create_dir_all(&dir)?;
+    run_verifier(
+        &dir.join(format!("proof_{}.json", std::process::id())),
+        proof,
+    )?;
+    Ok(())
+}
+
+/// Size of the compiled program.
+pub fn preprocessing_size(prepared: &PreparedCairo) -> usize {
+    fs::metadata(&prepared.program).map_or(0, |m| m.len() as usize)
+}
+
+/// Size of the proof itself, the `proof_hex` field of the JSON the prover writes next to its
+/// parameters and public input.
+pub fn proof_size(proof: &[u8]) -> usize {
+    serde_json::from_slice::<Value>(proof)
+        .ok()
+        .and_then(|proof| proof["proof_hex"].as_str().map(|hex| hex.len() / 2))
+        .unwrap_or(proof.len())
+}
+
+pub fn execution_cycles(prepared: &PreparedCairo) -> u64 {
+    prepared.steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fri_steps_reach_the_last_layer() {
+        for log_steps in 6..24 {
+            let params = prover_parameters(1 << log_steps);
+            let folded: u64 = params["stark"]["fri"]["fri_step_list"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|step| step.as_u64().unwrap())
+                .sum();
+            assert_eq!(folded + 6, log_steps + LOG_ROWS_PER_STEP);
+        }
+    }
+
+    #[test]
+    fn test_parses_cairo_run_output() {
+        let stdout = "Program output:\n  1779033703\n  -3\n\nNumber of steps: 16384 (originally, 9017).\nUsed memory cells: 42\n";
+        assert_eq!(parse_output(stdout), ["1779033703", "-3"]);
+        assert_eq!(parse_steps(stdout), Some(9017));
+        assert_eq!(parse_steps("Number of steps: 512.\n"), Some(512));
+    }
+
+    #[test]
+    fn test_reads_negative_output_as_field_elements() {
+        assert_eq!(felt("-1"), Some(FieldElement::MAX));
+        assert_eq!(felt("3"), Some(FieldElement::THREE));
+        assert_eq!(felt(&stark_prime().to_string()), Some(FieldElement::ZERO));
+        assert_eq!(felt("0x1"), None);
+    }
+
+    #[test]
+    fn test_public_statement_splits_program_and_output() {
+        let public_input = json!({
+            "memory_segments": {
+                "execution": { "begin_addr": 4, "stop_ptr": 9 },
+                "output": { "begin_addr": 9, "stop_ptr": 10 },
+            },
+            "public_memory": [
+                { "address": 9, "value": "0x2a", "page": 0 },
+                { "address": 2, "value": "0x2", "page": 0 },
+                { "address": 1, "value": "0x1", "page": 0 },
+                { "address": 4, "value": "0x9", "page": 0 },
+            ],
+        });
+        let (program, output) = public_statement(&public_input).unwrap();
+        assert_eq!(program, [&json!("0x1"), &json!("0x2")]);
+        assert_eq!(output, [&json!("0x2a")]);
+        assert_eq!(public_statement(&json!({})), None);
+    }
+
+    #[test]
+    fn test_exported_statement_must_match_the_proof() {
+        let proof = json!({
+            "public_input": {
+                "memory_segments": {
+                    "execution": { "begin_addr": 2, "stop_ptr": 3 },
+                    "output": { "begin_addr": 3, "stop_ptr": 4 },
+                },
+                "public_memory": [
+                    { "address": 1, "value": "0x1", "page": 0 },
+                    { "address": 3, "value": "0x2a", "page": 0 },
+                ],
+            },
+        })
+        .to_string();
+        let err = verify_artifacts(proof.as_bytes(), br#"["0x1"]"#, br#"["0x2b"]"#).unwrap_err();
+        assert!(err.to_string().contains("another program output"), "{err}");
+        let err = verify_artifacts(proof.as_bytes(), br#"["0x2"]"#, br#"["0x2a"]"#).unwrap_err();
+        assert!(err.to_string().contains("another program"), "{err}");
+    }
+}
@@ -0,0 +1,8 @@
+use circom::{CircomSuite, blake2s};
+use utils::harness::BenchTarget;
+
+utils::define_benchmark_suite!(CircomSuite {
+    target: BenchTarget::Blake2s,
+    mem_binary_name: "blake2s_mem_circom",
+    prepare: blake2s::prepare,
+});
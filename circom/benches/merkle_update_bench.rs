@@ -0,0 +1,8 @@
+use circom::{CircomSuite, merkle_update};
+use utils::harness::BenchTarget;
+
+utils::define_benchmark_suite!(CircomSuite {
+    target: BenchTarget::MerkleUpdate,
+    mem_binary_name: "merkle_update_mem_circom",
+    prepare: merkle_update::prepare,
+});
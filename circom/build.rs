@@ -42,6 +42,22 @@ fn find_rapidsnark_dirs(out_dir: &str) -> Vec<PathBuf> {
     dirs
 }
 
+/// Builds a circuit that circuits/setup.sh generates and is not checked in. A circuit that has not
+/// been generated is skipped with a warning rather than failing the build, so that
+/// `--all-features` still type-checks; its bench then fails to link until setup.sh has run.
+fn build_and_link_generated(circuit_dir: &str) {
+    let dir = Path::new(circuit_dir);
+    let name = dir.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    let generated = ["cpp", "dat"]
+        .iter()
+        .all(|ext| dir.join(format!("{name}.{ext}")).exists());
+    if !generated {
+        println!("cargo:warning=skipping {circuit_dir}: not generated, run circuits/setup.sh");
+        return;
+    }
+    witnesscalc_adapter::build_and_link(circuit_dir);
+}
+
 fn main() {
     // SHA256 circuits
     witnesscalc_adapter::build_and_link("./circuits/sha256/sha256_128");
@@ -57,6 +73,15 @@ fn main() {
     witnesscalc_adapter::build_and_link("./circuits/keccak/keccak_1024");
     witnesscalc_adapter::build_and_link("./circuits/keccak/keccak_2048");
 
+    // Keccak circuits at the sponge-boundary sizes, generated by circuits/setup.sh
+    if std::env::var_os("CARGO_FEATURE_KECCAK_BOUNDARY").is_some() {
+        build_and_link_generated("./circuits/keccak/keccak_135");
+        build_and_link_generated("./circuits/keccak/keccak_136");
+        build_and_link_generated("./circuits/keccak/keccak_137");
+        build_and_link_generated("./circuits/keccak/keccak_271");
+        build_and_link_generated("./circuits/keccak/keccak_272");
+    }
+
     // Poseidon circuits
     witnesscalc_adapter::build_and_link("./circuits/poseidon/poseidon_2");
     witnesscalc_adapter::build_and_link("./circuits/poseidon/poseidon_4");
@@ -64,6 +89,22 @@ fn main() {
     witnesscalc_adapter::build_and_link("./circuits/poseidon/poseidon_12");
     witnesscalc_adapter::build_and_link("./circuits/poseidon/poseidon_16");
 
+    // Merkle batch update circuits, generated by circuits/setup.sh
+    if std::env::var_os("CARGO_FEATURE_MERKLE_UPDATE").is_some() {
+        build_and_link_generated("./circuits/merkle_update/merkle_update_4");
+        build_and_link_generated("./circuits/merkle_update/merkle_update_16");
+        build_and_link_generated("./circuits/merkle_update/merkle_update_64");
+    }
+
+    // BLAKE2s circuits, generated by circuits/setup.sh
+    if std::env::var_os("CARGO_FEATURE_BLAKE2S").is_some() {
+        build_and_link_generated("./circuits/blake2s/blake2s_128");
+        build_and_link_generated("./circuits/blake2s/blake2s_256");
+        build_and_link_generated("./circuits/blake2s/blake2s_512");
+        build_and_link_generated("./circuits/blake2s/blake2s_1024");
+        build_and_link_generated("./circuits/blake2s/blake2s_2048");
+    }
+
     if let Ok(out_dir) = std::env::var("OUT_DIR") {
         let lib_dir = Path::new(&out_dir)
             .join("witnesscalc")
@@ -0,0 +1,5 @@
+pragma circom 2.0.0;
+
+include "./blake2s_hash.circom";
+
+component main = Blake2sHash(512);
@@ -0,0 +1,17 @@
+pragma circom 2.0.0;
+
+include "../../hash-circuits/circuits/blake2/blake2s.circom";
+
+template Blake2sHash(N) {
+    signal input in[N];
+    signal input hash[32];
+    signal output out[32];
+
+    component blake2s = Blake2s_bytes(N);
+    blake2s.inp_bytes <== in;
+    out <== blake2s.hash_bytes;
+
+    for (var i = 0; i < 32; i++) {
+        out[i] === hash[i];
+    }
+}
@@ -0,0 +1,5 @@
+pragma circom 2.0.0;
+
+include "./keccak_hash.circom";
+
+component main = Keccak256Hash(135);
@@ -0,0 +1,5 @@
+pragma circom 2.0.0;
+
+include "./merkle_update_batch.circom";
+
+component main {public [old_root, new_root]} = MerkleBatchUpdate(64, 20);
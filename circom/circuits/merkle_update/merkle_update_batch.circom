@@ -0,0 +1,62 @@
+pragma circom 2.0.0;
+
+include "../../circomlib/circuits/bitify.circom";
+include "../../circomlib/circuits/poseidon.circom";
+
+// Root of a tree holding `leaf` at the index whose bits, least significant first, are `bits`.
+template MerkleRoot(DEPTH) {
+    signal input leaf;
+    signal input bits[DEPTH];
+    signal input siblings[DEPTH];
+    signal output root;
+
+    component hashers[DEPTH];
+    signal nodes[DEPTH + 1];
+    signal left[DEPTH];
+    nodes[0] <== leaf;
+    for (var i = 0; i < DEPTH; i++) {
+        // A set bit puts the node on the right of its sibling.
+        left[i] <== nodes[i] + bits[i] * (siblings[i] - nodes[i]);
+        hashers[i] = Poseidon(2);
+        hashers[i].inputs[0] <== left[i];
+        hashers[i].inputs[1] <== nodes[i] + siblings[i] - left[i];
+        nodes[i + 1] <== hashers[i].out;
+    }
+    root <== nodes[DEPTH];
+}
+
+// Applies K leaf updates in order, moving the tree from `old_root` to `new_root`. Each update
+// checks the old leaf against the current root and recomputes the root with the new leaf along
+// the same path.
+template MerkleBatchUpdate(K, DEPTH) {
+    signal input old_root;
+    signal input new_root;
+    signal input indices[K];
+    signal input old_leaves[K];
+    signal input new_leaves[K];
+    signal input siblings[K][DEPTH];
+
+    component bits[K];
+    component old_paths[K];
+    component new_paths[K];
+    signal roots[K + 1];
+    roots[0] <== old_root;
+    for (var k = 0; k < K; k++) {
+        bits[k] = Num2Bits(DEPTH);
+        bits[k].in <== indices[k];
+
+        old_paths[k] = MerkleRoot(DEPTH);
+        new_paths[k] = MerkleRoot(DEPTH);
+        old_paths[k].leaf <== old_leaves[k];
+        new_paths[k].leaf <== new_leaves[k];
+        for (var i = 0; i < DEPTH; i++) {
+            old_paths[k].bits[i] <== bits[k].out[i];
+            new_paths[k].bits[i] <== bits[k].out[i];
+            old_paths[k].siblings[i] <== siblings[k][i];
+            new_paths[k].siblings[i] <== siblings[k][i];
+        }
+        old_paths[k].root === roots[k];
+        roots[k + 1] <== new_paths[k].root;
+    }
+    new_root === roots[K];
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+# Compiles the circuits that are not checked in (merkle_update, blake2s and the keccak
+# sponge-boundary sizes) into the layout build.rs links and the benches load:
+# <family>/<name>/<name>.cpp and <name>.dat (C++ witness generator) and
+# <family>/<name>/<name>_0001.zkey (Groth16 proving key), where <name> is <family>_<size>.
+#
+# Usage: PTAU=<file> circuits/setup.sh <family> <size>...
+# e.g.   PTAU=powersOfTau28_hez_final_20.ptau circuits/setup.sh merkle_update 4 16 64
+#
+# Needs circom 2, snarkjs and a Powers of Tau file large enough for the biggest circuit (e.g.
+# from the Hermez ceremony) passed as PTAU.
+set -euo pipefail
+
+: "${PTAU:?set PTAU to a Powers of Tau file large enough for the circuits}"
+family=${1:?usage: setup.sh <family> <size>...}
+shift
+[ $# -gt 0 ] || { echo "usage: setup.sh <family> <size>..." >&2; exit 1; }
+cd "$(dirname "$0")/$family"
+build_dir=$(mktemp -d)
+trap 'rm -rf "$build_dir"' EXIT
+
+for size in "$@"; do
+  name="${family}_${size}"
+  circom "${name}.circom" --r1cs --c -o "$build_dir"
+  mkdir -p "$name"
+  cp "$build_dir/${name}_cpp/${name}.cpp" "$build_dir/${name}_cpp/${name}.dat" "$name/"
+  snarkjs groth16 setup "$build_dir/${name}.r1cs" "$PTAU" "$build_dir/${name}_0000.zkey"
+  snarkjs zkey contribute "$build_dir/${name}_0000.zkey" "$name/${name}_0001.zkey" \
+    --name="csp-benchmarks" -e="$(head -c 32 /dev/urandom | od -An -tx1 | tr -d ' \n')"
+done
@@ -0,0 +1,11 @@
+use circom::blake2s::{prepare, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(
+        prepare,
+        |(witness_fn, input_str, zkey_path)| {
+            prove(*witness_fn, input_str.clone(), zkey_path.clone())
+        },
+        |(_, _, zkey_path), proof| verify(proof.clone(), zkey_path.clone()),
+    );
+}
@@ -1,20 +1,11 @@
-use circom::keccak::{prepare, prove};
-use clap::Parser;
-
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size parameter
-    #[arg(long)]
-    input_size: usize,
-}
+use circom::keccak::{prepare, prove, verify};
 
 fn main() {
-    let args = Args::parse();
-
-    keccak_mem(args.input_size);
-}
-
-fn keccak_mem(input_size: usize) {
-    let (witness_fn, input_str, zkey_path) = prepare(input_size);
-    let _ = prove(witness_fn, input_str, zkey_path);
+    utils::bench::mem::run_with_verify(
+        prepare,
+        |(witness_fn, input_str, zkey_path)| {
+            prove(*witness_fn, input_str.clone(), zkey_path.clone())
+        },
+        |(_, _, zkey_path), proof| verify(proof.clone(), zkey_path.clone()),
+    );
 }
@@ -1,20 +1,11 @@
-use circom::poseidon::{prepare, prove};
-use clap::Parser;
-
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size parameter (number of field elements)
-    #[arg(long)]
-    input_size: usize,
-}
+use circom::poseidon::{prepare, prove, verify};
 
 fn main() {
-    let args = Args::parse();
-
-    poseidon_mem(args.input_size);
-}
-
-fn poseidon_mem(input_size: usize) {
-    let (witness_fn, input_str, zkey_path) = prepare(input_size);
-    let _ = prove(witness_fn, input_str, zkey_path);
+    utils::bench::mem::run_with_verify(
+        prepare,
+        |(witness_fn, input_str, zkey_path)| {
+            prove(*witness_fn, input_str.clone(), zkey_path.clone())
+        },
+        |(_, _, zkey_path), proof| verify(proof.clone(), zkey_path.clone()),
+    );
 }
@@ -0,0 +1,54 @@
+use circom_prover::witness::WitnessFn;
+use std::collections::HashMap;
+use utils::generate_blake2s_input;
+use utils::harness::BenchError;
+
+pub use crate::{prove, verify};
+
+witnesscalc_adapter::witness!(blake2s_128);
+witnesscalc_adapter::witness!(blake2s_256);
+witnesscalc_adapter::witness!(blake2s_512);
+witnesscalc_adapter::witness!(blake2s_1024);
+witnesscalc_adapter::witness!(blake2s_2048);
+
+pub fn prepare(input_size: usize) -> Result<(WitnessFn, String, String), BenchError> {
+    let witness_fn = match input_size {
+        128 => WitnessFn::WitnessCalc(blake2s_128_witness),
+        256 => WitnessFn::WitnessCalc(blake2s_256_witness),
+        512 => WitnessFn::WitnessCalc(blake2s_512_witness),
+        1024 => WitnessFn::WitnessCalc(blake2s_1024_witness),
+        2048 => WitnessFn::WitnessCalc(blake2s_2048_witness),
+        _ => {
+            return Err(BenchError::setup(format!(
+                "Unsupported blake2s input size: {input_size}"
+            )));
+        }
+    };
+
+    let (input, digest) = generate_blake2s_input(input_size);
+    let inputs = HashMap::from([
+        (
+            "in".to_string(),
+            input
+                .into_iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<String>>(),
+        ),
+        (
+            "hash".to_string(),
+            digest
+                .into_iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<String>>(),
+        ),
+    ]);
+    let input_str = serde_json::to_string(&inputs).map_err(BenchError::setup)?;
+
+    let current_dir = std::env::current_dir()?;
+    let zkey_path = format!(
+        "{}/circuits/blake2s/blake2s_{input_size}/blake2s_{input_size}_0001.zkey",
+        current_dir.display()
+    );
+
+    Ok((witness_fn, input_str, zkey_path))
+}
@@ -1,6 +1,7 @@
 use circom_prover::witness::WitnessFn;
 use std::collections::HashMap;
 use utils::generate_keccak_input;
+use utils::harness::BenchError;
 
 pub use crate::{prove, verify};
 
@@ -9,15 +10,40 @@ witnesscalc_adapter::witness!(keccak_256);
 witnesscalc_adapter::witness!(keccak_512);
 witnesscalc_adapter::witness!(keccak_1024);
 witnesscalc_adapter::witness!(keccak_2048);
+#[cfg(feature = "keccak_boundary")]
+witnesscalc_adapter::witness!(keccak_135);
+#[cfg(feature = "keccak_boundary")]
+witnesscalc_adapter::witness!(keccak_136);
+#[cfg(feature = "keccak_boundary")]
+witnesscalc_adapter::witness!(keccak_137);
+#[cfg(feature = "keccak_boundary")]
+witnesscalc_adapter::witness!(keccak_271);
+#[cfg(feature = "keccak_boundary")]
+witnesscalc_adapter::witness!(keccak_272);
 
-pub fn prepare(input_size: usize) -> (WitnessFn, String, String) {
+pub fn prepare(input_size: usize) -> Result<(WitnessFn, String, String), BenchError> {
     let witness_fn = match input_size {
         128 => WitnessFn::WitnessCalc(keccak_128_witness),
         256 => WitnessFn::WitnessCalc(keccak_256_witness),
         512 => WitnessFn::WitnessCalc(keccak_512_witness),
         1024 => WitnessFn::WitnessCalc(keccak_1024_witness),
         2048 => WitnessFn::WitnessCalc(keccak_2048_witness),
-        _ => unreachable!("Unsupported keccak input size: {}", input_size),
+        #[cfg(feature = "keccak_boundary")]
+        135 => WitnessFn::WitnessCalc(keccak_135_witness),
+        #[cfg(feature = "keccak_boundary")]
+        136 => WitnessFn::WitnessCalc(keccak_136_witness),
+        #[cfg(feature = "keccak_boundary")]
+        137 => WitnessFn::WitnessCalc(keccak_137_witness),
+        #[cfg(feature = "keccak_boundary")]
+        271 => WitnessFn::WitnessCalc(keccak_271_witness),
+        #[cfg(feature = "keccak_boundary")]
+        272 => WitnessFn::WitnessCalc(keccak_272_witness),
+        _ => {
+            return Err(BenchError::setup(format!(
+                "Unsupported keccak input size: {input_size} (the sponge-boundary sizes need \
+                 circuits/setup.sh keccak 135 136 137 271 272 and --features keccak_boundary)"
+            )));
+        }
     };
 
     let (input, digest) = generate_keccak_input(input_size);
@@ -37,13 +63,13 @@ pub fn prepare(input_size: usize) -> (WitnessFn, String, String) {
                 .collect::<Vec<String>>(),
         ),
     ]);
-    let input_str = serde_json::to_string(&inputs).unwrap();
+    let input_str = serde_json::to_string(&inputs).map_err(BenchError::setup)?;
 
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
+    let current_dir = std::env::current_dir()?;
     let zkey_path = format!(
         "{}/circuits/keccak/keccak_{input_size}/keccak_{input_size}_0001.zkey",
-        current_dir.as_path().to_str().unwrap()
+        current_dir.display()
     );
 
-    (witness_fn, input_str, zkey_path)
+    Ok((witness_fn, input_str, zkey_path))
 }
@@ -1,15 +1,34 @@
+#[cfg(feature = "blake2s")]
+pub mod blake2s;
 pub mod keccak;
+#[cfg(feature = "merkle_update")]
+pub mod merkle_update;
 pub mod poseidon;
 pub mod sha256;
 
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::{Groth16, VerifyingKey, prepare_verifying_key};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use circom_prover::{
     CircomProver,
-    prover::{CircomProof, ProofLib},
-    witness::WitnessFn,
+    prover::{self, CircomProof, ProofLib, PublicInputs, ark_circom, circom::Proof},
+    witness::{self, WitnessFn},
 };
 use std::borrow::Cow;
+use std::fs::File;
+use std::io::BufReader;
 use std::path::Path;
-use utils::harness::{AuditStatus, BenchProperties};
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{
+    AuditStatus, BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite,
+    ProvingSystem,
+};
+use utils::metadata::KECCAK_BOUNDARY_INPUTS;
+use utils::verification::CrossVerification;
+use utils::witness_size::{ConstraintCount, WitnessSize};
+
+/// Witness generator, circuit inputs (JSON) and zkey path of one circuit.
+pub type Prepared = (WitnessFn, String, String);
 
 pub const CIRCOM_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     proving_system: Cow::Borrowed("Groth16"),
@@ -24,12 +43,16 @@ pub const CIRCOM_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     is_maintained: true,
     is_audited: AuditStatus::PartiallyAudited, // e.g., https://veridise.com/wp-content/uploads/2023/02/VAR-circom-bigint.pdf
     isa: None,
+    prover_resource: None,
+    version: None,
+    onchain_verifier: Some(Cow::Borrowed("evm")), // snarkjs exports a Solidity verifier
+    caveats: Cow::Borrowed(&[]),
 };
 
 pub fn sum_file_sizes_in_the_dir(file_path: &str) -> std::io::Result<usize> {
     let dir = Path::new(file_path)
         .parent()
-        .expect("File should have a parent directory");
+        .ok_or_else(|| std::io::Error::other("zkey path has no parent directory"))?;
 
     let mut total_size: usize = 0;
 
@@ -45,34 +68,77 @@ pub fn sum_file_sizes_in_the_dir(file_path: &str) -> std::io::Result<usize> {
     Ok(total_size)
 }
 
-pub fn prove(witness_fn: WitnessFn, input_str: String, zkey_path: String) -> CircomProof {
-    // Generate proof
-    CircomProver::prove(
-        ProofLib::Rapidsnark, // The rapidsnark prover
-        witness_fn,
-        input_str,
-        zkey_path,
-    )
-    .unwrap()
+/// Contents of the files [`sum_file_sizes_in_the_dir`] counts, one after the other.
+pub fn read_files_in_the_dir(file_path: &str) -> std::io::Result<Vec<u8>> {
+    let dir = Path::new(file_path)
+        .parent()
+        .ok_or_else(|| std::io::Error::other("zkey path has no parent directory"))?;
+
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.metadata()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+
+    let mut contents = Vec::new();
+    for path in paths {
+        contents.extend(std::fs::read(path)?);
+    }
+    Ok(contents)
+}
+
+pub fn prove(
+    witness_fn: WitnessFn,
+    input_str: String,
+    zkey_path: String,
+) -> Result<CircomProof, BenchError> {
+    // Generate the witness, then the proof, as `CircomProver::prove` does, but one after the
+    // other so each is timed in its own span
+    let witness = tracing::info_span!("generate_witness")
+        .in_scope(|| witness::generate_witness(witness_fn, input_str).join())
+        .map_err(|_| BenchError::prove("witness generation panicked"))?;
+    tracing::info_span!("groth16_prove")
+        .in_scope(|| {
+            prover::prove(
+                ProofLib::Rapidsnark, // The rapidsnark prover
+                zkey_path,
+                std::thread::spawn(move || witness),
+            )
+        })
+        .map_err(BenchError::prove)
 }
 
-pub fn verify(proof: CircomProof, zkey_path: String) {
+pub fn verify(proof: CircomProof, zkey_path: String) -> Result<(), BenchError> {
     // Verify proof
-    let valid = CircomProver::verify(ProofLib::Rapidsnark, proof, zkey_path).unwrap();
+    let valid =
+        CircomProver::verify(ProofLib::Rapidsnark, proof, zkey_path).map_err(BenchError::verify)?;
 
-    assert!(valid);
+    BenchError::check(valid, "Groth16 proof rejected")
 }
 
-pub fn read_constraint_count(zkey_path: &str) -> usize {
-    use ark_bn254::Bn254;
-    use circom_prover::prover::ark_circom;
-    use std::fs::File;
-    use std::io::BufReader;
+/// Verifies with ark-groth16 instead of rapidsnark, as an independent check of [`verify`].
+pub fn verify_arkworks(proof: CircomProof, zkey_path: String) -> Result<(), BenchError> {
+    let valid =
+        CircomProver::verify(ProofLib::Arkworks, proof, zkey_path).map_err(BenchError::verify)?;
 
-    let mut buffer = BufReader::new(File::open(zkey_path).expect("Unable to open zkey"));
-    let (_, constraint_matrices) =
-        ark_circom::read_zkey::<_, Bn254>(&mut buffer).expect("Unable to read zkey");
-    constraint_matrices.num_constraints
+    BenchError::check(valid, "Groth16 proof rejected by ark-groth16")
+}
+
+pub fn read_constraint_count(zkey_path: &str) -> Result<ConstraintCount, BenchError> {
+    let zkey = File::open(zkey_path)
+        .map_err(|e| BenchError::setup(format!("unable to open {zkey_path}: {e}")))?;
+    let (_, constraint_matrices) = ark_circom::read_zkey::<_, Bn254>(&mut BufReader::new(zkey))
+        .map_err(|e| BenchError::setup(format!("unable to read {zkey_path}: {e}")))?;
+    // The witness vector holds the public (instance) and private variables, BN254 elements.
+    let witness_len =
+        constraint_matrices.num_instance_variables + constraint_matrices.num_witness_variables;
+    Ok(ConstraintCount::with_witness(
+        constraint_matrices.num_constraints,
+        WitnessSize::new(witness_len as u64, 32),
+    ))
 }
 
 pub fn proof_size(proof: &CircomProof) -> usize {
@@ -80,3 +146,126 @@ pub fn proof_size(proof: &CircomProof) -> usize {
         .expect("Failed to serialize proof")
         .len()
 }
+
+/// Export the proof and public inputs as snarkjs JSON, and the verifying key of the zkey in
+/// arkworks' compressed encoding.
+pub fn export_artifacts(zkey_path: &str, proof: &CircomProof) -> anyhow::Result<ExportedArtifacts> {
+    let mut reader = BufReader::new(File::open(zkey_path)?);
+    let proving_key = ark_circom::read_proving_key::<_, Bn254>(&mut reader)?;
+    let mut verification_key = Vec::new();
+    proving_key.vk.serialize_compressed(&mut verification_key)?;
+    Ok(ExportedArtifacts {
+        proof: serde_json::to_vec(&proof.proof)?,
+        verification_key,
+        public_inputs: serde_json::to_vec(&proof.pub_inputs)?,
+        encoding: "snarkjs-json+ark-compressed",
+    })
+}
+
+/// Verify artifacts exported with [`export_artifacts`] with ark-groth16.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let vk = VerifyingKey::<Bn254>::deserialize_compressed(verification_key)?;
+    let proof: Proof = serde_json::from_slice(proof)?;
+    let PublicInputs(public_inputs) = serde_json::from_slice(public_inputs)?;
+    let public_inputs: Vec<Fr> = public_inputs.into_iter().map(Fr::from).collect();
+    let valid =
+        Groth16::<Bn254>::verify_proof(&prepare_verifying_key(&vk), &proof.into(), &public_inputs)?;
+    anyhow::ensure!(valid, "Groth16 proof rejected");
+    Ok(())
+}
+
+/// The circom bench of `target`: proves and verifies with rapidsnark, and cross-checks every
+/// proof with ark-groth16.
+pub struct CircomSuite {
+    pub target: BenchTarget,
+    pub mem_binary_name: &'static str,
+    pub prepare: fn(usize) -> Result<Prepared, BenchError>,
+}
+
+impl BenchmarkSuite for CircomSuite {
+    type Prepared = Prepared;
+    type Proof = CircomProof;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        BenchHarnessConfig {
+            target: self.target,
+            system: ProvingSystem::Circom,
+            feature: None,
+            mem_binary_name: self.mem_binary_name,
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        CIRCOM_BENCH_PROPERTIES
+    }
+
+    /// The sponge-boundary Keccak sizes need circuits compiled by circuits/setup.sh, so they are
+    /// only benchmarked with the `keccak_boundary` feature.
+    fn sizes(&self) -> Vec<usize> {
+        let mut sizes = utils::input_sizes_for(self.target);
+        if matches!(self.target, BenchTarget::Keccak) && !cfg!(feature = "keccak_boundary") {
+            sizes.retain(|size| !KECCAK_BOUNDARY_INPUTS.contains(size));
+        }
+        sizes
+    }
+
+    fn prepare(&self, size: usize) -> Result<Prepared, BenchError> {
+        (self.prepare)(size)
+    }
+
+    fn prove(
+        &self,
+        (witness_fn, input_str, zkey_path): &Prepared,
+    ) -> Result<CircomProof, BenchError> {
+        prove(*witness_fn, input_str.clone(), zkey_path.clone())
+    }
+
+    fn verify(&self, (_, _, zkey_path): &Prepared, proof: &CircomProof) -> Result<(), BenchError> {
+        verify(proof.clone(), zkey_path.clone())
+    }
+
+    fn constraint_count(
+        &self,
+        (_, _, zkey_path): &Prepared,
+    ) -> Result<ConstraintCount, BenchError> {
+        read_constraint_count(zkey_path)
+    }
+
+    // NOTE: We assume that the dir which includes "[circuit].zkey" also contains the files
+    //       needed for witness generation("[circuit].cpp", "[circuit].dat" files).
+    fn preprocessing_size(&self, (_, _, zkey_path): &Prepared) -> Result<usize, BenchError> {
+        sum_file_sizes_in_the_dir(zkey_path).map_err(BenchError::setup)
+    }
+
+    fn preprocessing_blob(&self, (_, _, zkey_path): &Prepared) -> Option<Vec<u8>> {
+        read_files_in_the_dir(zkey_path).ok()
+    }
+
+    fn proof_size(&self, proof: &CircomProof) -> usize {
+        proof_size(proof)
+    }
+
+    fn export_artifacts(
+        &self,
+        (_, _, zkey_path): &Prepared,
+        proof: &CircomProof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        export_artifacts(zkey_path, proof)
+            .map(Some)
+            .map_err(BenchError::setup)
+    }
+
+    fn cross_verify(
+        &self,
+        (_, _, zkey_path): &Prepared,
+        proof: &CircomProof,
+    ) -> Vec<CrossVerification> {
+        vec![CrossVerification::check("ark-groth16", || {
+            verify_arkworks(proof.clone(), zkey_path.clone())
+        })]
+    }
+}
@@ -0,0 +1,53 @@
+use circom_prover::witness::WitnessFn;
+use serde_json::json;
+use utils::generate_merkle_update_input;
+use utils::harness::BenchError;
+
+pub use crate::{prove, verify};
+
+witnesscalc_adapter::witness!(merkle_update_4);
+witnesscalc_adapter::witness!(merkle_update_16);
+witnesscalc_adapter::witness!(merkle_update_64);
+
+pub fn prepare(input_size: usize) -> Result<(WitnessFn, String, String), BenchError> {
+    let witness_fn = match input_size {
+        4 => WitnessFn::WitnessCalc(merkle_update_4_witness),
+        16 => WitnessFn::WitnessCalc(merkle_update_16_witness),
+        64 => WitnessFn::WitnessCalc(merkle_update_64_witness),
+        _ => {
+            return Err(BenchError::setup(format!(
+                "Unsupported merkle update input size: {input_size}"
+            )));
+        }
+    };
+
+    let batch = generate_merkle_update_input(input_size);
+    let updates = &batch.updates;
+    let input_str = json!({
+        "old_root": batch.old_root.to_string(),
+        "new_root": batch.new_root.to_string(),
+        "indices": decimal_strings(updates.iter().map(|update| update.index)),
+        "old_leaves": decimal_strings(updates.iter().map(|update| update.old_leaf)),
+        "new_leaves": decimal_strings(updates.iter().map(|update| update.new_leaf)),
+        "siblings": updates
+            .iter()
+            .map(|update| decimal_strings(&update.siblings))
+            .collect::<Vec<_>>(),
+    })
+    .to_string();
+
+    let current_dir = std::env::current_dir()?;
+    let zkey_path = format!(
+        "{}/circuits/merkle_update/merkle_update_{input_size}/merkle_update_{input_size}_0001.zkey",
+        current_dir.display()
+    );
+
+    Ok((witness_fn, input_str, zkey_path))
+}
+
+fn decimal_strings<T: ToString>(elements: impl IntoIterator<Item = T>) -> Vec<String> {
+    elements
+        .into_iter()
+        .map(|element| element.to_string())
+        .collect()
+}
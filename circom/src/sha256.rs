@@ -1,6 +1,7 @@
 use circom_prover::witness::WitnessFn;
 use std::collections::HashMap;
 use utils::generate_sha256_input;
+use utils::harness::BenchError;
 
 pub use crate::{prove, verify};
 
@@ -11,14 +12,18 @@ witnesscalc_adapter::witness!(sha256_512);
 witnesscalc_adapter::witness!(sha256_1024);
 witnesscalc_adapter::witness!(sha256_2048);
 
-pub fn prepare(input_size: usize) -> (WitnessFn, String, String) {
+pub fn prepare(input_size: usize) -> Result<(WitnessFn, String, String), BenchError> {
     let witness_fn = match input_size {
         128 => WitnessFn::WitnessCalc(sha256_128_witness),
         256 => WitnessFn::WitnessCalc(sha256_256_witness),
         512 => WitnessFn::WitnessCalc(sha256_512_witness),
         1024 => WitnessFn::WitnessCalc(sha256_1024_witness),
         2048 => WitnessFn::WitnessCalc(sha256_2048_witness),
-        _ => unreachable!("Unsupported sha256 input size: {}", input_size),
+        _ => {
+            return Err(BenchError::setup(format!(
+                "Unsupported sha256 input size: {input_size}"
+            )));
+        }
     };
 
     // Prepare inputs
@@ -39,14 +44,14 @@ pub fn prepare(input_size: usize) -> (WitnessFn, String, String) {
                 .collect::<Vec<String>>(),
         ),
     ]);
-    let input_str = serde_json::to_string(&inputs).unwrap();
+    let input_str = serde_json::to_string(&inputs).map_err(BenchError::setup)?;
 
     // Prepare zkey path
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
+    let current_dir = std::env::current_dir()?;
     let zkey_path = format!(
         "{}/circuits/sha256/sha256_{input_size}/sha256_{input_size}_0001.zkey",
-        current_dir.as_path().to_str().unwrap()
+        current_dir.display()
     );
 
-    (witness_fn, input_str, zkey_path)
+    Ok((witness_fn, input_str, zkey_path))
 }
@@ -0,0 +1,5 @@
+use gnark::{Backend, GnarkSuite};
+
+utils::define_benchmark_suite!(GnarkSuite {
+    backend: Backend::Plonk,
+});
@@ -0,0 +1,89 @@
+package main
+
+import (
+	"fmt"
+	"io"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark/backend/groth16"
+	"github.com/consensys/gnark/backend/plonk"
+	"github.com/consensys/gnark/backend/witness"
+	"github.com/consensys/gnark/constraint"
+	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/frontend/cs/r1cs"
+	"github.com/consensys/gnark/frontend/cs/scs"
+	"github.com/consensys/gnark/test/unsafekzg"
+)
+
+// object is a key or a proof, written to and read from the work directory.
+type object interface {
+	io.WriterTo
+	io.ReaderFrom
+}
+
+// backend is a gnark proving scheme over BN254.
+type backend struct {
+	builder  frontend.NewBuilder
+	newCS    func() constraint.ConstraintSystem
+	setup    func(ccs constraint.ConstraintSystem) (pk, vk object, err error)
+	newPK    func() object
+	newVK    func() object
+	newProof func() object
+	prove    func(ccs constraint.ConstraintSystem, pk object, full witness.Witness) (object, error)
+	verify   func(proof, vk object, public witness.Witness) error
+}
+
+var groth16Backend = backend{
+	builder: r1cs.NewBuilder,
+	newCS:   func() constraint.ConstraintSystem { return groth16.NewCS(ecc.BN254) },
+	setup: func(ccs constraint.ConstraintSystem) (object, object, error) {
+		pk, vk, err := groth16.Setup(ccs)
+		return pk, vk, err
+	},
+	newPK:    func() object { return groth16.NewProvingKey(ecc.BN254) },
+	newVK:    func() object { return groth16.NewVerifyingKey(ecc.BN254) },
+	newProof: func() object { return groth16.NewProof(ecc.BN254) },
+	prove: func(ccs constraint.ConstraintSystem, pk object, full witness.Witness) (object, error) {
+		proof, err := groth16.Prove(ccs, pk.(groth16.ProvingKey), full)
+		return proof, err
+	},
+	verify: func(proof, vk object, public witness.Witness) error {
+		return groth16.Verify(proof.(groth16.Proof), vk.(groth16.VerifyingKey), public)
+	},
+}
+
+// plonkBackend uses a KZG SRS generated locally with a known secret, which is fine for
+// benchmarking but not for production keys.
+var plonkBackend = backend{
+	builder: scs.NewBuilder,
+	newCS:   func() constraint.ConstraintSystem { return plonk.NewCS(ecc.BN254) },
+	setup: func(ccs constraint.ConstraintSystem) (object, object, error) {
+		srs, srsLagrange, err := unsafekzg.NewSRS(ccs)
+		if err != nil {
+			return nil, nil, err
+		}
+		pk, vk, err := plonk.Setup(ccs, srs, srsLagrange)
+		return pk, vk, err
+	},
+	newPK:    func() object { return plonk.NewProvingKey(ecc.BN254) },
+	newVK:    func() object { return plonk.NewVerifyingKey(ecc.BN254) },
+	newProof: func() object { return plonk.NewProof(ecc.BN254) },
+	prove: func(ccs constraint.ConstraintSystem, pk object, full witness.Witness) (object, error) {
+		proof, err := plonk.Prove(ccs, pk.(plonk.ProvingKey), full)
+		return proof, err
+	},
+	verify: func(proof, vk object, public witness.Witness) error {
+		return plonk.Verify(proof.(plonk.Proof), vk.(plonk.VerifyingKey), public)
+	},
+}
+
+func backendFor(scheme string) (backend, error) {
+	switch scheme {
+	case "groth16":
+		return groth16Backend, nil
+	case "plonk":
+		return plonkBackend, nil
+	default:
+		return backend{}, fmt.Errorf("unknown scheme %q", scheme)
+	}
+}
@@ -0,0 +1,261 @@
+// Command gnark-bench proves SHA-256 with gnark for the `gnark` benchmark crate, which runs it
+// as a subprocess. Every subcommand prints one JSON object on stdout; the durations in it are
+// measured around the gnark calls only, so that process start-up and key loading are not timed.
+//
+//	gnark-bench setup  -scheme S -dir D -input HEX -digest HEX
+//	gnark-bench prove  -scheme S -dir D -input HEX -digest HEX
+//	gnark-bench verify -scheme S -dir D -input HEX -digest HEX -proof FILE
+//	gnark-bench check  -scheme S -vk FILE -proof FILE -public FILE
+//
+// `setup` compiles the circuit for the length of the input and writes it with the keys to D,
+// `prove` proves and verifies the input with them and writes D/proof and the public witness to
+// D/public, and `verify` verifies FILE. `check` verifies exported artifacts: a proof against a
+// verifying key and a public witness, without the circuit or the input.
+package main
+
+import (
+	"bufio"
+	"encoding/hex"
+	"encoding/json"
+	"flag"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark/backend/witness"
+	"github.com/consensys/gnark/frontend"
+)
+
+const (
+	circuitFile = "circuit"
+	pkFile      = "pk"
+	vkFile      = "vk"
+	proofFile   = "proof"
+	publicFile  = "public"
+)
+
+type setupReport struct {
+	Constraints int `json:"constraints"`
+	// Public, secret and internal variables of the witness.
+	WitnessLen int `json:"witness_len"`
+	// Size of the proving and verifying keys.
+	PreprocessingSize int64 `json:"preprocessing_size"`
+}
+
+type proveReport struct {
+	ProveNs   int64 `json:"prove_ns"`
+	VerifyNs  int64 `json:"verify_ns"`
+	ProofSize int64 `json:"proof_size"`
+}
+
+type verifyReport struct {
+	VerifyNs int64 `json:"verify_ns"`
+}
+
+func main() {
+	if len(os.Args) < 2 {
+		fail(fmt.Errorf("usage: %s setup|prove|verify [flags]", os.Args[0]))
+	}
+	command := os.Args[1]
+	flags := flag.NewFlagSet(command, flag.ExitOnError)
+	scheme := flags.String("scheme", "groth16", "proving scheme: groth16 or plonk")
+	dir := flags.String("dir", ".", "directory of the circuit, the keys and the proof")
+	input := flags.String("input", "", "message, hex encoded")
+	digest := flags.String("digest", "", "SHA-256 digest of the message, hex encoded")
+	proof := flags.String("proof", "", "proof to verify (verify and check only)")
+	vkPath := flags.String("vk", "", "verifying key (check only)")
+	publicPath := flags.String("public", "", "public witness (check only)")
+	_ = flags.Parse(os.Args[2:])
+
+	b, err := backendFor(*scheme)
+	if err != nil {
+		fail(err)
+	}
+	msg, err := hex.DecodeString(*input)
+	if err != nil {
+		fail(fmt.Errorf("input: %w", err))
+	}
+	expected, err := hex.DecodeString(*digest)
+	if err != nil {
+		fail(fmt.Errorf("digest: %w", err))
+	}
+
+	var report any
+	switch command {
+	case "setup":
+		report, err = setup(b, *dir, len(msg))
+	case "prove":
+		report, err = prove(b, *dir, sha256Assignment(msg, expected))
+	case "verify":
+		report, err = verify(b, *dir, sha256Assignment(msg, expected), *proof)
+	case "check":
+		report, err = check(b, *vkPath, *proof, *publicPath)
+	default:
+		err = fmt.Errorf("unknown command %q", command)
+	}
+	if err != nil {
+		fail(err)
+	}
+	if err := json.NewEncoder(os.Stdout).Encode(report); err != nil {
+		fail(err)
+	}
+}
+
+func fail(err error) {
+	fmt.Fprintln(os.Stderr, err)
+	os.Exit(1)
+}
+
+func setup(b backend, dir string, n int) (setupReport, error) {
+	ccs, err := frontend.Compile(ecc.BN254.ScalarField(), b.builder, newSha256Circuit(n))
+	if err != nil {
+		return setupReport{}, fmt.Errorf("compile: %w", err)
+	}
+	pk, vk, err := b.setup(ccs)
+	if err != nil {
+		return setupReport{}, fmt.Errorf("setup: %w", err)
+	}
+	if err := os.MkdirAll(dir, 0o755); err != nil {
+		return setupReport{}, err
+	}
+	if _, err := write(filepath.Join(dir, circuitFile), ccs); err != nil {
+		return setupReport{}, err
+	}
+	pkSize, err := write(filepath.Join(dir, pkFile), pk)
+	if err != nil {
+		return setupReport{}, err
+	}
+	vkSize, err := write(filepath.Join(dir, vkFile), vk)
+	if err != nil {
+		return setupReport{}, err
+	}
+	return setupReport{
+		Constraints:       ccs.GetNbConstraints(),
+		WitnessLen:        ccs.GetNbPublicVariables() + ccs.GetNbSecretVariables() + ccs.GetNbInternalVariables(),
+		PreprocessingSize: pkSize + vkSize,
+	}, nil
+}
+
+func prove(b backend, dir string, assignment frontend.Circuit) (proveReport, error) {
+	ccs := b.newCS()
+	pk, vk := b.newPK(), b.newVK()
+	for file, obj := range map[string]io.ReaderFrom{circuitFile: ccs, pkFile: pk, vkFile: vk} {
+		if err := read(filepath.Join(dir, file), obj); err != nil {
+			return proveReport{}, err
+		}
+	}
+	full, public, err := witnesses(assignment)
+	if err != nil {
+		return proveReport{}, err
+	}
+
+	start := time.Now()
+	proof, err := b.prove(ccs, pk, full)
+	proveDuration := time.Since(start)
+	if err != nil {
+		return proveReport{}, fmt.Errorf("prove: %w", err)
+	}
+	start = time.Now()
+	err = b.verify(proof, vk, public)
+	verifyDuration := time.Since(start)
+	if err != nil {
+		return proveReport{}, fmt.Errorf("verify: %w", err)
+	}
+
+	proofSize, err := write(filepath.Join(dir, proofFile), proof)
+	if err != nil {
+		return proveReport{}, err
+	}
+	if _, err := write(filepath.Join(dir, publicFile), public); err != nil {
+		return proveReport{}, err
+	}
+	return proveReport{
+		ProveNs:   proveDuration.Nanoseconds(),
+		VerifyNs:  verifyDuration.Nanoseconds(),
+		ProofSize: proofSize,
+	}, nil
+}
+
+func verify(b backend, dir string, assignment frontend.Circuit, proofPath string) (verifyReport, error) {
+	vk, proof := b.newVK(), b.newProof()
+	if err := read(filepath.Join(dir, vkFile), vk); err != nil {
+		return verifyReport{}, err
+	}
+	if err := read(proofPath, proof); err != nil {
+		return verifyReport{}, err
+	}
+	_, public, err := witnesses(assignment)
+	if err != nil {
+		return verifyReport{}, err
+	}
+
+	start := time.Now()
+	err = b.verify(proof, vk, public)
+	verifyDuration := time.Since(start)
+	if err != nil {
+		return verifyReport{}, fmt.Errorf("verify: %w", err)
+	}
+	return verifyReport{VerifyNs: verifyDuration.Nanoseconds()}, nil
+}
+
+func check(b backend, vkPath, proofPath, publicPath string) (verifyReport, error) {
+	vk, proof := b.newVK(), b.newProof()
+	public, err := witness.New(ecc.BN254.ScalarField())
+	if err != nil {
+		return verifyReport{}, err
+	}
+	for path, obj := range map[string]io.ReaderFrom{vkPath: vk, proofPath: proof, publicPath: public} {
+		if err := read(path, obj); err != nil {
+			return verifyReport{}, err
+		}
+	}
+
+	start := time.Now()
+	err = b.verify(proof, vk, public)
+	verifyDuration := time.Since(start)
+	if err != nil {
+		return verifyReport{}, fmt.Errorf("verify: %w", err)
+	}
+	return verifyReport{VerifyNs: verifyDuration.Nanoseconds()}, nil
+}
+
+func witnesses(assignment frontend.Circuit) (full, public witness.Witness, err error) {
+	full, err = frontend.NewWitness(assignment, ecc.BN254.ScalarField())
+	if err != nil {
+		return nil, nil, fmt.Errorf("witness: %w", err)
+	}
+	public, err = full.Public()
+	if err != nil {
+		return nil, nil, fmt.Errorf("public witness: %w", err)
+	}
+	return full, public, nil
+}
+
+func write(path string, obj io.WriterTo) (int64, error) {
+	f, err := os.Create(path)
+	if err != nil {
+		return 0, err
+	}
+	defer f.Close()
+	w := bufio.NewWriter(f)
+	n, err := obj.WriteTo(w)
+	if err != nil {
+		return 0, fmt.Errorf("%s: %w", path, err)
+	}
+	return n, w.Flush()
+}
+
+func read(path string, obj io.ReaderFrom) error {
+	f, err := os.Open(path)
+	if err != nil {
+		return err
+	}
+	defer f.Close()
+	if _, err := obj.ReadFrom(bufio.NewReader(f)); err != nil {
+		return fmt.Errorf("%s: %w", path, err)
+	}
+	return nil
+}
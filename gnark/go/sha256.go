@@ -0,0 +1,41 @@
+package main
+
+import (
+	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/std/hash/sha2"
+	"github.com/consensys/gnark/std/math/uints"
+)
+
+// sha256Circuit proves that the private message hashes to the public digest.
+type sha256Circuit struct {
+	In     []uints.U8
+	Digest [32]uints.U8 `gnark:",public"`
+}
+
+func (c *sha256Circuit) Define(api frontend.API) error {
+	hasher, err := sha2.New(api)
+	if err != nil {
+		return err
+	}
+	bytes, err := uints.New[uints.U32](api)
+	if err != nil {
+		return err
+	}
+	hasher.Write(c.In)
+	for i, b := range hasher.Sum() {
+		bytes.ByteAssertEq(c.Digest[i], b)
+	}
+	return nil
+}
+
+// newSha256Circuit is the circuit shape for messages of n bytes.
+func newSha256Circuit(n int) *sha256Circuit {
+	return &sha256Circuit{In: make([]uints.U8, n)}
+}
+
+// sha256Assignment assigns msg and its digest.
+func sha256Assignment(msg, digest []byte) *sha256Circuit {
+	assignment := &sha256Circuit{In: uints.NewU8Array(msg)}
+	copy(assignment.Digest[:], uints.NewU8Array(digest))
+	return assignment
+}
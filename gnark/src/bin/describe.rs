@@ -0,0 +1,7 @@
+use gnark::BENCH_FEATURES;
+use utils::features::print_description;
+use utils::harness::ProvingSystem;
+
+fn main() {
+    print_description(ProvingSystem::Gnark, BENCH_FEATURES);
+}
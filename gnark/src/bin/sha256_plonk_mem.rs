@@ -0,0 +1,5 @@
+use gnark::{Backend, prepare_sha256, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(|size| prepare_sha256(Backend::Plonk, size), prove, verify);
+}
@@ -0,0 +1,434 @@
+//! SHA-256 proved with gnark (Go) over BN254, with Groth16 or Plonk, through a subprocess bridge.
+//!
+//! The Go program in `go/` is built once per process with `go build` and driven by subcommands:
+//! `setup` compiles the circuit for an input size and writes it with the keys to a work
+//! directory (skipped when the directory is newer than the Go sources), `prove` proves and
+//! verifies the input, `verify` checks a proof and `check` verifies exported artifacts, a proof
+//! with a verifying key and a public witness. Each prints a JSON report; the prove and
+//! verify times in it are measured around the gnark calls only, and replace the wall-clock time
+//! of the subprocess in the metrics (see `BenchmarkSuite::reported_durations`).
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Output;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
+use utils::artifacts::ExportedArtifacts;
+use utils::features::{BenchFeature, FeatureParameter};
+use utils::harness::{
+    AuditStatus, BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite,
+    ProvingSystem, ReportedDurations,
+};
+use utils::witness_size::WitnessSize;
+
+/// Setup report, kept in the work directory to reuse the keys.
+const SETUP_FILE: &str = "setup.json";
+/// Proof `prove` writes to the work directory.
+const PROOF_FILE: &str = "proof";
+/// Proof handed to `verify`, so that it checks the proof it is given.
+const VERIFY_PROOF_FILE: &str = "proof_to_verify";
+/// Verifying key `setup` writes to the work directory.
+const VK_FILE: &str = "vk";
+/// Public witness `prove` writes to the work directory.
+const PUBLIC_FILE: &str = "public";
+
+/// Feature tags of the gnark benches, printed by `describe_gnark`.
+pub const BENCH_FEATURES: &[BenchFeature] = &[BenchFeature {
+    tag: "plonk",
+    description: "Proves the same circuit with Plonk instead of Groth16.",
+    targets: &[BenchTarget::Sha256],
+    cargo_feature: None,
+    bench: Some("sha256_plonk"),
+    parameters: &[FeatureParameter {
+        name: "srs",
+        value: "unsafekzg",
+        description: "KZG SRS generated locally from a known secret; fine for timing, not for \
+                      production keys.",
+    }],
+}];
+
+pub const GNARK_GROTH16_PROPS: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("Groth16"),
+    field_curve: Cow::Borrowed("Bn254"),
+    iop: Cow::Borrowed("Groth16"),
+    pcs: None,
+    arithm: Cow::Borrowed("R1CS"),
+    is_zk: true,
+    is_zkvm: false,
+    security_bits: 128, // Bn254 curve
+    is_pq: false,
+    is_maintained: true,              // https://github.com/Consensys/gnark
+    is_audited: AuditStatus::Audited, // https://github.com/Consensys/gnark/tree/master/audits
+    isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("v0.11.0")),      // go/go.mod
+    onchain_verifier: Some(Cow::Borrowed("evm")), // gnark exports Solidity verifiers
+    caveats: Cow::Borrowed(&[]),
+};
+
+/// Plonk with KZG over the same curve; the SRS is generated locally (`unsafekzg`).
+pub const GNARK_PLONK_PROPS: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("Plonk"),
+    iop: Cow::Borrowed("Plonk"),
+    pcs: Some(Cow::Borrowed("KZG")),
+    arithm: Cow::Borrowed("Plonkish"),
+    ..GNARK_GROTH16_PROPS
+};
+
+/// Proving scheme of the Go program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Groth16,
+    Plonk,
+}
+
+impl Backend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Backend::Groth16 => "groth16",
+            Backend::Plonk => "plonk",
+        }
+    }
+}
+
+/// Result of `setup`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct SetupReport {
+    pub constraints: usize,
+    /// Public, secret and internal variables of the witness.
+    pub witness_len: usize,
+    /// Size of the proving and verifying keys.
+    pub preprocessing_size: usize,
+}
+
+/// Result of `prove`, in nanoseconds and bytes.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ProveReport {
+    pub prove_ns: u64,
+    pub verify_ns: u64,
+    pub proof_size: usize,
+}
+
+/// The input of one size, hex encoded as the Go program takes it, and its work directory.
+pub struct PreparedGnark {
+    pub backend: Backend,
+    pub dir: PathBuf,
+    pub input: String,
+    pub digest: String,
+    pub setup: SetupReport,
+}
+
+pub struct GnarkProof {
+    pub proof: Vec<u8>,
+    pub report: ProveReport,
+}
+
+fn go_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("go")
+}
+
+fn target_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/gnark")
+}
+
+fn check_status(tool: &str, output: Output) -> Result<String, BenchError> {
+    if !output.status.success() {
+        return Err(BenchError::tool(
+            tool,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Builds the Go program into `target/gnark`, once per process. `-mod=readonly` builds against
+/// the checksums of `go.sum` and fails rather than resolving other versions of the dependencies.
+fn binary() -> Result<PathBuf, BenchError> {
+    static BINARY: OnceLock<Result<PathBuf, BenchError>> = OnceLock::new();
+    BINARY
+        .get_or_init(|| {
+            if !go_dir().join("go.sum").is_file() {
+                return Err(BenchError::tool(
+                    "go",
+                    "gnark/go/go.sum is missing: run `go mod tidy` in gnark/go and commit it",
+                ));
+            }
+            let binary = target_dir().join("gnark-bench");
+            let output = utils::sandbox::command("go")
+                .args(["build", "-mod=readonly", "-o"])
+                .arg(&binary)
+                .arg(".")
+                .current_dir(go_dir())
+                .output()
+                .map_err(|e| BenchError::tool("go", e))?;
+            check_status("go", output)?;
+            Ok(binary)
+        })
+        .clone()
+}
+
+/// Runs a subcommand of the Go program on the input of `prepared`, returning its stdout.
+fn run(
+    prepared: &PreparedGnark,
+    command: &str,
+    proof: Option<&Path>,
+) -> Result<String, BenchError> {
+    let mut cmd = utils::sandbox::command(binary()?);
+    cmd.arg(command)
+        .arg("-scheme")
+        .arg(prepared.backend.as_str())
+        .arg("-dir")
+        .arg(&prepared.dir)
+        .arg("-input")
+        .arg(&prepared.input)
+        .arg("-digest")
+        .arg(&prepared.digest);
+    if let Some(proof) = proof {
+        cmd.arg("-proof").arg(proof);
+    }
+    let output = cmd
+        .output()
+        .map_err(|e| BenchError::tool("gnark-bench", e))?;
+    check_status("gnark-bench", output)
+}
+
+fn parse<T: serde::de::DeserializeOwned>(stdout: &str) -> Result<T, BenchError> {
+    serde_json::from_str(stdout.trim())
+        .map_err(|e| BenchError::tool("gnark-bench", format!("unexpected output {stdout:?}: {e}")))
+}
+
+/// Latest modification time of the Go sources, to tell whether saved keys are stale.
+fn sources_modified() -> Option<SystemTime> {
+    fs::read_dir(go_dir())
+        .ok()?
+        .filter_map(|entry| entry.ok()?.metadata().ok()?.modified().ok())
+        .max()
+}
+
+/// Reads the setup report saved in `dir`, if it is newer than the Go sources.
+fn saved_setup(dir: &Path) -> Option<SetupReport> {
+    let path = dir.join(SETUP_FILE);
+    if fs::metadata(&path).ok()?.modified().ok()? < sources_modified()? {
+        return None;
+    }
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Compiles the SHA-256 circuit for `input_size` bytes and runs the setup of `backend`, or
+/// reuses the keys of an earlier run.
+pub fn prepare_sha256(backend: Backend, input_size: usize) -> Result<PreparedGnark, BenchError> {
+    let (message, digest) = utils::generate_sha256_input(input_size);
+    let dir = target_dir().join(format!(
+        "{}_{}_{input_size}",
+        backend.as_str(),
+        BenchTarget::Sha256.as_str()
+    ));
+    fs::create_dir_all(&dir)?;
+    let mut prepared = PreparedGnark {
+        backend,
+        dir: dir.canonicalize()?,
+        input: hex::encode(&message),
+        digest: hex::encode(digest),
+        setup: SetupReport::default(),
+    };
+    prepared.setup = match saved_setup(&prepared.dir) {
+        Some(setup) => setup,
+        None => {
+            let stdout = run(&prepared, "setup", None).map_err(BenchError::setup)?;
+            let setup = parse(&stdout)?;
+            fs::write(prepared.dir.join(SETUP_FILE), stdout)?;
+            setup
+        }
+    };
+    Ok(prepared)
+}
+
+pub fn prove(prepared: &PreparedGnark) -> Result<GnarkProof, BenchError> {
+    let stdout = run(prepared, "prove", None).map_err(BenchError::prove)?;
+    Ok(GnarkProof {
+        report: parse(&stdout)?,
+        proof: fs::read(prepared.dir.join(PROOF_FILE))?,
+    })
+}
+
+pub fn verify(prepared: &PreparedGnark, proof: &GnarkProof) -> Result<(), BenchError> {
+    let path = prepared.dir.join(VERIFY_PROOF_FILE);
+    fs::write(&path, &proof.proof)?;
+    run(prepared, "verify", Some(&path))
+        .map(drop)
+        .map_err(BenchError::verify)
+}
+
+/// Exported verification key: the gnark verifying key, hex encoded, and the scheme it is for,
+/// which its encoding does not tell.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct ExportedKey {
+    scheme: String,
+    key: String,
+}
+
+/// The proof, the verifying key with its scheme as JSON, and the public witness (the digest),
+/// in gnark's binary encodings.
+pub fn export_artifacts(
+    prepared: &PreparedGnark,
+    proof: &GnarkProof,
+) -> Result<ExportedArtifacts, BenchError> {
+    let key = ExportedKey {
+        scheme: prepared.backend.as_str().to_string(),
+        key: hex::encode(fs::read(prepared.dir.join(VK_FILE))?),
+    };
+    Ok(ExportedArtifacts {
+        proof: proof.proof.clone(),
+        verification_key: serde_json::to_vec(&key).map_err(BenchError::setup)?,
+        public_inputs: fs::read(prepared.dir.join(PUBLIC_FILE))?,
+        encoding: "gnark-binary+json-key",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`] by running `gnark-bench check` on them,
+/// written to a directory of this process under `target/gnark/artifacts`.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let key: ExportedKey = serde_json::from_slice(verification_key)?;
+    let dir = target_dir()
+        .join("artifacts")
+        .join(std::process::id().to_string());
+    fs::create_dir_all(&dir)?;
+    let (vk, proof_path, public) = (
+        dir.join(VK_FILE),
+        dir.join(PROOF_FILE),
+        dir.join(PUBLIC_FILE),
+    );
+    fs::write(&vk, hex::decode(&key.key)?)?;
+    fs::write(&proof_path, proof)?;
+    fs::write(&public, public_inputs)?;
+    let output = utils::sandbox::command(binary()?)
+        .arg("check")
+        .arg("-scheme")
+        .arg(&key.scheme)
+        .arg("-vk")
+        .arg(&vk)
+        .arg("-proof")
+        .arg(&proof_path)
+        .arg("-public")
+        .arg(&public)
+        .output()
+        .map_err(|e| BenchError::tool("gnark-bench", e));
+    fs::remove_dir_all(&dir)?;
+    check_status("gnark-bench", output?)?;
+    Ok(())
+}
+
+/// SHA-256 with one gnark backend; Groth16 is the untagged bench, next to circom's Groth16.
+pub struct GnarkSuite {
+    pub backend: Backend,
+}
+
+impl BenchmarkSuite for GnarkSuite {
+    type Prepared = PreparedGnark;
+    type Proof = GnarkProof;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        match self.backend {
+            Backend::Groth16 => BenchHarnessConfig {
+                target: BenchTarget::Sha256,
+                system: ProvingSystem::Gnark,
+                feature: None,
+                mem_binary_name: "sha256_mem_gnark",
+            },
+            Backend::Plonk => BenchHarnessConfig {
+                target: BenchTarget::Sha256,
+                system: ProvingSystem::Gnark,
+                feature: Some("plonk"),
+                mem_binary_name: "sha256_plonk_mem_gnark",
+            },
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        match self.backend {
+            Backend::Groth16 => GNARK_GROTH16_PROPS,
+            Backend::Plonk => GNARK_PLONK_PROPS,
+        }
+    }
+
+    fn prepare(&self, size: usize) -> Result<PreparedGnark, BenchError> {
+        prepare_sha256(self.backend, size)
+    }
+
+    fn prove(&self, prepared: &PreparedGnark) -> Result<GnarkProof, BenchError> {
+        prove(prepared)
+    }
+
+    fn verify(&self, prepared: &PreparedGnark, proof: &GnarkProof) -> Result<(), BenchError> {
+        verify(prepared, proof)
+    }
+
+    fn num_constraints(&self, prepared: &PreparedGnark) -> usize {
+        prepared.setup.constraints
+    }
+
+    fn witness_size(&self, prepared: &PreparedGnark) -> Option<WitnessSize> {
+        Some(WitnessSize::new(prepared.setup.witness_len as u64, 32))
+    }
+
+    fn preprocessing_size(&self, prepared: &PreparedGnark) -> Result<usize, BenchError> {
+        Ok(prepared.setup.preprocessing_size)
+    }
+
+    fn proof_size(&self, proof: &GnarkProof) -> usize {
+        proof.report.proof_size
+    }
+
+    fn export_artifacts(
+        &self,
+        prepared: &PreparedGnark,
+        proof: &GnarkProof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        export_artifacts(prepared, proof).map(Some)
+    }
+
+    /// Times measured by the Go program, without its start-up and the loading of the keys.
+    fn reported_durations(&self, proof: &GnarkProof) -> Option<ReportedDurations> {
+        Some(ReportedDurations {
+            prove: Duration::from_nanos(proof.report.prove_ns),
+            verify: Duration::from_nanos(proof.report.verify_ns),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_go_reports() {
+        let prove: ProveReport =
+            parse("{\"prove_ns\":1500000,\"verify_ns\":900000,\"proof_size\":164}\n").unwrap();
+        let suite = GnarkSuite {
+            backend: Backend::Groth16,
+        };
+        let proof = GnarkProof {
+            proof: Vec::new(),
+            report: prove,
+        };
+        assert_eq!(
+            suite.reported_durations(&proof),
+            Some(ReportedDurations {
+                prove: Duration::from_micros(1500),
+                verify: Duration::from_micros(900),
+            })
+        );
+        assert_eq!(suite.proof_size(&proof), 164);
+        let setup: SetupReport =
+            parse("{\"constraints\":30000,\"witness_len\":31000,\"preprocessing_size\":4096}")
+                .unwrap();
+        assert_eq!(setup.constraints, 30000);
+        assert!(parse::<SetupReport>("compile: error").is_err());
+    }
+}
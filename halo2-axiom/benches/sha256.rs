@@ -0,0 +1,20 @@
+use halo2_axiom_bench::{
+    HALO2_AXIOM_BENCH_PROPERTIES, export_artifacts, num_constraints, prepare_sha256,
+    preprocessing_size, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::Halo2Axiom,
+    None,
+    "sha256_mem_halo2_axiom",
+    HALO2_AXIOM_BENCH_PROPERTIES,
+    prepare_sha256,
+    num_constraints,
+    prove,
+    |prepared, proof| verify(prepared, proof),
+    preprocessing_size,
+    |proof| proof.len(),
+    export_artifacts = |prepared: &_, proof: &Vec<u8>| export_artifacts(prepared, proof)
+);
@@ -0,0 +1,7 @@
+use halo2_axiom_bench::{prepare_sha256, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_sha256, prove, |prepared, proof| {
+        verify(prepared, proof)
+    });
+}
@@ -0,0 +1,295 @@
+//! SHA-256 over BN254 with Axiom's fork of the PSE halo2 and KZG commitments (SHPLONK
+//! multi-opening).
+//!
+//! The circuit is the bit-decomposed SHA-256 circuit of halo2-lib's `zkevm-hashes`, which lays
+//! out every 64-byte block over [`SHA256_NUM_ROWS`] rows of a single region. The digest is the
+//! public input, as two 128-bit limbs.
+
+use halo2_base::halo2_proofs::{
+    SerdeFormat,
+    circuit::{Layouter, SimpleFloorPlanner},
+    halo2curves::{
+        bn256::{Bn256, Fr, G1Affine},
+        ff::PrimeField,
+    },
+    plonk::{
+        Circuit, Column, ConstraintSystem, Error, Instance, ProvingKey, VerifyingKey, create_proof,
+        keygen_pk, keygen_vk, verify_proof,
+    },
+    poly::{
+        commitment::{Params, ParamsProver},
+        kzg::{
+            commitment::{KZGCommitmentScheme, ParamsKZG},
+            multiopen::{ProverSHPLONK, VerifierSHPLONK},
+            strategy::SingleStrategy,
+        },
+    },
+    transcript::{
+        Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
+    },
+};
+use rand::rngs::OsRng;
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::witness_size::{ConstraintCount, WitnessSize};
+use zkevm_hashes::sha256::vanilla::{
+    columns::Sha256CircuitConfig, param::SHA256_NUM_ROWS, util::get_num_sha2_blocks,
+};
+
+/// Rows kept free at the end of the region for the blinding factors and the rotations of the
+/// last block.
+const RESERVED_ROWS: usize = 64;
+
+pub const HALO2_AXIOM_BENCH_PROPERTIES: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("Halo2-Axiom"),
+    field_curve: Cow::Borrowed("Bn254"),
+    iop: Cow::Borrowed("PLONK"),
+    pcs: Some(Cow::Borrowed("KZG")),
+    arithm: Cow::Borrowed("Plonkish"),
+    is_zk: true, // blinded advice columns
+    is_zkvm: false,
+    security_bits: 128, // Bn254 curve
+    is_pq: false,
+    is_maintained: true, // https://github.com/axiom-crypto/halo2
+    is_audited: AuditStatus::NotAudited,
+    isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("v0.4")),
+    onchain_verifier: Some(Cow::Borrowed("evm")), // Solidity verifiers generated with snark-verifier
+    caveats: Cow::Borrowed(&[]),
+};
+
+/// Hashes `message`, a private witness, and constrains the two limbs of its digest to the
+/// public inputs.
+#[derive(Clone)]
+pub struct Sha256Circuit {
+    /// `None` in the circuit the keys are generated from.
+    message: Option<Vec<u8>>,
+    /// Blocks of the padded message, which fix the layout.
+    n_blocks: usize,
+}
+
+#[derive(Clone)]
+pub struct Sha256Config {
+    sha256: Sha256CircuitConfig<Fr>,
+    /// The high then the low 128 bits of the digest.
+    digest: Column<Instance>,
+}
+
+impl Circuit<Fr> for Sha256Circuit {
+    type Config = Sha256Config;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            message: None,
+            n_blocks: self.n_blocks,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let sha256 = Sha256CircuitConfig::new(meta);
+        let digest = meta.instance_column();
+        meta.enable_equality(digest);
+        Sha256Config { sha256, digest }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        let [hi, lo] = layouter.assign_region(
+            || "sha256",
+            |mut region| {
+                // Without the message, hashes of the empty message fill the same blocks, so the
+                // layout and the digest cells are the same.
+                let blocks = config.sha256.multi_sha256(
+                    &mut region,
+                    self.message.iter().cloned().collect(),
+                    Some(self.n_blocks),
+                );
+                let output = blocks.last().ok_or(Error::Synthesis)?.output();
+                Ok([output.hi().cell(), output.lo().cell()])
+            },
+        )?;
+        layouter.constrain_instance(hi, config.digest, 0);
+        layouter.constrain_instance(lo, config.digest, 1);
+        Ok(())
+    }
+}
+
+pub struct PreparedSha256 {
+    params: ParamsKZG<Bn256>,
+    pk: ProvingKey<G1Affine>,
+    circuit: Sha256Circuit,
+    /// The expected digest as the public inputs of the circuit.
+    digest: [Fr; 2],
+    k: u32,
+}
+
+/// The high and low 128 bits of `digest`, as [`Sha256Circuit`] exposes them.
+fn digest_limbs(digest: &[u8]) -> [Fr; 2] {
+    let (hi, lo) = digest.split_at(16);
+    [hi, lo].map(|limb| Fr::from_u128(u128::from_be_bytes(limb.try_into().unwrap())))
+}
+
+/// Generates the KZG parameters for the smallest domain that fits the message, and the keys of
+/// the circuit without the message.
+pub fn prepare_sha256(input_size: usize) -> Result<PreparedSha256, BenchError> {
+    let (message, digest) = utils::generate_sha256_input(input_size);
+    let n_blocks = get_num_sha2_blocks(message.len());
+    let k = (n_blocks * SHA256_NUM_ROWS + RESERVED_ROWS)
+        .next_power_of_two()
+        .ilog2();
+    let params = ParamsKZG::<Bn256>::setup(k, OsRng);
+    let circuit = Sha256Circuit {
+        message: Some(message),
+        n_blocks,
+    };
+    let vk = keygen_vk(&params, &circuit.without_witnesses())
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+    let pk = keygen_pk(&params, vk, &circuit.without_witnesses())
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+    Ok(PreparedSha256 {
+        params,
+        pk,
+        circuit,
+        digest: digest_limbs(&digest),
+        k,
+    })
+}
+
+pub fn prove(prepared: &PreparedSha256) -> Result<Vec<u8>, BenchError> {
+    let mut transcript = Blake2bWrite::<_, G1Affine, Challenge255<_>>::init(vec![]);
+    create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+        &prepared.params,
+        &prepared.pk,
+        std::slice::from_ref(&prepared.circuit),
+        &[&[&prepared.digest[..]]],
+        OsRng,
+        &mut transcript,
+    )
+    .map_err(|e| BenchError::prove(format!("{e:?}")))?;
+    Ok(transcript.finalize())
+}
+
+pub fn verify(prepared: &PreparedSha256, proof: &[u8]) -> Result<(), BenchError> {
+    let mut transcript = Blake2bRead::<_, G1Affine, Challenge255<_>>::init(proof);
+    verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+        prepared.params.verifier_params(),
+        prepared.pk.get_vk(),
+        SingleStrategy::new(&prepared.params),
+        &[&[&prepared.digest[..]]],
+        &mut transcript,
+    )
+    .map(|_| ())
+    .map_err(|e| BenchError::verify(format!("{e:?}")))
+}
+
+/// Advice rows used by the message blocks, with the advice cells of the whole domain as the
+/// witness.
+pub fn num_constraints(prepared: &PreparedSha256) -> ConstraintCount {
+    let advice_columns = prepared.pk.get_vk().cs().num_advice_columns();
+    ConstraintCount::with_witness(
+        prepared.circuit.n_blocks * SHA256_NUM_ROWS,
+        WitnessSize::new((advice_columns as u64) << prepared.k, 32),
+    )
+}
+
+/// Size of the serialized proving key.
+pub fn preprocessing_size(prepared: &PreparedSha256) -> usize {
+    prepared.pk.to_bytes(SerdeFormat::RawBytes).len()
+}
+
+/// The proof transcript, the KZG parameters followed by the verifying key, and the two limbs of
+/// the digest, each as a 32-byte field element.
+pub fn export_artifacts(
+    prepared: &PreparedSha256,
+    proof: &[u8],
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut verification_key = Vec::new();
+    prepared.params.write(&mut verification_key)?;
+    prepared
+        .pk
+        .get_vk()
+        .write(&mut verification_key, SerdeFormat::RawBytes)?;
+    Ok(ExportedArtifacts {
+        proof: proof.to_vec(),
+        verification_key,
+        public_inputs: prepared.digest.iter().flat_map(Fr::to_repr).collect(),
+        encoding: "halo2-raw-bytes",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`].
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let mut reader = verification_key;
+    let params = ParamsKZG::<Bn256>::read(&mut reader)?;
+    // The circuit has no configuration parameters.
+    let vk =
+        VerifyingKey::<G1Affine>::read::<_, Sha256Circuit>(&mut reader, SerdeFormat::RawBytes, ())?;
+    let digest = public_inputs
+        .chunks_exact(32)
+        .map(|limb| Option::from(Fr::from_repr(limb.try_into().unwrap())))
+        .collect::<Option<Vec<Fr>>>()
+        .filter(|digest| digest.len() == 2 && public_inputs.len() == 64)
+        .ok_or_else(|| anyhow::anyhow!("public inputs are not the two limbs of a digest"))?;
+    let mut transcript = Blake2bRead::<_, G1Affine, Challenge255<_>>::init(proof);
+    verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+        params.verifier_params(),
+        &vk,
+        SingleStrategy::new(&params),
+        &[&[&digest[..]]],
+        &mut transcript,
+    )
+    .map(|_| ())
+    .map_err(|e| anyhow::anyhow!("verification failed: {e:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halo2_sha256_rejects_a_wrong_digest() {
+        let mut prepared = prepare_sha256(128).unwrap();
+        let proof = prove(&prepared).unwrap();
+        verify(&prepared, &proof).unwrap();
+
+        prepared.digest[1] += Fr::one();
+        assert!(matches!(
+            verify(&prepared, &proof),
+            Err(BenchError::Verify { .. })
+        ));
+    }
+
+    #[test]
+    fn halo2_exported_artifacts_verify() {
+        let mut prepared = prepare_sha256(128).unwrap();
+        let proof = prove(&prepared).unwrap();
+        let artifacts = export_artifacts(&prepared, &proof).unwrap();
+        verify_artifacts(
+            &artifacts.proof,
+            &artifacts.verification_key,
+            &artifacts.public_inputs,
+        )
+        .unwrap();
+
+        prepared.digest[0] += Fr::one();
+        let tampered = export_artifacts(&prepared, &proof).unwrap();
+        assert!(
+            verify_artifacts(
+                &artifacts.proof,
+                &artifacts.verification_key,
+                &tampered.public_inputs,
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use hypernova::{
+    HYPERNOVA_BENCH_PROPERTIES, export_artifacts, num_constraints, prepare_poseidon,
+    preprocessing_size, proof_size, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Poseidon,
+    ProvingSystem::HyperNova,
+    None,
+    "poseidon_mem_hypernova",
+    HYPERNOVA_BENCH_PROPERTIES,
+    prepare_poseidon,
+    num_constraints,
+    prove,
+    verify,
+    preprocessing_size,
+    proof_size,
+    export_artifacts = export_artifacts
+);
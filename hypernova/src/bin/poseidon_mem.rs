@@ -0,0 +1,5 @@
+use hypernova::{prepare_poseidon, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_poseidon, prove, verify);
+}
@@ -0,0 +1,293 @@
+//! Poseidon over BN254 folded with HyperNova, the CCS-based folding scheme, through Sonobe.
+//!
+//! The step circuit absorbs one input element into the state: `z_{i+1} = H(z_i, x_i)`, with
+//! `H` the 2-to-1 Poseidon CRH of arkworks, starting from `z_0 = 0`. A bench over `n` elements
+//! therefore folds `n` steps. Sonobe compiles the augmented step circuit (the step and the
+//! HyperNova verifier) to CCS and folds the committed CCS instance of every step into the
+//! running linearized one (`MU = NU = 1`), with the CycleFold circuit on Grumpkin.
+
+use ark_bn254::{Fr, G1Projective};
+use ark_crypto_primitives::crh::{
+    CRHScheme, CRHSchemeGadget,
+    poseidon::{
+        CRH,
+        constraints::{CRHGadget, CRHParametersVar},
+    },
+};
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_grumpkin::Projective as GrumpkinProjective;
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use folding_schemes::{
+    Error, FoldingScheme,
+    commitment::pedersen::Pedersen,
+    folding::hypernova::{HyperNova, IVCProof, PreprocessorParam, ProverParams, VerifierParams},
+    frontend::FCircuit,
+    transcript::poseidon::poseidon_canonical_config,
+};
+use rand::rngs::OsRng;
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::witness_size::{ConstraintCount, WitnessSize};
+
+pub const HYPERNOVA_BENCH_PROPERTIES: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("HyperNova"),
+    field_curve: Cow::Borrowed("Bn254"),
+    iop: Cow::Borrowed("HyperNova"), // folding of CCS instances
+    pcs: Some(Cow::Borrowed("Pedersen")),
+    arithm: Cow::Borrowed("CCS"),
+    is_zk: false, // folded without blinding (`H = false`)
+    is_zkvm: false,
+    security_bits: 128, // BN254/Grumpkin cycle
+    is_pq: false,
+    is_maintained: true, // https://github.com/privacy-scaling-explorations/sonobe
+    is_audited: AuditStatus::NotAudited,
+    isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("main")),
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[Caveat::Note {
+        text: Cow::Borrowed(
+            "chains 2-to-1 Poseidon over the elements, one fold per element; the proof is the uncompressed IVC proof",
+        ),
+    }]),
+};
+
+/// `z_{i+1} = H(z_i, x_i)` for the external input `x_i`.
+#[derive(Clone, Debug)]
+pub struct PoseidonStep {
+    config: PoseidonConfig<Fr>,
+}
+
+impl FCircuit<Fr> for PoseidonStep {
+    type Params = PoseidonConfig<Fr>;
+    type ExternalInputs = [Fr; 1];
+    type ExternalInputsVar = [FpVar<Fr>; 1];
+
+    fn new(config: Self::Params) -> Result<Self, Error> {
+        Ok(Self { config })
+    }
+
+    fn state_len(&self) -> usize {
+        1
+    }
+
+    fn generate_step_constraints(
+        &self,
+        cs: ConstraintSystemRef<Fr>,
+        _i: usize,
+        z_i: Vec<FpVar<Fr>>,
+        external_inputs: Self::ExternalInputsVar,
+    ) -> Result<Vec<FpVar<Fr>>, SynthesisError> {
+        let params = CRHParametersVar::<Fr>::new_constant(cs, self.config.clone())?;
+        let [x_i] = external_inputs;
+        Ok(vec![CRHGadget::<Fr>::evaluate(
+            &params,
+            &[z_i[0].clone(), x_i],
+        )?])
+    }
+}
+
+type C1 = G1Projective;
+type C2 = GrumpkinProjective;
+type CS1 = Pedersen<C1>;
+type CS2 = Pedersen<C2>;
+type HN = HyperNova<C1, C2, PoseidonStep, CS1, CS2, 1, 1, false>;
+
+pub type Proof = IVCProof<C1, C2>;
+
+pub struct PreparedPoseidon {
+    step: PoseidonStep,
+    params: (
+        ProverParams<C1, C2, CS1, CS2, false>,
+        VerifierParams<C1, C2, CS1, CS2, false>,
+    ),
+    inputs: Vec<Fr>,
+    digest: Fr,
+}
+
+/// Preprocesses the augmented step circuit (commitment keys and CCS) and computes the expected
+/// final state natively.
+pub fn prepare_poseidon(input_size: usize) -> Result<PreparedPoseidon, BenchError> {
+    let config = poseidon_canonical_config::<Fr>();
+    let step = PoseidonStep::new(config.clone()).map_err(|e| BenchError::setup(e.to_string()))?;
+    let params = HN::preprocess(OsRng, &PreprocessorParam::new(config.clone(), step.clone()))
+        .map_err(|e| BenchError::setup(e.to_string()))?;
+
+    let inputs: Vec<Fr> = utils::generate_poseidon_input(input_size)
+        .iter()
+        .map(|bytes| Fr::from_le_bytes_mod_order(bytes))
+        .collect();
+    let digest = inputs.iter().try_fold(Fr::zero(), |z, &x| {
+        CRH::<Fr>::evaluate(&config, [z, x]).map_err(|e| BenchError::setup(e.to_string()))
+    })?;
+
+    Ok(PreparedPoseidon {
+        step,
+        params,
+        inputs,
+        digest,
+    })
+}
+
+/// Folds one step per input element and returns the IVC proof of the last one.
+pub fn prove(prepared: &PreparedPoseidon) -> Result<Proof, BenchError> {
+    let mut folding = HN::init(&prepared.params, prepared.step.clone(), vec![Fr::zero()])
+        .map_err(|e| BenchError::prove(e.to_string()))?;
+    for &x in &prepared.inputs {
+        tracing::info_span!("fold")
+            .in_scope(|| folding.prove_step(OsRng, [x], None))
+            .map_err(|e| BenchError::prove(e.to_string()))?;
+    }
+    Ok(folding.ivc_proof())
+}
+
+/// Verifies the IVC proof and checks that it starts at `z_0 = 0`, folds one step per input
+/// element and ends at the natively computed hash chain.
+pub fn verify(prepared: &PreparedPoseidon, proof: &Proof) -> Result<(), BenchError> {
+    HN::verify(prepared.params.1.clone(), proof.clone())
+        .map_err(|e| BenchError::verify(e.to_string()))?;
+    BenchError::check(
+        proof.z_0 == [Fr::zero()],
+        "IVC proof does not start at z_0 = 0",
+    )?;
+    BenchError::check(
+        proof.i == Fr::from(prepared.inputs.len() as u64),
+        "IVC proof does not fold one step per input element",
+    )?;
+    if proof.z_i != [prepared.digest] {
+        return Err(BenchError::wrong_output(
+            prepared.digest.into_bigint().to_bytes_le(),
+            proof
+                .z_i
+                .iter()
+                .flat_map(|z| z.into_bigint().to_bytes_le())
+                .collect::<Vec<_>>(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rows of the CCS of the augmented step circuit times the number of steps, with one step's CCS
+/// variables as the witness.
+pub fn num_constraints(prepared: &PreparedPoseidon) -> Result<ConstraintCount, BenchError> {
+    let ccs = prepared
+        .params
+        .0
+        .ccs
+        .as_ref()
+        .ok_or_else(|| BenchError::setup("preprocessing did not set the CCS"))?;
+    Ok(ConstraintCount::with_witness(
+        ccs.m * prepared.inputs.len(),
+        WitnessSize::new(ccs.n as u64, 32),
+    ))
+}
+
+/// Size of the compressed prover and verifier parameters.
+pub fn preprocessing_size(prepared: &PreparedPoseidon) -> usize {
+    let (pp, vp) = &prepared.params;
+    pp.compressed_size() + vp.compressed_size()
+}
+
+/// Size of the compressed IVC proof: the running and last instances with their witnesses, whose
+/// size depends on the step circuit and not on the number of steps.
+pub fn proof_size(proof: &Proof) -> usize {
+    proof.compressed_size()
+}
+
+fn compressed(value: &impl CanonicalSerialize) -> Result<Vec<u8>, BenchError> {
+    let mut bytes = Vec::new();
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(BenchError::setup)?;
+    Ok(bytes)
+}
+
+/// The IVC proof and the verifier parameters, compressed with `ark-serialize`. The public inputs
+/// are the number of steps and the expected final state, as two compressed field elements.
+pub fn export_artifacts(
+    prepared: &PreparedPoseidon,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: compressed(proof)?,
+        verification_key: compressed(&prepared.params.1)?,
+        public_inputs: compressed(&vec![
+            Fr::from(prepared.inputs.len() as u64),
+            prepared.digest,
+        ])?,
+        encoding: "ark-compressed",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`], with the same checks on the start, the
+/// steps and the final state as [`verify`].
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let vp = HN::vp_deserialize_with_mode(
+        verification_key,
+        Compress::Yes,
+        Validate::Yes,
+        poseidon_canonical_config::<Fr>(),
+    )?;
+    let proof = Proof::deserialize_compressed(proof)?;
+    let [steps, digest] = <[Fr; 2]>::try_from(Vec::<Fr>::deserialize_compressed(public_inputs)?)
+        .map_err(|_| anyhow::anyhow!("public inputs are not a step count and a digest"))?;
+    anyhow::ensure!(
+        proof.z_0 == [Fr::zero()],
+        "IVC proof does not start at z_0 = 0"
+    );
+    anyhow::ensure!(proof.i == steps, "IVC proof folds another number of steps");
+    anyhow::ensure!(proof.z_i == [digest], "IVC proof ends at another state");
+    HN::verify(vp, proof)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hypernova_poseidon_rejects_a_wrong_digest() {
+        let mut prepared = prepare_poseidon(2).unwrap();
+        let proof = prove(&prepared).unwrap();
+        verify(&prepared, &proof).unwrap();
+
+        prepared.digest += Fr::from(1u64);
+        assert!(matches!(
+            verify(&prepared, &proof),
+            Err(BenchError::WrongOutput { .. })
+        ));
+    }
+
+    #[test]
+    fn hypernova_exported_artifacts_verify() {
+        let mut prepared = prepare_poseidon(2).unwrap();
+        let proof = prove(&prepared).unwrap();
+        let artifacts = export_artifacts(&prepared, &proof).unwrap();
+        verify_artifacts(
+            &artifacts.proof,
+            &artifacts.verification_key,
+            &artifacts.public_inputs,
+        )
+        .unwrap();
+
+        prepared.digest += Fr::from(1u64);
+        let tampered = export_artifacts(&prepared, &proof).unwrap();
+        assert!(
+            verify_artifacts(
+                &artifacts.proof,
+                &artifacts.verification_key,
+                &tampered.public_inputs,
+            )
+            .is_err()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use ere_jolt::compiler::RustRv64imacCustomized;
 use jolt::{
-    execution_cycles, jolt_bench_properties, prepare_ecdsa, preprocessing_size, proof_size,
-    prove_ecdsa, verify_ecdsa,
+    JOLT_FEATURE, execution_cycles, export_artifacts, jolt_bench_properties, prepare_ecdsa,
+    preprocessing_size, proof_size, prove_ecdsa, verify_ecdsa,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::ECDSA_BENCH;
@@ -10,15 +10,16 @@ use utils::zkvm::helpers::load_or_compile_program;
 utils::define_benchmark_harness!(
     BenchTarget::Ecdsa,
     ProvingSystem::Jolt,
-    None,
+    JOLT_FEATURE,
     "ecdsa_mem_jolt",
     jolt_bench_properties(),
-    { load_or_compile_program(&RustRv64imacCustomized, ECDSA_BENCH) },
+    { load_or_compile_program(&RustRv64imacCustomized, ECDSA_BENCH)? },
     prepare_ecdsa,
     |_, _| 0,
     prove_ecdsa,
     verify_ecdsa,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    execution_cycles,
+    export_artifacts = export_artifacts
 );
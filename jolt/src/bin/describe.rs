@@ -0,0 +1,7 @@
+use jolt::BENCH_FEATURES;
+use utils::features::print_description;
+use utils::harness::ProvingSystem;
+
+fn main() {
+    print_description(ProvingSystem::Jolt, BENCH_FEATURES);
+}
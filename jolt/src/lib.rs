@@ -1,14 +1,45 @@
 use ere_jolt::{EreJolt, compiler::RustRv64imacCustomized};
 use ere_zkvm_interface::{Input, ProverResource};
 use serde::Serialize;
-use utils::harness::{AuditStatus, BenchProperties};
-use utils::zkvm::{CompiledProgram, PreparedEcdsa, PreparedKeccak, PreparedSha256};
+use utils::features::{BenchFeature, FeatureParameter};
+use utils::harness::{AuditStatus, BenchError, BenchProperties, BenchTarget};
+use utils::zkvm::{CompiledProgram, InputAbi, PreparedEcdsa, PreparedKeccak, PreparedSha256};
 
 pub use utils::zkvm::{
-    execution_cycles, preprocessing_size, proof_size, prove, prove_ecdsa, prove_sha256,
-    verify_ecdsa, verify_keccak, verify_sha256,
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_ecdsa,
+    prove_sha256, verify_ecdsa, verify_keccak, verify_sha256,
 };
 
+/// Feature tag of the benches: `gpu` when built with the `gpu` feature, giving `jolt_gpu`
+/// results next to the CPU ones.
+pub const JOLT_FEATURE: Option<&str> = if cfg!(feature = "gpu") {
+    Some("gpu")
+} else {
+    None
+};
+
+/// Feature tags of the Jolt benches, printed by `describe_jolt`.
+pub const BENCH_FEATURES: &[BenchFeature] = &[BenchFeature {
+    tag: "gpu",
+    description: "Proves on the GPU instead of the CPU; the guest programs and inputs are unchanged.",
+    targets: &[BenchTarget::Sha256, BenchTarget::Keccak, BenchTarget::Ecdsa],
+    cargo_feature: Some("gpu"),
+    bench: None,
+    parameters: &[FeatureParameter {
+        name: "prover_resource",
+        value: "gpu",
+        description: "ere `ProverResource` the prover runs on.",
+    }],
+}];
+
+pub fn prover_resource() -> ProverResource {
+    if cfg!(feature = "gpu") {
+        ProverResource::Gpu
+    } else {
+        ProverResource::Cpu
+    }
+}
+
 pub fn jolt_bench_properties() -> BenchProperties {
     BenchProperties::new(
         "Jolt",
@@ -24,53 +55,67 @@ pub fn jolt_bench_properties() -> BenchProperties {
         AuditStatus::NotAudited,
         Some("RISC-V RV64IMAC"),
     )
-}
-
-fn build_framed_input(data: Vec<u8>) -> Input {
-    let len = data.len() as u32;
-    let mut framed = Vec::with_capacity(4 + data.len());
-    framed.extend_from_slice(&len.to_le_bytes());
-    framed.extend(data);
-    Input::new().with_stdin(framed)
+    .with_prover_resource(if cfg!(feature = "gpu") { "gpu" } else { "cpu" })
 }
 
 pub fn prepare_sha256(
     input_size: usize,
     program: &CompiledProgram<RustRv64imacCustomized>,
-) -> PreparedSha256<EreJolt> {
-    let vm = EreJolt::new(program.program.clone(), ProverResource::Cpu)
-        .expect("jolt prover build failed");
+) -> Result<PreparedSha256<EreJolt>, BenchError> {
+    let vm = EreJolt::new(program.program.clone(), prover_resource()).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_sha256_input(input_size);
-    let input = build_framed_input(message_bytes);
-
-    PreparedSha256::with_expected_digest(vm, input, program.byte_size, digest)
+    let input = InputAbi::LengthPrefixed.input(&message_bytes);
+
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
 }
 
 pub fn prepare_keccak(
     input_size: usize,
     program: &CompiledProgram<RustRv64imacCustomized>,
-) -> PreparedKeccak<EreJolt> {
-    let vm = EreJolt::new(program.program.clone(), ProverResource::Cpu)
-        .expect("jolt prover build failed");
+) -> Result<PreparedKeccak<EreJolt>, BenchError> {
+    let vm = EreJolt::new(program.program.clone(), prover_resource()).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_keccak_input(input_size);
-    let input = build_framed_input(message_bytes);
-
-    PreparedKeccak::with_expected_digest(vm, input, program.byte_size, digest)
+    let input = InputAbi::LengthPrefixed.input(&message_bytes);
+
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
 }
 
 pub fn prepare_ecdsa(
     _input_size: usize,
     program: &CompiledProgram<RustRv64imacCustomized>,
-) -> PreparedEcdsa<EreJolt> {
-    let vm = EreJolt::new(program.program.clone(), ProverResource::Cpu)
-        .expect("jolt prover build failed");
+) -> Result<PreparedEcdsa<EreJolt>, BenchError> {
+    let vm = EreJolt::new(program.program.clone(), prover_resource()).map_err(BenchError::setup)?;
 
     let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_k256_input();
     let input = build_ecdsa_jolt_input(&digest, &pub_key_x, &pub_key_y, &signature);
 
-    PreparedEcdsa::new(vm, input, program.byte_size)
+    Ok(PreparedEcdsa::new(vm, input, program.byte_size))
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv64imacCustomized, _>(
+        |program| Ok(EreJolt::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
 }
 
 #[derive(Serialize)]
@@ -118,5 +163,5 @@ fn build_ecdsa_jolt_input(
     };
 
     let serialized = postcard::to_allocvec(&ecdsa_input).expect("failed to serialize ECDSA input");
-    build_framed_input(serialized)
+    InputAbi::LengthPrefixed.input(&serialized)
 }
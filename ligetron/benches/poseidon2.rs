@@ -0,0 +1,20 @@
+use ligetron::{
+    export_artifacts, ligetron_bench_properties, num_constraints, prepare_poseidon2,
+    preprocessing_size, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Poseidon2,
+    ProvingSystem::Ligetron,
+    None,
+    "poseidon2_mem_ligetron",
+    ligetron_bench_properties(),
+    prepare_poseidon2,
+    num_constraints,
+    prove,
+    |prepared, proof| verify(prepared, proof),
+    preprocessing_size,
+    |proof| proof.len(),
+    export_artifacts = |prepared: &_, proof: &Vec<u8>| export_artifacts(prepared, proof)
+);
@@ -0,0 +1,7 @@
+use ligetron::{prepare_poseidon2, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_poseidon2, prove, |prepared, proof| {
+        verify(prepared, proof)
+    });
+}
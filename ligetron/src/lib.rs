@@ -0,0 +1,242 @@
+//! Poseidon2 proved with the Ligero prover of the Ligetron platform, run as subprocesses.
+//!
+//! The statement is the SDK's `poseidon2` example, a C++ program compiled to WASM: `prepare`
+//! builds the prover arguments (the program, the shaders and the program arguments) and a work
+//! directory, `prove` runs `webgpu_prover` there, which writes `proof_data.gz`, and `verify` runs
+//! `webgpu_verifier` on that proof. The exported artifacts are the proof, the program and the
+//! program arguments with the private ones blanked, which is all the verifier reads. The SHA-256 and ECDSA targets are still run by the shell
+//! scripts next to this crate through `benchmark.sh`.
+
+use serde_json::{Value, json};
+use std::fs;
+use std::path::{Path, PathBuf};
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{AuditStatus, BenchError, BenchProperties, BenchTarget};
+
+/// Proof the prover writes to its working directory.
+const PROOF_FILE: &str = "proof_data.gz";
+/// Rows packed per Ligero codeword, as in the SHA-256 shell benchmark.
+const PACKING: u64 = 8192;
+
+pub fn ligetron_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Ligero",
+        "Bn254",
+        "Ligero",
+        Some("Ligero"),
+        "Ligero",
+        true,
+        true, // WASM programs
+        128,
+        true,                    // hash-based commitments
+        true,                    // https://github.com/ligeroinc/ligero-prover
+        AuditStatus::NotAudited, // no published audit of the prover
+        Some("WASM"),
+    )
+}
+
+/// The prover arguments of one input, with the work directory the prover runs in.
+pub struct PreparedLigetron {
+    pub dir: PathBuf,
+    pub program: PathBuf,
+    /// JSON the prover and the verifier take as their only argument.
+    pub args: String,
+}
+
+fn ligero_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("ligero-prover")
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().expect("Ligetron path is not UTF-8")
+}
+
+/// Runs a Ligero binary from `ligero-prover/build` in `dir`, returning its stdout.
+fn run(binary: &str, dir: &Path, args: &str) -> Result<String, BenchError> {
+    let output = utils::sandbox::command(ligero_dir().join("build").join(binary))
+        .arg(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| BenchError::tool(binary, e))?;
+    if !output.status.success() {
+        return Err(BenchError::tool(
+            binary,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Prover arguments running `program` on `args`, with the arguments at `private_indices`
+/// (1-based) kept private.
+fn prover_args(program: &Path, private_indices: &[usize], args: Value) -> String {
+    json!({
+        "program": path_str(program),
+        "shader-path": path_str(&ligero_dir().join("shader")),
+        "packing": PACKING,
+        "private-indices": private_indices,
+        "args": args,
+    })
+    .to_string()
+}
+
+/// Hashes the `input_size` field elements of the Poseidon2 input. The message is private; its
+/// length in bytes and the expected hash are public, and the program asserts the hash.
+pub fn prepare_poseidon2(input_size: usize) -> Result<PreparedLigetron, BenchError> {
+    let (message, digest) = utils::generate_poseidon2_input(input_size);
+    let program = ligero_dir().join("sdk/cpp/build/examples/poseidon2.wasm");
+    if !program.exists() {
+        return Err(BenchError::setup(format!(
+            "{} not found; build the Ligero SDK examples first",
+            program.display()
+        )));
+    }
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/ligetron")
+        .join(format!("{}_{input_size}", BenchTarget::Poseidon2.as_str()));
+    fs::create_dir_all(&dir)?;
+    let args = prover_args(
+        &program,
+        &[1],
+        json!([
+            { "hex": hex::encode(&message) },
+            { "i64": message.len() },
+            { "str": format!("0x{}", hex::encode(digest)) },
+        ]),
+    );
+    Ok(PreparedLigetron {
+        dir: dir.canonicalize()?,
+        program,
+        args,
+    })
+}
+
+/// The gzipped proof `webgpu_prover` writes.
+pub fn prove(prepared: &PreparedLigetron) -> Result<Vec<u8>, BenchError> {
+    run("webgpu_prover", &prepared.dir, &prepared.args).map_err(BenchError::prove)?;
+    Ok(fs::read(prepared.dir.join(PROOF_FILE))?)
+}
+
+/// Runs `webgpu_verifier` on `proof`, copied to a directory of its own so that the verifier does
+/// not pick up a proof left by the prover.
+pub fn verify(prepared: &PreparedLigetron, proof: &[u8]) -> Result<(), BenchError> {
+    let dir = prepared.dir.join("verify");
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(PROOF_FILE), proof)?;
+    run("webgpu_verifier", &dir, &prepared.args)
+        .map(drop)
+        .map_err(BenchError::verify)
+}
+
+/// Linear plus quadratic constraints of the first stage, from the output of a proving run (the
+/// prover has no separate constraint count). 0 if the prover fails.
+pub fn num_constraints(prepared: &PreparedLigetron) -> usize {
+    run("webgpu_prover", &prepared.dir, &prepared.args)
+        .ok()
+        .and_then(|stdout| parse_constraints(&stdout))
+        .unwrap_or(0)
+}
+
+fn parse_constraints(stdout: &str) -> Option<usize> {
+    let count = |line: &str| {
+        line.split(|c: char| !c.is_ascii_digit())
+            .find_map(|n| n.parse::<usize>().ok())
+    };
+    let mut stage = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("Start Stage 1"));
+    let linear = stage.find(|line| line.contains("Num Linear constraints:"))?;
+    let quadratic = stage.find(|line| line.contains("Num quadratic constraints:"))?;
+    Some(count(linear)? + count(quadratic)?)
+}
+
+/// The private indices and the arguments of prover arguments, with the private `hex` arguments
+/// replaced by zeros of the same length: the verifier only needs their size.
+fn public_args(args: &str) -> Option<Value> {
+    let args: Value = serde_json::from_str(args).ok()?;
+    let private_indices = args["private-indices"].clone();
+    let mut program_args = args["args"].as_array()?.clone();
+    for index in private_indices.as_array()? {
+        let arg = program_args.get_mut(usize::try_from(index.as_u64()?.checked_sub(1)?).ok()?)?;
+        if let Some(hex) = arg["hex"].as_str() {
+            arg["hex"] = json!("0".repeat(hex.len()));
+        }
+    }
+    Some(json!({ "private-indices": private_indices, "args": program_args }))
+}
+
+/// The gzipped proof, the WASM program as the verification key, and the public program
+/// arguments (see [`public_args`]) as JSON.
+pub fn export_artifacts(
+    prepared: &PreparedLigetron,
+    proof: &[u8],
+) -> Result<ExportedArtifacts, BenchError> {
+    let public = public_args(&prepared.args)
+        .ok_or_else(|| BenchError::setup("prover arguments have no private indices or args"))?;
+    Ok(ExportedArtifacts {
+        proof: proof.to_vec(),
+        verification_key: fs::read(&prepared.program)?,
+        public_inputs: serde_json::to_vec(&public).map_err(BenchError::setup)?,
+        encoding: "ligero-gzip+wasm+json",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`] by running `webgpu_verifier` on them,
+/// in a directory of this process under `target/ligetron/artifacts`.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let public: Value = serde_json::from_slice(public_inputs)?;
+    let private_indices: Vec<usize> = serde_json::from_value(public["private-indices"].clone())?;
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/ligetron/artifacts")
+        .join(std::process::id().to_string());
+    fs::create_dir_all(&dir)?;
+    let dir = dir.canonicalize()?;
+    let program = dir.join("program.wasm");
+    fs::write(&program, verification_key)?;
+    fs::write(dir.join(PROOF_FILE), proof)?;
+    let args = prover_args(&program, &private_indices, public["args"].clone());
+    let verified = run("webgpu_verifier", &dir, &args);
+    fs::remove_dir_all(&dir)?;
+    verified?;
+    Ok(())
+}
+
+/// Size of the WASM program, which both sides load.
+pub fn preprocessing_size(prepared: &PreparedLigetron) -> usize {
+    fs::metadata(&prepared.program).map_or(0, |m| m.len() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_stage_one_constraints() {
+        let stdout = "Start Stage 0\nNum Linear constraints: 7\nStart Stage 1\n\
+                      Num Linear constraints: 1200\nNum quadratic constraints: 345\n";
+        assert_eq!(parse_constraints(stdout), Some(1545));
+        assert_eq!(parse_constraints("Num Linear constraints: 7\n"), None);
+    }
+
+    #[test]
+    fn test_public_args_blank_the_private_message() {
+        let args = prover_args(
+            Path::new("poseidon2.wasm"),
+            &[1],
+            json!([{ "hex": "abcd" }, { "i64": 2 }, { "str": "0x12" }]),
+        );
+        assert_eq!(
+            public_args(&args),
+            Some(json!({
+                "private-indices": [1],
+                "args": [{ "hex": "0000" }, { "i64": 2 }, { "str": "0x12" }],
+            }))
+        );
+        assert_eq!(public_args("{}"), None);
+    }
+}
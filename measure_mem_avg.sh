@@ -53,9 +53,91 @@ if (( $# == 0 )); then
   exit 1
 fi
 
+# Writes a failure record in place of the measurement (see utils/src/bench/failure.rs).
+STDERR_TAIL_LINES=50
+BACKTRACE_LINES=40
+write_failure() {
+  local status="$1" output="$2" program_stderr signal exit_status
+  # Drop the time(1) report appended after the program's own stderr.
+  program_stderr=$(echo "$output" | awk '/Command being timed:|Command exited with non-zero status|Command terminated by signal|^[[:space:]]+[0-9.]+ real/ {exit} {print}')
+  signal=$(echo "$output" | sed -n 's/.*Command terminated by signal \([0-9]*\).*/\1/p' | head -n 1)
+  if [[ -z "$signal" ]] && (( status > 128 )); then
+    signal=$(( status - 128 ))
+  fi
+  exit_status=$([[ -n "$signal" ]] && echo null || echo "$status")
+
+  jq -n \
+    --argjson exit_status "$exit_status" \
+    --argjson signal "${signal:-null}" \
+    --arg stderr "$(echo "$program_stderr" | sed '/^stack backtrace:/,$d' | tail -n "$STDERR_TAIL_LINES")" \
+    --arg backtrace "$(echo "$program_stderr" | sed -n '/^stack backtrace:/,$p' | head -n "$BACKTRACE_LINES")" \
+    --arg panic_message "$(echo "$program_stderr" | grep -m 1 -A 1 'panicked at' | grep -v '^note:' || true)" \
+    '{peak_memory: 0, failure: ({
+      stage: "memory",
+      exit_status: $exit_status,
+      signal: $signal,
+      oom_suspected: ($signal == 9),
+      panic_message: (if $panic_message == "" then null else $panic_message end),
+      stderr: $stderr,
+      backtrace: (if $backtrace == "" then null else $backtrace end)
+    } | with_entries(select(.value != null)))}' > "$json_file"
+  echo "Failure record saved to $json_file"
+}
+
+# Linux only: peak virtual size (VmPeak) and swap (VmSwap) of the command's process tree, in KB,
+# sampled every 100 ms while it runs. RSS alone hides mmapped SRS and trace files.
+descendants() {
+  local child
+  echo "$1"
+  for child in $(pgrep -P "$1"); do
+    descendants "$child"
+  done
+}
+sample_peaks() {
+  local pid="$1" p pids key value gpu_mib
+  run_vm_kb=0
+  run_swap_kb=0
+  run_gpu_mib=0
+  while [[ -r "/proc/$pid/status" ]] && ! grep -q '^State:[[:space:]]*Z' "/proc/$pid/status"; do
+    pids=$(descendants "$pid")
+    for p in $pids; do
+      while read -r key value _; do
+        if [[ "$key" == "VmPeak:" ]] && (( value > run_vm_kb )); then run_vm_kb=$value; fi
+        if [[ "$key" == "VmSwap:" ]] && (( value > run_swap_kb )); then run_swap_kb=$value; fi
+      done 2>/dev/null < "/proc/$p/status"
+    done
+    if (( GPU_MEM )); then
+      gpu_mib=$(sample_gpu_mib $pids)
+      if (( gpu_mib > run_gpu_mib )); then run_gpu_mib=$gpu_mib; fi
+    fi
+    sleep 0.1
+  done
+}
+
+# GPU provers (MEASURE_GPU_MEM=1, Linux only): device memory used by the given processes, in MiB,
+# summed over all GPUs as NVML reports it through nvidia-smi.
+GPU_MEM=0
+if [[ "${MEASURE_GPU_MEM:-0}" == "1" && "$OS_TYPE" == "Linux" ]]; then
+  if command -v nvidia-smi >/dev/null 2>&1; then
+    GPU_MEM=1
+  else
+    echo "Warning: nvidia-smi not found, GPU memory is not measured" >&2
+  fi
+fi
+sample_gpu_mib() {
+  nvidia-smi --query-compute-apps=pid,used_memory --format=csv,noheader,nounits 2>/dev/null \
+    | awk -F', *' -v pids=" $* " 'index(pids, " " $1 " ") { sum += $2 } END { print sum + 0 }'
+}
+
+# Rust provers print a backtrace on panic, which the failure record keeps.
+export RUST_BACKTRACE="${RUST_BACKTRACE:-1}"
+
 # Fixed number of runs: 10
 NUM_RUNS=10
 total_bytes=0
+total_vm_bytes=0
+total_swap_bytes=0
+total_gpu_bytes=0
 
 echo "Running command: $* (averaging over $NUM_RUNS runs)"
 echo "JSON output file: $json_file"
@@ -64,7 +146,24 @@ for i in $(seq 1 $NUM_RUNS); do
   echo " Run #$i..."
   
   # Run the command and capture both program output and measurement output
-  output=$({ $TIME_CMD "$@" 2>&1 >/dev/null; } 2>&1)
+  err_file=$(mktemp)
+  set +e
+  $TIME_CMD "$@" >/dev/null 2>"$err_file" &
+  pid=$!
+  if [[ "$OS_TYPE" == "Linux" ]]; then
+    sample_peaks "$pid"
+  fi
+  wait "$pid"
+  status=$?
+  set -e
+  output=$(cat "$err_file")
+  rm -f "$err_file"
+
+  if (( status != 0 )); then
+    echo "  Error: command failed with status $status"
+    write_failure "$status" "$output"
+    exit "$status"
+  fi
 
   # Locate the memory measurement line
   line=$(echo "$output" | awk -v lab="$MEM_LABEL" 'tolower($0) ~ tolower(lab) {print $0}')
@@ -87,6 +186,16 @@ for i in $(seq 1 $NUM_RUNS); do
   echo "  Peak memory: ${bytes} bytes"
 
   total_bytes=$(( $total_bytes + $bytes ))
+
+  if [[ "$OS_TYPE" == "Linux" ]]; then
+    echo "  Peak virtual memory: $(( run_vm_kb * 1024 )) bytes, peak swap: $(( run_swap_kb * 1024 )) bytes"
+    total_vm_bytes=$(( total_vm_bytes + run_vm_kb * 1024 ))
+    total_swap_bytes=$(( total_swap_bytes + run_swap_kb * 1024 ))
+  fi
+  if (( GPU_MEM )); then
+    echo "  Peak GPU memory: $(( run_gpu_mib * 1024 * 1024 )) bytes"
+    total_gpu_bytes=$(( total_gpu_bytes + run_gpu_mib * 1024 * 1024 ))
+  fi
 done
 
 # Compute average
@@ -100,10 +209,21 @@ echo "  • ${avg_mib} MiB"
 
 
 # Prepare JSON output
-json_output=$(jq -n \
-  --argjson avg_bytes "$avg_bytes" \
-  '{peak_memory: $avg_bytes}'
-)
+if [[ "$OS_TYPE" == "Linux" ]]; then
+  json_output=$(jq -n \
+    --argjson avg_bytes "$avg_bytes" \
+    --argjson avg_vm_bytes "$(( total_vm_bytes / NUM_RUNS ))" \
+    --argjson avg_swap_bytes "$(( total_swap_bytes / NUM_RUNS ))" \
+    --argjson avg_gpu_bytes "$( (( GPU_MEM )) && echo $(( total_gpu_bytes / NUM_RUNS )) || echo null)" \
+    '{peak_memory: $avg_bytes, peak_virtual_memory: $avg_vm_bytes, peak_swap: $avg_swap_bytes}
+      + (if $avg_gpu_bytes == null then {} else {peak_gpu_memory: $avg_gpu_bytes} end)'
+  )
+else
+  json_output=$(jq -n \
+    --argjson avg_bytes "$avg_bytes" \
+    '{peak_memory: $avg_bytes}'
+  )
+fi
 
 echo "$json_output" > "$json_file"
 echo "Result saved to $json_file"
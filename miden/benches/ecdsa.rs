@@ -1,7 +1,7 @@
 use ere_miden::compiler::MidenAsm;
 use miden::{
-    execution_cycles, miden_bench_properties, prepare_ecdsa, preprocessing_size, proof_size,
-    prove_ecdsa, verify_ecdsa,
+    execution_cycles, export_artifacts, miden_bench_properties, prepare_ecdsa, preprocessing_size,
+    proof_size, prove_ecdsa, verify_ecdsa,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::ECDSA_BENCH;
@@ -13,12 +13,13 @@ utils::define_benchmark_harness!(
     None,
     "ecdsa_mem_miden",
     miden_bench_properties(),
-    { load_or_compile_program(&MidenAsm, ECDSA_BENCH) },
-    |size, prog| prepare_ecdsa(size, prog).expect("prepare_ecdsa"),
+    { load_or_compile_program(&MidenAsm, ECDSA_BENCH)? },
+    prepare_ecdsa,
     |_, _| 0,
     prove_ecdsa,
-    |p, proof, s| verify_ecdsa(p, proof, s).expect("verify_ecdsa"),
+    verify_ecdsa,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    execution_cycles,
+    export_artifacts = export_artifacts
 );
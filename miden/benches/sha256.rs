@@ -1,7 +1,7 @@
 use ere_miden::compiler::MidenAsm;
 use miden::{
-    execution_cycles, miden_bench_properties, prepare_sha256, preprocessing_size, proof_size,
-    prove_sha256, verify_sha256,
+    execution_cycles, export_artifacts, miden_bench_properties, prepare_sha256, preprocessing_size,
+    proof_size, prove_sha256, verify_sha256,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::SHA256_BENCH;
@@ -13,12 +13,13 @@ utils::define_benchmark_harness!(
     None,
     "sha256_mem_miden",
     miden_bench_properties(),
-    { load_or_compile_program(&MidenAsm, SHA256_BENCH) },
+    { load_or_compile_program(&MidenAsm, SHA256_BENCH)? },
     prepare_sha256,
     |_, _| 0,
     prove_sha256,
     verify_sha256,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    execution_cycles,
+    export_artifacts = export_artifacts
 );
@@ -1,21 +1,13 @@
-use clap::Parser;
 use ere_miden::compiler::MidenAsm;
-use miden::{prepare_ecdsa, prove_ecdsa};
+use miden::{prepare_ecdsa, prove_ecdsa, verify_ecdsa};
 use utils::zkvm::ECDSA_BENCH;
 use utils::zkvm::helpers::load_compiled_program;
 
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size in bytes for the SHA256 benchmark
-    #[arg(long = "input-size")]
-    input_size: Option<usize>,
-}
-
 fn main() {
-    let args = Args::parse();
-
-    let program = load_compiled_program::<MidenAsm>(ECDSA_BENCH);
-
-    let prepared = prepare_ecdsa(args.input_size.unwrap_or(1), &program).expect("prepare_ecdsa");
-    let _proof = prove_ecdsa(&prepared, &program);
+    let program = utils::bench::mem::shared_state(load_compiled_program::<MidenAsm>(ECDSA_BENCH));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_ecdsa(input_size, &program),
+        |prepared| prove_ecdsa(prepared, &program),
+        |prepared, proof| verify_ecdsa(prepared, proof, &&program),
+    );
 }
@@ -1,21 +1,13 @@
-use clap::Parser;
 use ere_miden::compiler::MidenAsm;
-use miden::{prepare_sha256, prove_sha256};
+use miden::{prepare_sha256, prove_sha256, verify_sha256};
 use utils::zkvm::SHA256_BENCH;
 use utils::zkvm::helpers::load_compiled_program;
 
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size in bytes for the SHA256 benchmark
-    #[arg(long = "input-size")]
-    input_size: usize,
-}
-
 fn main() {
-    let args = Args::parse();
-
-    let program = load_compiled_program::<MidenAsm>(SHA256_BENCH);
-
-    let prepared = prepare_sha256(args.input_size, &program);
-    let _proof = prove_sha256(&prepared, &program);
+    let program = utils::bench::mem::shared_state(load_compiled_program::<MidenAsm>(SHA256_BENCH));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_sha256(input_size, &program),
+        |prepared| prove_sha256(prepared, &program),
+        |prepared, proof| verify_sha256(prepared, proof, &&program),
+    );
 }
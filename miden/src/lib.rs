@@ -3,11 +3,11 @@ use ere_zkvm_interface::{Input, ProverResource};
 use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
 use k256::{EncodedPoint, FieldBytes};
 use std::convert::TryInto;
-use utils::harness::{AuditStatus, BenchProperties};
-use utils::zkvm::{CompiledProgram, PreparedEcdsa, PreparedSha256, ProofArtifacts};
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::zkvm::{CompiledProgram, InputAbi, PreparedEcdsa, PreparedSha256, ProofArtifacts};
 
 pub use utils::zkvm::{
-    execution_cycles, preprocessing_size, proof_size, prove_ecdsa, prove_sha256,
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove_ecdsa, prove_sha256,
 };
 
 pub fn miden_bench_properties() -> BenchProperties {
@@ -30,14 +30,19 @@ pub fn miden_bench_properties() -> BenchProperties {
 pub fn prepare_sha256(
     input_size: usize,
     program: &CompiledProgram<MidenAsm>,
-) -> PreparedSha256<EreMiden> {
-    let vm = EreMiden::new(program.program.clone(), ProverResource::Cpu)
-        .expect("failed to build miden prover instance");
+) -> Result<PreparedSha256<EreMiden>, BenchError> {
+    let vm =
+        EreMiden::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_sha256_input(input_size);
-    let input = build_input(message_bytes);
+    let input = InputAbi::MidenBeWords.input(&message_bytes);
 
-    PreparedSha256::with_expected_digest(vm, input, program.byte_size, digest)
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
 }
 
 // Miden has custom verification logic due to special public value decoding
@@ -45,44 +50,16 @@ pub fn verify_sha256(
     prepared: &PreparedSha256<EreMiden>,
     proof: &ProofArtifacts,
     _: &&CompiledProgram<MidenAsm>,
-) {
-    let public_values = prepared.verify(&proof.proof).expect("miden verify failed");
-
-    assert_eq!(public_values, proof.public_values, "public values mismatch");
-
-    let digest_bytes = decode_public_values(&proof.public_values);
-    let expected_digest = prepared
-        .expected_digest()
-        .expect("expected digest not recorded");
-    assert_eq!(digest_bytes, expected_digest, "digest mismatch");
-}
-
-fn build_input(data: Vec<u8>) -> Input {
-    let len = data.len();
-    let mut stdin = Vec::new();
-
-    // Write the length as u64 LE bytes
-    stdin.extend_from_slice(&(len as u64).to_le_bytes());
-
-    let blocks = len.div_ceil(16);
-    let words_needed = blocks * 4;
-
-    let mut words: Vec<u32> = data
-        .chunks(4)
-        .map(|chunk| {
-            let mut bytes = [0u8; 4];
-            bytes[..chunk.len()].copy_from_slice(chunk);
-            u32::from_be_bytes(bytes)
-        })
-        .collect();
-    words.resize(words_needed, 0);
-
-    for block in words.chunks_exact(4) {
-        for &word in block.iter().rev() {
-            stdin.extend_from_slice(&(word as u64).to_le_bytes());
-        }
+) -> Result<(), BenchError> {
+    let public_values = prepared.verify(&proof.proof).map_err(BenchError::verify)?;
+    if public_values != proof.public_values {
+        return Err(BenchError::verify("public values mismatch"));
     }
-    Input::new().with_stdin(stdin)
+
+    prepared
+        .expected_outputs()
+        .expect("expected digest not recorded")
+        .check(&decode_public_values(&proof.public_values))
 }
 
 fn decode_public_values(raw: &[u8]) -> Vec<u8> {
@@ -99,14 +76,15 @@ fn decode_public_values(raw: &[u8]) -> Vec<u8> {
 pub fn prepare_ecdsa(
     _input_size: usize,
     program: &CompiledProgram<MidenAsm>,
-) -> Result<PreparedEcdsa<EreMiden>, &'static str> {
-    let vm = EreMiden::new(program.program.clone(), ProverResource::Cpu)
-        .map_err(|_| "failed to build miden prover instance")?;
+) -> Result<PreparedEcdsa<EreMiden>, BenchError> {
+    let vm =
+        EreMiden::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_k256_input();
 
-    let compressed_pk = compress_public_key(&pub_key_x, &pub_key_y)?;
-    let recovery_id = compute_recovery_id(&digest, &signature, &pub_key_x, &pub_key_y)?;
+    let compressed_pk = compress_public_key(&pub_key_x, &pub_key_y).map_err(BenchError::setup)?;
+    let recovery_id = compute_recovery_id(&digest, &signature, &pub_key_x, &pub_key_y)
+        .map_err(BenchError::setup)?;
 
     let mut signature_with_recovery = signature;
     signature_with_recovery.push(recovery_id);
@@ -122,25 +100,37 @@ pub fn prepare_ecdsa(
     ))
 }
 
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<MidenAsm, _>(
+        |program| Ok(EreMiden::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
+}
+
 pub fn verify_ecdsa(
     prepared: &PreparedEcdsa<EreMiden>,
     proof: &ProofArtifacts,
     _: &&CompiledProgram<MidenAsm>,
-) -> Result<(), &'static str> {
-    let public_values = prepared
-        .verify(&proof.proof)
-        .map_err(|_| "miden verify failed")?;
+) -> Result<(), BenchError> {
+    let public_values = prepared.verify(&proof.proof).map_err(BenchError::verify)?;
     if public_values != proof.public_values {
-        return Err("public values mismatch");
+        return Err(BenchError::verify("public values mismatch"));
     }
 
-    let result = u64::from_le_bytes(
-        proof.public_values[..8]
-            .try_into()
-            .map_err(|_| "invalid miden output")?,
-    );
-    if result != 1 {
-        return Err("ECDSA verification failed in guest");
+    let result = proof
+        .public_values
+        .get(..8)
+        .ok_or_else(|| BenchError::verify("invalid miden output"))?;
+    let expected = 1u64.to_le_bytes();
+    if result != expected {
+        return Err(BenchError::wrong_output(expected, result));
     }
     Ok(())
 }
@@ -178,30 +168,7 @@ fn compute_recovery_id(
 }
 
 fn build_ecdsa_input(compressed_pk: &[u8], digest: &[u8], signature_with_recovery: &[u8]) -> Input {
-    let mut stdin = Vec::new();
-    pack_le_words(&mut stdin, compressed_pk);
-    pack_le_words(&mut stdin, digest);
-    pack_le_words(&mut stdin, signature_with_recovery);
-    Input::new().with_stdin(stdin)
-}
-
-// Pack bytes as u32 LE values into u64 LE advice tape format, padded to word boundaries.
-fn pack_le_words(stdin: &mut Vec<u8>, data: &[u8]) {
-    let mut words: Vec<u32> = data
-        .chunks(4)
-        .map(|chunk| {
-            let mut bytes = [0u8; 4];
-            bytes[..chunk.len()].copy_from_slice(chunk);
-            u32::from_le_bytes(bytes)
-        })
-        .collect();
-
-    let words_needed = words.len().div_ceil(4) * 4;
-    words.resize(words_needed, 0);
-
-    for &w in &words {
-        stdin.extend_from_slice(&(w as u64).to_le_bytes());
-    }
+    InputAbi::MidenLeWords.input_from_fields(&[compressed_pk, digest, signature_with_recovery])
 }
 
 /// Slice into array
@@ -253,7 +220,7 @@ mod tests {
         let guest_path = guest_dir(SHA256_BENCH);
         let program =
             compile_guest_program(&MidenAsm, &guest_path).expect("compile guest program for tests");
-        let prepared = prepare_sha256(2048, &program);
+        let prepared = prepare_sha256(2048, &program).unwrap();
 
         // Execute the guest to obtain the committed digest bytes
         let (public_values, _) = prepared
@@ -267,7 +234,52 @@ mod tests {
         assert_eq!(digest_bytes, expected_digest);
 
         // Ensure prove/verify plumbing also succeeds
-        let proof = prove_sha256(&prepared, &program);
-        verify_sha256(&prepared, &proof, &(&program));
+        let proof = prove_sha256(&prepared, &program).unwrap();
+        verify_sha256(&prepared, &proof, &(&program)).unwrap();
+    }
+
+    fn sha256_program() -> CompiledProgram<MidenAsm> {
+        use utils::zkvm::{SHA256_BENCH, compile_guest_program, guest_dir};
+        compile_guest_program(&MidenAsm, &guest_dir(SHA256_BENCH)).expect("compile sha256 guest")
+    }
+
+    #[test]
+    fn miden_sha256_handles_unaligned_lengths() {
+        let program = sha256_program();
+        for len in [0, 1, 3, 4, 5, 15, 17, 55, 56, 63, 64, 100, 135] {
+            let prepared = prepare_sha256(len, &program).unwrap();
+            let (public_values, _) = prepared
+                .vm()
+                .execute(prepared.input())
+                .unwrap_or_else(|e| panic!("guest execution failed for {len} bytes: {e:?}"));
+            assert_eq!(
+                decode_public_values(&public_values),
+                prepared.expected_digest().unwrap(),
+                "digest mismatch for {len} bytes"
+            );
+        }
+    }
+
+    #[test]
+    fn miden_sha256_rejects_malformed_padding() {
+        let program = sha256_program();
+        let vm = EreMiden::new(program.program.clone(), ProverResource::Cpu).unwrap();
+        // Advice elements of a 5-byte message: [len, w3, w2, w1, w0], where w1 holds the fifth
+        // byte followed by three bytes of padding.
+        let stdin = InputAbi::MidenBeWords.encode(&[1, 2, 3, 4, 5]);
+        let tampered = |element: usize, value: u64| {
+            let mut stdin = stdin.clone();
+            stdin[element * 8..][..8].copy_from_slice(&value.to_le_bytes());
+            Input::new().with_stdin(stdin)
+        };
+
+        assert!(vm.execute(&Input::new().with_stdin(stdin.clone())).is_ok());
+        // A padding byte inside the partial word.
+        assert!(vm.execute(&tampered(3, 0x0500_0001)).is_err());
+        // A whole padding word.
+        assert!(vm.execute(&tampered(2, 1)).is_err());
+        // A block cut short.
+        let truncated = Input::new().with_stdin(stdin[..stdin.len() - 8].to_vec());
+        assert!(vm.execute(&truncated).is_err());
     }
 }
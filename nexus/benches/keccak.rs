@@ -1,7 +1,7 @@
 use ere_nexus::compiler::RustRv32i;
 use nexus::{
-    NEXUS_PROPS, execution_cycles, prepare_keccak, preprocessing_size, proof_size, prove,
-    verify_keccak,
+    NEXUS_PROPS, execution_cycles, export_artifacts, prepare_keccak, preprocessing_size,
+    proof_size, prove, verify_keccak,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::KECCAK_BENCH;
@@ -13,12 +13,13 @@ utils::define_benchmark_harness!(
     None,
     "keccak_mem_nexus",
     NEXUS_PROPS,
-    { load_or_compile_program(&RustRv32i, KECCAK_BENCH) },
+    { load_or_compile_program(&RustRv32i, KECCAK_BENCH)? },
     prepare_keccak,
     |_, _| 0,
     prove,
     verify_keccak,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    execution_cycles,
+    export_artifacts = export_artifacts
 );
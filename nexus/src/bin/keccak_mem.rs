@@ -1,20 +1,13 @@
-use clap::Parser;
 use ere_nexus::compiler::RustRv32i;
-use nexus::{prepare_keccak, prove};
+use nexus::{prepare_keccak, prove, verify_keccak};
 use utils::zkvm::KECCAK_BENCH;
 use utils::zkvm::helpers::load_compiled_program;
 
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size in bytes for the Keccak benchmark
-    #[arg(long = "input-size")]
-    input_size: usize,
-}
-
 fn main() {
-    let args = Args::parse();
-    let program = load_compiled_program::<RustRv32i>(KECCAK_BENCH);
-
-    let prepared = prepare_keccak(args.input_size, &program);
-    prove(&prepared, &());
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32i>(KECCAK_BENCH));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_keccak(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_keccak(prepared, proof, &()),
+    );
 }
@@ -0,0 +1,5 @@
+//! Verifies artifacts exported by the Nexus benchmarks, for the `verifiers` crate.
+
+fn main() -> anyhow::Result<()> {
+    utils::artifacts::verify_artifacts_main(nexus::verify_artifacts)
+}
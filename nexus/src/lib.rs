@@ -2,12 +2,23 @@ use std::borrow::Cow;
 
 use ere_nexus::{EreNexus, NexusExtension, compiler::RustRv32i};
 use ere_zkvm_interface::ProverResource;
-use utils::harness::{AuditStatus, BenchProperties};
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::security::{FriParams, QM31_BITS};
 use utils::zkvm::{CompiledProgram, PreparedKeccak, PreparedSha256, build_input};
 
 pub use utils::zkvm::{
-    execution_cycles, preprocessing_size, proof_size, prove, prove_sha256, verify_keccak,
-    verify_sha256,
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_sha256,
+    verify_keccak, verify_sha256,
+};
+
+/// Circle-FRI parameters of the Nexus prover, which proves with Stwo's `PcsConfig::default()`
+/// (`pow_bits: 5`, `log_blowup_factor: 1`, `n_queries: 3`): a development setting, not a
+/// production security level.
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 1,
+    queries: 3,
+    pow_bits: 5,
+    field_bits: QM31_BITS,
 };
 
 pub const NEXUS_PROPS: BenchProperties = BenchProperties {
@@ -18,38 +29,83 @@ pub const NEXUS_PROPS: BenchProperties = BenchProperties {
     arithm: Cow::Borrowed("AIR"),      // https://specification.nexus.xyz/
     is_zk: false,                      // Based on STWO which is currently not ZK
     is_zkvm: true,
-    security_bits: 0, // TODO: https://github.com/privacy-ethereum/csp-benchmarks/issues/147
-    is_pq: true,      // hash-based PCS
-    is_maintained: true, // https://github.com/nexus-xyz/nexus-zkvm/releases
-    is_audited: AuditStatus::NotAudited, // https://github.com/nexus-xyz/nexus-zkvm
+    security_bits: FRI_PARAMS.conjectured_security_bits(),
+    is_pq: true,                              // hash-based PCS
+    is_maintained: true,                      // https://github.com/nexus-xyz/nexus-zkvm/releases
+    is_audited: AuditStatus::NotAudited,      // https://github.com/nexus-xyz/nexus-zkvm
     isa: Some(Cow::Borrowed("RISC-V RV32I")), // base ISA + precompiles; https://specification.nexus.xyz/
+    prover_resource: None,
+    version: Some(Cow::Borrowed("0.3.6")),
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
 };
 
 pub fn prepare_sha256(
     input_size: usize,
     program: &CompiledProgram<RustRv32i>,
-) -> PreparedSha256<EreNexus> {
-    let vm = EreNexus::new(program.program.clone(), ProverResource::Cpu).unwrap();
+) -> Result<PreparedSha256<EreNexus>, BenchError> {
+    let vm =
+        EreNexus::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_sha256_input(input_size);
     let input = build_input(message_bytes);
 
-    PreparedSha256::with_expected_digest(vm, input, program.byte_size, digest)
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
 }
 
 pub fn prepare_keccak(
     input_size: usize,
     program: &CompiledProgram<RustRv32i>,
-) -> PreparedKeccak<EreNexus> {
+) -> Result<PreparedKeccak<EreNexus>, BenchError> {
     let vm = EreNexus::with_extensions(
         program.program.clone(),
         ProverResource::Cpu,
         NexusExtension::keccak_extensions().to_vec(),
     )
-    .unwrap();
+    .map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_keccak_input(input_size);
     let input = build_input(message_bytes);
 
-    PreparedKeccak::with_expected_digest(vm, input, program.byte_size, digest)
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Verifies artifacts exported with [`export_artifacts`]. The exported program does not record
+/// the extensions of its VM, so a proof the plain VM rejects is checked again with the Keccak
+/// extensions the Keccak guest is proved with.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv32i, _>(
+        |program| Ok(EreNexus::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
+    .or_else(|_| {
+        utils::zkvm::verify_artifacts::<RustRv32i, _>(
+            |program| {
+                Ok(EreNexus::with_extensions(
+                    program,
+                    ProverResource::Cpu,
+                    NexusExtension::keccak_extensions().to_vec(),
+                )?)
+            },
+            proof,
+            verification_key,
+            public_inputs,
+        )
+    })
 }
@@ -0,0 +1,20 @@
+use nova::{
+    NOVA_BENCH_PROPERTIES, export_artifacts, num_constraints, prepare_sha256, preprocessing_size,
+    proof_size, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::Nova,
+    None,
+    "sha256_mem_nova",
+    NOVA_BENCH_PROPERTIES,
+    prepare_sha256,
+    num_constraints,
+    prove,
+    verify,
+    preprocessing_size,
+    proof_size,
+    export_artifacts = export_artifacts
+);
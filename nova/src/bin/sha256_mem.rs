@@ -0,0 +1,5 @@
+use nova::{prepare_sha256, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_sha256, prove, verify);
+}
@@ -0,0 +1,228 @@
+//! The step circuit: one SHA-256 compression over the eight state words in `z`.
+
+use crate::Scalar;
+use bellpepper::gadgets::{multieq::MultiEq, uint32::UInt32};
+use bellpepper_core::{
+    ConstraintSystem, LinearCombination, SynthesisError,
+    boolean::{AllocatedBit, Boolean},
+    num::AllocatedNum,
+};
+use ff::{Field, PrimeField};
+use nova_snark::traits::circuit::StepCircuit;
+
+pub const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// One 64-byte block of the padded message, a private input of its step.
+#[derive(Clone, Debug)]
+pub struct Sha256Step {
+    block: [u8; 64],
+}
+
+impl Default for Sha256Step {
+    fn default() -> Self {
+        Self { block: [0; 64] }
+    }
+}
+
+impl Sha256Step {
+    pub fn new(block: [u8; 64]) -> Self {
+        Self { block }
+    }
+}
+
+/// The blocks of `message` after SHA-256 padding: a 0x80 byte, zeros, and the bit length.
+pub fn padded_blocks(message: &[u8]) -> Vec<[u8; 64]> {
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    padded.resize((message.len() + 9).div_ceil(64) * 64 - 8, 0);
+    padded.extend_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+    padded
+        .chunks_exact(64)
+        .map(|block| block.try_into().expect("64-byte block"))
+        .collect()
+}
+
+impl StepCircuit<Scalar> for Sha256Step {
+    fn arity(&self) -> usize {
+        8
+    }
+
+    fn synthesize<CS: ConstraintSystem<Scalar>>(
+        &self,
+        cs: &mut CS,
+        z: &[AllocatedNum<Scalar>],
+    ) -> Result<Vec<AllocatedNum<Scalar>>, SynthesisError> {
+        let state = z
+            .iter()
+            .enumerate()
+            .map(|(i, word)| unpack(cs.namespace(|| format!("state word {i}")), word))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Message bits, most significant bit of each byte first.
+        let block = self
+            .block
+            .iter()
+            .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .enumerate()
+            .map(|(i, bit)| {
+                AllocatedBit::alloc(cs.namespace(|| format!("block bit {i}")), Some(bit))
+                    .map(Boolean::from)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let state = compress(cs.namespace(|| "compression"), &block, &state)?;
+
+        state
+            .iter()
+            .enumerate()
+            .map(|(i, word)| pack(cs.namespace(|| format!("next state word {i}")), word))
+            .collect()
+    }
+}
+
+/// The SHA-256 compression function of FIPS 180-4 over 512 message bits.
+fn compress<CS: ConstraintSystem<Scalar>>(
+    cs: CS,
+    block: &[Boolean],
+    state: &[UInt32],
+) -> Result<Vec<UInt32>, SynthesisError> {
+    // `addmany` batches the equalities of its additions into this root.
+    let mut cs = MultiEq::new(cs);
+    let mut w: Vec<UInt32> = block.chunks(32).map(UInt32::from_bits_be).collect();
+    for i in 16..64 {
+        let cs = &mut cs.namespace(|| format!("schedule {i}"));
+        let s0 = w[i - 15]
+            .rotr(7)
+            .xor(cs.namespace(|| "s0 xor 1"), &w[i - 15].rotr(18))?;
+        let s0 = s0.xor(cs.namespace(|| "s0 xor 2"), &w[i - 15].shr(3))?;
+        let s1 = w[i - 2]
+            .rotr(17)
+            .xor(cs.namespace(|| "s1 xor 1"), &w[i - 2].rotr(19))?;
+        let s1 = s1.xor(cs.namespace(|| "s1 xor 2"), &w[i - 2].shr(10))?;
+        let word = UInt32::addmany(
+            cs.namespace(|| "w"),
+            &[w[i - 16].clone(), s0, w[i - 7].clone(), s1],
+        )?;
+        w.push(word);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h]: [UInt32; 8] =
+        state.to_vec().try_into().expect("eight state words");
+    for (i, (&k, word)) in ROUND_CONSTANTS.iter().zip(&w).enumerate() {
+        let cs = &mut cs.namespace(|| format!("round {i}"));
+        let s1 = e.rotr(6).xor(cs.namespace(|| "S1 xor 1"), &e.rotr(11))?;
+        let s1 = s1.xor(cs.namespace(|| "S1 xor 2"), &e.rotr(25))?;
+        let ch = UInt32::sha256_ch(cs.namespace(|| "ch"), &e, &f, &g)?;
+        let temp1 = UInt32::addmany(
+            cs.namespace(|| "temp1"),
+            &[h, s1, ch, UInt32::constant(k), word.clone()],
+        )?;
+        let s0 = a.rotr(2).xor(cs.namespace(|| "S0 xor 1"), &a.rotr(13))?;
+        let s0 = s0.xor(cs.namespace(|| "S0 xor 2"), &a.rotr(22))?;
+        let maj = UInt32::sha256_maj(cs.namespace(|| "maj"), &a, &b, &c)?;
+
+        h = g;
+        g = f;
+        f = e;
+        e = UInt32::addmany(cs.namespace(|| "e"), &[d, temp1.clone()])?;
+        d = c;
+        c = b;
+        b = a;
+        a = UInt32::addmany(cs.namespace(|| "a"), &[temp1, s0, maj])?;
+    }
+
+    [a, b, c, d, e, f, g, h]
+        .into_iter()
+        .zip(state)
+        .enumerate()
+        .map(|(i, (word, previous))| {
+            UInt32::addmany(
+                cs.namespace(|| format!("next state {i}")),
+                &[previous.clone(), word],
+            )
+        })
+        .collect()
+}
+
+/// The 32 bits of `word`, constrained to pack into it, so `z` stays below 2^32.
+fn unpack<CS: ConstraintSystem<Scalar>>(
+    mut cs: CS,
+    word: &AllocatedNum<Scalar>,
+) -> Result<UInt32, SynthesisError> {
+    let value = word.get_value().map(|value| {
+        let repr = value.to_repr();
+        u32::from_le_bytes(repr.as_ref()[..4].try_into().expect("32-byte repr"))
+    });
+    let bits = UInt32::alloc(cs.namespace(|| "bits"), value)?;
+    enforce_packing(cs, &bits, word);
+    Ok(bits)
+}
+
+/// `bits` as a field element.
+fn pack<CS: ConstraintSystem<Scalar>>(
+    mut cs: CS,
+    bits: &UInt32,
+) -> Result<AllocatedNum<Scalar>, SynthesisError> {
+    let word = AllocatedNum::alloc(cs.namespace(|| "word"), || {
+        bits.clone()
+            .into_bits_be()
+            .iter()
+            .try_fold(0u64, |value, bit| {
+                Some(value << 1 | u64::from(bit.get_value()?))
+            })
+            .map(Scalar::from)
+            .ok_or(SynthesisError::AssignmentMissing)
+    })?;
+    enforce_packing(cs, bits, &word);
+    Ok(word)
+}
+
+fn enforce_packing<CS: ConstraintSystem<Scalar>>(
+    mut cs: CS,
+    bits: &UInt32,
+    word: &AllocatedNum<Scalar>,
+) {
+    let mut packed = LinearCombination::zero();
+    let mut coeff = Scalar::ONE;
+    for bit in bits.clone().into_bits_be().iter().rev() {
+        packed = packed + &bit.lc(CS::one(), coeff);
+        coeff = coeff.double();
+    }
+    cs.enforce(
+        || "packing",
+        |_| packed,
+        |lc| lc + CS::one(),
+        |lc| lc + word.get_variable(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::digest::generic_array::GenericArray;
+
+    #[test]
+    fn test_blocks_compress_to_the_digest() {
+        for size in [0, 55, 56, 64, 128] {
+            let (message, digest) = utils::generate_sha256_input(size);
+            let mut state = SHA256_IV;
+            for block in padded_blocks(&message) {
+                sha2::compress256(&mut state, &[GenericArray::clone_from_slice(&block)]);
+            }
+            let state: Vec<u8> = state.iter().flat_map(|word| word.to_be_bytes()).collect();
+            assert_eq!(state, digest, "{size}-byte message");
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! SHA-256 as incrementally verifiable computation with Nova over the Pallas/Vesta cycle.
+//!
+//! Every step of the IVC applies the SHA-256 compression function to one 64-byte block of the
+//! padded message, carrying the eight state words in `z`. `prove` folds one step per block into a
+//! `RecursiveSNARK` and compresses it into a `CompressedSNARK` (Spartan with IPA), whose
+//! verification checks that `z` ends at the digest. The two phases run in the `fold` and
+//! `compress` spans, so the spans file reports the folding time per step next to the time of the
+//! final proof.
+
+pub mod circuit;
+
+use circuit::{SHA256_IV, Sha256Step, padded_blocks};
+use ff::{Field, PrimeField};
+use nova_snark::{
+    CompressedSNARK, ProverKey, PublicParams, RecursiveSNARK, VerifierKey,
+    errors::NovaError,
+    provider::{PallasEngine, VestaEngine, ipa_pc::EvaluationEngine},
+    spartan::snark::RelaxedR1CSSNARK,
+    traits::{Engine, circuit::TrivialCircuit, snark::RelaxedR1CSSNARKTrait},
+};
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::witness_size::{ConstraintCount, WitnessSize};
+
+type E1 = PallasEngine;
+type E2 = VestaEngine;
+type S1 = RelaxedR1CSSNARK<E1, EvaluationEngine<E1>>;
+type S2 = RelaxedR1CSSNARK<E2, EvaluationEngine<E2>>;
+type C2 = TrivialCircuit<<E2 as Engine>::Scalar>;
+
+pub type Scalar = <E1 as Engine>::Scalar;
+pub type Proof = CompressedSNARK<E1, E2, Sha256Step, C2, S1, S2>;
+
+pub const NOVA_BENCH_PROPERTIES: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("Nova"),
+    field_curve: Cow::Borrowed("Pallas"),
+    iop: Cow::Borrowed("Nova"), // folding, compressed with Spartan
+    pcs: Some(Cow::Borrowed("IPA")),
+    arithm: Cow::Borrowed("R1CS"),
+    is_zk: false,
+    is_zkvm: false,
+    security_bits: 128, // Pasta curves
+    is_pq: false,
+    is_maintained: true, // https://github.com/microsoft/Nova
+    is_audited: AuditStatus::NotAudited,
+    isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("0.37.0")),
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[Caveat::Note {
+        text: Cow::Borrowed("message padding is a private step input, not checked in the circuit"),
+    }]),
+};
+
+pub struct PreparedSha256 {
+    pp: PublicParams<E1, E2, Sha256Step, C2>,
+    pk: ProverKey<E1, E2, Sha256Step, C2, S1, S2>,
+    vk: VerifierKey<E1, E2, Sha256Step, C2, S1, S2>,
+    steps: Vec<Sha256Step>,
+    digest: Vec<u8>,
+}
+
+/// Public parameters of the step circuit. Their commitment keys are derived from fixed labels,
+/// so they are the same on every run.
+fn public_params() -> Result<PublicParams<E1, E2, Sha256Step, C2>, NovaError> {
+    PublicParams::setup(
+        &Sha256Step::default(),
+        &C2::default(),
+        &*S1::ck_floor(),
+        &*S2::ck_floor(),
+    )
+}
+
+/// Generates the public parameters of the step circuit and the keys of the compressed SNARK,
+/// and splits the padded message into one step per block.
+pub fn prepare_sha256(input_size: usize) -> Result<PreparedSha256, BenchError> {
+    let (message, digest) = utils::generate_sha256_input(input_size);
+    let pp = public_params().map_err(|e| BenchError::setup(format!("{e:?}")))?;
+    let (pk, vk) = Proof::setup(&pp).map_err(|e| BenchError::setup(format!("{e:?}")))?;
+    let steps = padded_blocks(&message)
+        .into_iter()
+        .map(Sha256Step::new)
+        .collect();
+    Ok(PreparedSha256 {
+        pp,
+        pk,
+        vk,
+        steps,
+        digest,
+    })
+}
+
+fn z0_primary() -> Vec<Scalar> {
+    SHA256_IV
+        .iter()
+        .map(|&word| Scalar::from(u64::from(word)))
+        .collect()
+}
+
+fn z0_secondary() -> Vec<<E2 as Engine>::Scalar> {
+    vec![<E2 as Engine>::Scalar::ZERO]
+}
+
+/// Folds one step per block, then compresses the folded instance.
+pub fn prove(prepared: &PreparedSha256) -> Result<Proof, BenchError> {
+    let secondary = C2::default();
+    // `RecursiveSNARK::new` folds the first step, and its `prove_step` only counts it.
+    let mut recursive_snark = tracing::info_span!("fold")
+        .in_scope(|| {
+            let mut recursive_snark = RecursiveSNARK::new(
+                &prepared.pp,
+                &prepared.steps[0],
+                &secondary,
+                &z0_primary(),
+                &z0_secondary(),
+            )?;
+            recursive_snark.prove_step(&prepared.pp, &prepared.steps[0], &secondary)?;
+            Ok::<_, NovaError>(recursive_snark)
+        })
+        .map_err(|e| BenchError::prove(format!("{e:?}")))?;
+    for step in &prepared.steps[1..] {
+        tracing::info_span!("fold")
+            .in_scope(|| recursive_snark.prove_step(&prepared.pp, step, &secondary))
+            .map_err(|e| BenchError::prove(format!("{e:?}")))?;
+    }
+    tracing::info_span!("compress")
+        .in_scope(|| Proof::prove(&prepared.pp, &prepared.pk, &recursive_snark))
+        .map_err(|e| BenchError::prove(format!("{e:?}")))
+}
+
+/// The digest the state `z` of the primary circuit holds, one word per field element below 2^32.
+fn state_digest(z_primary: &[Scalar]) -> Vec<u8> {
+    z_primary
+        .iter()
+        .flat_map(|word| {
+            let repr = word.to_repr();
+            let mut bytes: [u8; 4] = repr.as_ref()[..4].try_into().expect("32-byte repr");
+            bytes.reverse();
+            bytes
+        })
+        .collect()
+}
+
+/// Verifies the compressed SNARK and checks that the state it ends at is the digest.
+pub fn verify(prepared: &PreparedSha256, proof: &Proof) -> Result<(), BenchError> {
+    let (z_primary, _) = proof
+        .verify(
+            &prepared.vk,
+            prepared.steps.len(),
+            &z0_primary(),
+            &z0_secondary(),
+        )
+        .map_err(|e| BenchError::verify(format!("{e:?}")))?;
+    let state = state_digest(&z_primary);
+    if state != prepared.digest {
+        return Err(BenchError::wrong_output(&prepared.digest, state));
+    }
+    Ok(())
+}
+
+/// Constraints of the augmented step circuit (the compression function and the folding
+/// verifier) times the number of steps. The witness is that of one step: folding keeps only the
+/// running instance and the step being folded, whatever the number of steps.
+pub fn num_constraints(prepared: &PreparedSha256) -> ConstraintCount {
+    let (step_constraints, _) = prepared.pp.num_constraints();
+    let (step_variables, _) = prepared.pp.num_variables();
+    ConstraintCount::with_witness(
+        step_constraints * prepared.steps.len(),
+        WitnessSize::new(step_variables as u64, 32),
+    )
+}
+
+/// Size of the serialized public parameters and the prover key of the compressed SNARK.
+pub fn preprocessing_size(prepared: &PreparedSha256) -> Result<usize, BenchError> {
+    let pp = bincode::serialize(&prepared.pp).map_err(BenchError::setup)?;
+    let pk = bincode::serialize(&prepared.pk).map_err(BenchError::setup)?;
+    Ok(pp.len() + pk.len())
+}
+
+/// Size of the serialized compressed SNARK.
+pub fn proof_size(proof: &Proof) -> Result<usize, BenchError> {
+    bincode::serialize(proof)
+        .map(|bytes| bytes.len())
+        .map_err(BenchError::prove)
+}
+
+/// The compressed SNARK, serialized with bincode. The verifier key holds the commitment keys
+/// (24 MB for SHA-256), so the digest of the public parameters stands for it; the public inputs
+/// are the number of steps, a little-endian `u64`, followed by the digest.
+pub fn export_artifacts(
+    prepared: &PreparedSha256,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut public_inputs = (prepared.steps.len() as u64).to_le_bytes().to_vec();
+    public_inputs.extend_from_slice(&prepared.digest);
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(BenchError::setup)?,
+        verification_key: prepared.pp.digest().to_repr().as_ref().to_vec(),
+        public_inputs,
+        encoding: "bincode+pp-digest",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`], with a verifier key regenerated from
+/// the public parameters after checking that they have the exported digest.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let pp = public_params().map_err(|e| anyhow::anyhow!("setup failed: {e:?}"))?;
+    anyhow::ensure!(
+        pp.digest().to_repr().as_ref() == verification_key,
+        "the public parameters of the step circuit have another digest"
+    );
+    let (_, vk) = Proof::setup(&pp).map_err(|e| anyhow::anyhow!("setup failed: {e:?}"))?;
+    let proof: Proof = bincode::deserialize(proof)?;
+    let (steps, digest) = public_inputs
+        .split_first_chunk::<8>()
+        .ok_or_else(|| anyhow::anyhow!("public inputs have no step count"))?;
+    let (z_primary, _) = proof
+        .verify(
+            &vk,
+            usize::try_from(u64::from_le_bytes(*steps))?,
+            &z0_primary(),
+            &z0_secondary(),
+        )
+        .map_err(|e| anyhow::anyhow!("verification failed: {e:?}"))?;
+    anyhow::ensure!(state_digest(&z_primary) == digest, "digest mismatch");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nova_sha256_rejects_a_wrong_digest() {
+        let mut prepared = prepare_sha256(128).unwrap();
+        let proof = prove(&prepared).unwrap();
+        verify(&prepared, &proof).unwrap();
+
+        prepared.digest[0] ^= 1;
+        assert!(matches!(
+            verify(&prepared, &proof),
+            Err(BenchError::WrongOutput { .. })
+        ));
+    }
+
+    #[test]
+    fn nova_exported_artifacts_verify() {
+        let mut prepared = prepare_sha256(128).unwrap();
+        let proof = prove(&prepared).unwrap();
+        let artifacts = export_artifacts(&prepared, &proof).unwrap();
+        verify_artifacts(
+            &artifacts.proof,
+            &artifacts.verification_key,
+            &artifacts.public_inputs,
+        )
+        .unwrap();
+
+        prepared.digest[0] ^= 1;
+        let tampered = export_artifacts(&prepared, &proof).unwrap();
+        assert!(
+            verify_artifacts(
+                &artifacts.proof,
+                &artifacts.verification_key,
+                &tampered.public_inputs,
+            )
+            .is_err()
+        );
+    }
+}
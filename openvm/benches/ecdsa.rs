@@ -0,0 +1,25 @@
+use ere_openvm::compiler::RustRv32imaCustomized;
+use openvm::{
+    execution_cycles, export_artifacts, openvm_bench_properties, prepare_ecdsa, preprocessing_size,
+    proof_size, prove_ecdsa, verify_ecdsa,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::ECDSA_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Ecdsa,
+    ProvingSystem::OpenVM,
+    None,
+    "ecdsa_mem_openvm",
+    openvm_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, ECDSA_BENCH)? },
+    prepare_ecdsa,
+    |_, _| 0,
+    prove_ecdsa,
+    verify_ecdsa,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,25 @@
+use ere_openvm::compiler::RustRv32imaCustomized;
+use openvm::{
+    execution_cycles, export_artifacts, openvm_bench_properties, prepare_keccak,
+    preprocessing_size, proof_size, prove, verify_keccak,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::KECCAK_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Keccak,
+    ProvingSystem::OpenVM,
+    None,
+    "keccak_mem_openvm",
+    openvm_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, KECCAK_BENCH)? },
+    prepare_keccak,
+    |_, _| 0,
+    prove,
+    verify_keccak,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
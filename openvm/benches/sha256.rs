@@ -1,6 +1,7 @@
 use ere_openvm::compiler::RustRv32imaCustomized;
 use openvm::{
-    execution_cycles, prepare_sha256, preprocessing_size, proof_size, prove_sha256, verify_sha256,
+    execution_cycles, export_artifacts, openvm_bench_properties, prepare_sha256,
+    preprocessing_size, proof_size, prove_sha256, verify_sha256,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::SHA256_BENCH;
@@ -11,16 +12,14 @@ utils::define_benchmark_harness!(
     ProvingSystem::OpenVM,
     None,
     "sha256_mem_openvm",
-    utils::harness::BenchProperties {
-        is_zkvm: true,
-        ..Default::default()
-    },
-    { load_or_compile_program(&RustRv32imaCustomized, SHA256_BENCH) },
+    openvm_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, SHA256_BENCH)? },
     prepare_sha256,
     |_, _| 0,
     prove_sha256,
     verify_sha256,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    execution_cycles,
+    export_artifacts = export_artifacts
 );
@@ -0,0 +1,25 @@
+use openvm::io::{read_vec, reveal_bytes32};
+use openvm_k256::ecdsa::{Signature, VerifyingKey, signature::hazmat::PrehashVerifier};
+
+openvm::init!();
+
+fn main() {
+    use bincode::Options;
+
+    let input_bytes = read_vec();
+    let (encoded_verifying_key_bytes, digest, signature_bytes): (Vec<u8>, Vec<u8>, Vec<u8>) =
+        bincode::options()
+            .deserialize(&input_bytes)
+            .expect("Failed to deserialize input");
+
+    let verifying_key =
+        VerifyingKey::from_sec1_bytes(&encoded_verifying_key_bytes).expect("Invalid verifying key");
+    let signature = Signature::from_slice(&signature_bytes).expect("Invalid signature");
+
+    verifying_key
+        .verify_prehash(&digest, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // OpenVM exposes 32 bytes of public values, too few for the encoded key; reveal the digest.
+    reveal_bytes32(digest.try_into().expect("digest must be 32 bytes"));
+}
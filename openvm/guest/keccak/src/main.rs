@@ -0,0 +1,10 @@
+use core::hint::black_box;
+
+use openvm::io::{read_vec, reveal_bytes32};
+use openvm_keccak256::keccak256;
+
+fn main() {
+    let input = read_vec();
+    let hash = keccak256(&black_box(input));
+    reveal_bytes32(hash);
+}
@@ -0,0 +1,15 @@
+use ere_openvm::compiler::RustRv32imaCustomized;
+use openvm::{prepare_ecdsa, prove_ecdsa, verify_ecdsa};
+use utils::zkvm::ECDSA_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        ECDSA_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_ecdsa(input_size, &program),
+        |prepared| prove_ecdsa(prepared, &()),
+        |prepared, proof| verify_ecdsa(prepared, proof, &()),
+    );
+}
@@ -0,0 +1,15 @@
+use ere_openvm::compiler::RustRv32imaCustomized;
+use openvm::{prepare_keccak, prove, verify_keccak};
+use utils::zkvm::KECCAK_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        KECCAK_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_keccak(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_keccak(prepared, proof, &()),
+    );
+}
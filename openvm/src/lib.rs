@@ -1,20 +1,100 @@
 use ere_openvm::{EreOpenVM, compiler::RustRv32imaCustomized};
 use ere_zkvm_interface::ProverResource;
-use utils::zkvm::{CompiledProgram, PreparedSha256, build_input};
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::zkvm::{
+    CompiledProgram, PreparedEcdsa, PreparedKeccak, PreparedSha256, build_ecdsa_input, build_input,
+    encode_public_key,
+};
 
 pub use utils::zkvm::{
-    execution_cycles, preprocessing_size, proof_size, prove_sha256, verify_sha256,
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_ecdsa,
+    prove_sha256, verify_ecdsa, verify_keccak, verify_sha256,
 };
 
+pub fn openvm_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "STARK",
+        "BabyBear",            // 15 × 2^27 + 1; https://docs.openvm.dev/whitepaper.pdf
+        "STARK",               // Plonky3-based; https://github.com/openvm-org/stark-backend
+        Some("FRI"),           // https://github.com/openvm-org/stark-backend
+        "AIR",                 // https://github.com/openvm-org/stark-backend
+        false,                 // the STARK backend does not blind its traces
+        true,                  // zkVM
+        100,                   // conjectured; https://docs.openvm.dev/book/advanced-usage/sdk
+        true,                  // STARK is PQ-safe (the Halo2 wrapper is not)
+        true,                  // https://github.com/openvm-org/openvm/releases
+        AuditStatus::Audited,  // https://github.com/openvm-org/openvm/tree/main/audits
+        Some("RISC-V RV32IM"), // https://docs.openvm.dev/book/getting-started/introduction
+    )
+    .with_onchain_verifier("evm") // Halo2 wrapper, openvm-solidity-sdk
+}
+
 pub fn prepare_sha256(
     input_size: usize,
     program: &CompiledProgram<RustRv32imaCustomized>,
-) -> PreparedSha256<EreOpenVM> {
-    let vm = EreOpenVM::new(program.program.clone(), ProverResource::Cpu)
-        .expect("failed to build OpenVM prover instance");
+) -> Result<PreparedSha256<EreOpenVM>, BenchError> {
+    let vm =
+        EreOpenVM::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_sha256_input(input_size);
     let input = build_input(message_bytes);
 
-    PreparedSha256::with_expected_digest(vm, input, program.byte_size, digest)
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Prepares an ECDSA signature verification benchmark (single secp256k1 signature). The guest
+/// only reveals the verified digest, which [`verify_ecdsa`] compares with the proof.
+pub fn prepare_ecdsa(
+    _input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedEcdsa<EreOpenVM>, BenchError> {
+    let vm =
+        EreOpenVM::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_k256_input();
+
+    let encoded_verifying_key =
+        encode_public_key(&pub_key_x, &pub_key_y).map_err(BenchError::setup)?;
+    let input =
+        build_ecdsa_input(encoded_verifying_key, digest, signature).map_err(BenchError::setup)?;
+
+    Ok(PreparedEcdsa::new(vm, input, program.byte_size))
+}
+
+/// Prepares a Keccak256 hash benchmark.
+pub fn prepare_keccak(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedKeccak<EreOpenVM>, BenchError> {
+    let vm =
+        EreOpenVM::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (message_bytes, digest) = utils::generate_keccak_input(input_size);
+    let input = build_input(message_bytes);
+
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv32imaCustomized, _>(
+        |program| Ok(EreOpenVM::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
 }
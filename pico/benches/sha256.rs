@@ -0,0 +1,25 @@
+use ere_pico::compiler::RustRv32imaCustomized;
+use pico::{
+    execution_cycles, export_artifacts, pico_bench_properties, prepare_sha256, preprocessing_size,
+    proof_size, prove_sha256, verify_sha256,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::SHA256_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::Pico,
+    None,
+    "sha256_mem_pico",
+    pico_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, SHA256_BENCH)? },
+    prepare_sha256,
+    |_, _| 0,
+    prove_sha256,
+    verify_sha256,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
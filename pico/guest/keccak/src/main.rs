@@ -0,0 +1,16 @@
+#![no_main]
+
+use tiny_keccak::{Hasher, Keccak};
+
+pico_sdk::entrypoint!(main);
+
+pub fn main() {
+    let input_bytes = pico_sdk::io::read_vec();
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&input_bytes);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    pico_sdk::io::commit_bytes(&hash);
+}
@@ -0,0 +1,15 @@
+#![no_main]
+
+use sha2::{Digest, Sha256};
+
+pico_sdk::entrypoint!(main);
+
+pub fn main() {
+    let input_bytes = pico_sdk::io::read_vec();
+
+    let mut hasher = Sha256::new();
+    hasher.update(input_bytes);
+    let hash: [u8; 32] = hasher.finalize().into();
+
+    pico_sdk::io::commit_bytes(&hash);
+}
@@ -0,0 +1,15 @@
+use ere_pico::compiler::RustRv32imaCustomized;
+use pico::{prepare_sha256, prove_sha256, verify_sha256};
+use utils::zkvm::SHA256_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        SHA256_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_sha256(input_size, &program),
+        |prepared| prove_sha256(prepared, &()),
+        |prepared, proof| verify_sha256(prepared, proof, &()),
+    );
+}
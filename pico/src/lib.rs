@@ -0,0 +1,105 @@
+use ere_pico::{ErePico, compiler::RustRv32imaCustomized};
+use ere_zkvm_interface::ProverResource;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::zkvm::{
+    CompiledProgram, PreparedEcdsa, PreparedKeccak, PreparedSha256, build_ecdsa_input, build_input,
+    encode_public_key,
+};
+
+pub use utils::zkvm::{
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_ecdsa,
+    prove_sha256, verify_ecdsa, verify_keccak, verify_sha256,
+};
+
+pub fn pico_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "STARK",
+        "KoalaBear", // 2^31 - 2^24 + 1, Pico's default field; https://docs.brevis.network/pico
+        "STARK",     // Plonky3-based; https://github.com/brevis-network/pico
+        Some("FRI"),
+        "AIR",
+        false,                   // the STARK proofs do not blind their traces
+        true,                    // zkVM
+        100,                     // conjectured FRI soundness, as for the other Plonky3-based zkVMs
+        true,                    // STARK is PQ-safe (the Groth16 wrapper is not)
+        true,                    // https://github.com/brevis-network/pico/releases
+        AuditStatus::NotAudited, // no published audit of the prover
+        Some("RISC-V RV32IM"),
+    )
+    .with_onchain_verifier("evm") // Groth16 wrapper
+}
+
+pub fn prepare_sha256(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedSha256<ErePico>, BenchError> {
+    let vm =
+        ErePico::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (message_bytes, digest) = utils::generate_sha256_input(input_size);
+    let input = build_input(message_bytes);
+
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Prepares an ECDSA signature verification benchmark (single secp256k1 signature).
+pub fn prepare_ecdsa(
+    _input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedEcdsa<ErePico>, BenchError> {
+    let vm =
+        ErePico::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_k256_input();
+
+    let encoded_verifying_key =
+        encode_public_key(&pub_key_x, &pub_key_y).map_err(BenchError::setup)?;
+    let input = build_ecdsa_input(encoded_verifying_key, digest.clone(), signature)
+        .map_err(BenchError::setup)?;
+
+    Ok(PreparedEcdsa::with_expected_values(
+        vm,
+        input,
+        program.byte_size,
+        (pub_key_x, pub_key_y),
+        digest,
+    ))
+}
+
+/// Prepares a Keccak256 hash benchmark.
+pub fn prepare_keccak(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedKeccak<ErePico>, BenchError> {
+    let vm =
+        ErePico::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (message_bytes, digest) = utils::generate_keccak_input(input_size);
+    let input = build_input(message_bytes);
+
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv32imaCustomized, _>(
+        |program| Ok(ErePico::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
+}
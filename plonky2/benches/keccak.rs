@@ -1,7 +1,9 @@
 use plonky2_circuits::PLONKY2_BENCH_PROPERTIES;
 use plonky2_circuits::bench::{
-    compute_proof_size, compute_u32_preprocessing_size, keccak256_prepare, prove, verify_proof,
+    compute_proof_size, compute_u32_preprocessing_size, constraint_count, export_u32_artifacts,
+    keccak256_prepare, prove, verify_proof,
 };
+use utils::caveat::Caveat;
 use utils::harness::ProvingSystem;
 
 utils::define_benchmark_harness!(
@@ -9,11 +11,12 @@ utils::define_benchmark_harness!(
     ProvingSystem::Plonky2,
     None,
     "keccak_mem",
-    PLONKY2_BENCH_PROPERTIES,
+    PLONKY2_BENCH_PROPERTIES.with_caveat(Caveat::DigestBakedIn),
     keccak256_prepare,
-    |(_, _, n_gates)| *n_gates,
+    |(circuit_data, _, n_gates)| constraint_count(circuit_data, *n_gates),
     |(circuit_data, pw, _)| { prove(circuit_data, pw.clone()) },
     verify_proof,
     |(circuit_data, _pw, _)| compute_u32_preprocessing_size(circuit_data),
-    compute_proof_size
+    compute_proof_size,
+    export_artifacts = export_u32_artifacts
 );
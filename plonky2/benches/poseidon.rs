@@ -1,7 +1,10 @@
 use plonky2::plonk::config::PoseidonGoldilocksConfig;
 use plonky2::util::serialization::{DefaultGateSerializer, DefaultGeneratorSerializer};
 use plonky2_circuits::PLONKY2_BENCH_PROPERTIES;
-use plonky2_circuits::bench::{compute_proof_size, poseidon_prepare, prove, verify_proof};
+use plonky2_circuits::bench::{
+    compute_proof_size, constraint_count, export_default_artifacts, poseidon_prepare, prove,
+    verify_proof,
+};
 use utils::harness::ProvingSystem;
 
 const D: usize = 2;
@@ -14,7 +17,7 @@ utils::define_benchmark_harness!(
     "poseidon_mem_plonky2",
     PLONKY2_BENCH_PROPERTIES,
     poseidon_prepare,
-    |(_, _, n_gates)| *n_gates,
+    |(circuit_data, _, n_gates)| constraint_count(circuit_data, *n_gates),
     |(circuit_data, pw, _)| { prove(circuit_data, pw.clone()) },
     verify_proof,
     |(circuit_data, _pw, _)| {
@@ -32,5 +35,6 @@ utils::define_benchmark_harness!(
             .len();
         prover_data_size + common_data_size
     },
-    compute_proof_size
+    compute_proof_size,
+    export_artifacts = export_default_artifacts
 );
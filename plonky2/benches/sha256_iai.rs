@@ -0,0 +1,33 @@
+//! Instruction-count (iai-callgrind) variant of the SHA-256 bench. Requires valgrind and
+//! `iai-callgrind-runner`; run with
+//! `IAI_CALLGRIND_SAVE_SUMMARY=json cargo bench --features callgrind --bench sha256_iai`.
+//! The build script generates one bench per size `BENCH_INPUT_PROFILE` selects, with the
+//! Criterion group id as its id so `collect_benchmarks` can attach the counts.
+
+use iai_callgrind::{library_benchmark, library_benchmark_group, main};
+use plonky2::field::goldilocks_field::GoldilocksField;
+use plonky2::iop::witness::PartialWitness;
+use plonky2::plonk::circuit_data::CircuitData;
+use plonky2::plonk::config::PoseidonGoldilocksConfig;
+use plonky2::plonk::proof::ProofWithPublicInputs;
+use plonky2_circuits::bench::{prove, sha256_prepare};
+use std::hint::black_box;
+use utils::harness::BenchError;
+
+const D: usize = 2;
+type C = PoseidonGoldilocksConfig;
+type F = GoldilocksField;
+
+include!(concat!(env!("OUT_DIR"), "/callgrind_benches.rs"));
+
+callgrind_benches! {
+    fn prove_sha256(
+        prepared: Result<(CircuitData<F, C, D>, PartialWitness<F>, usize), BenchError>,
+    ) -> ProofWithPublicInputs<F, C, D> {
+        let (circuit_data, pw, _) = prepared.expect("prepare failed");
+        black_box(prove(&circuit_data, pw).expect("prove failed"))
+    }
+}
+
+library_benchmark_group!(name = sha256; benchmarks = prove_sha256);
+main!(library_benchmark_groups = sha256);
@@ -0,0 +1,12 @@
+//! Generates the benches of `sha256_iai` for the sizes `BENCH_INPUT_PROFILE` selects.
+
+fn main() {
+    #[cfg(feature = "callgrind")]
+    utils::callgrind::write_library_benches(
+        utils::harness::BenchTarget::Sha256,
+        utils::harness::ProvingSystem::Plonky2,
+        None,
+        "sha256_prepare",
+    )
+    .expect("failed to write the callgrind benches");
+}
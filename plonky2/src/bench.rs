@@ -1,5 +1,6 @@
+use anyhow::{anyhow, ensure};
 use plonky2::{
-    field::goldilocks_field::GoldilocksField,
+    field::{goldilocks_field::GoldilocksField, types::PrimeField64},
     hash::poseidon::PoseidonHash,
     iop::witness::{PartialWitness, WitnessWrite},
     plonk::{
@@ -8,34 +9,43 @@ use plonky2::{
         config::{GenericConfig, PoseidonGoldilocksConfig},
         proof::ProofWithPublicInputs,
     },
-    util::serialization::Write,
+    util::serialization::{DefaultGateSerializer, GateSerializer, IoResult, Write},
 };
 
 use crate::keccak256::circuit::{array_to_bits_lsb, keccak256_circuit};
 use crate::sha256::circuit::{array_to_bits, make_circuits};
 use plonky2_u32::gates::arithmetic_u32::{U32GateSerializer, U32GeneratorSerializer};
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::BenchError;
+use utils::proof_size::ProofSize;
+use utils::witness_size::{ConstraintCount, WitnessSize};
 
 const D: usize = 2;
 type C = PoseidonGoldilocksConfig;
 type F = <C as GenericConfig<D>>::F;
 
-pub fn verify(data: &VerifierCircuitData<F, C, D>, proof: ProofWithPublicInputs<F, C, D>) {
-    data.verify(proof).unwrap()
+pub fn verify(
+    data: &VerifierCircuitData<F, C, D>,
+    proof: ProofWithPublicInputs<F, C, D>,
+) -> Result<(), BenchError> {
+    data.verify(proof).map_err(BenchError::verify)
 }
 
 pub fn prove(
     data: &CircuitData<GoldilocksField, PoseidonGoldilocksConfig, 2>,
     pw: PartialWitness<F>,
-) -> ProofWithPublicInputs<GoldilocksField, C, D> {
-    data.prove(pw).unwrap()
+) -> Result<ProofWithPublicInputs<GoldilocksField, C, D>, BenchError> {
+    data.prove(pw).map_err(BenchError::prove)
 }
 
-pub fn sha256_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWitness<F>, usize) {
+pub fn sha256_prepare(
+    input_size: usize,
+) -> Result<(CircuitData<F, C, D>, PartialWitness<F>, usize), BenchError> {
     let (msg, hash) = utils::generate_sha256_input(input_size);
 
     let msg_bits = array_to_bits(&msg);
     let len = msg.len() * 8;
-    println!("block count: {}", (len + 65).div_ceil(512));
+    tracing::debug!(blocks = (len + 65).div_ceil(512), "sha256 message");
     const D: usize = 2;
     type C = PoseidonGoldilocksConfig;
     type F = <C as GenericConfig<D>>::F;
@@ -44,7 +54,8 @@ pub fn sha256_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWitnes
     let mut pw = PartialWitness::new();
 
     for (i, msg_bit) in msg_bits.iter().enumerate().take(len) {
-        pw.set_bool_target(targets.message[i], *msg_bit).unwrap();
+        pw.set_bool_target(targets.message[i], *msg_bit)
+            .map_err(BenchError::setup)?;
     }
 
     let expected_res = array_to_bits(hash.as_slice());
@@ -57,10 +68,12 @@ pub fn sha256_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWitnes
     }
 
     let n_gates = builder.num_gates();
-    (builder.build::<C>(), pw, n_gates)
+    Ok((builder.build::<C>(), pw, n_gates))
 }
 
-pub fn poseidon_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWitness<F>, usize) {
+pub fn poseidon_prepare(
+    input_size: usize,
+) -> Result<(CircuitData<F, C, D>, PartialWitness<F>, usize), BenchError> {
     use plonky2::field::types::Field;
 
     let inputs = utils::generate_poseidon_input_goldilocks(input_size);
@@ -76,14 +89,44 @@ pub fn poseidon_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWitn
     let mut pw = PartialWitness::new();
     for (i, target) in input_targets.iter().enumerate() {
         pw.set_target(*target, F::from_canonical_u64(inputs[i]))
-            .unwrap();
+            .map_err(BenchError::setup)?;
     }
 
     let n_gates = builder.num_gates();
-    (builder.build::<C>(), pw, n_gates)
+    Ok((builder.build::<C>(), pw, n_gates))
 }
 
-pub fn keccak256_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWitness<F>, usize) {
+/// Hashes the sponge-target message with Plonky2's Poseidon sponge (`hash_n_to_hash_no_pad`,
+/// rate 8), packed into Goldilocks elements as [`utils::poseidon_sponge_elements_goldilocks`] does.
+pub fn poseidon_sponge_prepare(
+    input_size: usize,
+) -> Result<(CircuitData<F, C, D>, PartialWitness<F>, usize), BenchError> {
+    use plonky2::field::types::Field;
+
+    let message = utils::generate_poseidon_sponge_input(input_size);
+    let inputs = utils::poseidon_sponge_elements_goldilocks(&message);
+    let mut builder = CircuitBuilder::<F, D>::new(CircuitConfig::standard_recursion_config());
+
+    let input_targets: Vec<_> = (0..inputs.len())
+        .map(|_| builder.add_virtual_target())
+        .collect();
+
+    let hash_out = builder.hash_n_to_hash_no_pad::<PoseidonHash>(input_targets.clone());
+    builder.register_public_inputs(&hash_out.elements);
+
+    let mut pw = PartialWitness::new();
+    for (target, input) in input_targets.iter().zip(inputs) {
+        pw.set_target(*target, F::from_canonical_u64(input))
+            .map_err(BenchError::setup)?;
+    }
+
+    let n_gates = builder.num_gates();
+    Ok((builder.build::<C>(), pw, n_gates))
+}
+
+pub fn keccak256_prepare(
+    input_size: usize,
+) -> Result<(CircuitData<F, C, D>, PartialWitness<F>, usize), BenchError> {
     let (msg, hash) = utils::generate_keccak_input(input_size);
 
     let msg_bits = array_to_bits_lsb(&msg);
@@ -102,7 +145,8 @@ pub fn keccak256_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWit
     let mut pw = PartialWitness::new();
 
     for (i, msg_bit) in msg_bits.iter().enumerate().take(len) {
-        pw.set_bool_target(input_targets[i], *msg_bit).unwrap();
+        pw.set_bool_target(input_targets[i], *msg_bit)
+            .map_err(BenchError::setup)?;
     }
 
     let expected_res = array_to_bits_lsb(hash.as_slice());
@@ -115,7 +159,14 @@ pub fn keccak256_prepare(input_size: usize) -> (CircuitData<F, C, D>, PartialWit
     }
 
     let n_gates = builder.num_gates();
-    (builder.build::<C>(), pw, n_gates)
+    Ok((builder.build::<C>(), pw, n_gates))
+}
+
+/// Gates of the circuit, with the wire values of every row of the padded circuit as the witness.
+pub fn constraint_count(circuit_data: &CircuitData<F, C, D>, n_gates: usize) -> ConstraintCount {
+    let common = &circuit_data.common;
+    let cells = common.degree() * common.config.num_wires;
+    ConstraintCount::with_witness(n_gates, WitnessSize::new(cells as u64, 8)) // Goldilocks
 }
 
 pub fn compute_u32_preprocessing_size(circuit_data: &CircuitData<F, C, D>) -> usize {
@@ -137,13 +188,112 @@ pub fn compute_u32_preprocessing_size(circuit_data: &CircuitData<F, C, D>) -> us
 pub fn verify_proof(
     (circuit_data, _pw, _): &(CircuitData<F, C, D>, PartialWitness<F>, usize),
     proof: &ProofWithPublicInputs<GoldilocksField, C, D>,
-) {
+) -> Result<(), BenchError> {
     let verifier_data = circuit_data.verifier_data();
-    verify(&verifier_data, proof.clone());
+    verify(&verifier_data, proof.clone())
 }
 
-pub fn compute_proof_size(proof: &ProofWithPublicInputs<GoldilocksField, C, D>) -> usize {
-    let mut buffer = Vec::new();
-    buffer.write_proof(&proof.proof).unwrap();
-    buffer.len()
+/// Size of the serialized proof, split into the Merkle caps of the wire, permutation and
+/// quotient commitments, the opened values, and the FRI proof. The public inputs are serialized
+/// apart from the proof.
+pub fn compute_proof_size(proof: &ProofWithPublicInputs<GoldilocksField, C, D>) -> ProofSize {
+    fn serialized_len(write: impl FnOnce(&mut Vec<u8>) -> IoResult<()>) -> usize {
+        let mut buffer = Vec::new();
+        write(&mut buffer).unwrap();
+        buffer.len()
+    }
+
+    let inner = &proof.proof;
+    let commitments = [
+        &inner.wires_cap,
+        &inner.plonk_zs_partial_products_cap,
+        &inner.quotient_polys_cap,
+    ]
+    .into_iter()
+    .map(|cap| serialized_len(|buffer| buffer.write_merkle_cap(cap)))
+    .sum();
+    ProofSize::with_breakdown(
+        serialized_len(|buffer| buffer.write_proof(inner)),
+        [
+            ("commitments", commitments),
+            (
+                "openings",
+                serialized_len(|buffer| buffer.write_opening_set(&inner.openings)),
+            ),
+            (
+                "fri",
+                serialized_len(|buffer| buffer.write_fri_proof::<F, C, D>(&inner.opening_proof)),
+            ),
+            (
+                "public_values",
+                serialized_len(|buffer| buffer.write_field_vec(&proof.public_inputs)),
+            ),
+        ],
+    )
+}
+
+pub fn export_artifacts(
+    (circuit_data, _pw, _): &(CircuitData<F, C, D>, PartialWitness<F>, usize),
+    proof: &ProofWithPublicInputs<GoldilocksField, C, D>,
+    gate_serializer: &dyn GateSerializer<F, D>,
+) -> ExportedArtifacts {
+    let verification_key = circuit_data
+        .verifier_data()
+        .to_bytes(gate_serializer)
+        .unwrap();
+    let public_inputs = proof
+        .public_inputs
+        .iter()
+        .flat_map(|x| x.to_canonical_u64().to_le_bytes())
+        .collect();
+    ExportedArtifacts {
+        proof: proof.to_bytes(),
+        verification_key,
+        public_inputs,
+        encoding: "plonky2",
+    }
+}
+
+pub fn export_u32_artifacts(
+    prepared: &(CircuitData<F, C, D>, PartialWitness<F>, usize),
+    proof: &ProofWithPublicInputs<GoldilocksField, C, D>,
+) -> ExportedArtifacts {
+    export_artifacts(prepared, proof, &U32GateSerializer)
+}
+
+pub fn export_default_artifacts(
+    prepared: &(CircuitData<F, C, D>, PartialWitness<F>, usize),
+    proof: &ProofWithPublicInputs<GoldilocksField, C, D>,
+) -> ExportedArtifacts {
+    export_artifacts(prepared, proof, &DefaultGateSerializer)
+}
+
+/// Verifies a proof exported with [`export_artifacts`]. The verifier data may have been
+/// serialized with either the U32 or the default gate serializer.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let verifier_data =
+        VerifierCircuitData::<F, C, D>::from_bytes(verification_key.to_vec(), &U32GateSerializer)
+            .or_else(|_| {
+                VerifierCircuitData::<F, C, D>::from_bytes(
+                    verification_key.to_vec(),
+                    &DefaultGateSerializer,
+                )
+            })
+            .map_err(|e| anyhow!("failed to deserialize verifier data: {:?}", e))?;
+    let proof =
+        ProofWithPublicInputs::<F, C, D>::from_bytes(proof.to_vec(), &verifier_data.common)?;
+    let proof_public_inputs: Vec<u8> = proof
+        .public_inputs
+        .iter()
+        .flat_map(|x| x.to_canonical_u64().to_le_bytes())
+        .collect();
+    ensure!(
+        proof_public_inputs == public_inputs,
+        "public inputs mismatch"
+    );
+    verifier_data.verify(proof)
 }
@@ -1,16 +1,9 @@
-use clap::Parser;
-use plonky2_circuits::bench::{poseidon_prepare, prove};
-
-#[derive(Parser, Debug)]
-struct Args {
-    /// Number of field elements to hash
-    #[arg(long)]
-    input_size: usize,
-}
+use plonky2_circuits::bench::{poseidon_prepare, prove, verify_proof};
 
 fn main() {
-    let args = Args::parse();
-
-    let (circuit_data, pw, _) = poseidon_prepare(args.input_size);
-    let _ = prove(&circuit_data, pw);
+    utils::bench::mem::run_with_verify(
+        poseidon_prepare,
+        |(data, pw, _)| prove(data, pw.clone()),
+        verify_proof,
+    );
 }
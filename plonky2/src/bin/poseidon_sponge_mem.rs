@@ -0,0 +1,9 @@
+use plonky2_circuits::bench::{poseidon_sponge_prepare, prove, verify_proof};
+
+fn main() {
+    utils::bench::mem::run_with_verify(
+        poseidon_sponge_prepare,
+        |(data, pw, _)| prove(data, pw.clone()),
+        verify_proof,
+    );
+}
@@ -1,10 +1,19 @@
 use std::borrow::Cow;
 use utils::harness::{AuditStatus, BenchProperties};
+use utils::security::{FriParams, GOLDILOCKS_EXT2_BITS};
 
 pub mod bench;
 pub mod keccak256;
 pub mod sha256;
 
+/// FRI parameters of `CircuitConfig::standard_recursion_config`, which all benches use.
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 3,
+    queries: 28,
+    pow_bits: 16,
+    field_bits: GOLDILOCKS_EXT2_BITS,
+};
+
 pub const PLONKY2_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     proving_system: Cow::Borrowed("Plonky2"), // https://github.com/0xPolygonZero/plonky2/blob/main/plonky2/plonky2.pdf
     field_curve: Cow::Borrowed("Goldilocks"), // https://github.com/0xPolygonZero/plonky2/blob/main/plonky2/plonky2.pdf
@@ -13,9 +22,13 @@ pub const PLONKY2_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     arithm: Cow::Borrowed("Plonkish"), // https://github.com/0xPolygonZero/plonky2/blob/main/plonky2/plonky2.pdf
     is_zk: true, // https://github.com/0xPolygonZero/plonky2/blob/main/plonky2/plonky2.pdf
     is_zkvm: false,
-    security_bits: 100, // https://github.com/0xPolygonZero/plonky2?tab=readme-ov-file#security
-    is_pq: true,        // hash-based PCS
+    security_bits: FRI_PARAMS.conjectured_security_bits(), // https://github.com/0xPolygonZero/plonky2?tab=readme-ov-file#security
+    is_pq: true,                                           // hash-based PCS
     is_maintained: false, // deprecated: https://github.com/0xPolygonZero/plonky2?tab=readme-ov-file#%EF%B8%8F-plonky2-deprecation-notice
     is_audited: AuditStatus::Audited, // https://github.com/0xPolygonZero/plonky2/tree/main/audits
     isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("1.1")),
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
 };
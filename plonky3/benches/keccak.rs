@@ -0,0 +1,3 @@
+use plonky3::KeccakSuite;
+
+utils::define_benchmark_suite!(KeccakSuite);
@@ -0,0 +1,3 @@
+use plonky3::poseidon::PoseidonSuite;
+
+utils::define_benchmark_suite!(PoseidonSuite);
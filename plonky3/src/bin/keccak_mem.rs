@@ -0,0 +1,5 @@
+use plonky3::{prepare, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare, prove, verify);
+}
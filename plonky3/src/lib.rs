@@ -0,0 +1,297 @@
+//! Keccak-256 proved with Plonky3's uni-stark prover: the upstream `p3-keccak-air` over BabyBear
+//! with the two-adic FRI PCS and Keccak Merkle trees, as in Plonky3's own Keccak example.
+//!
+//! The AIR proves Keccak-f permutations, one per 24 rows. The sponge around them (absorbing the
+//! padded message, chaining the permutations and squeezing the digest) runs natively in
+//! [`prepare`]. [`poseidon`] proves Poseidon2 over Goldilocks the same way.
+
+pub mod poseidon;
+
+use p3_baby_bear::BabyBear;
+use p3_challenger::{HashChallenger, SerializingChallenger32};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_fri::{FriConfig, TwoAdicFriPcs};
+use p3_keccak::Keccak256Hash;
+use p3_keccak_air::{KeccakAir, NUM_KECCAK_COLS, NUM_ROUNDS, generate_trace_rows};
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{CompressionFunctionFromHasher, SerializingHasher32};
+use p3_uni_stark::{StarkConfig, get_max_constraint_degree, get_symbolic_constraints};
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::circuit_size::{CircuitSize, TracePadding};
+use utils::harness::{
+    AuditStatus, BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite,
+    ProvingSystem,
+};
+use utils::security::{BABYBEAR_EXT4_BITS, FriParams};
+use utils::witness_size::WitnessSize;
+
+type Val = BabyBear;
+type Challenge = BinomialExtensionField<Val, 4>;
+type ByteHash = Keccak256Hash;
+type FieldHash = SerializingHasher32<ByteHash>;
+type Compress = CompressionFunctionFromHasher<ByteHash, 2, 32>;
+type ValMmcs = MerkleTreeMmcs<Val, u8, FieldHash, Compress, 32>;
+type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+type Dft = Radix2DitParallel<Val>;
+type Challenger = SerializingChallenger32<Val, HashChallenger<u8, ByteHash, 32>>;
+type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+type Config = StarkConfig<Pcs, Challenge, Challenger>;
+
+pub type Proof = p3_uni_stark::Proof<Config>;
+
+/// Keccak-256 rate in bytes (17 lanes).
+const RATE_BYTES: usize = 136;
+
+/// FRI parameters: the blowup of Plonky3's Keccak example, with enough queries and grinding to
+/// reach the size of the challenge field.
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 1,
+    queries: 100,
+    pow_bits: 24,
+    field_bits: BABYBEAR_EXT4_BITS,
+};
+
+pub fn plonky3_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Plonky3",
+        "BabyBear",
+        "FRI",
+        Some("FRI"),
+        "AIR",
+        false, // uni-stark does not blind the trace
+        false,
+        FRI_PARAMS.conjectured_security_bits(),
+        true,                    // hash-based PCS
+        true,                    // https://github.com/Plonky3/Plonky3
+        AuditStatus::NotAudited, // no published audit of uni-stark
+        None,
+    )
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed(
+            "proves the Keccak-f permutations only; absorbing, chaining and the digest are native",
+        ),
+    })
+}
+
+fn config() -> Config {
+    let byte_hash = ByteHash {};
+    let val_mmcs = ValMmcs::new(FieldHash::new(byte_hash), Compress::new(byte_hash));
+    let fri_config = FriConfig {
+        log_blowup: FRI_PARAMS.log_blowup as usize,
+        log_final_poly_len: 0,
+        num_queries: FRI_PARAMS.queries as usize,
+        proof_of_work_bits: FRI_PARAMS.pow_bits as usize,
+        mmcs: ChallengeMmcs::new(val_mmcs.clone()),
+    };
+    Config::new(Pcs::new(Dft::default(), val_mmcs, fri_config))
+}
+
+fn challenger() -> Challenger {
+    Challenger::from_hasher(vec![], ByteHash {})
+}
+
+/// The inputs of the Keccak-f permutations of one message.
+pub struct PreparedKeccak {
+    pub permutation_inputs: Vec<[u64; 25]>,
+}
+
+pub fn prepare(input_size: usize) -> Result<PreparedKeccak, BenchError> {
+    let (message, digest) = utils::generate_keccak_input(input_size);
+    let (permutation_inputs, actual) = sponge(&message);
+    if actual != digest {
+        return Err(BenchError::wrong_output(digest, actual));
+    }
+    Ok(PreparedKeccak { permutation_inputs })
+}
+
+/// Absorbs the pad10*1-padded `message` into the Keccak-256 sponge, returning the state entering
+/// every permutation and the digest.
+fn sponge(message: &[u8]) -> (Vec<[u64; 25]>, Vec<u8>) {
+    let mut padded = message.to_vec();
+    padded.push(0x01);
+    padded.resize(padded.len().next_multiple_of(RATE_BYTES), 0);
+    *padded.last_mut().expect("non-empty padding") |= 0x80;
+
+    let mut state = [0u64; 25];
+    let mut inputs = Vec::with_capacity(padded.len() / RATE_BYTES);
+    for block in padded.chunks_exact(RATE_BYTES) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().expect("8-byte lane"));
+        }
+        inputs.push(state);
+        keccak::f1600(&mut state);
+    }
+    let digest = state[..4]
+        .iter()
+        .flat_map(|lane| lane.to_le_bytes())
+        .collect();
+    (inputs, digest)
+}
+
+pub fn prove(prepared: &PreparedKeccak) -> Result<Proof, BenchError> {
+    let trace = tracing::info_span!("generate_trace")
+        .in_scope(|| generate_trace_rows::<Val>(prepared.permutation_inputs.clone()));
+    Ok(p3_uni_stark::prove(
+        &config(),
+        &KeccakAir {},
+        &mut challenger(),
+        trace,
+        &vec![],
+    ))
+}
+
+pub fn verify(_prepared: &PreparedKeccak, proof: &Proof) -> Result<(), BenchError> {
+    p3_uni_stark::verify(&config(), &KeccakAir {}, &mut challenger(), proof, &vec![])
+        .map_err(|e| BenchError::verify(format!("{e:?}")))
+}
+
+/// Constraints of the AIR, enforced on every row.
+pub fn num_constraints() -> usize {
+    get_symbolic_constraints::<Val, _>(&KeccakAir {}, 0, 0).len()
+}
+
+/// Cells of the main trace: one row per round of every permutation, padded to a power of two.
+pub fn witness_size(prepared: &PreparedKeccak) -> WitnessSize {
+    let rows = (prepared.permutation_inputs.len() * NUM_ROUNDS).next_power_of_two();
+    WitnessSize::new((rows * NUM_KECCAK_COLS) as u64, 4) // BabyBear
+}
+
+/// Main trace of the AIR, one row per round padded as [`witness_size`] pads it, with the
+/// highest degree of its constraints.
+pub fn circuit_size(prepared: &PreparedKeccak) -> CircuitSize {
+    CircuitSize::from_trace(
+        (prepared.permutation_inputs.len() * NUM_ROUNDS) as u64,
+        Some(TracePadding::PowerOfTwo),
+        Some(NUM_KECCAK_COLS as u64),
+    )
+    .with_constraint_degree(get_max_constraint_degree::<Val, _>(&KeccakAir {}, 0, 0) as u64)
+}
+
+/// uni-stark has no preprocessed trace.
+pub fn preprocessing_size(_prepared: &PreparedKeccak) -> usize {
+    0
+}
+
+pub fn proof_size(proof: &Proof) -> usize {
+    bincode::serialize(proof).map(|v| v.len()).unwrap_or(0)
+}
+
+/// Verification key of the Keccak AIR. uni-stark has no key of its own: the AIR and the STARK
+/// configuration are built into the verifier, so the key only names the AIR to verify against.
+const KECCAK_AIR: &[u8] = b"p3-keccak-air";
+
+/// The proof, serialized with bincode. The AIR proves the permutations without public values.
+pub fn export_artifacts(
+    _prepared: &PreparedKeccak,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(BenchError::setup)?,
+        verification_key: KECCAK_AIR.to_vec(),
+        public_inputs: Vec::new(),
+        encoding: "bincode",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`] or [`poseidon::export_artifacts`]
+/// against the AIR the key names.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    anyhow::ensure!(public_inputs.is_empty(), "the AIR has no public values");
+    match verification_key {
+        KECCAK_AIR => {
+            let proof: Proof = bincode::deserialize(proof)?;
+            p3_uni_stark::verify(&config(), &KeccakAir {}, &mut challenger(), &proof, &vec![])
+                .map_err(|e| anyhow::anyhow!("verification failed: {e:?}"))
+        }
+        poseidon::POSEIDON2_AIR => poseidon::verify_proof_bytes(proof),
+        _ => anyhow::bail!(
+            "unknown AIR {:?}",
+            String::from_utf8_lossy(verification_key)
+        ),
+    }
+}
+
+/// Keccak-256 with the AIR statistics of [`circuit_size`] next to the constraint count.
+pub struct KeccakSuite;
+
+impl BenchmarkSuite for KeccakSuite {
+    type Prepared = PreparedKeccak;
+    type Proof = Proof;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        BenchHarnessConfig {
+            target: BenchTarget::Keccak,
+            system: ProvingSystem::Plonky3,
+            feature: None,
+            mem_binary_name: "keccak_mem_plonky3",
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        plonky3_bench_properties()
+    }
+
+    fn prepare(&self, size: usize) -> Result<PreparedKeccak, BenchError> {
+        prepare(size)
+    }
+
+    fn prove(&self, prepared: &PreparedKeccak) -> Result<Proof, BenchError> {
+        prove(prepared)
+    }
+
+    fn verify(&self, prepared: &PreparedKeccak, proof: &Proof) -> Result<(), BenchError> {
+        verify(prepared, proof)
+    }
+
+    fn num_constraints(&self, _prepared: &PreparedKeccak) -> usize {
+        num_constraints()
+    }
+
+    fn witness_size(&self, prepared: &PreparedKeccak) -> Option<WitnessSize> {
+        Some(witness_size(prepared))
+    }
+
+    fn preprocessing_size(&self, prepared: &PreparedKeccak) -> Result<usize, BenchError> {
+        Ok(preprocessing_size(prepared))
+    }
+
+    fn proof_size(&self, proof: &Proof) -> usize {
+        proof_size(proof)
+    }
+
+    fn circuit_size(&self, prepared: &PreparedKeccak) -> Option<CircuitSize> {
+        Some(circuit_size(prepared))
+    }
+
+    fn export_artifacts(
+        &self,
+        prepared: &PreparedKeccak,
+        proof: &Proof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        export_artifacts(prepared, proof).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::{Digest, Keccak256};
+
+    #[test]
+    fn test_sponge_matches_keccak256() {
+        for len in [0, 135, 136, 137, 271, 272] {
+            let message: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let (inputs, digest) = sponge(&message);
+            assert_eq!(inputs.len(), len / RATE_BYTES + 1);
+            assert_eq!(digest, Keccak256::digest(&message).to_vec());
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Poseidon2 over Goldilocks proved with Plonky3's uni-stark prover: the upstream
+//! `p3-poseidon2-air` with the Goldilocks linear layers, next to Plonky2's native Poseidon gate
+//! over the same field.
+//!
+//! The input elements are hashed as Plonky2's `hash_n_to_hash_no_pad` does: a width-12 sponge
+//! overwriting its first 8 elements with every chunk of the input, then permuting. The AIR proves
+//! one permutation per row; the sponge around them runs natively in [`prepare`], as for the
+//! Keccak AIR of this crate.
+
+use p3_air::BaseAir;
+use p3_challenger::{HashChallenger, SerializingChallenger64};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::FieldAlgebra;
+use p3_field::extension::BinomialExtensionField;
+use p3_fri::{FriConfig, TwoAdicFriPcs};
+use p3_goldilocks::{GenericPoseidon2LinearLayersGoldilocks, Goldilocks, Poseidon2Goldilocks};
+use p3_keccak::Keccak256Hash;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_poseidon2::ExternalLayerConstants;
+use p3_poseidon2_air::{Poseidon2Air, RoundConstants, generate_trace_rows};
+use p3_symmetric::{CompressionFunctionFromHasher, Permutation, SerializingHasher64};
+use p3_uni_stark::{StarkConfig, get_max_constraint_degree, get_symbolic_constraints};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::circuit_size::CircuitSize;
+use utils::harness::{
+    AuditStatus, BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite,
+    ProvingSystem,
+};
+use utils::security::{FriParams, GOLDILOCKS_EXT2_BITS};
+use utils::witness_size::WitnessSize;
+
+type Val = Goldilocks;
+type Challenge = BinomialExtensionField<Val, 2>;
+type ByteHash = Keccak256Hash;
+type FieldHash = SerializingHasher64<ByteHash>;
+type Compress = CompressionFunctionFromHasher<ByteHash, 2, 32>;
+type ValMmcs = MerkleTreeMmcs<Val, u8, FieldHash, Compress, 32>;
+type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+type Dft = Radix2DitParallel<Val>;
+type Challenger = SerializingChallenger64<Val, HashChallenger<u8, ByteHash, 32>>;
+type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+type Config = StarkConfig<Pcs, Challenge, Challenger>;
+
+pub type Proof = p3_uni_stark::Proof<Config>;
+
+/// Width and rate of Plonky2's Poseidon sponge.
+const WIDTH: usize = 12;
+const RATE: usize = 8;
+
+/// Round numbers of Poseidon2 over Goldilocks at width 12 for 128 bits, with the degree-7 S-box
+/// split by one committed register so the constraints stay of degree 3.
+const SBOX_DEGREE: u64 = 7;
+const SBOX_REGISTERS: usize = 1;
+const HALF_FULL_ROUNDS: usize = 4;
+const PARTIAL_ROUNDS: usize = 22;
+
+type Air = Poseidon2Air<
+    Val,
+    GenericPoseidon2LinearLayersGoldilocks,
+    WIDTH,
+    SBOX_DEGREE,
+    SBOX_REGISTERS,
+    HALF_FULL_ROUNDS,
+    PARTIAL_ROUNDS,
+>;
+type Constants = RoundConstants<Val, WIDTH, HALF_FULL_ROUNDS, PARTIAL_ROUNDS>;
+
+/// Seed of the round constants, shared by the AIR and the native permutation.
+const CONSTANTS_SEED: u64 = 1;
+
+/// FRI parameters: the blowup the degree-3 constraints allow, with enough queries and grinding
+/// to reach the size of the challenge field.
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 1,
+    queries: 100,
+    pow_bits: 28,
+    field_bits: GOLDILOCKS_EXT2_BITS,
+};
+
+pub fn poseidon_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Plonky3",
+        "Goldilocks",
+        "FRI",
+        Some("FRI"),
+        "AIR",
+        false, // uni-stark does not blind the trace
+        false,
+        FRI_PARAMS.conjectured_security_bits(),
+        true,                    // hash-based PCS
+        true,                    // https://github.com/Plonky3/Plonky3
+        AuditStatus::NotAudited, // no published audit of uni-stark
+        None,
+    )
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed(
+            "proves Poseidon2 permutations only; absorbing, chaining and the digest are native",
+        ),
+    })
+}
+
+fn config() -> Config {
+    let byte_hash = ByteHash {};
+    let val_mmcs = ValMmcs::new(FieldHash::new(byte_hash), Compress::new(byte_hash));
+    let fri_config = FriConfig {
+        log_blowup: FRI_PARAMS.log_blowup as usize,
+        log_final_poly_len: 0,
+        num_queries: FRI_PARAMS.queries as usize,
+        proof_of_work_bits: FRI_PARAMS.pow_bits as usize,
+        mmcs: ChallengeMmcs::new(val_mmcs.clone()),
+    };
+    Config::new(Pcs::new(Dft::default(), val_mmcs, fri_config))
+}
+
+fn challenger() -> Challenger {
+    Challenger::from_hasher(vec![], ByteHash {})
+}
+
+/// The round constants of the AIR and the native permutation with the same constants.
+fn round_constants() -> (Constants, Poseidon2Goldilocks<WIDTH>) {
+    let mut rng = SmallRng::seed_from_u64(CONSTANTS_SEED);
+    let beginning: [[Val; WIDTH]; HALF_FULL_ROUNDS] = rng.r#gen();
+    let partial: [Val; PARTIAL_ROUNDS] = rng.r#gen();
+    let ending: [[Val; WIDTH]; HALF_FULL_ROUNDS] = rng.r#gen();
+    let permutation = Poseidon2Goldilocks::new(
+        ExternalLayerConstants::new(beginning.to_vec(), ending.to_vec()),
+        partial.to_vec(),
+    );
+    (Constants::new(beginning, partial, ending), permutation)
+}
+
+/// The inputs of the permutations of one hash, with the AIR proving them.
+pub struct PreparedPoseidon {
+    air: Air,
+    constants: Constants,
+    pub permutation_inputs: Vec<[Val; WIDTH]>,
+}
+
+pub fn prepare(input_size: usize) -> Result<PreparedPoseidon, BenchError> {
+    let inputs: Vec<Val> = utils::generate_poseidon_input_goldilocks(input_size)
+        .into_iter()
+        .map(Val::from_canonical_u64)
+        .collect();
+    let (constants, permutation) = round_constants();
+    Ok(PreparedPoseidon {
+        air: Air::new(constants.clone()),
+        constants,
+        permutation_inputs: sponge(&permutation, &inputs),
+    })
+}
+
+/// Absorbs `inputs` into the sponge without padding, returning the state entering every
+/// permutation. The digest would be the first 4 elements of the state after the last one.
+fn sponge(permutation: &Poseidon2Goldilocks<WIDTH>, inputs: &[Val]) -> Vec<[Val; WIDTH]> {
+    let mut state = [Val::ZERO; WIDTH];
+    let mut states = Vec::with_capacity(inputs.len().div_ceil(RATE));
+    for chunk in inputs.chunks(RATE) {
+        state[..chunk.len()].copy_from_slice(chunk);
+        states.push(state);
+        permutation.permute_mut(&mut state);
+    }
+    states
+}
+
+/// Rows of the trace: one per permutation, padded with permutations of zero to a power of two
+/// of at least a few rows.
+fn trace_inputs(prepared: &PreparedPoseidon) -> Vec<[Val; WIDTH]> {
+    let mut inputs = prepared.permutation_inputs.clone();
+    inputs.resize(inputs.len().next_power_of_two().max(8), [Val::ZERO; WIDTH]);
+    inputs
+}
+
+pub fn prove(prepared: &PreparedPoseidon) -> Result<Proof, BenchError> {
+    let trace = tracing::info_span!("generate_trace").in_scope(|| {
+        generate_trace_rows::<
+            Val,
+            GenericPoseidon2LinearLayersGoldilocks,
+            WIDTH,
+            SBOX_DEGREE,
+            SBOX_REGISTERS,
+            HALF_FULL_ROUNDS,
+            PARTIAL_ROUNDS,
+        >(trace_inputs(prepared), &prepared.constants)
+    });
+    Ok(p3_uni_stark::prove(
+        &config(),
+        &prepared.air,
+        &mut challenger(),
+        trace,
+        &vec![],
+    ))
+}
+
+pub fn verify(prepared: &PreparedPoseidon, proof: &Proof) -> Result<(), BenchError> {
+    p3_uni_stark::verify(&config(), &prepared.air, &mut challenger(), proof, &vec![])
+        .map_err(|e| BenchError::verify(format!("{e:?}")))
+}
+
+/// Constraints of the AIR, enforced on every row.
+pub fn num_constraints(prepared: &PreparedPoseidon) -> usize {
+    get_symbolic_constraints::<Val, _>(&prepared.air, 0, 0).len()
+}
+
+/// Cells of the main trace, padded as [`prove`] pads it.
+pub fn witness_size(prepared: &PreparedPoseidon) -> WitnessSize {
+    let rows = trace_inputs(prepared).len();
+    WitnessSize::new((rows * BaseAir::<Val>::width(&prepared.air)) as u64, 8) // Goldilocks
+}
+
+/// Main trace of the AIR, one row per permutation padded as [`prove`] pads it, with the highest
+/// degree of its constraints.
+pub fn circuit_size(prepared: &PreparedPoseidon) -> CircuitSize {
+    let rows = trace_inputs(prepared).len() as u64;
+    let columns = BaseAir::<Val>::width(&prepared.air) as u64;
+    CircuitSize {
+        trace_length: prepared.permutation_inputs.len() as u64,
+        padded_trace_length: Some(rows),
+        columns: Some(columns),
+        trace_area: Some(rows * columns),
+        constraint_degree: Some(get_max_constraint_degree::<Val, _>(&prepared.air, 0, 0) as u64),
+    }
+}
+
+/// uni-stark has no preprocessed trace.
+pub fn preprocessing_size(_prepared: &PreparedPoseidon) -> usize {
+    0
+}
+
+pub fn proof_size(proof: &Proof) -> usize {
+    bincode::serialize(proof).map(|v| v.len()).unwrap_or(0)
+}
+
+/// Verification key of the Poseidon2 AIR, which names it as `KECCAK_AIR` names the Keccak AIR.
+pub(crate) const POSEIDON2_AIR: &[u8] = b"p3-poseidon2-air";
+
+/// The proof, serialized with bincode. The AIR proves the permutations without public values.
+pub fn export_artifacts(
+    _prepared: &PreparedPoseidon,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(BenchError::setup)?,
+        verification_key: POSEIDON2_AIR.to_vec(),
+        public_inputs: Vec::new(),
+        encoding: "bincode",
+    })
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the AIR with the round constants
+/// of [`prepare`].
+pub(crate) fn verify_proof_bytes(proof: &[u8]) -> anyhow::Result<()> {
+    let proof: Proof = bincode::deserialize(proof)?;
+    let (constants, _) = round_constants();
+    p3_uni_stark::verify(
+        &config(),
+        &Air::new(constants),
+        &mut challenger(),
+        &proof,
+        &vec![],
+    )
+    .map_err(|e| anyhow::anyhow!("verification failed: {e:?}"))
+}
+
+/// Poseidon2 with the AIR statistics of [`circuit_size`] next to the constraint count.
+pub struct PoseidonSuite;
+
+impl BenchmarkSuite for PoseidonSuite {
+    type Prepared = PreparedPoseidon;
+    type Proof = Proof;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        BenchHarnessConfig {
+            target: BenchTarget::Poseidon,
+            system: ProvingSystem::Plonky3,
+            feature: None,
+            mem_binary_name: "poseidon_mem_plonky3",
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        poseidon_bench_properties()
+    }
+
+    fn prepare(&self, size: usize) -> Result<PreparedPoseidon, BenchError> {
+        prepare(size)
+    }
+
+    fn prove(&self, prepared: &PreparedPoseidon) -> Result<Proof, BenchError> {
+        prove(prepared)
+    }
+
+    fn verify(&self, prepared: &PreparedPoseidon, proof: &Proof) -> Result<(), BenchError> {
+        verify(prepared, proof)
+    }
+
+    fn num_constraints(&self, prepared: &PreparedPoseidon) -> usize {
+        num_constraints(prepared)
+    }
+
+    fn witness_size(&self, prepared: &PreparedPoseidon) -> Option<WitnessSize> {
+        Some(witness_size(prepared))
+    }
+
+    fn preprocessing_size(&self, prepared: &PreparedPoseidon) -> Result<usize, BenchError> {
+        Ok(preprocessing_size(prepared))
+    }
+
+    fn proof_size(&self, proof: &Proof) -> usize {
+        proof_size(proof)
+    }
+
+    fn circuit_size(&self, prepared: &PreparedPoseidon) -> Option<CircuitSize> {
+        Some(circuit_size(prepared))
+    }
+
+    fn export_artifacts(
+        &self,
+        prepared: &PreparedPoseidon,
+        proof: &Proof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        export_artifacts(prepared, proof).map(Some)
+    }
+}
@@ -0,0 +1,57 @@
+use gkr_engine::MPIConfig;
+use sha256_expander_benchmark::EXPANDER_BENCH_PROPERTIES;
+use sha256_expander_benchmark::keccak::{
+    export_artifacts, get_constraints, prepare, prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Keccak,
+    ProvingSystem::Expander,
+    None,
+    "keccak_mem_expander",
+    EXPANDER_BENCH_PROPERTIES,
+    {
+        let universe = MPIConfig::init().expect("Failed to initialize MPI");
+        let world = universe.world();
+        (universe, world)
+    },
+    |size, _| prepare(size),
+    |(circuit_bytes, witness_bytes), (universe, world)| get_constraints(
+        circuit_bytes,
+        witness_bytes,
+        MPIConfig::prover_new(Some(universe), Some(world))
+    ),
+    |(circuit_bytes, witness_bytes), (universe, world)| {
+        let (_, proof) = prove(
+            circuit_bytes,
+            witness_bytes,
+            MPIConfig::prover_new(Some(universe), Some(world)),
+        )?;
+        Ok(proof)
+    },
+    |(circuit_bytes, witness_bytes), proof, (universe, world)| {
+        let (claimed, _) = prove(
+            circuit_bytes,
+            witness_bytes,
+            MPIConfig::prover_new(Some(universe), Some(world)),
+        )?;
+        verify(
+            circuit_bytes,
+            witness_bytes,
+            proof,
+            &claimed,
+            MPIConfig::prover_new(Some(universe), Some(world)),
+        )
+    },
+    |(circuit_bytes, _), _| { circuit_bytes.len() },
+    |proof, _shared| proof.bytes.len(),
+    export_artifacts = |(circuit_bytes, witness_bytes), proof, (universe, world)| {
+        let (claimed, _) = prove(
+            circuit_bytes,
+            witness_bytes,
+            MPIConfig::prover_new(Some(universe), Some(world)),
+        )?;
+        export_artifacts(circuit_bytes, witness_bytes, proof, &claimed)
+    }
+);
@@ -1,6 +1,6 @@
 use gkr_engine::MPIConfig;
 use sha256_expander_benchmark::EXPANDER_BENCH_PROPERTIES;
-use sha256_expander_benchmark::bench::{get_constraints, prepare, prove, verify};
+use sha256_expander_benchmark::bench::{export_artifacts, get_constraints, prepare, prove, verify};
 use utils::harness::ProvingSystem;
 
 utils::define_benchmark_harness!(
@@ -25,23 +25,31 @@ utils::define_benchmark_harness!(
             circuit_bytes,
             witness_bytes,
             MPIConfig::prover_new(Some(universe), Some(world)),
-        );
-        proof
+        )?;
+        Ok(proof)
     },
     |(circuit_bytes, witness_bytes), proof, (universe, world)| {
         let (claimed, _) = prove(
             circuit_bytes,
             witness_bytes,
             MPIConfig::prover_new(Some(universe), Some(world)),
-        );
+        )?;
         verify(
             circuit_bytes,
             witness_bytes,
             proof,
             &claimed,
             MPIConfig::prover_new(Some(universe), Some(world)),
-        );
+        )
     },
     |(circuit_bytes, _), _| { circuit_bytes.len() },
-    |proof, _shared| proof.bytes.len()
+    |proof, _shared| proof.bytes.len(),
+    export_artifacts = |(circuit_bytes, witness_bytes), proof, (universe, world)| {
+        let (claimed, _) = prove(
+            circuit_bytes,
+            witness_bytes,
+            MPIConfig::prover_new(Some(universe), Some(world)),
+        )?;
+        export_artifacts(circuit_bytes, witness_bytes, proof, &claimed)
+    }
 );
@@ -15,44 +15,41 @@ fn main() {
     let utils_metadata = root.join("src/metadata.rs");
     let contents = fs::read_to_string(&utils_metadata).expect("read src/metadata.rs");
 
-    generate_circuit(&root, &out_dir, &contents, "BYTE_INPUTS_FULL", "sha256");
+    generate_circuit(&root, &out_dir, &contents, &["BYTE_INPUTS_FULL"], "sha256");
     generate_circuit(
         &root,
         &out_dir,
         &contents,
-        "FIELD_ELEMENT_INPUTS_FULL",
+        &["BYTE_INPUTS_FULL", "KECCAK_BOUNDARY_INPUTS"],
+        "keccak",
+    );
+    generate_circuit(
+        &root,
+        &out_dir,
+        &contents,
+        &["FIELD_ELEMENT_INPUTS_FULL"],
         "poseidon",
     );
 
     println!("cargo:rerun-if-env-changed=BENCH_INPUT_PROFILE");
     println!("cargo:rerun-if-changed=templates/sha256_sizes.rs.tpl");
+    println!("cargo:rerun-if-changed=templates/keccak_sizes.rs.tpl");
     println!("cargo:rerun-if-changed=templates/poseidon_sizes.rs.tpl");
     println!("cargo:rerun-if-changed={}", utils_metadata.display());
 }
 
-fn generate_circuit(root: &Path, out_dir: &Path, contents: &str, const_name: &str, name: &str) {
-    // Parse input sizes from const
-    let mut sizes: Vec<String> = Vec::new();
-    if let Some(id_start) = contents.find(const_name) {
-        let after_id = &contents[id_start..];
-        if let Some(eq_rel) = after_id.find('=') {
-            let after_eq = &after_id[eq_rel + 1..];
-            if let Some(lb_rel) = after_eq.find('[') {
-                let after_lb = &after_eq[lb_rel + 1..];
-                if let Some(rb_rel) = after_lb.find(']') {
-                    let inner = &after_lb[..rb_rel];
-                    sizes = inner
-                        .split(',')
-                        .map(|s| s.trim())
-                        .filter(|s| !s.is_empty())
-                        .filter_map(|s| s.parse::<usize>().ok())
-                        .map(|n| n.to_string())
-                        .collect();
-                }
-            }
-        }
-    }
-    assert!(!sizes.is_empty(), "Failed to parse {}", const_name);
+fn generate_circuit(root: &Path, out_dir: &Path, contents: &str, const_names: &[&str], name: &str) {
+    // Union of the input sizes of the consts
+    let mut sizes: Vec<usize> = const_names
+        .iter()
+        .flat_map(|const_name| {
+            let sizes = parse_sizes(contents, const_name);
+            assert!(!sizes.is_empty(), "Failed to parse {}", const_name);
+            sizes
+        })
+        .collect();
+    sizes.sort_unstable();
+    sizes.dedup();
 
     // Load and process template
     let template_path = root.join(format!("templates/{name}_sizes.rs.tpl"));
@@ -76,14 +73,16 @@ fn generate_circuit(root: &Path, out_dir: &Path, contents: &str, const_name: &st
 
     let mut decls_rendered = String::new();
     for size in &sizes {
-        let rendered = decl_snippet.replace("{{LEN}}", size);
+        let rendered = decl_snippet
+            .replace("{{LEN_BITS}}", &(size * 8).to_string())
+            .replace("{{LEN}}", &size.to_string());
         decls_rendered.push_str(rendered.trim_start_matches('\n'));
         decls_rendered.push('\n');
     }
 
     let mut arms_rendered = String::new();
     for size in &sizes {
-        let rendered = match_arm_snippet.replace("{{LEN}}", size);
+        let rendered = match_arm_snippet.replace("{{LEN}}", &size.to_string());
         arms_rendered.push_str(rendered.trim_start_matches('\n'));
     }
 
@@ -98,3 +97,27 @@ fn generate_circuit(root: &Path, out_dir: &Path, contents: &str, const_name: &st
 
     fs::write(out_dir.join(format!("{name}_sizes.rs")), final_out).expect("write generated file");
 }
+
+/// Parses the sizes of `const NAME: [usize; N] = [..];`.
+fn parse_sizes(contents: &str, const_name: &str) -> Vec<usize> {
+    let mut sizes = Vec::new();
+    if let Some(id_start) = contents.find(&format!("const {const_name}:")) {
+        let after_id = &contents[id_start..];
+        if let Some(eq_rel) = after_id.find('=') {
+            let after_eq = &after_id[eq_rel + 1..];
+            if let Some(lb_rel) = after_eq.find('[') {
+                let after_lb = &after_eq[lb_rel + 1..];
+                if let Some(rb_rel) = after_lb.find(']') {
+                    let inner = &after_lb[..rb_rel];
+                    sizes = inner
+                        .split(',')
+                        .map(|s| s.trim())
+                        .filter(|s| !s.is_empty())
+                        .filter_map(|s| s.parse::<usize>().ok())
+                        .collect();
+                }
+            }
+        }
+    }
+    sizes
+}
@@ -18,6 +18,9 @@ use poly_commit::OrionPCSForGKR;
 use serdes::ExpSerde;
 use std::io::Cursor;
 use transcript::BytesHashTranscript;
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::BenchError;
+use utils::witness_size::{ConstraintCount, WitnessSize};
 
 // Constants and circuit definition
 const OUTPUT_LEN: usize = 32; // SHA-256 digest length
@@ -64,11 +67,12 @@ pub fn prove(
     circuit_bytes: &[u8],
     witness_bytes: &[u8],
     mpi_config: MPIConfig<'_>,
-) -> (M31Ext3, Proof) {
+) -> Result<(M31Ext3, Proof), BenchError> {
     // Taken from Circuit::prover_load_circuit
     let circuit_opt = if mpi_config.is_root() {
         let rc: RecursiveCircuit<M31x1Config> =
-            RecursiveCircuit::deserialize_from(Cursor::new(circuit_bytes)).unwrap();
+            RecursiveCircuit::deserialize_from(Cursor::new(circuit_bytes))
+                .map_err(|e| BenchError::prove(format!("{e:?}")))?;
         let circuit = rc.flatten();
         Some(circuit)
     } else {
@@ -79,26 +83,29 @@ pub fn prove(
     circuit.pre_process_gkr();
 
     // Taken from circuit.prover_load_witness_file
-    circuit.load_witness_bytes(witness_bytes, &mpi_config, true, false);
+    tracing::info_span!("load_witness")
+        .in_scope(|| circuit.load_witness_bytes(witness_bytes, &mpi_config, true, false));
 
-    let proof = expander_prove::<M31SingleConfig>(&mut circuit, mpi_config.clone());
+    let proof = tracing::info_span!("gkr_prove")
+        .in_scope(|| expander_prove::<M31SingleConfig>(&mut circuit, mpi_config.clone()));
 
     // Clean up shared memory
     circuit.discard_control_of_shared_mem();
     mpi_config.free_shared_mem(&mut window);
 
-    proof
+    Ok(proof)
 }
 
 pub fn get_constraints(
     circuit_bytes: &[u8],
     _witness_bytes: &[u8],
     mpi_config: MPIConfig<'_>,
-) -> usize {
+) -> Result<ConstraintCount, BenchError> {
     // Taken from Circuit::prover_load_circuit
     let circuit_opt = if mpi_config.is_root() {
         let rc: RecursiveCircuit<M31x1Config> =
-            RecursiveCircuit::deserialize_from(Cursor::new(circuit_bytes)).unwrap();
+            RecursiveCircuit::deserialize_from(Cursor::new(circuit_bytes))
+                .map_err(|e| BenchError::setup(format!("{e:?}")))?;
         let circuit = rc.flatten();
         Some(circuit)
     } else {
@@ -113,12 +120,17 @@ pub fn get_constraints(
         .iter()
         .map(|layer| layer.add.len() + layer.mul.len() + layer.const_.len() + layer.uni.len())
         .sum();
+    // The witness is the input layer of the circuit, M31 values
+    let witness_len = 1u64 << circuit.layers[0].input_var_num;
 
     // Clean up shared memory
     circuit.discard_control_of_shared_mem();
     mpi_config.free_shared_mem(&mut window);
 
-    n_constraints
+    Ok(ConstraintCount::with_witness(
+        n_constraints,
+        WitnessSize::new(witness_len, 4),
+    ))
 }
 
 pub fn verify(
@@ -127,10 +139,10 @@ pub fn verify(
     proof: &Proof,
     claimed_v: &M31Ext3,
     mpi_config: MPIConfig<'_>,
-) {
+) -> Result<(), BenchError> {
     // Taken from Circuit::verifier_load_circuit
-    let rc: RecursiveCircuit<M31x1Config> =
-        ExpSerde::deserialize_from(Cursor::new(circuit_bytes)).unwrap();
+    let rc: RecursiveCircuit<M31x1Config> = ExpSerde::deserialize_from(Cursor::new(circuit_bytes))
+        .map_err(|e| BenchError::verify(format!("{e:?}")))?;
     let mut circuit = rc.flatten();
     circuit.pre_process_gkr();
 
@@ -138,19 +150,61 @@ pub fn verify(
     circuit.load_witness_bytes(witness_bytes, &mpi_config, false, false);
 
     let verifier = Verifier::<M31SingleConfig>::new(mpi_config);
-    assert!(expander_verify::<M31SingleConfig>(
-        &mut circuit,
-        verifier.mpi_config,
-        proof,
-        claimed_v
-    ));
+    BenchError::check(
+        expander_verify::<M31SingleConfig>(&mut circuit, verifier.mpi_config, proof, claimed_v),
+        "GKR proof rejected",
+    )
+}
+
+/// The claimed output value followed by the proof, the layered circuit as the verification key,
+/// and the witness as the public inputs: Expander's verifier reads the public inputs from the
+/// witness, and its proofs are not zero-knowledge anyway.
+pub fn export_artifacts(
+    circuit_bytes: &[u8],
+    witness_bytes: &[u8],
+    proof: &Proof,
+    claimed_v: &M31Ext3,
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut proof_bytes = Vec::new();
+    claimed_v
+        .serialize_into(&mut proof_bytes)
+        .and_then(|()| proof.serialize_into(&mut proof_bytes))
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+    Ok(ExportedArtifacts {
+        proof: proof_bytes,
+        verification_key: circuit_bytes.to_vec(),
+        public_inputs: witness_bytes.to_vec(),
+        encoding: "expander-serdes",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`] in a single verifier process, without
+/// initializing MPI.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let mut reader = Cursor::new(proof);
+    let claimed_v = M31Ext3::deserialize_from(&mut reader)
+        .map_err(|e| anyhow::anyhow!("invalid claimed value: {e:?}"))?;
+    let proof = Proof::deserialize_from(&mut reader)
+        .map_err(|e| anyhow::anyhow!("invalid proof: {e:?}"))?;
+    verify(
+        verification_key,
+        public_inputs,
+        &proof,
+        &claimed_v,
+        MPIConfig::verifier_new(1),
+    )?;
+    Ok(())
 }
 
 // Macro generating a match arm that prepares the circuit and witness for the given input size
 macro_rules! prepare_arm {
     ($Circuit:ident, $LEN:expr) => {{
-        let compile_result =
-            compile(&$Circuit::<Variable>::default(), CompileOptions::default()).unwrap();
+        let compile_result = compile(&$Circuit::<Variable>::default(), CompileOptions::default())
+            .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
         const LEN: usize = $LEN;
         let (message_bytes, output) = utils::generate_sha256_input(LEN);
@@ -166,7 +220,7 @@ macro_rules! prepare_arm {
         let witness = compile_result
             .witness_solver
             .solve_witness_with_hints(&assignment, &EmptyHintCaller)
-            .unwrap();
+            .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
         serialize_outputs(compile_result, witness)
     }};
@@ -175,21 +229,23 @@ macro_rules! prepare_arm {
 pub fn serialize_outputs(
     compile_result: CompileResult<M31SingleConfig>,
     witness: Witness<M31SingleConfig>,
-) -> (Vec<u8>, Vec<u8>) {
+) -> Result<(Vec<u8>, Vec<u8>), BenchError> {
     let mut circuit_bytes = Vec::new();
     compile_result
         .layered_circuit
         .serialize_into(&mut circuit_bytes)
-        .unwrap();
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
     let mut witness_bytes = Vec::new();
-    witness.serialize_into(&mut witness_bytes).unwrap();
+    witness
+        .serialize_into(&mut witness_bytes)
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
-    (circuit_bytes, witness_bytes)
+    Ok((circuit_bytes, witness_bytes))
 }
 
 /// Prepare the circuit and witness for the given input size
 /// (invokes `match` for all input sizes that was generated by `../build.rs`)
-pub fn prepare(input_len: usize) -> (Vec<u8>, Vec<u8>) {
+pub fn prepare(input_len: usize) -> Result<(Vec<u8>, Vec<u8>), BenchError> {
     match_sha2_sizes!(input_len, prepare_arm)
 }
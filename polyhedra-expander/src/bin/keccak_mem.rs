@@ -0,0 +1,19 @@
+use gkr_engine::MPIConfig;
+use sha256_expander_benchmark::keccak::{prepare, prove};
+
+fn main() {
+    let universe = MPIConfig::init().expect("Failed to initialize MPI");
+    let world = universe.world();
+    utils::bench::mem::run(
+        // The compiled circuit and witness are cached, so the measured runs only prove.
+        |input_size| {
+            utils::bench::mem::cached(&format!("expander_keccak_{input_size}"), || {
+                prepare(input_size)
+            })
+        },
+        |(circuit_bytes, witness_bytes)| {
+            let mpi_config = MPIConfig::prover_new(Some(&universe), Some(&world));
+            prove(circuit_bytes, witness_bytes, mpi_config)
+        },
+    );
+}
@@ -0,0 +1,197 @@
+use expander_compiler::frontend::*;
+use mersenne31::M31;
+use utils::harness::BenchError;
+
+// Reuse shared types and functions from bench module
+pub use crate::bench::{M31SingleConfig, export_artifacts, get_constraints, prove, verify};
+
+/// Keccak-256 rate in bytes (17 lanes).
+const RATE_BYTES: usize = 136;
+/// Keccak-256 digest length in bits.
+const OUTPUT_BITS: usize = 256;
+
+/// Keccak-f[1600] round constants.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rho rotation offsets, indexed `[x][y]`.
+const ROTATIONS: [[usize; 5]; 5] = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+];
+
+// Includes the circuits that were generated by '../build.rs'
+include!(concat!(env!("OUT_DIR"), "/keccak_sizes.rs"));
+
+/// Bit `z` of lane `(x, y)` in a state of 1600 bits.
+fn bit(x: usize, y: usize, z: usize) -> usize {
+    (x + 5 * y) * 64 + z
+}
+
+/// `a + b - 2ab`, the XOR of two bits.
+fn xor<C: Config, B: RootAPI<C>>(api: &mut B, a: Variable, b: Variable) -> Variable {
+    let ab = api.mul(a, b);
+    let sum = api.add(a, b);
+    let twice = api.add(ab, ab);
+    api.sub(sum, twice)
+}
+
+fn keccak_f<C: Config, B: RootAPI<C>>(api: &mut B, state: &mut [Variable]) {
+    let one = api.constant(1);
+    for round_constant in ROUND_CONSTANTS {
+        // Theta
+        let mut parities = Vec::with_capacity(5 * 64);
+        for x in 0..5 {
+            for z in 0..64 {
+                let mut parity = state[bit(x, 0, z)];
+                for y in 1..5 {
+                    parity = xor(api, parity, state[bit(x, y, z)]);
+                }
+                parities.push(parity);
+            }
+        }
+        for x in 0..5 {
+            for z in 0..64 {
+                let left = parities[((x + 4) % 5) * 64 + z];
+                let right = parities[((x + 1) % 5) * 64 + (z + 63) % 64];
+                let d = xor(api, left, right);
+                for y in 0..5 {
+                    state[bit(x, y, z)] = xor(api, state[bit(x, y, z)], d);
+                }
+            }
+        }
+
+        // Rho and pi only move bits.
+        let mut moved = state.to_vec();
+        for x in 0..5 {
+            for y in 0..5 {
+                for z in 0..64 {
+                    moved[bit(y, (2 * x + 3 * y) % 5, z)] =
+                        state[bit(x, y, (z + 64 - ROTATIONS[x][y]) % 64)];
+                }
+            }
+        }
+
+        // Chi: a ^ (!b & c), with !b & c = c - bc.
+        for x in 0..5 {
+            for y in 0..5 {
+                for z in 0..64 {
+                    let b = moved[bit((x + 1) % 5, y, z)];
+                    let c = moved[bit((x + 2) % 5, y, z)];
+                    let bc = api.mul(b, c);
+                    let not_b_and_c = api.sub(c, bc);
+                    state[bit(x, y, z)] = xor(api, moved[bit(x, y, z)], not_b_and_c);
+                }
+            }
+        }
+
+        // Iota flips the bits of lane (0, 0) set in the round constant.
+        for z in (0..64).filter(|z| round_constant >> z & 1 == 1) {
+            state[bit(0, 0, z)] = api.sub(one, state[bit(0, 0, z)]);
+        }
+    }
+}
+
+/// Keccak-256 over the message bits (least significant bit of each byte first). The pad10*1
+/// padding depends only on the length, so it is a constant of the circuit.
+pub fn check_keccak<C: Config, B: RootAPI<C>>(
+    api: &mut B,
+    data: &[Variable], //  msg bits ‖ digest bits
+) -> Vec<Variable> {
+    let msg_bits = data.len() - OUTPUT_BITS;
+    let (message, expected) = data.split_at(msg_bits);
+    for &b in message {
+        api.assert_is_bool(b);
+    }
+
+    let mut padding = vec![0u8; (msg_bits / 8 / RATE_BYTES + 1) * RATE_BYTES - msg_bits / 8];
+    padding[0] = 0x01;
+    *padding.last_mut().expect("non-empty padding") |= 0x80;
+    let mut padded = message.to_vec();
+    for byte in padding {
+        for i in 0..8 {
+            padded.push(api.constant(u32::from(byte >> i & 1)));
+        }
+    }
+
+    let zero = api.constant(0);
+    let mut state = vec![zero; 1600];
+    for block in padded.chunks_exact(RATE_BYTES * 8) {
+        for (lane_bit, &b) in state.iter_mut().zip(block) {
+            *lane_bit = xor(api, *lane_bit, b);
+        }
+        keccak_f(api, &mut state);
+    }
+
+    // The digest is the first four lanes, little-endian.
+    for (&computed, &digest_bit) in state.iter().zip(expected) {
+        api.assert_is_equal(computed, digest_bit);
+    }
+    state.truncate(OUTPUT_BITS);
+    state
+}
+
+fn to_bits(bytes: &[u8]) -> impl Iterator<Item = M31> + '_ {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| M31::from(u32::from(byte >> i & 1))))
+}
+
+// Macro generating a match arm that prepares the circuit and witness for the given input size
+macro_rules! prepare_arm {
+    ($Circuit:ident, $LEN:expr) => {{
+        let compile_result = compile(&$Circuit::<Variable>::default(), CompileOptions::default())
+            .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+
+        const LEN: usize = $LEN;
+        let (message_bytes, digest) = utils::generate_keccak_input(LEN);
+
+        let mut assignment = $Circuit::<M31>::default();
+        for (i, input_bit) in to_bits(&message_bytes).enumerate() {
+            assignment.input[i] = input_bit;
+        }
+        for (i, output_bit) in to_bits(&digest).enumerate() {
+            assignment.output[i] = output_bit;
+        }
+
+        let witness = compile_result
+            .witness_solver
+            .solve_witness_with_hints(&assignment, &EmptyHintCaller)
+            .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+
+        crate::bench::serialize_outputs(compile_result, witness)
+    }};
+}
+
+/// Prepare the circuit and witness for the given input size
+/// (invokes `match` for all input sizes that was generated by `../build.rs`)
+pub fn prepare(input_len: usize) -> Result<(Vec<u8>, Vec<u8>), BenchError> {
+    match_keccak_sizes!(input_len, prepare_arm)
+}
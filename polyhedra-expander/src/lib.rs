@@ -1,9 +1,11 @@
 use std::borrow::Cow;
 
 pub mod bench;
+pub mod keccak;
 pub mod metadata;
 pub mod poseidon;
 
+pub use bench::verify_artifacts;
 pub use utils::harness::{AuditStatus, BenchProperties};
 
 pub const EXPANDER_BENCH_PROPERTIES: BenchProperties = BenchProperties {
@@ -19,4 +21,8 @@ pub const EXPANDER_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     is_maintained: true,
     is_audited: AuditStatus::NotAudited,
     isa: None,
+    prover_resource: None,
+    version: None, // tracks the main branch
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
 };
@@ -3,9 +3,10 @@ use circuit_std_rs::poseidon::utils::*;
 use circuit_std_rs::utils::register_hint;
 use expander_compiler::frontend::*;
 use mersenne31::M31;
+use utils::harness::BenchError;
 
 // Reuse shared types and functions from bench module
-pub use crate::bench::{M31SingleConfig, get_constraints, prove, verify};
+pub use crate::bench::{M31SingleConfig, export_artifacts, get_constraints, prove, verify};
 
 // Includes the circuits that were generated by '../build.rs'
 include!(concat!(env!("OUT_DIR"), "/poseidon_sizes.rs"));
@@ -33,8 +34,8 @@ pub fn check_poseidon<C: Config, B: RootAPI<C>>(api: &mut B, data: &[Variable])
 // Macro generating a match arm that prepares the circuit and witness for the given input size
 macro_rules! prepare_arm {
     ($Circuit:ident, $LEN:expr) => {{
-        let compile_result =
-            compile(&$Circuit::<Variable>::default(), CompileOptions::default()).unwrap();
+        let compile_result = compile(&$Circuit::<Variable>::default(), CompileOptions::default())
+            .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
         const LEN: usize = $LEN;
         let inputs = utils::generate_poseidon_input_m31(LEN);
@@ -54,13 +55,13 @@ macro_rules! prepare_arm {
         let witness = compile_result
             .witness_solver
             .solve_witness_with_hints(&assignment, &mut hint_registry)
-            .unwrap();
+            .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
         crate::bench::serialize_outputs(compile_result, witness)
     }};
 }
 
 /// Prepare the circuit and witness for the given input size
-pub fn prepare(input_len: usize) -> (Vec<u8>, Vec<u8>) {
+pub fn prepare(input_len: usize) -> Result<(Vec<u8>, Vec<u8>), BenchError> {
     match_poseidon_sizes!(input_len, prepare_arm)
 }
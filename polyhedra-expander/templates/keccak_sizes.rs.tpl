@@ -0,0 +1,28 @@
+// Auto-generated. Do not edit.
+
+// BEGIN_DECL
+declare_circuit!(KeccakCircuit{{LEN}} { input: [Variable; {{LEN_BITS}}], output: [Variable; OUTPUT_BITS], });
+impl Define<M31SingleConfig> for KeccakCircuit{{LEN}}<Variable> {
+    fn define<Builder: RootAPI<M31SingleConfig>>(&self, api: &mut Builder) {
+        let mut data = self.input.to_vec();
+        data.extend(self.output.to_vec());
+        api.memorized_simple_call(|api, data| check_keccak(api, data), &data);
+    }
+}
+// END_DECL
+
+{{CIRCUIT_DECLS}}
+
+macro_rules! match_keccak_sizes {
+    ($input_len:expr, $arm:ident) => { match $input_len {
+{{MATCH_ARMS}}
+        _ => Err(utils::harness::BenchError::setup(format!(
+            "unsupported input length: {}",
+            $input_len
+        ))),
+    }};
+}
+
+// BEGIN_MATCH_ARM
+        {{LEN}} => $arm!(KeccakCircuit{{LEN}}, {{LEN}}),
+// END_MATCH_ARM
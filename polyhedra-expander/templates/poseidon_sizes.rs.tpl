@@ -18,7 +18,10 @@ impl Define<M31SingleConfig> for PoseidonCircuit{{LEN}}<Variable> {
 macro_rules! match_poseidon_sizes {
     ($input_len:expr, $arm:ident) => { match $input_len {
 {{MATCH_ARMS}}
-        _ => panic!("unsupported input length: {}", $input_len),
+        _ => Err(utils::harness::BenchError::setup(format!(
+            "unsupported input length: {}",
+            $input_len
+        ))),
     }};
 }
 
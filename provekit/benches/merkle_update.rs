@@ -0,0 +1,20 @@
+use provekit::{
+    PROVEKIT_PROPS, constraint_count, export_artifacts, prepare_merkle_update, preprocessing_size,
+    prove, verify,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::MerkleUpdate,
+    ProvingSystem::Provekit,
+    None,
+    "merkle_update_mem_provekit",
+    PROVEKIT_PROPS,
+    prepare_merkle_update,
+    |(proof_scheme, _, _)| constraint_count(proof_scheme),
+    |(proof_scheme, toml_path, _)| { prove(proof_scheme, toml_path) },
+    |(proof_scheme, _, _), proof| verify(proof, proof_scheme),
+    |(_, _, circuit_path)| { preprocessing_size(circuit_path) },
+    |proof| { proof.whir_r1cs_proof.transcript.len() },
+    export_artifacts = |(_, _, circuit_path), proof| export_artifacts(proof, circuit_path)
+);
@@ -0,0 +1,3 @@
+use provekit::gnark::Sha256GnarkSuite;
+
+utils::define_benchmark_suite!(Sha256GnarkSuite);
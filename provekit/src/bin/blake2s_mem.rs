@@ -0,0 +1,9 @@
+use provekit::{prepare_blake2s, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(
+        prepare_blake2s,
+        |(scheme, toml_path, _pre_size)| prove(scheme, toml_path),
+        |(scheme, _, _), proof| verify(proof, scheme),
+    );
+}
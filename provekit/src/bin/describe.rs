@@ -0,0 +1,7 @@
+use provekit::BENCH_FEATURES;
+use utils::features::print_description;
+use utils::harness::ProvingSystem;
+
+fn main() {
+    print_description(ProvingSystem::Provekit, BENCH_FEATURES);
+}
@@ -1,16 +1,9 @@
-use clap::Parser;
-use provekit::{prepare_ecdsa, prove};
-
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size parameter
-    #[arg(long)]
-    input_size: usize,
-}
+use provekit::{prepare_ecdsa, prove, verify};
 
 fn main() {
-    let args = Args::parse();
-
-    let (scheme, toml_path, _pre_size) = prepare_ecdsa(args.input_size);
-    let _proof = prove(&scheme, &toml_path);
+    utils::bench::mem::run_with_verify(
+        prepare_ecdsa,
+        |(scheme, toml_path, _pre_size)| prove(scheme, toml_path),
+        |(scheme, _, _), proof| verify(proof, scheme),
+    );
 }
@@ -1,14 +1,9 @@
-use clap::Parser;
-use provekit::{prepare_keccak, prove};
-
-#[derive(Parser, Debug)]
-struct Args {
-    #[arg(long)]
-    input_size: usize,
-}
+use provekit::{prepare_keccak, prove, verify};
 
 fn main() {
-    let args = Args::parse();
-    let (scheme, toml_path, _pre_size) = prepare_keccak(args.input_size);
-    let _proof = prove(&scheme, &toml_path);
+    utils::bench::mem::run_with_verify(
+        prepare_keccak,
+        |(scheme, toml_path, _pre_size)| prove(scheme, toml_path),
+        |(scheme, _, _), proof| verify(proof, scheme),
+    );
 }
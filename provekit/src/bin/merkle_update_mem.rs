@@ -0,0 +1,9 @@
+use provekit::{prepare_merkle_update, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(
+        prepare_merkle_update,
+        |(scheme, toml_path, _pre_size)| prove(scheme, toml_path),
+        |(scheme, _, _), proof| verify(proof, scheme),
+    );
+}
@@ -0,0 +1,9 @@
+use provekit::{prepare_poseidon_sponge, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(
+        prepare_poseidon_sponge,
+        |(scheme, toml_path, _pre_size)| prove(scheme, toml_path),
+        |(scheme, _, _), proof| verify(proof, scheme),
+    );
+}
@@ -0,0 +1,5 @@
+use provekit::gnark::{prepare_sha256_gnark, wrap};
+
+fn main() {
+    utils::bench::mem::run(prepare_sha256_gnark, wrap);
+}
@@ -1,16 +1,9 @@
-use clap::Parser;
-use provekit::{prepare_sha256, prove};
-
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size parameter
-    #[arg(long)]
-    input_size: usize,
-}
+use provekit::{prepare_sha256, prove, verify};
 
 fn main() {
-    let args = Args::parse();
-
-    let (scheme, toml_path, _pre_size) = prepare_sha256(args.input_size);
-    let _proof = prove(&scheme, &toml_path);
+    utils::bench::mem::run_with_verify(
+        prepare_sha256,
+        |(scheme, toml_path, _pre_size)| prove(scheme, toml_path),
+        |(scheme, _, _), proof| verify(proof, scheme),
+    );
 }
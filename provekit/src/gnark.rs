@@ -0,0 +1,174 @@
+//! Gnark wrapping of ProveKit proofs for on-chain verification.
+//!
+//! ProveKit compresses a WHIR proof by verifying it inside a Gnark Groth16 circuit: the
+//! `recursive-verifier` Go program of the ProveKit repository reads the WHIR parameters and
+//! transcript written by `provekit-gnark` together with the R1CS, and proves their verification.
+//! The benchmark times that wrapping stage; the WHIR proof it wraps is made during `prepare`.
+//! Its metrics also list both verification paths: the native WHIR verifier and the Groth16
+//! proof (see [`utils::verification`]).
+
+use crate::{PROVEKIT_PROPS, prepare_sha256, preprocessing_size, prove, verify};
+use provekit_common::{NoirProof, NoirProofScheme};
+use provekit_gnark::write_gnark_parameters_to_file;
+use std::borrow::Cow;
+use std::fs;
+use std::path::{Path, PathBuf};
+use utils::harness::{
+    BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite, ProvingSystem,
+};
+use utils::verification::VerificationPath;
+
+/// Checkout of ProveKit's `recursive-verifier` directory, at the rev pinned in `Cargo.toml`.
+pub const RECURSIVE_VERIFIER_DIR_ENV: &str = "PROVEKIT_RECURSIVE_VERIFIER_DIR";
+
+const PARAMS_FILE: &str = "params_for_recursive_verifier";
+const R1CS_FILE: &str = "r1cs.json";
+/// Groth16 proof written by the recursive verifier into its working directory.
+const PROOF_FILE: &str = "proof";
+
+/// The wrapped proof is a Groth16 proof over BN254: no longer post-quantum, but verifiable by
+/// Gnark's Solidity verifier.
+pub const PROVEKIT_GNARK_PROPS: BenchProperties = BenchProperties {
+    proving_system: Cow::Borrowed("Spartan+WHIR+Groth16"),
+    is_pq: false,
+    onchain_verifier: Some(Cow::Borrowed("evm")),
+    ..PROVEKIT_PROPS
+};
+
+/// A WHIR proof and the Gnark inputs derived from it, in `dir`.
+pub struct GnarkWrap {
+    pub scheme: NoirProofScheme,
+    pub proof: NoirProof,
+    pub circuit_path: PathBuf,
+    pub dir: PathBuf,
+}
+
+pub fn prepare_sha256_gnark(input_size: usize) -> Result<GnarkWrap, BenchError> {
+    let (scheme, toml_path, circuit_path) = prepare_sha256(input_size)?;
+    let proof = prove(&scheme, &toml_path)?;
+    let dir = toml_path
+        .parent()
+        .expect("Prover.toml has a parent directory")
+        .join("gnark");
+    write_gnark_inputs(&scheme, &proof, &dir)?;
+    Ok(GnarkWrap {
+        scheme,
+        proof,
+        circuit_path,
+        dir,
+    })
+}
+
+fn write_gnark_inputs(
+    scheme: &NoirProofScheme,
+    proof: &NoirProof,
+    dir: &Path,
+) -> Result<(), BenchError> {
+    fs::create_dir_all(dir)?;
+    write_gnark_parameters_to_file(
+        &scheme.whir_for_witness,
+        &proof.whir_r1cs_proof.transcript,
+        &scheme.create_io_pattern(),
+        scheme.whir_for_witness.m_0,
+        scheme.whir_for_witness.m,
+        dir.join(PARAMS_FILE)
+            .to_str()
+            .expect("gnark input path is not UTF-8"),
+    );
+    let r1cs = serde_json::to_string(&scheme.r1cs).map_err(BenchError::setup)?;
+    fs::write(dir.join(R1CS_FILE), r1cs)?;
+    Ok(())
+}
+
+/// Runs the recursive verifier on the inputs in `wrap.dir` and returns the Groth16 proof. The
+/// recursive verifier checks the proof it produced before exiting.
+pub fn wrap(wrap: &GnarkWrap) -> Result<Vec<u8>, BenchError> {
+    let verifier_dir = std::env::var(RECURSIVE_VERIFIER_DIR_ENV).map_err(|_| {
+        BenchError::setup(format!(
+            "{RECURSIVE_VERIFIER_DIR_ENV} must point to ProveKit's recursive-verifier"
+        ))
+    })?;
+    let proof_path = Path::new(&verifier_dir).join(PROOF_FILE);
+    let _ = fs::remove_file(&proof_path);
+    let output = utils::sandbox::command("go")
+        .args(["run", "./cmd/cli", "--config"])
+        .arg(wrap.dir.join(PARAMS_FILE))
+        .arg("--r1cs")
+        .arg(wrap.dir.join(R1CS_FILE))
+        .current_dir(&verifier_dir)
+        .output()
+        .map_err(|e| BenchError::tool("go", e))?;
+    if !output.status.success() {
+        return Err(BenchError::tool(
+            "go",
+            format!(
+                "Gnark wrapping failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        ));
+    }
+    fs::read(&proof_path)
+        .map_err(|e| BenchError::prove(format!("no Groth16 proof was written: {e}")))
+}
+
+/// The `provekit_gnark` sha256 bench: times the wrapping and reports the native and wrapped
+/// verification paths of each proof.
+pub struct Sha256GnarkSuite;
+
+impl BenchmarkSuite for Sha256GnarkSuite {
+    type Prepared = GnarkWrap;
+    type Proof = Vec<u8>;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        BenchHarnessConfig {
+            target: BenchTarget::Sha256,
+            system: ProvingSystem::Provekit,
+            feature: Some("gnark"),
+            mem_binary_name: "sha256_gnark_mem_provekit",
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        PROVEKIT_GNARK_PROPS
+    }
+
+    fn prepare(&self, size: usize) -> Result<GnarkWrap, BenchError> {
+        prepare_sha256_gnark(size)
+    }
+
+    fn prove(&self, prepared: &GnarkWrap) -> Result<Vec<u8>, BenchError> {
+        wrap(prepared)
+    }
+
+    // The recursive verifier only writes the proof after verifying it.
+    fn verify(&self, _prepared: &GnarkWrap, proof: &Vec<u8>) -> Result<(), BenchError> {
+        BenchError::check(!proof.is_empty(), "empty Groth16 proof")
+    }
+
+    fn num_constraints(&self, prepared: &GnarkWrap) -> usize {
+        prepared.scheme.r1cs.num_constraints()
+    }
+
+    fn preprocessing_size(&self, prepared: &GnarkWrap) -> Result<usize, BenchError> {
+        Ok(preprocessing_size(&prepared.circuit_path))
+    }
+
+    fn preprocessing_blob(&self, prepared: &GnarkWrap) -> Option<Vec<u8>> {
+        std::fs::read(&prepared.circuit_path).ok()
+    }
+
+    fn proof_size(&self, proof: &Vec<u8>) -> usize {
+        proof.len()
+    }
+
+    /// The WHIR proof verified natively, and the Groth16 proof, which the recursive verifier
+    /// checks in the Go process that made it without a standalone verifier to time.
+    fn verification_paths(&self, prepared: &GnarkWrap, proof: &Vec<u8>) -> Vec<VerificationPath> {
+        vec![
+            VerificationPath::native(prepared.proof.whir_r1cs_proof.transcript.len(), || {
+                verify(&prepared.proof, &prepared.scheme).unwrap()
+            }),
+            VerificationPath::wrapped("groth16", proof.len(), None),
+        ]
+    }
+}
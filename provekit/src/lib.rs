@@ -5,16 +5,48 @@ use provekit_verifier::Verify;
 use std::borrow::Cow;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use utils::artifacts::ExportedArtifacts;
+use utils::features::{BenchFeature, FeatureParameter};
 use utils::generate_ecdsa_input;
-use utils::harness::{AuditStatus, BenchProperties};
+use utils::harness::{AuditStatus, BenchError, BenchProperties, BenchTarget};
+use utils::noir::NoirCircuit;
+use utils::witness_size::{ConstraintCount, WitnessSize};
+
+#[cfg(feature = "gnark")]
+pub mod gnark;
 
 const WORKSPACE_ROOT: &str = "circuits";
 const SHA256_CIRCUIT_SUB_PATH: &str = "hash/sha256-provekit";
 const POSEIDON_CIRCUIT_SUB_PATH: &str = "hash/poseidon";
+const POSEIDON_SPONGE_CIRCUIT_SUB_PATH: &str = "hash/poseidon_sponge";
 const KECCAK_CIRCUIT_SUB_PATH: &str = "hash/keccak";
+const BLAKE2S_CIRCUIT_SUB_PATH: &str = "hash/blake2s";
+const MERKLE_UPDATE_CIRCUIT_SUB_PATH: &str = "merkle/merkle_update";
 const ECDSA_CIRCUIT_SUB_PATH: &str = "ecdsa";
 
+/// Feature tags of the ProveKit benches, printed by `describe_provekit`.
+pub const BENCH_FEATURES: &[BenchFeature] = &[BenchFeature {
+    tag: "gnark",
+    description: "Wraps the WHIR proof in a Gnark Groth16 proof for on-chain verification; only the \
+                  wrapping is timed, the WHIR proof is made during prepare.",
+    targets: &[BenchTarget::Sha256],
+    cargo_feature: Some("gnark"),
+    bench: Some("sha256_gnark"),
+    parameters: &[
+        FeatureParameter {
+            name: "wrapper",
+            value: "Groth16 over BN254",
+            description: "Outer proof; constant size but not post-quantum.",
+        },
+        FeatureParameter {
+            name: "PROVEKIT_RECURSIVE_VERIFIER_DIR",
+            value: "path",
+            description: "Checkout of ProveKit's recursive-verifier (Go) that proves the WHIR \
+                          verification.",
+        },
+    ],
+}];
+
 pub const PROVEKIT_PROPS: BenchProperties = BenchProperties {
     proving_system: Cow::Borrowed("Spartan+WHIR"), // https://github.com/worldfnd/provekit
     field_curve: Cow::Borrowed("Bn254"),           // https://github.com/worldfnd/provekit
@@ -28,12 +60,16 @@ pub const PROVEKIT_PROPS: BenchProperties = BenchProperties {
     is_maintained: true, // https://github.com/worldfnd/provekit
     is_audited: AuditStatus::NotAudited,
     isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("7005a93")), // pinned rev
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
 };
 
-fn compile_workspace() -> PathBuf {
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
+fn compile_workspace() -> Result<PathBuf, BenchError> {
+    let current_dir = std::env::current_dir()?;
     let workspace_root = current_dir.join(WORKSPACE_ROOT);
-    let output = Command::new("nargo")
+    let output = utils::sandbox::command("nargo")
         .args([
             "compile",
             "--workspace",
@@ -42,48 +78,34 @@ fn compile_workspace() -> PathBuf {
         ])
         .current_dir(&workspace_root)
         .output()
-        .expect("Failed to run nargo compile");
+        .map_err(|e| BenchError::tool("nargo", e))?;
     if !output.status.success() {
-        panic!(
-            "Workspace compilation failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
+        return Err(BenchError::tool(
+            "nargo",
+            format!(
+                "workspace compilation failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        ));
     }
-    workspace_root
+    Ok(workspace_root)
 }
 
-pub fn prepare_sha256(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf) {
-    // 1) Rewrite circuit input length to match input_size before compiling
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
-    let workspace_root_pre = current_dir.join(WORKSPACE_ROOT);
-    let circuit_source =
-        workspace_root_pre.join("hash/sha256-provekit/sha256_var_input/src/main.nr");
-
-    if let Ok(mut content) = fs::read_to_string(&circuit_source) {
-        // Replace only the input param length in `fn main(input: [u8; N], ...)`
-        if let Some(fn_pos) = content.find("fn main(")
-            && let Some(input_pos_rel) = content[fn_pos..].find("input: [u8;")
-        {
-            let input_pos = fn_pos + input_pos_rel + "input: [u8;".len();
-            // Skip whitespace
-            let bytes = content.as_bytes();
-            let mut start = input_pos;
-            while start < bytes.len() && bytes[start].is_ascii_whitespace() {
-                start += 1;
-            }
-            let mut end = start;
-            while end < bytes.len() && bytes[end].is_ascii_digit() {
-                end += 1;
-            }
-            if start != end {
-                content.replace_range(start..end, &input_size.to_string());
-                fs::write(&circuit_source, content).expect("Failed to update circuit input length");
-            }
-        }
-    }
+pub fn prepare_sha256(
+    input_size: usize,
+) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    // 1) Generate the entry point for input_size before compiling
+    let current_dir = std::env::current_dir()?;
+    NoirCircuit::Sha256Native.write_main(
+        &current_dir
+            .join(WORKSPACE_ROOT)
+            .join(SHA256_CIRCUIT_SUB_PATH)
+            .join("sha256_var_input"),
+        input_size,
+    )?;
 
     // 2) Compile workspace
-    let workspace_root = compile_workspace();
+    let workspace_root = compile_workspace()?;
 
     // 3) Load scheme and prepare TOML matching the chosen size
     let package_name = "sha256_var_input";
@@ -92,11 +114,11 @@ pub fn prepare_sha256(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf)
         .join(format!("{package_name}.json"));
 
     let proof_scheme = NoirProofScheme::from_file(&circuit_path)
-        .unwrap_or_else(|e| panic!("Failed to load proof scheme: {e}"));
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
 
     let dir_name = "sha256_var_input";
     let circuit_member_dir = workspace_root.join(SHA256_CIRCUIT_SUB_PATH).join(dir_name);
-    fs::create_dir_all(&circuit_member_dir).expect("Failed to create circuit dir");
+    fs::create_dir_all(&circuit_member_dir)?;
 
     // Generate exactly `input_size` bytes of input; circuit expects fixed array with `input_size` elements
     let (data, _digest) = utils::generate_sha256_input(input_size);
@@ -109,59 +131,23 @@ pub fn prepare_sha256(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf)
     );
 
     let toml_path = circuit_member_dir.join("Prover.toml");
-    fs::write(&toml_path, toml_content).expect("Failed to write Prover.toml");
+    fs::write(&toml_path, toml_content)?;
 
-    (proof_scheme, toml_path, circuit_path)
+    Ok((proof_scheme, toml_path, circuit_path))
 }
 
-pub fn prepare_poseidon(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf) {
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
-    let workspace_root_pre = current_dir.join(WORKSPACE_ROOT);
-    let circuit_source = workspace_root_pre.join("hash/poseidon/src/main.nr");
-
-    if let Ok(mut content) = fs::read_to_string(&circuit_source) {
-        if let Some(import_pos) = content.find("poseidon::bn254::hash_") {
-            let start = import_pos + "poseidon::bn254::hash_".len();
-            let mut end = start;
-            while end < content.len() && content.as_bytes()[end].is_ascii_digit() {
-                end += 1;
-            }
-            if start != end {
-                content.replace_range(start..end, &input_size.to_string());
-            }
-        }
-
-        if let Some(hash_pos) = content.find("    hash_") {
-            let start = hash_pos + "    hash_".len();
-            let mut end = start;
-            while end < content.len() && content.as_bytes()[end].is_ascii_digit() {
-                end += 1;
-            }
-            if start != end {
-                content.replace_range(start..end, &input_size.to_string());
-            }
-        }
-
-        if let Some(field_pos) = content.find("[Field;") {
-            let start = field_pos + "[Field;".len();
-            let bytes = content.as_bytes();
-            let mut num_start = start;
-            while num_start < bytes.len() && bytes[num_start].is_ascii_whitespace() {
-                num_start += 1;
-            }
-            let mut end = num_start;
-            while end < bytes.len() && bytes[end].is_ascii_digit() {
-                end += 1;
-            }
-            if num_start != end {
-                content.replace_range(num_start..end, &input_size.to_string());
-            }
-        }
-
-        fs::write(&circuit_source, content).expect("Failed to update circuit");
-    }
+pub fn prepare_poseidon(
+    input_size: usize,
+) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    let current_dir = std::env::current_dir()?;
+    NoirCircuit::Poseidon.write_main(
+        &current_dir
+            .join(WORKSPACE_ROOT)
+            .join(POSEIDON_CIRCUIT_SUB_PATH),
+        input_size,
+    )?;
 
-    let workspace_root = compile_workspace();
+    let workspace_root = compile_workspace()?;
 
     let package_name = "poseidon";
     let circuit_path = workspace_root
@@ -169,10 +155,10 @@ pub fn prepare_poseidon(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf
         .join(format!("{package_name}.json"));
 
     let proof_scheme = NoirProofScheme::from_file(&circuit_path)
-        .unwrap_or_else(|e| panic!("Failed to load proof scheme: {e}"));
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
 
     let circuit_member_dir = workspace_root.join(POSEIDON_CIRCUIT_SUB_PATH);
-    fs::create_dir_all(&circuit_member_dir).expect("Failed to create circuit dir");
+    fs::create_dir_all(&circuit_member_dir)?;
 
     let field_elements = utils::generate_poseidon_input_strings(input_size);
     let toml_content = format!(
@@ -185,37 +171,65 @@ pub fn prepare_poseidon(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf
     );
 
     let toml_path = circuit_member_dir.join("Prover.toml");
-    fs::write(&toml_path, toml_content).expect("Failed to write Prover.toml");
+    fs::write(&toml_path, toml_content)?;
 
-    (proof_scheme, toml_path, circuit_path)
+    Ok((proof_scheme, toml_path, circuit_path))
 }
 
-pub fn prepare_keccak(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf) {
-    let current_dir = std::env::current_dir().expect("Failed to get current directory");
-    let workspace_root_pre = current_dir.join(WORKSPACE_ROOT);
-    let circuit_source = workspace_root_pre.join("hash/keccak/src/main.nr");
-
-    if let Ok(mut content) = fs::read_to_string(&circuit_source)
-        && let Some(fn_pos) = content.find("fn main(")
-        && let Some(msg_pos_rel) = content[fn_pos..].find("msg: [u8;")
-    {
-        let msg_pos = fn_pos + msg_pos_rel + "msg: [u8;".len();
-        let bytes = content.as_bytes();
-        let mut start = msg_pos;
-        while start < bytes.len() && bytes[start].is_ascii_whitespace() {
-            start += 1;
-        }
-        let mut end = start;
-        while end < bytes.len() && bytes[end].is_ascii_digit() {
-            end += 1;
-        }
-        if start != end {
-            content.replace_range(start..end, &input_size.to_string());
-            fs::write(&circuit_source, content).expect("Failed to update circuit input length");
-        }
-    }
+pub fn prepare_poseidon_sponge(
+    input_size: usize,
+) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    let current_dir = std::env::current_dir()?;
+    NoirCircuit::PoseidonSponge.write_main(
+        &current_dir
+            .join(WORKSPACE_ROOT)
+            .join(POSEIDON_SPONGE_CIRCUIT_SUB_PATH),
+        input_size,
+    )?;
+
+    let workspace_root = compile_workspace()?;
+
+    let package_name = "poseidon_sponge";
+    let circuit_path = workspace_root
+        .join("target")
+        .join(format!("{package_name}.json"));
 
-    let workspace_root = compile_workspace();
+    let proof_scheme = NoirProofScheme::from_file(&circuit_path)
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
+
+    let circuit_member_dir = workspace_root.join(POSEIDON_SPONGE_CIRCUIT_SUB_PATH);
+    fs::create_dir_all(&circuit_member_dir)?;
+
+    let message = utils::generate_poseidon_sponge_input(input_size);
+    let toml_content = format!(
+        "inputs = [{}]",
+        utils::poseidon_sponge_elements_bn254(&message)
+            .iter()
+            .map(|s| format!("\"{}\"", s))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let toml_path = circuit_member_dir.join("Prover.toml");
+    fs::write(&toml_path, toml_content)?;
+
+    Ok((proof_scheme, toml_path, circuit_path))
+}
+
+pub fn prepare_keccak(
+    input_size: usize,
+) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    // The circuit hashes any `message_size <= N` bytes, so a stale capacity would still prove
+    // but report the constraints of the wrong size: always regenerate it.
+    let current_dir = std::env::current_dir()?;
+    NoirCircuit::Keccak256Native.write_main(
+        &current_dir
+            .join(WORKSPACE_ROOT)
+            .join(KECCAK_CIRCUIT_SUB_PATH),
+        input_size,
+    )?;
+
+    let workspace_root = compile_workspace()?;
 
     let package_name = "keccak";
     let circuit_path = workspace_root
@@ -223,10 +237,10 @@ pub fn prepare_keccak(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf)
         .join(format!("{package_name}.json"));
 
     let proof_scheme = NoirProofScheme::from_file(&circuit_path)
-        .unwrap_or_else(|e| panic!("Failed to load proof scheme: {e}"));
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
 
     let circuit_member_dir = workspace_root.join(KECCAK_CIRCUIT_SUB_PATH);
-    fs::create_dir_all(&circuit_member_dir).expect("Failed to create circuit dir");
+    fs::create_dir_all(&circuit_member_dir)?;
 
     let (data, digest) = utils::generate_keccak_input(input_size);
     let toml_content = format!(
@@ -243,13 +257,112 @@ pub fn prepare_keccak(input_size: usize) -> (NoirProofScheme, PathBuf, PathBuf)
     );
 
     let toml_path = circuit_member_dir.join("Prover.toml");
-    fs::write(&toml_path, toml_content).expect("Failed to write Prover.toml");
+    fs::write(&toml_path, toml_content)?;
 
-    (proof_scheme, toml_path, circuit_path)
+    Ok((proof_scheme, toml_path, circuit_path))
 }
 
-pub fn prepare_ecdsa(_: usize) -> (NoirProofScheme, PathBuf, PathBuf) {
-    let workspace_root = compile_workspace();
+pub fn prepare_blake2s(
+    input_size: usize,
+) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    let current_dir = std::env::current_dir()?;
+    NoirCircuit::Blake2sNative.write_main(
+        &current_dir
+            .join(WORKSPACE_ROOT)
+            .join(BLAKE2S_CIRCUIT_SUB_PATH),
+        input_size,
+    )?;
+
+    let workspace_root = compile_workspace()?;
+
+    let package_name = "blake2s";
+    let circuit_path = workspace_root
+        .join("target")
+        .join(format!("{package_name}.json"));
+
+    let proof_scheme = NoirProofScheme::from_file(&circuit_path)
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
+
+    let circuit_member_dir = workspace_root.join(BLAKE2S_CIRCUIT_SUB_PATH);
+    fs::create_dir_all(&circuit_member_dir)?;
+
+    let (data, digest) = utils::generate_blake2s_input(input_size);
+    let toml_content = format!(
+        "input = [{}]\nresult = [{}]",
+        data.iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+        digest
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+
+    let toml_path = circuit_member_dir.join("Prover.toml");
+    fs::write(&toml_path, toml_content)?;
+
+    Ok((proof_scheme, toml_path, circuit_path))
+}
+
+pub fn prepare_merkle_update(
+    input_size: usize,
+) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    let current_dir = std::env::current_dir()?;
+    NoirCircuit::MerkleUpdate.write_main(
+        &current_dir
+            .join(WORKSPACE_ROOT)
+            .join(MERKLE_UPDATE_CIRCUIT_SUB_PATH),
+        input_size,
+    )?;
+
+    let workspace_root = compile_workspace()?;
+
+    let package_name = "merkle_update";
+    let circuit_path = workspace_root
+        .join("target")
+        .join(format!("{package_name}.json"));
+
+    let proof_scheme = NoirProofScheme::from_file(&circuit_path)
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
+
+    let circuit_member_dir = workspace_root.join(MERKLE_UPDATE_CIRCUIT_SUB_PATH);
+    fs::create_dir_all(&circuit_member_dir)?;
+
+    let batch = utils::generate_merkle_update_input(input_size);
+    let toml_content = format!(
+        "old_root = \"{}\"\nnew_root = \"{}\"\nindices = {}\nold_leaves = {}\nnew_leaves = {}\nsiblings = [{}]",
+        batch.old_root,
+        batch.new_root,
+        quoted_array(batch.updates.iter().map(|update| update.index)),
+        quoted_array(batch.updates.iter().map(|update| update.old_leaf)),
+        quoted_array(batch.updates.iter().map(|update| update.new_leaf)),
+        batch
+            .updates
+            .iter()
+            .map(|update| quoted_array(&update.siblings))
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+
+    let toml_path = circuit_member_dir.join("Prover.toml");
+    fs::write(&toml_path, toml_content)?;
+
+    Ok((proof_scheme, toml_path, circuit_path))
+}
+
+/// TOML array of the elements as quoted decimal strings.
+fn quoted_array<T: ToString>(elements: impl IntoIterator<Item = T>) -> String {
+    let elements: Vec<String> = elements
+        .into_iter()
+        .map(|element| format!("\"{}\"", element.to_string()))
+        .collect();
+    format!("[{}]", elements.join(", "))
+}
+
+pub fn prepare_ecdsa(_: usize) -> Result<(NoirProofScheme, PathBuf, PathBuf), BenchError> {
+    let workspace_root = compile_workspace()?;
 
     let package_name = "p256_bigcurve";
     let circuit_path = workspace_root
@@ -257,11 +370,11 @@ pub fn prepare_ecdsa(_: usize) -> (NoirProofScheme, PathBuf, PathBuf) {
         .join(format!("{package_name}.json"));
 
     let proof_scheme = NoirProofScheme::from_file(&circuit_path)
-        .unwrap_or_else(|e| panic!("Failed to load proof scheme: {e}"));
+        .map_err(|e| BenchError::setup(format!("failed to load proof scheme: {e:#}")))?;
 
     let dir_name = "p256_bigcurve";
     let circuit_member_dir = workspace_root.join(ECDSA_CIRCUIT_SUB_PATH).join(dir_name);
-    fs::create_dir_all(&circuit_member_dir).expect("Failed to create circuit dir");
+    fs::create_dir_all(&circuit_member_dir)?;
 
     let (digest, (pub_key_x, pub_key_y), signature) = generate_ecdsa_input();
     let toml_content = format!(
@@ -289,20 +402,28 @@ pub fn prepare_ecdsa(_: usize) -> (NoirProofScheme, PathBuf, PathBuf) {
     );
 
     let toml_path = circuit_member_dir.join("Prover.toml");
-    fs::write(&toml_path, toml_content).expect("Failed to write Prover.toml");
+    fs::write(&toml_path, toml_content)?;
 
-    (proof_scheme, toml_path, circuit_path)
+    Ok((proof_scheme, toml_path, circuit_path))
 }
 
-pub fn prove(proof_scheme: &NoirProofScheme, toml_path: &Path) -> NoirProof {
+pub fn prove(proof_scheme: &NoirProofScheme, toml_path: &Path) -> Result<NoirProof, BenchError> {
     let prover = Prover::from_noir_proof_scheme(proof_scheme.clone());
-    prover.prove(toml_path).expect("Proof generation failed")
+    prover.prove(toml_path).map_err(BenchError::prove)
 }
 
 /// Verify a proof with the given scheme
-pub fn verify(proof: &NoirProof, proof_scheme: &NoirProofScheme) -> Result<(), &'static str> {
+pub fn verify(proof: &NoirProof, proof_scheme: &NoirProofScheme) -> Result<(), BenchError> {
     let mut verifier = Verifier::from_noir_proof_scheme(proof_scheme.clone());
-    verifier.verify(proof).map_err(|_| "Proof is not valid")
+    verifier.verify(proof).map_err(BenchError::verify)
+}
+
+/// R1CS constraints, with the witness vector of BN254 elements the prover commits to.
+pub fn constraint_count(scheme: &NoirProofScheme) -> ConstraintCount {
+    ConstraintCount::with_witness(
+        scheme.r1cs.num_constraints(),
+        WitnessSize::new(scheme.r1cs.num_witnesses() as u64, 32),
+    )
 }
 
 pub fn preprocessing_size(circuit_path: &Path) -> usize {
@@ -310,3 +431,37 @@ pub fn preprocessing_size(circuit_path: &Path) -> usize {
         .map(|m| m.len())
         .unwrap_or(0) as usize
 }
+
+/// The proof as JSON, public inputs included, and the compiled Noir circuit as the verification
+/// key: the verifier rebuilds the proof scheme from it, as `prepare` does.
+pub fn export_artifacts(
+    proof: &NoirProof,
+    circuit_path: &Path,
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: serde_json::to_vec(proof).map_err(BenchError::setup)?,
+        verification_key: fs::read(circuit_path)?,
+        public_inputs: Vec::new(),
+        encoding: "json+noir-circuit",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`], with the proof scheme of the exported
+/// circuit, written to a directory of this process under `target/provekit/artifacts`.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    _public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/provekit/artifacts")
+        .join(std::process::id().to_string());
+    fs::create_dir_all(&dir)?;
+    let circuit_path = dir.join("circuit.json");
+    fs::write(&circuit_path, verification_key)?;
+    let proof_scheme = NoirProofScheme::from_file(&circuit_path);
+    fs::remove_dir_all(&dir)?;
+    let proof: NoirProof = serde_json::from_slice(proof)?;
+    verify(&proof, &proof_scheme?)?;
+    Ok(())
+}
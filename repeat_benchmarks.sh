@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+# Runs the Rust benchmarks R times with different input seeds, as one campaign.
+# Usage: repeat_benchmarks.sh [--runs R] [--campaign ID] [-- <cargo bench args>]
+#
+# Run i proves inputs seeded with BENCH_SEED=i and writes its results to
+# results/<campaign>/r<i>/, together with a copy of Criterion's estimates. Collect the campaign
+# with `BENCH_RUN_ID=<campaign> cargo run --release -p utils --bin collect_benchmarks` to get the
+# mean, min, max and standard deviation of every measurement across the runs.
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+RUNS=3
+CAMPAIGN="campaign-$(date -u +%Y%m%dT%H%M%SZ)"
+BENCH_ARGS=()
+
+while [[ $# -gt 0 ]]; do
+  case "$1" in
+    --runs)
+      RUNS="$2"; shift 2 ;;
+    --campaign)
+      CAMPAIGN="$2"; shift 2 ;;
+    --)
+      shift; BENCH_ARGS=("$@"); break ;;
+    *)
+      echo "Unknown argument: $1" >&2; exit 2 ;;
+  esac
+done
+
+if ! [[ "$RUNS" =~ ^[1-9][0-9]*$ ]]; then
+  echo "--runs must be a positive integer" >&2
+  exit 2
+fi
+
+RESULTS_ROOT="${BENCH_RESULTS_DIR:-$SCRIPT_DIR/results}"
+
+for ((i = 1; i <= RUNS; i++)); do
+  RUN_ID="$CAMPAIGN/r$i"
+  echo "==> Run $i/$RUNS (BENCH_SEED=$i, BENCH_RUN_ID=$RUN_ID)"
+  # Criterion compares against the previous run's estimates; start each run from scratch.
+  rm -rf "$SCRIPT_DIR/target/criterion"
+  (cd "$SCRIPT_DIR" && BENCH_SEED="$i" BENCH_RUN_ID="$RUN_ID" cargo bench "${BENCH_ARGS[@]}")
+  if [[ -d "$SCRIPT_DIR/target/criterion" ]]; then
+    mkdir -p "$RESULTS_ROOT/$RUN_ID"
+    cp -r "$SCRIPT_DIR/target/criterion" "$RESULTS_ROOT/$RUN_ID/criterion"
+  fi
+done
+
+echo "Collect with: BENCH_RUN_ID=$CAMPAIGN cargo run --release -p utils --bin collect_benchmarks"
@@ -1,7 +1,7 @@
 use ere_risc0::compiler::RustRv32imaCustomized;
 use risc0::{
-    execution_cycles, prepare_ecdsa, preprocessing_size, proof_size, prove_ecdsa,
-    risc0_bench_properties, verify_ecdsa,
+    ecdsa_execution_cycles, export_artifacts, prepare_ecdsa, preprocessing_size, proof_size,
+    prove_ecdsa, risc0_bench_properties, verify_ecdsa,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::ECDSA_BENCH;
@@ -13,12 +13,13 @@ utils::define_benchmark_harness!(
     None,
     "ecdsa_mem_risc0",
     risc0_bench_properties(),
-    { load_or_compile_program(&RustRv32imaCustomized, ECDSA_BENCH) },
+    { load_or_compile_program(&RustRv32imaCustomized, ECDSA_BENCH)? },
     prepare_ecdsa,
     |_, _| 0,
     prove_ecdsa,
     verify_ecdsa,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    ecdsa_execution_cycles,
+    export_artifacts = export_artifacts
 );
@@ -1,7 +1,7 @@
 use ere_risc0::compiler::RustRv32imaCustomized;
 use risc0::{
-    execution_cycles, prepare_keccak, preprocessing_size, proof_size, prove,
-    risc0_bench_properties, verify_keccak,
+    export_artifacts, keccak_execution_cycles, prepare_keccak, preprocessing_size, proof_size,
+    prove, risc0_bench_properties, verify_keccak,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::KECCAK_BENCH;
@@ -13,12 +13,13 @@ utils::define_benchmark_harness!(
     None,
     "keccak_mem_risc0",
     risc0_bench_properties(),
-    { load_or_compile_program(&RustRv32imaCustomized, KECCAK_BENCH) },
+    { load_or_compile_program(&RustRv32imaCustomized, KECCAK_BENCH)? },
     prepare_keccak,
     |_, _| 0,
     prove,
     verify_keccak,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    keccak_execution_cycles,
+    export_artifacts = export_artifacts
 );
@@ -0,0 +1,24 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{
+    KECCAK_PURE_BENCH, export_artifacts, keccak_pure_execution_cycles, prepare_keccak,
+    preprocessing_size, proof_size, prove, risc0_bench_properties, verify_keccak,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Keccak,
+    ProvingSystem::Risc0,
+    Some("keccak_pure"),
+    "keccak_pure_mem_risc0",
+    risc0_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, KECCAK_PURE_BENCH)? },
+    prepare_keccak,
+    |_, _| 0,
+    prove,
+    verify_keccak,
+    preprocessing_size,
+    proof_size,
+    keccak_pure_execution_cycles,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,25 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{
+    export_artifacts, merkle_update_execution_cycles, prepare_merkle_update, preprocessing_size,
+    proof_size, prove, risc0_bench_properties, verify_merkle_update,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::MERKLE_UPDATE_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::MerkleUpdate,
+    ProvingSystem::Risc0,
+    None,
+    "merkle_update_mem_risc0",
+    risc0_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, MERKLE_UPDATE_BENCH)? },
+    prepare_merkle_update,
+    |_, _| 0,
+    prove,
+    verify_merkle_update,
+    preprocessing_size,
+    proof_size,
+    merkle_update_execution_cycles,
+    export_artifacts = export_artifacts
+);
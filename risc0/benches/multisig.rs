@@ -0,0 +1,25 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{
+    export_artifacts, multisig_execution_cycles, prepare_multisig, preprocessing_size, proof_size,
+    prove, risc0_bench_properties, verify_multisig,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::MULTISIG_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Multisig,
+    ProvingSystem::Risc0,
+    None,
+    "multisig_mem_risc0",
+    risc0_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, MULTISIG_BENCH)? },
+    prepare_multisig,
+    |_, _| 0,
+    prove,
+    verify_multisig,
+    preprocessing_size,
+    proof_size,
+    multisig_execution_cycles,
+    export_artifacts = export_artifacts
+);
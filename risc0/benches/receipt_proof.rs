@@ -0,0 +1,25 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{
+    export_artifacts, prepare_receipt_proof, preprocessing_size, proof_size, prove,
+    receipt_proof_execution_cycles, risc0_bench_properties, verify_receipt_proof,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::RECEIPT_PROOF_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::ReceiptProof,
+    ProvingSystem::Risc0,
+    None,
+    "receipt_proof_mem_risc0",
+    risc0_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, RECEIPT_PROOF_BENCH)? },
+    prepare_receipt_proof,
+    |_, _| 0,
+    prove,
+    verify_receipt_proof,
+    preprocessing_size,
+    proof_size,
+    receipt_proof_execution_cycles,
+    export_artifacts = export_artifacts
+);
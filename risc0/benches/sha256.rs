@@ -1,7 +1,7 @@
 use ere_risc0::compiler::RustRv32imaCustomized;
 use risc0::{
-    execution_cycles, prepare_sha256, preprocessing_size, proof_size, prove_sha256,
-    risc0_bench_properties, verify_sha256,
+    export_artifacts, prepare_sha256, preprocessing_size, proof_size, prove_sha256,
+    risc0_bench_properties, sha256_execution_cycles, verify_sha256,
 };
 use utils::harness::ProvingSystem;
 use utils::zkvm::SHA256_BENCH;
@@ -13,12 +13,13 @@ utils::define_benchmark_harness!(
     None,
     "sha256_mem_risc0",
     risc0_bench_properties(),
-    { load_or_compile_program(&RustRv32imaCustomized, SHA256_BENCH) },
+    { load_or_compile_program(&RustRv32imaCustomized, SHA256_BENCH)? },
     prepare_sha256,
     |_, _| 0,
     prove_sha256,
     verify_sha256,
     preprocessing_size,
     proof_size,
-    execution_cycles
+    sha256_execution_cycles,
+    export_artifacts = export_artifacts
 );
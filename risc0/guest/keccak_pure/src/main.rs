@@ -0,0 +1,34 @@
+// Copyright 2026 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use risc0_zkvm::guest::env;
+use tiny_keccak::{Hasher, Keccak};
+
+fn main() {
+    let data = env::read_frame();
+    let hash = keccak(&data);
+    env::commit_slice(&hash);
+}
+
+#[inline]
+pub fn keccak(data: impl AsRef<[u8]>) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data.as_ref());
+
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+
+    output
+}
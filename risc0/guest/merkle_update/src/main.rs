@@ -0,0 +1,65 @@
+//! Applies a batch of leaf updates to a Poseidon Merkle tree and commits the old and new roots.
+//! Mirrors `utils::merkle::verify_merkle_update`; the input layout is
+//! `utils::merkle::MerkleBatchUpdate::to_bytes`.
+
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use light_poseidon::{Poseidon, PoseidonHasher};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    let data = env::read_frame();
+    let mut input = data.as_slice();
+    let depth = read_u32(&mut input) as usize;
+    let update_count = read_u32(&mut input);
+    let old_root = read_field(&mut input);
+
+    // circomlib's `Poseidon(2)`.
+    let mut poseidon = Poseidon::<Fr>::new_circom(2).expect("supported width");
+    let mut root = old_root;
+    for _ in 0..update_count {
+        let index = read_u32(&mut input);
+        let old_leaf = read_field(&mut input);
+        let new_leaf = read_field(&mut input);
+        let siblings: Vec<Fr> = (0..depth).map(|_| read_field(&mut input)).collect();
+        assert!(index >> depth == 0, "index outside the tree");
+        assert_eq!(
+            root_of(&mut poseidon, old_leaf, index, &siblings),
+            root,
+            "old leaf not in the tree"
+        );
+        root = root_of(&mut poseidon, new_leaf, index, &siblings);
+    }
+    assert!(input.is_empty(), "trailing input");
+
+    env::commit_slice(&old_root.into_bigint().to_bytes_be());
+    env::commit_slice(&root.into_bigint().to_bytes_be());
+}
+
+fn root_of(poseidon: &mut Poseidon<Fr>, leaf: Fr, index: u32, siblings: &[Fr]) -> Fr {
+    siblings
+        .iter()
+        .enumerate()
+        .fold(leaf, |node, (level, &sibling)| {
+            let children = if (index >> level) & 1 == 1 {
+                [sibling, node]
+            } else {
+                [node, sibling]
+            };
+            poseidon.hash(&children).expect("two inputs")
+        })
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> &'a [u8] {
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    head
+}
+
+fn read_u32(input: &mut &[u8]) -> u32 {
+    u32::from_le_bytes(take(input, 4).try_into().unwrap())
+}
+
+fn read_field(input: &mut &[u8]) -> Fr {
+    Fr::from_be_bytes_mod_order(take(input, 32))
+}
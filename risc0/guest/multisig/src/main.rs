@@ -0,0 +1,64 @@
+//! Verifies a committee's secp256k1 signatures over one digest and checks that at least the
+//! threshold of them are valid. Mirrors `utils::multisig::valid_signatures`; the input layout is
+//! `utils::multisig::MultisigInput::to_bytes`.
+
+#![no_main]
+
+use k256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::hazmat::PrehashVerifier},
+};
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::guest::entry!(main);
+
+fn main() {
+    let data = env::read_frame();
+    let mut input = data.as_slice();
+    let signer_count = read_u32(&mut input);
+    let threshold = read_u32(&mut input);
+    let digest = take(&mut input, 32);
+
+    let mut keys = Vec::with_capacity(signer_count as usize);
+    let mut valid = 0u32;
+    for _ in 0..signer_count {
+        let key = take(&mut input, 65);
+        let signature = take(&mut input, 64);
+        keys.push(key);
+        if verifies(key, digest, signature) {
+            valid += 1;
+        }
+    }
+    assert!(input.is_empty(), "trailing input");
+    assert!(valid >= threshold, "threshold not reached");
+
+    env::commit_slice(digest);
+    env::commit_slice(&threshold.to_le_bytes());
+    for key in keys {
+        env::commit_slice(key);
+    }
+}
+
+/// A malformed key or signature counts as a missing signature.
+fn verifies(key: &[u8], digest: &[u8], signature: &[u8]) -> bool {
+    let Ok(point) = EncodedPoint::from_bytes(key) else {
+        return false;
+    };
+    let (Ok(key), Ok(signature)) = (
+        VerifyingKey::from_encoded_point(&point),
+        Signature::from_slice(signature),
+    ) else {
+        return false;
+    };
+    key.verify_prehash(digest, &signature).is_ok()
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> &'a [u8] {
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    head
+}
+
+fn read_u32(input: &mut &[u8]) -> u32 {
+    u32::from_le_bytes(take(input, 4).try_into().unwrap())
+}
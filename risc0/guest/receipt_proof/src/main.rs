@@ -0,0 +1,142 @@
+//! Verifies an Ethereum receipt inclusion proof and commits the address, first topic and first
+//! data word of one of the receipt's logs. Mirrors `utils::receipt::verify_receipt_proof`; the
+//! input layout is `utils::receipt::ReceiptProofInput::to_bytes`.
+
+use risc0_zkvm::guest::env;
+use tiny_keccak::{Hasher, Keccak};
+
+fn main() {
+    let data = env::read_frame();
+    let mut input = data.as_slice();
+    let root = take(&mut input, 32);
+    let log_index = read_u32(&mut input) as usize;
+    let key_len = read_u32(&mut input) as usize;
+    let path = nibbles(take(&mut input, key_len));
+    let node_count = read_u32(&mut input);
+
+    let mut position = 0;
+    let mut expected_hash = root;
+    let mut value = None;
+    for _ in 0..node_count {
+        let node_len = read_u32(&mut input) as usize;
+        let node = take(&mut input, node_len);
+        assert!(value.is_none(), "proof continues past the receipt");
+        assert_eq!(keccak(node).as_slice(), expected_hash, "node hash mismatch");
+
+        let items = list_items(payload(node, true));
+        match items.len() {
+            17 if position == path.len() => value = Some(payload(items[16], false)),
+            17 => {
+                expected_hash = payload(items[usize::from(path[position])], false);
+                position += 1;
+            }
+            2 => {
+                let (segment, is_leaf) = decode_hex_prefix(payload(items[0], false));
+                assert!(path[position..].starts_with(&segment), "path mismatch");
+                position += segment.len();
+                if is_leaf {
+                    assert_eq!(position, path.len(), "leaf before the end of the key");
+                    value = Some(payload(items[1], false));
+                } else {
+                    expected_hash = payload(items[1], false);
+                }
+            }
+            _ => panic!("not a trie node"),
+        }
+        assert!(
+            value.is_some() || expected_hash.len() == 32,
+            "embedded node reference"
+        );
+    }
+    assert!(input.is_empty(), "trailing input");
+
+    let mut receipt = value.expect("proof ends before the receipt");
+    // Typed receipts are the transaction type followed by the RLP list.
+    if receipt[0] < 0x80 {
+        receipt = &receipt[1..];
+    }
+    let fields = list_items(payload(receipt, true));
+    let logs = list_items(payload(fields[3], true));
+    let log = list_items(payload(logs[log_index], true));
+    assert_eq!(log.len(), 3, "malformed log");
+    let address = payload(log[0], false);
+    let topic = payload(list_items(payload(log[1], true))[0], false);
+    let data = payload(log[2], false);
+    assert!(address.len() == 20 && topic.len() == 32 && data.len() >= 32);
+
+    env::commit_slice(address);
+    env::commit_slice(topic);
+    env::commit_slice(&data[..32]);
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> &'a [u8] {
+    let (head, rest) = input.split_at(len);
+    *input = rest;
+    head
+}
+
+fn read_u32(input: &mut &[u8]) -> u32 {
+    u32::from_le_bytes(take(input, 4).try_into().unwrap())
+}
+
+fn nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+fn decode_hex_prefix(encoded: &[u8]) -> (Vec<u8>, bool) {
+    let flag = encoded[0] >> 4;
+    assert!(flag <= 3, "bad hex-prefix flag");
+    let mut path = if flag % 2 == 1 {
+        vec![encoded[0] & 0x0f]
+    } else {
+        Vec::new()
+    };
+    path.extend(nibbles(&encoded[1..]));
+    (path, flag >= 2)
+}
+
+/// Splits the RLP item at the front of `data` into (is list, payload, rest).
+fn split(data: &[u8]) -> (bool, &[u8], &[u8]) {
+    let prefix = data[0];
+    let (is_list, offset, len) = match prefix {
+        0..=0x7f => return (false, &data[..1], &data[1..]),
+        0x80..=0xb7 => (false, 0, usize::from(prefix - 0x80)),
+        0xb8..=0xbf => (false, usize::from(prefix - 0xb7), 0),
+        0xc0..=0xf7 => (true, 0, usize::from(prefix - 0xc0)),
+        0xf8..=0xff => (true, usize::from(prefix - 0xf7), 0),
+    };
+    let len = if offset > 0 {
+        data[1..=offset]
+            .iter()
+            .fold(0usize, |len, &b| (len << 8) | usize::from(b))
+    } else {
+        len
+    };
+    let start = 1 + offset;
+    (is_list, &data[start..start + len], &data[start + len..])
+}
+
+/// Payload of `item`, which must be a single RLP item of the given kind.
+fn payload(item: &[u8], list: bool) -> &[u8] {
+    let (is_list, payload, rest) = split(item);
+    assert!(is_list == list && rest.is_empty(), "unexpected RLP item");
+    payload
+}
+
+fn list_items(mut payload: &[u8]) -> Vec<&[u8]> {
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let (_, _, rest) = split(payload);
+        items.push(&payload[..payload.len() - rest.len()]);
+        payload = rest;
+    }
+    items
+}
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
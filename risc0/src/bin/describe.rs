@@ -0,0 +1,7 @@
+use risc0::BENCH_FEATURES;
+use utils::features::print_description;
+use utils::harness::ProvingSystem;
+
+fn main() {
+    print_description(ProvingSystem::Risc0, BENCH_FEATURES);
+}
@@ -0,0 +1,14 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{KECCAK_PURE_BENCH, prepare_keccak, prove, verify_keccak};
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        KECCAK_PURE_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_keccak(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_keccak(prepared, proof, &()),
+    );
+}
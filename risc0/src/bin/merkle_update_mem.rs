@@ -0,0 +1,15 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{prepare_merkle_update, prove, verify_merkle_update};
+use utils::zkvm::MERKLE_UPDATE_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        MERKLE_UPDATE_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_merkle_update(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_merkle_update(prepared, proof, &()),
+    );
+}
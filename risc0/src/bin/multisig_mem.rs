@@ -0,0 +1,15 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{prepare_multisig, prove, verify_multisig};
+use utils::zkvm::MULTISIG_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        MULTISIG_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_multisig(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_multisig(prepared, proof, &()),
+    );
+}
@@ -0,0 +1,15 @@
+use ere_risc0::compiler::RustRv32imaCustomized;
+use risc0::{prepare_receipt_proof, prove, verify_receipt_proof};
+use utils::zkvm::RECEIPT_PROOF_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        RECEIPT_PROOF_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_receipt_proof(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_receipt_proof(prepared, proof, &()),
+    );
+}
@@ -1,21 +1,15 @@
-use clap::Parser;
 use ere_risc0::compiler::RustRv32imaCustomized;
-use risc0::{prepare_sha256, prove_sha256};
+use risc0::{prepare_sha256, prove_sha256, verify_sha256};
 use utils::zkvm::SHA256_BENCH;
 use utils::zkvm::helpers::load_compiled_program;
 
-#[derive(Parser, Debug)]
-struct Args {
-    /// Input size in bytes for the SHA256 benchmark
-    #[arg(long = "input-size")]
-    input_size: usize,
-}
-
 fn main() {
-    let args = Args::parse();
-
-    let program = load_compiled_program::<RustRv32imaCustomized>(SHA256_BENCH);
-
-    let prepared = prepare_sha256(args.input_size, &program);
-    prove_sha256(&prepared, &());
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv32imaCustomized>(
+        SHA256_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_sha256(input_size, &program),
+        |prepared| prove_sha256(prepared, &()),
+        |prepared, proof| verify_sha256(prepared, proof, &()),
+    );
 }
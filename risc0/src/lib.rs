@@ -1,16 +1,43 @@
 use bincode::Options;
 use ere_risc0::{EreRisc0, compiler::RustRv32imaCustomized};
 use ere_zkvm_interface::{Input, ProverResource};
-use utils::harness::{AuditStatus, BenchProperties};
+use risc0_zkvm::{ExecutorEnv, ExecutorImpl};
+use std::collections::BTreeMap;
+use utils::cycles::{AcceleratorUsage, CycleBreakdown, ExecutionCycles};
+use utils::features::{BenchFeature, FeatureParameter};
+use utils::harness::{AuditStatus, BenchError, BenchProperties, BenchTarget};
+use utils::zkvm::helpers::load_compiled_program;
 use utils::zkvm::{
-    CompiledProgram, PreparedEcdsa, PreparedKeccak, PreparedSha256, encode_public_key,
+    CompiledProgram, ECDSA_BENCH, InputAbi, KECCAK_BENCH, MERKLE_UPDATE_BENCH, MULTISIG_BENCH,
+    PreparedEcdsa, PreparedKeccak, PreparedMerkleUpdate, PreparedMultisig, PreparedReceiptProof,
+    PreparedSha256, RECEIPT_PROOF_BENCH, SHA256_BENCH, encode_public_key,
 };
 
 pub use utils::zkvm::{
-    execution_cycles, preprocessing_size, proof_size, prove, prove_ecdsa, prove_sha256,
-    verify_ecdsa, verify_keccak, verify_sha256,
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_ecdsa,
+    prove_sha256, verify_ecdsa, verify_keccak, verify_merkle_update, verify_multisig,
+    verify_receipt_proof, verify_sha256,
 };
 
+/// Keccak guest without the accelerator (see `guest/keccak_pure`), benched as the `keccak_pure`
+/// feature against the default `keccak` guest to isolate the precompile speedup.
+pub const KECCAK_PURE_BENCH: &str = "keccak_pure";
+
+/// Feature tags of the RISC Zero benches, printed by `describe_risc0`.
+pub const BENCH_FEATURES: &[BenchFeature] = &[BenchFeature {
+    tag: KECCAK_PURE_BENCH,
+    description: "Keccak guest hashing in plain Rust instead of calling the Keccak accelerator; \
+                  compare with the untagged keccak results for the precompile speedup.",
+    targets: &[BenchTarget::Keccak],
+    cargo_feature: None,
+    bench: Some(KECCAK_PURE_BENCH),
+    parameters: &[FeatureParameter {
+        name: "guest",
+        value: "guest/keccak_pure",
+        description: "Guest program proved in place of guest/keccak.",
+    }],
+}];
+
 pub fn risc0_bench_properties() -> BenchProperties {
     BenchProperties::new(
         "STARK",
@@ -26,66 +53,244 @@ pub fn risc0_bench_properties() -> BenchProperties {
         AuditStatus::Audited, // https://github.com/risc0/rz-security/tree/main/audits
         Some("RISC-V RV32IM"), // base + multiplication; https://dev.risczero.com/reference-docs/about-risc-v
     )
+    .with_onchain_verifier("evm") // Groth16 receipts, RiscZeroVerifierRouter
 }
 
 pub fn prepare_sha256(
     input_size: usize,
     program: &CompiledProgram<RustRv32imaCustomized>,
-) -> PreparedSha256<EreRisc0> {
-    let vm = EreRisc0::new(program.program.clone(), ProverResource::Cpu)
-        .expect("failed to build risc0 prover instance");
+) -> Result<PreparedSha256<EreRisc0>, BenchError> {
+    let vm =
+        EreRisc0::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_sha256_input(input_size);
-    let input = build_framed_input(message_bytes);
+    let input = InputAbi::LengthPrefixed.input(&message_bytes);
 
-    PreparedSha256::with_expected_digest(vm, input, program.byte_size, digest)
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
 }
 
 /// Prepares an ECDSA signature verification benchmark (single secp256k1 signature).
 pub fn prepare_ecdsa(
     _input_size: usize,
     program: &CompiledProgram<RustRv32imaCustomized>,
-) -> PreparedEcdsa<EreRisc0> {
-    let vm = EreRisc0::new(program.program.clone(), ProverResource::Cpu)
-        .expect("failed to build risc0 prover instance");
+) -> Result<PreparedEcdsa<EreRisc0>, BenchError> {
+    let vm =
+        EreRisc0::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_k256_input();
 
-    let encoded_verifying_key = encode_public_key(&pub_key_x, &pub_key_y)
-        .expect("generated public key should have valid size");
+    let encoded_verifying_key =
+        encode_public_key(&pub_key_x, &pub_key_y).map_err(BenchError::setup)?;
 
-    let input = build_framed_ecdsa_input(encoded_verifying_key.clone(), digest.clone(), signature);
+    let input = build_framed_ecdsa_input(encoded_verifying_key.clone(), digest.clone(), signature)?;
 
-    PreparedEcdsa::with_expected_values(
+    Ok(PreparedEcdsa::with_expected_values(
         vm,
         input,
         program.byte_size,
         (pub_key_x, pub_key_y),
         digest,
-    )
+    ))
 }
 
 /// Prepares a Keccak256 hash benchmark.
 pub fn prepare_keccak(
     input_size: usize,
     program: &CompiledProgram<RustRv32imaCustomized>,
-) -> PreparedKeccak<EreRisc0> {
-    let vm = EreRisc0::new(program.program.clone(), ProverResource::Cpu)
-        .expect("failed to build risc0 prover instance");
+) -> Result<PreparedKeccak<EreRisc0>, BenchError> {
+    let vm =
+        EreRisc0::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_keccak_input(input_size);
-    let input = build_framed_input(message_bytes);
+    let input = InputAbi::LengthPrefixed.input(&message_bytes);
+
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Prepares an Ethereum receipt inclusion proof benchmark in a block of `input_size` receipts.
+pub fn prepare_receipt_proof(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedReceiptProof<EreRisc0>, BenchError> {
+    let vm =
+        EreRisc0::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (proof_input, expected_fields) = utils::generate_receipt_proof_input(input_size);
+    let input = InputAbi::LengthPrefixed.input(&proof_input.to_bytes());
+
+    Ok(PreparedReceiptProof::with_expected_outputs(
+        vm,
+        input,
+        program.byte_size,
+        utils::receipt::public_outputs(&expected_fields),
+    ))
+}
+
+/// Prepares a batch of `input_size` leaf updates to a depth-20 Poseidon Merkle tree.
+pub fn prepare_merkle_update(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedMerkleUpdate<EreRisc0>, BenchError> {
+    let vm =
+        EreRisc0::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let batch = utils::generate_merkle_update_input(input_size);
+    let input = InputAbi::LengthPrefixed.input(&batch.to_bytes());
+
+    Ok(PreparedMerkleUpdate::with_expected_outputs(
+        vm,
+        input,
+        program.byte_size,
+        batch.public_outputs(),
+    ))
+}
+
+/// Prepares the verification of `input_size` secp256k1 signatures against a two-thirds threshold.
+pub fn prepare_multisig(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedMultisig<EreRisc0>, BenchError> {
+    let vm =
+        EreRisc0::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let multisig = utils::generate_multisig_input(input_size);
+    let input = InputAbi::LengthPrefixed.input(&multisig.to_bytes());
+
+    Ok(PreparedMultisig::with_expected_outputs(
+        vm,
+        input,
+        program.byte_size,
+        multisig.public_outputs(),
+    ))
+}
+
+pub fn sha256_execution_cycles(
+    prepared: &PreparedSha256<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(SHA256_BENCH, prepared.input(), execution_cycles(prepared)?)
+}
+
+pub fn ecdsa_execution_cycles(
+    prepared: &PreparedEcdsa<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(ECDSA_BENCH, prepared.input(), execution_cycles(prepared)?)
+}
+
+pub fn keccak_execution_cycles(
+    prepared: &PreparedKeccak<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(KECCAK_BENCH, prepared.input(), execution_cycles(prepared)?)
+}
+
+pub fn receipt_proof_execution_cycles(
+    prepared: &PreparedReceiptProof<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(
+        RECEIPT_PROOF_BENCH,
+        prepared.input(),
+        execution_cycles(prepared)?,
+    )
+}
 
-    PreparedKeccak::with_expected_digest(vm, input, program.byte_size, digest)
+pub fn merkle_update_execution_cycles(
+    prepared: &PreparedMerkleUpdate<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(
+        MERKLE_UPDATE_BENCH,
+        prepared.input(),
+        execution_cycles(prepared)?,
+    )
+}
+
+pub fn multisig_execution_cycles(
+    prepared: &PreparedMultisig<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(
+        MULTISIG_BENCH,
+        prepared.input(),
+        execution_cycles(prepared)?,
+    )
 }
 
-/// Build risc0 input with length-prefixed frame format.
-fn build_framed_input(data: Vec<u8>) -> Input {
-    let len = data.len() as u32;
-    let mut framed = Vec::with_capacity(4 + data.len());
-    framed.extend_from_slice(&len.to_le_bytes());
-    framed.extend(data);
-    Input::new().with_stdin(framed)
+pub fn keccak_pure_execution_cycles(
+    prepared: &PreparedKeccak<EreRisc0>,
+) -> Result<ExecutionCycles, BenchError> {
+    split_execution_cycles(
+        KECCAK_PURE_BENCH,
+        prepared.input(),
+        execution_cycles(prepared)?,
+    )
+}
+
+/// `total` (the cycle count ere reports) split by re-executing `guest` with the RISC Zero
+/// executor. SHA-256 runs inline in the main segments, but Keccak-f permutations are queued for
+/// the Keccak coprocessor and proven in segments of their own, which `total` leaves out; they are
+/// reported as the `keccak` accelerator.
+fn split_execution_cycles(
+    guest: &str,
+    input: &Input,
+    total: u64,
+) -> Result<ExecutionCycles, BenchError> {
+    let program = load_compiled_program::<RustRv32imaCustomized>(guest)?;
+    let env = ExecutorEnv::builder()
+        .write_slice(input.stdin())
+        .build()
+        .map_err(BenchError::setup)?;
+    let session = ExecutorImpl::from_elf(env, program.program.elf())
+        .and_then(|mut executor| executor.run())
+        .map_err(BenchError::prove)?;
+
+    let mut accelerators = BTreeMap::new();
+    if !session.pending_keccaks.is_empty() {
+        accelerators.insert(
+            "keccak".to_string(),
+            AcceleratorUsage {
+                calls: session
+                    .pending_keccaks
+                    .iter()
+                    .map(|request| request.input.len() as u64)
+                    .sum(),
+                segments: session.pending_keccaks.len() as u64,
+                cycles: session
+                    .pending_keccaks
+                    .iter()
+                    .map(|request| 1u64 << request.po2)
+                    .sum(),
+            },
+        );
+    }
+    Ok(ExecutionCycles {
+        total,
+        breakdown: Some(CycleBreakdown {
+            main_segments: session.segments.len() as u64,
+            user_cycles: Some(session.user_cycles),
+            accelerators,
+        }),
+    })
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv32imaCustomized, _>(
+        |program| Ok(EreRisc0::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
 }
 
 /// Build risc0 ECDSA input with framing.
@@ -93,10 +298,10 @@ fn build_framed_ecdsa_input(
     encoded_verifying_key: Vec<u8>,
     digest: Vec<u8>,
     signature: Vec<u8>,
-) -> Input {
+) -> Result<Input, BenchError> {
     let data = (encoded_verifying_key, digest, signature);
     let serialized = bincode::options()
         .serialize(&data)
-        .expect("failed to serialize ECDSA input");
-    build_framed_input(serialized)
+        .map_err(BenchError::setup)?;
+    Ok(InputAbi::LengthPrefixed.input(&serialized))
 }
@@ -0,0 +1,22 @@
+//! Keccak256 benchmark of the Stwo Keccak AIR under Rookie Numbers.
+
+use rookie_numbers::keccak::{
+    export_artifacts, keccak_bench_properties, prepare, preprocessing_size, proof_size,
+    prove_keccak, verify_keccak, N_CONSTRAINTS,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Keccak,
+    ProvingSystem::RookieNumbers,
+    None,
+    "keccak_mem_rookie_numbers",
+    keccak_bench_properties(),
+    prepare,
+    |_prepared| N_CONSTRAINTS,
+    prove_keccak,
+    verify_keccak,
+    preprocessing_size,
+    proof_size,
+    export_artifacts = export_artifacts
+);
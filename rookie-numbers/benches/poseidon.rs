@@ -0,0 +1,22 @@
+//! Poseidon2 (M31) benchmark of the Rookie Numbers Poseidon AIR.
+
+use rookie_numbers::poseidon::{
+    export_artifacts, poseidon_bench_properties, prepare, preprocessing_size, proof_size,
+    prove_poseidon, verify_poseidon, N_CONSTRAINTS,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Poseidon,
+    ProvingSystem::RookieNumbers,
+    None,
+    "poseidon_mem_rookie_numbers",
+    poseidon_bench_properties(),
+    prepare,
+    |_prepared| N_CONSTRAINTS,
+    prove_poseidon,
+    verify_poseidon,
+    preprocessing_size,
+    proof_size,
+    export_artifacts = export_artifacts
+);
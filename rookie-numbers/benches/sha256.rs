@@ -1,32 +1,35 @@
 //! SHA256 benchmark using Rookie Numbers prover.
 
 use rookie_numbers::{
-    secure_pcs_config, MAX_PREPROCESSED_LOG_SIZE, ROOKIE_NUMBERS_BENCH_PROPERTIES,
+    export_sha256_artifacts, rookie_numbers_bench_properties, secure_pcs_config,
+    MAX_PREPROCESSED_LOG_SIZE,
 };
 use sha256::{preprocess_sha256, prove_sha256, verify_sha256};
-use utils::harness::ProvingSystem;
+use utils::harness::{BenchError, ProvingSystem};
 
 utils::define_benchmark_harness!(
     BenchTarget::Sha256,
     ProvingSystem::RookieNumbers,
     None,
     "sha256_mem_rookie_numbers",
-    ROOKIE_NUMBERS_BENCH_PROPERTIES,
-    // Shared state: preprocess once with MAX_PREPROCESSED_LOG_SIZE
+    rookie_numbers_bench_properties(),
+    // Shared state: preprocess once per security level with MAX_PREPROCESSED_LOG_SIZE
     { preprocess_sha256(MAX_PREPROCESSED_LOG_SIZE, secure_pcs_config()) },
-    // prepare: |input_size, &preprocessed| -> PreparedContext
-    |input_size, _preprocessed| utils::generate_sha256_input(input_size).0,
+    // prepare: |input_size, &preprocessed| -> Result<PreparedContext, BenchError>
+    |input_size, _preprocessed| Ok(utils::generate_sha256_input(input_size).0),
     // num_constraints: |ctx, &shared| -> usize
     |_words, _preprocessed| 1076, // components.n_constraints()
-    // prove: |words, &shared| -> Proof
-    |words, preprocessed| prove_sha256(words, secure_pcs_config(), preprocessed),
-    // verify: |words, proof, &shared| -> ()
+    // prove: |words, &shared| -> Result<Proof, BenchError>
+    |words, preprocessed| Ok(prove_sha256(words, secure_pcs_config(), preprocessed)),
+    // verify: |words, proof, &shared| -> Result<(), BenchError>
     |_words, proof, _preprocessed| verify_sha256(proof.0.clone(), proof.1, &proof.2)
-        .expect("verify failed"),
+        .map_err(|e| BenchError::verify(format!("{e:?}"))),
     // preprocessing_size: |words, &shared| -> usize
     |_words, preprocessed| bincode::serialize(preprocessed)
         .map(|v| v.len())
         .unwrap_or(0),
     // proof_size: |proof, &shared| -> usize
-    |proof, _preprocessed| bincode::serialize(proof).map(|v| v.len()).unwrap_or(0)
+    |proof, _preprocessed| bincode::serialize(proof).map(|v| v.len()).unwrap_or(0),
+    // export_artifacts: |words, proof, &shared| -> Result<ExportedArtifacts, BenchError>
+    export_artifacts = |_words, proof, _preprocessed| export_sha256_artifacts(proof)
 );
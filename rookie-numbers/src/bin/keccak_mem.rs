@@ -0,0 +1,7 @@
+//! Memory measurement binary for the Rookie Numbers Keccak256 bench.
+
+use rookie_numbers::keccak::{prepare, prove_keccak, verify_keccak};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare, prove_keccak, verify_keccak);
+}
@@ -0,0 +1,7 @@
+//! Memory measurement binary for the Rookie Numbers Poseidon2 AIR.
+
+use rookie_numbers::poseidon::{prepare, prove_poseidon, verify_poseidon};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare, prove_poseidon, verify_poseidon);
+}
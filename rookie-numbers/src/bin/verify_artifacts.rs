@@ -0,0 +1,5 @@
+//! Verifies artifacts exported by the Rookie Numbers benchmarks, for the `verifiers` crate.
+
+fn main() -> anyhow::Result<()> {
+    utils::artifacts::verify_artifacts_main(rookie_numbers::verify_artifacts)
+}
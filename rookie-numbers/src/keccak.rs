@@ -0,0 +1,54 @@
+//! Keccak-256 for Rookie Numbers, which ships a SHA-256 component only: the hand-written AIR of
+//! the `stwo` crate, proved with upstream Stwo at the FRI parameters of [`fri_params`].
+
+use crate::{fri_params, KECCAK_TAG};
+use std::borrow::Cow;
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+
+pub use stwo_air::{
+    preprocessing_size, proof_size, prove_keccak, verify_keccak, PreparedKeccak, Proof,
+    N_CONSTRAINTS,
+};
+
+pub fn keccak_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Rookie Numbers",
+        "M31",
+        "FRI",
+        Some("Circle-PCS"),
+        "AIR",
+        false, // the trace is not blinded
+        false,
+        fri_params().conjectured_security_bits(),
+        true,                    // hash-based PCS
+        true,                    // https://github.com/starkware-libs/stwo
+        AuditStatus::NotAudited, // no published audit of the prover
+        None,
+    )
+    .with_caveat(Caveat::DigestBakedIn)
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed("message padding is private witness and not constrained"),
+    })
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed(
+            "the Keccak AIR of the stwo crate on upstream Stwo; Rookie Numbers ships SHA-256 only",
+        ),
+    })
+}
+
+pub fn prepare(input_size: usize) -> Result<PreparedKeccak, BenchError> {
+    stwo_air::prepare_with_fri_params(input_size, fri_params())
+}
+
+/// The artifacts of [`stwo_air::export_artifacts`], with [`KECCAK_TAG`] in front of the
+/// verification key.
+pub fn export_artifacts(
+    prepared: &PreparedKeccak,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut artifacts = stwo_air::export_artifacts(prepared, proof)?;
+    artifacts.verification_key.insert(0, KECCAK_TAG);
+    Ok(artifacts)
+}
@@ -1,32 +1,99 @@
+//! Rookie Numbers, hand-written Stwo components proved without a VM: the upstream SHA-256
+//! component, and Keccak and Poseidon components on upstream Stwo (see [`keccak`] and
+//! [`poseidon`]).
+
+pub mod keccak;
+pub mod poseidon;
+
+use anyhow::{anyhow, bail};
+use serde::Serialize;
 use std::borrow::Cow;
-use utils::harness::{AuditStatus, BenchProperties};
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::security::{FriParams, QM31_BITS};
 
 // Re-export types from sha256 crate
 pub use sha256::{FriConfig, PcsConfig, MAX_PREPROCESSED_LOG_SIZE};
 
-/// Benchmark properties for Rookie Numbers prover
-pub const ROOKIE_NUMBERS_BENCH_PROPERTIES: BenchProperties = BenchProperties {
-    proving_system: Cow::Borrowed("Rookie Numbers"),
-    field_curve: Cow::Borrowed("M31"),
-    iop: Cow::Borrowed("FRI"),
-    pcs: Some(Cow::Borrowed("Circle-PCS")),
-    arithm: Cow::Borrowed("AIR"),
-    is_zk: false,
-    is_zkvm: false,
-    security_bits: 96,
-    is_pq: true,
-    is_maintained: true,
-    is_audited: AuditStatus::NotAudited,
-    isa: None,
+/// First byte of the exported verification keys, naming the component the rest of the key is
+/// for.
+const SHA256_TAG: u8 = 0;
+const KECCAK_TAG: u8 = 1;
+const POSEIDON_TAG: u8 = 2;
+
+/// Default Circle-FRI parameters of [`secure_pcs_config`].
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 1,
+    queries: 70,
+    pow_bits: 26,
+    field_bits: QM31_BITS,
 };
 
+/// Circle-FRI parameters of [`secure_pcs_config`]: [`FRI_PARAMS`] with the query count adjusted
+/// to the `BENCH_SECURITY_BITS` level being measured, if any.
+pub fn fri_params() -> FriParams {
+    FRI_PARAMS.for_security_level()
+}
+
+/// Benchmark properties for Rookie Numbers prover
+pub fn rookie_numbers_bench_properties() -> BenchProperties {
+    BenchProperties {
+        proving_system: Cow::Borrowed("Rookie Numbers"),
+        field_curve: Cow::Borrowed("M31"),
+        iop: Cow::Borrowed("FRI"),
+        pcs: Some(Cow::Borrowed("Circle-PCS")),
+        arithm: Cow::Borrowed("AIR"),
+        is_zk: false,
+        is_zkvm: false,
+        security_bits: fri_params().conjectured_security_bits(),
+        is_pq: true,
+        is_maintained: true,
+        is_audited: AuditStatus::NotAudited,
+        isa: None,
+        prover_resource: None,
+        version: Some(Cow::Borrowed("7ac382b")), // pinned rev
+        onchain_verifier: None,
+        caveats: Cow::Borrowed(&[]),
+    }
+}
+
 pub fn secure_pcs_config() -> PcsConfig {
+    let fri_params = fri_params();
     PcsConfig {
-        pow_bits: 26,
+        pow_bits: fri_params.pow_bits,
         fri_config: FriConfig {
             log_last_layer_degree_bound: 0,
-            log_blowup_factor: 1,
-            n_queries: 70,
+            log_blowup_factor: fri_params.log_blowup,
+            n_queries: fri_params.queries as usize,
         },
     }
 }
+
+/// The SHA-256 proof in bincode, which the upstream verifier checks on its own: the verification
+/// key is [`SHA256_TAG`] alone and there are no public inputs.
+pub fn export_sha256_artifacts(proof: &impl Serialize) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(|e| BenchError::prove(e.to_string()))?,
+        verification_key: vec![SHA256_TAG],
+        public_inputs: Vec::new(),
+        encoding: "rookie-numbers-bincode",
+    })
+}
+
+/// Verifies artifacts exported by any of the three benchmarks, dispatching on the tag of the
+/// verification key.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    match verification_key.split_first() {
+        Some((&SHA256_TAG, [])) => {
+            let proof: (_, _, _) = bincode::deserialize(proof)?;
+            sha256::verify_sha256(proof.0, proof.1, &proof.2).map_err(|e| anyhow!("{e:?}"))
+        }
+        Some((&KECCAK_TAG, key)) => stwo_air::verify_artifacts(proof, key, public_inputs),
+        Some((&POSEIDON_TAG, key)) => poseidon::verify_artifacts(proof, key, public_inputs),
+        _ => bail!("not a Rookie Numbers verification key"),
+    }
+}
@@ -0,0 +1,435 @@
+//! Poseidon2 over M31 as a hand-written AIR: one width-16 permutation per row.
+//!
+//! Rookie Numbers only ships a SHA-256 component, so this one is written against upstream Stwo,
+//! like the Keccak AIR of the `stwo` crate. The input elements are absorbed by a width-16 sponge
+//! overwriting its first 8 elements with every chunk of the input, then permuting, as the
+//! Plonky3 Poseidon bench does over Goldilocks. The sponge runs natively in [`prepare`] and the
+//! AIR proves the permutations.
+//!
+//! A row holds the input state, the state after every full round and the S-box output of every
+//! partial round. The native permutation and the constraints are the same code ([`permute`]),
+//! so the trace and the AIR cannot drift apart. Every constraint has degree 5.
+
+use crate::{fri_params, POSEIDON_TAG};
+use anyhow::Context;
+use num_traits::Zero;
+use std::array::from_fn;
+use std::borrow::Cow;
+use std::ops::{Add, Mul};
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, TraceLocationAllocator,
+};
+use stwo_prover::core::air::Component;
+use stwo_prover::core::backend::simd::column::BaseColumn;
+use stwo_prover::core::backend::simd::m31::LOG_N_LANES;
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::channel::{Blake2sChannel, Channel};
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::pcs::{CommitmentSchemeProver, CommitmentSchemeVerifier, PcsConfig};
+use stwo_prover::core::poly::circle::{CanonicCoset, CircleEvaluation, PolyOps};
+use stwo_prover::core::poly::twiddles::TwiddleTree;
+use stwo_prover::core::poly::BitReversedOrder;
+use stwo_prover::core::prover::{prove, verify, StarkProof};
+use stwo_prover::core::vcs::blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher};
+use stwo_prover::core::ColumnVec;
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+
+/// Width and rate of the sponge.
+pub const WIDTH: usize = 16;
+pub const RATE: usize = 8;
+
+/// Round numbers of Poseidon2 over M31 at width 16 with the x^5 S-box.
+pub const HALF_FULL_ROUNDS: usize = 4;
+pub const PARTIAL_ROUNDS: usize = 14;
+const FULL_ROUNDS: usize = 2 * HALF_FULL_ROUNDS;
+
+/// Main trace width: the input state, the state after every full round and the S-box output of
+/// every partial round.
+pub const N_COLUMNS: usize = WIDTH * (1 + FULL_ROUNDS) + PARTIAL_ROUNDS;
+
+/// Constraints per row: one per committed column but the input state.
+pub const N_CONSTRAINTS: usize = WIDTH * FULL_ROUNDS + PARTIAL_ROUNDS;
+
+/// Degree-5 constraints need a quotient of at most four times the trace degree.
+const LOG_EXPAND: u32 = 2;
+
+/// Seed of the round constants.
+const CONSTANTS_SEED: u64 = 1;
+
+const M31_MODULUS: u64 = (1 << 31) - 1;
+
+pub type Proof = StarkProof<Blake2sMerkleHasher>;
+
+pub type TraceColumn = CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>;
+
+pub fn poseidon_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Rookie Numbers",
+        "M31",
+        "FRI",
+        Some("Circle-PCS"),
+        "AIR",
+        false, // the trace is not blinded
+        false,
+        fri_params().conjectured_security_bits(),
+        true,                    // hash-based PCS
+        true,                    // https://github.com/starkware-libs/stwo
+        AuditStatus::NotAudited, // no published audit of the prover
+        None,
+    )
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed(
+            "proves Poseidon2 permutations only; absorbing, chaining and the digest are native",
+        ),
+    })
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed(
+            "component written for this benchmark on upstream Stwo; Rookie Numbers ships SHA-256 only",
+        ),
+    })
+}
+
+/// Circle-FRI parameters of [`fri_params`], for upstream Stwo.
+pub fn pcs_config() -> PcsConfig {
+    stwo_air::fri_pcs_config(fri_params())
+}
+
+/// Round constants of the external (full) and internal (partial) rounds.
+#[derive(Clone, Debug)]
+pub struct RoundConstants {
+    external: [[BaseField; WIDTH]; FULL_ROUNDS],
+    internal: [BaseField; PARTIAL_ROUNDS],
+}
+
+impl RoundConstants {
+    /// Constants drawn from SplitMix64 seeded with [`CONSTANTS_SEED`], reduced modulo M31.
+    pub fn new() -> Self {
+        let mut seed = CONSTANTS_SEED;
+        let mut next = || {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            BaseField::from_u32_unchecked(((z ^ (z >> 31)) % M31_MODULUS) as u32)
+        };
+        let external = from_fn(|_| from_fn(|_| next()));
+        let internal = from_fn(|_| next());
+        Self { external, internal }
+    }
+}
+
+impl Default for RoundConstants {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Arithmetic shared by the native permutation and the constraints.
+trait Arith: Clone + Add<Output = Self> + Mul<Output = Self> + From<BaseField> {}
+
+impl<F: Clone + Add<Output = F> + Mul<Output = F> + From<BaseField>> Arith for F {}
+
+fn pow5<F: Arith>(x: F) -> F {
+    let x2 = x.clone() * x.clone();
+    x2.clone() * x2 * x
+}
+
+/// Multiplication by Poseidon2's 4x4 MDS matrix, as in Stwo's Poseidon2 example.
+fn apply_m4<F: Arith>([x0, x1, x2, x3]: [F; 4]) -> [F; 4] {
+    let t0 = x0 + x1.clone();
+    let t02 = t0.clone() + t0.clone();
+    let t1 = x2 + x3.clone();
+    let t12 = t1.clone() + t1.clone();
+    let t2 = x1.clone() + x1 + t1;
+    let t3 = x3.clone() + x3 + t0;
+    let t4 = t12.clone() + t12 + t3.clone();
+    let t5 = t02.clone() + t02 + t2.clone();
+    [t3 + t5.clone(), t5, t2 + t4.clone(), t4]
+}
+
+/// External matrix `circ(2 M4, M4, M4, M4)`.
+fn apply_external_matrix<F: Arith>(state: &mut [F; WIDTH]) {
+    for chunk in state.chunks_exact_mut(4) {
+        let product = apply_m4(from_fn(|i| chunk[i].clone()));
+        chunk.clone_from_slice(&product);
+    }
+    let sums: [F; 4] = from_fn(|j| {
+        (4..WIDTH)
+            .step_by(4)
+            .fold(state[j].clone(), |acc, i| acc + state[i + j].clone())
+    });
+    for (i, s) in state.iter_mut().enumerate() {
+        *s = s.clone() + sums[i % 4].clone();
+    }
+}
+
+/// Internal matrix `diag(2, 4, ..., 2^16)` plus the all-ones matrix, as in Stwo's Poseidon2
+/// example.
+fn apply_internal_matrix<F: Arith>(state: &mut [F; WIDTH]) {
+    let sum = state[1..]
+        .iter()
+        .cloned()
+        .fold(state[0].clone(), |acc, s| acc + s);
+    for (i, s) in state.iter_mut().enumerate() {
+        *s = s.clone() * F::from(BaseField::from_u32_unchecked(1 << (i + 1))) + sum.clone();
+    }
+}
+
+fn full_round<F: Arith>(
+    constants: &RoundConstants,
+    round: usize,
+    state: [F; WIDTH],
+    commit: &mut impl FnMut(F) -> F,
+) -> [F; WIDTH] {
+    let mut state: [F; WIDTH] =
+        from_fn(|i| pow5(state[i].clone() + F::from(constants.external[round][i])));
+    apply_external_matrix(&mut state);
+    state.map(&mut *commit)
+}
+
+/// Poseidon2 permutation of `state`. `commit` is called on the state after every full round and
+/// on the S-box output of every partial round, and the permutation continues from what it
+/// returns: natively the value itself, recorded in the trace, and in the AIR the committed
+/// column, constrained to equal the value.
+fn permute<F: Arith>(
+    constants: &RoundConstants,
+    mut state: [F; WIDTH],
+    mut commit: impl FnMut(F) -> F,
+) -> [F; WIDTH] {
+    apply_external_matrix(&mut state);
+    for round in 0..HALF_FULL_ROUNDS {
+        state = full_round(constants, round, state, &mut commit);
+    }
+    for round in 0..PARTIAL_ROUNDS {
+        state[0] = commit(pow5(state[0].clone() + F::from(constants.internal[round])));
+        apply_internal_matrix(&mut state);
+    }
+    for round in HALF_FULL_ROUNDS..FULL_ROUNDS {
+        state = full_round(constants, round, state, &mut commit);
+    }
+    state
+}
+
+/// Poseidon2 permutations of `n_rows` rows.
+#[derive(Clone, Debug)]
+pub struct PoseidonEval {
+    pub log_n_rows: u32,
+    pub constants: RoundConstants,
+}
+
+pub type PoseidonComponent = FrameworkComponent<PoseidonEval>;
+
+impl FrameworkEval for PoseidonEval {
+    fn log_size(&self) -> u32 {
+        self.log_n_rows
+    }
+
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_n_rows + LOG_EXPAND
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let input = from_fn(|_| eval.next_trace_mask());
+        permute(&self.constants, input, |value| {
+            let column = eval.next_trace_mask();
+            eval.add_constraint(column.clone() - value);
+            column
+        });
+        eval
+    }
+}
+
+/// The states entering the permutations of one hash, with the AIR proving them.
+pub struct PreparedPoseidon {
+    pub eval: PoseidonEval,
+    pub permutation_inputs: Vec<[BaseField; WIDTH]>,
+    pub twiddles: TwiddleTree<SimdBackend>,
+}
+
+fn component(eval: &PoseidonEval) -> PoseidonComponent {
+    PoseidonComponent::new(
+        &mut TraceLocationAllocator::default(),
+        eval.clone(),
+        SecureField::zero(),
+    )
+}
+
+/// Channel bound to the PCS configuration and the number of permutations.
+fn channel(pcs_config: PcsConfig, n_permutations: usize) -> Blake2sChannel {
+    let mut channel = Blake2sChannel::default();
+    pcs_config.mix_into(&mut channel);
+    channel.mix_u64(n_permutations as u64);
+    channel
+}
+
+/// Rows of the trace: padded with permutations of zero to a power of two of at least one SIMD
+/// vector.
+fn log_n_rows(n_permutations: usize) -> u32 {
+    n_permutations.next_power_of_two().ilog2().max(LOG_N_LANES)
+}
+
+pub fn prepare(input_size: usize) -> Result<PreparedPoseidon, BenchError> {
+    let inputs: Vec<BaseField> = utils::generate_poseidon_input_m31(input_size)
+        .into_iter()
+        .map(BaseField::from_u32_unchecked)
+        .collect();
+    let constants = RoundConstants::new();
+    let permutation_inputs = sponge(&constants, &inputs);
+    let eval = PoseidonEval {
+        log_n_rows: log_n_rows(permutation_inputs.len()),
+        constants,
+    };
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(eval.max_constraint_log_degree_bound() + fri_params().log_blowup)
+            .circle_domain()
+            .half_coset,
+    );
+    Ok(PreparedPoseidon {
+        eval,
+        permutation_inputs,
+        twiddles,
+    })
+}
+
+/// Absorbs `inputs` into the sponge without padding, returning the state entering every
+/// permutation. The digest would be the first 8 elements of the state after the last one.
+fn sponge(constants: &RoundConstants, inputs: &[BaseField]) -> Vec<[BaseField; WIDTH]> {
+    let mut state = [BaseField::zero(); WIDTH];
+    let mut states = Vec::with_capacity(inputs.len().div_ceil(RATE));
+    for chunk in inputs.chunks(RATE) {
+        state[..chunk.len()].copy_from_slice(chunk);
+        states.push(state);
+        state = permute(constants, state, |value| value);
+    }
+    states
+}
+
+/// Main trace: one permutation per row, padded with permutations of the zero state. The AIR
+/// has no constraint between rows, so the rows are in no particular order.
+pub fn gen_trace(prepared: &PreparedPoseidon) -> ColumnVec<TraceColumn> {
+    let n_rows = 1 << prepared.eval.log_n_rows;
+    let mut columns: Vec<Vec<BaseField>> =
+        (0..N_COLUMNS).map(|_| Vec::with_capacity(n_rows)).collect();
+    let padding = [BaseField::zero(); WIDTH];
+    for row in 0..n_rows {
+        let input = *prepared.permutation_inputs.get(row).unwrap_or(&padding);
+        let mut values = input.to_vec();
+        permute(&prepared.eval.constants, input, |value| {
+            values.push(value);
+            value
+        });
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+    let domain = CanonicCoset::new(prepared.eval.log_n_rows).circle_domain();
+    columns
+        .into_iter()
+        .map(|column| TraceColumn::new(domain, BaseColumn::from_iter(column)))
+        .collect()
+}
+
+pub fn prove_poseidon(prepared: &PreparedPoseidon) -> Result<Proof, BenchError> {
+    let channel = &mut channel(pcs_config(), prepared.permutation_inputs.len());
+    let mut commitment_scheme = CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(
+        pcs_config(),
+        &prepared.twiddles,
+    );
+
+    // No preprocessed columns.
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals([]);
+    tree_builder.commit(channel);
+
+    let trace = tracing::info_span!("generate_trace").in_scope(|| gen_trace(prepared));
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(trace);
+    tree_builder.commit(channel);
+
+    prove::<SimdBackend, Blake2sMerkleChannel>(
+        &[&component(&prepared.eval)],
+        channel,
+        commitment_scheme,
+    )
+    .map_err(|e| BenchError::prove(format!("{e:?}")))
+}
+
+pub fn verify_poseidon(prepared: &PreparedPoseidon, proof: &Proof) -> Result<(), BenchError> {
+    verify_statement(
+        &prepared.eval,
+        prepared.permutation_inputs.len(),
+        pcs_config(),
+        proof,
+    )
+}
+
+/// Verifies `proof` for `n_permutations` permutations, without the witness of
+/// [`PreparedPoseidon`].
+fn verify_statement(
+    eval: &PoseidonEval,
+    n_permutations: usize,
+    pcs_config: PcsConfig,
+    proof: &Proof,
+) -> Result<(), BenchError> {
+    let channel = &mut channel(pcs_config, n_permutations);
+    let commitment_scheme = &mut CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(pcs_config);
+    let component = component(eval);
+    let sizes = component.trace_log_degree_bounds();
+    commitment_scheme.commit(proof.commitments[0], &sizes[0], channel);
+    commitment_scheme.commit(proof.commitments[1], &sizes[1], channel);
+    verify(&[&component], channel, commitment_scheme, proof.clone())
+        .map_err(|e| BenchError::verify(format!("{e:?}")))
+}
+
+/// The proof in bincode; [`POSEIDON_TAG`] and the FRI parameters ([`stwo_air::pcs_config_bytes`])
+/// as the verification key, the round constants being fixed; the number of permutations (u64 LE)
+/// as the public inputs.
+pub fn export_artifacts(
+    prepared: &PreparedPoseidon,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut verification_key = vec![POSEIDON_TAG];
+    verification_key.extend(stwo_air::pcs_config_bytes(&pcs_config()));
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(|e| BenchError::prove(e.to_string()))?,
+        verification_key,
+        public_inputs: (prepared.permutation_inputs.len() as u64)
+            .to_le_bytes()
+            .to_vec(),
+        encoding: "stwo-bincode",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`], given the verification key without its
+/// tag.
+pub(crate) fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let pcs_config = stwo_air::pcs_config_from_bytes(verification_key)?;
+    let n_permutations = u64::from_le_bytes(
+        public_inputs
+            .try_into()
+            .context("expected the number of permutations")?,
+    ) as usize;
+    let eval = PoseidonEval {
+        log_n_rows: log_n_rows(n_permutations),
+        constants: RoundConstants::new(),
+    };
+    let proof: Proof = bincode::deserialize(proof)?;
+    verify_statement(&eval, n_permutations, pcs_config, &proof)?;
+    Ok(())
+}
+
+/// The AIR has no preprocessed trace.
+pub fn preprocessing_size(_prepared: &PreparedPoseidon) -> usize {
+    0
+}
+
+pub fn proof_size(proof: &Proof) -> usize {
+    bincode::serialize(proof).map(|v| v.len()).unwrap_or(0)
+}
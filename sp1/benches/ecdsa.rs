@@ -0,0 +1,25 @@
+use ere_sp1::compiler::RustRv32imaCustomized;
+use sp1::{
+    execution_cycles, export_artifacts, prepare_ecdsa, preprocessing_size, proof_size, prove_ecdsa,
+    sp1_bench_properties, verify_ecdsa,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::ECDSA_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Ecdsa,
+    ProvingSystem::Sp1,
+    None,
+    "ecdsa_mem_sp1",
+    sp1_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, ECDSA_BENCH)? },
+    prepare_ecdsa,
+    |_, _| 0,
+    prove_ecdsa,
+    verify_ecdsa,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,25 @@
+use ere_sp1::compiler::RustRv32imaCustomized;
+use sp1::{
+    execution_cycles, export_artifacts, prepare_keccak, preprocessing_size, proof_size, prove,
+    sp1_bench_properties, verify_keccak,
+};
+use utils::harness::ProvingSystem;
+use utils::zkvm::KECCAK_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Keccak,
+    ProvingSystem::Sp1,
+    None,
+    "keccak_mem_sp1",
+    sp1_bench_properties(),
+    { load_or_compile_program(&RustRv32imaCustomized, KECCAK_BENCH)? },
+    prepare_keccak,
+    |_, _| 0,
+    prove,
+    verify_keccak,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,16 @@
+#![no_main]
+
+use tiny_keccak::{Hasher, Keccak};
+
+sp1_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let input_bytes = sp1_zkvm::io::read_vec();
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&input_bytes);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    sp1_zkvm::io::commit_slice(&hash);
+}
@@ -1,20 +1,105 @@
 use ere_sp1::{EreSP1, compiler::RustRv32imaCustomized};
 use ere_zkvm_interface::ProverResource;
-use utils::zkvm::{CompiledProgram, PreparedSha256, build_input};
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::zkvm::{
+    CompiledProgram, PreparedEcdsa, PreparedKeccak, PreparedSha256, build_ecdsa_input, build_input,
+    encode_public_key,
+};
 
 pub use utils::zkvm::{
-    execution_cycles, preprocessing_size, proof_size, prove_sha256, verify_sha256,
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_ecdsa,
+    prove_sha256, verify_ecdsa, verify_keccak, verify_sha256,
 };
 
+pub fn sp1_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "STARK",
+        "BabyBear", // 15 × 2^27 + 1; https://docs.succinct.xyz/docs/sp1/security/security-model
+        "STARK",    // Plonky3 uni-STARK; https://github.com/succinctlabs/sp1
+        Some("FRI"), // https://docs.succinct.xyz/docs/sp1/security/security-model
+        "AIR",      // https://github.com/succinctlabs/sp1/tree/dev/crates/core/machine
+        false,      // core STARK proofs are not zero-knowledge
+        true,       // zkVM
+        100,        // conjectured; https://docs.succinct.xyz/docs/sp1/security/security-model
+        true,       // STARK is PQ-safe (Groth16/PLONK wrapping is not)
+        true,       // https://github.com/succinctlabs/sp1/releases
+        AuditStatus::Audited, // https://github.com/succinctlabs/sp1/tree/dev/audits
+        Some("RISC-V RV32IM"), // https://docs.succinct.xyz/docs/sp1/introduction
+    )
+    .with_onchain_verifier("evm") // Groth16/PLONK wrapping, SP1VerifierGateway
+}
+
 pub fn prepare_sha256(
     input_size: usize,
     program: &CompiledProgram<RustRv32imaCustomized>,
-) -> PreparedSha256<EreSP1> {
-    let vm = EreSP1::new(program.program.clone(), ProverResource::Cpu)
-        .expect("failed to build sp1 prover instance");
+) -> Result<PreparedSha256<EreSP1>, BenchError> {
+    let vm =
+        EreSP1::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
 
     let (message_bytes, digest) = utils::generate_sha256_input(input_size);
     let input = build_input(message_bytes);
 
-    PreparedSha256::with_expected_digest(vm, input, program.byte_size, digest)
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Prepares an ECDSA signature verification benchmark (single secp256k1 signature).
+pub fn prepare_ecdsa(
+    _input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedEcdsa<EreSP1>, BenchError> {
+    let vm =
+        EreSP1::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_k256_input();
+
+    let encoded_verifying_key =
+        encode_public_key(&pub_key_x, &pub_key_y).map_err(BenchError::setup)?;
+    let input = build_ecdsa_input(encoded_verifying_key, digest.clone(), signature)
+        .map_err(BenchError::setup)?;
+
+    Ok(PreparedEcdsa::with_expected_values(
+        vm,
+        input,
+        program.byte_size,
+        (pub_key_x, pub_key_y),
+        digest,
+    ))
+}
+
+/// Prepares a Keccak256 hash benchmark.
+pub fn prepare_keccak(
+    input_size: usize,
+    program: &CompiledProgram<RustRv32imaCustomized>,
+) -> Result<PreparedKeccak<EreSP1>, BenchError> {
+    let vm =
+        EreSP1::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (message_bytes, digest) = utils::generate_keccak_input(input_size);
+    let input = build_input(message_bytes);
+
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv32imaCustomized, _>(
+        |program| Ok(EreSP1::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
 }
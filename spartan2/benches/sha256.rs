@@ -1,6 +1,6 @@
 use spartan2_bench::{
-    num_constraints, prepare_sha256, preprocessing_size, proof_size, prove_sha256, verify_sha256,
-    SPARTAN2_BENCH_PROPERTIES,
+    export_artifacts, num_constraints, prepare_sha256, preprocessing_size, proof_size,
+    prove_sha256, verify_sha256, SPARTAN2_BENCH_PROPERTIES,
 };
 use utils::harness::ProvingSystem;
 
@@ -15,5 +15,6 @@ utils::define_benchmark_harness!(
     prove_sha256,
     verify_sha256,
     preprocessing_size,
-    proof_size
+    proof_size,
+    export_artifacts = export_artifacts
 );
@@ -0,0 +1,23 @@
+//! Instruction-count (iai-callgrind) variant of the SHA-256 bench. Requires valgrind and
+//! `iai-callgrind-runner`; run with
+//! `IAI_CALLGRIND_SAVE_SUMMARY=json cargo bench --features callgrind --bench sha256_iai`.
+//! The build script generates one bench per size `BENCH_INPUT_PROFILE` selects, with the
+//! Criterion group id as its id so `collect_benchmarks` can attach the counts.
+
+use iai_callgrind::{library_benchmark, library_benchmark_group, main};
+use spartan2::spartan::SpartanSNARK;
+use spartan2_bench::{prepare_sha256, prove_sha256, PreparedSha256, E};
+use std::hint::black_box;
+use utils::harness::BenchError;
+
+include!(concat!(env!("OUT_DIR"), "/callgrind_benches.rs"));
+
+callgrind_benches! {
+    fn prove(prepared: Result<PreparedSha256, BenchError>) -> SpartanSNARK<E> {
+        let prepared = prepared.expect("prepare failed");
+        black_box(prove_sha256(&prepared).expect("prove failed"))
+    }
+}
+
+library_benchmark_group!(name = sha256; benchmarks = prove);
+main!(library_benchmark_groups = sha256);
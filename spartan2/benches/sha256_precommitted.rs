@@ -0,0 +1,22 @@
+//! SHA256 with the preimage committed ahead of proving: `prepare` runs setup and `prep_prove`,
+//! so the timed `prove` covers only the hash evaluation.
+
+use spartan2_bench::{
+    num_constraints_precommitted, prepare_sha256_precommitted, preprocessing_size_precommitted,
+    proof_size, prove_sha256_precommitted, verify_sha256_precommitted, SPARTAN2_BENCH_PROPERTIES,
+};
+use utils::harness::ProvingSystem;
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::Spartan2,
+    Some("precommitted"),
+    "sha256_precommitted_mem_spartan2",
+    SPARTAN2_BENCH_PROPERTIES,
+    |input_size| { prepare_sha256_precommitted(input_size) },
+    num_constraints_precommitted,
+    prove_sha256_precommitted,
+    verify_sha256_precommitted,
+    preprocessing_size_precommitted,
+    proof_size
+);
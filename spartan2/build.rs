@@ -0,0 +1,12 @@
+//! Generates the benches of `sha256_iai` for the sizes `BENCH_INPUT_PROFILE` selects.
+
+fn main() {
+    #[cfg(feature = "callgrind")]
+    utils::callgrind::write_library_benches(
+        utils::harness::BenchTarget::Sha256,
+        utils::harness::ProvingSystem::Spartan2,
+        None,
+        "prepare_sha256",
+    )
+    .expect("failed to write the callgrind benches");
+}
@@ -0,0 +1,11 @@
+use spartan2_bench::{
+    prepare_sha256_precommitted, prove_sha256_precommitted, verify_sha256_precommitted,
+};
+
+fn main() {
+    utils::bench::mem::run_with_verify(
+        prepare_sha256_precommitted,
+        prove_sha256_precommitted,
+        verify_sha256_precommitted,
+    );
+}
@@ -1 +1,2 @@
 pub mod sha256_circuit;
+pub mod sha256_precommitted_circuit;
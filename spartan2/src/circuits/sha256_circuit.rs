@@ -78,66 +78,17 @@ impl SpartanCircuit<E> for Sha256Circuit {
         _: &[AllocatedNum<Scalar>], // shared variables, if any
     ) -> Result<Vec<AllocatedNum<Scalar>>, SynthesisError> {
         // 1. Preimage bits
-        let bit_values: Vec<_> = self
-            .preimage
-            .clone()
-            .into_iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
-            .map(Some)
-            .collect();
-        assert_eq!(bit_values.len(), self.preimage.len() * 8);
-
-        let preimage_bits = bit_values
-            .into_iter()
+        let preimage_bits = preimage_bit_values(&self.preimage)
             .enumerate()
-            .map(|(i, b)| AllocatedBit::alloc(cs.namespace(|| format!("preimage bit {i}")), b))
+            .map(|(i, b)| {
+                AllocatedBit::alloc(cs.namespace(|| format!("preimage bit {i}")), Some(b))
+            })
             .map(|b| b.map(Boolean::from))
             .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(preimage_bits.len(), self.preimage.len() * 8);
 
-        // 2. SHA-256 gadget
-        let hash_bits = sha256(cs.namespace(|| "sha256"), &preimage_bits)?;
-
-        // 3. Sanity-check against Rust SHA-256
-        let mut hasher = Sha256::new();
-        hasher.update(&self.preimage);
-        let expected = hasher.finalize();
-
-        let mut expected_bits = expected
-            .iter()
-            .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1));
-
-        for b in &hash_bits {
-            match b {
-                Boolean::Is(bit) => {
-                    assert_eq!(expected_bits.next().unwrap(), bit.get_value().unwrap())
-                }
-                Boolean::Not(bit) => {
-                    assert_ne!(expected_bits.next().unwrap(), bit.get_value().unwrap())
-                }
-                Boolean::Constant(_) => unreachable!(),
-            }
-        }
-
-        for (i, bit) in hash_bits.iter().enumerate() {
-            // Allocate public input
-            let n = AllocatedNum::alloc_input(cs.namespace(|| format!("public num {i}")), || {
-                Ok(
-                    if bit.get_value().ok_or(SynthesisError::AssignmentMissing)? {
-                        Scalar::ONE
-                    } else {
-                        Scalar::ZERO
-                    },
-                )
-            })?;
-
-            // Single equality constraint is enough
-            cs.enforce(
-                || format!("bit == num {i}"),
-                |_| bit.lc(CS::one(), Scalar::ONE),
-                |lc| lc + CS::one(),
-                |lc| lc + n.get_variable(),
-            );
-        }
+        // 2. SHA-256 gadget, with its digest as public values
+        hash_to_public_digest(cs, &self.preimage, &preimage_bits)?;
 
         Ok(vec![])
     }
@@ -157,3 +108,61 @@ impl SpartanCircuit<E> for Sha256Circuit {
         Ok(())
     }
 }
+
+/// Bits of `bytes`, most significant bit of each byte first.
+pub(crate) fn preimage_bit_values(bytes: &[u8]) -> impl Iterator<Item = bool> + '_ {
+    bytes
+        .iter()
+        .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+}
+
+/// Hashes `preimage_bits` with the SHA-256 gadget, checks the result against the native digest
+/// of `preimage` and exposes the digest bits as public inputs.
+pub(crate) fn hash_to_public_digest<CS: ConstraintSystem<Scalar>>(
+    cs: &mut CS,
+    preimage: &[u8],
+    preimage_bits: &[Boolean],
+) -> Result<(), SynthesisError> {
+    let hash_bits = sha256(cs.namespace(|| "sha256"), preimage_bits)?;
+
+    // Sanity-check against Rust SHA-256
+    let mut hasher = Sha256::new();
+    hasher.update(preimage);
+    let expected = hasher.finalize();
+
+    let mut expected_bits = preimage_bit_values(&expected);
+
+    for b in &hash_bits {
+        match b {
+            Boolean::Is(bit) => {
+                assert_eq!(expected_bits.next().unwrap(), bit.get_value().unwrap())
+            }
+            Boolean::Not(bit) => {
+                assert_ne!(expected_bits.next().unwrap(), bit.get_value().unwrap())
+            }
+            Boolean::Constant(_) => unreachable!(),
+        }
+    }
+
+    for (i, bit) in hash_bits.iter().enumerate() {
+        // Allocate public input
+        let n = AllocatedNum::alloc_input(cs.namespace(|| format!("public num {i}")), || {
+            Ok(
+                if bit.get_value().ok_or(SynthesisError::AssignmentMissing)? {
+                    Scalar::ONE
+                } else {
+                    Scalar::ZERO
+                },
+            )
+        })?;
+
+        // Single equality constraint is enough
+        cs.enforce(
+            || format!("bit == num {i}"),
+            |_| bit.lc(CS::one(), Scalar::ONE),
+            |lc| lc + CS::one(),
+            |lc| lc + n.get_variable(),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! SHA-256 circuit with the preimage committed ahead of the hash evaluation.
+//!
+//! Spartan2 commits the `precommitted` witness segment in `prep_prove`, separately from the rest
+//! of the witness. Here that segment holds only the preimage bits, and `synthesize` evaluates the
+//! hash over them, so a prover that commits to its input early only pays for the hash at proving
+//! time. The hash gadget needs booleans, so every precommitted bit is re-allocated as an
+//! `AllocatedBit` in `synthesize` and tied to it by one equality constraint.
+
+use super::sha256_circuit::{hash_to_public_digest, preimage_bit_values};
+use crate::{Scalar, E};
+use bellpepper_core::{
+    boolean::{AllocatedBit, Boolean},
+    num::AllocatedNum,
+    ConstraintSystem, SynthesisError,
+};
+use ff::Field;
+use spartan2::traits::circuit::SpartanCircuit;
+
+#[derive(Clone, Debug)]
+pub struct PrecommittedSha256Circuit {
+    preimage: Vec<u8>,
+}
+
+impl PrecommittedSha256Circuit {
+    pub fn new(preimage: Vec<u8>) -> Self {
+        Self { preimage }
+    }
+}
+
+impl SpartanCircuit<E> for PrecommittedSha256Circuit {
+    fn public_values(&self) -> Result<Vec<Scalar>, SynthesisError> {
+        super::sha256_circuit::Sha256Circuit::new(self.preimage.clone()).public_values()
+    }
+
+    fn shared<CS: ConstraintSystem<Scalar>>(
+        &self,
+        _: &mut CS,
+    ) -> Result<Vec<AllocatedNum<Scalar>>, SynthesisError> {
+        Ok(vec![])
+    }
+
+    fn precommitted<CS: ConstraintSystem<Scalar>>(
+        &self,
+        cs: &mut CS,
+        _: &[AllocatedNum<Scalar>],
+    ) -> Result<Vec<AllocatedNum<Scalar>>, SynthesisError> {
+        // Booleanity is enforced by the bits these are tied to in `synthesize`.
+        preimage_bit_values(&self.preimage)
+            .enumerate()
+            .map(|(i, b)| {
+                AllocatedNum::alloc(cs.namespace(|| format!("preimage bit {i}")), || {
+                    Ok(if b { Scalar::ONE } else { Scalar::ZERO })
+                })
+            })
+            .collect()
+    }
+
+    fn num_challenges(&self) -> usize {
+        0
+    }
+
+    fn synthesize<CS: ConstraintSystem<Scalar>>(
+        &self,
+        cs: &mut CS,
+        _: &[AllocatedNum<Scalar>],
+        precommitted: &[AllocatedNum<Scalar>],
+        _: Option<&[Scalar]>,
+    ) -> Result<(), SynthesisError> {
+        assert_eq!(precommitted.len(), self.preimage.len() * 8);
+        let preimage_bits = precommitted
+            .iter()
+            .zip(preimage_bit_values(&self.preimage))
+            .enumerate()
+            .map(|(i, (num, b))| {
+                let bit = AllocatedBit::alloc(cs.namespace(|| format!("bit {i}")), Some(b))?;
+                cs.enforce(
+                    || format!("bit {i} == precommitted {i}"),
+                    |lc| lc + bit.get_variable(),
+                    |lc| lc + CS::one(),
+                    |lc| lc + num.get_variable(),
+                );
+                Ok(Boolean::from(bit))
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        hash_to_public_digest(cs, &self.preimage, &preimage_bits)
+    }
+}
@@ -7,9 +7,16 @@ pub type Scalar = <E as spartan2::traits::Engine>::Scalar;
 pub mod circuits;
 
 use circuits::sha256_circuit::Sha256Circuit;
-use spartan2::{spartan::SpartanSNARK, traits::snark::R1CSSNARKTrait};
+use circuits::sha256_precommitted_circuit::PrecommittedSha256Circuit;
+use ff::PrimeField;
+use spartan2::{
+    spartan::SpartanSNARK,
+    traits::{circuit::SpartanCircuit, snark::R1CSSNARKTrait},
+};
+use utils::artifacts::ExportedArtifacts;
 use utils::generate_sha256_input;
-use utils::harness::{AuditStatus, BenchProperties};
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::witness_size::{ConstraintCount, WitnessSize};
 
 pub const SPARTAN2_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     proving_system: Cow::Borrowed("Spartan2"),
@@ -24,17 +31,25 @@ pub const SPARTAN2_BENCH_PROPERTIES: BenchProperties = BenchProperties {
     is_maintained: true,
     is_audited: AuditStatus::NotAudited,
     isa: None,
+    prover_resource: None,
+    version: Some(Cow::Borrowed("80a6a26")), // pinned rev
+    onchain_verifier: None,
+    caveats: Cow::Borrowed(&[]),
 };
 
+type ProverKey = <SpartanSNARK<E> as R1CSSNARKTrait<E>>::ProverKey;
+type VerifierKey = <SpartanSNARK<E> as R1CSSNARKTrait<E>>::VerifierKey;
+type PrepSnark = <SpartanSNARK<E> as R1CSSNARKTrait<E>>::PrepSNARK;
+
 /// Prepared context for SHA256 benchmark
 pub struct PreparedSha256 {
     circuit: Sha256Circuit,
-    pk: <SpartanSNARK<E> as R1CSSNARKTrait<E>>::ProverKey,
-    vk: <SpartanSNARK<E> as R1CSSNARKTrait<E>>::VerifierKey,
+    pk: ProverKey,
+    vk: VerifierKey,
 }
 
 /// Prepare SHA256 circuit for benchmarking
-pub fn prepare_sha256(input_size: usize) -> PreparedSha256 {
+pub fn prepare_sha256(input_size: usize) -> Result<PreparedSha256, BenchError> {
     // Generate SHA256 inputs
     let (preimage, _digest) = generate_sha256_input(input_size);
 
@@ -42,34 +57,51 @@ pub fn prepare_sha256(input_size: usize) -> PreparedSha256 {
     let circuit = Sha256Circuit::new(preimage);
 
     // Setup keys
-    let (pk, vk) = SpartanSNARK::<E>::setup(circuit.clone()).expect("setup failed");
+    let (pk, vk) = SpartanSNARK::<E>::setup(circuit.clone())
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
 
-    PreparedSha256 { circuit, pk, vk }
+    Ok(PreparedSha256 { circuit, pk, vk })
 }
 
 /// Generate proof for SHA256 circuit
-pub fn prove_sha256(prepared: &PreparedSha256) -> SpartanSNARK<E> {
+pub fn prove_sha256(prepared: &PreparedSha256) -> Result<SpartanSNARK<E>, BenchError> {
     // Prepare the SNARK
-    let prep_snark = SpartanSNARK::<E>::prep_prove(&prepared.pk, prepared.circuit.clone(), true)
-        .expect("prep_prove failed");
+    let prep_snark = tracing::info_span!("prep_prove")
+        .in_scope(|| SpartanSNARK::<E>::prep_prove(&prepared.pk, prepared.circuit.clone(), true))
+        .map_err(|e| BenchError::prove(format!("{e:?}")))?;
 
     // Generate proof
-    SpartanSNARK::<E>::prove(&prepared.pk, prepared.circuit.clone(), &prep_snark, true)
-        .expect("Failed to generate proof")
+    tracing::info_span!("snark_prove")
+        .in_scope(|| {
+            SpartanSNARK::<E>::prove(&prepared.pk, prepared.circuit.clone(), &prep_snark, true)
+        })
+        .map_err(|e| BenchError::prove(format!("{e:?}")))
 }
 
 /// Verify proof for SHA256 circuit
-pub fn verify_sha256(_prepared: &PreparedSha256, proof: &SpartanSNARK<E>) {
-    proof.verify(&_prepared.vk).expect("Verification failed");
+pub fn verify_sha256(prepared: &PreparedSha256, proof: &SpartanSNARK<E>) -> Result<(), BenchError> {
+    proof
+        .verify(&prepared.vk)
+        .map_err(|e| BenchError::verify(format!("{e:?}")))?;
+    Ok(())
+}
+
+/// Get number of constraints, with the witness length
+pub fn num_constraints(prepared: &PreparedSha256) -> ConstraintCount {
+    constraint_count(&prepared.pk)
 }
 
-/// Get number of constraints
-pub fn num_constraints(prepared: &PreparedSha256) -> usize {
+fn constraint_count(pk: &ProverKey) -> ConstraintCount {
     // Get number of constraints from the proving key's sizes
     // sizes() returns [num_cons_unpadded, num_shared_unpadded, num_precommitted_unpadded, num_rest_unpadded,
     //                  num_cons, num_shared, num_precommitted, num_rest, num_public, num_challenges]
-    let sizes = prepared.pk.sizes();
-    sizes[4] // num_cons (padded)
+    let sizes = pk.sizes();
+    // The witness is the shared, precommitted and remaining variables (padded), P-256 scalars
+    let witness_len = sizes[5] + sizes[6] + sizes[7];
+    ConstraintCount::with_witness(
+        sizes[4], // num_cons (padded)
+        WitnessSize::new(witness_len as u64, 32),
+    )
 }
 
 /// Get preprocessing size (proving key size)
@@ -85,3 +117,107 @@ pub fn proof_size(proof: &SpartanSNARK<E>) -> usize {
         .map(|bytes| bytes.len())
         .unwrap_or(0)
 }
+
+/// Prepared context for the precommitted SHA256 variant: the preimage bits are committed by
+/// `prep_prove` here, ahead of proving
+pub struct PreparedPrecommittedSha256 {
+    circuit: PrecommittedSha256Circuit,
+    pk: ProverKey,
+    vk: VerifierKey,
+    prep_snark: PrepSnark,
+}
+
+/// Prepare the precommitted SHA256 circuit: setup, then commit to the preimage
+pub fn prepare_sha256_precommitted(
+    input_size: usize,
+) -> Result<PreparedPrecommittedSha256, BenchError> {
+    let (preimage, _digest) = generate_sha256_input(input_size);
+    let circuit = PrecommittedSha256Circuit::new(preimage);
+
+    let (pk, vk) = SpartanSNARK::<E>::setup(circuit.clone())
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+    let prep_snark = SpartanSNARK::<E>::prep_prove(&pk, circuit.clone(), true)
+        .map_err(|e| BenchError::setup(format!("{e:?}")))?;
+
+    Ok(PreparedPrecommittedSha256 {
+        circuit,
+        pk,
+        vk,
+        prep_snark,
+    })
+}
+
+/// Generate a proof from the precommitted preimage, evaluating only the hash
+pub fn prove_sha256_precommitted(
+    prepared: &PreparedPrecommittedSha256,
+) -> Result<SpartanSNARK<E>, BenchError> {
+    SpartanSNARK::<E>::prove(
+        &prepared.pk,
+        prepared.circuit.clone(),
+        &prepared.prep_snark,
+        true,
+    )
+    .map_err(|e| BenchError::prove(format!("{e:?}")))
+}
+
+/// Verify proof for the precommitted SHA256 circuit
+pub fn verify_sha256_precommitted(
+    prepared: &PreparedPrecommittedSha256,
+    proof: &SpartanSNARK<E>,
+) -> Result<(), BenchError> {
+    proof
+        .verify(&prepared.vk)
+        .map_err(|e| BenchError::verify(format!("{e:?}")))?;
+    Ok(())
+}
+
+/// Get number of constraints of the precommitted SHA256 circuit (padded, see [`num_constraints`])
+pub fn num_constraints_precommitted(prepared: &PreparedPrecommittedSha256) -> ConstraintCount {
+    constraint_count(&prepared.pk)
+}
+
+/// Get preprocessing size (proving key size) of the precommitted SHA256 circuit
+pub fn preprocessing_size_precommitted(prepared: &PreparedPrecommittedSha256) -> usize {
+    bincode::serialize(&prepared.pk)
+        .map(|bytes| bytes.len())
+        .unwrap_or(0)
+}
+
+/// Export proof, verifier key and public values (digest bits as field element reprs)
+pub fn export_artifacts(
+    prepared: &PreparedSha256,
+    proof: &SpartanSNARK<E>,
+) -> Result<ExportedArtifacts, BenchError> {
+    let public_values = prepared
+        .circuit
+        .public_values()
+        .map_err(|e| BenchError::prove(format!("public values computation failed: {e:?}")))?;
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(BenchError::setup)?,
+        verification_key: bincode::serialize(&prepared.vk).map_err(BenchError::setup)?,
+        public_inputs: public_values
+            .iter()
+            .flat_map(|v| v.to_repr().as_ref().to_vec())
+            .collect(),
+        encoding: "bincode",
+    })
+}
+
+/// Verify artifacts exported with [`export_artifacts`]
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let vk: VerifierKey = bincode::deserialize(verification_key)?;
+    let proof: SpartanSNARK<E> = bincode::deserialize(proof)?;
+    let public_values = proof
+        .verify(&vk)
+        .map_err(|e| anyhow::anyhow!("verification failed: {:?}", e))?;
+    let public_values: Vec<u8> = public_values
+        .iter()
+        .flat_map(|v| v.to_repr().as_ref().to_vec())
+        .collect();
+    anyhow::ensure!(public_values == public_inputs, "public values mismatch");
+    Ok(())
+}
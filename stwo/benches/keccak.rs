@@ -0,0 +1,5 @@
+//! Keccak256 benchmark of the hand-written Stwo AIR.
+
+use stwo::KeccakSuite;
+
+utils::define_benchmark_suite!(KeccakSuite);
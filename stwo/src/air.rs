@@ -0,0 +1,285 @@
+//! Keccak-256 AIR: one row per Keccak-f round, 32 rows per permutation.
+//!
+//! The column layout follows Plonky3's `keccak-air`: θ is checked through the column parities
+//! `c` and `c'` (the sum of a θ-output column minus `c'` must be even), ρ and π are index
+//! remaps, and χ and ι are packed into 16-bit limbs so that consecutive rounds can be chained
+//! with linear constraints. Rows 24..32 of each permutation are idle zero rounds, which keeps
+//! permutation boundaries aligned to a power of two. Between permutations only the capacity
+//! lanes are chained: the rate lanes of the next permutation's input are the previous output
+//! XOR the next message block, and the message is private witness, as in the zkVM guests.
+//! Every constraint has degree at most 3.
+
+use crate::keccak::{pi_source, NUM_ROUNDS, RATE_LANES, RC_BITS, RHO_OFFSETS};
+use stwo_prover::constraint_framework::preprocessed_columns::PreProcessedColumnId;
+use stwo_prover::constraint_framework::{
+    EvalAtRow, FrameworkComponent, FrameworkEval, ORIGINAL_TRACE_IDX,
+};
+use stwo_prover::core::fields::m31::BaseField;
+
+pub const LOG_ROWS_PER_PERMUTATION: u32 = 5;
+pub const ROWS_PER_PERMUTATION: usize = 1 << LOG_ROWS_PER_PERMUTATION;
+/// Mask offset from the last round of a permutation to the first round of the next.
+pub const CHAIN_OFFSET: isize = (ROWS_PER_PERMUTATION - NUM_ROUNDS + 1) as isize;
+
+pub const LIMB_BITS: usize = 16;
+pub const LIMBS_PER_LANE: usize = 64 / LIMB_BITS;
+/// Lanes of the state that make up the Keccak-256 digest.
+pub const DIGEST_LANES: usize = 4;
+
+/// Main trace width: `a` limbs, `c` and `c'` bits, `a'` bits, `a''` limbs, the bits of
+/// `a''[0][0]` and the limbs of `a'''[0][0]`.
+pub const N_COLUMNS: usize =
+    25 * LIMBS_PER_LANE + 5 * 64 + 5 * 64 + 25 * 64 + 25 * LIMBS_PER_LANE + 64 + LIMBS_PER_LANE;
+
+/// Constraints per row, as counted by [`KeccakEval::evaluate`].
+pub const N_CONSTRAINTS: usize = (5 * 64 + 5 * 64 + 25 * 64 + 64) // booleanity
+    + 5 * 64 // c'
+    + 5 * 64 // θ parity
+    + 25 * LIMBS_PER_LANE // a
+    + 25 * LIMBS_PER_LANE // a''
+    + LIMBS_PER_LANE // a''[0][0] bits
+    + LIMBS_PER_LANE // ι
+    + 25 * LIMBS_PER_LANE // round transition
+    + 2 * (25 - RATE_LANES) * LIMBS_PER_LANE // capacity chaining and initial state
+    + DIGEST_LANES * LIMBS_PER_LANE; // digest
+
+/// Highest degree of the constraints of [`KeccakEval::evaluate`] (χ on bits, times a selector).
+pub const CONSTRAINT_DEGREE: u64 = 3;
+
+/// Fixed columns: row selectors and the bits of the round constants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreprocessedColumn {
+    /// Rounds 0..23 of every permutation, whose output is the next row's input.
+    IsTransition,
+    /// Last round of every permutation but the last, whose capacity lanes feed the next one.
+    IsChain,
+    /// First round of the first permutation, whose capacity lanes are zero.
+    IsInitial,
+    /// Last round of the last permutation, whose output holds the digest.
+    IsFinal,
+    /// Bit `RC_BITS[i]` of the round constant of each row; zero on idle rows.
+    RoundConstantBit(usize),
+}
+
+impl PreprocessedColumn {
+    pub fn all() -> Vec<Self> {
+        let mut columns = vec![
+            Self::IsTransition,
+            Self::IsChain,
+            Self::IsInitial,
+            Self::IsFinal,
+        ];
+        columns.extend((0..RC_BITS.len()).map(Self::RoundConstantBit));
+        columns
+    }
+
+    pub fn id(&self) -> PreProcessedColumnId {
+        let id = match self {
+            Self::IsTransition => "keccak_is_transition".to_string(),
+            Self::IsChain => "keccak_is_chain".to_string(),
+            Self::IsInitial => "keccak_is_initial".to_string(),
+            Self::IsFinal => "keccak_is_final".to_string(),
+            Self::RoundConstantBit(i) => format!("keccak_rc_bit_{}", RC_BITS[*i]),
+        };
+        PreProcessedColumnId { id }
+    }
+}
+
+/// Keccak-256 sponge over `n_permutations` permutations whose digest is `digest` (as lanes).
+#[derive(Clone, Debug)]
+pub struct KeccakEval {
+    pub log_n_rows: u32,
+    pub n_permutations: usize,
+    pub digest: [u64; DIGEST_LANES],
+}
+
+pub type KeccakComponent = FrameworkComponent<KeccakEval>;
+
+fn constant<E: EvalAtRow>(value: u32) -> E::F {
+    E::F::from(BaseField::from_u32_unchecked(value))
+}
+
+fn xor<E: EvalAtRow>(a: E::F, b: E::F) -> E::F {
+    a.clone() + b.clone() - constant::<E>(2) * a * b
+}
+
+fn xor3<E: EvalAtRow>(a: E::F, b: E::F, c: E::F) -> E::F {
+    xor::<E>(xor::<E>(a, b), c)
+}
+
+/// `a ^ (!b & c)` on bits.
+fn chi<E: EvalAtRow>(a: E::F, b: E::F, c: E::F) -> E::F {
+    xor::<E>(a, (constant::<E>(1) - b) * c)
+}
+
+/// `limb - Σ 2^i bits[i]`.
+fn pack_diff<E: EvalAtRow>(limb: E::F, bits: impl Iterator<Item = E::F>) -> E::F {
+    bits.enumerate()
+        .fold(limb, |acc, (i, bit)| acc - constant::<E>(1 << i) * bit)
+}
+
+impl FrameworkEval for KeccakEval {
+    fn log_size(&self) -> u32 {
+        self.log_n_rows
+    }
+
+    // Degree-3 constraints need a quotient of at most twice the trace degree.
+    fn max_constraint_log_degree_bound(&self) -> u32 {
+        self.log_n_rows + 1
+    }
+
+    fn evaluate<E: EvalAtRow>(&self, mut eval: E) -> E {
+        let is_transition = eval.get_preprocessed_column(PreprocessedColumn::IsTransition.id());
+        let is_chain = eval.get_preprocessed_column(PreprocessedColumn::IsChain.id());
+        let is_initial = eval.get_preprocessed_column(PreprocessedColumn::IsInitial.id());
+        let is_final = eval.get_preprocessed_column(PreprocessedColumn::IsFinal.id());
+        let rc: [E::F; RC_BITS.len()] = std::array::from_fn(|i| {
+            eval.get_preprocessed_column(PreprocessedColumn::RoundConstantBit(i).id())
+        });
+
+        let mut a = Vec::with_capacity(25);
+        let mut a_next = Vec::with_capacity(25);
+        let mut a_chain = Vec::with_capacity(25 - RATE_LANES);
+        for lane in 0..25 {
+            let mut cur = Vec::with_capacity(LIMBS_PER_LANE);
+            let mut next = Vec::with_capacity(LIMBS_PER_LANE);
+            let mut chain = Vec::with_capacity(LIMBS_PER_LANE);
+            for _ in 0..LIMBS_PER_LANE {
+                if lane < RATE_LANES {
+                    let [c, n] = eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1]);
+                    cur.push(c);
+                    next.push(n);
+                } else {
+                    let [c, n, ch] =
+                        eval.next_interaction_mask(ORIGINAL_TRACE_IDX, [0, 1, CHAIN_OFFSET]);
+                    cur.push(c);
+                    next.push(n);
+                    chain.push(ch);
+                }
+            }
+            a.push(cur);
+            a_next.push(next);
+            if lane >= RATE_LANES {
+                a_chain.push(chain);
+            }
+        }
+        let c: [[E::F; 64]; 5] =
+            std::array::from_fn(|_| std::array::from_fn(|_| eval.next_trace_mask()));
+        let c_prime: [[E::F; 64]; 5] =
+            std::array::from_fn(|_| std::array::from_fn(|_| eval.next_trace_mask()));
+        let a_prime: [[E::F; 64]; 25] =
+            std::array::from_fn(|_| std::array::from_fn(|_| eval.next_trace_mask()));
+        let a_prime_prime: [[E::F; LIMBS_PER_LANE]; 25] =
+            std::array::from_fn(|_| std::array::from_fn(|_| eval.next_trace_mask()));
+        let a_prime_prime_0_0_bits: [E::F; 64] = std::array::from_fn(|_| eval.next_trace_mask());
+        let a_prime_prime_prime_0_0: [E::F; LIMBS_PER_LANE] =
+            std::array::from_fn(|_| eval.next_trace_mask());
+
+        for bit in c
+            .iter()
+            .chain(&c_prime)
+            .chain(&a_prime)
+            .flatten()
+            .chain(&a_prime_prime_0_0_bits)
+        {
+            eval.add_constraint(bit.clone() * (bit.clone() - constant::<E>(1)));
+        }
+
+        // θ: c'[x][z] = c[x][z] ^ c[x - 1][z] ^ c[x + 1][z - 1].
+        for x in 0..5 {
+            for z in 0..64 {
+                let expected = xor3::<E>(
+                    c[x][z].clone(),
+                    c[(x + 4) % 5][z].clone(),
+                    c[(x + 1) % 5][(z + 63) % 64].clone(),
+                );
+                eval.add_constraint(c_prime[x][z].clone() - expected);
+            }
+        }
+        // The XOR of a θ-output column is c', i.e. their sum minus c' is 0, 2 or 4.
+        for x in 0..5 {
+            for z in 0..64 {
+                let diff = (0..5).fold(-c_prime[x][z].clone(), |acc, y| {
+                    acc + a_prime[x + 5 * y][z].clone()
+                });
+                eval.add_constraint(
+                    diff.clone() * (diff.clone() - constant::<E>(2)) * (diff - constant::<E>(4)),
+                );
+            }
+        }
+        // a = a' ^ c ^ c', which ties c to the column parities of a.
+        for lane in 0..25 {
+            let x = lane % 5;
+            for k in 0..LIMBS_PER_LANE {
+                let bits = (k * LIMB_BITS..(k + 1) * LIMB_BITS).map(|z| {
+                    xor3::<E>(
+                        a_prime[lane][z].clone(),
+                        c[x][z].clone(),
+                        c_prime[x][z].clone(),
+                    )
+                });
+                eval.add_constraint(pack_diff::<E>(a[lane][k].clone(), bits));
+            }
+        }
+
+        // ρ and π: bit z of lane (x, y) of B is a bit of the θ output.
+        let b = |x: usize, y: usize, z: usize| {
+            let (sx, sy) = pi_source(x, y);
+            let offset = RHO_OFFSETS[sx][sy] as usize;
+            a_prime[sx + 5 * sy][(z + 64 - offset) % 64].clone()
+        };
+        // χ.
+        for lane in 0..25 {
+            let (x, y) = (lane % 5, lane / 5);
+            for k in 0..LIMBS_PER_LANE {
+                let bits = (k * LIMB_BITS..(k + 1) * LIMB_BITS)
+                    .map(|z| chi::<E>(b(x, y, z), b((x + 1) % 5, y, z), b((x + 2) % 5, y, z)));
+                eval.add_constraint(pack_diff::<E>(a_prime_prime[lane][k].clone(), bits));
+            }
+        }
+        // ι, on the bits of lane (0, 0).
+        for k in 0..LIMBS_PER_LANE {
+            let bits = a_prime_prime_0_0_bits[k * LIMB_BITS..(k + 1) * LIMB_BITS]
+                .iter()
+                .cloned();
+            eval.add_constraint(pack_diff::<E>(a_prime_prime[0][k].clone(), bits));
+        }
+        for k in 0..LIMBS_PER_LANE {
+            let bits = (k * LIMB_BITS..(k + 1) * LIMB_BITS).map(|z| {
+                let bit = a_prime_prime_0_0_bits[z].clone();
+                match RC_BITS.iter().position(|&rc_bit| rc_bit == z) {
+                    Some(i) => xor::<E>(bit, rc[i].clone()),
+                    None => bit,
+                }
+            });
+            eval.add_constraint(pack_diff::<E>(a_prime_prime_prime_0_0[k].clone(), bits));
+        }
+
+        let output = |lane: usize, k: usize| match lane {
+            0 => a_prime_prime_prime_0_0[k].clone(),
+            _ => a_prime_prime[lane][k].clone(),
+        };
+        for lane in 0..25 {
+            for k in 0..LIMBS_PER_LANE {
+                eval.add_constraint(
+                    is_transition.clone() * (a_next[lane][k].clone() - output(lane, k)),
+                );
+            }
+        }
+        for lane in RATE_LANES..25 {
+            for k in 0..LIMBS_PER_LANE {
+                eval.add_constraint(
+                    is_chain.clone() * (a_chain[lane - RATE_LANES][k].clone() - output(lane, k)),
+                );
+                eval.add_constraint(is_initial.clone() * a[lane][k].clone());
+            }
+        }
+        for (lane, word) in self.digest.iter().enumerate() {
+            for k in 0..LIMBS_PER_LANE {
+                let limb = ((word >> (k * LIMB_BITS)) & 0xffff) as u32;
+                eval.add_constraint(is_final.clone() * (output(lane, k) - constant::<E>(limb)));
+            }
+        }
+        eval
+    }
+}
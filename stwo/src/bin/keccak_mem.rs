@@ -0,0 +1,7 @@
+//! Memory measurement binary for the Stwo Keccak256 AIR.
+
+use stwo::{prepare, prove_keccak, verify_keccak};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare, prove_keccak, verify_keccak);
+}
@@ -0,0 +1,5 @@
+//! Verifies artifacts exported by the Keccak256 benchmark, for the `verifiers` crate.
+
+fn main() -> anyhow::Result<()> {
+    utils::artifacts::verify_artifacts_main(stwo::verify_artifacts)
+}
@@ -0,0 +1,166 @@
+//! Native Keccak-256 sponge producing the per-round witness of the AIR.
+//!
+//! Lanes are indexed `x + 5 * y` and bits LSB-first, as in the Keccak reference.
+
+/// Keccak-256 rate in bytes (17 lanes).
+pub const RATE_BYTES: usize = 136;
+pub const RATE_LANES: usize = RATE_BYTES / 8;
+pub const NUM_ROUNDS: usize = 24;
+
+pub const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The only bit positions set in any round constant.
+pub const RC_BITS: [usize; 7] = [0, 1, 3, 7, 15, 31, 63];
+
+/// ρ rotation offsets, indexed `[x][y]`.
+pub const RHO_OFFSETS: [[u32; 5]; 5] = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+];
+
+/// Lane `(x, y)` of the state after π takes lane `pi_source(x, y)` of the state before it
+/// (`B[y][2x + 3y] = A[x][y]`).
+pub const fn pi_source(x: usize, y: usize) -> (usize, usize) {
+    ((x + 3 * y) % 5, x)
+}
+
+/// Witness of one round: its input state and the intermediate values the AIR constrains.
+#[derive(Clone, Debug, Default)]
+pub struct RoundWitness {
+    /// State entering the round.
+    pub a: [u64; 25],
+    /// Column parities of `a`.
+    pub c: [u64; 5],
+    /// `c[x] ^ c[x - 1] ^ rotl(c[x + 1], 1)`.
+    pub c_prime: [u64; 5],
+    /// State after θ.
+    pub a_prime: [u64; 25],
+    /// State after ρ, π and χ.
+    pub a_prime_prime: [u64; 25],
+    /// Lane `(0, 0)` after ι.
+    pub a_prime_prime_prime_0_0: u64,
+}
+
+impl RoundWitness {
+    /// Applies one round with round constant `rc` to `a`.
+    pub fn new(a: [u64; 25], rc: u64) -> Self {
+        let mut c = [0u64; 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            *c = (0..5).fold(0, |acc, y| acc ^ a[x + 5 * y]);
+        }
+        let mut c_prime = [0u64; 5];
+        for (x, c_prime) in c_prime.iter_mut().enumerate() {
+            *c_prime = c[x] ^ c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        }
+        let mut a_prime = [0u64; 25];
+        for (i, a_prime) in a_prime.iter_mut().enumerate() {
+            let x = i % 5;
+            *a_prime = a[i] ^ c[x] ^ c_prime[x];
+        }
+        let mut b = [0u64; 25];
+        for (i, b) in b.iter_mut().enumerate() {
+            let (x, y) = pi_source(i % 5, i / 5);
+            *b = a_prime[x + 5 * y].rotate_left(RHO_OFFSETS[x][y]);
+        }
+        let mut a_prime_prime = [0u64; 25];
+        for (i, a_prime_prime) in a_prime_prime.iter_mut().enumerate() {
+            let (x, y) = (i % 5, i / 5);
+            *a_prime_prime = b[i] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+        }
+        Self {
+            a,
+            c,
+            c_prime,
+            a_prime,
+            a_prime_prime,
+            a_prime_prime_prime_0_0: a_prime_prime[0] ^ rc,
+        }
+    }
+
+    /// State leaving the round.
+    pub fn output(&self) -> [u64; 25] {
+        let mut out = self.a_prime_prime;
+        out[0] = self.a_prime_prime_prime_0_0;
+        out
+    }
+}
+
+/// Rounds of one Keccak-f[1600] permutation of `state`.
+pub fn permutation_witness(state: [u64; 25]) -> Vec<RoundWitness> {
+    let mut rounds = Vec::with_capacity(NUM_ROUNDS);
+    let mut state = state;
+    for rc in ROUND_CONSTANTS {
+        let round = RoundWitness::new(state, rc);
+        state = round.output();
+        rounds.push(round);
+    }
+    rounds
+}
+
+/// Rounds of every permutation absorbing `message` (pad10*1), and the digest.
+pub fn sponge_witness(message: &[u8]) -> (Vec<Vec<RoundWitness>>, [u8; 32]) {
+    let mut padded = message.to_vec();
+    padded.push(0x01);
+    padded.resize(padded.len().div_ceil(RATE_BYTES) * RATE_BYTES, 0);
+    *padded.last_mut().expect("padded message is not empty") |= 0x80;
+
+    let mut state = [0u64; 25];
+    let mut permutations = Vec::with_capacity(padded.len() / RATE_BYTES);
+    for block in padded.chunks_exact(RATE_BYTES) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().expect("8-byte lane"));
+        }
+        let rounds = permutation_witness(state);
+        state = rounds.last().expect("24 rounds").output();
+        permutations.push(rounds);
+    }
+
+    let mut digest = [0u8; 32];
+    for (bytes, lane) in digest.chunks_exact_mut(8).zip(state) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    (permutations, digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sponge_matches_keccak256() {
+        for size in [0, 135, 136, 2048] {
+            let (message, expected) = utils::generate_keccak_input(size);
+            let (permutations, digest) = sponge_witness(&message);
+            assert_eq!(permutations.len(), size / RATE_BYTES + 1);
+            assert_eq!(digest.to_vec(), expected);
+        }
+    }
+}
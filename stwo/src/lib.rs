@@ -0,0 +1,390 @@
+//! Keccak-256 as a hand-written AIR proved directly with Stwo (Circle STARK over M31), as a
+//! baseline for the zkVMs built on it.
+
+pub mod air;
+pub mod keccak;
+pub mod trace;
+
+use air::{
+    KeccakComponent, KeccakEval, PreprocessedColumn, CONSTRAINT_DEGREE, DIGEST_LANES, N_COLUMNS,
+    ROWS_PER_PERMUTATION,
+};
+use anyhow::Context;
+use keccak::{sponge_witness, RATE_BYTES};
+use num_traits::Zero;
+use std::borrow::Cow;
+use stwo_prover::constraint_framework::{FrameworkEval, TraceLocationAllocator};
+use stwo_prover::core::air::Component;
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::channel::{Blake2sChannel, Channel};
+use stwo_prover::core::fields::qm31::SecureField;
+use stwo_prover::core::fri::FriConfig;
+use stwo_prover::core::pcs::{CommitmentSchemeProver, CommitmentSchemeVerifier, PcsConfig};
+use stwo_prover::core::poly::circle::{CanonicCoset, PolyOps};
+use stwo_prover::core::poly::twiddles::TwiddleTree;
+use stwo_prover::core::prover::{prove, verify, StarkProof};
+use stwo_prover::core::vcs::blake2_hash::Blake2sHash;
+use stwo_prover::core::vcs::blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher};
+use trace::{gen_preprocessed_trace, gen_trace, log_n_rows, TraceColumn};
+use utils::artifacts::ExportedArtifacts;
+use utils::caveat::Caveat;
+use utils::harness::circuit_size::{CircuitSize, TracePadding};
+use utils::harness::{
+    AuditStatus, BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite,
+    ProvingSystem,
+};
+use utils::security::{FriParams, QM31_BITS};
+
+pub use air::N_CONSTRAINTS;
+
+/// Circle-FRI parameters, the same as Rookie Numbers' defaults.
+pub const FRI_PARAMS: FriParams = FriParams {
+    log_blowup: 1,
+    queries: 70,
+    pow_bits: 26,
+    field_bits: QM31_BITS,
+};
+
+pub type Proof = StarkProof<Blake2sMerkleHasher>;
+
+pub fn stwo_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "Stwo",
+        "M31",
+        "FRI",
+        Some("Circle-PCS"),
+        "AIR",
+        false, // the trace is not blinded
+        false,
+        FRI_PARAMS.conjectured_security_bits(),
+        true,                    // hash-based PCS
+        true,                    // https://github.com/starkware-libs/stwo
+        AuditStatus::NotAudited, // no published audit of the prover
+        None,
+    )
+    .with_caveat(Caveat::DigestBakedIn)
+    .with_caveat(Caveat::Note {
+        text: Cow::Borrowed("message padding is private witness and not constrained"),
+    })
+}
+
+pub fn pcs_config() -> PcsConfig {
+    fri_pcs_config(FRI_PARAMS)
+}
+
+/// Circle-FRI configuration with the given parameters.
+pub fn fri_pcs_config(fri_params: FriParams) -> PcsConfig {
+    PcsConfig {
+        pow_bits: fri_params.pow_bits,
+        fri_config: FriConfig {
+            log_last_layer_degree_bound: 0,
+            log_blowup_factor: fri_params.log_blowup,
+            n_queries: fri_params.queries as usize,
+        },
+    }
+}
+
+/// A Keccak-256 statement with its fixed columns and their commitment, computed once per size.
+pub struct PreparedKeccak {
+    pub message: Vec<u8>,
+    pub eval: KeccakEval,
+    pub preprocessed_trace: Vec<TraceColumn>,
+    /// Root of the preprocessed trace the verifier expects.
+    pub preprocessed_root: Blake2sHash,
+    pub twiddles: TwiddleTree<SimdBackend>,
+    pub pcs_config: PcsConfig,
+}
+
+impl PreparedKeccak {
+    fn commitment_scheme(&self) -> CommitmentSchemeProver<'_, SimdBackend, Blake2sMerkleChannel> {
+        CommitmentSchemeProver::new(self.pcs_config, &self.twiddles)
+    }
+}
+
+fn component(eval: &KeccakEval) -> KeccakComponent {
+    let preprocessed_ids: Vec<_> = PreprocessedColumn::all()
+        .iter()
+        .map(PreprocessedColumn::id)
+        .collect();
+    KeccakComponent::new(
+        &mut TraceLocationAllocator::new_with_preproccessed_columns(&preprocessed_ids),
+        eval.clone(),
+        SecureField::zero(),
+    )
+}
+
+/// Channel bound to the PCS configuration and the public statement.
+fn channel(eval: &KeccakEval, pcs_config: PcsConfig) -> Blake2sChannel {
+    let mut channel = Blake2sChannel::default();
+    pcs_config.mix_into(&mut channel);
+    channel.mix_u64(eval.n_permutations as u64);
+    for lane in eval.digest {
+        channel.mix_u64(lane);
+    }
+    channel
+}
+
+pub fn prepare(input_size: usize) -> Result<PreparedKeccak, BenchError> {
+    prepare_with_fri_params(input_size, FRI_PARAMS)
+}
+
+/// [`prepare`] with other FRI parameters, for provers reusing this AIR at another security level.
+pub fn prepare_with_fri_params(
+    input_size: usize,
+    fri_params: FriParams,
+) -> Result<PreparedKeccak, BenchError> {
+    let pcs_config = fri_pcs_config(fri_params);
+    let (message, digest) = utils::generate_keccak_input(input_size);
+    let n_permutations = message.len() / RATE_BYTES + 1;
+    let log_n_rows = log_n_rows(n_permutations);
+    let eval = KeccakEval {
+        log_n_rows,
+        n_permutations,
+        digest: std::array::from_fn::<_, DIGEST_LANES, _>(|i| {
+            u64::from_le_bytes(
+                digest[8 * i..8 * (i + 1)]
+                    .try_into()
+                    .expect("32-byte digest"),
+            )
+        }),
+    };
+    let twiddles = SimdBackend::precompute_twiddles(
+        CanonicCoset::new(eval.max_constraint_log_degree_bound() + fri_params.log_blowup)
+            .circle_domain()
+            .half_coset,
+    );
+    let preprocessed_trace = gen_preprocessed_trace(log_n_rows, n_permutations);
+
+    // The root does not depend on the channel, so the verifier can pin it ahead of time.
+    let preprocessed_root = {
+        let mut commitment_scheme =
+            CommitmentSchemeProver::<SimdBackend, Blake2sMerkleChannel>::new(pcs_config, &twiddles);
+        let mut tree_builder = commitment_scheme.tree_builder();
+        tree_builder.extend_evals(preprocessed_trace.clone());
+        tree_builder.commit(&mut Blake2sChannel::default());
+        commitment_scheme.roots()[0]
+    };
+
+    Ok(PreparedKeccak {
+        message,
+        eval,
+        preprocessed_trace,
+        preprocessed_root,
+        twiddles,
+        pcs_config,
+    })
+}
+
+pub fn prove_keccak(prepared: &PreparedKeccak) -> Result<Proof, BenchError> {
+    let channel = &mut channel(&prepared.eval, prepared.pcs_config);
+    let mut commitment_scheme = prepared.commitment_scheme();
+
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(prepared.preprocessed_trace.clone());
+    tree_builder.commit(channel);
+
+    let (permutations, _) = sponge_witness(&prepared.message);
+    let mut tree_builder = commitment_scheme.tree_builder();
+    tree_builder.extend_evals(gen_trace(prepared.eval.log_n_rows, &permutations));
+    tree_builder.commit(channel);
+
+    prove::<SimdBackend, Blake2sMerkleChannel>(
+        &[&component(&prepared.eval)],
+        channel,
+        commitment_scheme,
+    )
+    .map_err(|e| BenchError::prove(format!("{e:?}")))
+}
+
+pub fn verify_keccak(prepared: &PreparedKeccak, proof: &Proof) -> Result<(), BenchError> {
+    verify_statement(
+        &prepared.eval,
+        prepared.pcs_config,
+        prepared.preprocessed_root,
+        proof,
+    )
+}
+
+/// Verifies `proof` for the statement of `eval` against the pinned preprocessed root, without
+/// the prover-side data of [`PreparedKeccak`].
+fn verify_statement(
+    eval: &KeccakEval,
+    pcs_config: PcsConfig,
+    preprocessed_root: Blake2sHash,
+    proof: &Proof,
+) -> Result<(), BenchError> {
+    BenchError::check(
+        proof.commitments[0] == preprocessed_root,
+        "proof commits to a different preprocessed trace",
+    )?;
+    let channel = &mut channel(eval, pcs_config);
+    let commitment_scheme = &mut CommitmentSchemeVerifier::<Blake2sMerkleChannel>::new(pcs_config);
+    let component = component(eval);
+    let sizes = component.trace_log_degree_bounds();
+    commitment_scheme.commit(proof.commitments[0], &sizes[0], channel);
+    commitment_scheme.commit(proof.commitments[1], &sizes[1], channel);
+    verify(&[&component], channel, commitment_scheme, proof.clone())
+        .map_err(|e| BenchError::verify(format!("{e:?}")))
+}
+
+/// The proof in bincode; the root of the preprocessed trace followed by the FRI parameters
+/// ([`pcs_config_bytes`]) as the verification key; the number of permutations and the digest
+/// lanes (u64 LE) as the public inputs.
+pub fn export_artifacts(
+    prepared: &PreparedKeccak,
+    proof: &Proof,
+) -> Result<ExportedArtifacts, BenchError> {
+    let mut verification_key = prepared.preprocessed_root.0.to_vec();
+    verification_key.extend(pcs_config_bytes(&prepared.pcs_config));
+    let public_inputs = std::iter::once(prepared.eval.n_permutations as u64)
+        .chain(prepared.eval.digest)
+        .flat_map(u64::to_le_bytes)
+        .collect();
+    Ok(ExportedArtifacts {
+        proof: bincode::serialize(proof).map_err(|e| BenchError::prove(e.to_string()))?,
+        verification_key,
+        public_inputs,
+        encoding: "stwo-bincode",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`], also by the provers reusing this AIR
+/// at other FRI parameters.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let (root, fri) = verification_key
+        .split_first_chunk::<32>()
+        .context("verification key shorter than the preprocessed root")?;
+    let pcs_config = pcs_config_from_bytes(fri)?;
+
+    let [n_permutations, digest @ ..] = words::<8, { 1 + DIGEST_LANES }>(public_inputs)
+        .context("expected the number of permutations and the digest lanes")?
+        .map(u64::from_le_bytes);
+    let n_permutations = n_permutations as usize;
+    let eval = KeccakEval {
+        log_n_rows: log_n_rows(n_permutations),
+        n_permutations,
+        digest,
+    };
+
+    let proof: Proof = bincode::deserialize(proof)?;
+    verify_statement(&eval, pcs_config, Blake2sHash(*root), &proof)?;
+    Ok(())
+}
+
+/// The FRI parameters of `pcs_config` (log blowup, queries, proof-of-work bits and log last-layer
+/// degree bound), u32 LE each, as exported in the verification keys of Stwo AIRs.
+pub fn pcs_config_bytes(pcs_config: &PcsConfig) -> Vec<u8> {
+    let fri_config = pcs_config.fri_config;
+    [
+        fri_config.log_blowup_factor,
+        fri_config.n_queries as u32,
+        pcs_config.pow_bits,
+        fri_config.log_last_layer_degree_bound,
+    ]
+    .into_iter()
+    .flat_map(u32::to_le_bytes)
+    .collect()
+}
+
+/// Inverse of [`pcs_config_bytes`].
+pub fn pcs_config_from_bytes(bytes: &[u8]) -> anyhow::Result<PcsConfig> {
+    let [log_blowup_factor, n_queries, pow_bits, log_last_layer_degree_bound] = words(bytes)
+        .context("expected 4 FRI parameters")?
+        .map(u32::from_le_bytes);
+    Ok(PcsConfig {
+        pow_bits,
+        fri_config: FriConfig {
+            log_last_layer_degree_bound,
+            log_blowup_factor,
+            n_queries: n_queries as usize,
+        },
+    })
+}
+
+/// Splits `bytes` into exactly `N` words of `W` bytes.
+fn words<const W: usize, const N: usize>(bytes: &[u8]) -> Option<[[u8; W]; N]> {
+    let (words, rest) = bytes.as_chunks::<W>();
+    rest.is_empty().then_some(())?;
+    words.try_into().ok()
+}
+
+/// Size of the preprocessed trace (selectors and round-constant bits).
+pub fn preprocessing_size(prepared: &PreparedKeccak) -> usize {
+    PreprocessedColumn::all().len() * (1 << prepared.eval.log_n_rows) * size_of::<u32>()
+}
+
+pub fn proof_size(proof: &Proof) -> usize {
+    bincode::serialize(proof).map(|v| v.len()).unwrap_or(0)
+}
+
+/// Main trace of the AIR, 32 rows per permutation padded to a power of two, with the highest
+/// degree of its constraints.
+pub fn circuit_size(prepared: &PreparedKeccak) -> CircuitSize {
+    CircuitSize::from_trace(
+        (prepared.eval.n_permutations * ROWS_PER_PERMUTATION) as u64,
+        Some(TracePadding::PowerOfTwo),
+        Some(N_COLUMNS as u64),
+    )
+    .with_constraint_degree(CONSTRAINT_DEGREE)
+}
+
+/// Keccak-256 with the AIR statistics of [`circuit_size`] next to the constraint count.
+pub struct KeccakSuite;
+
+impl BenchmarkSuite for KeccakSuite {
+    type Prepared = PreparedKeccak;
+    type Proof = Proof;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        BenchHarnessConfig {
+            target: BenchTarget::Keccak,
+            system: ProvingSystem::Stwo,
+            feature: None,
+            mem_binary_name: "keccak_mem_stwo",
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        stwo_bench_properties()
+    }
+
+    fn prepare(&self, size: usize) -> Result<PreparedKeccak, BenchError> {
+        prepare(size)
+    }
+
+    fn prove(&self, prepared: &PreparedKeccak) -> Result<Proof, BenchError> {
+        prove_keccak(prepared)
+    }
+
+    fn verify(&self, prepared: &PreparedKeccak, proof: &Proof) -> Result<(), BenchError> {
+        verify_keccak(prepared, proof)
+    }
+
+    fn num_constraints(&self, _prepared: &PreparedKeccak) -> usize {
+        N_CONSTRAINTS
+    }
+
+    fn preprocessing_size(&self, prepared: &PreparedKeccak) -> Result<usize, BenchError> {
+        Ok(preprocessing_size(prepared))
+    }
+
+    fn proof_size(&self, proof: &Proof) -> usize {
+        proof_size(proof)
+    }
+
+    fn circuit_size(&self, prepared: &PreparedKeccak) -> Option<CircuitSize> {
+        Some(circuit_size(prepared))
+    }
+
+    fn export_artifacts(
+        &self,
+        prepared: &PreparedKeccak,
+        proof: &Proof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        export_artifacts(prepared, proof).map(Some)
+    }
+}
@@ -0,0 +1,107 @@
+//! Trace generation for [`KeccakEval`](crate::air::KeccakEval).
+
+use crate::air::{
+    PreprocessedColumn, LIMBS_PER_LANE, LIMB_BITS, LOG_ROWS_PER_PERMUTATION, N_COLUMNS,
+    ROWS_PER_PERMUTATION,
+};
+use crate::keccak::{permutation_witness, RoundWitness, NUM_ROUNDS, RC_BITS, ROUND_CONSTANTS};
+use stwo_prover::core::backend::simd::column::BaseColumn;
+use stwo_prover::core::backend::simd::SimdBackend;
+use stwo_prover::core::fields::m31::BaseField;
+use stwo_prover::core::poly::circle::{CanonicCoset, CircleEvaluation};
+use stwo_prover::core::poly::BitReversedOrder;
+use stwo_prover::core::utils::{bit_reverse_index, coset_index_to_circle_domain_index};
+use stwo_prover::core::ColumnVec;
+
+pub type TraceColumn = CircleEvaluation<SimdBackend, BaseField, BitReversedOrder>;
+
+/// Rows of a trace holding `n_permutations` permutations.
+pub fn log_n_rows(n_permutations: usize) -> u32 {
+    n_permutations.next_power_of_two().ilog2() + LOG_ROWS_PER_PERMUTATION
+}
+
+/// Main-trace values of one row, in the order [`KeccakEval::evaluate`] reads them.
+///
+/// [`KeccakEval::evaluate`]: stwo_prover::constraint_framework::FrameworkEval::evaluate
+pub fn row_values(round: &RoundWitness) -> Vec<u32> {
+    let limbs =
+        |lane: u64| (0..LIMBS_PER_LANE).map(move |k| ((lane >> (k * LIMB_BITS)) & 0xffff) as u32);
+    let bits = |lane: u64| (0..64).map(move |z| ((lane >> z) & 1) as u32);
+
+    let mut values = Vec::with_capacity(N_COLUMNS);
+    values.extend(round.a.iter().flat_map(|&lane| limbs(lane)));
+    values.extend(round.c.iter().flat_map(|&lane| bits(lane)));
+    values.extend(round.c_prime.iter().flat_map(|&lane| bits(lane)));
+    values.extend(round.a_prime.iter().flat_map(|&lane| bits(lane)));
+    values.extend(round.a_prime_prime.iter().flat_map(|&lane| limbs(lane)));
+    values.extend(bits(round.a_prime_prime[0]));
+    values.extend(limbs(round.a_prime_prime_prime_0_0));
+    values
+}
+
+/// Value of a preprocessed column at (natural-order) `row`.
+pub fn preprocessed_value(column: PreprocessedColumn, row: usize, n_permutations: usize) -> u32 {
+    let (permutation, round) = (row / ROWS_PER_PERMUTATION, row % ROWS_PER_PERMUTATION);
+    let last_round = NUM_ROUNDS - 1;
+    let value = match column {
+        PreprocessedColumn::IsTransition => round < last_round,
+        PreprocessedColumn::IsChain => round == last_round && permutation + 1 < n_permutations,
+        PreprocessedColumn::IsInitial => row == 0,
+        PreprocessedColumn::IsFinal => round == last_round && permutation + 1 == n_permutations,
+        PreprocessedColumn::RoundConstantBit(i) => {
+            round < NUM_ROUNDS && (ROUND_CONSTANTS[round] >> RC_BITS[i]) & 1 == 1
+        }
+    };
+    value as u32
+}
+
+/// Position of natural-order `row` in a bit-reversed circle-domain column, so that mask offset 1
+/// is the next row.
+fn column_index(row: usize, log_size: u32) -> usize {
+    bit_reverse_index(coset_index_to_circle_domain_index(row, log_size), log_size)
+}
+
+fn to_evaluations(log_size: u32, columns: Vec<Vec<BaseField>>) -> ColumnVec<TraceColumn> {
+    let domain = CanonicCoset::new(log_size).circle_domain();
+    columns
+        .into_iter()
+        .map(|column| TraceColumn::new(domain, BaseColumn::from_iter(column)))
+        .collect()
+}
+
+/// Main trace of `permutations`, padded with permutations of the zero state.
+pub fn gen_trace(log_size: u32, permutations: &[Vec<RoundWitness>]) -> ColumnVec<TraceColumn> {
+    let n_rows = 1 << log_size;
+    let mut columns = vec![vec![BaseField::from_u32_unchecked(0); n_rows]; N_COLUMNS];
+    let padding = permutation_witness([0; 25]);
+    let idle = RoundWitness::default();
+    for row in 0..n_rows {
+        let (permutation, round) = (row / ROWS_PER_PERMUTATION, row % ROWS_PER_PERMUTATION);
+        let witness = match permutations.get(permutation).unwrap_or(&padding).get(round) {
+            Some(witness) => witness,
+            None => &idle,
+        };
+        let index = column_index(row, log_size);
+        for (column, value) in columns.iter_mut().zip(row_values(witness)) {
+            column[index] = BaseField::from_u32_unchecked(value);
+        }
+    }
+    to_evaluations(log_size, columns)
+}
+
+/// Preprocessed trace, in the order of [`PreprocessedColumn::all`].
+pub fn gen_preprocessed_trace(log_size: u32, n_permutations: usize) -> ColumnVec<TraceColumn> {
+    let n_rows = 1 << log_size;
+    let columns = PreprocessedColumn::all()
+        .into_iter()
+        .map(|column| {
+            let mut values = vec![BaseField::from_u32_unchecked(0); n_rows];
+            for row in 0..n_rows {
+                values[column_index(row, log_size)] =
+                    BaseField::from_u32_unchecked(preprocessed_value(column, row, n_permutations));
+            }
+            values
+        })
+        .collect();
+    to_evaluations(log_size, columns)
+}
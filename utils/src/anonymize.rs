@@ -0,0 +1,155 @@
+//! Stripping machine-identifying metadata from result files before publication.
+//!
+//! Results from external contributors are merged into the public dataset, but a collected
+//! results file or a metrics file can carry the CI workflow and artifact URLs of the run,
+//! hostnames, and home directories of the contributor's machine in failure logs and `BENCH_*`
+//! settings. [`anonymize`] removes them and adds a salted `machine_fingerprint` to every
+//! `environment` block instead, so measurements taken on the same machine can still be
+//! correlated across runs without revealing which machine it was.
+
+use regex::Regex;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::sync::LazyLock;
+
+/// Secret salt of the machine fingerprints. Keep it the same across runs to correlate them, and
+/// private, so fingerprints cannot be matched against guessed machine identities.
+pub const SALT_ENV: &str = "BENCH_ANONYMIZE_SALT";
+
+/// Keys whose values identify the machine or the CI run; they are dropped wherever they occur.
+const REMOVED_KEYS: [&str; 4] = ["workflow_run_url", "artifact_urls", "hostname", "host"];
+
+/// Key of the salted fingerprint added to every `environment` block.
+pub const FINGERPRINT_KEY: &str = "machine_fingerprint";
+
+/// Hex digits of the SHA-256 kept in the fingerprint.
+const FINGERPRINT_HEX_LEN: usize = 16;
+
+/// Home directories (`/home/<user>`, `/Users/<user>`, `/root`, `C:\Users\<user>`) at the start
+/// of an absolute path.
+static HOME_DIR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(^|[\s"'=:(\[])(?:/home/[^/\s"']+|/Users/[^/\s"']+|/root\b|[A-Za-z]:\\Users\\[^\\\s"']+)"#)
+        .expect("valid home directory pattern")
+});
+
+/// Anonymizes a parsed results file in place: drops the [`REMOVED_KEYS`], replaces home
+/// directories in every string with `~` and fingerprints every `environment` block.
+///
+/// A block's fingerprint hashes `salt` with the block itself and the hostname next to it, if
+/// any, before they are stripped. Blocks that already carry a fingerprint keep it, so
+/// anonymizing twice changes nothing.
+pub fn anonymize(value: &mut Value, salt: &[u8]) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Object(environment)) = object.get("environment")
+                && !environment.contains_key(FINGERPRINT_KEY)
+            {
+                let fingerprint = machine_fingerprint(salt, environment, host(object));
+                if let Some(Value::Object(environment)) = object.get_mut("environment") {
+                    environment.insert(FINGERPRINT_KEY.to_string(), Value::String(fingerprint));
+                }
+            }
+            object.retain(|key, _| !REMOVED_KEYS.contains(&key.as_str()));
+            for child in object.values_mut() {
+                anonymize(child, salt);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                anonymize(item, salt);
+            }
+        }
+        Value::String(s) => {
+            if let std::borrow::Cow::Owned(redacted) = HOME_DIR.replace_all(s, "${1}~") {
+                *s = redacted;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+fn host(object: &Map<String, Value>) -> Option<&str> {
+    ["hostname", "host"]
+        .iter()
+        .find_map(|key| object.get(*key)?.as_str())
+}
+
+fn machine_fingerprint(
+    salt: &[u8],
+    environment: &Map<String, Value>,
+    host: Option<&str>,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    // `serde_json` keeps object keys sorted, so equal blocks serialize to equal bytes.
+    hasher.update(serde_json::to_vec(environment).expect("JSON values serialize"));
+    if let Some(host) = host {
+        hasher.update(host.as_bytes());
+    }
+    let mut fingerprint = hex::encode(hasher.finalize());
+    fingerprint.truncate(FINGERPRINT_HEX_LEN);
+    fingerprint
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn collected(host: &str, stderr: &str) -> Value {
+        json!({
+            "metadata": {
+                "workflow_run_url": "https://github.com/org/repo/actions/runs/1",
+                "artifact_urls": ["https://example.com/artifact"],
+                "manifest": { "bench_env": { "BENCH_EXPORT_ARTIFACTS": "/home/alice/exports" } }
+            },
+            "measurements": [{
+                "system": "sp1",
+                "hostname": host,
+                "environment": { "in_container": false, "glibc_version": "2.39" },
+                "failures": [{ "stderr_tail": stderr }]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_identifying_metadata_is_stripped() {
+        let mut value = collected(
+            "alice-laptop",
+            "thread 'main' panicked at /Users/alice/src/lib.rs:3\nsee C:\\Users\\alice\\log and /root/.cargo",
+        );
+        anonymize(&mut value, b"salt");
+
+        assert!(value["metadata"].get("workflow_run_url").is_none());
+        assert!(value["metadata"].get("artifact_urls").is_none());
+        assert_eq!(
+            value["metadata"]["manifest"]["bench_env"]["BENCH_EXPORT_ARTIFACTS"],
+            "~/exports"
+        );
+        let measurement = &value["measurements"][0];
+        assert!(measurement.get("hostname").is_none());
+        assert_eq!(
+            measurement["failures"][0]["stderr_tail"],
+            "thread 'main' panicked at ~/src/lib.rs:3\nsee ~\\log and ~/.cargo"
+        );
+        assert_eq!(measurement["environment"]["glibc_version"], "2.39");
+
+        let fingerprint = measurement["environment"][FINGERPRINT_KEY].clone();
+        assert_eq!(fingerprint.as_str().unwrap().len(), FINGERPRINT_HEX_LEN);
+        let before = value.clone();
+        anonymize(&mut value, b"other salt");
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_machine_and_salt() {
+        let fingerprint = |host: &str, salt: &[u8]| {
+            let mut value = collected(host, "");
+            anonymize(&mut value, salt);
+            value["measurements"][0]["environment"][FINGERPRINT_KEY].clone()
+        };
+        assert_eq!(fingerprint("a", b"salt"), fingerprint("a", b"salt"));
+        assert_ne!(fingerprint("a", b"salt"), fingerprint("b", b"salt"));
+        assert_ne!(fingerprint("a", b"salt"), fingerprint("a", b"pepper"));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::BenchId;
+use crate::bench::Metrics;
+use crate::harness::BenchProperties;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that enables the artifact export mode. When set, the harness writes
+/// the proof, verification key and public inputs of every measurement under this directory.
+pub const EXPORT_DIR_ENV: &str = "BENCH_EXPORT_ARTIFACTS";
+
+/// File names used inside each exported measurement directory.
+pub const PROOF_FILE: &str = "proof.bin";
+pub const VERIFICATION_KEY_FILE: &str = "verification_key.bin";
+pub const PUBLIC_INPUTS_FILE: &str = "public_inputs.bin";
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Serialized artifacts needed to re-verify a proof outside of the benchmark harness.
+///
+/// The byte encodings are system-specific (e.g. bincode for zkVM programs, the native
+/// serializer for circuit systems); `encoding` documents which one was used.
+#[derive(Clone, Debug, Default)]
+pub struct ExportedArtifacts {
+    pub proof: Vec<u8>,
+    pub verification_key: Vec<u8>,
+    pub public_inputs: Vec<u8>,
+    pub encoding: &'static str,
+}
+
+/// A single exported file, described by its relative path, size and SHA-256 digest.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ArtifactFile {
+    pub path: String,
+    pub size: usize,
+    pub sha256: String,
+}
+
+impl ArtifactFile {
+    fn describe(path: &str, bytes: &[u8]) -> Self {
+        Self {
+            path: path.to_string(),
+            size: bytes.len(),
+            sha256: hex::encode(Sha256::digest(bytes)),
+        }
+    }
+}
+
+/// Manifest written next to the exported artifacts of one measurement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArtifactManifest {
+    pub name: String,
+    pub feat: Option<String>,
+    pub target: String,
+    pub input_size: usize,
+    pub encoding: String,
+    pub proof: ArtifactFile,
+    pub verification_key: ArtifactFile,
+    pub public_inputs: ArtifactFile,
+    pub bench_properties: BenchProperties,
+}
+
+/// Returns the export root directory if the export mode is enabled.
+pub fn export_dir() -> Option<PathBuf> {
+    std::env::var(EXPORT_DIR_ENV)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Directory holding the artifacts of one measurement:
+/// `<root>/<target>_<input_size>_<system>[_<feat>]/`.
+pub fn measurement_dir(root: &Path, metrics: &Metrics) -> PathBuf {
+    let id = BenchId::new(
+        &metrics.target,
+        metrics.input_size,
+        &metrics.name,
+        metrics.feat.as_deref(),
+    );
+    root.join(id.to_string())
+}
+
+/// Writes the artifacts and their manifest for the measurement described by `metrics`.
+/// Returns the directory the files were written to.
+pub fn write_artifacts(
+    root: &Path,
+    metrics: &Metrics,
+    artifacts: &ExportedArtifacts,
+) -> io::Result<PathBuf> {
+    let dir = measurement_dir(root, metrics);
+    fs::create_dir_all(&dir)?;
+
+    fs::write(dir.join(PROOF_FILE), &artifacts.proof)?;
+    fs::write(dir.join(VERIFICATION_KEY_FILE), &artifacts.verification_key)?;
+    fs::write(dir.join(PUBLIC_INPUTS_FILE), &artifacts.public_inputs)?;
+
+    let manifest = ArtifactManifest {
+        name: metrics.name.clone(),
+        feat: metrics.feat.clone(),
+        target: metrics.target.clone(),
+        input_size: metrics.input_size,
+        encoding: artifacts.encoding.to_string(),
+        proof: ArtifactFile::describe(PROOF_FILE, &artifacts.proof),
+        verification_key: ArtifactFile::describe(
+            VERIFICATION_KEY_FILE,
+            &artifacts.verification_key,
+        ),
+        public_inputs: ArtifactFile::describe(PUBLIC_INPUTS_FILE, &artifacts.public_inputs),
+        bench_properties: metrics.bench_properties.clone(),
+    };
+    let json = serde_json::to_string_pretty(&manifest).map_err(io::Error::other)?;
+    fs::write(dir.join(MANIFEST_FILE), json)?;
+
+    Ok(dir)
+}
+
+/// Entry point of the `verify_artifacts_<system>` binaries of the crates kept out of the
+/// workspace, which the `verifiers` crate runs as a subprocess: verifies the artifacts in the
+/// directory given as the only argument with the crate's `verify_artifacts`.
+pub fn verify_artifacts_main(
+    verify: impl FnOnce(&[u8], &[u8], &[u8]) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let dir = PathBuf::from(
+        std::env::args_os()
+            .nth(1)
+            .context("usage: verify_artifacts_<system> <artifacts dir>")?,
+    );
+    let read = |file: &str| {
+        fs::read(dir.join(file))
+            .with_context(|| format!("failed to read {}", dir.join(file).display()))
+    };
+    verify(
+        &read(PROOF_FILE)?,
+        &read(VERIFICATION_KEY_FILE)?,
+        &read(PUBLIC_INPUTS_FILE)?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_metrics(feat: Option<&str>) -> Metrics {
+        Metrics::new(
+            "risc0".to_string(),
+            feat.map(str::to_string),
+            "sha256".to_string(),
+            128,
+            BenchProperties::default(),
+        )
+    }
+
+    #[test]
+    fn test_measurement_dir_naming() {
+        let root = Path::new("/tmp/export");
+        assert_eq!(
+            measurement_dir(root, &sample_metrics(None)),
+            root.join("sha256_128_risc0")
+        );
+        assert_eq!(
+            measurement_dir(root, &sample_metrics(Some(""))),
+            root.join("sha256_128_risc0")
+        );
+        assert_eq!(
+            measurement_dir(root, &sample_metrics(Some("groth16"))),
+            root.join("sha256_128_risc0_groth16")
+        );
+    }
+
+    #[test]
+    fn test_write_artifacts_manifest_matches_files() {
+        let root = std::env::temp_dir().join(format!("csp_artifacts_{}", std::process::id()));
+        let artifacts = ExportedArtifacts {
+            proof: vec![1, 2, 3],
+            verification_key: vec![4, 5],
+            public_inputs: vec![],
+            encoding: "test",
+        };
+
+        let dir = write_artifacts(&root, &sample_metrics(None), &artifacts).unwrap();
+        let manifest: ArtifactManifest =
+            serde_json::from_str(&fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap()).unwrap();
+
+        assert_eq!(fs::read(dir.join(PROOF_FILE)).unwrap(), vec![1, 2, 3]);
+        assert_eq!(manifest.proof.size, 3);
+        assert_eq!(manifest.verification_key.size, 2);
+        assert_eq!(manifest.public_inputs.size, 0);
+        assert_eq!(
+            manifest.proof.sha256,
+            hex::encode(Sha256::digest([1u8, 2, 3]))
+        );
+        assert_eq!(manifest.encoding, "test");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
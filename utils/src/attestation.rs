@@ -0,0 +1,170 @@
+//! Integrity of published results.
+//!
+//! `collect_benchmarks` embeds the SHA-256 of the golden input vector of every measured target and
+//! size in the collected metadata, and, with [`SIGNING_KEY_ENV`] set, signs the exact bytes of
+//! `collected_benchmarks.json` with ed25519 into `collected_benchmarks.json.sig`. The
+//! `verify_attestation` binary checks the signature and regenerates the input hashes, so third
+//! parties can tell that neither the numbers nor the inputs they were measured on changed after
+//! collection.
+
+use crate::BenchTarget;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::io;
+use std::str::FromStr;
+
+/// Hex-encoded 32-byte ed25519 secret key; unset disables signing.
+pub const SIGNING_KEY_ENV: &str = "BENCH_SIGNING_KEY";
+
+pub const SIGNATURE_EXTENSION: &str = "sig";
+
+const ALGORITHM: &str = "ed25519";
+
+/// Detached signature of a results file.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ResultSignature {
+    pub algorithm: String,
+    /// Hex-encoded ed25519 public key.
+    pub public_key: String,
+    /// Hex-encoded signature over the file's bytes.
+    pub signature: String,
+}
+
+/// The signing key from [`SIGNING_KEY_ENV`], if set.
+pub fn signing_key_from_env() -> io::Result<Option<SigningKey>> {
+    let Some(hex_key) = env::var(SIGNING_KEY_ENV).ok().filter(|s| !s.is_empty()) else {
+        return Ok(None);
+    };
+    let bytes: [u8; 32] = hex::decode(hex_key.trim())
+        .map_err(io::Error::other)?
+        .try_into()
+        .map_err(|_| io::Error::other(format!("{SIGNING_KEY_ENV} must be 32 bytes")))?;
+    Ok(Some(SigningKey::from_bytes(&bytes)))
+}
+
+pub fn sign(bytes: &[u8], key: &SigningKey) -> ResultSignature {
+    ResultSignature {
+        algorithm: ALGORITHM.to_string(),
+        public_key: hex::encode(key.verifying_key().as_bytes()),
+        signature: hex::encode(key.sign(bytes).to_bytes()),
+    }
+}
+
+/// Checks `signature` over `bytes`. Pass `trusted_key` (hex) to also require that it was made by
+/// that key rather than by whichever key the signature names.
+pub fn verify(
+    bytes: &[u8],
+    signature: &ResultSignature,
+    trusted_key: Option<&str>,
+) -> io::Result<()> {
+    if signature.algorithm != ALGORITHM {
+        return Err(io::Error::other(format!(
+            "unsupported signature algorithm {}",
+            signature.algorithm
+        )));
+    }
+    if trusted_key.is_some_and(|key| !key.eq_ignore_ascii_case(&signature.public_key)) {
+        return Err(io::Error::other("signed by an untrusted key"));
+    }
+    let public_key: [u8; 32] = decode_hex(&signature.public_key)?;
+    let key = VerifyingKey::from_bytes(&public_key).map_err(io::Error::other)?;
+    let signature = Signature::from_bytes(&decode_hex(&signature.signature)?);
+    key.verify(bytes, &signature).map_err(io::Error::other)
+}
+
+fn decode_hex<const N: usize>(hex_str: &str) -> io::Result<[u8; N]> {
+    hex::decode(hex_str)
+        .map_err(io::Error::other)?
+        .try_into()
+        .map_err(|_| io::Error::other(format!("expected {N} hex-encoded bytes")))
+}
+
+/// SHA-256 of the input `target` is benchmarked on at `input_size`, as generated by this
+/// version of `utils` (all fields of the input, concatenated). `None` for an unknown target.
+pub fn input_hash(target: &str, input_size: usize) -> Option<String> {
+    let mut hasher = Sha256::new();
+    match BenchTarget::from_str(target).ok()? {
+        BenchTarget::Sha256 => {
+            let (message, digest) = crate::generate_sha256_input(input_size);
+            hasher.update(message);
+            hasher.update(digest);
+        }
+        BenchTarget::Keccak => {
+            let (message, digest) = crate::generate_keccak_input(input_size);
+            hasher.update(message);
+            hasher.update(digest);
+        }
+        BenchTarget::Blake2s => {
+            let (message, digest) = crate::generate_blake2s_input(input_size);
+            hasher.update(message);
+            hasher.update(digest);
+        }
+        BenchTarget::Ecdsa => {
+            for (digest, (x, y), signature) in [
+                crate::generate_ecdsa_input(),
+                crate::generate_ecdsa_k256_input(),
+            ] {
+                hasher.update(digest);
+                hasher.update(x);
+                hasher.update(y);
+                hasher.update(signature);
+            }
+        }
+        BenchTarget::Poseidon => {
+            for element in crate::generate_poseidon_input(input_size) {
+                hasher.update(element);
+            }
+        }
+        BenchTarget::Poseidon2 => {
+            let (message, digest) = crate::generate_poseidon2_input(input_size);
+            hasher.update(message);
+            hasher.update(digest);
+        }
+        BenchTarget::PoseidonSponge => {
+            hasher.update(crate::generate_poseidon_sponge_input(input_size));
+        }
+        BenchTarget::ReceiptProof => {
+            let (input, expected) = crate::generate_receipt_proof_input(input_size);
+            hasher.update(input.to_bytes());
+            hasher.update(expected);
+        }
+        BenchTarget::MerkleUpdate => {
+            let batch = crate::generate_merkle_update_input(input_size);
+            hasher.update(batch.to_bytes());
+            hasher.update(batch.public_output());
+        }
+        BenchTarget::Multisig => {
+            let input = crate::generate_multisig_input(input_size);
+            hasher.update(input.to_bytes());
+            hasher.update(input.public_output());
+        }
+    }
+    Some(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_round_trip() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let results = br#"{"measurements":[]}"#;
+        let signature = sign(results, &key);
+
+        assert!(verify(results, &signature, None).is_ok());
+        assert!(verify(results, &signature, Some(&signature.public_key)).is_ok());
+        assert!(verify(br#"{"measurements":[{}]}"#, &signature, None).is_err());
+        let other = hex::encode(SigningKey::from_bytes(&[8; 32]).verifying_key().as_bytes());
+        assert!(verify(results, &signature, Some(&other)).is_err());
+    }
+
+    #[test]
+    fn test_input_hash_is_deterministic() {
+        assert_eq!(input_hash("sha256", 128), input_hash("sha256", 128));
+        assert_ne!(input_hash("sha256", 128), input_hash("sha256", 256));
+        assert_eq!(input_hash("unknown", 128), None);
+    }
+}
@@ -1,10 +1,38 @@
-use crate::harness::BenchProperties;
+pub mod failure;
+pub mod mem;
+pub mod perf;
+
+use crate::BenchId;
+use crate::callgrind::CallgrindCounts;
+use crate::caveat::Caveat;
+use crate::compression::CompressedSizes;
+use crate::cost::ProofCost;
+use crate::cycles::CycleBreakdown;
+use crate::entropy::ProofEntropy;
+use crate::environment::EnvironmentFingerprint;
+use crate::harness::baseline::BaselineDelta;
+use crate::harness::circuit_size::CircuitSize;
+use crate::harness::cold_start::ColdStart;
+use crate::harness::invalid_input::InvalidInputRejection;
+use crate::harness::memory_pressure::MemoryPressure;
+use crate::harness::soak::Soak;
+use crate::harness::spans::ProvePhases;
+use crate::harness::verify_batch::VerifyBatch;
+use crate::harness::verify_budget::VerifyBudget;
+use crate::harness::{BenchError, BenchProperties, StepError};
+use crate::matrix::RuntimeConfig;
+use crate::proof_size::ProofSizeBreakdown;
+use crate::results;
+use crate::sandbox::{self, SandboxSettings};
+use crate::verification::{CrossVerification, VerificationPath};
+use crate::witness_size::WitnessSize;
 use human_repr::{HumanCount, HumanDuration};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use serde_with::{DurationNanoSeconds, serde_as};
 use std::{
     fmt::Display,
+    path::Path,
     process::Command,
     sync::{
         Arc,
@@ -73,6 +101,13 @@ pub struct Metrics {
     pub target: String,
     #[tabled(display_with = "display_bytes")]
     pub input_size: usize,
+    /// Real-world sample the input was built from, if any (see `corpus`).
+    #[tabled(skip)]
+    pub corpus: Option<String>,
+    /// SHA-256 of the input the bench ran on (see `attestation::input_hash`), so submitted
+    /// results can be checked against the golden inputs.
+    #[tabled(skip)]
+    pub input_hash: Option<String>,
     #[serde_as(as = "DurationNanoSeconds")]
     #[tabled(display_with = "display_duration")]
     pub proof_duration: Duration,
@@ -81,13 +116,113 @@ pub struct Metrics {
     pub verify_duration: Duration,
     #[tabled(display_with = "display_cycles")]
     pub cycles: Option<u64>,
+    /// Split of `cycles` between the main execution and accelerator segments (see `cycles`).
+    #[tabled(skip)]
+    pub cycle_breakdown: Option<CycleBreakdown>,
     #[tabled(display_with = "display_bytes")]
     pub proof_size: usize,
+    /// Sizes of the proof's components, where the backend exposes them (see `proof_size`).
+    #[tabled(skip)]
+    pub proof_size_breakdown: Option<ProofSizeBreakdown>,
     #[tabled(display_with = "display_bytes")]
     pub preprocessing_size: usize,
+    /// zstd-compressed sizes of the exported proof and verification key (see `compression`).
+    #[tabled(skip)]
+    pub compressed_size: Option<CompressedSizes>,
+    /// Duplicate chunks and entropy-adjusted size of the exported proof (see `entropy`).
+    #[tabled(skip)]
+    pub proof_entropy: Option<ProofEntropy>,
     pub num_constraints: usize,
+    /// Execution-trace size standing in for `num_constraints` (see `harness::circuit_size`).
+    #[tabled(display_with = "display_circuit_size")]
+    pub circuit_size: Option<CircuitSize>,
+    /// Witness elements or trace cells times the field size (see `witness_size`).
+    #[tabled(display_with = "display_witness_size")]
+    pub witness_size: Option<WitnessSize>,
     #[tabled(display_with = "display_bytes")]
     pub peak_memory: usize,
+    /// Peak virtual (committed) memory, which unlike `peak_memory` counts mmapped SRS and trace
+    /// files that are not resident. Linux only.
+    #[tabled(skip)]
+    pub peak_virtual_memory: Option<usize>,
+    /// Peak swap usage. Linux only.
+    #[tabled(skip)]
+    pub peak_swap: Option<usize>,
+    /// Peak device memory of the prover's process tree, sampled through NVML for GPU provers
+    /// (see [`mem::GPU_MEMORY_ENV`]). NVIDIA only.
+    #[tabled(skip)]
+    pub peak_gpu_memory: Option<usize>,
+    /// Peak memory of setup, proving and verification measured apart (see [`mem`]).
+    #[tabled(skip)]
+    pub phase_peak_memory: Option<mem::PhasePeakMemory>,
+    /// Instruction and cache counts of the prove step, from the optional iai-callgrind backend.
+    #[tabled(skip)]
+    pub callgrind: Option<CallgrindCounts>,
+    /// Hardware counters of the metrics-pass prove call (see [`perf`]).
+    #[tabled(skip)]
+    pub perf_counters: Option<perf::PerfCounters>,
+    /// Time proving spent in MSMs and FFTs, from the system's tracing spans (see `harness::spans`).
+    #[tabled(skip)]
+    pub prove_phases: Option<ProvePhases>,
+    /// First prepare/prove of this size in a fresh process (see `harness::cold_start`).
+    #[tabled(skip)]
+    pub cold_start: Option<ColdStart>,
+    /// Single-threaded, memory-capped verification (see `harness::verify_budget`).
+    #[tabled(skip)]
+    pub light_client_verify: Option<VerifyBudget>,
+    /// Amortized time of verifying a batch of proofs (see `harness::verify_batch`).
+    #[tabled(skip)]
+    pub verify_batch: Option<VerifyBatch>,
+    /// Native and wrapped verification of the proof, for systems with more than one (see
+    /// [`verification`](crate::verification)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[tabled(skip)]
+    pub verification_paths: Vec<VerificationPath>,
+    /// Whether independent verifier implementations accept the proof too (see
+    /// [`verification`](crate::verification)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[tabled(skip)]
+    pub cross_verifications: Vec<CrossVerification>,
+    /// Proving slowdown under a memory cap (see `harness::memory_pressure`).
+    #[tabled(skip)]
+    pub memory_pressure: Option<MemoryPressure>,
+    /// Step and time at which an invalid input was rejected (see `harness::invalid_input`).
+    #[tabled(skip)]
+    pub invalid_input: Option<InvalidInputRejection>,
+    /// Throughput drift and memory growth over a long proving loop (see `harness::soak`).
+    #[tabled(skip)]
+    pub soak: Option<Soak>,
+    /// Thread count, stack size and environment overrides in effect (see `matrix::RuntimeConfig`).
+    #[tabled(skip)]
+    pub runtime: Option<RuntimeConfig>,
+    /// Hosted-prover cost per proof, billed or estimated (see `cost`).
+    #[tabled(skip)]
+    pub proof_cost: Option<ProofCost>,
+    /// Prove and verify times of the named Criterion baseline the run was compared against
+    /// (see `harness::baseline`).
+    #[tabled(skip)]
+    pub baseline: Option<BaselineDelta>,
+    /// Child processes that crashed instead of producing their measurement (see [`failure`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[tabled(skip)]
+    pub failures: Vec<failure::ProverFailure>,
+    /// Step of the metrics pass that returned an error; the input size was then neither timed
+    /// nor measured (see `harness::BenchError`).
+    #[tabled(skip)]
+    pub step_error: Option<StepError>,
+    /// Runner state captured at bench time (see `environment::capture`).
+    #[tabled(skip)]
+    pub environment: Option<EnvironmentFingerprint>,
+    /// Durations timed from a single prove and verify instead of Criterion samples (quick mode
+    /// or the Divan backend, see `harness::quick`), so not comparable to sampled ones.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[tabled(skip)]
+    pub low_confidence: bool,
+    /// Reasons the measurement is not directly comparable with the rest of its target (see
+    /// [`caveat`](crate::caveat)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[tabled(skip)]
+    pub caveats: Vec<Caveat>,
     #[serde(flatten)]
     #[tabled(skip)]
     pub bench_properties: BenchProperties,
@@ -115,6 +250,54 @@ fn display_cycles(cycles: &Option<u64>) -> String {
     }
 }
 
+/// Padded trace length (or the unpadded one if padding is not modeled), times the width if known,
+/// and the constraint degree of an AIR.
+fn display_circuit_size(size: &Option<CircuitSize>) -> String {
+    let Some(size) = size else {
+        return "-".to_string();
+    };
+    let rows = size.padded_trace_length.unwrap_or(size.trace_length);
+    let trace = match size.columns {
+        Some(columns) => format!("{} x {}", rows.human_count_bare(), columns),
+        None => format!("{} rows", rows.human_count_bare()),
+    };
+    match size.constraint_degree {
+        Some(degree) => format!("{trace}, degree {degree}"),
+        None => trace,
+    }
+}
+
+fn display_witness_size(size: &Option<WitnessSize>) -> String {
+    match size {
+        Some(size) => size.bytes.human_count_bytes().to_string(),
+        None => "-".to_string(),
+    }
+}
+
+/// Contents of a `*_mem_report.json` written by `measure_mem_avg.sh`, in bytes averaged over
+/// its runs.
+#[derive(Deserialize, Debug, Default)]
+pub struct MemReport {
+    /// Peak resident set size.
+    pub peak_memory: usize,
+    pub peak_virtual_memory: Option<usize>,
+    pub peak_swap: Option<usize>,
+    /// Peak device memory, for GPU provers.
+    pub peak_gpu_memory: Option<usize>,
+    /// Set if the measured binary crashed, in which case the sizes are zero.
+    pub failure: Option<failure::ProverFailure>,
+}
+
+impl MemReport {
+    pub fn apply_to(self, metrics: &mut Metrics) {
+        metrics.peak_memory = self.peak_memory;
+        metrics.peak_virtual_memory = self.peak_virtual_memory;
+        metrics.peak_swap = self.peak_swap;
+        metrics.peak_gpu_memory = self.peak_gpu_memory;
+        metrics.failures.extend(self.failure);
+    }
+}
+
 impl Metrics {
     pub fn new(
         name: String,
@@ -128,13 +311,44 @@ impl Metrics {
             feat,
             target,
             input_size: size,
+            corpus: None,
+            input_hash: None,
             proof_duration: Duration::default(),
             verify_duration: Duration::default(),
             cycles: None,
+            cycle_breakdown: None,
             proof_size: 0,
+            proof_size_breakdown: None,
             preprocessing_size: 0,
+            compressed_size: None,
+            proof_entropy: None,
             num_constraints: 0,
+            circuit_size: None,
+            witness_size: None,
             peak_memory: 0,
+            peak_virtual_memory: None,
+            peak_swap: None,
+            peak_gpu_memory: None,
+            phase_peak_memory: None,
+            callgrind: None,
+            perf_counters: None,
+            prove_phases: None,
+            cold_start: None,
+            light_client_verify: None,
+            verify_batch: None,
+            verification_paths: Vec::new(),
+            cross_verifications: Vec::new(),
+            memory_pressure: None,
+            invalid_input: None,
+            soak: None,
+            runtime: None,
+            proof_cost: None,
+            baseline: None,
+            failures: Vec::new(),
+            step_error: None,
+            environment: None,
+            low_confidence: false,
+            caveats: Vec::new(),
             bench_properties,
         }
     }
@@ -172,13 +386,6 @@ pub fn write_csv(out_path: &str, results: &[Metrics]) {
     println!("{table}");
 }
 
-fn metrics_filename(target: &str, size: usize, system: &str, feat: Option<&str>) -> String {
-    match feat {
-        Some(f) if !f.is_empty() => format!("{}_{}_{}_{}_metrics.json", target, size, system, f),
-        _ => format!("{}_{}_{}_metrics.json", target, size, system),
-    }
-}
-
 pub fn write_json_metrics(
     target_str: &'static str,
     size: usize,
@@ -186,9 +393,12 @@ pub fn write_json_metrics(
     feature: Option<&str>,
     metrics: &Metrics,
 ) {
-    let metrics_file = metrics_filename(target_str, size, system_str, feature);
-
-    write_json_metrics_file(&metrics_file, metrics);
+    let writer = results::Writer::from_env();
+    let path = writer.metrics_path(&BenchId::new(target_str, size, system_str, feature));
+    // The metrics are where a failed step would be recorded, so this one can only be logged.
+    if let Err(e) = writer.write_json(&path, metrics) {
+        tracing::error!("Failed to write metrics to {}: {}", path.display(), e);
+    }
 }
 
 pub fn write_json_metrics_file(output_path: &str, metrics: &Metrics) {
@@ -196,29 +406,94 @@ pub fn write_json_metrics_file(output_path: &str, metrics: &Metrics) {
     std::fs::write(output_path, json).unwrap();
 }
 
-pub fn compile_binary(binary_name: &str) {
-    let _compile_output = Command::new("cargo")
+pub fn compile_binary(binary_name: &str) -> Result<(), BenchError> {
+    compile_binary_with_features(binary_name, &[])
+}
+
+pub fn compile_binary_with_features(
+    binary_name: &str,
+    features: &[&str],
+) -> Result<(), BenchError> {
+    let sandbox = SandboxSettings::from_env();
+    let mut command = sandbox.command("cargo");
+    command
         .arg("build")
         .arg("--release")
         .arg("--bin")
-        .arg(binary_name)
+        .arg(binary_name);
+    // Dependencies cannot be fetched from inside the sandbox.
+    if sandbox.offline() {
+        command.arg("--offline");
+    }
+    if !features.is_empty() {
+        command.arg("--features").arg(features.join(","));
+    }
+    command
         .output()
-        .expect("failed to compile");
+        .map(|_| ())
+        .map_err(|e| BenchError::tool("cargo", e))
 }
 
-pub fn run_measure_mem_script(json_file: &str, binary_path: &str, input_size: usize) {
+/// Whether the crate in the current directory declares the cargo feature `feature`.
+pub fn crate_has_feature(feature: &str) -> bool {
+    let Ok(output) = Command::new("cargo")
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .output()
+    else {
+        return false;
+    };
+    let Ok(metadata) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
+        return false;
+    };
+    let Ok(manifest) = std::env::current_dir().map(|dir| dir.join("Cargo.toml")) else {
+        return false;
+    };
+    metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|package| package["manifest_path"].as_str() == manifest.to_str())
+        .any(|package| package["features"].get(feature).is_some())
+}
+
+/// Measures `binary_path --input-size <input_size>`, restricted to `phase` if given, with
+/// `measure_mem_avg.sh` into `json_file`. With `gpu`, device memory is sampled too.
+pub fn run_measure_mem_script(
+    json_file: &Path,
+    binary_path: &str,
+    input_size: usize,
+    phase: Option<mem::Phase>,
+    gpu: bool,
+) -> Result<(), BenchError> {
     let script = "../measure_mem_avg.sh";
 
-    let output = Command::new("sh")
+    let mut command = sandbox::command("sh");
+    command
         .arg(script)
         .arg("--json")
         .arg(json_file)
         .arg("--")
         .arg(binary_path)
         .arg("--input-size")
-        .arg(input_size.to_string())
-        .output()
-        .expect("failed to execute script");
+        .arg(input_size.to_string());
+    if let Some(phase) = phase {
+        command.arg("--phase").arg(phase.as_str());
+    }
+    if gpu {
+        command.env(mem::GPU_MEMORY_ENV, "1");
+    }
+    let output = command.output().map_err(|e| BenchError::tool("sh", e))?;
 
-    println!("{}", String::from_utf8_lossy(&output.stdout));
+    tracing::info!("{}", String::from_utf8_lossy(&output.stdout).trim_end());
+    // The script still writes the report (with a `failure` record) when the binary crashes.
+    if !output.status.success() {
+        tracing::warn!(
+            "Memory measurement of {} failed ({}), see {}\n{}",
+            binary_path,
+            output.status,
+            json_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(())
 }
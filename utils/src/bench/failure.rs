@@ -0,0 +1,142 @@
+//! Structured record of a prover process that crashed or was killed.
+//!
+//! Stored in place of the measurement the process should have produced, so the collected output
+//! tells "failed" apart from "not run". `measure_mem_avg.sh` writes the same record into the
+//! memory report when the measured binary dies.
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::fmt;
+use std::process::ExitStatus;
+
+/// Lines of stderr kept before the backtrace.
+pub const STDERR_TAIL_LINES: usize = 50;
+
+/// Lines of the Rust backtrace kept.
+pub const BACKTRACE_LINES: usize = 40;
+
+const SIGKILL: i32 = 9;
+
+const BACKTRACE_HEADER: &str = "stack backtrace:";
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProverFailure {
+    /// Measurement that did not complete: `memory`, `cold_start` or `light_client_verify`.
+    pub stage: String,
+    /// Exit code; `None` when the process was killed by a signal.
+    pub exit_status: Option<i32>,
+    pub signal: Option<i32>,
+    /// Killed by `SIGKILL`, which on Linux is usually the OOM killer.
+    #[serde(default)]
+    pub oom_suspected: bool,
+    /// Location and message of the panic, if the prover panicked.
+    pub panic_message: Option<String>,
+    /// Last [`STDERR_TAIL_LINES`] lines of stderr before the backtrace.
+    pub stderr: String,
+    /// First [`BACKTRACE_LINES`] lines of the backtrace, if one was printed.
+    pub backtrace: Option<String>,
+}
+
+impl ProverFailure {
+    pub fn new(stage: &str, exit_status: Option<i32>, signal: Option<i32>, stderr: &str) -> Self {
+        let lines: Vec<&str> = stderr.lines().collect();
+        let backtrace_start = lines
+            .iter()
+            .position(|line| line.trim_start() == BACKTRACE_HEADER)
+            .unwrap_or(lines.len());
+        let (output, backtrace) = lines.split_at(backtrace_start);
+
+        let panic_message = output
+            .iter()
+            .position(|line| line.contains("panicked at"))
+            .map(|i| {
+                let mut message = output[i].to_string();
+                // Since Rust 1.73 the message follows the location on its own line.
+                if let Some(next) = output.get(i + 1)
+                    && !next.starts_with("note:")
+                {
+                    message.push('\n');
+                    message.push_str(next);
+                }
+                message
+            });
+
+        Self {
+            stage: stage.to_string(),
+            exit_status,
+            signal,
+            oom_suspected: signal == Some(SIGKILL),
+            panic_message,
+            stderr: output[output.len().saturating_sub(STDERR_TAIL_LINES)..].join("\n"),
+            backtrace: (!backtrace.is_empty())
+                .then(|| backtrace[..backtrace.len().min(BACKTRACE_LINES)].join("\n")),
+        }
+    }
+
+    /// Failure of a finished child process.
+    pub fn from_status(stage: &str, status: ExitStatus, stderr: &str) -> Self {
+        #[cfg(unix)]
+        let signal = std::os::unix::process::ExitStatusExt::signal(&status);
+        #[cfg(not(unix))]
+        let signal = None;
+        Self::new(stage, status.code(), signal, stderr)
+    }
+}
+
+impl fmt::Display for ProverFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed", self.stage)?;
+        match (self.signal, self.exit_status) {
+            (Some(signal), _) => write!(f, ", killed by signal {signal}")?,
+            (None, Some(code)) => write!(f, ", exit status {code}")?,
+            (None, None) => {}
+        }
+        if self.oom_suspected {
+            write!(f, " (out of memory?)")?;
+        }
+        if let Some(panic_message) = &self.panic_message {
+            write!(f, ": {panic_message}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_from_panic_and_signal() {
+        let stderr = "\
+proving sha256 with 2048 bytes
+thread 'main' panicked at src/lib.rs:10:5:
+constraint not satisfied
+stack backtrace:
+   0: rust_begin_unwind
+   1: core::panicking::panic_fmt
+note: Some details are omitted, run with `RUST_BACKTRACE=full` for a verbose backtrace.";
+        let failure = ProverFailure::new("memory", Some(101), None, stderr);
+        assert_eq!(
+            failure.panic_message.as_deref(),
+            Some("thread 'main' panicked at src/lib.rs:10:5:\nconstraint not satisfied")
+        );
+        assert!(failure.stderr.ends_with("constraint not satisfied"));
+        assert!(
+            failure
+                .backtrace
+                .as_deref()
+                .unwrap()
+                .starts_with(BACKTRACE_HEADER)
+        );
+        assert!(!failure.oom_suspected);
+
+        let killed = ProverFailure::new("memory", None, Some(SIGKILL), "");
+        assert!(killed.oom_suspected);
+        assert!(killed.panic_message.is_none() && killed.backtrace.is_none());
+        assert_eq!(
+            killed.to_string(),
+            "memory failed, killed by signal 9 (out of memory?)"
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Shared body of the `*_mem` binaries.
+//!
+//! The harness measures peak memory by running `<mem binary> --input-size <n>` under
+//! `measure_mem_avg.sh`. [`run`] is the whole `main` of such a binary: it prepares with the same
+//! function the bench uses and proves once, so the report covers the code path that is timed.
+//!
+//! Setup that a system can persist (compiled circuits, keys, preprocessed traces) can be wrapped
+//! in [`cached`]. Before measuring, the harness runs the binary once with `--phase prepare`, which
+//! rebuilds the cache entries; the measured runs then load them from disk, so the memory numbers
+//! reflect proving rather than setup.
+//!
+//! With `--phase`, a run measures a single [`Phase`]: on Linux the peak RSS is reset once the
+//! earlier phases are done, so the `time` report of the process only covers that phase (with the
+//! data the earlier phases left resident). With [`PHASES_ENV`] set, the harness measures every
+//! phase the binary supports into [`PhasePeakMemory`].
+
+use crate::harness::BenchError;
+use bincode::Options;
+use clap::{Parser, ValueEnum};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Directory of the preprocessing cache, defaulting to `target/preprocessing-cache` at the
+/// workspace root; `off` disables caching.
+pub const PREPROCESSING_CACHE_ENV: &str = "BENCH_PREPROCESSING_CACHE";
+
+/// Set to `1` to also measure the peak memory of each phase separately.
+pub const PHASES_ENV: &str = "BENCH_MEM_PHASES";
+
+/// Set by the harness for GPU provers: `measure_mem_avg.sh` then also samples the device memory
+/// of the process tree with `nvidia-smi` (NVML) and reports its peak as `peak_gpu_memory`.
+pub const GPU_MEMORY_ENV: &str = "MEASURE_GPU_MEM";
+
+/// Set by `--phase prepare`: recompute cache entries instead of loading them.
+static REFRESH_CACHE: AtomicBool = AtomicBool::new(false);
+
+/// Exit status of a binary asked for a phase it does not support.
+const UNSUPPORTED_PHASE_EXIT: i32 = 3;
+
+/// Exit status of a binary whose prepare, prove or verify step returned an error.
+const STEP_FAILED_EXIT: i32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Phase {
+    /// Setup and witness inputs, without the preprocessing cache.
+    Prepare,
+    Prove,
+    Verify,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Prepare, Phase::Prove, Phase::Verify];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Prepare => "prepare",
+            Phase::Prove => "prove",
+            Phase::Verify => "verify",
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+pub struct MemArgs {
+    /// Input size of the measured proof
+    #[arg(long = "input-size")]
+    pub input_size: usize,
+
+    /// Measure only this phase; without it, setup and proving are measured together
+    #[arg(long, value_enum)]
+    pub phase: Option<Phase>,
+}
+
+/// Peak resident set size of each phase in bytes, averaged like `peak_memory`; `None` for a
+/// phase the binary does not support or that failed.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct PhasePeakMemory {
+    pub prepare: Option<usize>,
+    pub prove: Option<usize>,
+    pub verify: Option<usize>,
+}
+
+impl PhasePeakMemory {
+    pub fn set(&mut self, phase: Phase, peak_memory: usize) {
+        *match phase {
+            Phase::Prepare => &mut self.prepare,
+            Phase::Prove => &mut self.prove,
+            Phase::Verify => &mut self.verify,
+        } = Some(peak_memory);
+    }
+}
+
+/// Parses the arguments, prepares, and proves once. Binaries that use it cannot measure
+/// [`Phase::Verify`]; see [`run_with_verify`].
+pub fn run<Prepared, Proof>(
+    prepare: impl FnOnce(usize) -> Result<Prepared, BenchError>,
+    prove: impl FnOnce(&Prepared) -> Result<Proof, BenchError>,
+) {
+    run_phases(
+        prepare,
+        prove,
+        None::<fn(&Prepared, &Proof) -> Result<(), BenchError>>,
+    );
+}
+
+/// [`run`] for binaries that can also verify the proof.
+pub fn run_with_verify<Prepared, Proof>(
+    prepare: impl FnOnce(usize) -> Result<Prepared, BenchError>,
+    prove: impl FnOnce(&Prepared) -> Result<Proof, BenchError>,
+    verify: impl FnOnce(&Prepared, &Proof) -> Result<(), BenchError>,
+) {
+    run_phases(prepare, prove, Some(verify));
+}
+
+fn run_phases<Prepared, Proof>(
+    prepare: impl FnOnce(usize) -> Result<Prepared, BenchError>,
+    prove: impl FnOnce(&Prepared) -> Result<Proof, BenchError>,
+    verify: Option<impl FnOnce(&Prepared, &Proof) -> Result<(), BenchError>>,
+) {
+    let args = MemArgs::parse();
+    if args.phase == Some(Phase::Verify) && verify.is_none() {
+        eprintln!("This binary does not measure the verify phase");
+        std::process::exit(UNSUPPORTED_PHASE_EXIT);
+    }
+    REFRESH_CACHE.store(args.phase == Some(Phase::Prepare), Ordering::Relaxed);
+    let prepared = step(Phase::Prepare, prepare(args.input_size));
+    if args.phase == Some(Phase::Prepare) {
+        return;
+    }
+    if args.phase == Some(Phase::Prove) {
+        reset_peak_memory();
+    }
+    let proof = step(Phase::Prove, prove(&prepared));
+    if let (Some(Phase::Verify), Some(verify)) = (args.phase, verify) {
+        reset_peak_memory();
+        step(Phase::Verify, verify(&prepared, &proof));
+    }
+}
+
+/// State loaded before [`run`] or [`run_with_verify`], such as a compiled guest, or exits as a
+/// failed [`Phase::Prepare`].
+pub fn shared_state<T>(state: Result<T, BenchError>) -> T {
+    step(Phase::Prepare, state)
+}
+
+/// Value of a step, or exits with [`STEP_FAILED_EXIT`] so that `measure_mem_avg.sh` records the
+/// error as the failure of the memory measurement.
+fn step<T>(phase: Phase, result: Result<T, BenchError>) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("{} failed: {error}", phase.as_str());
+        std::process::exit(STEP_FAILED_EXIT);
+    })
+}
+
+/// Resets the peak RSS of the process to its current RSS (Linux only), so that `time` reports
+/// the peak of what follows.
+fn reset_peak_memory() {
+    #[cfg(target_os = "linux")]
+    if let Err(e) = fs::write("/proc/self/clear_refs", "5") {
+        tracing::warn!("Failed to reset the peak RSS: {e}");
+    }
+}
+
+/// `compute()`, stored on disk under `key` and loaded from there by later runs. Entries are per
+/// input seed (see [`run_seed`](crate::run_seed)), so `key` only needs to tell the circuit and
+/// input size apart. Errors are returned without being stored.
+pub fn cached<T: Serialize + DeserializeOwned, E>(
+    key: &str,
+    compute: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let Some(dir) = cache_dir() else {
+        return compute();
+    };
+    let path = dir.join(format!("{key}_{:x}.bin", crate::run_seed()));
+    if !REFRESH_CACHE.load(Ordering::Relaxed)
+        && let Some(value) = fs::read(&path)
+            .ok()
+            .and_then(|bytes| bincode::options().deserialize(&bytes).ok())
+    {
+        return Ok(value);
+    }
+    let value = compute()?;
+    if let Err(e) = write_entry(&path, &value) {
+        tracing::warn!("Failed to cache preprocessing at {}: {e}", path.display());
+    }
+    Ok(value)
+}
+
+fn write_entry<T: Serialize>(path: &Path, value: &T) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, bincode::options().serialize(value)?)?;
+    Ok(())
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    match env::var_os(PREPROCESSING_CACHE_ENV) {
+        Some(dir) if dir == "off" => None,
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => Some(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("utils is inside the workspace")
+                .join("target")
+                .join("preprocessing-cache"),
+        ),
+    }
+}
+
+/// Runs `binary_path --phase prepare` to fill the preprocessing cache before measuring.
+pub(crate) fn warm_cache(binary_path: &str, input_size: usize) {
+    let status = crate::sandbox::command(binary_path)
+        .arg("--input-size")
+        .arg(input_size.to_string())
+        .arg("--phase")
+        .arg(Phase::Prepare.as_str())
+        .status();
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(status) => tracing::warn!("Preparing {binary_path} failed ({status})"),
+        Err(e) => tracing::warn!("Failed to run {binary_path}: {e}"),
+    }
+}
+
+/// Whether [`PHASES_ENV`] is set.
+pub(crate) fn phases_enabled() -> bool {
+    env::var(PHASES_ENV).is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+}
@@ -0,0 +1,137 @@
+//! Hardware performance counters around a closure (Linux only, `perf-counters` feature).
+//!
+//! Counters are opened for every thread of the process and inherited by threads spawned while
+//! they run, so multi-threaded provers are counted in full. Opening counters needs a permissive
+//! `kernel.perf_event_paranoid` (<= 2 for user-space events); on failure nothing is reported.
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Counts recorded around one prove call.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct PerfCounters {
+    pub instructions: Option<u64>,
+    pub llc_misses: Option<u64>,
+    pub branch_misses: Option<u64>,
+}
+
+/// Runs `func` and returns its result together with the hardware counters it accumulated,
+/// or `None` if counters are unsupported or could not be opened.
+pub fn measure_perf_counters<R, F: FnOnce() -> R>(func: F) -> (R, Option<PerfCounters>) {
+    imp::measure(func)
+}
+
+#[cfg(all(target_os = "linux", feature = "perf-counters"))]
+mod imp {
+    use super::PerfCounters;
+    use perf_event::events::Hardware;
+    use perf_event::{Builder, Counter};
+    use std::fs;
+    use std::io;
+
+    /// One counter per (event, thread), summed on read.
+    struct ProcessCounters {
+        instructions: Vec<Counter>,
+        llc_misses: Vec<Counter>,
+        branch_misses: Vec<Counter>,
+    }
+
+    impl ProcessCounters {
+        fn open() -> io::Result<Self> {
+            let tids = fs::read_dir("/proc/self/task")?
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<i32>().ok())
+                .collect::<Vec<_>>();
+            let open = |kind: Hardware| -> io::Result<Vec<Counter>> {
+                tids.iter()
+                    .map(|&tid| {
+                        Builder::new()
+                            .kind(kind)
+                            .observe_pid(tid)
+                            .inherit(true)
+                            .build()
+                    })
+                    .collect()
+            };
+            Ok(Self {
+                instructions: open(Hardware::INSTRUCTIONS)?,
+                llc_misses: open(Hardware::CACHE_MISSES)?,
+                branch_misses: open(Hardware::BRANCH_MISSES)?,
+            })
+        }
+
+        fn all(&mut self) -> impl Iterator<Item = &mut Counter> {
+            self.instructions
+                .iter_mut()
+                .chain(self.llc_misses.iter_mut())
+                .chain(self.branch_misses.iter_mut())
+        }
+
+        fn enable(&mut self) -> io::Result<()> {
+            self.all().try_for_each(|c| c.enable())
+        }
+
+        fn disable(&mut self) -> io::Result<()> {
+            self.all().try_for_each(|c| c.disable())
+        }
+
+        fn read(&mut self) -> io::Result<PerfCounters> {
+            fn sum(counters: &mut [Counter]) -> io::Result<Option<u64>> {
+                counters
+                    .iter_mut()
+                    .map(|c| c.read())
+                    .sum::<io::Result<u64>>()
+                    .map(Some)
+            }
+            Ok(PerfCounters {
+                instructions: sum(&mut self.instructions)?,
+                llc_misses: sum(&mut self.llc_misses)?,
+                branch_misses: sum(&mut self.branch_misses)?,
+            })
+        }
+    }
+
+    pub(super) fn measure<R, F: FnOnce() -> R>(func: F) -> (R, Option<PerfCounters>) {
+        let mut counters = match ProcessCounters::open().and_then(|mut c| c.enable().map(|_| c)) {
+            Ok(counters) => counters,
+            Err(e) => {
+                tracing::warn!("Hardware performance counters unavailable: {}", e);
+                return (func(), None);
+            }
+        };
+
+        let result = func();
+
+        let counts = counters.disable().and_then(|_| counters.read());
+        match counts {
+            Ok(counts) => (result, Some(counts)),
+            Err(e) => {
+                tracing::warn!("Failed to read hardware performance counters: {}", e);
+                (result, None)
+            }
+        }
+    }
+}
+
+#[cfg(not(all(target_os = "linux", feature = "perf-counters")))]
+mod imp {
+    use super::PerfCounters;
+
+    pub(super) fn measure<R, F: FnOnce() -> R>(func: F) -> (R, Option<PerfCounters>) {
+        (func(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_returns_closure_result() {
+        let (result, counters) = measure_perf_counters(|| (1..=10u64).sum::<u64>());
+        assert_eq!(result, 55);
+        if let Some(counters) = counters {
+            assert!(counters.instructions.unwrap_or_default() > 0);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::bench::mem::Phase;
+use crate::harness::BenchTarget;
+use std::fmt;
+use std::str::FromStr;
+
+/// Identity of one measurement: `{target}_{input_size}_{system}[_{feat}]`.
+///
+/// The same id names the Criterion group, the metrics and memory-report files, and the
+/// iai-callgrind bench, so the harness that writes them and `collect_benchmarks` that reads them
+/// must agree on it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BenchId {
+    pub target: String,
+    pub input_size: usize,
+    pub system: String,
+    /// Feature tag; an empty tag is normalized to `None`.
+    pub feat: Option<String>,
+}
+
+impl BenchId {
+    pub fn new(target: &str, input_size: usize, system: &str, feat: Option<&str>) -> Self {
+        Self {
+            target: target.to_string(),
+            input_size,
+            system: system.to_string(),
+            feat: feat.filter(|f| !f.is_empty()).map(str::to_string),
+        }
+    }
+
+    /// Criterion function id of the `step` (`prove`/`verify`) benchmark of this group.
+    pub fn step(&self, step: &str) -> String {
+        format!("{self}_{step}")
+    }
+
+    pub fn metrics_filename(&self) -> String {
+        format!("{self}_metrics.json")
+    }
+
+    pub fn mem_report_filename(&self) -> String {
+        format!("{self}_mem_report.json")
+    }
+
+    /// Memory report of a single phase of the memory binary (see `bench::mem`).
+    pub fn phase_mem_report_filename(&self, phase: Phase) -> String {
+        format!("{self}_mem_report_{}.json", phase.as_str())
+    }
+
+    /// Span timings of the metrics pass (see `harness::spans`).
+    pub fn spans_filename(&self) -> String {
+        format!("{self}_spans.json")
+    }
+}
+
+impl fmt::Display for BenchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}_{}", self.target, self.input_size, self.system)?;
+        if let Some(feat) = &self.feat {
+            write!(f, "_{feat}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `{target}_{input_size}_{system}[_{feat}]`. Targets may contain `_`
+/// (`poseidon_sponge`), so the target is the longest [`BenchTarget`] the id starts with. Systems
+/// never contain `_`, so everything after the system is the feature tag (which may).
+impl FromStr for BenchId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BenchId, String> {
+        let (target, rest) = BenchTarget::ALL
+            .iter()
+            .filter_map(|target| {
+                let rest = s.strip_prefix(target.as_str())?.strip_prefix('_')?;
+                Some((target.as_str(), rest))
+            })
+            .max_by_key(|(target, _)| target.len())
+            .ok_or_else(|| format!("Unknown benchmark target in benchmark id: {}", s))?;
+        let mut parts = rest.splitn(3, '_');
+        let (Some(input_size), Some(system)) = (parts.next(), parts.next()) else {
+            return Err(format!("Invalid benchmark id: {}", s));
+        };
+        let input_size = input_size
+            .parse()
+            .map_err(|_| format!("Invalid input size in benchmark id: {}", s))?;
+        if system.is_empty() {
+            return Err(format!("Invalid benchmark id: {}", s));
+        }
+        Ok(BenchId::new(target, input_size, system, parts.next()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_id_round_trip() {
+        let plain = BenchId::new("sha256", 128, "sp1", None);
+        assert_eq!(plain.to_string(), "sha256_128_sp1");
+        assert_eq!(plain.step("prove"), "sha256_128_sp1_prove");
+        assert_eq!(plain.metrics_filename(), "sha256_128_sp1_metrics.json");
+        assert_eq!(plain.spans_filename(), "sha256_128_sp1_spans.json");
+        assert_eq!(BenchId::new("sha256", 128, "sp1", Some("")), plain);
+
+        let featured = BenchId::new("keccak", 2048, "cairo-m", Some("no_lookup"));
+        assert_eq!(featured.to_string(), "keccak_2048_cairo-m_no_lookup");
+        assert_eq!(
+            featured.mem_report_filename(),
+            "keccak_2048_cairo-m_no_lookup_mem_report.json"
+        );
+        assert_eq!(
+            featured.phase_mem_report_filename(Phase::Verify),
+            "keccak_2048_cairo-m_no_lookup_mem_report_verify.json"
+        );
+
+        for id in [plain, featured] {
+            assert_eq!(id.to_string().parse::<BenchId>(), Ok(id));
+        }
+        assert!("sha256_big_sp1".parse::<BenchId>().is_err());
+        assert!("sha256_128".parse::<BenchId>().is_err());
+        assert!("md5_128_sp1".parse::<BenchId>().is_err());
+    }
+
+    #[test]
+    fn test_bench_id_with_underscore_target() {
+        let sponge = "poseidon_sponge_128_plonky2".parse::<BenchId>().unwrap();
+        assert_eq!(
+            sponge,
+            BenchId::new("poseidon_sponge", 128, "plonky2", None)
+        );
+
+        let receipt = BenchId::new("receipt_proof", 16, "risc0", Some("groth16"));
+        assert_eq!(receipt.to_string().parse::<BenchId>(), Ok(receipt));
+        assert_eq!(
+            "poseidon2_64_plonky3".parse::<BenchId>(),
+            Ok(BenchId::new("poseidon2", 64, "plonky3", None))
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use clap::Parser;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use utils::anonymize::{self, SALT_ENV};
+use utils::attestation;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Strip machine-identifying metadata from result files before publication", long_about = None)]
+struct Cli {
+    /// Collected results or metrics files to anonymize
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Secret salt of the machine fingerprints; reuse it to correlate runs of the same machine
+    #[arg(long, env = SALT_ENV, hide_env_values = true)]
+    salt: String,
+
+    /// Directory the anonymized files are written to, under their original names
+    #[arg(
+        long,
+        required_unless_present = "in_place",
+        conflicts_with = "in_place"
+    )]
+    out_dir: Option<PathBuf>,
+
+    /// Overwrite the input files instead
+    #[arg(long)]
+    in_place: bool,
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    if let Some(dir) = &cli.out_dir {
+        fs::create_dir_all(dir)?;
+    }
+    let signing_key = attestation::signing_key_from_env()?;
+
+    for file in &cli.files {
+        let output_path = match &cli.out_dir {
+            Some(dir) => dir
+                .join(file.file_name().ok_or_else(|| {
+                    io::Error::other(format!("{} is not a file", file.display()))
+                })?),
+            None => file.clone(),
+        };
+        let output = anonymize_file(file, cli.salt.as_bytes())?;
+        fs::write(&output_path, &output)?;
+
+        // The signature of the original no longer matches; re-sign or drop it.
+        let signature_path = signature_path(&output_path);
+        match &signing_key {
+            Some(key) => {
+                let signature = attestation::sign(output.as_bytes(), key);
+                fs::write(&signature_path, serde_json::to_string_pretty(&signature)?)?;
+            }
+            None if signature_path.exists() => {
+                fs::remove_file(&signature_path)?;
+                eprintln!(
+                    "Removed the stale signature {}; set {} to re-sign",
+                    signature_path.display(),
+                    attestation::SIGNING_KEY_ENV
+                );
+            }
+            None => {}
+        }
+        println!(
+            "Anonymized {} into {}",
+            file.display(),
+            output_path.display()
+        );
+    }
+    Ok(())
+}
+
+fn anonymize_file(path: &Path, salt: &[u8]) -> io::Result<String> {
+    let contents = fs::read_to_string(path)?;
+    let mut value: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| io::Error::other(format!("{}: {}", path.display(), e)))?;
+    anonymize::anonymize(&mut value, salt);
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+fn signature_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(attestation::SIGNATURE_EXTENSION);
+    PathBuf::from(name)
+}
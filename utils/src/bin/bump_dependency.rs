@@ -0,0 +1,463 @@
+use clap::Parser;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use utils::bump::{Collected, read_collected, report};
+use utils::harness::quick::QUICK_ENV;
+use utils::results::RUN_ID_ENV;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Bump a dependency in a scratch worktree, run the quick benches of the crates depending on it before and after, and report build compatibility and performance deltas", long_about = None)]
+struct Cli {
+    /// Package to bump, e.g. `ere-risc0` or `provekit-prover`
+    dependency: String,
+
+    /// Git revision or version to bump to; the newest compatible version if unset
+    #[arg(long)]
+    to: Option<String>,
+
+    /// Worktree the bump is made and benchmarked in [default: ../target/bump/<dependency>]
+    #[arg(long)]
+    scratch: Option<PathBuf>,
+
+    /// `BENCH_INPUT_PROFILE` of the benches
+    #[arg(long, default_value = "minimal")]
+    profile: String,
+
+    /// Changes smaller than this many percent are not reported
+    #[arg(long, default_value_t = 2.0)]
+    threshold: f64,
+
+    /// Markdown report [default: ../bump_<dependency>.md]
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+/// Run ids of the two sides, so their results land in separate directories of the worktree.
+const BEFORE: &str = "bump-before";
+const AFTER: &str = "bump-after";
+
+/// How far a crate got on one side of the bump.
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Ok,
+    BuildFailed(String),
+    BenchFailed(String),
+}
+
+impl Outcome {
+    fn cell(&self) -> String {
+        match self {
+            Outcome::Ok => "ok".to_string(),
+            Outcome::BuildFailed(error) => format!("build failed: `{error}`"),
+            Outcome::BenchFailed(error) => format!("bench failed: `{error}`"),
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let dependency = cli.dependency.replace('_', "-");
+    let scratch = std::env::current_dir()?.join(
+        cli.scratch
+            .unwrap_or_else(|| Path::new("../target/bump").join(&dependency)),
+    );
+    let out = cli
+        .out
+        .unwrap_or_else(|| PathBuf::from(format!("../bump_{dependency}.md")));
+
+    create_worktree(&scratch)?;
+    let metadata = cargo_metadata(&scratch)?;
+    let crates = dependents(&metadata, &dependency);
+    if crates.is_empty() {
+        return Err(io::Error::other(format!(
+            "no benchmark crate of the workspace depends on {dependency}"
+        )));
+    }
+    let old_versions = versions(&metadata, &dependency);
+    println!("Benchmarking {} before the bump", crates.join(", "));
+    let before = run_benches(&scratch, &crates, &cli.profile, BEFORE);
+
+    bump(&scratch, &dependency, cli.to.as_deref())?;
+    let new_versions = versions(&cargo_metadata(&scratch)?, &dependency);
+    println!("Benchmarking {} after the bump", crates.join(", "));
+    let after = run_benches(&scratch, &crates, &cli.profile, AFTER);
+
+    let old = collect(&scratch, BEFORE);
+    let new = collect(&scratch, AFTER);
+    let deltas = match (&old, &new) {
+        (Some(old), Some(new)) => report(old, new, cli.threshold, false),
+        _ => Vec::new(),
+    };
+
+    let markdown = markdown(
+        &dependency,
+        (&old_versions, &new_versions),
+        &crates,
+        (&before, &after),
+        &deltas,
+    );
+    fs::write(&out, &markdown)?;
+    print!("{markdown}");
+    println!("Wrote {}", out.display());
+    Ok(())
+}
+
+/// Checks out `HEAD` at `scratch`, replacing an earlier worktree there.
+fn create_worktree(scratch: &Path) -> io::Result<()> {
+    if scratch.exists() {
+        run(Command::new("git")
+            .args(["worktree", "remove", "--force"])
+            .arg(scratch))?;
+    }
+    if let Some(parent) = scratch.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    run(Command::new("git")
+        .args(["worktree", "add", "--detach"])
+        .arg(scratch)
+        .arg("HEAD"))
+}
+
+/// Runs `command`, turning a non-zero exit into an error with [`failure`].
+fn run(command: &mut Command) -> io::Result<()> {
+    match failure(command) {
+        None => Ok(()),
+        Some(error) => Err(io::Error::other(format!(
+            "{} failed: {error}",
+            command.get_program().to_string_lossy()
+        ))),
+    }
+}
+
+/// Runs `command`; on failure, the first error line of its output.
+fn failure(command: &mut Command) -> Option<String> {
+    match command.output() {
+        Ok(output) if output.status.success() => None,
+        Ok(output) => Some(last_error(&String::from_utf8_lossy(&output.stderr))),
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+/// The first `error` line of cargo's output, or its last line.
+fn last_error(stderr: &str) -> String {
+    let lines: Vec<&str> = stderr.lines().map(str::trim).collect();
+    lines
+        .iter()
+        .find(|line| line.starts_with("error"))
+        .or_else(|| lines.iter().rev().find(|line| !line.is_empty()))
+        .unwrap_or(&"")
+        .to_string()
+}
+
+fn cargo_metadata(root: &Path) -> io::Result<Value> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1"])
+        .current_dir(root)
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "cargo metadata failed: {}",
+            last_error(&String::from_utf8_lossy(&output.stderr))
+        )));
+    }
+    serde_json::from_slice(&output.stdout).map_err(io::Error::other)
+}
+
+/// Workspace members with benches that depend on `dependency`, directly or not.
+fn dependents(metadata: &Value, dependency: &str) -> Vec<String> {
+    let packages: BTreeMap<&str, &Value> = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|package| Some((package["id"].as_str()?, package)))
+        .collect();
+    let edges: BTreeMap<&str, Vec<&str>> = metadata["resolve"]["nodes"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|node| {
+            let deps = node["dependencies"].as_array()?;
+            Some((
+                node["id"].as_str()?,
+                deps.iter().filter_map(Value::as_str).collect(),
+            ))
+        })
+        .collect();
+    let is_dependency = |id: &str| {
+        packages
+            .get(id)
+            .and_then(|package| package["name"].as_str())
+            .is_some_and(|name| name.replace('_', "-") == dependency)
+    };
+    let reaches = |member: &str| {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![member];
+        while let Some(id) = stack.pop() {
+            if !seen.insert(id) {
+                continue;
+            }
+            if id != member && is_dependency(id) {
+                return true;
+            }
+            stack.extend(edges.get(id).into_iter().flatten());
+        }
+        false
+    };
+
+    metadata["workspace_members"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter(|id| reaches(id))
+        .filter_map(|id| packages.get(id))
+        .filter(|package| {
+            package["targets"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|target| {
+                    target["kind"]
+                        .as_array()
+                        .is_some_and(|kinds| kinds.iter().any(|kind| kind == "bench"))
+                })
+        })
+        .filter_map(|package| package["name"].as_str().map(String::from))
+        .collect()
+}
+
+/// Resolved versions of `dependency`, with the commit for git sources (`0.1.0 (66c516a)`).
+fn versions(metadata: &Value, dependency: &str) -> Vec<String> {
+    let versions: BTreeSet<String> = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|package| {
+            package["name"]
+                .as_str()
+                .is_some_and(|name| name.replace('_', "-") == dependency)
+        })
+        .filter_map(|package| {
+            let version = package["version"].as_str()?;
+            let commit = package["source"]
+                .as_str()
+                .and_then(|source| source.strip_prefix("git+"))
+                .and_then(|source| source.rsplit_once('#'))
+                .map(|(_, commit)| &commit[..commit.len().min(7)]);
+            Some(match commit {
+                Some(commit) => format!("{version} ({commit})"),
+                None => version.to_string(),
+            })
+        })
+        .collect();
+    versions.into_iter().collect()
+}
+
+/// Moves `dependency` to `to`. A git dependency pinned with `rev` in a manifest gets the new
+/// revision everywhere the old one appears, so that the crates of the same repository (e.g. the
+/// `provekit-*` crates) move together; anything else goes through `cargo update`.
+fn bump(root: &Path, dependency: &str, to: Option<&str>) -> io::Result<()> {
+    let manifests = manifests(root)?;
+    if let Some(to) = to {
+        let pinned = Regex::new(&format!(
+            r#"(?m)^{}\s*=.*\brev\s*=\s*"([^"]+)""#,
+            regex::escape(dependency)
+        ))
+        .expect("valid regex");
+        let old_revs: BTreeSet<String> = manifests
+            .iter()
+            .filter_map(|manifest| fs::read_to_string(manifest).ok())
+            .flat_map(|contents| {
+                pinned
+                    .captures_iter(&contents)
+                    .map(|captures| captures[1].to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        if !old_revs.is_empty() {
+            for manifest in &manifests {
+                let contents = fs::read_to_string(manifest)?;
+                let bumped = old_revs.iter().fold(contents.clone(), |contents, old| {
+                    contents.replace(&format!("rev = \"{old}\""), &format!("rev = \"{to}\""))
+                });
+                if bumped != contents {
+                    fs::write(manifest, bumped)?;
+                }
+            }
+            return Ok(());
+        }
+    }
+
+    let mut update = Command::new("cargo");
+    update.args(["update", "-p", dependency]).current_dir(root);
+    if let Some(to) = to {
+        update.args(["--precise", to]);
+    }
+    run(&mut update)
+}
+
+/// The workspace manifest and those of its members.
+fn manifests(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let metadata = cargo_metadata(root)?;
+    let members: BTreeSet<&str> = metadata["workspace_members"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let mut manifests = vec![root.join("Cargo.toml")];
+    manifests.extend(
+        metadata["packages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|package| {
+                package["id"]
+                    .as_str()
+                    .is_some_and(|id| members.contains(id))
+            })
+            .filter_map(|package| package["manifest_path"].as_str().map(PathBuf::from)),
+    );
+    Ok(manifests)
+}
+
+/// Builds and runs the benches of `crates` in quick mode into the run `run_id`.
+fn run_benches(root: &Path, crates: &[String], profile: &str, run_id: &str) -> Vec<Outcome> {
+    crates
+        .iter()
+        .map(|name| {
+            let cargo = |subcommand: &str| {
+                let mut command = Command::new("cargo");
+                command
+                    .args([subcommand, "--release", "-p", name])
+                    .current_dir(root)
+                    .env(QUICK_ENV, "1")
+                    .env("BENCH_INPUT_PROFILE", profile)
+                    .env(RUN_ID_ENV, run_id);
+                command
+            };
+            if let Some(error) = failure(cargo("build").arg("--benches")) {
+                return Outcome::BuildFailed(error);
+            }
+            match failure(&mut cargo("bench")) {
+                None => Outcome::Ok,
+                Some(error) => Outcome::BenchFailed(error),
+            }
+        })
+        .collect()
+}
+
+/// Collects the run `run_id` with `collect_benchmarks`; `None` if nothing was measured.
+fn collect(root: &Path, run_id: &str) -> Option<Collected> {
+    let collected = root.join("collected_benchmarks.json");
+    let _ = fs::remove_file(&collected);
+    let status = run(Command::new("cargo")
+        .args([
+            "run",
+            "--release",
+            "-p",
+            "utils",
+            "--bin",
+            "collect_benchmarks",
+        ])
+        .current_dir(root.join("utils"))
+        .env(RUN_ID_ENV, run_id));
+    if let Err(e) = status {
+        eprintln!("Collecting {run_id}: {e}");
+    }
+    let kept = root.join(format!("collected_{run_id}.json"));
+    fs::rename(&collected, &kept).ok()?;
+    read_collected(&kept).ok()
+}
+
+fn markdown(
+    dependency: &str,
+    (old_versions, new_versions): (&[String], &[String]),
+    crates: &[String],
+    (before, after): (&[Outcome], &[Outcome]),
+    deltas: &[String],
+) -> String {
+    let mut md = format!(
+        "# Bumping {dependency}: {} → {}\n\n## Compatibility\n\n| Crate | Before | After |\n| --- | --- | --- |\n",
+        old_versions.join(", "),
+        new_versions.join(", ")
+    );
+    for ((name, before), after) in crates.iter().zip(before).zip(after) {
+        let _ = writeln!(md, "| {name} | {} | {} |", before.cell(), after.cell());
+    }
+    md.push_str("\n## Performance\n\n");
+    if deltas.is_empty() {
+        md.push_str("No change above the threshold.\n");
+    }
+    for line in deltas {
+        let _ = writeln!(md, "- {line}");
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn package(id: &str, name: &str, source: Option<&str>, bench: bool) -> Value {
+        let kind = if bench { "bench" } else { "lib" };
+        json!({
+            "id": id,
+            "name": name,
+            "version": "0.1.0",
+            "source": source,
+            "targets": [{ "kind": [kind] }],
+        })
+    }
+
+    #[test]
+    fn test_dependents_follow_the_resolve_graph() {
+        let metadata = json!({
+            "packages": [
+                package("risc0", "risc0", None, true),
+                package("utils", "utils", None, false),
+                package("sp1", "sp1", None, true),
+                package("ere", "ere-risc0", Some("git+https://github.com/eth-act/ere?rev=66c516a2#66c516a24d9c"), false),
+                package("zkvm", "ere-zkvm-interface", None, false),
+            ],
+            "workspace_members": ["risc0", "utils", "sp1"],
+            "resolve": { "nodes": [
+                { "id": "risc0", "dependencies": ["ere", "utils"] },
+                { "id": "utils", "dependencies": ["zkvm"] },
+                { "id": "sp1", "dependencies": ["utils"] },
+                { "id": "ere", "dependencies": ["zkvm"] },
+                { "id": "zkvm", "dependencies": [] },
+            ] },
+        });
+        assert_eq!(dependents(&metadata, "ere-risc0"), ["risc0"]);
+        // Benches only: `utils` depends on the interface too, but has none.
+        assert_eq!(
+            dependents(&metadata, "ere-zkvm-interface"),
+            ["risc0", "sp1"]
+        );
+        assert_eq!(versions(&metadata, "ere-risc0"), ["0.1.0 (66c516a)"]);
+
+        let md = markdown(
+            "ere-risc0",
+            (
+                &versions(&metadata, "ere-risc0"),
+                &["0.2.0 (0123456)".to_string()],
+            ),
+            &["risc0".to_string()],
+            (
+                &[Outcome::Ok],
+                &[Outcome::BuildFailed("error[E0432]".to_string())],
+            ),
+            &[],
+        );
+        assert!(md.starts_with("# Bumping ere-risc0: 0.1.0 (66c516a) → 0.2.0 (0123456)"));
+        assert!(md.contains("| risc0 | ok | build failed: `error[E0432]` |"));
+    }
+}
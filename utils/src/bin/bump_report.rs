@@ -0,0 +1,32 @@
+use clap::Parser;
+use std::io;
+use std::path::PathBuf;
+use utils::bump::{read_collected, report};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Compare two collected_benchmarks.json files per system and upstream version", long_about = None)]
+struct Cli {
+    /// Collected results before the bump
+    old: PathBuf,
+
+    /// Collected results after the bump
+    new: PathBuf,
+
+    /// Changes smaller than this many percent are not reported
+    #[arg(long, default_value_t = 2.0)]
+    threshold: f64,
+
+    /// Only report systems whose upstream version changed
+    #[arg(long)]
+    bumped_only: bool,
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let old = read_collected(&cli.old)?;
+    let new = read_collected(&cli.new)?;
+    for line in report(&old, &new, cli.threshold, cli.bumped_only) {
+        println!("{line}");
+    }
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use utils::harness::BenchProperties;
+use utils::matrix::{BenchMatrix, DEFAULT_MATRIX_PATH, read_matrix};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Derive the capability matrix (system × target: max size, features, GPU, on-chain verifier) from the bench matrix and a collected_benchmarks.json", long_about = None)]
+struct Cli {
+    /// Collected results
+    #[arg(long, default_value = "../collected_benchmarks.json")]
+    collected: PathBuf,
+
+    /// Bench matrix declaring the benches of every system
+    #[arg(long, default_value = DEFAULT_MATRIX_PATH)]
+    matrix: PathBuf,
+
+    /// Output JSON; a Markdown table is written next to it with the `.md` extension
+    #[arg(long, default_value = "../capability_matrix.json")]
+    out: PathBuf,
+}
+
+/// The parts of `collect_benchmarks`' output the matrix needs.
+#[derive(Deserialize)]
+struct Collected {
+    systems: BTreeMap<String, BenchProperties>,
+    measurements: Vec<Measurement>,
+}
+
+#[derive(Deserialize)]
+struct Measurement {
+    system: String,
+    target: String,
+    input_size: usize,
+    #[serde(default)]
+    failures: Vec<serde_json::Value>,
+}
+
+/// What a system can do for one target, over all its declared variants.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, PartialEq)]
+struct Capability {
+    system: String,
+    target: String,
+    /// Feature tags declared besides the untagged bench (`gpu`, `keccak_pure`).
+    features: Vec<String>,
+    /// Largest input size any variant proved without a failure; `None` if none did.
+    max_size: Option<usize>,
+    /// A variant proves on the GPU.
+    gpu: bool,
+    /// Chain whose contracts verify the proofs (see `BenchProperties::onchain_verifier`).
+    onchain_verifier: Option<String>,
+    /// Every declared variant is optional, so the pair is only measured on request.
+    optional: bool,
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let contents = fs::read_to_string(&cli.collected)?;
+    let collected: Collected = serde_json::from_str(&contents)
+        .map_err(|e| io::Error::other(format!("{}: {}", cli.collected.display(), e)))?;
+    let matrix = read_matrix(&cli.matrix)?;
+
+    let capabilities = capabilities(&matrix, &collected);
+    fs::write(&cli.out, serde_json::to_string_pretty(&capabilities)?)?;
+    fs::write(cli.out.with_extension("md"), markdown(&capabilities))?;
+    println!(
+        "Wrote {} capabilities to {}",
+        capabilities.len(),
+        cli.out.display()
+    );
+    Ok(())
+}
+
+/// One row per (system, target) declared in `matrix`, in system then target order.
+fn capabilities(matrix: &BenchMatrix, collected: &Collected) -> Vec<Capability> {
+    let mut rows: BTreeMap<(&str, &str), Capability> = BTreeMap::new();
+    for bench in &matrix.benches {
+        let key = match bench.feature.as_deref() {
+            Some(feature) if !feature.is_empty() => format!("{}_{feature}", bench.system),
+            _ => bench.system.clone(),
+        };
+        let properties = collected.systems.get(&key);
+        let max_size = collected
+            .measurements
+            .iter()
+            .filter(|m| m.system == key && m.target == bench.target && m.failures.is_empty())
+            .map(|m| m.input_size)
+            .max();
+
+        let row = rows
+            .entry((&bench.system, &bench.target))
+            .or_insert_with(|| Capability {
+                system: bench.system.clone(),
+                target: bench.target.clone(),
+                features: Vec::new(),
+                max_size: None,
+                gpu: false,
+                onchain_verifier: None,
+                optional: true,
+            });
+        if let Some(feature) = bench.feature.as_ref().filter(|f| !f.is_empty()) {
+            row.features.push(feature.clone());
+        }
+        row.max_size = row.max_size.max(max_size);
+        row.gpu |= bench.feature.as_deref() == Some("gpu")
+            || properties.and_then(|p| p.prover_resource.as_deref()) == Some("gpu");
+        if row.onchain_verifier.is_none() {
+            row.onchain_verifier = properties
+                .and_then(|p| p.onchain_verifier.as_deref())
+                .map(String::from);
+        }
+        row.optional &= bench.optional;
+    }
+    rows.into_values().collect()
+}
+
+fn markdown(capabilities: &[Capability]) -> String {
+    let mut md = String::from(
+        "| System | Target | Max size | Features | GPU | On-chain verifier |\n| --- | --- | ---: | --- | --- | --- |\n",
+    );
+    for c in capabilities {
+        let max_size = match c.max_size {
+            Some(size) => size.to_string(),
+            None if c.optional => "not measured (optional)".to_string(),
+            None => "not measured".to_string(),
+        };
+        let _ = writeln!(
+            md,
+            "| {} | {} | {} | {} | {} | {} |",
+            c.system,
+            c.target,
+            max_size,
+            c.features.join(", "),
+            if c.gpu { "yes" } else { "no" },
+            c.onchain_verifier.as_deref().unwrap_or("no"),
+        );
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use utils::matrix::DeclaredBench;
+
+    fn bench(system: &str, target: &str, feature: Option<&str>) -> DeclaredBench {
+        DeclaredBench {
+            system: system.to_string(),
+            target: target.to_string(),
+            feature: feature.map(String::from),
+            optional: feature.is_some(),
+            ..Default::default()
+        }
+    }
+
+    fn measurement(system: &str, input_size: usize, failed: bool) -> Measurement {
+        Measurement {
+            system: system.to_string(),
+            target: "sha256".to_string(),
+            input_size,
+            failures: if failed {
+                vec![serde_json::json!({ "step": "prove" })]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    #[test]
+    fn test_variants_merge_into_one_row() {
+        let matrix = BenchMatrix {
+            benches: vec![
+                bench("jolt", "sha256", None),
+                bench("jolt", "sha256", Some("gpu")),
+                bench("circom", "sha256", None),
+            ],
+            ..Default::default()
+        };
+        let collected = Collected {
+            systems: BTreeMap::from([
+                (
+                    "jolt_gpu".to_string(),
+                    BenchProperties::default().with_prover_resource("gpu"),
+                ),
+                (
+                    "circom".to_string(),
+                    BenchProperties {
+                        onchain_verifier: Some(Cow::Borrowed("evm")),
+                        ..Default::default()
+                    },
+                ),
+            ]),
+            measurements: vec![
+                measurement("jolt", 1024, false),
+                measurement("jolt_gpu", 2048, false),
+                measurement("jolt_gpu", 4096, true),
+            ],
+        };
+
+        let rows = capabilities(&matrix, &collected);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            Capability {
+                system: "circom".to_string(),
+                target: "sha256".to_string(),
+                features: Vec::new(),
+                max_size: None,
+                gpu: false,
+                onchain_verifier: Some("evm".to_string()),
+                optional: false,
+            }
+        );
+        assert_eq!(rows[1].features, ["gpu"]);
+        assert_eq!(rows[1].max_size, Some(2048));
+        assert!(rows[1].gpu);
+        assert!(!rows[1].optional);
+        assert!(markdown(&rows).contains("| jolt | sha256 | 2048 | gpu | yes | no |"));
+    }
+}
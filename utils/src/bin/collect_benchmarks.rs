@@ -1,14 +1,47 @@
 use chrono::Utc;
+use clap::{Parser, Subcommand};
 use glob::glob;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{env, fs, io};
-use utils::bench::Metrics;
-use utils::harness::BenchProperties;
+use utils::BenchId;
+use utils::attestation;
+use utils::bench::failure::ProverFailure;
+use utils::bench::mem::{Phase, PhasePeakMemory};
+use utils::bench::perf::PerfCounters;
+use utils::bench::{MemReport, Metrics};
+use utils::callgrind::{self, CallgrindCounts};
+use utils::caveat::Caveat;
+use utils::compression::CompressedSizes;
+use utils::cost::ProofCost;
+use utils::coverage::{self, GapReason};
+use utils::criterion_layout::{self, CriterionBench};
+use utils::cycles::CycleBreakdown;
+use utils::entropy::ProofEntropy;
+use utils::environment::EnvironmentFingerprint;
+use utils::harness::baseline::BaselineDelta;
+use utils::harness::circuit_size::CircuitSize;
+use utils::harness::cold_start::ColdStart;
+use utils::harness::invalid_input::InvalidInputRejection;
+use utils::harness::memory_pressure::MemoryPressure;
+use utils::harness::soak::Soak;
+use utils::harness::spans::ProvePhases;
+use utils::harness::verify_batch::VerifyBatch;
+use utils::harness::verify_budget::VerifyBudget;
+use utils::harness::{BenchProperties, StepError};
+use utils::ingest;
+use utils::manifest::{self, RunManifest};
+use utils::matrix::{self, RuntimeConfig};
+use utils::proof_size::ProofSizeBreakdown;
+use utils::results;
+use utils::scaling::{self, ScalingFit};
+use utils::variance::{RepeatStats, Spread};
+use utils::verification::{CrossVerification, VerificationPath};
+use utils::witness_size::WitnessSize;
 
 /// Top-level output structure for collected benchmark results.
 #[derive(Serialize)]
@@ -16,35 +49,220 @@ struct CollectedBenchmarks {
     metadata: Metadata,
     systems: BTreeMap<String, BenchProperties>,
     measurements: Vec<Measurement>,
+    scaling: Vec<ScalingTrend>,
+    security: SecurityReport,
 }
 
 /// Origin metadata for the collected benchmark run.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct Metadata {
     timestamp: String,
     commit_sha: Option<String>,
     workflow_run_url: Option<String>,
     artifact_urls: Option<Vec<String>>,
+    /// Provenance of the collected run, from its `manifest.json`.
+    manifest: Option<RunManifest>,
+    /// SHA-256 of the golden input of every measured target and size, keyed `<target>/<size>`
+    /// (see [`attestation::input_hash`]).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    input_hashes: BTreeMap<String, String>,
 }
 
 /// A single benchmark measurement, referencing a system by key.
 #[serde_as]
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct Measurement {
     system: String,
     target: String,
     input_size: usize,
+    corpus: Option<String>,
     #[serde_as(as = "DurationNanoSeconds")]
     proof_duration: Duration,
     #[serde_as(as = "DurationNanoSeconds")]
     verify_duration: Duration,
     cycles: Option<u64>,
+    cycle_breakdown: Option<CycleBreakdown>,
     proof_size: usize,
+    proof_size_breakdown: Option<ProofSizeBreakdown>,
     preprocessing_size: usize,
+    compressed_size: Option<CompressedSizes>,
+    proof_entropy: Option<ProofEntropy>,
     num_constraints: usize,
+    circuit_size: Option<CircuitSize>,
+    witness_size: Option<WitnessSize>,
     peak_memory: usize,
+    peak_virtual_memory: Option<usize>,
+    peak_swap: Option<usize>,
+    peak_gpu_memory: Option<usize>,
+    phase_peak_memory: Option<PhasePeakMemory>,
+    callgrind: Option<CallgrindCounts>,
+    perf_counters: Option<PerfCounters>,
+    prove_phases: Option<ProvePhases>,
+    cold_start: Option<ColdStart>,
+    light_client_verify: Option<VerifyBudget>,
+    verify_batch: Option<VerifyBatch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    verification_paths: Vec<VerificationPath>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cross_verifications: Vec<CrossVerification>,
+    memory_pressure: Option<MemoryPressure>,
+    invalid_input: Option<InvalidInputRejection>,
+    soak: Option<Soak>,
+    runtime: Option<RuntimeConfig>,
+    proof_cost: Option<ProofCost>,
+    baseline: Option<BaselineDelta>,
+    /// Non-empty if a measurement failed (as opposed to not being run).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    failures: Vec<ProverFailure>,
+    /// Set if a prepare, prove or verify step returned an error instead of a measurement.
+    step_error: Option<StepError>,
+    environment: Option<EnvironmentFingerprint>,
+    /// Timed from a single prove and verify (quick mode), not from Criterion samples.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    low_confidence: bool,
+    /// Reasons the measurement is not directly comparable with the rest of its target.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    caveats: Vec<Caveat>,
+    /// Spread over the runs of a repeated campaign; the durations and peak memory above are then
+    /// the means.
+    repeats: Option<RepeatStats>,
+    /// Set if the measurement was submitted from outside this repository's CI and merged with
+    /// `collect_benchmarks ingest`.
+    third_party: Option<ThirdParty>,
+}
+
+/// Provenance of a contributor-submitted measurement.
+#[derive(Clone, Serialize, Deserialize)]
+struct ThirdParty {
+    contributor: String,
+    ingested_at: String,
+}
+
+/// A previous collection, as read back by `ingest`.
+#[derive(Deserialize)]
+struct Dataset {
+    metadata: Metadata,
+    systems: BTreeMap<String, BenchProperties>,
+    measurements: Vec<Measurement>,
+}
+
+/// Best-fit growth of one metric over input size for a (system, target) pair.
+#[derive(Serialize)]
+struct ScalingTrend {
+    system: String,
+    target: String,
+    metric: &'static str,
+    #[serde(flatten)]
+    fit: ScalingFit,
+}
+
+/// Fits proving time and peak memory against input size for every (system, target) pair that was
+/// measured at enough sizes (see [`scaling::fit_scaling`]). Third-party measurements come from
+/// other machines and are left out of the fits.
+fn scaling_trends(measurements: &[Measurement]) -> Vec<ScalingTrend> {
+    type Extract = fn(&Measurement) -> f64;
+    const METRICS: [(&str, Extract); 2] = [
+        ("proof_duration", |m| m.proof_duration.as_nanos() as f64),
+        ("peak_memory", |m| m.peak_memory as f64),
+    ];
+
+    let mut groups: BTreeMap<(&str, &str), Vec<&Measurement>> = BTreeMap::new();
+    for m in measurements.iter().filter(|m| m.third_party.is_none()) {
+        groups.entry((&m.system, &m.target)).or_default().push(m);
+    }
+
+    let mut trends = Vec::new();
+    for ((system, target), group) in groups {
+        for (metric, extract) in METRICS {
+            let samples: Vec<(f64, f64)> = group
+                .iter()
+                .map(|m| (m.input_size as f64, extract(m)))
+                .collect();
+            if let Some(fit) = scaling::fit_scaling(&samples) {
+                trends.push(ScalingTrend {
+                    system: system.to_string(),
+                    target: target.to_string(),
+                    metric,
+                    fit,
+                });
+            }
+        }
+    }
+    trends
+}
+
+/// Declared security levels are compared in buckets: numbers from different buckets are not a
+/// like-for-like comparison.
+const SECURITY_BUCKETS: [(u64, &str); 3] = [(128, ">=128"), (100, "100-127"), (80, "80-99")];
+
+/// Systems grouped by security bucket, and the measurements that mix buckets.
+#[derive(Serialize)]
+struct SecurityReport {
+    buckets: BTreeMap<&'static str, Vec<String>>,
+    cross_bucket_comparisons: Vec<CrossBucketComparison>,
+}
+
+/// A (target, input size) pair measured by systems from more than one security bucket.
+#[derive(Serialize)]
+struct CrossBucketComparison {
+    target: String,
+    input_size: usize,
+    buckets: BTreeMap<&'static str, Vec<String>>,
+}
+
+fn security_bucket(security_bits: u64) -> &'static str {
+    if security_bits == 0 {
+        return "unknown";
+    }
+    SECURITY_BUCKETS
+        .iter()
+        .find(|(min_bits, _)| security_bits >= *min_bits)
+        .map_or("<80", |(_, label)| label)
+}
+
+fn security_report(
+    systems: &BTreeMap<String, BenchProperties>,
+    measurements: &[Measurement],
+) -> SecurityReport {
+    let bucket_of =
+        |system: &str| security_bucket(systems.get(system).map_or(0, |props| props.security_bits));
+
+    let mut buckets: BTreeMap<&'static str, Vec<String>> = BTreeMap::new();
+    for system in systems.keys() {
+        buckets
+            .entry(bucket_of(system))
+            .or_default()
+            .push(system.clone());
+    }
+
+    let mut by_input: BTreeMap<(&str, usize), BTreeMap<&'static str, Vec<String>>> =
+        BTreeMap::new();
+    for m in measurements {
+        let systems = by_input
+            .entry((&m.target, m.input_size))
+            .or_default()
+            .entry(bucket_of(&m.system))
+            .or_default();
+        if !systems.contains(&m.system) {
+            systems.push(m.system.clone());
+        }
+    }
+    let cross_bucket_comparisons = by_input
+        .into_iter()
+        .filter(|(_, buckets)| buckets.len() > 1)
+        .map(|((target, input_size), buckets)| CrossBucketComparison {
+            target: target.to_string(),
+            input_size,
+            buckets,
+        })
+        .collect();
+
+    SecurityReport {
+        buckets,
+        cross_bucket_comparisons,
+    }
 }
 
 /// Compute the unique system key from a metrics entry.
@@ -55,8 +273,8 @@ fn system_key(name: &str, feat: &Option<String>) -> String {
     }
 }
 
-/// Build [`Metadata`] from environment variables, if available.
-fn build_metadata() -> Metadata {
+/// Build [`Metadata`] from environment variables, if available, and the run manifest.
+fn build_metadata(manifest: Option<RunManifest>) -> Metadata {
     let timestamp = Utc::now().to_rfc3339();
     let commit_sha = env::var("COMMIT_SHA").ok().filter(|s| !s.is_empty());
     let workflow_run_url = env::var("WORKFLOW_RUN_URL").ok().filter(|s| !s.is_empty());
@@ -69,35 +287,347 @@ fn build_metadata() -> Metadata {
         commit_sha,
         workflow_run_url,
         artifact_urls,
+        manifest,
+        input_hashes: BTreeMap::new(),
+    }
+}
+
+/// [`attestation::input_hash`] of every (target, input size) pair that was measured.
+fn input_hashes(measurements: &[Measurement]) -> BTreeMap<String, String> {
+    measurements
+        .iter()
+        .filter_map(|m| {
+            let hash = attestation::input_hash(&m.target, m.input_size)?;
+            Some((format!("{}/{}", m.target, m.input_size), hash))
+        })
+        .collect()
+}
+
+/// The collected form of `m`, with the spread of its runs if it was repeated.
+fn measurement(m: Metrics, repeats: Option<RepeatStats>) -> Measurement {
+    Measurement {
+        system: system_key(&m.name, &m.feat),
+        target: m.target,
+        input_size: m.input_size,
+        corpus: m.corpus,
+        proof_duration: m.proof_duration,
+        verify_duration: m.verify_duration,
+        cycles: m.cycles,
+        cycle_breakdown: m.cycle_breakdown,
+        proof_size: m.proof_size,
+        proof_size_breakdown: m.proof_size_breakdown,
+        preprocessing_size: m.preprocessing_size,
+        compressed_size: m.compressed_size,
+        proof_entropy: m.proof_entropy,
+        num_constraints: m.num_constraints,
+        circuit_size: m.circuit_size,
+        witness_size: m.witness_size,
+        peak_memory: m.peak_memory,
+        peak_virtual_memory: m.peak_virtual_memory,
+        peak_swap: m.peak_swap,
+        peak_gpu_memory: m.peak_gpu_memory,
+        phase_peak_memory: m.phase_peak_memory,
+        callgrind: m.callgrind,
+        perf_counters: m.perf_counters,
+        prove_phases: m.prove_phases,
+        cold_start: m.cold_start,
+        light_client_verify: m.light_client_verify,
+        verify_batch: m.verify_batch,
+        verification_paths: m.verification_paths,
+        cross_verifications: m.cross_verifications,
+        memory_pressure: m.memory_pressure,
+        invalid_input: m.invalid_input,
+        soak: m.soak,
+        runtime: m.runtime,
+        proof_cost: m.proof_cost,
+        baseline: m.baseline,
+        failures: m.failures,
+        step_error: m.step_error,
+        environment: m.environment,
+        low_confidence: m.low_confidence,
+        caveats: m.caveats,
+        repeats,
+        third_party: None,
+    }
+}
+
+/// Assembles the collected output, deriving the input hashes, scaling fits and security report
+/// from `measurements`.
+fn collected_benchmarks(
+    mut metadata: Metadata,
+    systems: BTreeMap<String, BenchProperties>,
+    measurements: Vec<Measurement>,
+) -> CollectedBenchmarks {
+    metadata.input_hashes = input_hashes(&measurements);
+    CollectedBenchmarks {
+        metadata,
+        scaling: scaling_trends(&measurements),
+        security: security_report(&systems, &measurements),
+        systems,
+        measurements,
+    }
+}
+
+/// Writes `collected` to `output_path` and, with [`attestation::SIGNING_KEY_ENV`] set, its
+/// detached signature next to it.
+fn write_collected(output_path: &Path, collected: &CollectedBenchmarks) -> io::Result<()> {
+    let output = serde_json::to_string_pretty(collected)?;
+    fs::write(output_path, &output)?;
+    if let Some(key) = attestation::signing_key_from_env()? {
+        let signature_path =
+            output_path.with_extension(format!("json.{}", attestation::SIGNATURE_EXTENSION));
+        let signature = attestation::sign(output.as_bytes(), &key);
+        fs::write(&signature_path, serde_json::to_string_pretty(&signature)?)?;
+        println!(
+            "Signed {} with key {} into {}",
+            output_path.display(),
+            signature.public_key,
+            signature_path.display()
+        );
     }
+    Ok(())
+}
+
+/// Collect benchmark metrics into a single JSON file
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List the benches declared in bench_matrix.json that have no successful measurement
+    Coverage {
+        /// Output of a previous collection
+        #[arg(long, default_value = "../collected_benchmarks.json")]
+        collected: PathBuf,
+
+        /// Where to write the report as JSON
+        #[arg(long, default_value = "../coverage_report.json")]
+        output: PathBuf,
+
+        /// Exit with an error if a bench that is not optional has a gap
+        #[arg(long)]
+        fail_on_gaps: bool,
+    },
+    /// Validate metrics files produced outside CI and merge them as third-party measurements
+    Ingest {
+        /// Submitted `*_metrics.json` files
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// Who produced the measurements, recorded with each of them
+        #[arg(long)]
+        contributor: String,
+
+        /// Dataset to merge into; it is rewritten (and re-signed) in place
+        #[arg(long, default_value = "../collected_benchmarks.json")]
+        collected: PathBuf,
+    },
 }
 
-/// Collect all JSON files in subdirectories of the workspace directory
-/// containing benchmark metrics, and write them to a single JSON file
-/// at `../collected_benchmarks.json`.
 fn main() -> io::Result<()> {
-    let mut all_metrics: Vec<Metrics> = Vec::new();
+    match Cli::parse().command {
+        None => collect(),
+        Some(Command::Coverage {
+            collected,
+            output,
+            fail_on_gaps,
+        }) => report_coverage(&collected, &output, fail_on_gaps),
+        Some(Command::Ingest {
+            files,
+            contributor,
+            collected,
+        }) => ingest_submissions(&files, &contributor, &collected),
+    }
+}
+
+/// Validate the contributor-submitted metrics `files` (see [`ingest::validate`]) and merge them
+/// into the dataset at `collected`. Nothing is merged if any file is rejected.
+fn ingest_submissions(files: &[PathBuf], contributor: &str, collected: &Path) -> io::Result<()> {
+    let mut dataset: Dataset = serde_json::from_str(&fs::read_to_string(collected)?)
+        .map_err(|e| io::Error::other(format!("{}: {}", collected.display(), e)))?;
+    let submissions = files
+        .iter()
+        .map(|file| {
+            serde_json::from_str::<Metrics>(&fs::read_to_string(file)?)
+                .map_err(|e| io::Error::other(format!("{}: {}", file.display(), e)))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let third_party = ThirdParty {
+        contributor: contributor.to_string(),
+        ingested_at: Utc::now().to_rfc3339(),
+    };
+    let rejections = merge_submissions(&mut dataset, submissions, &third_party);
+    let mut rejected = 0;
+    for (file, reasons) in files.iter().zip(&rejections) {
+        if reasons.is_empty() {
+            println!("OK     {}", file.display());
+        } else {
+            rejected += 1;
+            let reasons: Vec<String> = reasons.iter().map(ToString::to_string).collect();
+            eprintln!("REJECT {}: {}", file.display(), reasons.join(", "));
+        }
+    }
+    if rejected > 0 {
+        return Err(io::Error::other(format!(
+            "{rejected} of {} submitted files rejected, nothing was merged",
+            files.len()
+        )));
+    }
+
+    let collected_benchmarks =
+        collected_benchmarks(dataset.metadata, dataset.systems, dataset.measurements);
+    write_collected(collected, &collected_benchmarks)?;
+    println!(
+        "Merged {} third-party measurement(s) from {contributor} into {}",
+        files.len(),
+        collected.display()
+    );
+    Ok(())
+}
+
+/// Validates `submissions` against `dataset` and, if none is rejected, merges them as
+/// measurements of `third_party`, replacing the contributor's earlier submissions of the same
+/// measurements. Returns the rejections of each submission, in order.
+fn merge_submissions(
+    dataset: &mut Dataset,
+    submissions: Vec<Metrics>,
+    third_party: &ThirdParty,
+) -> Vec<Vec<ingest::Rejection>> {
+    // Submissions of a system the dataset does not have yet are checked against each other.
+    let mut systems = dataset.systems.clone();
+    let rejections: Vec<_> = submissions
+        .iter()
+        .map(|m| {
+            let key = system_key(&m.name, &m.feat);
+            let rejections = ingest::validate(m, &key, &systems);
+            if rejections.is_empty() {
+                systems
+                    .entry(key)
+                    .or_insert_with(|| m.bench_properties.clone());
+            }
+            rejections
+        })
+        .collect();
+    if rejections.iter().any(|r| !r.is_empty()) {
+        return rejections;
+    }
+
+    dataset.systems = systems;
+    for m in submissions {
+        let mut submitted = measurement(m, None);
+        submitted.third_party = Some(third_party.clone());
+        dataset.measurements.retain(|existing| {
+            !(existing.system == submitted.system
+                && existing.target == submitted.target
+                && existing.input_size == submitted.input_size
+                && existing
+                    .third_party
+                    .as_ref()
+                    .is_some_and(|t| t.contributor == third_party.contributor))
+        });
+        dataset.measurements.push(submitted);
+    }
+    rejections
+}
+
+/// Cross-reference the benches declared in the bench matrix with the measurements in
+/// `collected`, print the gaps, and write the [`coverage::CoverageReport`] to `output`.
+fn report_coverage(collected: &Path, output: &Path, fail_on_gaps: bool) -> io::Result<()> {
+    let declared = matrix::load_matrix().benches;
+    if declared.is_empty() {
+        eprintln!(
+            "No benches declared in {}, nothing to check",
+            matrix::matrix_path().display()
+        );
+    }
+    let (manifest, measurements) = coverage::read_collected(collected)?;
+    let report = coverage::coverage_report(&declared, manifest.as_ref(), &measurements);
+
+    println!(
+        "{} of {} declared measurements covered",
+        report.covered, report.declared
+    );
+    for gap in &report.gaps {
+        let reason = match gap.reason {
+            GapReason::NotRun => "not run",
+            GapReason::NoMeasurement => "no measurement",
+            GapReason::Failed => "failed",
+        };
+        let optional = if gap.optional { " (optional)" } else { "" };
+        println!("MISSING {}: {reason}{optional}", gap.id);
+    }
+    for id in &report.undeclared {
+        println!("UNDECLARED {id}");
+    }
+    fs::write(output, serde_json::to_string_pretty(&report)?)?;
+    println!("Wrote {}", output.display());
+
+    let required = report.required_gaps().count();
+    if fail_on_gaps && required > 0 {
+        return Err(io::Error::other(format!(
+            "{required} declared measurement(s) are missing"
+        )));
+    }
+    Ok(())
+}
+
+/// Collect the metrics files of the run directory selected by `BENCH_RESULTS_DIR` and
+/// `BENCH_RUN_ID` (see [`utils::results`]), and write them to a single JSON file at
+/// `../collected_benchmarks.json`.
+fn collect() -> io::Result<()> {
+    // Runs of each measurement, keyed by its `BenchId`.
+    let mut runs_by_id: BTreeMap<String, Vec<Metrics>> = BTreeMap::new();
     let mut had_errors = false;
     let root_dir = workspace_dir();
-    for entry in fs::read_dir(root_dir)? {
-        let path = entry?.path();
-        if path.is_dir() {
-            let metrics_file_paths = find_metrics_files(&path);
-            for metrics_file_path in metrics_file_paths {
-                println!("Extracting metrics from {}", metrics_file_path.display());
-                match extract_metrics(&path, &metrics_file_path) {
-                    Ok((metrics, errors)) => {
-                        all_metrics.push(metrics);
-                        had_errors |= errors;
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "\n===== WARNING: failed to parse metrics file =====\n  file: {}\n  error: {}\n===============================================\n",
-                            metrics_file_path.display(),
-                            e
-                        );
-                        continue;
-                    }
+    let target_dir = root_dir.join("target");
+    let run_dir = results::Writer::from_env().dir().to_path_buf();
+    let runs = campaign_runs(&run_dir);
+    println!(
+        "Collecting {} run(s) from {}",
+        runs.len(),
+        run_dir.display()
+    );
+    let manifest = manifest::read_manifest(&runs[0]).unwrap_or_else(|e| {
+        eprintln!(
+            "\n===== WARNING: failed to read run manifest =====\n  dir: {}\n  error: {}\n===============================================\n",
+            runs[0].display(),
+            e
+        );
+        had_errors = true;
+        None
+    });
+    for run in &runs {
+        // Repeated runs keep their own copy of Criterion's estimates.
+        let criterion_dir = match run.join("criterion") {
+            dir if dir.is_dir() => dir,
+            _ => target_dir.join("criterion"),
+        };
+        let criterion_benches = criterion_layout::discover(&criterion_dir);
+        for metrics_file_path in find_metrics_files(run) {
+            println!("Extracting metrics from {}", metrics_file_path.display());
+            match extract_metrics(run, &target_dir, &metrics_file_path, &criterion_benches) {
+                Ok((metrics, errors)) => {
+                    let id = BenchId::new(
+                        &metrics.target,
+                        metrics.input_size,
+                        &metrics.name,
+                        metrics.feat.as_deref(),
+                    );
+                    runs_by_id.entry(id.to_string()).or_default().push(metrics);
+                    had_errors |= errors;
+                }
+                Err(e) => {
+                    eprintln!(
+                        "\n===== WARNING: failed to parse metrics file =====\n  file: {}\n  error: {}\n===============================================\n",
+                        metrics_file_path.display(),
+                        e
+                    );
+                    continue;
                 }
             }
         }
@@ -105,31 +635,15 @@ fn main() -> io::Result<()> {
 
     let mut systems = BTreeMap::new();
     let mut measurements = Vec::new();
-    for m in all_metrics {
-        let key = system_key(&m.name, &m.feat);
-        systems.entry(key.clone()).or_insert(m.bench_properties);
-        measurements.push(Measurement {
-            system: key,
-            target: m.target,
-            input_size: m.input_size,
-            proof_duration: m.proof_duration,
-            verify_duration: m.verify_duration,
-            cycles: m.cycles,
-            proof_size: m.proof_size,
-            preprocessing_size: m.preprocessing_size,
-            num_constraints: m.num_constraints,
-            peak_memory: m.peak_memory,
-        });
+    for (m, repeats) in runs_by_id.into_values().map(merge_runs) {
+        systems
+            .entry(system_key(&m.name, &m.feat))
+            .or_insert_with(|| m.bench_properties.clone());
+        measurements.push(measurement(m, repeats));
     }
 
-    let collected = CollectedBenchmarks {
-        metadata: build_metadata(),
-        systems,
-        measurements,
-    };
-
-    let output = serde_json::to_string_pretty(&collected)?;
-    std::fs::write("../collected_benchmarks.json", output)?;
+    let collected = collected_benchmarks(build_metadata(manifest), systems, measurements);
+    write_collected(Path::new("../collected_benchmarks.json"), &collected)?;
 
     if had_errors {
         Err(io::Error::other(
@@ -145,137 +659,86 @@ fn main() -> io::Result<()> {
 ///
 /// Specifically, this function looks for fields `proof_duration` and
 /// `verify_duration` in the JSON file and fills them in with the mean
-/// execution times reported by Criterion's JSON files, if they are not
-/// already set. It also fills in the `peak_memory` field if it is not
-/// already set, using the memory usage reported by the `mem_report` JSON
-/// file.
+/// execution times reported by Criterion's JSON files (located among
+/// `criterion_benches`), if they are not already set. It also fills in the
+/// `peak_memory` field if it is not already set, using the memory usage
+/// reported by the `mem_report` JSON file next to it in the run directory `dir`.
 ///
 /// Returns `Metrics` if successful.
-fn extract_metrics(dir: &Path, metrics_file_path: &Path) -> io::Result<(Metrics, bool)> {
+fn extract_metrics(
+    dir: &Path,
+    target_dir: &Path,
+    metrics_file_path: &Path,
+    criterion_benches: &[CriterionBench],
+) -> io::Result<(Metrics, bool)> {
     let mut had_errors = false;
     let metrics_json: Value = serde_json::from_str(&fs::read_to_string(metrics_file_path)?)?;
 
     let mut metrics: Metrics = serde_json::from_value(metrics_json)?;
 
-    let target = &metrics.target;
-    let input_size = metrics.input_size;
-    let proving_system = &metrics.name;
-    let feat = metrics.feat.as_deref();
+    let bench_id = BenchId::new(
+        &metrics.target,
+        metrics.input_size,
+        &metrics.name,
+        metrics.feat.as_deref(),
+    );
+    let group_id = bench_id.to_string();
+
+    // A step that returned an error is a result, not a collection error: the size was neither
+    // timed nor measured.
+    if let Some(step_error) = &metrics.step_error {
+        eprintln!(
+            "\n===== WARNING: benchmark step failed =====\n  benchmark: {group_id}\n  {step_error}\n=========================================\n"
+        );
+        return Ok((metrics, had_errors));
+    }
 
     if metrics.proof_duration.is_zero() {
-        let crit_path_p = match feat {
-            Some(f) if !f.is_empty() => dir.parent().unwrap().join(format!(
-                "target/criterion/{target}_{input_size}_{proving_system}_{f}/{target}_{input_size}_{proving_system}_{f}_prove/new/estimates.json"
-            )),
-            _ => dir.parent().unwrap().join(format!(
-                "target/criterion/{target}_{input_size}_{proving_system}/{target}_{input_size}_{proving_system}_prove/new/estimates.json"
-            )),
-        };
-        if crit_path_p.exists() {
-            println!("Reading proof duration from {}", crit_path_p.display());
-            match fs::read_to_string(&crit_path_p) {
-                Ok(contents) => match serde_json::from_str::<Value>(&contents) {
-                    Ok(proof_crit) => {
-                        if let Some(est) =
-                            proof_crit.get("mean").and_then(|m| m.get("point_estimate"))
-                            && let Some(f) = est.as_f64()
-                        {
-                            metrics.proof_duration = Duration::from_nanos(f.round() as u64);
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "\n===== WARNING: failed to parse proof estimates =====\n  file: {}\n  error: {}\n===================================================\n",
-                            crit_path_p.display(),
-                            e
-                        );
-                        had_errors = true;
-                    }
-                },
-                Err(e) => {
-                    eprintln!(
-                        "\n===== WARNING: failed to read proof estimates =====\n  file: {}\n  error: {}\n==================================================\n",
-                        crit_path_p.display(),
-                        e
-                    );
-                    had_errors = true;
-                }
-            }
-        } else {
-            eprintln!(
-                "\n===== WARNING: proof estimates.json not found =====\n  file: {}\n==================================================\n",
-                crit_path_p.display()
-            );
-            had_errors = true;
+        match criterion_mean(criterion_benches, &group_id, "prove") {
+            Some(duration) => metrics.proof_duration = duration,
+            None => had_errors = true,
         }
     }
 
     if metrics.verify_duration.is_zero() {
-        let crit_path_v = match feat {
-            Some(f) if !f.is_empty() => dir.parent().unwrap().join(format!(
-                "target/criterion/{target}_{input_size}_{proving_system}_{f}/{target}_{input_size}_{proving_system}_{f}_verify/new/estimates.json"
-            )),
-            _ => dir.parent().unwrap().join(format!(
-                "target/criterion/{target}_{input_size}_{proving_system}/{target}_{input_size}_{proving_system}_verify/new/estimates.json"
-            )),
+        match criterion_mean(criterion_benches, &group_id, "verify") {
+            Some(duration) => metrics.verify_duration = duration,
+            None => had_errors = true,
+        }
+    }
+
+    if let Some(baseline) = &mut metrics.baseline {
+        let baseline_mean = |step: &str| {
+            let estimates = criterion_layout::find(criterion_benches, &group_id, step)?
+                .baseline_estimates(&baseline.name);
+            criterion_layout::read_mean(&estimates).ok()
         };
-        if crit_path_v.exists() {
-            println!("Reading verify duration from {}", crit_path_v.display());
-            match fs::read_to_string(&crit_path_v) {
-                Ok(contents) => match serde_json::from_str::<Value>(&contents) {
-                    Ok(verify_crit) => {
-                        if let Some(est) = verify_crit
-                            .get("mean")
-                            .and_then(|m| m.get("point_estimate"))
-                            && let Some(f) = est.as_f64()
-                        {
-                            metrics.verify_duration = Duration::from_nanos(f.round() as u64);
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "\n===== WARNING: failed to parse verify estimates =====\n  file: {}\n  error: {}\n====================================================\n",
-                            crit_path_v.display(),
-                            e
-                        );
-                        had_errors = true;
-                    }
-                },
-                Err(e) => {
-                    eprintln!(
-                        "\n===== WARNING: failed to read verify estimates =====\n  file: {}\n  error: {}\n===================================================\n",
-                        crit_path_v.display(),
-                        e
-                    );
-                    had_errors = true;
-                }
-            }
-        } else {
+        let means = (baseline_mean("prove"), baseline_mean("verify"));
+        if means == (None, None) {
             eprintln!(
-                "\n===== WARNING: verify estimates.json not found =====\n  file: {}\n===================================================\n",
-                crit_path_v.display()
+                "\n===== WARNING: baseline estimates not found =====\n  benchmark: {group_id}\n  baseline: {}\n=================================================\n",
+                baseline.name
             );
-            had_errors = true;
         }
+        baseline.compare(means, metrics.proof_duration, metrics.verify_duration);
     }
 
     if metrics.peak_memory == 0 {
-        let mem_path = match feat {
-            Some(f) if !f.is_empty() => dir.join(format!(
-                "{target}_{input_size}_{proving_system}_{f}_mem_report.json"
-            )),
-            _ => dir.join(format!(
-                "{target}_{input_size}_{proving_system}_mem_report.json"
-            )),
-        };
+        let mem_path = dir.join(bench_id.mem_report_filename());
         if mem_path.exists() {
             println!("Reading peak memory from {}", mem_path.display());
             match fs::read_to_string(&mem_path) {
-                Ok(contents) => match serde_json::from_str::<Value>(&contents) {
-                    Ok(mem) => {
-                        if let Some(m) = mem.get("peak_memory") {
-                            metrics.peak_memory = m.as_u64().unwrap_or(0) as usize;
+                Ok(contents) => match serde_json::from_str::<MemReport>(&contents) {
+                    Ok(report) => {
+                        // A crashed prover is a result, not a collection error.
+                        if let Some(failure) = &report.failure {
+                            eprintln!(
+                                "\n===== WARNING: prover crashed =====\n  file: {}\n  {}\n==================================\n",
+                                mem_path.display(),
+                                failure
+                            );
                         }
+                        report.apply_to(&mut metrics);
                     }
                     Err(e) => {
                         eprintln!(
@@ -295,6 +758,9 @@ fn extract_metrics(dir: &Path, metrics_file_path: &Path) -> io::Result<(Metrics,
                     had_errors = true;
                 }
             }
+        } else if metrics.low_confidence {
+            // Quick mode, flagged low-confidence, does not measure RAM.
+            println!("No memory report for {bench_id} (quick mode), RAM not measured");
         } else {
             eprintln!(
                 "\n===== WARNING: memory report not found =====\n  file: {}\n===========================================\n",
@@ -304,9 +770,81 @@ fn extract_metrics(dir: &Path, metrics_file_path: &Path) -> io::Result<(Metrics,
         }
     }
 
+    // Phase reports are optional: they are only written with `BENCH_MEM_PHASES` set.
+    if metrics.phase_peak_memory.is_none() {
+        metrics.phase_peak_memory = read_phase_peak_memory(dir, &bench_id);
+    }
+
+    // Instruction counts are optional: only benches with an iai-callgrind target produce them.
+    if metrics.callgrind.is_none()
+        && let Some(summary_path) = callgrind::find_summary(target_dir, &group_id)
+    {
+        println!("Reading instruction counts from {}", summary_path.display());
+        match callgrind::read_summary(&summary_path) {
+            Ok(counts) => metrics.callgrind = Some(counts),
+            Err(e) => {
+                eprintln!(
+                    "\n===== WARNING: failed to read callgrind summary =====\n  file: {}\n  error: {}\n====================================================\n",
+                    summary_path.display(),
+                    e
+                );
+                had_errors = true;
+            }
+        }
+    }
+
     Ok((metrics, had_errors))
 }
 
+/// Peak memory of each phase from the per-phase memory reports next to the metrics. A phase whose
+/// binary failed, which includes a binary that cannot verify, is left out.
+fn read_phase_peak_memory(dir: &Path, bench_id: &BenchId) -> Option<PhasePeakMemory> {
+    let mut phases = PhasePeakMemory::default();
+    let mut found = false;
+    for phase in Phase::ALL {
+        let path = dir.join(bench_id.phase_mem_report_filename(phase));
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        match serde_json::from_str::<MemReport>(&contents) {
+            Ok(report) if report.failure.is_none() => {
+                phases.set(phase, report.peak_memory);
+                found = true;
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!(
+                "\n===== WARNING: failed to parse memory report =====\n  file: {}\n  error: {}\n==================================================\n",
+                path.display(),
+                e
+            ),
+        }
+    }
+    found.then_some(phases)
+}
+
+/// Mean duration of the `step` (`prove`/`verify`) benchmark of `group_id` from Criterion's
+/// estimates. Logs a warning and returns `None` if it is missing or unreadable.
+fn criterion_mean(benches: &[CriterionBench], group_id: &str, step: &str) -> Option<Duration> {
+    let Some(bench) = criterion_layout::find(benches, group_id, step) else {
+        eprintln!(
+            "\n===== WARNING: {step} estimates.json not found =====\n  benchmark: {group_id}\n==================================================\n"
+        );
+        return None;
+    };
+    println!("Reading {step} duration from {}", bench.estimates.display());
+    match criterion_layout::read_mean(&bench.estimates) {
+        Ok(duration) => Some(duration),
+        Err(e) => {
+            eprintln!(
+                "\n===== WARNING: failed to read {step} estimates =====\n  file: {}\n  error: {}\n==================================================\n",
+                bench.estimates.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
 /// Returns the root directory of the current workspace, as determined by the
 /// `cargo locate-project` command.
 fn workspace_dir() -> PathBuf {
@@ -321,6 +859,51 @@ fn workspace_dir() -> PathBuf {
     cargo_path.parent().unwrap().to_path_buf()
 }
 
+/// Run directories to collect: `dir` itself, or for a campaign of repeated runs (see
+/// `repeat_benchmarks.sh`) its subdirectories that hold metrics, in run order.
+fn campaign_runs(dir: &Path) -> Vec<PathBuf> {
+    if !find_metrics_files(dir).is_empty() {
+        return vec![dir.to_path_buf()];
+    }
+    let mut runs: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.is_dir() && !find_metrics_files(path).is_empty())
+        .collect();
+    // `r2` before `r10`.
+    runs.sort_by_key(|path| (path.as_os_str().len(), path.clone()));
+    if runs.is_empty() {
+        runs.push(dir.to_path_buf());
+    }
+    runs
+}
+
+/// Folds the runs of one measurement into the first one, replacing its durations and peak memory
+/// by their means over the runs and collecting the failures of every run.
+fn merge_runs(runs: Vec<Metrics>) -> (Metrics, Option<RepeatStats>) {
+    let spread = |extract: fn(&Metrics) -> f64| {
+        let samples: Vec<f64> = runs.iter().map(extract).collect();
+        Spread::of(&samples).unwrap_or_default()
+    };
+    let stats = (runs.len() > 1).then(|| RepeatStats {
+        runs: runs.len(),
+        proof_duration: spread(|m| m.proof_duration.as_nanos() as f64),
+        verify_duration: spread(|m| m.verify_duration.as_nanos() as f64),
+        peak_memory: spread(|m| m.peak_memory as f64),
+    });
+
+    let mut runs = runs.into_iter();
+    let mut merged = runs.next().expect("a measurement has at least one run");
+    if let Some(stats) = &stats {
+        merged.proof_duration = Duration::from_nanos(stats.proof_duration.mean as u64);
+        merged.verify_duration = Duration::from_nanos(stats.verify_duration.mean as u64);
+        merged.peak_memory = stats.peak_memory.mean as usize;
+        merged.failures.extend(runs.flat_map(|m| m.failures));
+    }
+    (merged, stats)
+}
+
 /// Try to find a file(s) matching "*_metrics.json" in `dir`.
 /// Returns `Vec<PathBuf>`.
 fn find_metrics_files(dir: &Path) -> Vec<PathBuf> {
@@ -381,6 +964,10 @@ mod tests {
             is_maintained: true,
             is_audited: AuditStatus::NotAudited,
             isa: None,
+            prover_resource: None,
+            version: None,
+            onchain_verifier: None,
+            caveats: Cow::Borrowed(&[]),
         };
 
         let mut systems = BTreeMap::new();
@@ -390,13 +977,45 @@ mod tests {
             system: "binius64".to_string(),
             target: "sha256".to_string(),
             input_size: 128,
+            corpus: None,
             proof_duration: Duration::from_nanos(12345000),
             verify_duration: Duration::from_nanos(6789000),
             cycles: None,
+            cycle_breakdown: None,
             proof_size: 1024,
+            proof_size_breakdown: None,
             preprocessing_size: 2048,
+            compressed_size: None,
+            proof_entropy: None,
             num_constraints: 5000,
+            circuit_size: None,
+            witness_size: None,
             peak_memory: 100000,
+            peak_virtual_memory: None,
+            peak_swap: None,
+            peak_gpu_memory: None,
+            phase_peak_memory: None,
+            callgrind: None,
+            perf_counters: None,
+            prove_phases: None,
+            cold_start: None,
+            light_client_verify: None,
+            verify_batch: None,
+            verification_paths: Vec::new(),
+            cross_verifications: Vec::new(),
+            memory_pressure: None,
+            invalid_input: None,
+            soak: None,
+            runtime: None,
+            proof_cost: None,
+            baseline: None,
+            failures: Vec::new(),
+            step_error: None,
+            environment: None,
+            low_confidence: false,
+            caveats: Vec::new(),
+            repeats: None,
+            third_party: None,
         }];
 
         let collected = CollectedBenchmarks {
@@ -405,9 +1024,16 @@ mod tests {
                 commit_sha: None,
                 workflow_run_url: None,
                 artifact_urls: None,
+                manifest: None,
+                input_hashes: BTreeMap::new(),
             },
             systems,
             measurements,
+            scaling: Vec::new(),
+            security: SecurityReport {
+                buckets: BTreeMap::new(),
+                cross_bucket_comparisons: Vec::new(),
+            },
         };
 
         let json_str = serde_json::to_string_pretty(&collected).unwrap();
@@ -441,10 +1067,172 @@ mod tests {
         assert!(measurements[0].get("cycles").is_none());
     }
 
+    #[test]
+    fn test_scaling_trends_per_system_and_target() {
+        let measurement = |input_size: usize| Measurement {
+            system: "binius64".to_string(),
+            target: "sha256".to_string(),
+            input_size,
+            corpus: None,
+            proof_duration: Duration::from_micros(10 * input_size as u64),
+            verify_duration: Duration::from_micros(1),
+            cycles: None,
+            cycle_breakdown: None,
+            proof_size: 1024,
+            proof_size_breakdown: None,
+            preprocessing_size: 2048,
+            compressed_size: None,
+            proof_entropy: None,
+            num_constraints: 5000,
+            circuit_size: None,
+            witness_size: None,
+            peak_memory: 0,
+            peak_virtual_memory: None,
+            peak_swap: None,
+            peak_gpu_memory: None,
+            phase_peak_memory: None,
+            callgrind: None,
+            perf_counters: None,
+            prove_phases: None,
+            cold_start: None,
+            light_client_verify: None,
+            verify_batch: None,
+            verification_paths: Vec::new(),
+            cross_verifications: Vec::new(),
+            memory_pressure: None,
+            invalid_input: None,
+            soak: None,
+            runtime: None,
+            proof_cost: None,
+            baseline: None,
+            failures: Vec::new(),
+            step_error: None,
+            environment: None,
+            low_confidence: false,
+            caveats: Vec::new(),
+            repeats: None,
+            third_party: None,
+        };
+        let measurements: Vec<_> = [128, 256, 512].into_iter().map(measurement).collect();
+
+        let trends = scaling_trends(&measurements);
+        // Peak memory was not measured, so only proving time is fitted.
+        assert_eq!(trends.len(), 1);
+        assert_eq!(trends[0].metric, "proof_duration");
+        assert_eq!(trends[0].fit.model, scaling::ScalingModel::Linear);
+        assert!((trends[0].fit.exponent - 1.0).abs() < 1e-9);
+
+        assert!(scaling_trends(&measurements[..2]).is_empty());
+    }
+
+    #[test]
+    fn test_security_report_flags_cross_bucket_comparisons() {
+        let props = |security_bits| BenchProperties {
+            security_bits,
+            ..Default::default()
+        };
+        let systems = BTreeMap::from([
+            ("risc0".to_string(), props(96)),
+            ("jolt".to_string(), props(128)),
+            ("sp1".to_string(), props(100)),
+        ]);
+        let measurement = |system: &str, input_size| Measurement {
+            system: system.to_string(),
+            target: "sha256".to_string(),
+            input_size,
+            corpus: None,
+            proof_duration: Duration::from_millis(1),
+            verify_duration: Duration::from_millis(1),
+            cycles: None,
+            cycle_breakdown: None,
+            proof_size: 0,
+            proof_size_breakdown: None,
+            preprocessing_size: 0,
+            compressed_size: None,
+            proof_entropy: None,
+            num_constraints: 0,
+            circuit_size: None,
+            witness_size: None,
+            peak_memory: 0,
+            peak_virtual_memory: None,
+            peak_swap: None,
+            peak_gpu_memory: None,
+            phase_peak_memory: None,
+            callgrind: None,
+            perf_counters: None,
+            prove_phases: None,
+            cold_start: None,
+            light_client_verify: None,
+            verify_batch: None,
+            verification_paths: Vec::new(),
+            cross_verifications: Vec::new(),
+            memory_pressure: None,
+            invalid_input: None,
+            soak: None,
+            runtime: None,
+            proof_cost: None,
+            baseline: None,
+            failures: Vec::new(),
+            step_error: None,
+            environment: None,
+            low_confidence: false,
+            caveats: Vec::new(),
+            repeats: None,
+            third_party: None,
+        };
+        let measurements = vec![
+            measurement("risc0", 128),
+            measurement("jolt", 128),
+            measurement("jolt", 256),
+        ];
+
+        let report = security_report(&systems, &measurements);
+        assert_eq!(report.buckets["80-99"], vec!["risc0"]);
+        assert_eq!(report.buckets["100-127"], vec!["sp1"]);
+        assert_eq!(report.buckets[">=128"], vec!["jolt"]);
+
+        assert_eq!(report.cross_bucket_comparisons.len(), 1);
+        let comparison = &report.cross_bucket_comparisons[0];
+        assert_eq!(comparison.input_size, 128);
+        assert_eq!(comparison.buckets.len(), 2);
+
+        assert_eq!(security_bucket(0), "unknown");
+        assert_eq!(security_bucket(64), "<80");
+    }
+
+    #[test]
+    fn test_merge_runs_reports_means_and_spread() {
+        let run = |proof_ms, peak_memory| {
+            let mut m = Metrics::new(
+                "sp1".to_string(),
+                None,
+                "sha256".to_string(),
+                128,
+                BenchProperties::default(),
+            );
+            m.proof_duration = Duration::from_millis(proof_ms);
+            m.peak_memory = peak_memory;
+            m
+        };
+
+        let (single, repeats) = merge_runs(vec![run(100, 1000)]);
+        assert_eq!(single.proof_duration, Duration::from_millis(100));
+        assert!(repeats.is_none());
+
+        let (merged, repeats) = merge_runs(vec![run(100, 1000), run(120, 1200), run(110, 1100)]);
+        let repeats = repeats.unwrap();
+        assert_eq!(repeats.runs, 3);
+        assert_eq!(merged.proof_duration, Duration::from_millis(110));
+        assert_eq!(merged.peak_memory, 1100);
+        assert_eq!(repeats.proof_duration.min, 100e6);
+        assert_eq!(repeats.proof_duration.max, 120e6);
+        assert!((repeats.peak_memory.stddev - 100.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_metadata_from_env() {
         // Without env vars set, metadata fields should be None
-        let metadata = build_metadata();
+        let metadata = build_metadata(None);
         // Cannot guarantee env vars are unset, but the function should not panic
         let json = serde_json::to_string(&metadata).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -455,5 +1243,72 @@ mod tests {
         if metadata.workflow_run_url.is_none() {
             assert!(parsed.get("workflow_run_url").is_none());
         }
+        assert!(parsed.get("manifest").is_none());
+
+        let manifest = RunManifest {
+            run_id: "ci".to_string(),
+            ..Default::default()
+        };
+        let parsed = serde_json::to_value(build_metadata(Some(manifest))).unwrap();
+        assert_eq!(parsed["manifest"]["run_id"], "ci");
+    }
+
+    #[test]
+    fn test_ingested_measurements_are_tagged_and_replace_resubmissions() {
+        let submission = |proof_ms| {
+            let mut m = Metrics::new(
+                "sp1".to_string(),
+                None,
+                "sha256".to_string(),
+                128,
+                BenchProperties::default(),
+            );
+            m.input_hash = attestation::input_hash("sha256", 128);
+            m.proof_duration = Duration::from_millis(proof_ms);
+            m.verify_duration = Duration::from_millis(1);
+            m.peak_memory = 1000;
+            m
+        };
+        let mut dataset = Dataset {
+            metadata: build_metadata(None),
+            systems: BTreeMap::from([("sp1".to_string(), BenchProperties::default())]),
+            measurements: vec![measurement(submission(100), None)],
+        };
+        let third_party = ThirdParty {
+            contributor: "alice".to_string(),
+            ingested_at: "2026-01-01T00:00:00+00:00".to_string(),
+        };
+
+        for proof_ms in [200, 300] {
+            let rejections =
+                merge_submissions(&mut dataset, vec![submission(proof_ms)], &third_party);
+            assert!(rejections[0].is_empty());
+        }
+        // The CI measurement is kept next to the latest submission.
+        let durations: Vec<_> = dataset
+            .measurements
+            .iter()
+            .map(|m| (m.third_party.is_some(), m.proof_duration))
+            .collect();
+        assert_eq!(
+            durations,
+            [
+                (false, Duration::from_millis(100)),
+                (true, Duration::from_millis(300))
+            ]
+        );
+        assert_eq!(scaling_trends(&dataset.measurements).len(), 0);
+
+        let mut unhashed = submission(400);
+        unhashed.input_hash = None;
+        let rejections =
+            merge_submissions(&mut dataset, vec![submission(400), unhashed], &third_party);
+        assert!(rejections[0].is_empty());
+        assert_eq!(rejections[1], [ingest::Rejection::MissingInputHash]);
+        assert_eq!(dataset.measurements.len(), 2);
+        assert_eq!(
+            dataset.measurements[1].proof_duration,
+            Duration::from_millis(300)
+        );
     }
 }
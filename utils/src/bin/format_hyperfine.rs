@@ -6,8 +6,11 @@ use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
-use utils::bench::Metrics;
+use utils::BenchId;
+use utils::bench::{MemReport, Metrics};
+use utils::caveat;
 use utils::harness::BenchProperties;
+use utils::results;
 
 #[derive(clap::Args, Debug, Clone, Default)]
 struct BenchPropsArgs {
@@ -62,9 +65,13 @@ struct Cli {
 #[derive(Deserialize)]
 struct HyperfineRecord {
     mean: f64,
+    /// One entry per timed run.
+    #[serde(default)]
+    times: Vec<f64>,
 }
 
-/// Formats hyperfine + RAM outputs into Metrics JSON and cleans up
+/// Formats hyperfine + RAM outputs into Metrics JSON in the run directory (see
+/// [`utils::results`]) and cleans up
 fn main() -> std::io::Result<()> {
     let cli = Cli::parse();
 
@@ -82,6 +89,8 @@ fn main() -> std::io::Result<()> {
             .to_string()
     });
 
+    let writer = results::Writer::from_env();
+
     // hyperfine files have the form: hyperfine_<target>_<size>_prover_metrics.json
     let pattern = system_dir.join("hyperfine_*_*_prover_metrics.json");
     let pattern = pattern.to_string_lossy().into_owned();
@@ -116,9 +125,9 @@ fn main() -> std::io::Result<()> {
         let sizes_path = system_dir.join(format!("{target}_{input_size}_sizes.json"));
 
         // Parse hyperfine JSONs to extract mean seconds
-        let prover_mean_sec = read_hyperfine_mean_seconds(&prover_path)?;
+        let prover = read_hyperfine_record(&prover_path)?;
         println!("Reading prover time from {}", prover_path.display());
-        let verifier_mean_sec = read_hyperfine_mean_seconds(&verifier_path)?;
+        let verifier = read_hyperfine_record(&verifier_path)?;
         println!("Reading verifier time from {}", verifier_path.display());
 
         let feat = match cli.feature.as_deref() {
@@ -141,14 +150,23 @@ fn main() -> std::io::Result<()> {
             input_size,
             bench_properties,
         );
-        metrics.proof_duration = to_duration_ns(prover_mean_sec);
-        metrics.verify_duration = to_duration_ns(verifier_mean_sec);
+        metrics.proof_duration = to_duration_ns(prover.mean);
+        metrics.verify_duration = to_duration_ns(verifier.mean);
+        // `benchmark.sh --quick` times a single run.
+        metrics.low_confidence = prover.times.len() < 2 || verifier.times.len() < 2;
+        if let Ok(target) = target.parse() {
+            metrics.caveats = caveat::collect(target, &metrics.bench_properties);
+        }
+        metrics.environment = Some(utils::environment::capture());
 
         if mem_path.exists()
-            && let Ok(mem_bytes) = read_peak_memory_bytes(&mem_path)
+            && let Ok(report) = read_mem_report(&mem_path)
         {
             println!("Reading peak memory from {}", mem_path.display());
-            metrics.peak_memory = mem_bytes;
+            if let Some(failure) = &report.failure {
+                eprintln!("Prover crashed: {}", failure);
+            }
+            report.apply_to(&mut metrics);
         }
 
         if sizes_path.exists()
@@ -177,10 +195,10 @@ fn main() -> std::io::Result<()> {
             }
         }
 
-        let out_file = system_dir.join(format!(
-            "{target}_{input_size}_{proving_system}_metrics.json"
-        ));
-        utils::bench::write_json_metrics_file(out_file.to_str().unwrap(), &metrics);
+        let out_file =
+            writer.metrics_path(&BenchId::new(&target, input_size, &proving_system, None));
+        println!("Writing metrics to {}", out_file.display());
+        writer.write_json(&out_file, &metrics)?;
 
         // Cleanup originals
         let _ = fs::remove_file(&prover_path);
@@ -231,24 +249,18 @@ fn load_properties_json(path: &Path) -> std::io::Result<BenchProperties> {
     serde_json::from_str::<BenchProperties>(&s).map_err(|e| io_err(&e.to_string()))
 }
 
-fn read_hyperfine_mean_seconds(path: &Path) -> std::io::Result<f64> {
+fn read_hyperfine_record(path: &Path) -> std::io::Result<HyperfineRecord> {
     let v: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
     let results = v
         .get("results")
         .and_then(|r| r.as_array())
         .ok_or_else(|| io_err("missing results array"))?;
     let first = results.first().ok_or_else(|| io_err("results empty"))?;
-    let rec: HyperfineRecord =
-        serde_json::from_value(first.clone()).map_err(|_| io_err("invalid hyperfine record"))?;
-    Ok(rec.mean)
+    serde_json::from_value(first.clone()).map_err(|_| io_err("invalid hyperfine record"))
 }
 
-fn read_peak_memory_bytes(path: &Path) -> std::io::Result<usize> {
-    let v: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
-    v.get("peak_memory")
-        .and_then(|m| m.as_u64())
-        .map(|n| n as usize)
-        .ok_or_else(|| io_err("missing peak_memory"))
+fn read_mem_report(path: &Path) -> std::io::Result<MemReport> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
 }
 
 fn read_sizes_bytes(path: &Path) -> std::io::Result<(usize, usize)> {
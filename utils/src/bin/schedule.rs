@@ -0,0 +1,93 @@
+use chrono::Utc;
+use clap::Parser;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+use utils::matrix;
+use utils::schedule::{self, SCHEDULE_ENV};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Plan a run of the bench matrix that fits a time budget", long_about = None)]
+struct Cli {
+    /// Earlier collected_benchmarks.json files, in any order; the latest measurement of each
+    /// (system, target, size) counts
+    history: Vec<PathBuf>,
+
+    /// Total time budget of the run, in minutes
+    #[arg(long)]
+    budget_mins: f64,
+
+    /// JSON object with the current upstream version of each system, e.g. {"jolt": "0.3"};
+    /// systems whose version differs from their last measurement are scheduled first
+    #[arg(long)]
+    versions: Option<PathBuf>,
+
+    /// Estimate for a measurement that was never made, in minutes
+    #[arg(long, default_value_t = 15.0)]
+    default_mins: f64,
+
+    /// Where to write the plan
+    #[arg(long, default_value = "../schedule_plan.json")]
+    output: PathBuf,
+
+    /// Also schedule benches the matrix marks as optional
+    #[arg(long)]
+    include_optional: bool,
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let matrix = matrix::load_matrix();
+    let declared: Vec<_> = matrix
+        .benches
+        .iter()
+        .filter(|bench| cli.include_optional || !bench.optional)
+        .cloned()
+        .collect();
+    let history = schedule::read_history(&cli.history)?;
+    let versions: BTreeMap<String, String> = match &cli.versions {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::other(format!("{}: {}", path.display(), e)))?,
+        None => BTreeMap::new(),
+    };
+
+    let plan = schedule::plan(
+        &declared,
+        &matrix,
+        &history,
+        &versions,
+        Duration::from_secs_f64(cli.budget_mins * 60.0),
+        Duration::from_secs_f64(cli.default_mins * 60.0),
+        Utc::now(),
+    );
+    fs::write(&cli.output, serde_json::to_string_pretty(&plan)?)?;
+
+    println!(
+        "Selected {} of {} measurements, {:.0} of {:.0} minutes",
+        plan.selected.len(),
+        plan.selected.len() + plan.deferred.len(),
+        plan.estimated_secs / 60.0,
+        plan.budget_secs / 60.0
+    );
+    for bench in plan.benches() {
+        let feature = bench
+            .feature
+            .as_deref()
+            .map(|f| format!(" [{f}]"))
+            .unwrap_or_default();
+        println!(
+            "  {} {}{}: {:?}",
+            bench.system,
+            bench.target,
+            feature,
+            bench.sizes.unwrap_or_default()
+        );
+    }
+    println!(
+        "Run these benches with {SCHEDULE_ENV}={} to measure only the selected sizes.",
+        cli.output.display()
+    );
+    Ok(())
+}
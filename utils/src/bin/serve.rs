@@ -0,0 +1,284 @@
+use clap::Parser;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Serve a collected_benchmarks.json over read-only HTTP endpoints (/systems, /measurements, /compare)", long_about = None)]
+struct Cli {
+    /// Collected results
+    #[arg(long, default_value = "../collected_benchmarks.json")]
+    collected: PathBuf,
+
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    addr: String,
+}
+
+/// `collect_benchmarks`' output, with the systems and measurements kept as they were written so
+/// that every field is served.
+#[derive(Deserialize)]
+struct Collected {
+    systems: BTreeMap<String, Value>,
+    measurements: Vec<Value>,
+}
+
+/// Measurement fields compared by `/compare`, as the ratio of the first system to the second.
+const COMPARED_FIELDS: &[&str] = &[
+    "proof_duration",
+    "verify_duration",
+    "proof_size",
+    "preprocessing_size",
+    "peak_memory",
+];
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let contents = fs::read_to_string(&cli.collected)?;
+    let collected: Collected = serde_json::from_str(&contents)
+        .map_err(|e| io::Error::other(format!("{}: {}", cli.collected.display(), e)))?;
+    let collected = Arc::new(collected);
+
+    let listener = TcpListener::bind(&cli.addr)?;
+    println!(
+        "Serving {} measurements of {} systems from {} on http://{}",
+        collected.measurements.len(),
+        collected.systems.len(),
+        cli.collected.display(),
+        listener.local_addr()?
+    );
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Failed to accept a connection: {e}");
+                continue;
+            }
+        };
+        let collected = Arc::clone(&collected);
+        thread::spawn(move || {
+            if let Err(e) = handle(stream, &collected) {
+                eprintln!("Failed to answer a request: {e}");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Answers the one request on `stream`; the connection is closed after the response.
+fn handle(stream: TcpStream, collected: &Collected) -> io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // The headers are not used, but are read so the client is not reset mid-request.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let (status, body) = match request_line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["GET", target, _] => route(collected, target),
+        [_, _, _] => (405, json!({ "error": "only GET is supported" })),
+        _ => (400, json!({ "error": "malformed request line" })),
+    };
+    let body = serde_json::to_string(&body)?;
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Method Not Allowed",
+    };
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nAccess-Control-Allow-Origin: *\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// The status and JSON body of the endpoint `target` (path and query string).
+fn route(collected: &Collected, target: &str) -> (u16, Value) {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let params = query_params(query);
+    let param = |name: &str| params.get(name).map(String::as_str);
+    match path.trim_end_matches('/') {
+        "/systems" => (200, json!(collected.systems)),
+        "/measurements" => {
+            let size = match param("size").map(str::parse::<usize>).transpose() {
+                Ok(size) => size,
+                Err(_) => return (400, json!({ "error": "size is not an integer" })),
+            };
+            let measurements: Vec<&Value> = collected
+                .measurements
+                .iter()
+                .filter(|m| matches(m, param("system"), param("target"), size))
+                .collect();
+            (200, json!(measurements))
+        }
+        "/compare" => match (param("a"), param("b")) {
+            (Some(a), Some(b)) => (200, compare(collected, a, b, param("target"))),
+            _ => (
+                400,
+                json!({ "error": "both systems to compare are required, as a and b" }),
+            ),
+        },
+        _ => (
+            404,
+            json!({ "error": format!("no endpoint {path}; try /systems, /measurements or /compare") }),
+        ),
+    }
+}
+
+/// Whether `m` is of `system`, `target` and input `size`, each where given.
+fn matches(m: &Value, system: Option<&str>, target: Option<&str>, size: Option<usize>) -> bool {
+    system.is_none_or(|system| m["system"] == system)
+        && target.is_none_or(|target| m["target"] == target)
+        && size.is_none_or(|size| m["input_size"] == size)
+}
+
+/// The measurements of systems `a` and `b` on the same target and input size, with the ratio
+/// of `a` to `b` of each of the [`COMPARED_FIELDS`].
+fn compare(collected: &Collected, a: &str, b: &str, target: Option<&str>) -> Value {
+    let pairs: Vec<Value> = collected
+        .measurements
+        .iter()
+        .filter(|m| matches(m, Some(a), target, None))
+        .filter_map(|ma| {
+            let mb = collected.measurements.iter().find(|mb| {
+                matches(mb, Some(b), ma["target"].as_str(), None)
+                    && mb["input_size"] == ma["input_size"]
+            })?;
+            let ratios: BTreeMap<&str, f64> = COMPARED_FIELDS
+                .iter()
+                .filter_map(|&field| {
+                    let (va, vb) = (ma[field].as_f64()?, mb[field].as_f64()?);
+                    (vb != 0.0).then_some((field, va / vb))
+                })
+                .collect();
+            Some(json!({
+                "target": ma["target"],
+                "input_size": ma["input_size"],
+                "a": ma,
+                "b": mb,
+                "ratio": ratios,
+            }))
+        })
+        .collect();
+    json!({ "a": a, "b": b, "pairs": pairs })
+}
+
+/// The `name=value` pairs of a query string, percent-decoded.
+fn query_params(query: &str) -> BTreeMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(name), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match (bytes[i], bytes.get(i + 1..i + 3).and_then(hex_byte)) {
+            (b'+', _) => decoded.push(b' '),
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 2;
+            }
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_byte(hex: &[u8]) -> Option<u8> {
+    u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collected() -> Collected {
+        let measurement = |system: &str, target: &str, input_size: usize, proof_duration: u64| {
+            json!({
+                "system": system,
+                "target": target,
+                "input_size": input_size,
+                "proof_duration": proof_duration,
+                "proof_size": 1000,
+            })
+        };
+        Collected {
+            systems: BTreeMap::from([
+                ("jolt".to_string(), json!({ "proving_system": "Jolt" })),
+                (
+                    "risc0".to_string(),
+                    json!({ "proving_system": "RISC Zero" }),
+                ),
+            ]),
+            measurements: vec![
+                measurement("jolt", "keccak", 1024, 2_000),
+                measurement("jolt", "keccak", 2048, 4_000),
+                measurement("risc0", "keccak", 2048, 8_000),
+                measurement("risc0", "sha256", 2048, 6_000),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_measurements_are_filtered_by_the_query() {
+        let collected = collected();
+        let (status, body) = route(&collected, "/measurements?target=keccak&size=2048");
+        assert_eq!(status, 200);
+        let systems: Vec<&Value> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| &m["system"])
+            .collect();
+        assert_eq!(systems, ["jolt", "risc0"]);
+
+        assert_eq!(route(&collected, "/measurements?size=big").0, 400);
+        assert_eq!(route(&collected, "/results").0, 404);
+        assert_eq!(
+            route(&collected, "/systems").1["risc0"]["proving_system"],
+            "RISC Zero"
+        );
+    }
+
+    #[test]
+    fn test_compare_pairs_the_same_target_and_size() {
+        let (status, body) = route(&collected(), "/compare?a=jolt&b=risc0");
+        assert_eq!(status, 200);
+        let pairs = body["pairs"].as_array().unwrap();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0]["target"], "keccak");
+        assert_eq!(pairs[0]["input_size"], 2048);
+        assert_eq!(pairs[0]["ratio"]["proof_duration"], 0.5);
+        assert_eq!(pairs[0]["ratio"]["proof_size"], 1.0);
+
+        assert_eq!(route(&collected(), "/compare?a=jolt").0, 400);
+    }
+
+    #[test]
+    fn test_query_values_are_percent_decoded() {
+        let params = query_params("a=jolt%5Fgpu&b=risc0+keccak&c");
+        assert_eq!(params["a"], "jolt_gpu");
+        assert_eq!(params["b"], "risc0 keccak");
+        assert_eq!(params["c"], "");
+    }
+}
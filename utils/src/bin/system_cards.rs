@@ -0,0 +1,371 @@
+use clap::Parser;
+use human_repr::{HumanCount, HumanDuration};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use utils::caveat::Caveat;
+use utils::harness::{AuditStatus, BenchProperties};
+use utils::scaling::{ScalingFit, ScalingModel};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Render one JSON and Markdown card per proving system from a collected_benchmarks.json", long_about = None)]
+struct Cli {
+    /// Collected results
+    #[arg(long, default_value = "../collected_benchmarks.json")]
+    collected: PathBuf,
+
+    /// Directory the `<system>.json` and `<system>.md` cards are written to
+    #[arg(long, default_value = "../system_cards")]
+    out_dir: PathBuf,
+}
+
+/// The parts of `collect_benchmarks`' output the cards need.
+#[derive(Deserialize)]
+struct Collected {
+    metadata: Metadata,
+    systems: BTreeMap<String, BenchProperties>,
+    measurements: Vec<Measurement>,
+    #[serde(default)]
+    scaling: Vec<ScalingTrend>,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    timestamp: String,
+    commit_sha: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Measurement {
+    system: String,
+    target: String,
+    input_size: usize,
+    proof_duration: u64,
+    verify_duration: u64,
+    cycles: Option<u64>,
+    proof_size: usize,
+    preprocessing_size: usize,
+    num_constraints: usize,
+    peak_memory: usize,
+    #[serde(default)]
+    failures: Vec<serde_json::Value>,
+    #[serde(default)]
+    caveats: Vec<Caveat>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct ScalingTrend {
+    system: String,
+    target: String,
+    metric: String,
+    #[serde(flatten)]
+    fit: ScalingFit,
+}
+
+/// Properties and measurements of one system, as written to `<system>.json`.
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct SystemCard<'a> {
+    /// System key of the collected results, including the feature tag (`jolt_gpu`).
+    system: &'a str,
+    properties: &'a BenchProperties,
+    /// Time of the collection the card was generated from.
+    collected_at: &'a str,
+    commit_sha: Option<&'a str>,
+    targets: Vec<TargetCard>,
+}
+
+#[derive(Serialize)]
+struct TargetCard {
+    target: String,
+    /// One entry per measured input size, in increasing order.
+    sizes: Vec<SizeCard>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    scaling: Vec<ScalingTrend>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct SizeCard {
+    input_size: usize,
+    /// Mean proving time in nanoseconds.
+    proof_duration: u64,
+    /// Mean verification time in nanoseconds.
+    verify_duration: u64,
+    proof_size: usize,
+    preprocessing_size: usize,
+    peak_memory: usize,
+    num_constraints: usize,
+    cycles: Option<u64>,
+    /// The measurement recorded a prover failure, so some fields may be missing or zero.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    failed: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    caveats: Vec<Caveat>,
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let contents = fs::read_to_string(&cli.collected)?;
+    let collected: Collected = serde_json::from_str(&contents)
+        .map_err(|e| io::Error::other(format!("{}: {}", cli.collected.display(), e)))?;
+
+    fs::create_dir_all(&cli.out_dir)?;
+    for card in cards(&collected) {
+        write_card(&cli.out_dir, &card)?;
+    }
+    println!(
+        "Wrote {} system card(s) to {}",
+        collected.systems.len(),
+        cli.out_dir.display()
+    );
+    Ok(())
+}
+
+fn write_card(dir: &Path, card: &SystemCard) -> io::Result<()> {
+    fs::write(
+        dir.join(format!("{}.json", card.system)),
+        serde_json::to_string_pretty(card)?,
+    )?;
+    fs::write(dir.join(format!("{}.md", card.system)), markdown(card))
+}
+
+/// One card per system of `collected`, with its measurements grouped by target.
+fn cards(collected: &Collected) -> Vec<SystemCard<'_>> {
+    collected
+        .systems
+        .iter()
+        .map(|(system, properties)| {
+            let mut targets: BTreeMap<&str, Vec<SizeCard>> = BTreeMap::new();
+            for m in collected
+                .measurements
+                .iter()
+                .filter(|m| &m.system == system)
+            {
+                targets.entry(&m.target).or_default().push(SizeCard {
+                    input_size: m.input_size,
+                    proof_duration: m.proof_duration,
+                    verify_duration: m.verify_duration,
+                    proof_size: m.proof_size,
+                    preprocessing_size: m.preprocessing_size,
+                    peak_memory: m.peak_memory,
+                    num_constraints: m.num_constraints,
+                    cycles: m.cycles,
+                    failed: !m.failures.is_empty(),
+                    caveats: m.caveats.clone(),
+                });
+            }
+            SystemCard {
+                system,
+                properties,
+                collected_at: &collected.metadata.timestamp,
+                commit_sha: collected.metadata.commit_sha.as_deref(),
+                targets: targets
+                    .into_iter()
+                    .map(|(target, mut sizes)| {
+                        sizes.sort_by_key(|size| size.input_size);
+                        TargetCard {
+                            target: target.to_string(),
+                            sizes,
+                            scaling: collected
+                                .scaling
+                                .iter()
+                                .filter(|t| &t.system == system && t.target == target)
+                                .cloned()
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn markdown(card: &SystemCard) -> String {
+    let p = card.properties;
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let mut rows = vec![
+        ("Field / curve", p.field_curve.to_string()),
+        ("IOP", p.iop.to_string()),
+        ("PCS", p.pcs.as_deref().unwrap_or("n/a").to_string()),
+        ("Arithmetization", p.arithm.to_string()),
+        ("Zero-knowledge", yes_no(p.is_zk).to_string()),
+        (
+            "zkVM",
+            match &p.isa {
+                Some(isa) if p.is_zkvm => format!("yes ({isa})"),
+                _ => yes_no(p.is_zkvm).to_string(),
+            },
+        ),
+        ("Security", format!("{} bits", p.security_bits)),
+        ("Post-quantum", yes_no(p.is_pq).to_string()),
+        ("Maintained", yes_no(p.is_maintained).to_string()),
+        (
+            "Audited",
+            match p.is_audited {
+                AuditStatus::Audited => "yes",
+                AuditStatus::PartiallyAudited => "partially",
+                AuditStatus::NotAudited => "no",
+            }
+            .to_string(),
+        ),
+    ];
+    if let Some(resource) = &p.prover_resource {
+        rows.push(("Prover resource", resource.to_string()));
+    }
+    if let Some(version) = &p.version {
+        rows.push(("Version", version.to_string()));
+    }
+    if let Some(chain) = &p.onchain_verifier {
+        rows.push(("On-chain verifier", chain.to_string()));
+    }
+
+    let mut md = format!("# {} (`{}`)\n\n", p.proving_system, card.system);
+    md.push_str("| Property | Value |\n| --- | --- |\n");
+    for (property, value) in rows {
+        let _ = writeln!(md, "| {property} | {value} |");
+    }
+    for target in &card.targets {
+        let _ = write!(
+            md,
+            "\n## {}\n\n| Input size | Prove | Verify | Proof size | Preprocessing | Peak memory |\n| ---: | ---: | ---: | ---: | ---: | ---: |\n",
+            target.target
+        );
+        // Caveats are numbered per target and referenced from the input size column.
+        let mut notes: Vec<&Caveat> = Vec::new();
+        for size in &target.sizes {
+            let mut input_size = size.input_size.to_string();
+            for caveat in &size.caveats {
+                let index = match notes.iter().position(|note| *note == caveat) {
+                    Some(index) => index,
+                    None => {
+                        notes.push(caveat);
+                        notes.len() - 1
+                    }
+                };
+                let _ = write!(input_size, " [{}]", index + 1);
+            }
+            if size.failed {
+                let _ = writeln!(md, "| {input_size} | failed | | | | |");
+                continue;
+            }
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} | {} | {} | {} |",
+                input_size,
+                duration(size.proof_duration),
+                duration(size.verify_duration),
+                bytes(size.proof_size),
+                bytes(size.preprocessing_size),
+                bytes(size.peak_memory),
+            );
+        }
+        if !notes.is_empty() {
+            md.push('\n');
+            for (index, caveat) in notes.iter().enumerate() {
+                let _ = writeln!(md, "{}. {caveat}", index + 1);
+            }
+        }
+        if let Some(trend) = target.scaling.iter().find(|t| t.metric == "proof_duration") {
+            let model = match trend.fit.model {
+                ScalingModel::Linear => "linear",
+                ScalingModel::NLogN => "n log n",
+                ScalingModel::Quadratic => "quadratic",
+            };
+            let _ = writeln!(
+                md,
+                "\nProving time grows as n^{:.2} (best fit: {model}, R² {:.2}).",
+                trend.fit.exponent, trend.fit.r_squared
+            );
+        }
+    }
+    let _ = write!(md, "\nCollected {}", card.collected_at);
+    if let Some(sha) = card.commit_sha {
+        let _ = write!(md, " at commit `{sha}`");
+    }
+    md.push_str(".\n");
+    md
+}
+
+fn duration(nanos: u64) -> String {
+    Duration::from_nanos(nanos).human_duration().to_string()
+}
+
+fn bytes(bytes: usize) -> String {
+    bytes.human_count_bytes().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn measurement(input_size: usize, failed: bool) -> Measurement {
+        Measurement {
+            system: "plonky2".to_string(),
+            target: "sha256".to_string(),
+            input_size,
+            proof_duration: 1_500_000_000,
+            verify_duration: 3_250_000,
+            cycles: None,
+            proof_size: 130 * 1024,
+            preprocessing_size: 512,
+            num_constraints: 1 << 16,
+            peak_memory: 3 << 30,
+            failures: if failed {
+                vec![serde_json::json!({ "stage": "prove" })]
+            } else {
+                Vec::new()
+            },
+            caveats: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_card_groups_measurements_by_target() {
+        let collected = Collected {
+            metadata: Metadata {
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+                commit_sha: Some("abc123".to_string()),
+            },
+            systems: BTreeMap::from([(
+                "plonky2".to_string(),
+                BenchProperties {
+                    proving_system: Cow::Borrowed("Plonky2"),
+                    version: Some(Cow::Borrowed("1.0.2")),
+                    ..Default::default()
+                },
+            )]),
+            measurements: vec![
+                measurement(2048, true),
+                Measurement {
+                    caveats: vec![Caveat::DigestBakedIn],
+                    ..measurement(128, false)
+                },
+            ],
+            scaling: Vec::new(),
+        };
+
+        let cards = cards(&collected);
+        assert_eq!(cards.len(), 1);
+        let sizes: Vec<_> = cards[0].targets[0]
+            .sizes
+            .iter()
+            .map(|size| (size.input_size, size.failed))
+            .collect();
+        assert_eq!(sizes, [(128, false), (2048, true)]);
+
+        let md = markdown(&cards[0]);
+        assert!(md.starts_with("# Plonky2 (`plonky2`)\n"));
+        assert!(md.contains("| Version | 1.0.2 |"));
+        assert!(md.contains("| 128 [1] | 1.5s | 3.3ms | 130KB | 512B | 3GB |"));
+        assert!(md.contains("\n1. digest baked into the circuit\n"));
+        assert!(md.contains("| 2048 | failed | | | | |"));
+        assert!(md.ends_with("Collected 2026-01-01T00:00:00Z at commit `abc123`.\n"));
+    }
+}
@@ -0,0 +1,73 @@
+use clap::Parser;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use utils::attestation::{self, ResultSignature};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Check the signature and input hashes of a collected_benchmarks.json", long_about = None)]
+struct Cli {
+    /// Collected results
+    results: PathBuf,
+
+    /// Detached signature (defaults to `<results>.sig`)
+    #[arg(long)]
+    signature: Option<PathBuf>,
+
+    /// Hex-encoded public key the results must be signed with
+    #[arg(long)]
+    public_key: Option<String>,
+}
+
+/// The parts of `collect_benchmarks`' output the check needs.
+#[derive(Deserialize)]
+struct Collected {
+    metadata: Metadata,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    input_hashes: BTreeMap<String, String>,
+}
+
+fn main() -> io::Result<()> {
+    let cli = Cli::parse();
+    let results = fs::read(&cli.results)?;
+
+    let signature_path = cli.signature.unwrap_or_else(|| {
+        let mut path = cli.results.clone().into_os_string();
+        path.push(".");
+        path.push(attestation::SIGNATURE_EXTENSION);
+        path.into()
+    });
+    let signature: ResultSignature = serde_json::from_slice(&fs::read(&signature_path)?)?;
+    attestation::verify(&results, &signature, cli.public_key.as_deref())?;
+    println!("Signature OK (key {})", signature.public_key);
+
+    let collected: Collected = serde_json::from_slice(&results)?;
+    let mismatches: Vec<&String> = collected
+        .metadata
+        .input_hashes
+        .iter()
+        .filter(|(key, hash)| {
+            let expected = key
+                .rsplit_once('/')
+                .and_then(|(target, size)| attestation::input_hash(target, size.parse().ok()?));
+            expected.as_ref() != Some(*hash)
+        })
+        .map(|(key, _)| key)
+        .collect();
+    if !mismatches.is_empty() {
+        return Err(io::Error::other(format!(
+            "inputs differ from the ones this version generates: {mismatches:?}"
+        )));
+    }
+    println!(
+        "Input hashes OK ({} inputs)",
+        collected.metadata.input_hashes.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! BLAKE2s-256 (RFC 7693, unkeyed) for the `blake2s` target.
+//!
+//! The circuits hash the message in one call, so only the expected digest is computed here; the
+//! block loop mirrors ProveKit's `blake2s_native` Noir library.
+
+const IV: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+];
+
+/// Message word permutation of each of the 10 rounds.
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Parameter block word 0 of an unkeyed hash with a 32-byte digest: fanout and depth 1.
+const PARAMS: u32 = 0x0101_0000 | 32;
+
+const BLOCK_BYTES: usize = 64;
+
+/// BLAKE2s-256 digest of `message`.
+pub fn blake2s(message: &[u8]) -> [u8; 32] {
+    let mut h = IV;
+    h[0] ^= PARAMS;
+    let mut blocks: Vec<&[u8]> = message.chunks(BLOCK_BYTES).collect();
+    // The empty message is one all-zero block.
+    if blocks.is_empty() {
+        blocks.push(&[]);
+    }
+    let num_blocks = blocks.len();
+    for (index, block) in blocks.into_iter().enumerate() {
+        let mut bytes = [0u8; BLOCK_BYTES];
+        bytes[..block.len()].copy_from_slice(block);
+        let words = std::array::from_fn(|i| {
+            u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())
+        });
+        let last = index == num_blocks - 1;
+        let counter = if last {
+            message.len()
+        } else {
+            (index + 1) * BLOCK_BYTES
+        };
+        compress(&mut h, &words, counter as u64, last);
+    }
+
+    let mut digest = [0u8; 32];
+    for (chunk, word) in digest.chunks_mut(4).zip(h) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+fn compress(h: &mut [u32; 8], m: &[u32; 16], counter: u64, last: bool) {
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    v[12] ^= counter as u32;
+    v[13] ^= (counter >> 32) as u32;
+    if last {
+        v[14] = !v[14];
+    }
+
+    for s in &SIGMA {
+        g(&mut v, [0, 4, 8, 12], m[s[0]], m[s[1]]);
+        g(&mut v, [1, 5, 9, 13], m[s[2]], m[s[3]]);
+        g(&mut v, [2, 6, 10, 14], m[s[4]], m[s[5]]);
+        g(&mut v, [3, 7, 11, 15], m[s[6]], m[s[7]]);
+        g(&mut v, [0, 5, 10, 15], m[s[8]], m[s[9]]);
+        g(&mut v, [1, 6, 11, 12], m[s[10]], m[s[11]]);
+        g(&mut v, [2, 7, 8, 13], m[s[12]], m[s[13]]);
+        g(&mut v, [3, 4, 9, 14], m[s[14]], m[s[15]]);
+    }
+    for i in 0..8 {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+/// The G mixing function on the state words `[a, b, c, d]`.
+fn g(v: &mut [u32; 16], [a, b, c, d]: [usize; 4], x: u32, y: u32) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(12);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(8);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake2s_vectors() {
+        // RFC 7693 Appendix B, and the empty message.
+        assert_eq!(
+            hex::encode(blake2s(b"abc")),
+            "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+        );
+        assert_eq!(
+            hex::encode(blake2s(b"")),
+            "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+        );
+        // Block boundaries: a full last block is not followed by an empty one.
+        let message: Vec<u8> = (0..=255).collect();
+        assert_eq!(
+            hex::encode(blake2s(&message[..64])),
+            "56f34e8b96557e90c1f24b52d0c89d51086acf1b00f634cf1dde9233b8eaaa3e"
+        );
+        assert_eq!(
+            hex::encode(blake2s(&message[..65])),
+            "1b53ee94aaf34e4b159d48de352c7f0661d0a40edff95a0b1639b4090e974472"
+        );
+    }
+}
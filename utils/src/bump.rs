@@ -0,0 +1,180 @@
+//! Performance deltas of an upstream version bump.
+//!
+//! [`report`] compares two `collect_benchmarks` outputs per system and measurement, labelled with
+//! the upstream versions the systems declare (`BenchProperties::version`). The `bump_report`
+//! binary prints it for two collected files; `bump_dependency` collects both sides itself by
+//! benchmarking a scratch worktree before and after bumping a dependency.
+
+use crate::harness::BenchProperties;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The parts of `collect_benchmarks`' output the report needs.
+#[derive(Deserialize)]
+pub struct Collected {
+    pub systems: BTreeMap<String, BenchProperties>,
+    pub measurements: Vec<Measurement>,
+}
+
+#[derive(Deserialize)]
+pub struct Measurement {
+    pub system: String,
+    pub target: String,
+    pub input_size: usize,
+    pub proof_duration: u64,
+    pub verify_duration: u64,
+    pub proof_size: usize,
+    pub peak_memory: usize,
+}
+
+type Metric = (&'static str, fn(&Measurement) -> f64);
+
+/// Compared metrics and their labels in the report, in order.
+const METRICS: [Metric; 4] = [
+    ("prove", |m| m.proof_duration as f64),
+    ("verify", |m| m.verify_duration as f64),
+    ("proof", |m| m.proof_size as f64),
+    ("memory", |m| m.peak_memory as f64),
+];
+
+pub fn read_collected(path: &Path) -> io::Result<Collected> {
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents)
+        .map_err(|e| io::Error::other(format!("{}: {}", path.display(), e)))
+}
+
+/// One line per (system, target, input size) measured in both files with a change of at least
+/// `threshold` percent, e.g. `jolt 0.2→0.3: keccak_2048 prove −18%, proof +4%`.
+pub fn report(old: &Collected, new: &Collected, threshold: f64, bumped_only: bool) -> Vec<String> {
+    let old_measurements: BTreeMap<_, _> = old
+        .measurements
+        .iter()
+        .map(|m| ((m.system.as_str(), m.target.as_str(), m.input_size), m))
+        .collect();
+    let mut new_measurements: Vec<&Measurement> = new.measurements.iter().collect();
+    new_measurements.sort_by_key(|m| (&m.system, &m.target, m.input_size));
+
+    let mut lines = Vec::new();
+    for m in new_measurements {
+        let Some(before) =
+            old_measurements.get(&(m.system.as_str(), m.target.as_str(), m.input_size))
+        else {
+            continue;
+        };
+        let old_version = version(old, &m.system);
+        let new_version = version(new, &m.system);
+        if bumped_only && old_version == new_version {
+            continue;
+        }
+
+        let changes: Vec<String> = METRICS
+            .iter()
+            .filter_map(|(label, extract)| {
+                let (before, after) = (extract(before), extract(m));
+                if before == 0.0 || after == 0.0 {
+                    return None;
+                }
+                let percent = (after / before - 1.0) * 100.0;
+                (percent.abs() >= threshold && percent.round() != 0.0)
+                    .then(|| format!("{label} {}", signed(percent)))
+            })
+            .collect();
+        if changes.is_empty() {
+            continue;
+        }
+
+        let versions = match (old_version, new_version) {
+            (Some(old), Some(new)) if old != new => format!(" {old}→{new}"),
+            (None, Some(new)) => format!(" ?→{new}"),
+            (Some(old), None) => format!(" {old}→?"),
+            (Some(version), Some(_)) => format!(" {version}"),
+            (None, None) => String::new(),
+        };
+        lines.push(format!(
+            "{}{}: {}_{} {}",
+            m.system,
+            versions,
+            m.target,
+            m.input_size,
+            changes.join(", ")
+        ));
+    }
+    lines
+}
+
+fn version<'a>(collected: &'a Collected, system: &str) -> Option<&'a str> {
+    collected.systems.get(system)?.version.as_deref()
+}
+
+/// Rounded percentage with an explicit sign; decreases use a minus sign (−).
+fn signed(percent: f64) -> String {
+    let rounded = percent.round() as i64;
+    if rounded < 0 {
+        format!("\u{2212}{}%", -rounded)
+    } else {
+        format!("+{rounded}%")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn collected(version: &'static str, measurements: Vec<Measurement>) -> Collected {
+        let props = BenchProperties {
+            version: Some(Cow::Borrowed(version)),
+            ..Default::default()
+        };
+        Collected {
+            systems: BTreeMap::from([("jolt".to_string(), props)]),
+            measurements,
+        }
+    }
+
+    fn measurement(target: &str, proof_duration: u64, proof_size: usize) -> Measurement {
+        Measurement {
+            system: "jolt".to_string(),
+            target: target.to_string(),
+            input_size: 2048,
+            proof_duration,
+            verify_duration: 1000,
+            proof_size,
+            peak_memory: 0,
+        }
+    }
+
+    #[test]
+    fn test_report_lists_changes_above_threshold() {
+        let old = collected(
+            "0.2",
+            vec![
+                measurement("keccak", 1000, 100),
+                measurement("sha256", 1000, 100),
+            ],
+        );
+        let new = collected(
+            "0.3",
+            vec![
+                measurement("keccak", 820, 104),
+                measurement("sha256", 1010, 100),
+                measurement("ecdsa", 10, 10),
+            ],
+        );
+        assert_eq!(
+            report(&old, &new, 2.0, false),
+            ["jolt 0.2→0.3: keccak_2048 prove −18%, proof +4%"]
+        );
+        assert!(report(&old, &old, 0.0, false).is_empty());
+
+        let unbumped = collected("0.2", vec![measurement("keccak", 820, 104)]);
+        assert!(report(&old, &unbumped, 2.0, true).is_empty());
+        assert_eq!(
+            report(&old, &unbumped, 2.0, false),
+            ["jolt 0.2: keccak_2048 prove −18%, proof +4%"]
+        );
+    }
+}
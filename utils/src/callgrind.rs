@@ -0,0 +1,178 @@
+use crate::bench_id::BenchId;
+use crate::harness::{BenchTarget, ProvingSystem};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// iai-callgrind only writes `summary.json` files when this is set to `json` (or `pretty-json`).
+pub const SAVE_SUMMARY_ENV: &str = "IAI_CALLGRIND_SAVE_SUMMARY";
+
+/// File under `OUT_DIR` that [`write_library_benches`] writes the `callgrind_benches!` macro to.
+pub const BENCHES_FILE: &str = "callgrind_benches.rs";
+
+/// Deterministic counts reported by an iai-callgrind run of the prove step.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CallgrindCounts {
+    /// Executed instructions (`Ir`).
+    pub instructions: Option<u64>,
+    pub l1_hits: Option<u64>,
+    pub ll_hits: Option<u64>,
+    pub ram_hits: Option<u64>,
+    pub total_rw: Option<u64>,
+    pub estimated_cycles: Option<u64>,
+}
+
+/// Writes the `callgrind_benches!` macro to `$OUT_DIR/`[`BENCHES_FILE`], for the build script
+/// of a crate with an iai-callgrind bench. iai-callgrind only takes its benches from attributes,
+/// so the sizes `BENCH_INPUT_PROFILE` selects for `target` are fixed when the bench is built.
+pub fn write_library_benches(
+    target: BenchTarget,
+    system: ProvingSystem,
+    feature: Option<&str>,
+    setup: &str,
+) -> io::Result<()> {
+    println!("cargo:rerun-if-env-changed=BENCH_INPUT_PROFILE");
+    let out_dir = std::env::var_os("OUT_DIR")
+        .ok_or_else(|| io::Error::other("OUT_DIR is not set, run from a build script"))?;
+    let sizes = crate::input_sizes_for(target);
+    fs::write(
+        Path::new(&out_dir).join(BENCHES_FILE),
+        library_benches(target, system, feature, setup, &sizes),
+    )
+}
+
+/// A macro that marks the function it wraps as a `#[library_benchmark]` with one `#[bench]` per
+/// size, named with the [`BenchId`] of its Criterion group and prepared by `setup(size)`.
+fn library_benches(
+    target: BenchTarget,
+    system: ProvingSystem,
+    feature: Option<&str>,
+    setup: &str,
+    sizes: &[usize],
+) -> String {
+    let benches: String = sizes
+        .iter()
+        .map(|&size| {
+            let id = BenchId::new(target.as_str(), size, system.as_str(), feature);
+            format!("        #[bench::{id}(args = ({size}), setup = {setup})]\n")
+        })
+        .collect();
+    format!(
+        "macro_rules! callgrind_benches {{\n    ($($item:tt)*) => {{\n        #[library_benchmark]\n{benches}        $($item)*\n    }};\n}}\n"
+    )
+}
+
+/// Locates the iai-callgrind summary of the benchmark whose id equals `group_id`
+/// (`{target}_{size}_{system}[_feat]`, the same id Criterion uses).
+///
+/// iai-callgrind stores summaries under `<target>/iai/<package>/<bench>/<group>/<function>.<id>/`.
+pub fn find_summary(target_dir: &Path, group_id: &str) -> Option<PathBuf> {
+    let pattern = target_dir
+        .join("iai")
+        .join("**")
+        .join(format!("*.{}", group_id))
+        .join("summary.json");
+    glob::glob(pattern.to_str()?).ok()?.flatten().next()
+}
+
+/// Reads the event counts of an iai-callgrind `summary.json`.
+pub fn read_summary(path: &Path) -> io::Result<CallgrindCounts> {
+    let summary: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(CallgrindCounts {
+        instructions: find_event(&summary, "Ir"),
+        l1_hits: find_event(&summary, "L1hits"),
+        ll_hits: find_event(&summary, "LLhits"),
+        ram_hits: find_event(&summary, "RamHits"),
+        total_rw: find_event(&summary, "TotalRW"),
+        estimated_cycles: find_event(&summary, "EstimatedCycles"),
+    })
+}
+
+/// Returns the current value of the first occurrence of `event`. iai-callgrind reports an event
+/// as `{"metrics": ..., "diffs": ...}`, with `metrics` holding the new value (`{"Left": n}`), the
+/// new and the old one (`{"Both": [new, old]}`) or only the old one (`{"Right": n}`), and each
+/// value either a number or `{"Int": n}`.
+fn find_event(value: &Value, event: &str) -> Option<u64> {
+    match value {
+        Value::Object(map) => map
+            .get(event)
+            .and_then(current_metric)
+            .or_else(|| map.values().find_map(|v| find_event(v, event))),
+        Value::Array(items) => items.iter().find_map(|v| find_event(v, event)),
+        _ => None,
+    }
+}
+
+fn current_metric(event: &Value) -> Option<u64> {
+    let metrics = event.get("metrics")?;
+    let new = metrics
+        .get("Left")
+        .or_else(|| metrics.get("Both")?.get(0))?;
+    match new {
+        Value::Number(n) => n.as_u64(),
+        metric => metric.get("Int")?.as_u64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_find_event_in_nested_summary() {
+        let summary = json!({
+            "function_name": "prove",
+            "callgrind_summary": {
+                "callgrind_run": {
+                    "segments": [{
+                        "events": {
+                            "Ir": { "metrics": { "Both": [1200, 1100] }, "diffs": null },
+                            "EstimatedCycles": { "metrics": { "Left": 5000 } }
+                        }
+                    }]
+                }
+            }
+        });
+        assert_eq!(find_event(&summary, "Ir"), Some(1200));
+        assert_eq!(find_event(&summary, "EstimatedCycles"), Some(5000));
+        assert_eq!(find_event(&summary, "RamHits"), None);
+    }
+
+    #[test]
+    fn test_find_event_reads_the_new_metric() {
+        let summary = json!({
+            "events": {
+                "Ir": {
+                    "diffs": { "diff_pct": 9, "factor": 1 },
+                    "metrics": { "Both": [{ "Int": 1200 }, { "Int": 1100 }] }
+                },
+                "L1hits": { "metrics": { "Right": 700 } }
+            }
+        });
+        assert_eq!(find_event(&summary, "Ir"), Some(1200));
+        assert_eq!(find_event(&summary, "L1hits"), None);
+    }
+
+    #[test]
+    fn test_library_benches_name_sizes_with_their_bench_ids() {
+        let benches = library_benches(
+            BenchTarget::Sha256,
+            ProvingSystem::Plonky2,
+            None,
+            "sha256_prepare",
+            &[128, 256],
+        );
+        assert!(benches.starts_with("macro_rules! callgrind_benches {"));
+        assert!(
+            benches.contains("#[bench::sha256_128_plonky2(args = (128), setup = sha256_prepare)]")
+        );
+        assert!(
+            benches.contains("#[bench::sha256_256_plonky2(args = (256), setup = sha256_prepare)]")
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Machine-readable caveats attached to a measurement.
+//!
+//! Some numbers are not directly comparable with the rest of their target: the circuit checks a
+//! digest it was compiled with, the input is fixed whatever the input size, or the system runs
+//! below the 128-bit security the others are configured for. Instead of keeping those remarks in
+//! README footnotes, systems attach them to their [`BenchProperties`] with
+//! [`BenchProperties::with_caveat`], the harness adds the ones it can derive itself (see
+//! [`derived`]) and both end up in `Metrics::caveats`, so comparison tables can mark the affected
+//! rows.
+
+use crate::harness::{BenchProperties, BenchTarget};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+
+/// Security level the comparison tables assume; systems below it get a
+/// [`Caveat::ReducedSecurity`].
+pub const BASELINE_SECURITY_BITS: u64 = 128;
+
+/// Size of the digest an ECDSA signature is verified over; message hashing is not part of the
+/// `ecdsa` target.
+const ECDSA_DIGEST_BYTES: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Caveat {
+    /// The expected digest is a constant of the circuit rather than a public input, so the
+    /// circuit is rebuilt for every input and its proofs only attest to that one.
+    DigestBakedIn,
+    /// The statement takes an input of this many bytes whatever the input size.
+    FixedInput { bytes: usize },
+    /// Soundness below [`BASELINE_SECURITY_BITS`].
+    ReducedSecurity { bits: u64 },
+    /// Anything the other variants do not cover.
+    Note { text: Cow<'static, str> },
+}
+
+impl fmt::Display for Caveat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Caveat::DigestBakedIn => write!(f, "digest baked into the circuit"),
+            Caveat::FixedInput { bytes } => write!(f, "fixed {bytes}-byte input"),
+            Caveat::ReducedSecurity { bits } => write!(
+                f,
+                "security level {bits} bits, not {BASELINE_SECURITY_BITS}"
+            ),
+            Caveat::Note { text } => write!(f, "{text}"),
+        }
+    }
+}
+
+/// Caveats that follow from the target and the system's properties alone.
+pub fn derived(target: BenchTarget, properties: &BenchProperties) -> Vec<Caveat> {
+    let mut caveats = Vec::new();
+    if matches!(target, BenchTarget::Ecdsa) {
+        caveats.push(Caveat::FixedInput {
+            bytes: ECDSA_DIGEST_BYTES,
+        });
+    }
+    // Zero means the system did not record its security level.
+    if (1..BASELINE_SECURITY_BITS).contains(&properties.security_bits) {
+        caveats.push(Caveat::ReducedSecurity {
+            bits: properties.security_bits,
+        });
+    }
+    caveats
+}
+
+/// Caveats of a measurement: the system's own, then the derived ones it does not already state.
+pub fn collect(target: BenchTarget, properties: &BenchProperties) -> Vec<Caveat> {
+    let mut caveats = properties.caveats.to_vec();
+    for caveat in derived(target, properties) {
+        if !caveats.contains(&caveat) {
+            caveats.push(caveat);
+        }
+    }
+    caveats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_merges_stated_and_derived_caveats() {
+        let properties = BenchProperties {
+            security_bits: 96,
+            ..Default::default()
+        }
+        .with_caveat(Caveat::DigestBakedIn);
+        assert_eq!(
+            collect(BenchTarget::Sha256, &properties),
+            [Caveat::DigestBakedIn, Caveat::ReducedSecurity { bits: 96 }]
+        );
+
+        let properties = BenchProperties {
+            security_bits: 128,
+            ..Default::default()
+        }
+        .with_caveat(Caveat::FixedInput { bytes: 32 });
+        assert_eq!(
+            collect(BenchTarget::Ecdsa, &properties),
+            [Caveat::FixedInput { bytes: 32 }]
+        );
+        assert_eq!(
+            collect(BenchTarget::Keccak, &properties),
+            [Caveat::FixedInput { bytes: 32 }]
+        );
+    }
+
+    #[test]
+    fn test_caveats_serialize_with_a_kind_tag() {
+        let json = serde_json::to_value([
+            Caveat::ReducedSecurity { bits: 96 },
+            Caveat::Note {
+                text: Cow::Borrowed("no message hashing"),
+            },
+        ])
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "kind": "reduced_security", "bits": 96 },
+                { "kind": "note", "text": "no message hashing" },
+            ])
+        );
+        assert_eq!(
+            Caveat::ReducedSecurity { bits: 96 }.to_string(),
+            "security level 96 bits, not 128"
+        );
+    }
+}
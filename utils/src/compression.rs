@@ -0,0 +1,96 @@
+//! Transmitted (compressed) sizes of the serialized proof, verification key and preprocessing.
+//!
+//! `proof_size` and `preprocessing_size` are in-memory or raw serialized sizes, but what crosses
+//! the network is usually compressed, and compressibility differs widely between systems (field
+//! elements and hashes are incompressible, padded or sparse encodings are not). The metrics pass
+//! compresses the artifacts a suite exports (see [`crate::artifacts`]) and the preprocessing it
+//! serializes (see [`BenchmarkSuite::preprocessing_blob`]) with zstd and records the compressed
+//! sizes.
+//!
+//! [`BenchmarkSuite::preprocessing_blob`]: crate::harness::BenchmarkSuite::preprocessing_blob
+
+use crate::artifacts::ExportedArtifacts;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// zstd's default level, which is what transports typically use.
+pub const ZSTD_LEVEL: i32 = zstd::DEFAULT_COMPRESSION_LEVEL;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CompressedSizes {
+    pub zstd_level: i32,
+    /// Serialized proof before and after compression; `None` if the suite exports no artifacts.
+    pub proof: Option<usize>,
+    pub compressed_proof: Option<usize>,
+    /// Serialized verification key before and after compression; `None` if the suite exports
+    /// none.
+    pub verification_key: Option<usize>,
+    pub compressed_verification_key: Option<usize>,
+    /// Serialized preprocessing before and after compression; `None` if the suite does not
+    /// serialize it.
+    pub preprocessing: Option<usize>,
+    pub compressed_preprocessing: Option<usize>,
+}
+
+impl CompressedSizes {
+    /// Compresses the exported proof and verification key and the serialized preprocessing,
+    /// whichever the suite provides. `None` if it provides neither.
+    pub fn measure(
+        artifacts: Option<&ExportedArtifacts>,
+        preprocessing: Option<&[u8]>,
+    ) -> Option<Self> {
+        if artifacts.is_none() && preprocessing.is_none() {
+            return None;
+        }
+        let vk = artifacts
+            .map(|artifacts| artifacts.verification_key.as_slice())
+            .filter(|vk| !vk.is_empty());
+        Some(Self {
+            zstd_level: ZSTD_LEVEL,
+            proof: artifacts.map(|artifacts| artifacts.proof.len()),
+            compressed_proof: artifacts.map(|artifacts| compressed_len(&artifacts.proof)),
+            verification_key: vk.map(<[u8]>::len),
+            compressed_verification_key: vk.map(compressed_len),
+            preprocessing: preprocessing.map(<[u8]>::len),
+            compressed_preprocessing: preprocessing.map(compressed_len),
+        })
+    }
+}
+
+fn compressed_len(bytes: &[u8]) -> usize {
+    zstd::bulk::compress(bytes, ZSTD_LEVEL)
+        .expect("zstd compression of an in-memory buffer failed")
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compressed_sizes_reflect_redundancy() {
+        let artifacts = ExportedArtifacts {
+            proof: vec![7; 4096],
+            verification_key: Vec::new(),
+            public_inputs: Vec::new(),
+            encoding: "test",
+        };
+        let sizes = CompressedSizes::measure(Some(&artifacts), None).unwrap();
+        assert_eq!(sizes.proof, Some(4096));
+        assert!(sizes.compressed_proof.unwrap() < 100);
+        assert_eq!(sizes.verification_key, None);
+        assert_eq!(sizes.compressed_verification_key, None);
+        assert_eq!(sizes.preprocessing, None);
+    }
+
+    #[test]
+    fn test_compressed_sizes_of_preprocessing_without_artifacts() {
+        let preprocessing = vec![0; 1 << 16];
+        let sizes = CompressedSizes::measure(None, Some(&preprocessing)).unwrap();
+        assert_eq!(sizes.proof, None);
+        assert_eq!(sizes.preprocessing, Some(1 << 16));
+        assert!(sizes.compressed_preprocessing.unwrap() < 100);
+        assert_eq!(CompressedSizes::measure(None, None), None);
+    }
+}
@@ -0,0 +1,152 @@
+//! Real-world benchmark inputs.
+//!
+//! Hash benchmarks prove uniformly random messages by default. With [`CORPUS_ENV`] set, the
+//! message is built from a committed sample instead: Ethereum calldata, an email, a certificate
+//! chain. Samples live in `utils/corpus/<corpus>/<sha256>.bin`, named by the SHA-256 of their
+//! contents, so a sample is selected (and checked) by its hash and a corpus ID such as
+//! `email/dd9b99b74841a89c` identifies the exact bytes a measurement proved.
+
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// `<corpus>` or `<corpus>/<hash prefix>`; without a prefix, the sample with the lowest hash.
+pub const CORPUS_ENV: &str = "BENCH_CORPUS";
+
+/// Hex digits of the sample hash kept in the corpus ID.
+const ID_HASH_LEN: usize = 16;
+
+const SAMPLE_EXTENSION: &str = "bin";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub corpus: String,
+    /// Hex SHA-256 of `bytes`.
+    pub hash: String,
+    pub bytes: Vec<u8>,
+}
+
+impl Sample {
+    /// Corpus ID recorded with the measurement: `<corpus>/<hash prefix>`.
+    pub fn id(&self) -> String {
+        format!("{}/{}", self.corpus, &self.hash[..ID_HASH_LEN])
+    }
+
+    /// A message of exactly `input_size` bytes: the sample, repeated as often as needed.
+    pub fn message(&self, input_size: usize) -> Vec<u8> {
+        self.bytes
+            .iter()
+            .copied()
+            .cycle()
+            .take(input_size)
+            .collect()
+    }
+}
+
+/// Directory holding the committed corpora.
+pub fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus")
+}
+
+/// Hashes of the samples of `corpus`, sorted.
+pub fn list(dir: &Path, corpus: &str) -> io::Result<Vec<String>> {
+    let mut hashes: Vec<String> = fs::read_dir(dir.join(corpus))?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != SAMPLE_EXTENSION {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    hashes.sort();
+    Ok(hashes)
+}
+
+/// Loads the sample of `corpus` whose hash starts with `hash_prefix` (the first one if `None`),
+/// checking that its contents match its hash.
+pub fn load(dir: &Path, corpus: &str, hash_prefix: Option<&str>) -> io::Result<Sample> {
+    let hashes = list(dir, corpus)?;
+    let mut matching = hashes
+        .iter()
+        .filter(|hash| hash.starts_with(hash_prefix.unwrap_or_default()));
+    let hash = match (matching.next(), matching.next()) {
+        (Some(hash), None) => hash,
+        (Some(hash), Some(_)) if hash_prefix.is_none() => hash,
+        (Some(_), Some(_)) => {
+            return Err(io::Error::other(format!(
+                "ambiguous sample hash prefix {} in corpus {corpus}",
+                hash_prefix.unwrap_or_default()
+            )));
+        }
+        (None, _) => {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no matching sample in corpus {corpus}"),
+            ));
+        }
+    };
+
+    let path = dir.join(corpus).join(hash).with_extension(SAMPLE_EXTENSION);
+    let bytes = fs::read(&path)?;
+    let actual = hex::encode(Sha256::digest(&bytes));
+    if &actual != hash {
+        return Err(io::Error::other(format!(
+            "{} does not match its hash ({actual})",
+            path.display()
+        )));
+    }
+    Ok(Sample {
+        corpus: corpus.to_string(),
+        hash: actual,
+        bytes,
+    })
+}
+
+/// The sample selected by [`CORPUS_ENV`], if any. Panics if the selection does not resolve,
+/// rather than silently falling back to random inputs.
+pub fn selected() -> Option<Sample> {
+    let selection = env::var(CORPUS_ENV).ok().filter(|s| !s.is_empty())?;
+    let (corpus, hash_prefix) = match selection.split_once('/') {
+        Some((corpus, prefix)) => (corpus, Some(prefix)),
+        None => (selection.as_str(), None),
+    };
+    Some(
+        load(&corpus_dir(), corpus, hash_prefix)
+            .unwrap_or_else(|e| panic!("Invalid {CORPUS_ENV}={selection}: {e}")),
+    )
+}
+
+/// Corpus ID of the selected sample, recorded in the metrics.
+pub fn selected_id() -> Option<String> {
+    selected().map(|sample| sample.id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_committed_samples_load_by_hash() {
+        let dir = corpus_dir();
+        for corpus in ["calldata", "email", "certchain"] {
+            let hashes = list(&dir, corpus).unwrap();
+            assert!(!hashes.is_empty(), "{corpus}");
+            for hash in &hashes {
+                let sample = load(&dir, corpus, Some(&hash[..8])).unwrap();
+                assert_eq!(&sample.hash, hash);
+            }
+        }
+
+        let email = load(&dir, "email", None).unwrap();
+        assert_eq!(email.id(), "email/dd9b99b74841a89c");
+        let message = email.message(email.bytes.len() * 2 + 3);
+        assert_eq!(&message[..email.bytes.len()], email.bytes.as_slice());
+        assert_eq!(&message[email.bytes.len() * 2..], &email.bytes[..3]);
+
+        assert!(load(&dir, "calldata", Some("ff")).is_err());
+        assert!(load(&dir, "no-such-corpus", None).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Monetary or credit cost of generating one proof on a hosted prover network.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProofCost {
+    pub amount: f64,
+    /// Currency or credit unit, e.g. `USD` or `PROVE`.
+    pub unit: String,
+    /// Where the number comes from: the provider API that billed the proof, or the price list
+    /// used for the estimate.
+    pub source: Option<String>,
+}
+
+/// Published price list of a hosted prover, configured per system in the bench matrix.
+/// Used to estimate [`ProofCost`] when the system cannot report the billed amount itself.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProverPricing {
+    /// Flat fee per proof request.
+    #[serde(default)]
+    pub per_proof: f64,
+    /// Price per million executed cycles (zkVMs).
+    #[serde(default)]
+    pub per_million_cycles: f64,
+    pub unit: String,
+    pub source: Option<String>,
+}
+
+impl ProverPricing {
+    /// Cost of a proof of `cycles` execution cycles. `None` if the price depends on cycles and
+    /// they were not reported.
+    pub fn estimate(&self, cycles: Option<u64>) -> Option<ProofCost> {
+        let cycle_cost = if self.per_million_cycles == 0.0 {
+            0.0
+        } else {
+            self.per_million_cycles * cycles? as f64 / 1_000_000.0
+        };
+        Some(ProofCost {
+            amount: self.per_proof + cycle_cost,
+            unit: self.unit.clone(),
+            source: self.source.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_from_pricing() {
+        let pricing = ProverPricing {
+            per_proof: 0.01,
+            per_million_cycles: 0.5,
+            unit: "USD".to_string(),
+            source: Some("price list".to_string()),
+        };
+        let cost = pricing.estimate(Some(4_000_000)).unwrap();
+        assert!((cost.amount - 2.01).abs() < 1e-9);
+        assert_eq!(cost.unit, "USD");
+        assert!(pricing.estimate(None).is_none());
+
+        let flat = ProverPricing {
+            per_proof: 1.0,
+            unit: "PROVE".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(flat.estimate(None).unwrap().amount, 1.0);
+    }
+}
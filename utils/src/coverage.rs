@@ -0,0 +1,246 @@
+//! Gaps between the benches declared in the bench matrix and the collected measurements.
+//!
+//! A failed CI job or a bench that was never wired up leaves no metrics file, so its
+//! combinations are simply absent from `collected_benchmarks.json`. `collect_benchmarks coverage`
+//! lists every declared (system, target, size) without a successful measurement instead, with the
+//! reason as far as the run manifest tells it.
+
+use crate::BenchId;
+use crate::manifest::RunManifest;
+use crate::matrix::DeclaredBench;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapReason {
+    /// The run manifest has no bench start for this size: the job did not run or the bench is
+    /// not wired up.
+    NotRun,
+    /// The bench ran (or the run has no manifest) but left no metrics.
+    NoMeasurement,
+    /// The measurement recorded a prover failure.
+    Failed,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize)]
+pub struct CoverageGap {
+    /// Measurement id, `{target}_{size}_{system}[_{feature}]`.
+    pub id: String,
+    pub system: String,
+    pub target: String,
+    pub feature: Option<String>,
+    pub input_size: usize,
+    pub reason: GapReason,
+    /// The bench is only run on request, so the gap may be expected.
+    pub optional: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CoverageReport {
+    /// Declared measurements.
+    pub declared: usize,
+    /// Declared measurements with a successful measurement.
+    pub covered: usize,
+    pub gaps: Vec<CoverageGap>,
+    /// Measurements of benches the matrix does not declare.
+    pub undeclared: Vec<String>,
+}
+
+impl CoverageReport {
+    /// Gaps of benches that are not optional.
+    pub fn required_gaps(&self) -> impl Iterator<Item = &CoverageGap> {
+        self.gaps.iter().filter(|gap| !gap.optional)
+    }
+}
+
+/// A collected measurement, as far as coverage is concerned.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CollectedMeasurement {
+    /// System key, including the feature tag (`jolt_gpu`).
+    pub system: String,
+    pub target: String,
+    pub input_size: usize,
+    #[serde(default)]
+    pub failures: Vec<serde_json::Value>,
+}
+
+impl CollectedMeasurement {
+    fn id(&self) -> String {
+        BenchId::new(&self.target, self.input_size, &self.system, None).to_string()
+    }
+}
+
+#[derive(Deserialize)]
+struct Collected {
+    metadata: CollectedMetadata,
+    measurements: Vec<CollectedEntry>,
+}
+
+#[derive(Deserialize)]
+struct CollectedEntry {
+    #[serde(flatten)]
+    measurement: CollectedMeasurement,
+    /// Set on measurements merged with `collect_benchmarks ingest`.
+    third_party: Option<serde::de::IgnoredAny>,
+}
+
+#[derive(Deserialize)]
+struct CollectedMetadata {
+    manifest: Option<RunManifest>,
+}
+
+/// Reads the run manifest and the measurements of a `collected_benchmarks.json`. Third-party
+/// measurements are left out: coverage is about what the run itself measured.
+pub fn read_collected(path: &Path) -> io::Result<(Option<RunManifest>, Vec<CollectedMeasurement>)> {
+    let collected: Collected = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let measurements = collected
+        .measurements
+        .into_iter()
+        .filter(|entry| entry.third_party.is_none())
+        .map(|entry| entry.measurement)
+        .collect();
+    Ok((collected.metadata.manifest, measurements))
+}
+
+/// Cross-references the `declared` benches with the `measurements` of a run and, if available,
+/// the benches its `manifest` records as started.
+pub fn coverage_report(
+    declared: &[DeclaredBench],
+    manifest: Option<&RunManifest>,
+    measurements: &[CollectedMeasurement],
+) -> CoverageReport {
+    let started: Option<BTreeSet<String>> = manifest.map(|manifest| {
+        manifest
+            .benches
+            .iter()
+            .flat_map(|bench| {
+                bench.sizes.iter().map(|&size| {
+                    BenchId::new(&bench.target, size, &bench.system, bench.feature.as_deref())
+                        .to_string()
+                })
+            })
+            .collect()
+    });
+    let measured: BTreeMap<String, bool> = measurements
+        .iter()
+        .map(|m| (m.id(), m.failures.is_empty()))
+        .collect();
+
+    let mut declared_ids = BTreeSet::new();
+    let mut gaps = Vec::new();
+    for bench in declared {
+        for id in bench.ids() {
+            let key = id.to_string();
+            let reason = match measured.get(&key) {
+                Some(true) => None,
+                Some(false) => Some(GapReason::Failed),
+                None if started
+                    .as_ref()
+                    .is_some_and(|started| !started.contains(&key)) =>
+                {
+                    Some(GapReason::NotRun)
+                }
+                None => Some(GapReason::NoMeasurement),
+            };
+            if let Some(reason) = reason {
+                gaps.push(CoverageGap {
+                    id: key.clone(),
+                    system: id.system,
+                    target: id.target,
+                    feature: id.feat,
+                    input_size: id.input_size,
+                    reason,
+                    optional: bench.optional,
+                });
+            }
+            declared_ids.insert(key);
+        }
+    }
+
+    CoverageReport {
+        declared: declared_ids.len(),
+        covered: declared_ids.len() - gaps.len(),
+        gaps,
+        undeclared: measured
+            .into_keys()
+            .filter(|id| !declared_ids.contains(id))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::BenchEntry;
+
+    fn measured(
+        system: &str,
+        target: &str,
+        input_size: usize,
+        failed: bool,
+    ) -> CollectedMeasurement {
+        CollectedMeasurement {
+            system: system.to_string(),
+            target: target.to_string(),
+            input_size,
+            failures: if failed {
+                vec![serde_json::json!({ "kind": "oom" })]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    #[test]
+    fn test_gaps_are_classified() {
+        let declared: Vec<DeclaredBench> = serde_json::from_str(
+            r#"[
+                { "system": "sp1", "target": "sha256", "sizes": [128, 256, 512] },
+                { "system": "jolt", "target": "sha256", "feature": "gpu", "sizes": [128], "optional": true }
+            ]"#,
+        )
+        .unwrap();
+        let manifest = RunManifest {
+            benches: vec![BenchEntry {
+                system: "sp1".to_string(),
+                target: "sha256".to_string(),
+                sizes: vec![128, 256],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let measurements = [
+            measured("sp1", "sha256", 128, false),
+            measured("sp1", "sha256", 256, true),
+            measured("risc0", "sha256", 128, false),
+        ];
+
+        let report = coverage_report(&declared, Some(&manifest), &measurements);
+        assert_eq!(report.declared, 4);
+        assert_eq!(report.covered, 1);
+        let reasons: Vec<_> = report
+            .gaps
+            .iter()
+            .map(|gap| (gap.id.as_str(), gap.reason))
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                ("sha256_256_sp1", GapReason::Failed),
+                ("sha256_512_sp1", GapReason::NotRun),
+                ("sha256_128_jolt_gpu", GapReason::NotRun),
+            ]
+        );
+        assert_eq!(report.required_gaps().count(), 2);
+        assert_eq!(report.undeclared, ["sha256_128_risc0"]);
+
+        let without_manifest = coverage_report(&declared, None, &measurements);
+        assert_eq!(without_manifest.gaps[1].reason, GapReason::NoMeasurement);
+    }
+}
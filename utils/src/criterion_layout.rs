@@ -0,0 +1,200 @@
+//! Locates Criterion results under `target/criterion`.
+//!
+//! Criterion stores every benchmark as `<group>/<function>/new/estimates.json`, but it sanitizes
+//! (and may truncate) the directory names, and the layout has shifted between releases. Instead of
+//! formatting the expected path, the tree is walked and each benchmark's ids are read from the
+//! `benchmark.json` written next to its estimates, falling back to the directory names.
+
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// One benchmark found under `target/criterion`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CriterionBench {
+    pub group_id: String,
+    pub function_id: Option<String>,
+    /// Path of the latest `estimates.json`.
+    pub estimates: PathBuf,
+}
+
+impl CriterionBench {
+    /// Path of the `estimates.json` saved under the named baseline `name`.
+    pub fn baseline_estimates(&self, name: &str) -> PathBuf {
+        let bench_dir = self
+            .estimates
+            .parent()
+            .and_then(Path::parent)
+            .expect("estimates.json is under <bench>/new");
+        bench_dir.join(name).join("estimates.json")
+    }
+}
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    function_id: Option<String>,
+}
+
+/// Walks `criterion_dir` and returns every benchmark with an `estimates.json` of the latest run.
+pub fn discover(criterion_dir: &Path) -> Vec<CriterionBench> {
+    let pattern = criterion_dir.join("**").join("new").join("estimates.json");
+    let Some(pattern) = pattern.to_str() else {
+        return Vec::new();
+    };
+    let Ok(paths) = glob::glob(pattern) else {
+        return Vec::new();
+    };
+    paths
+        .flatten()
+        .filter_map(|estimates| {
+            let new_dir = estimates.parent()?;
+            let (group_id, function_id) = read_benchmark_id(new_dir)
+                .or_else(|| ids_from_dirs(criterion_dir, new_dir.parent()?))?;
+            Some(CriterionBench {
+                group_id,
+                function_id,
+                estimates,
+            })
+        })
+        .collect()
+}
+
+/// Finds the `step` benchmark (`prove`, `verify`) of the group `group_id`.
+///
+/// Ids are compared after normalizing case and separators, and the function id may be either the
+/// bare step or `<group>_<step>` (as named by the harness), so feature suffixes spelled
+/// differently by the group and the function still match.
+pub fn find<'a>(
+    benches: &'a [CriterionBench],
+    group_id: &str,
+    step: &str,
+) -> Option<&'a CriterionBench> {
+    let group_id = normalize(group_id);
+    let step = normalize(step);
+    benches.iter().find(|bench| {
+        normalize(&bench.group_id) == group_id
+            && bench.function_id.as_deref().is_some_and(|function_id| {
+                let function_id = normalize(function_id);
+                function_id == step || function_id.ends_with(&format!("_{step}"))
+            })
+    })
+}
+
+/// Reads the mean point estimate of an `estimates.json`.
+pub fn read_mean(estimates: &Path) -> io::Result<Duration> {
+    let value: Value = serde_json::from_str(&fs::read_to_string(estimates)?)?;
+    value
+        .get("mean")
+        .and_then(|mean| mean.get("point_estimate"))
+        .and_then(Value::as_f64)
+        .map(|nanos| Duration::from_nanos(nanos.round() as u64))
+        .ok_or_else(|| io::Error::other("no mean.point_estimate"))
+}
+
+fn read_benchmark_id(new_dir: &Path) -> Option<(String, Option<String>)> {
+    let contents = fs::read_to_string(new_dir.join("benchmark.json")).ok()?;
+    let id: BenchmarkId = serde_json::from_str(&contents).ok()?;
+    Some((id.group_id, id.function_id))
+}
+
+/// Ids from `<group>/<function>/new`, or `<group>/new` for a benchmark without a function id.
+fn ids_from_dirs(criterion_dir: &Path, bench_dir: &Path) -> Option<(String, Option<String>)> {
+    let name = |dir: &Path| Some(dir.file_name()?.to_string_lossy().into_owned());
+    let parent = bench_dir.parent()?;
+    if parent == criterion_dir {
+        Some((name(bench_dir)?, None))
+    } else {
+        Some((name(parent)?, Some(name(bench_dir)?)))
+    }
+}
+
+fn normalize(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_bench(dir: &Path, benchmark_json: Option<&str>, mean: f64) {
+        let new_dir = dir.join("new");
+        fs::create_dir_all(&new_dir).unwrap();
+        fs::write(
+            new_dir.join("estimates.json"),
+            format!(r#"{{"mean": {{"point_estimate": {mean}}}}}"#),
+        )
+        .unwrap();
+        if let Some(benchmark_json) = benchmark_json {
+            fs::write(new_dir.join("benchmark.json"), benchmark_json).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_discover_and_find_in_fixture_tree() {
+        let root = std::env::temp_dir().join(format!("csp_criterion_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        // Criterion 0.5 layout with benchmark.json; the directory name is sanitized.
+        write_bench(
+            &root.join("sha256_128_sp1").join("sha256_128_sp1_prove"),
+            Some(r#"{"group_id":"sha256_128_sp1","function_id":"sha256_128_sp1_prove"}"#),
+            1500.4,
+        );
+        // Directory names only, with a feature suffix spelled with a dash in the group.
+        write_bench(
+            &root
+                .join("keccak_256_plonky2_no-lookup")
+                .join("keccak_256_plonky2_no_lookup_verify"),
+            None,
+            20.0,
+        );
+        // Stale `base` results are ignored.
+        let base = root.join("sha256_128_sp1/sha256_128_sp1_prove/base");
+        fs::create_dir_all(&base).unwrap();
+        fs::write(
+            base.join("estimates.json"),
+            r#"{"mean": {"point_estimate": 1.0}}"#,
+        )
+        .unwrap();
+
+        let benches = discover(&root);
+        assert_eq!(benches.len(), 2);
+
+        let prove = find(&benches, "sha256_128_sp1", "prove").unwrap();
+        assert_eq!(
+            read_mean(&prove.estimates).unwrap(),
+            Duration::from_nanos(1500)
+        );
+        assert!(find(&benches, "sha256_128_sp1", "verify").is_none());
+        assert!(find(&benches, "sha256_1280_sp1", "prove").is_none());
+
+        assert_eq!(
+            prove.baseline_estimates("before"),
+            root.join("sha256_128_sp1/sha256_128_sp1_prove/before/estimates.json")
+        );
+
+        let verify = find(&benches, "keccak_256_plonky2_no_lookup", "verify").unwrap();
+        assert_eq!(
+            verify.function_id.as_deref(),
+            Some("keccak_256_plonky2_no_lookup_verify")
+        );
+        assert_eq!(
+            read_mean(&verify.estimates).unwrap(),
+            Duration::from_nanos(20)
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
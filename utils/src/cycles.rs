@@ -0,0 +1,47 @@
+//! Split of zkVM execution cycles between the main execution and accelerators.
+//!
+//! zkVMs differ in how they account for precompiles: SP1 and Jolt count precompile rows in their
+//! cycle totals, while RISC Zero proves Keccak in separate coprocessor segments that its cycle
+//! count leaves out. A [`CycleBreakdown`] reports the accelerator work next to the main
+//! execution, so `cycles` can be compared knowing what each total covers.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Execution cycles returned by a bench's `execution_cycles` function: a plain `u64` total, or
+/// the total with its breakdown.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ExecutionCycles {
+    pub total: u64,
+    pub breakdown: Option<CycleBreakdown>,
+}
+
+impl From<u64> for ExecutionCycles {
+    fn from(total: u64) -> Self {
+        Self {
+            total,
+            breakdown: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CycleBreakdown {
+    /// Segments the main execution was split into.
+    pub main_segments: u64,
+    /// Cycles of the main execution spent in the guest program, without paging and padding.
+    pub user_cycles: Option<u64>,
+    /// Work proven apart from the main segments, by accelerator name (e.g. `keccak`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub accelerators: BTreeMap<String, AcceleratorUsage>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct AcceleratorUsage {
+    /// Accelerator invocations, e.g. Keccak-f permutations.
+    pub calls: u64,
+    /// Segments proven by the accelerator circuit.
+    pub segments: u64,
+    /// Rows of those segments (the sum of their padded sizes).
+    pub cycles: u64,
+}
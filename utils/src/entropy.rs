@@ -0,0 +1,112 @@
+//! Redundancy analysis of the serialized proof.
+//!
+//! A reported `proof_size` can include padding, repeated commitments or sparse encodings that
+//! carry no information, so two systems with the same raw size may transmit very different
+//! amounts of data. With [`PROOF_ENTROPY_ENV`] set, the metrics pass inspects the exported proof
+//! (see [`crate::artifacts`]) for repeated chunks and measures its byte entropy, and reports an
+//! entropy-adjusted size next to the raw one.
+
+use crate::artifacts::ExportedArtifacts;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::env;
+
+/// Set to `1` to analyze the exported proof of every measurement.
+pub const PROOF_ENTROPY_ENV: &str = "BENCH_PROOF_ENTROPY";
+
+/// Chunk size of the duplicate scan: one hash digest or 256-bit field element.
+pub const CHUNK_SIZE: usize = 32;
+
+/// Proofs whose entropy-adjusted size is below this fraction of the raw size are flagged.
+pub const FLAG_RATIO: f64 = 0.9;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProofEntropy {
+    /// Serialized proof size.
+    pub raw_size: usize,
+    /// Bytes in [`CHUNK_SIZE`]-aligned chunks that repeat an earlier chunk.
+    pub duplicate_bytes: usize,
+    /// Shannon entropy of the byte histogram of the remaining bytes, in bits per byte.
+    pub entropy_bits_per_byte: f64,
+    /// Remaining bytes times their entropy, in bytes.
+    pub effective_size: usize,
+    /// Whether `effective_size` is below [`FLAG_RATIO`] of `raw_size`.
+    pub flagged: bool,
+}
+
+impl ProofEntropy {
+    /// Analyzes the exported proof.
+    pub fn of(artifacts: &ExportedArtifacts) -> Self {
+        Self::analyze(&artifacts.proof)
+    }
+
+    pub fn analyze(proof: &[u8]) -> Self {
+        let mut seen = HashSet::new();
+        let mut unique = Vec::with_capacity(proof.len());
+        for chunk in proof.chunks(CHUNK_SIZE) {
+            // A short trailing chunk cannot repeat an aligned one, so it is always kept.
+            if chunk.len() < CHUNK_SIZE || seen.insert(chunk) {
+                unique.extend_from_slice(chunk);
+            }
+        }
+        let entropy = shannon_entropy(&unique);
+        let effective_size = (unique.len() as f64 * entropy / 8.0).ceil() as usize;
+        Self {
+            raw_size: proof.len(),
+            duplicate_bytes: proof.len() - unique.len(),
+            entropy_bits_per_byte: entropy,
+            effective_size,
+            flagged: (effective_size as f64) < FLAG_RATIO * proof.len() as f64,
+        }
+    }
+}
+
+pub fn enabled() -> bool {
+    matches!(
+        env::var(PROOF_ENTROPY_ENV).ok().as_deref(),
+        Some("1") | Some("true")
+    )
+}
+
+/// Order-0 Shannon entropy in bits per byte. Short inputs underestimate it (a 256-byte random
+/// proof measures about 7.2 bits), which [`FLAG_RATIO`] leaves room for.
+fn shannon_entropy(bytes: &[u8]) -> f64 {
+    if bytes.is_empty() {
+        return 0.0;
+    }
+    let mut counts = [0usize; 256];
+    for &byte in bytes {
+        counts[byte as usize] += 1;
+    }
+    let len = bytes.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count != 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{RngCore, SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_repeated_and_random_proofs() {
+        let mut random = vec![0u8; 4096];
+        StdRng::seed_from_u64(0).fill_bytes(&mut random);
+        let analysis = ProofEntropy::analyze(&random);
+        assert_eq!(analysis.duplicate_bytes, 0);
+        assert!(analysis.entropy_bits_per_byte > 7.9);
+        assert!(!analysis.flagged);
+
+        let repeated = random[..CHUNK_SIZE].repeat(128);
+        let analysis = ProofEntropy::analyze(&repeated);
+        assert_eq!(analysis.duplicate_bytes, 127 * CHUNK_SIZE);
+        assert!(analysis.effective_size <= CHUNK_SIZE);
+        assert!(analysis.flagged);
+    }
+}
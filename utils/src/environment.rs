@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Environment variable carrying the digest of the container image the benchmarks run in.
+/// Containers cannot observe their own image digest, so the runner has to pass it in.
+pub const CONTAINER_IMAGE_DIGEST_ENV: &str = "BENCH_CONTAINER_IMAGE_DIGEST";
+
+const CPU_VULNERABILITIES_DIR: &str = "/sys/devices/system/cpu/vulnerabilities";
+
+/// Snapshot of the runner state that affects cross-run comparability of measurements.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct EnvironmentFingerprint {
+    /// True if running inside a Docker/Podman container.
+    pub in_container: bool,
+    /// Container image digest, taken from `BENCH_CONTAINER_IMAGE_DIGEST`.
+    pub container_image_digest: Option<String>,
+    /// Nix shell kind (`pure`/`impure`) if running inside `nix develop`/`nix-shell`.
+    pub nix_shell: Option<String>,
+    /// Version of the GNU C library the binary is linked against (Linux only).
+    pub glibc_version: Option<String>,
+    /// CPU vulnerability mitigation status, keyed by vulnerability (Linux only).
+    #[serde(default)]
+    pub cpu_mitigations: BTreeMap<String, String>,
+    /// Salted hash identifying the machine, added when the results are anonymized for
+    /// publication (see [`crate::anonymize`]).
+    pub machine_fingerprint: Option<String>,
+}
+
+/// Captures the fingerprint of the current environment.
+pub fn capture() -> EnvironmentFingerprint {
+    EnvironmentFingerprint {
+        in_container: Path::new("/.dockerenv").exists() || Path::new("/run/.containerenv").exists(),
+        container_image_digest: non_empty_env(CONTAINER_IMAGE_DIGEST_ENV),
+        nix_shell: non_empty_env("IN_NIX_SHELL"),
+        glibc_version: glibc_version(),
+        cpu_mitigations: cpu_mitigations(Path::new(CPU_VULNERABILITIES_DIR)),
+        machine_fingerprint: None,
+    }
+}
+
+fn non_empty_env(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|s| !s.is_empty())
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn glibc_version() -> Option<String> {
+    // SAFETY: gnu_get_libc_version returns a pointer to a static NUL-terminated string.
+    let version = unsafe { std::ffi::CStr::from_ptr(libc::gnu_get_libc_version()) };
+    version.to_str().ok().map(str::to_string)
+}
+
+#[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+fn glibc_version() -> Option<String> {
+    None
+}
+
+/// Reads the kernel's per-vulnerability mitigation status files; empty if unavailable.
+fn cpu_mitigations(dir: &Path) -> BTreeMap<String, String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let status = fs::read_to_string(entry.path()).ok()?;
+            Some((
+                entry.file_name().to_string_lossy().into_owned(),
+                status.trim().to_string(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_mitigations_reads_status_files() {
+        let dir = std::env::temp_dir().join(format!("csp_vulns_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("spectre_v2"), "Mitigation: Retpolines\n").unwrap();
+        fs::write(dir.join("meltdown"), "Not affected\n").unwrap();
+
+        let mitigations = cpu_mitigations(&dir);
+        assert_eq!(mitigations.len(), 2);
+        assert_eq!(mitigations["spectre_v2"], "Mitigation: Retpolines");
+        assert_eq!(mitigations["meltdown"], "Not affected");
+        assert!(cpu_mitigations(&dir.join("missing")).is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fingerprint_serialization_skips_missing_fields() {
+        let json = serde_json::to_value(EnvironmentFingerprint::default()).unwrap();
+        assert_eq!(json["in_container"], false);
+        assert!(json.get("glibc_version").is_none());
+        assert!(json.get("container_image_digest").is_none());
+    }
+}
@@ -0,0 +1,83 @@
+//! Self-description of the bench feature tags a system crate offers.
+//!
+//! A feature tag (the `feature` of [`BenchHarnessConfig`](crate::BenchHarnessConfig)) selects a
+//! variant of a system's benchmark, e.g. `jolt_gpu` or `risc0_keccak_pure`, and is appended to the
+//! system name in the results. Crates that have tags list them as a `BENCH_FEATURES` constant and
+//! print it from a `describe_<system>` binary (see [`print_description`]), so the runner can build
+//! its matrix and the dashboard can label results without parsing READMEs.
+
+use crate::harness::{BenchTarget, ProvingSystem};
+use serde::{Serialize, Serializer};
+
+/// One feature tag of a system.
+#[derive(Clone, Debug, Serialize)]
+pub struct BenchFeature {
+    /// Tag as it appears in result names (`<system>_<tag>`).
+    pub tag: &'static str,
+    pub description: &'static str,
+    /// Targets the tag is benchmarked for.
+    #[serde(serialize_with = "serialize_targets")]
+    pub targets: &'static [BenchTarget],
+    /// Cargo feature the variant is built with, if any.
+    pub cargo_feature: Option<&'static str>,
+    /// Criterion bench target that runs the variant, if it is a separate one.
+    pub bench: Option<&'static str>,
+    /// Parameters the variant fixes, with explanations.
+    pub parameters: &'static [FeatureParameter],
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeatureParameter {
+    pub name: &'static str,
+    pub value: &'static str,
+    pub description: &'static str,
+}
+
+fn serialize_targets<S: Serializer>(targets: &[BenchTarget], s: S) -> Result<S::Ok, S::Error> {
+    s.collect_seq(targets.iter().map(BenchTarget::as_str))
+}
+
+/// What `describe_<system>` prints.
+#[derive(Clone, Debug, Serialize)]
+pub struct SystemDescription<'a> {
+    pub system: &'static str,
+    pub features: &'a [BenchFeature],
+}
+
+pub fn describe(system: ProvingSystem, features: &[BenchFeature]) -> SystemDescription<'_> {
+    SystemDescription {
+        system: system.as_str(),
+        features,
+    }
+}
+
+/// Prints the description of `system` as JSON on stdout; the body of a `describe_<system>` binary.
+pub fn print_description(system: ProvingSystem, features: &[BenchFeature]) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&describe(system, features))
+            .expect("Failed to serialize feature description")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_serialization() {
+        const FEATURES: &[BenchFeature] = &[BenchFeature {
+            tag: "gpu",
+            description: "Proves on the GPU.",
+            targets: &[BenchTarget::Sha256],
+            cargo_feature: Some("gpu"),
+            bench: None,
+            parameters: &[],
+        }];
+        let json = serde_json::to_value(describe(ProvingSystem::Jolt, FEATURES)).unwrap();
+        assert_eq!(json["system"], "jolt");
+        assert_eq!(json["features"][0]["tag"], "gpu");
+        assert_eq!(json["features"][0]["targets"][0], "sha256");
+        assert_eq!(json["features"][0]["bench"], serde_json::Value::Null);
+    }
+}
@@ -1,29 +1,105 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::marker::PhantomData;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-use crate::bench::{Metrics, compile_binary, run_measure_mem_script, write_json_metrics};
+use crate::BenchId;
+use crate::artifacts::{self, ExportedArtifacts};
+use crate::attestation;
+use crate::bench::perf::measure_perf_counters;
+use crate::bench::{
+    Metrics, compile_binary_with_features, crate_has_feature, mem, run_measure_mem_script,
+    write_json_metrics,
+};
+use crate::caveat::{self, Caveat};
+use crate::compression::CompressedSizes;
+use crate::corpus;
+use crate::cost::{ProofCost, ProverPricing};
+use crate::cycles::ExecutionCycles;
+use crate::entropy::{self, ProofEntropy};
+use crate::environment;
+use crate::manifest;
+use crate::matrix::{self, BenchMatrix, RuntimeConfig, SamplingConfig};
 use crate::metadata::{selected_byte_inputs, selected_field_element_inputs};
+use crate::proof_size::ProofSize;
+use crate::results;
+use crate::schedule;
+use crate::verification::{CrossVerification, VerificationPath};
+use crate::witness_size::{ConstraintCount, WitnessSize};
+use baseline::BaselineDelta;
+use circuit_size::CircuitSize;
 use criterion::{BatchSize, Criterion};
 
-const SAMPLE_SIZE: usize = 10;
+pub mod baseline;
+mod child;
+pub mod circuit_size;
+pub mod cold_start;
+#[cfg(feature = "divan")]
+pub mod divan;
+mod error;
+pub mod invalid_input;
+pub mod memory_pressure;
+pub mod quick;
+mod runtime;
+pub mod soak;
+pub mod spans;
+pub mod verify_batch;
+pub mod verify_budget;
+
+pub use error::{BenchError, StepError};
+
+pub(crate) const SAMPLE_SIZE: usize = 10;
 
 #[derive(Clone, Copy, Debug)]
 pub enum BenchTarget {
     Sha256,
     Ecdsa,
     Keccak,
+    /// BLAKE2s-256 of an `input_size`-byte message (see [`blake2s`](crate::blake2s)).
+    Blake2s,
     Poseidon,
     Poseidon2,
+    /// Poseidon over a byte stream of `input_size` bytes, absorbed in sponge mode rather than as
+    /// one fixed-arity call (see [`generate_poseidon_sponge_input`](crate::generate_poseidon_sponge_input)).
+    PoseidonSponge,
+    /// Ethereum receipt inclusion proof: Keccak MPT nodes, RLP decoding and log field extraction
+    /// in a block of `input_size` receipts (see [`receipt`](crate::receipt)).
+    ReceiptProof,
+    /// Batch of `input_size` leaf updates in a Poseidon Merkle tree, from the old to the new root
+    /// (see [`merkle`](crate::merkle)).
+    MerkleUpdate,
+    /// `input_size` secp256k1 signatures over one digest, checked against a two-thirds threshold
+    /// (see [`multisig`](crate::multisig)).
+    Multisig,
 }
 
 impl BenchTarget {
+    pub const ALL: [BenchTarget; 10] = [
+        BenchTarget::Sha256,
+        BenchTarget::Ecdsa,
+        BenchTarget::Keccak,
+        BenchTarget::Blake2s,
+        BenchTarget::Poseidon,
+        BenchTarget::Poseidon2,
+        BenchTarget::PoseidonSponge,
+        BenchTarget::ReceiptProof,
+        BenchTarget::MerkleUpdate,
+        BenchTarget::Multisig,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             BenchTarget::Sha256 => "sha256",
             BenchTarget::Ecdsa => "ecdsa",
             BenchTarget::Keccak => "keccak",
+            BenchTarget::Blake2s => "blake2s",
             BenchTarget::Poseidon => "poseidon",
             BenchTarget::Poseidon2 => "poseidon2",
+            BenchTarget::PoseidonSponge => "poseidon_sponge",
+            BenchTarget::ReceiptProof => "receipt_proof",
+            BenchTarget::MerkleUpdate => "merkle_update",
+            BenchTarget::Multisig => "multisig",
         }
     }
 }
@@ -36,8 +112,13 @@ impl FromStr for BenchTarget {
             "sha256" => Ok(BenchTarget::Sha256),
             "ecdsa" => Ok(BenchTarget::Ecdsa),
             "keccak" => Ok(BenchTarget::Keccak),
+            "blake2s" => Ok(BenchTarget::Blake2s),
             "poseidon" => Ok(BenchTarget::Poseidon),
             "poseidon2" => Ok(BenchTarget::Poseidon2),
+            "poseidon_sponge" => Ok(BenchTarget::PoseidonSponge),
+            "receipt_proof" => Ok(BenchTarget::ReceiptProof),
+            "merkle_update" => Ok(BenchTarget::MerkleUpdate),
+            "multisig" => Ok(BenchTarget::Multisig),
             _ => Err(format!("Invalid benchmark target: {}", s)),
         }
     }
@@ -53,16 +134,58 @@ pub enum ProvingSystem {
     Circom,
     Risc0,
     Sp1,
+    Pico,
+    Zisk,
     Jolt,
     Miden,
     CairoM,
     Nexus,
     Spartan2,
     RookieNumbers,
+    Stwo,
+    Halo2Axiom,
+    Plonky3,
+    Cairo,
+    Ligetron,
+    Gnark,
+    Nova,
+    HyperNova,
+    ArkGroth16,
+    Valida,
     // Extend as needed
 }
 
 impl ProvingSystem {
+    /// Every system, in declaration order.
+    pub const ALL: [ProvingSystem; 26] = [
+        ProvingSystem::Binius64,
+        ProvingSystem::Expander,
+        ProvingSystem::Plonky2,
+        ProvingSystem::OpenVM,
+        ProvingSystem::Provekit,
+        ProvingSystem::Circom,
+        ProvingSystem::Risc0,
+        ProvingSystem::Sp1,
+        ProvingSystem::Pico,
+        ProvingSystem::Zisk,
+        ProvingSystem::Jolt,
+        ProvingSystem::Miden,
+        ProvingSystem::CairoM,
+        ProvingSystem::Nexus,
+        ProvingSystem::Spartan2,
+        ProvingSystem::RookieNumbers,
+        ProvingSystem::Stwo,
+        ProvingSystem::Halo2Axiom,
+        ProvingSystem::Plonky3,
+        ProvingSystem::Cairo,
+        ProvingSystem::Ligetron,
+        ProvingSystem::Gnark,
+        ProvingSystem::Nova,
+        ProvingSystem::HyperNova,
+        ProvingSystem::ArkGroth16,
+        ProvingSystem::Valida,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             ProvingSystem::Binius64 => "binius64",
@@ -73,12 +196,66 @@ impl ProvingSystem {
             ProvingSystem::Circom => "circom",
             ProvingSystem::Risc0 => "risc0",
             ProvingSystem::Sp1 => "sp1",
+            ProvingSystem::Pico => "pico",
+            ProvingSystem::Zisk => "zisk",
             ProvingSystem::Jolt => "jolt",
             ProvingSystem::Miden => "miden",
             ProvingSystem::CairoM => "cairo-m",
             ProvingSystem::Nexus => "nexus",
             ProvingSystem::Spartan2 => "spartan2",
             ProvingSystem::RookieNumbers => "rookie-numbers",
+            ProvingSystem::Stwo => "stwo",
+            ProvingSystem::Halo2Axiom => "halo2-axiom",
+            ProvingSystem::Plonky3 => "plonky3",
+            ProvingSystem::Cairo => "cairo",
+            ProvingSystem::Ligetron => "ligetron",
+            ProvingSystem::Gnark => "gnark",
+            ProvingSystem::Nova => "nova",
+            ProvingSystem::HyperNova => "hypernova",
+            ProvingSystem::ArkGroth16 => "ark-groth16",
+            ProvingSystem::Valida => "valida",
+        }
+    }
+
+    /// Whether the bench reconfigures its PCS for the levels in
+    /// [`SECURITY_BITS_ENV`](crate::security::SECURITY_BITS_ENV).
+    pub fn configurable_security(&self) -> bool {
+        matches!(self, ProvingSystem::RookieNumbers)
+    }
+}
+
+impl FromStr for ProvingSystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<ProvingSystem, String> {
+        match s {
+            "binius64" => Ok(ProvingSystem::Binius64),
+            "expander" => Ok(ProvingSystem::Expander),
+            "plonky2" => Ok(ProvingSystem::Plonky2),
+            "openvm" => Ok(ProvingSystem::OpenVM),
+            "provekit" => Ok(ProvingSystem::Provekit),
+            "circom" => Ok(ProvingSystem::Circom),
+            "risc0" => Ok(ProvingSystem::Risc0),
+            "sp1" => Ok(ProvingSystem::Sp1),
+            "pico" => Ok(ProvingSystem::Pico),
+            "zisk" => Ok(ProvingSystem::Zisk),
+            "jolt" => Ok(ProvingSystem::Jolt),
+            "miden" => Ok(ProvingSystem::Miden),
+            "cairo-m" => Ok(ProvingSystem::CairoM),
+            "nexus" => Ok(ProvingSystem::Nexus),
+            "spartan2" => Ok(ProvingSystem::Spartan2),
+            "rookie-numbers" => Ok(ProvingSystem::RookieNumbers),
+            "stwo" => Ok(ProvingSystem::Stwo),
+            "halo2-axiom" => Ok(ProvingSystem::Halo2Axiom),
+            "plonky3" => Ok(ProvingSystem::Plonky3),
+            "cairo" => Ok(ProvingSystem::Cairo),
+            "ligetron" => Ok(ProvingSystem::Ligetron),
+            "gnark" => Ok(ProvingSystem::Gnark),
+            "nova" => Ok(ProvingSystem::Nova),
+            "hypernova" => Ok(ProvingSystem::HyperNova),
+            "ark-groth16" => Ok(ProvingSystem::ArkGroth16),
+            "valida" => Ok(ProvingSystem::Valida),
+            _ => Err(format!("Invalid proving system: {}", s)),
         }
     }
 }
@@ -142,6 +319,26 @@ pub struct BenchProperties {
 
     // zkVM specifics
     pub isa: Option<Cow<'static, str>>,
+
+    // Hardware
+    /// Prover resource (`cpu`, `gpu`) for systems benched on more than one; `None` if unrecorded.
+    pub prover_resource: Option<Cow<'static, str>>,
+
+    // Upstream
+    /// Release or pinned revision of the prover, so results can be compared across version bumps
+    /// (see the `bump_report` tool).
+    pub version: Option<Cow<'static, str>>,
+
+    // Deployment
+    /// Chain whose contracts can verify the proofs (`evm`, `starknet`), possibly through a
+    /// wrapper proof; `None` if there is no on-chain verifier.
+    pub onchain_verifier: Option<Cow<'static, str>>,
+
+    // Caveats
+    /// Caveats of every measurement taken with these properties (see [`caveat`]). Recorded with
+    /// each measurement in `Metrics::caveats` rather than with the system.
+    #[serde(skip)]
+    pub caveats: Cow<'static, [Caveat]>,
 }
 
 impl BenchProperties {
@@ -188,8 +385,32 @@ impl BenchProperties {
             is_maintained,
             is_audited,
             isa: isa.map(Cow::Borrowed),
+            prover_resource: None,
+            version: None,
+            onchain_verifier: None,
+            caveats: Cow::Borrowed(&[]),
         }
     }
+
+    pub fn with_prover_resource(mut self, prover_resource: &'static str) -> Self {
+        self.prover_resource = Some(Cow::Borrowed(prover_resource));
+        self
+    }
+
+    pub fn with_version(mut self, version: &'static str) -> Self {
+        self.version = Some(Cow::Borrowed(version));
+        self
+    }
+
+    pub fn with_onchain_verifier(mut self, chain: &'static str) -> Self {
+        self.onchain_verifier = Some(Cow::Borrowed(chain));
+        self
+    }
+
+    pub fn with_caveat(mut self, caveat: Caveat) -> Self {
+        self.caveats.to_mut().push(caveat);
+        self
+    }
 }
 
 impl Default for BenchProperties {
@@ -207,147 +428,869 @@ impl Default for BenchProperties {
             is_maintained: false,
             is_audited: AuditStatus::NotAudited,
             isa: None,
+            prover_resource: None,
+            version: None,
+            onchain_verifier: None,
+            caveats: Cow::Borrowed(&[]),
         }
     }
 }
 
-fn feat_suffix(feat: Option<&str>) -> String {
-    match feat {
-        Some(f) if !f.is_empty() => format!("_{}", f),
-        _ => String::new(),
+fn input_sizes_for(target: BenchTarget) -> Vec<usize> {
+    match target {
+        BenchTarget::Sha256 | BenchTarget::Blake2s | BenchTarget::PoseidonSponge => {
+            selected_byte_inputs()
+        }
+        BenchTarget::Keccak => crate::metadata::selected_keccak_inputs(),
+        BenchTarget::Ecdsa => vec![32],
+        BenchTarget::Poseidon | BenchTarget::Poseidon2 => selected_field_element_inputs(),
+        BenchTarget::ReceiptProof => crate::metadata::selected_receipt_counts(),
+        BenchTarget::MerkleUpdate => crate::metadata::selected_merkle_update_leaves(),
+        BenchTarget::Multisig => crate::metadata::selected_multisig_signers(),
     }
 }
 
-fn group_id(target: &str, size: usize, system: &str, feat: Option<&str>) -> String {
-    format!("{}_{}_{}{}", target, size, system, feat_suffix(feat))
+/// A benchmark for one (target, proving system) pair.
+///
+/// Implementors describe how to prepare, prove and verify a single input size; [`run_suite`]
+/// drives them uniformly (metrics pass, artifact export, RAM measurement, Criterion timing).
+/// Only the required methods have to be implemented; the optional measurements default to
+/// "not reported". A step that returns an error is recorded as a failure of its input size,
+/// which is then skipped.
+pub trait BenchmarkSuite {
+    type Prepared;
+    type Proof;
+
+    /// Target, proving system, feature tag and RAM-measurement binary of this benchmark.
+    fn config(&self) -> BenchHarnessConfig<'_>;
+
+    /// Descriptive properties of the proving system.
+    fn properties(&self) -> BenchProperties;
+
+    /// Input sizes to benchmark. Defaults to the sizes selected for the target by `BENCH_INPUT_PROFILE`.
+    fn sizes(&self) -> Vec<usize> {
+        input_sizes_for(self.config().target)
+    }
+
+    fn prepare(&self, size: usize) -> Result<Self::Prepared, BenchError>;
+
+    fn prove(&self, prepared: &Self::Prepared) -> Result<Self::Proof, BenchError>;
+
+    fn verify(&self, prepared: &Self::Prepared, proof: &Self::Proof) -> Result<(), BenchError>;
+
+    fn num_constraints(&self, _prepared: &Self::Prepared) -> usize {
+        0
+    }
+
+    /// Witness elements and their size, for circuit systems (see
+    /// [`witness_size`](crate::witness_size)). Without it, the witness size is derived from the
+    /// trace area of [`Self::circuit_size`], if known.
+    fn witness_size(&self, _prepared: &Self::Prepared) -> Option<WitnessSize> {
+        None
+    }
+
+    /// Constraint count with the witness size. Defaults to [`Self::num_constraints`] and
+    /// [`Self::witness_size`]. An error, e.g. an unreadable key file, fails the input size.
+    fn constraint_count(&self, prepared: &Self::Prepared) -> Result<ConstraintCount, BenchError> {
+        Ok(ConstraintCount {
+            total: self.num_constraints(prepared),
+            witness_size: self.witness_size(prepared),
+        })
+    }
+
+    /// Size of the preprocessing (keys, preprocessed commitments). An error fails the input size.
+    fn preprocessing_size(&self, _prepared: &Self::Prepared) -> Result<usize, BenchError> {
+        Ok(0)
+    }
+
+    /// The serialized preprocessing [`Self::preprocessing_size`] measures (proving and
+    /// verification keys, preprocessed commitments), whose compressed size the metrics pass
+    /// records (see [`compression`](crate::compression)). `None` if not serialized.
+    fn preprocessing_blob(&self, _prepared: &Self::Prepared) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn proof_size(&self, _proof: &Self::Proof) -> usize {
+        0
+    }
+
+    /// Proof size with the sizes of its components, where the backend exposes them. Defaults to
+    /// [`Self::proof_size`] without a breakdown. An error, e.g. a proof that fails to serialize,
+    /// fails the input size.
+    fn proof_size_report(&self, proof: &Self::Proof) -> Result<ProofSize, BenchError> {
+        Ok(self.proof_size(proof).into())
+    }
+
+    /// Execution cycles (zkVMs only). `None` or `Some(0)` means not reported.
+    fn execution_cycles(&self, _prepared: &Self::Prepared) -> Option<u64> {
+        None
+    }
+
+    /// Execution cycles with their split between the main execution and accelerator segments,
+    /// for zkVMs that prove precompiles apart. Defaults to [`Self::execution_cycles`], unsplit.
+    /// A failed execution fails the input size.
+    fn execution_report(
+        &self,
+        prepared: &Self::Prepared,
+    ) -> Result<Option<ExecutionCycles>, BenchError> {
+        Ok(self.execution_cycles(prepared).map(ExecutionCycles::from))
+    }
+
+    /// Verifies `proofs` as one batch, for systems with batch verification. Returns `None`
+    /// without verifying if unsupported.
+    fn verify_batch(
+        &self,
+        _prepared: &Self::Prepared,
+        _proofs: &[&Self::Proof],
+    ) -> Option<Result<(), BenchError>> {
+        None
+    }
+
+    /// Trace length, padding and width, for systems without a constraint count. Defaults to the
+    /// trace derived from [`Self::execution_cycles`] (see `circuit_size`).
+    fn circuit_size(&self, _prepared: &Self::Prepared) -> Option<CircuitSize> {
+        None
+    }
+
+    /// Cost billed by a hosted prover network for this proof, if the system reports it. Without
+    /// it, the cost is estimated from the bench matrix `pricing`, if configured.
+    fn proof_cost(&self, _prepared: &Self::Prepared, _proof: &Self::Proof) -> Option<ProofCost> {
+        None
+    }
+
+    /// Verification paths of `proof` besides the timed [`Self::verify`], e.g. the native
+    /// verifier next to a wrapped proof (see [`verification`](crate::verification)). Empty if
+    /// the system verifies in one way only.
+    fn verification_paths(
+        &self,
+        _prepared: &Self::Prepared,
+        _proof: &Self::Proof,
+    ) -> Vec<VerificationPath> {
+        Vec::new()
+    }
+
+    /// Verdicts of independent verifier implementations on `proof`, which [`Self::verify`]
+    /// accepted (see [`verification`](crate::verification)). Empty if the system has a single
+    /// verifier.
+    fn cross_verify(
+        &self,
+        _prepared: &Self::Prepared,
+        _proof: &Self::Proof,
+    ) -> Vec<CrossVerification> {
+        Vec::new()
+    }
+
+    /// Artifacts written when `BENCH_EXPORT_ARTIFACTS` is set. `None` if export is unsupported;
+    /// an error fails the input size.
+    fn export_artifacts(
+        &self,
+        _prepared: &Self::Prepared,
+        _proof: &Self::Proof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        Ok(None)
+    }
+
+    /// Prove and verify times the prover measured itself, for systems driven as a subprocess
+    /// whose start-up and key loading should not be timed. When reported, they replace the
+    /// wall-clock times of [`Self::prove`] and [`Self::verify`]. `None` times the calls.
+    fn reported_durations(&self, _proof: &Self::Proof) -> Option<ReportedDurations> {
+        None
+    }
 }
 
-fn bench_id(target: &str, size: usize, system: &str, feat: Option<&str>, which: &str) -> String {
-    format!(
-        "{}_{}_{}{}_{}",
-        target,
-        size,
-        system,
-        feat_suffix(feat),
-        which
-    )
+/// Durations a prover reports for one proof (see [`BenchmarkSuite::reported_durations`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportedDurations {
+    pub prove: Duration,
+    pub verify: Duration,
 }
 
-fn mem_report_filename(target: &str, size: usize, system: &str, feat: Option<&str>) -> String {
-    match feat {
-        Some(f) if !f.is_empty() => format!("{}_{}_{}_{}_mem_report.json", target, size, system, f),
-        _ => format!("{}_{}_{}_mem_report.json", target, size, system),
+/// Runs a [`BenchmarkSuite`]: for every input size, records the metrics JSON, exports artifacts
+/// if requested, measures RAM with the suite's memory binary and times prove/verify with Criterion.
+pub fn run_suite<S: BenchmarkSuite>(c: &mut Criterion, suite: &S) {
+    let cfg = suite.config();
+    let target_str = cfg.target.as_str();
+    let system_str = cfg.system.as_str();
+    spans::init();
+    run_child_if_requested(suite);
+    let matrix = matrix::load_matrix();
+    let sizes = scheduled_sizes(suite);
+    if sizes.is_empty() {
+        return;
     }
+    record_run_start(suite, &matrix);
+    baseline::configure(c);
+
+    let quick = quick::enabled();
+    for size in sizes {
+        let (_runtime, applied_runtime) =
+            runtime::apply(&matrix.runtime(system_str, target_str, size));
+        let pricing = matrix.pricing(system_str, target_str, size);
+        let Some((mut metrics, reported)) =
+            record_metrics(suite, size, quick, applied_runtime, pricing)
+        else {
+            continue;
+        };
+        if quick {
+            continue;
+        }
+
+        let sampling = matrix.sampling(system_str, target_str, size);
+        let mut group = init_bench_group(c, &cfg, target_str, system_str, size, &sampling);
+
+        let prove_id = BenchId::new(target_str, size, system_str, cfg.feature).step("prove");
+        let verify_id = BenchId::new(target_str, size, system_str, cfg.feature).step("verify");
+        // A step can still fail after the metrics pass got through it (a flaky prover, a full
+        // disk). The first failure is recorded in the metrics, and the remaining iterations skip
+        // the work rather than timing a failing step.
+        let failed: RefCell<Option<StepError>> = RefCell::new(None);
+        let fail = |step: &str, error: BenchError| {
+            failed.borrow_mut().get_or_insert(StepError {
+                step: step.to_string(),
+                error,
+            });
+        };
+        if reported {
+            group.bench_function(prove_id, |bench| {
+                bench.iter_custom(|iters| sum_reported(suite, size, iters, &fail, |r| r.prove));
+            });
+            group.bench_function(verify_id, |bench| {
+                bench.iter_custom(|iters| sum_reported(suite, size, iters, &fail, |r| r.verify));
+            });
+        } else {
+            group.bench_function(prove_id, |bench| {
+                bench.iter_batched(
+                    || {
+                        if failed.borrow().is_some() {
+                            return None;
+                        }
+                        suite.prepare(size).map_err(|e| fail("prepare", e)).ok()
+                    },
+                    |prepared| {
+                        if let Some(Err(error)) = prepared.map(|prepared| suite.prove(&prepared)) {
+                            fail("prove", error);
+                        }
+                    },
+                    BatchSize::SmallInput,
+                );
+            });
+
+            group.bench_function(verify_id, |bench| {
+                bench.iter_batched(
+                    || {
+                        if failed.borrow().is_some() {
+                            return None;
+                        }
+                        let prepared = suite.prepare(size).map_err(|e| fail("prepare", e)).ok()?;
+                        let proof = suite.prove(&prepared).map_err(|e| fail("prove", e)).ok()?;
+                        Some((prepared, proof))
+                    },
+                    |input| {
+                        if let Some((prepared, proof)) = input
+                            && let Err(error) = suite.verify(&prepared, &proof)
+                        {
+                            fail("verify", error);
+                        }
+                    },
+                    BatchSize::SmallInput,
+                );
+            });
+        }
+        group.finish();
+
+        if let Some(error) = failed.into_inner() {
+            tracing::error!("{target_str} {system_str} size {size}: timing failed at {error}");
+            metrics.step_error = Some(error);
+            write_json_metrics(target_str, size, system_str, cfg.feature, &metrics);
+        }
+    }
+    record_run_finish(&cfg);
 }
 
-fn input_sizes_for(target: BenchTarget) -> Vec<usize> {
-    match target {
-        BenchTarget::Sha256 | BenchTarget::Keccak => selected_byte_inputs(),
-        BenchTarget::Ecdsa => vec![32],
-        BenchTarget::Poseidon | BenchTarget::Poseidon2 => selected_field_element_inputs(),
+/// Sum of the durations selected by `step` over `iters` fresh proofs of `size`, each of which is
+/// verified so that a failing step is not timed silently. Criterion's timing loop for suites that
+/// report their own durations. A failed step is passed to `fail` and adds no time.
+fn sum_reported<S: BenchmarkSuite>(
+    suite: &S,
+    size: usize,
+    iters: u64,
+    fail: &impl Fn(&str, BenchError),
+    step: impl Fn(ReportedDurations) -> Duration,
+) -> Duration {
+    (0..iters)
+        .map(|_| {
+            let prepared = suite.prepare(size).map_err(|e| fail("prepare", e)).ok()?;
+            let proof = suite.prove(&prepared).map_err(|e| fail("prove", e)).ok()?;
+            suite
+                .verify(&prepared, &proof)
+                .map_err(|e| fail("verify", e))
+                .ok()?;
+            suite.reported_durations(&proof).map(&step)
+        })
+        .map(Option::unwrap_or_default)
+        .sum()
+}
+
+/// Adds the suite to the run manifest (see [`manifest`]). A manifest that cannot be written is
+/// logged rather than failing the benchmarks.
+pub(crate) fn record_run_start<S: BenchmarkSuite>(suite: &S, matrix: &BenchMatrix) {
+    let cfg = suite.config();
+    if let Err(e) = manifest::record_bench_start(
+        &results::Writer::from_env(),
+        matrix,
+        cfg.system.as_str(),
+        cfg.target.as_str(),
+        cfg.feature,
+        scheduled_sizes(suite),
+    ) {
+        tracing::warn!("Failed to update the run manifest: {}", e);
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn run_benchmarks_fn<
+/// Sizes of the suite to run: all of them, or the ones selected by the schedule plan of a
+/// time-budgeted run (see [`schedule`]).
+pub(crate) fn scheduled_sizes<S: BenchmarkSuite>(suite: &S) -> Vec<usize> {
+    let sizes = suite.sizes();
+    let Some(plan) = schedule::load_schedule() else {
+        return sizes;
+    };
+    let cfg = suite.config();
+    sizes
+        .into_iter()
+        .filter(|&size| {
+            plan.is_selected(&BenchId::new(
+                cfg.target.as_str(),
+                size,
+                cfg.system.as_str(),
+                cfg.feature,
+            ))
+        })
+        .collect()
+}
+
+pub(crate) fn record_run_finish(cfg: &BenchHarnessConfig<'_>) {
+    if let Err(e) = manifest::record_bench_finish(
+        &results::Writer::from_env(),
+        cfg.system.as_str(),
+        cfg.target.as_str(),
+        cfg.feature,
+    ) {
+        tracing::warn!("Failed to update the run manifest: {}", e);
+    }
+}
+
+/// Measures and exits if this process is a re-executed child of the cold-start (also used by the
+/// memory-pressure mode), light-client verification, invalid-input or soak modes. Must run before the
+/// suite does any other work in the process.
+pub(crate) fn run_child_if_requested<S: BenchmarkSuite>(suite: &S) {
+    cold_start::run_child_if_requested(suite);
+    verify_budget::run_child_if_requested(suite);
+    invalid_input::run_child_if_requested(suite);
+    soak::run_child_if_requested(suite);
+}
+
+/// Metrics pass for one input size: proves once, writes the metrics JSON and the span timings,
+/// exports artifacts if requested and measures RAM. With `timed`, the wall-clock time of this
+/// prove and of the verify that follows is stored as the durations, flagged low-confidence, so
+/// `collect_benchmarks` does not look for Criterion estimates.
+///
+/// Returns the metrics written and whether the suite reported its own durations (see
+/// [`BenchmarkSuite::reported_durations`]), or `None` if a step failed: the metrics JSON then
+/// only holds the failure, and the size is not timed or measured.
+pub(crate) fn record_metrics<S: BenchmarkSuite>(
+    suite: &S,
+    size: usize,
+    timed: bool,
+    runtime: RuntimeConfig,
+    pricing: Option<&ProverPricing>,
+) -> Option<(Metrics, bool)> {
+    let cfg = suite.config();
+    let properties = suite.properties();
+    let target_str = cfg.target.as_str();
+    let system_str = cfg.system.as_str();
+
+    let mut metrics = init_metrics(&cfg, target_str, system_str, size, &properties);
+    metrics.runtime = Some(runtime);
+    if !timed {
+        metrics.baseline = baseline::compared().map(BaselineDelta::new);
+    }
+    let step_failed = |mut metrics: Metrics, stage: &str, error: BenchError| {
+        tracing::error!("{target_str} {system_str} size {size}: {stage} failed: {error}");
+        metrics.step_error = Some(StepError {
+            step: stage.to_string(),
+            error,
+        });
+        spans::finish_capture();
+        write_json_metrics(target_str, size, system_str, cfg.feature, &metrics);
+        None
+    };
+
+    spans::start_capture();
+    let prepared_context =
+        match tracing::info_span!("prepare", size).in_scope(|| suite.prepare(size)) {
+            Ok(prepared) => prepared,
+            Err(error) => return step_failed(metrics, "prepare", error),
+        };
+    if cold_start::enabled() {
+        match cold_start::measure(size) {
+            Ok(cold_start) => metrics.cold_start = Some(cold_start),
+            Err(failure) => metrics.failures.push(failure),
+        }
+    }
+    if verify_budget::enabled() {
+        let (budget, failure) = verify_budget::measure(size);
+        metrics.light_client_verify = Some(budget);
+        metrics.failures.extend(failure);
+    }
+    if memory_pressure::enabled() {
+        let (pressure, failures) = memory_pressure::measure(size);
+        metrics.memory_pressure = pressure;
+        metrics.failures.extend(failures);
+    }
+    if invalid_input::enabled() {
+        match invalid_input::measure(size) {
+            Ok(rejection) => metrics.invalid_input = Some(rejection),
+            Err(failure) => metrics.failures.push(failure),
+        }
+    }
+    if soak::soak_duration().is_some() {
+        match soak::measure(size) {
+            Ok(soak) => metrics.soak = Some(soak),
+            Err(failure) => metrics.failures.push(failure),
+        }
+    }
+    let sizes = suite
+        .preprocessing_size(&prepared_context)
+        .and_then(|size| Ok((size, suite.constraint_count(&prepared_context)?)));
+    let constraints = match sizes {
+        Ok((preprocessing_size, constraints)) => {
+            metrics.preprocessing_size = preprocessing_size;
+            constraints
+        }
+        Err(error) => return step_failed(metrics, "prepare", error),
+    };
+    metrics.num_constraints = constraints.total;
+    let ((proof, proof_duration), perf_counters) = measure_perf_counters(|| {
+        let start = Instant::now();
+        let proof = tracing::info_span!("prove", size).in_scope(|| suite.prove(&prepared_context));
+        (proof, start.elapsed())
+    });
+    let proof = match proof {
+        Ok(proof) => proof,
+        Err(error) => return step_failed(metrics, "prove", error),
+    };
+    let start = Instant::now();
+    if let Err(error) =
+        tracing::info_span!("verify", size).in_scope(|| suite.verify(&prepared_context, &proof))
+    {
+        return step_failed(metrics, "verify", error);
+    }
+    let verify_duration = start.elapsed();
+    let reported = suite.reported_durations(&proof);
+    if timed {
+        metrics.proof_duration = reported.map_or(proof_duration, |r| r.prove);
+        metrics.verify_duration = reported.map_or(verify_duration, |r| r.verify);
+        metrics.low_confidence = true;
+    }
+    let span_timings = spans::finish_capture();
+    metrics.perf_counters = perf_counters;
+    metrics.prove_phases = spans::prove_phases(&span_timings);
+    if let Some(batch_size) = verify_batch::batch_size() {
+        metrics.verify_batch = Some(verify_batch::measure(
+            suite,
+            &prepared_context,
+            &proof,
+            batch_size,
+        ));
+    }
+    metrics.verification_paths = suite.verification_paths(&prepared_context, &proof);
+    metrics.cross_verifications = suite.cross_verify(&prepared_context, &proof);
+    for disagreement in metrics
+        .cross_verifications
+        .iter()
+        .filter(|check| !check.agrees)
+    {
+        tracing::warn!(
+            "{} rejected a proof the timed verifier accepted: {}",
+            disagreement.verifier,
+            disagreement.error.as_deref().unwrap_or("no error")
+        );
+    }
+    let proof_size = match suite.proof_size_report(&proof) {
+        Ok(proof_size) => proof_size,
+        Err(error) => return step_failed(metrics, "proof_size", error),
+    };
+    metrics.proof_size = proof_size.total;
+    metrics.proof_size_breakdown = proof_size.breakdown;
+    let execution = match suite.execution_report(&prepared_context) {
+        Ok(execution) => execution.filter(|execution| execution.total != 0),
+        Err(error) => return step_failed(metrics, "execute", error),
+    };
+    metrics.cycles = execution.as_ref().map(|execution| execution.total);
+    metrics.cycle_breakdown = execution.and_then(|execution| execution.breakdown);
+    metrics.circuit_size = suite.circuit_size(&prepared_context).or_else(|| {
+        metrics
+            .cycles
+            .map(|cycles| CircuitSize::from_cycles(cfg.system, cycles))
+    });
+    metrics.witness_size = constraints.witness_size.or_else(|| {
+        let area = metrics.circuit_size.as_ref()?.trace_area?;
+        WitnessSize::over_field(area, &properties.field_curve)
+    });
+    metrics.proof_cost = suite
+        .proof_cost(&prepared_context, &proof)
+        .or_else(|| pricing?.estimate(metrics.cycles));
+
+    let exported = match suite.export_artifacts(&prepared_context, &proof) {
+        Ok(exported) => exported,
+        Err(error) => return step_failed(metrics, "export_artifacts", error),
+    };
+    let preprocessing = suite.preprocessing_blob(&prepared_context);
+    metrics.compressed_size = CompressedSizes::measure(exported.as_ref(), preprocessing.as_deref());
+    if entropy::enabled() {
+        metrics.proof_entropy = exported.as_ref().map(ProofEntropy::of);
+    }
+    if let Err(error) = export_proof_artifacts(&metrics, exported) {
+        return step_failed(metrics, "export_artifacts", error);
+    }
+
+    let writer = results::Writer::from_env();
+    if let Err(error) = writer.write_json(
+        &writer.spans_path(&BenchId::new(target_str, size, system_str, cfg.feature)),
+        &span_timings,
+    ) {
+        return step_failed(metrics, "write_spans", error.into());
+    }
+
+    // A single run of the memory binary is no peak to report, so quick mode leaves RAM out.
+    if quick::enabled() {
+        tracing::info!("Quick mode: RAM not measured");
+    } else {
+        let gpu = properties.prover_resource.as_deref() == Some("gpu");
+        if let Err(error) =
+            measure_ram(&cfg, target_str, system_str, cfg.mem_binary_name, size, gpu)
+        {
+            return step_failed(metrics, "measure_ram", error);
+        }
+    }
+    write_json_metrics(target_str, size, system_str, cfg.feature, &metrics);
+    Some((metrics, reported.is_some()))
+}
+
+/// A value reported by a closure of [`define_benchmark_harness!`]: the value itself, or the
+/// result of a step that can fail (reading a key file, executing the guest, serializing a key or
+/// proof to measure it). An error fails the input size like a failed prove.
+pub trait Reported<T> {
+    fn into_reported(self) -> Result<T, BenchError>;
+}
+
+impl Reported<ConstraintCount> for usize {
+    fn into_reported(self) -> Result<ConstraintCount, BenchError> {
+        Ok(self.into())
+    }
+}
+
+impl Reported<ConstraintCount> for ConstraintCount {
+    fn into_reported(self) -> Result<ConstraintCount, BenchError> {
+        Ok(self)
+    }
+}
+
+impl<C: Into<ConstraintCount>> Reported<ConstraintCount> for Result<C, BenchError> {
+    fn into_reported(self) -> Result<ConstraintCount, BenchError> {
+        self.map(Into::into)
+    }
+}
+
+impl Reported<ExecutionCycles> for u64 {
+    fn into_reported(self) -> Result<ExecutionCycles, BenchError> {
+        Ok(self.into())
+    }
+}
+
+impl Reported<ExecutionCycles> for ExecutionCycles {
+    fn into_reported(self) -> Result<ExecutionCycles, BenchError> {
+        Ok(self)
+    }
+}
+
+impl<C: Into<ExecutionCycles>> Reported<ExecutionCycles> for Result<C, BenchError> {
+    fn into_reported(self) -> Result<ExecutionCycles, BenchError> {
+        self.map(Into::into)
+    }
+}
+
+impl Reported<usize> for usize {
+    fn into_reported(self) -> Result<usize, BenchError> {
+        Ok(self)
+    }
+}
+
+impl Reported<usize> for Result<usize, BenchError> {
+    fn into_reported(self) -> Result<usize, BenchError> {
+        self
+    }
+}
+
+impl Reported<ProofSize> for usize {
+    fn into_reported(self) -> Result<ProofSize, BenchError> {
+        Ok(self.into())
+    }
+}
+
+impl Reported<ProofSize> for ProofSize {
+    fn into_reported(self) -> Result<ProofSize, BenchError> {
+        Ok(self)
+    }
+}
+
+impl<S: Into<ProofSize>> Reported<ProofSize> for Result<S, BenchError> {
+    fn into_reported(self) -> Result<ProofSize, BenchError> {
+        self.map(Into::into)
+    }
+}
+
+impl Reported<ExportedArtifacts> for ExportedArtifacts {
+    fn into_reported(self) -> Result<ExportedArtifacts, BenchError> {
+        Ok(self)
+    }
+}
+
+impl Reported<ExportedArtifacts> for Result<ExportedArtifacts, BenchError> {
+    fn into_reported(self) -> Result<ExportedArtifacts, BenchError> {
+        self
+    }
+}
+
+/// Shared state of [`define_benchmark_harness!`], whose initializer may use `?` on a
+/// [`BenchError`]. A failed initialization fails every input size at `prepare`.
+pub fn shared_state<T>(init: impl FnOnce() -> Result<T, BenchError>) -> Result<T, BenchError> {
+    init()
+}
+
+/// [`BenchmarkSuite`] built from the positional closures of [`define_benchmark_harness!`].
+/// The `FnMut` closures are kept in `RefCell`s since suite methods only borrow `&self`.
+struct ClosureSuite<
+    'a,
+    SharedState,
     PreparedContext,
     Proof,
     PrepareFn,
     NumConstraintsFn,
+    Count,
     ProveFn,
     VerifyFn,
     PrepSizeFn,
+    PrepSize,
     ProofSizeFn,
-    ExecutionCyclesFn: Fn(&PreparedContext) -> u64,
->(
-    c: &mut Criterion,
-    cfg: BenchHarnessConfig<'_>,
+    Size,
+    ExecutionCyclesFn,
+    Cycles,
+    ExportArtifactsFn,
+    Exported,
+> {
+    cfg: BenchHarnessConfig<'a>,
     properties: BenchProperties,
-    mut prepare: PrepareFn,
-    mut num_constraints: NumConstraintsFn,
-    mut prove: ProveFn,
-    mut verify: VerifyFn,
-    mut preprocessing_size: PrepSizeFn,
-    mut proof_size: ProofSizeFn,
+    /// The error of a failed initialization, returned by every `prepare`.
+    shared: Result<SharedState, BenchError>,
+    prepare: PrepareFn,
+    num_constraints: RefCell<NumConstraintsFn>,
+    prove: ProveFn,
+    verify: RefCell<VerifyFn>,
+    preprocessing_size: RefCell<PrepSizeFn>,
+    proof_size: RefCell<ProofSizeFn>,
     execution_cycles: Option<ExecutionCyclesFn>,
-) where
-    PrepareFn: FnMut(usize) -> PreparedContext + Copy,
-    ProveFn: FnMut(&PreparedContext) -> Proof + Copy,
-    NumConstraintsFn: FnMut(&PreparedContext) -> usize,
-    VerifyFn: FnMut(&PreparedContext, &Proof),
-    PrepSizeFn: FnMut(&PreparedContext) -> usize,
-    ProofSizeFn: FnMut(&Proof) -> usize,
+    export_artifacts: Option<ExportArtifactsFn>,
+    _marker: PhantomData<fn() -> (PreparedContext, Proof)>,
+    _cycles: PhantomData<fn() -> Cycles>,
+    _size: PhantomData<fn() -> (PrepSize, Size)>,
+    _count: PhantomData<fn() -> Count>,
+    _exported: PhantomData<fn() -> Exported>,
+}
+
+impl<
+    SharedState,
+    PreparedContext,
+    Proof,
+    PrepareFn,
+    NumConstraintsFn,
+    Count,
+    ProveFn,
+    VerifyFn,
+    PrepSizeFn,
+    PrepSize,
+    ProofSizeFn,
+    Size,
+    ExecutionCyclesFn,
+    Cycles,
+    ExportArtifactsFn,
+    Exported,
+>
+    ClosureSuite<
+        '_,
+        SharedState,
+        PreparedContext,
+        Proof,
+        PrepareFn,
+        NumConstraintsFn,
+        Count,
+        ProveFn,
+        VerifyFn,
+        PrepSizeFn,
+        PrepSize,
+        ProofSizeFn,
+        Size,
+        ExecutionCyclesFn,
+        Cycles,
+        ExportArtifactsFn,
+        Exported,
+    >
 {
-    let target_str = cfg.target.as_str();
-    let system_str = cfg.system.as_str();
+    /// The shared state; only reached once `prepare` succeeded, which needs it.
+    fn shared(&self) -> &SharedState {
+        self.shared
+            .as_ref()
+            .unwrap_or_else(|_| unreachable!("prepare fails without the shared state"))
+    }
+}
 
-    for size in input_sizes_for(cfg.target) {
-        let prepared_context = prepare(size);
+impl<
+    SharedState,
+    PreparedContext,
+    Proof,
+    PrepareFn,
+    NumConstraintsFn,
+    Count,
+    ProveFn,
+    VerifyFn,
+    PrepSizeFn,
+    PrepSize,
+    ProofSizeFn,
+    Size,
+    ExecutionCyclesFn,
+    Cycles,
+    ExportArtifactsFn,
+    Exported,
+> BenchmarkSuite
+    for ClosureSuite<
+        '_,
+        SharedState,
+        PreparedContext,
+        Proof,
+        PrepareFn,
+        NumConstraintsFn,
+        Count,
+        ProveFn,
+        VerifyFn,
+        PrepSizeFn,
+        PrepSize,
+        ProofSizeFn,
+        Size,
+        ExecutionCyclesFn,
+        Cycles,
+        ExportArtifactsFn,
+        Exported,
+    >
+where
+    SharedState: Copy,
+    PrepareFn: FnMut(usize, SharedState) -> Result<PreparedContext, BenchError> + Copy,
+    NumConstraintsFn: FnMut(&PreparedContext, &SharedState) -> Count,
+    Count: Reported<ConstraintCount>,
+    ProveFn: FnMut(&PreparedContext, &SharedState) -> Result<Proof, BenchError> + Copy,
+    VerifyFn: FnMut(&PreparedContext, &Proof, &SharedState) -> Result<(), BenchError>,
+    PrepSizeFn: FnMut(&PreparedContext, &SharedState) -> PrepSize,
+    PrepSize: Reported<usize>,
+    ProofSizeFn: FnMut(&Proof, &SharedState) -> Size,
+    Size: Reported<ProofSize>,
+    ExecutionCyclesFn: Fn(&PreparedContext) -> Cycles,
+    Cycles: Reported<ExecutionCycles>,
+    ExportArtifactsFn: Fn(&PreparedContext, &Proof, &SharedState) -> Exported,
+    Exported: Reported<ExportedArtifacts>,
+{
+    type Prepared = PreparedContext;
+    type Proof = Proof;
 
-        let mut metrics = init_metrics(&cfg, target_str, system_str, size, &properties);
-        metrics.preprocessing_size = preprocessing_size(&prepared_context);
-        metrics.num_constraints = num_constraints(&prepared_context);
-        let proof = prove(&prepared_context);
-        metrics.proof_size = proof_size(&proof);
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        self.cfg.clone()
+    }
 
-        if let Some(ref cycles_fn) = execution_cycles {
-            let c = cycles_fn(&prepared_context);
-            metrics.cycles = if c == 0 { None } else { Some(c) };
-        }
+    fn properties(&self) -> BenchProperties {
+        self.properties.clone()
+    }
 
-        write_json_metrics(target_str, size, system_str, cfg.feature, &metrics);
+    fn prepare(&self, size: usize) -> Result<PreparedContext, BenchError> {
+        let mut prepare = self.prepare;
+        prepare(size, self.shared.clone()?)
+    }
 
-        measure_ram(&cfg, target_str, system_str, cfg.mem_binary_name, size);
+    fn prove(&self, prepared: &PreparedContext) -> Result<Proof, BenchError> {
+        let mut prove = self.prove;
+        prove(prepared, self.shared())
+    }
 
-        let mut group = init_bench_group(c, &cfg, target_str, system_str, size);
+    fn verify(&self, prepared: &PreparedContext, proof: &Proof) -> Result<(), BenchError> {
+        (self.verify.borrow_mut())(prepared, proof, self.shared())
+    }
 
-        let prove_id = bench_id(target_str, size, system_str, cfg.feature, "prove");
-        group.bench_function(prove_id, move |bench| {
-            bench.iter_batched(
-                || prepare(size),
-                |prepared| {
-                    let _ = (prove)(&prepared);
-                },
-                BatchSize::SmallInput,
-            );
-        });
+    fn constraint_count(&self, prepared: &PreparedContext) -> Result<ConstraintCount, BenchError> {
+        (self.num_constraints.borrow_mut())(prepared, self.shared()).into_reported()
+    }
 
-        let verify_id = bench_id(target_str, size, system_str, cfg.feature, "verify");
-        group.bench_function(verify_id, |bench| {
-            bench.iter_batched(
-                || {
-                    let prepared = prepare(size);
-                    let proof_local = (prove)(&prepared);
-                    (prepared, proof_local)
-                },
-                |(prepared, proof_local)| {
-                    (verify)(&prepared, &proof_local);
-                },
-                BatchSize::SmallInput,
-            );
-        });
+    fn preprocessing_size(&self, prepared: &PreparedContext) -> Result<usize, BenchError> {
+        (self.preprocessing_size.borrow_mut())(prepared, self.shared()).into_reported()
+    }
 
-        group.finish();
+    fn proof_size(&self, proof: &Proof) -> usize {
+        self.proof_size_report(proof).map_or(0, |size| size.total)
+    }
+
+    fn proof_size_report(&self, proof: &Proof) -> Result<ProofSize, BenchError> {
+        (self.proof_size.borrow_mut())(proof, self.shared()).into_reported()
+    }
+
+    fn execution_report(
+        &self,
+        prepared: &PreparedContext,
+    ) -> Result<Option<ExecutionCycles>, BenchError> {
+        self.execution_cycles
+            .as_ref()
+            .map(|f| f(prepared).into_reported())
+            .transpose()
+    }
+
+    fn export_artifacts(
+        &self,
+        prepared: &PreparedContext,
+        proof: &Proof,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        self.export_artifacts
+            .as_ref()
+            .map(|f| f(prepared, proof, self.shared()).into_reported())
+            .transpose()
     }
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn run_benchmarks_with_state_fn<
-    SharedState: Copy,
+pub fn run_benchmarks_fn<
     PreparedContext,
     Proof,
     PrepareFn,
     NumConstraintsFn,
+    Count,
     ProveFn,
     VerifyFn,
     PrepSizeFn,
+    PrepSize,
     ProofSizeFn,
-    ExecutionCyclesFn: Fn(&PreparedContext) -> u64,
+    Size,
+    Cycles: Reported<ExecutionCycles>,
+    ExecutionCyclesFn: Fn(&PreparedContext) -> Cycles,
+    Exported: Reported<ExportedArtifacts>,
+    ExportArtifactsFn: Fn(&PreparedContext, &Proof) -> Exported,
 >(
     c: &mut Criterion,
     cfg: BenchHarnessConfig<'_>,
     properties: BenchProperties,
-    shared: SharedState,
     mut prepare: PrepareFn,
     mut num_constraints: NumConstraintsFn,
     mut prove: ProveFn,
@@ -355,65 +1298,96 @@ pub fn run_benchmarks_with_state_fn<
     mut preprocessing_size: PrepSizeFn,
     mut proof_size: ProofSizeFn,
     execution_cycles: Option<ExecutionCyclesFn>,
+    export_artifacts: Option<ExportArtifactsFn>,
 ) where
-    PrepareFn: FnMut(usize, SharedState) -> PreparedContext + Copy,
-    NumConstraintsFn: FnMut(&PreparedContext, &SharedState) -> usize,
-    ProveFn: FnMut(&PreparedContext, &SharedState) -> Proof + Copy,
-    VerifyFn: FnMut(&PreparedContext, &Proof, &SharedState),
-    PrepSizeFn: FnMut(&PreparedContext, &SharedState) -> usize,
-    ProofSizeFn: FnMut(&Proof, &SharedState) -> usize,
+    PrepareFn: FnMut(usize) -> Result<PreparedContext, BenchError> + Copy,
+    ProveFn: FnMut(&PreparedContext) -> Result<Proof, BenchError> + Copy,
+    NumConstraintsFn: FnMut(&PreparedContext) -> Count,
+    Count: Reported<ConstraintCount>,
+    VerifyFn: FnMut(&PreparedContext, &Proof) -> Result<(), BenchError>,
+    PrepSizeFn: FnMut(&PreparedContext) -> PrepSize,
+    PrepSize: Reported<usize>,
+    ProofSizeFn: FnMut(&Proof) -> Size,
+    Size: Reported<ProofSize>,
 {
-    let target_str = cfg.target.as_str();
-    let system_str = cfg.system.as_str();
-
-    for size in input_sizes_for(cfg.target) {
-        let prepared_context = prepare(size, shared);
-
-        let mut metrics = init_metrics(&cfg, target_str, system_str, size, &properties);
-        metrics.preprocessing_size = preprocessing_size(&prepared_context, &shared);
-        metrics.num_constraints = num_constraints(&prepared_context, &shared);
-        let proof = prove(&prepared_context, &shared);
-        metrics.proof_size = proof_size(&proof, &shared);
-
-        if let Some(ref cycles_fn) = execution_cycles {
-            let c = cycles_fn(&prepared_context);
-            metrics.cycles = if c == 0 { None } else { Some(c) };
-        }
-
-        write_json_metrics(target_str, size, system_str, cfg.feature, &metrics);
-
-        measure_ram(&cfg, target_str, system_str, cfg.mem_binary_name, size);
-
-        let mut group = init_bench_group(c, &cfg, target_str, system_str, size);
-
-        let prove_id = bench_id(target_str, size, system_str, cfg.feature, "prove");
-        group.bench_function(prove_id, move |bench| {
-            bench.iter_batched(
-                move || prepare(size, shared),
-                move |prepared| {
-                    let _ = (prove)(&prepared, &shared);
-                },
-                BatchSize::SmallInput,
-            );
-        });
-
-        let verify_id = bench_id(target_str, size, system_str, cfg.feature, "verify");
-        group.bench_function(verify_id, |bench| {
-            bench.iter_batched(
-                || {
-                    let prepared = prepare(size, shared);
-                    let proof_local = (prove)(&prepared, &shared);
-                    (prepared, proof_local)
-                },
-                |(prepared, proof_local)| {
-                    (verify)(&prepared, &proof_local, &shared);
-                },
-                BatchSize::SmallInput,
-            );
-        });
+    run_benchmarks_with_state_fn(
+        c,
+        cfg,
+        properties,
+        Ok(()),
+        move |size, _| prepare(size),
+        move |prepared: &PreparedContext, _: &()| num_constraints(prepared),
+        move |prepared: &PreparedContext, _: &()| prove(prepared),
+        move |prepared: &PreparedContext, proof: &Proof, _: &()| verify(prepared, proof),
+        move |prepared: &PreparedContext, _: &()| preprocessing_size(prepared),
+        move |proof: &Proof, _: &()| proof_size(proof),
+        execution_cycles,
+        export_artifacts
+            .map(|f| move |prepared: &PreparedContext, proof: &Proof, _: &()| f(prepared, proof)),
+    );
+}
 
-        group.finish();
-    }
+#[allow(clippy::too_many_arguments)]
+pub fn run_benchmarks_with_state_fn<
+    SharedState: Copy,
+    PreparedContext,
+    Proof,
+    PrepareFn,
+    NumConstraintsFn,
+    Count,
+    ProveFn,
+    VerifyFn,
+    PrepSizeFn,
+    PrepSize,
+    ProofSizeFn,
+    Size,
+    Cycles: Reported<ExecutionCycles>,
+    ExecutionCyclesFn: Fn(&PreparedContext) -> Cycles,
+    Exported: Reported<ExportedArtifacts>,
+    ExportArtifactsFn: Fn(&PreparedContext, &Proof, &SharedState) -> Exported,
+>(
+    c: &mut Criterion,
+    cfg: BenchHarnessConfig<'_>,
+    properties: BenchProperties,
+    shared: Result<SharedState, BenchError>,
+    prepare: PrepareFn,
+    num_constraints: NumConstraintsFn,
+    prove: ProveFn,
+    verify: VerifyFn,
+    preprocessing_size: PrepSizeFn,
+    proof_size: ProofSizeFn,
+    execution_cycles: Option<ExecutionCyclesFn>,
+    export_artifacts: Option<ExportArtifactsFn>,
+) where
+    PrepareFn: FnMut(usize, SharedState) -> Result<PreparedContext, BenchError> + Copy,
+    NumConstraintsFn: FnMut(&PreparedContext, &SharedState) -> Count,
+    Count: Reported<ConstraintCount>,
+    ProveFn: FnMut(&PreparedContext, &SharedState) -> Result<Proof, BenchError> + Copy,
+    VerifyFn: FnMut(&PreparedContext, &Proof, &SharedState) -> Result<(), BenchError>,
+    PrepSizeFn: FnMut(&PreparedContext, &SharedState) -> PrepSize,
+    PrepSize: Reported<usize>,
+    ProofSizeFn: FnMut(&Proof, &SharedState) -> Size,
+    Size: Reported<ProofSize>,
+{
+    let suite = ClosureSuite {
+        cfg,
+        properties,
+        shared,
+        prepare,
+        num_constraints: RefCell::new(num_constraints),
+        prove,
+        verify: RefCell::new(verify),
+        preprocessing_size: RefCell::new(preprocessing_size),
+        proof_size: RefCell::new(proof_size),
+        execution_cycles,
+        export_artifacts,
+        _marker: PhantomData,
+        _cycles: PhantomData,
+        _size: PhantomData,
+        _count: PhantomData,
+        _exported: PhantomData,
+    };
+    run_suite(c, &suite);
 }
 
 fn init_bench_group<'a>(
@@ -422,10 +1396,17 @@ fn init_bench_group<'a>(
     target_str: &'static str,
     system_str: &'static str,
     size: usize,
+    sampling: &SamplingConfig,
 ) -> criterion::BenchmarkGroup<'a, criterion::measurement::WallTime> {
-    let gid = group_id(target_str, size, system_str, cfg.feature);
+    let gid = BenchId::new(target_str, size, system_str, cfg.feature).to_string();
     let mut group = c.benchmark_group(gid);
-    group.sample_size(SAMPLE_SIZE);
+    group.sample_size(sampling.sample_size.unwrap_or(SAMPLE_SIZE));
+    if let Some(warm_up_time) = sampling.warm_up_time() {
+        group.warm_up_time(warm_up_time);
+    }
+    if let Some(measurement_time) = sampling.measurement_time() {
+        group.measurement_time(measurement_time);
+    }
     group
 }
 
@@ -436,7 +1417,7 @@ fn init_metrics(
     size: usize,
     properties: &BenchProperties,
 ) -> Metrics {
-    Metrics::new(
+    let mut metrics = Metrics::new(
         system_str.to_string(),
         match cfg.feature {
             Some(f) if !f.is_empty() => Some(f.to_string()),
@@ -445,137 +1426,203 @@ fn init_metrics(
         target_str.to_string(),
         size,
         properties.clone(),
-    )
+    );
+    metrics.corpus = corpus::selected_id();
+    metrics.input_hash = attestation::input_hash(target_str, size);
+    metrics.caveats = caveat::collect(cfg.target, properties);
+    metrics.environment = Some(environment::capture());
+    metrics
 }
 
+/// Writes the proof, verification key and public inputs of the metrics-pass proof when the
+/// export mode is enabled (see [`artifacts::EXPORT_DIR_ENV`]).
+fn export_proof_artifacts(
+    metrics: &Metrics,
+    exported: Option<ExportedArtifacts>,
+) -> Result<(), BenchError> {
+    let (Some(root), Some(exported)) = (artifacts::export_dir(), exported) else {
+        return Ok(());
+    };
+    let dir = artifacts::write_artifacts(&root, metrics, &exported)?;
+    tracing::info!("Exported proof artifacts to {}", dir.display());
+    Ok(())
+}
+
+/// Runs the memory binary of the suite. Failing to build or run it fails the input size; a
+/// binary that crashes while measured is recorded in its memory report instead.
 fn measure_ram(
     cfg: &BenchHarnessConfig<'_>,
     target_str: &'static str,
     system_str: &'static str,
     mem_bin_name_ref: &str,
     size: usize,
-) {
-    compile_binary(mem_bin_name_ref);
+    gpu: bool,
+) -> Result<(), BenchError> {
+    // A feature tag that is also a cargo feature of the crate (e.g. jolt's `gpu`) gates a build
+    // variant, so the memory binary must be built with it too.
+    let features: Vec<&str> = cfg
+        .feature
+        .filter(|feature| crate_has_feature(feature))
+        .into_iter()
+        .collect();
+    compile_binary_with_features(mem_bin_name_ref, &features)?;
     let bin_path = format!("../target/release/{}", mem_bin_name_ref);
-    let mem_json = mem_report_filename(target_str, size, system_str, cfg.feature);
-    run_measure_mem_script(&mem_json, &bin_path, size);
+    let writer = results::Writer::from_env();
+    writer.create_dir()?;
+    let id = BenchId::new(target_str, size, system_str, cfg.feature);
+    let mem_json = writer.mem_report_path(&id);
+    mem::warm_cache(&bin_path, size);
+    run_measure_mem_script(&mem_json, &bin_path, size, None, gpu)?;
+    if mem::phases_enabled() {
+        for phase in mem::Phase::ALL {
+            let phase_json = writer.phase_mem_report_path(&id, phase);
+            run_measure_mem_script(&phase_json, &bin_path, size, Some(phase), gpu)?;
+        }
+    }
+    Ok(())
 }
 
 #[macro_export]
 macro_rules! __define_benchmark_harness {
-    // With shared state
-    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr, { $($shared_init:tt)* },
-        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr
+    // Internal: no shared state
+    (@run $public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr,
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr,
+        $execution_cycles:expr, $export_artifacts:expr
     ) => {
         fn criterion_benchmarks(c: &mut ::criterion::Criterion) {
             let system = $system;
-            let cfg = ::utils::harness::BenchHarnessConfig {
-                target: $target,
-                system,
-                feature: $feature,
-                mem_binary_name: $mem_binary_name,
-            };
-            ::utils::harness::run_benchmarks_with_state_fn(
-                c,
-                cfg,
-                $properties,
-                &{ $($shared_init)* },
-                $prepare,
-                $num_constraints,
-                $prove,
-                $verify,
-                $prep_size,
-                $proof_size,
-                None::<fn(&_) -> u64>,
-            );
+            ::utils::security::for_each_security_level(system, |level| {
+                let feature = ::utils::security::level_feature($feature, level);
+                let cfg = ::utils::harness::BenchHarnessConfig {
+                    target: $target,
+                    system,
+                    feature: feature.as_deref(),
+                    mem_binary_name: $mem_binary_name,
+                };
+                ::utils::harness::run_benchmarks_fn(
+                    c,
+                    cfg,
+                    $properties,
+                    $prepare,
+                    $num_constraints,
+                    $prove,
+                    $verify,
+                    $prep_size,
+                    $proof_size,
+                    $execution_cycles,
+                    $export_artifacts,
+                );
+            });
         }
         ::criterion::criterion_group!($public_group_ident, criterion_benchmarks);
         ::criterion::criterion_main!($public_group_ident);
     };
-    // No shared state, with execution_cycles
-    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr,
-        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr, $execution_cycles:expr
+    // Internal: with shared state
+    (@run_with_state $public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr, { $($shared_init:tt)* },
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr,
+        $execution_cycles:expr, $export_artifacts:expr
     ) => {
         fn criterion_benchmarks(c: &mut ::criterion::Criterion) {
             let system = $system;
-            let cfg = ::utils::harness::BenchHarnessConfig {
-                target: $target,
-                system,
-                feature: $feature,
-                mem_binary_name: $mem_binary_name,
-            };
-            ::utils::harness::run_benchmarks_fn(
-                c,
-                cfg,
-                $properties,
-                $prepare,
-                $num_constraints,
-                $prove,
-                $verify,
-                $prep_size,
-                $proof_size,
-                Some($execution_cycles),
-            );
+            // Shared state and properties are rebuilt per level, since they depend on the
+            // PCS parameters.
+            ::utils::security::for_each_security_level(system, |level| {
+                let feature = ::utils::security::level_feature($feature, level);
+                let cfg = ::utils::harness::BenchHarnessConfig {
+                    target: $target,
+                    system,
+                    feature: feature.as_deref(),
+                    mem_binary_name: $mem_binary_name,
+                };
+                let shared = ::utils::harness::shared_state(|| Ok({ $($shared_init)* }));
+                ::utils::harness::run_benchmarks_with_state_fn(
+                    c,
+                    cfg,
+                    $properties,
+                    shared.as_ref().map_err(Clone::clone),
+                    $prepare,
+                    $num_constraints,
+                    $prove,
+                    $verify,
+                    $prep_size,
+                    $proof_size,
+                    $execution_cycles,
+                    $export_artifacts,
+                );
+            });
         }
         ::criterion::criterion_group!($public_group_ident, criterion_benchmarks);
         ::criterion::criterion_main!($public_group_ident);
     };
+    // The `export_artifacts = ...` arms must come first: an assignment is itself a valid
+    // expression, so the execution_cycles arms below would otherwise swallow it.
+    // With shared state, execution_cycles and export_artifacts
+    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr, { $($shared_init:tt)* },
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr, $execution_cycles:expr,
+        export_artifacts = $export_artifacts:expr
+    ) => {
+        $crate::__define_benchmark_harness!(@run_with_state $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties, { $($shared_init)* },
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            Some($execution_cycles), Some($export_artifacts));
+    };
+    // With shared state and export_artifacts
+    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr, { $($shared_init:tt)* },
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr,
+        export_artifacts = $export_artifacts:expr
+    ) => {
+        $crate::__define_benchmark_harness!(@run_with_state $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties, { $($shared_init)* },
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            None::<fn(&_) -> u64>, Some($export_artifacts));
+    };
+    // No shared state, with execution_cycles and export_artifacts
+    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr,
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr, $execution_cycles:expr,
+        export_artifacts = $export_artifacts:expr
+    ) => {
+        $crate::__define_benchmark_harness!(@run $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties,
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            Some($execution_cycles), Some($export_artifacts));
+    };
+    // No shared state, with export_artifacts
+    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr,
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr,
+        export_artifacts = $export_artifacts:expr
+    ) => {
+        $crate::__define_benchmark_harness!(@run $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties,
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            None::<fn(&_) -> u64>, Some($export_artifacts));
+    };
+    // With shared state
+    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr, { $($shared_init:tt)* },
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr
+    ) => {
+        $crate::__define_benchmark_harness!(@run_with_state $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties, { $($shared_init)* },
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            None::<fn(&_) -> u64>, None::<fn(&_, &_, &_) -> ::utils::artifacts::ExportedArtifacts>);
+    };
+    // No shared state, with execution_cycles
+    ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr,
+        $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr, $execution_cycles:expr
+    ) => {
+        $crate::__define_benchmark_harness!(@run $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties,
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            Some($execution_cycles), None::<fn(&_, &_) -> ::utils::artifacts::ExportedArtifacts>);
+    };
     // With shared state and execution_cycles
     ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr, { $($shared_init:tt)* },
         $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr, $execution_cycles:expr
     ) => {
-        fn criterion_benchmarks(c: &mut ::criterion::Criterion) {
-            let system = $system;
-            let cfg = ::utils::harness::BenchHarnessConfig {
-                target: $target,
-                system,
-                feature: $feature,
-                mem_binary_name: $mem_binary_name,
-            };
-            ::utils::harness::run_benchmarks_with_state_fn(
-                c,
-                cfg,
-                $properties,
-                &{ $($shared_init)* },
-                $prepare,
-                $num_constraints,
-                $prove,
-                $verify,
-                $prep_size,
-                $proof_size,
-                Some($execution_cycles),
-            );
-        }
-        ::criterion::criterion_group!($public_group_ident, criterion_benchmarks);
-        ::criterion::criterion_main!($public_group_ident);
+        $crate::__define_benchmark_harness!(@run_with_state $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties, { $($shared_init)* },
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            Some($execution_cycles), None::<fn(&_, &_, &_) -> ::utils::artifacts::ExportedArtifacts>);
     };
     // No shared state, no execution_cycles
     ($public_group_ident:ident, $target:expr, $system:expr, $feature:expr, $mem_binary_name:expr, $properties:expr,
         $prepare:expr, $num_constraints:expr, $prove:expr, $verify:expr, $prep_size:expr, $proof_size:expr
     ) => {
-        fn criterion_benchmarks(c: &mut ::criterion::Criterion) {
-            let system = $system;
-            let cfg = ::utils::harness::BenchHarnessConfig {
-                target: $target,
-                system,
-                feature: $feature,
-                mem_binary_name: $mem_binary_name,
-            };
-            ::utils::harness::run_benchmarks_fn(
-                c,
-                cfg,
-                $properties,
-                $prepare,
-                $num_constraints,
-                $prove,
-                $verify,
-                $prep_size,
-                $proof_size,
-                None::<fn(&_) -> u64>,
-            );
-        }
-        ::criterion::criterion_group!($public_group_ident, criterion_benchmarks);
-        ::criterion::criterion_main!($public_group_ident);
+        $crate::__define_benchmark_harness!(@run $public_group_ident, $target, $system, $feature, $mem_binary_name, $properties,
+            $prepare, $num_constraints, $prove, $verify, $prep_size, $proof_size,
+            None::<fn(&_) -> u64>, None::<fn(&_, &_) -> ::utils::artifacts::ExportedArtifacts>);
     };
 }
 
@@ -590,10 +1637,71 @@ macro_rules! define_benchmark_harness {
     (BenchTarget::Keccak, $($rest:tt)*) => {
         $crate::__define_benchmark_harness!(keccak, $crate::harness::BenchTarget::Keccak, $($rest)*);
     };
+    (BenchTarget::Blake2s, $($rest:tt)*) => {
+        $crate::__define_benchmark_harness!(blake2s, $crate::harness::BenchTarget::Blake2s, $($rest)*);
+    };
     (BenchTarget::Poseidon, $($rest:tt)*) => {
         $crate::__define_benchmark_harness!(poseidon, $crate::harness::BenchTarget::Poseidon, $($rest)*);
     };
     (BenchTarget::Poseidon2, $($rest:tt)*) => {
         $crate::__define_benchmark_harness!(poseidon2, $crate::harness::BenchTarget::Poseidon2, $($rest)*);
     };
+    (BenchTarget::PoseidonSponge, $($rest:tt)*) => {
+        $crate::__define_benchmark_harness!(poseidon_sponge, $crate::harness::BenchTarget::PoseidonSponge, $($rest)*);
+    };
+    (BenchTarget::ReceiptProof, $($rest:tt)*) => {
+        $crate::__define_benchmark_harness!(receipt_proof, $crate::harness::BenchTarget::ReceiptProof, $($rest)*);
+    };
+    (BenchTarget::MerkleUpdate, $($rest:tt)*) => {
+        $crate::__define_benchmark_harness!(merkle_update, $crate::harness::BenchTarget::MerkleUpdate, $($rest)*);
+    };
+    (BenchTarget::Multisig, $($rest:tt)*) => {
+        $crate::__define_benchmark_harness!(multisig, $crate::harness::BenchTarget::Multisig, $($rest)*);
+    };
+}
+
+/// Registers a [`harness::BenchmarkSuite`](crate::harness::BenchmarkSuite) implementation as the
+/// Criterion entry point of a bench target.
+#[macro_export]
+macro_rules! define_benchmark_suite {
+    ($suite:expr) => {
+        fn criterion_benchmarks(c: &mut ::criterion::Criterion) {
+            $crate::harness::run_suite(c, &$suite);
+        }
+        ::criterion::criterion_group!(benches, criterion_benchmarks);
+        ::criterion::criterion_main!(benches);
+    };
+}
+
+/// Registers a [`harness::BenchmarkSuite`](crate::harness::BenchmarkSuite) as the Divan entry point
+/// of a bench target (`divan` feature, see [`harness::divan`](crate::harness::divan)). Allocations
+/// are tracked with Divan's `AllocProfiler`.
+#[cfg(feature = "divan")]
+#[macro_export]
+macro_rules! define_divan_benchmark_suite {
+    ($suite:expr) => {
+        #[global_allocator]
+        static ALLOC: $crate::__divan::AllocProfiler = $crate::__divan::AllocProfiler::system();
+
+        use $crate::harness::BenchmarkSuite as _;
+
+        fn suite() -> impl $crate::harness::BenchmarkSuite {
+            $suite
+        }
+
+        #[$crate::__divan::bench(crate = $crate::__divan, args = suite().sizes(), sample_count = 10, sample_size = 1)]
+        fn prove(bencher: $crate::__divan::Bencher, size: usize) {
+            $crate::harness::divan::bench_prove(bencher, &suite(), size);
+        }
+
+        #[$crate::__divan::bench(crate = $crate::__divan, args = suite().sizes(), sample_count = 10, sample_size = 1)]
+        fn verify(bencher: $crate::__divan::Bencher, size: usize) {
+            $crate::harness::divan::bench_verify(bencher, &suite(), size);
+        }
+
+        fn main() {
+            $crate::harness::divan::record_suite_metrics(&suite());
+            $crate::__divan::main();
+        }
+    };
 }
@@ -0,0 +1,106 @@
+//! Named Criterion baselines, for before/after comparisons while tuning a prover.
+//!
+//! With [`SAVE_BASELINE_ENV`] set, Criterion saves the prove and verify estimates of the run
+//! under that name (next to its `new` directory, instead of `base`), as with
+//! `--save-baseline`. With [`BASELINE_ENV`] set, it compares the run against the named baseline
+//! without overwriting it, as with `--baseline-lenient`, and the metrics record the name so that
+//! `collect_benchmarks` adds the baseline's means and the relative change of the run
+//! ([`BaselineDelta`]). A size without saved estimates of that baseline keeps the name only.
+
+use criterion::Criterion;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::env;
+use std::time::Duration;
+
+/// Name of the baseline to save the run as.
+pub const SAVE_BASELINE_ENV: &str = "BENCH_SAVE_BASELINE";
+/// Name of the saved baseline to compare the run against.
+pub const BASELINE_ENV: &str = "BENCH_BASELINE";
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BaselineDelta {
+    pub name: String,
+    /// Mean prove and verify times of the baseline.
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub proof_duration: Option<Duration>,
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub verify_duration: Option<Duration>,
+    /// Relative change of the mean from the baseline, e.g. `-0.1` for proving 10% faster.
+    pub proof_change: Option<f64>,
+    pub verify_change: Option<f64>,
+}
+
+impl BaselineDelta {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            proof_duration: None,
+            verify_duration: None,
+            proof_change: None,
+            verify_change: None,
+        }
+    }
+
+    /// Records the baseline means and the change of `proof_duration` and `verify_duration`
+    /// against them.
+    pub fn compare(
+        &mut self,
+        baseline: (Option<Duration>, Option<Duration>),
+        proof_duration: Duration,
+        verify_duration: Duration,
+    ) {
+        let change = |baseline: Duration, current: Duration| {
+            (!baseline.is_zero())
+                .then(|| (current.as_secs_f64() - baseline.as_secs_f64()) / baseline.as_secs_f64())
+        };
+        (self.proof_duration, self.verify_duration) = baseline;
+        self.proof_change = baseline.0.and_then(|b| change(b, proof_duration));
+        self.verify_change = baseline.1.and_then(|b| change(b, verify_duration));
+    }
+}
+
+fn name(var: &str) -> Option<String> {
+    env::var(var)
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Baseline the run is compared against, if any.
+pub fn compared() -> Option<String> {
+    name(BASELINE_ENV)
+}
+
+/// Applies the baseline variables to `c`. Panics if both are set, since Criterion either saves
+/// or compares against a named baseline in one run.
+pub(crate) fn configure(c: &mut Criterion) {
+    match (name(SAVE_BASELINE_ENV), compared()) {
+        (Some(_), Some(_)) => {
+            panic!("Set only one of {SAVE_BASELINE_ENV} and {BASELINE_ENV}")
+        }
+        (Some(name), None) => *c = std::mem::take(c).save_baseline(name),
+        (None, Some(name)) => *c = std::mem::take(c).retain_baseline(name, false),
+        (None, None) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_change_is_relative_to_the_baseline() {
+        let mut delta = BaselineDelta::new("before".to_string());
+        delta.compare(
+            (Some(Duration::from_secs(2)), None),
+            Duration::from_millis(1500),
+            Duration::from_millis(3),
+        );
+        assert_eq!(delta.proof_duration, Some(Duration::from_secs(2)));
+        assert_eq!(delta.proof_change, Some(-0.25));
+        assert_eq!(delta.verify_change, None);
+    }
+}
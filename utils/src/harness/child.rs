@@ -0,0 +1,111 @@
+//! Re-executing the current bench binary to measure one input size in a fresh process.
+//!
+//! The parent spawns the binary with a mode-specific size variable set; the child notices it at
+//! the harness entry point, measures, prints one prefixed JSON line and exits before any other
+//! harness work.
+
+use crate::bench::failure::ProverFailure;
+use crate::harness::BenchError;
+use crate::sandbox::SandboxSettings;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::any::Any;
+use std::env;
+use std::process::{self, Command};
+
+pub(crate) fn flag_enabled(name: &str) -> bool {
+    matches!(env::var(name).ok().as_deref(), Some("1") | Some("true"))
+}
+
+/// The size this process was re-executed to measure, if it is such a child.
+pub(crate) fn requested_size(size_env: &str) -> Option<usize> {
+    env::var(size_env).ok().and_then(|s| s.parse().ok())
+}
+
+/// Message of a panic caught with `catch_unwind`, if it carried a string.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+/// Value of a step the child cannot go on without. A failed step panics, so the parent records
+/// it from the child's stderr like any other crash.
+pub(crate) fn expect_step<T>(stage: &str, result: Result<T, BenchError>) -> T {
+    result.unwrap_or_else(|error| panic!("{stage} failed: {error}"))
+}
+
+/// Prints the child's result for the parent and exits.
+pub(crate) fn report<T: Serialize>(prefix: &str, result: &T) -> ! {
+    println!(
+        "{}{}",
+        prefix,
+        serde_json::to_string(result).expect("Failed to serialize child measurement")
+    );
+    process::exit(0);
+}
+
+/// Re-executes the bench binary for `size` with `envs` and parses its result.
+/// If the child crashes or reports nothing, returns (and logs) a failure record for `stage`.
+pub(crate) fn spawn<T: DeserializeOwned>(
+    stage: &str,
+    size_env: &str,
+    size: usize,
+    prefix: &str,
+    envs: &[(&str, String)],
+) -> Result<T, ProverFailure> {
+    spawn_via(&[], stage, size_env, size, prefix, envs)
+}
+
+/// Like [`spawn`], but starts the bench binary through `launcher` (a program and its arguments,
+/// e.g. one that confines the binary to a cgroup). The binary runs in the configured sandbox
+/// either way (see [`crate::sandbox`]).
+pub(crate) fn spawn_via<T: DeserializeOwned>(
+    launcher: &[String],
+    stage: &str,
+    size_env: &str,
+    size: usize,
+    prefix: &str,
+    envs: &[(&str, String)],
+) -> Result<T, ProverFailure> {
+    let exe =
+        env::current_exe().map_err(|e| ProverFailure::new(stage, None, None, &e.to_string()))?;
+    let sandbox = SandboxSettings::from_env();
+    let mut command = match launcher.split_first() {
+        Some((program, args)) => {
+            let mut command = Command::new(program);
+            command.args(args).args(sandbox.launcher()).arg(exe);
+            sandbox.apply_limits(&mut command);
+            command
+        }
+        None => sandbox.command(exe),
+    };
+    let output = command
+        .env(size_env, size.to_string())
+        .env(
+            "RUST_BACKTRACE",
+            env::var("RUST_BACKTRACE").unwrap_or("1".into()),
+        )
+        .envs(envs.iter().map(|(k, v)| (k, v)))
+        .output()
+        .map_err(|e| ProverFailure::new(stage, None, None, &e.to_string()));
+
+    let result = output.and_then(|output| {
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .and_then(|json| serde_json::from_str(json).ok())
+            .ok_or_else(|| {
+                ProverFailure::from_status(
+                    stage,
+                    output.status,
+                    &String::from_utf8_lossy(&output.stderr),
+                )
+            })
+    });
+    if let Err(failure) = &result {
+        tracing::warn!("Child measurement for size {}: {}", size, failure);
+    }
+    result
+}
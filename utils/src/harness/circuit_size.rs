@@ -0,0 +1,128 @@
+//! Circuit-size analogue for systems that prove an execution trace instead of a fixed circuit.
+//!
+//! zkVMs have no constraint count to report, so `num_constraints` stays 0 for them. What the
+//! prover commits to instead is the trace: its length (the execution cycles), the length after
+//! the system pads it, and its width in columns. The harness derives the first two from the
+//! execution cycles and the system's [`TracePadding`]; suites that know the width of their trace
+//! report a full [`CircuitSize`] through `BenchmarkSuite::circuit_size`. Hand-written AIRs report
+//! their trace the same way, with the highest degree of their constraints, which together with
+//! the width explains more of their proving time than the constraint count.
+
+use super::ProvingSystem;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct CircuitSize {
+    /// Rows of the execution trace before padding.
+    pub trace_length: u64,
+    /// Rows after the system pads the trace; `None` if its padding rule is not modeled.
+    pub padded_trace_length: Option<u64>,
+    /// Trace width; `None` unless the suite reports it.
+    pub columns: Option<u64>,
+    /// `padded_trace_length * columns`, the number of committed cells.
+    pub trace_area: Option<u64>,
+    /// Highest degree of the AIR constraints; `None` unless the suite reports it.
+    pub constraint_degree: Option<u64>,
+}
+
+/// How a system pads its execution trace before committing to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TracePadding {
+    /// The whole trace is padded to the next power of two.
+    PowerOfTwo,
+    /// The trace is split into segments of `2^max_po2` rows; the last one is padded to the next
+    /// power of two.
+    Segments { max_po2: u32 },
+}
+
+impl TracePadding {
+    pub fn padded_length(self, trace_length: u64) -> u64 {
+        match self {
+            TracePadding::PowerOfTwo => trace_length.next_power_of_two(),
+            TracePadding::Segments { max_po2 } => {
+                let tail = trace_length % (1u64 << max_po2);
+                let padded_tail = if tail == 0 {
+                    0
+                } else {
+                    tail.next_power_of_two()
+                };
+                trace_length - tail + padded_tail
+            }
+        }
+    }
+}
+
+impl ProvingSystem {
+    /// Padding of the execution trace, for the zkVMs whose rule is modeled. SP1, OpenVM and Nexus
+    /// pad each chip or segment separately, which the execution cycles alone do not determine.
+    pub fn trace_padding(&self) -> Option<TracePadding> {
+        match self {
+            // Cairo's proof mode pads the steps to a power of two too.
+            ProvingSystem::Jolt | ProvingSystem::Miden | ProvingSystem::Cairo => {
+                Some(TracePadding::PowerOfTwo)
+            }
+            // Default `segment_limit_po2` of the RISC Zero executor.
+            ProvingSystem::Risc0 => Some(TracePadding::Segments { max_po2: 20 }),
+            _ => None,
+        }
+    }
+}
+
+impl CircuitSize {
+    pub fn from_trace(
+        trace_length: u64,
+        padding: Option<TracePadding>,
+        columns: Option<u64>,
+    ) -> Self {
+        let padded_trace_length = padding.map(|padding| padding.padded_length(trace_length));
+        Self {
+            trace_length,
+            padded_trace_length,
+            columns,
+            trace_area: padded_trace_length
+                .zip(columns)
+                .map(|(rows, cols)| rows * cols),
+            constraint_degree: None,
+        }
+    }
+
+    pub fn with_constraint_degree(self, degree: u64) -> Self {
+        Self {
+            constraint_degree: Some(degree),
+            ..self
+        }
+    }
+
+    /// Derived from the execution cycles of `system`, without the trace width.
+    pub fn from_cycles(system: ProvingSystem, cycles: u64) -> Self {
+        Self::from_trace(cycles, system.trace_padding(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padding_and_area() {
+        let segments = TracePadding::Segments { max_po2: 20 };
+        assert_eq!(segments.padded_length(3 << 20), 3 << 20);
+        assert_eq!(segments.padded_length((2 << 20) + 1000), (2 << 20) + 1024);
+        assert_eq!(TracePadding::PowerOfTwo.padded_length(1000), 1024);
+
+        let size = CircuitSize::from_trace(1000, Some(TracePadding::PowerOfTwo), Some(80));
+        assert_eq!(size.trace_area, Some(1024 * 80));
+        assert_eq!(size.constraint_degree, None);
+        assert_eq!(size.with_constraint_degree(3).constraint_degree, Some(3));
+
+        let sp1 = CircuitSize::from_cycles(ProvingSystem::Sp1, 1000);
+        assert_eq!(sp1.padded_trace_length, None);
+        assert_eq!(sp1.trace_area, None);
+        assert_eq!(
+            CircuitSize::from_cycles(ProvingSystem::Miden, 1000).padded_trace_length,
+            Some(1024)
+        );
+    }
+}
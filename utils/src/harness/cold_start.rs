@@ -0,0 +1,69 @@
+//! Cold-start measurement: the first prepare and prove of a size in a fresh process, including
+//! any lazy parameter generation, SRS loading or JIT compilation that steady-state (Criterion)
+//! samples amortize away.
+//!
+//! With [`COLD_START_ENV`] set, the harness re-executes the bench binary once per size (see
+//! `harness::child`); the child measures and exits.
+
+use super::{BenchmarkSuite, child};
+use crate::bench::failure::ProverFailure;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as};
+use std::time::{Duration, Instant};
+
+/// Enables the cold-start measurement when set to `1`/`true`.
+pub const COLD_START_ENV: &str = "BENCH_COLD_START";
+
+/// Set on the re-executed child to the input size it should measure.
+const CHILD_SIZE_ENV: &str = "BENCH_COLD_START_CHILD_SIZE";
+
+const RESULT_PREFIX: &str = "BENCH_COLD_START_RESULT ";
+
+#[serde_as]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ColdStart {
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub prepare_duration: Duration,
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub proof_duration: Duration,
+}
+
+pub fn enabled() -> bool {
+    child::flag_enabled(COLD_START_ENV)
+}
+
+/// In a re-executed child, measures the requested size and exits the process. No-op otherwise.
+pub(crate) fn run_child_if_requested<S: BenchmarkSuite>(suite: &S) {
+    let Some(size) = child::requested_size(CHILD_SIZE_ENV) else {
+        return;
+    };
+
+    let start = Instant::now();
+    let prepared = child::expect_step("prepare", suite.prepare(size));
+    let prepare_duration = start.elapsed();
+    let start = Instant::now();
+    let _proof = child::expect_step("prove", suite.prove(&prepared));
+    let proof_duration = start.elapsed();
+
+    child::report(
+        RESULT_PREFIX,
+        &ColdStart {
+            prepare_duration,
+            proof_duration,
+        },
+    );
+}
+
+/// Re-executes the current bench binary to measure `size` from a cold process.
+pub(crate) fn measure(size: usize) -> Result<ColdStart, ProverFailure> {
+    child::spawn("cold_start", CHILD_SIZE_ENV, size, RESULT_PREFIX, &[])
+}
+
+/// Like [`measure`], but starts the fresh process through `launcher` (see `child::spawn_via`).
+pub(crate) fn measure_via(
+    launcher: &[String],
+    stage: &str,
+    size: usize,
+) -> Result<ColdStart, ProverFailure> {
+    child::spawn_via(launcher, stage, CHILD_SIZE_ENV, size, RESULT_PREFIX, &[])
+}
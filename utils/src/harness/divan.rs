@@ -0,0 +1,66 @@
+//! [Divan](https://github.com/nvzqz/divan) backend for [`BenchmarkSuite`]s (`divan` feature).
+//!
+//! Divan has less per-sample overhead than Criterion and reports allocations, but it does not
+//! persist results. The metrics pass therefore records the wall-clock time of one prove and
+//! verify as the durations (flagged `low_confidence`, as in quick mode), and Divan's table is
+//! printed for interactive inspection.
+//! Register a suite with [`define_divan_benchmark_suite!`](crate::define_divan_benchmark_suite).
+
+use super::{
+    BenchError, BenchmarkSuite, record_metrics, record_run_finish, record_run_start,
+    run_child_if_requested, runtime, scheduled_sizes, spans,
+};
+use crate::matrix;
+use ::divan::Bencher;
+
+/// Runs the metrics pass (metrics JSON, artifact export, RAM measurement) for every input size.
+/// Bench matrix runtime controls apply to this pass only, not to Divan's timing runs.
+pub fn record_suite_metrics<S: BenchmarkSuite>(suite: &S) {
+    spans::init();
+    run_child_if_requested(suite);
+    let cfg = suite.config();
+    let matrix = matrix::load_matrix();
+    let sizes = scheduled_sizes(suite);
+    if sizes.is_empty() {
+        return;
+    }
+    record_run_start(suite, &matrix);
+    for size in sizes {
+        let (_runtime, applied_runtime) =
+            runtime::apply(&matrix.runtime(cfg.system.as_str(), cfg.target.as_str(), size));
+        let pricing = matrix.pricing(cfg.system.as_str(), cfg.target.as_str(), size);
+        record_metrics(suite, size, true, applied_runtime, pricing);
+    }
+    record_run_finish(&cfg);
+}
+
+/// Times `prove` on freshly prepared inputs of `size`. Divan keeps no results to record a
+/// failure in, so a failing step is logged and its iteration does no work.
+pub fn bench_prove<S: BenchmarkSuite>(bencher: Bencher, suite: &S, size: usize) {
+    bencher
+        .with_inputs(|| logged("prepare", suite.prepare(size)))
+        .bench_local_refs(|prepared| {
+            let prepared = prepared.as_ref()?;
+            logged("prove", suite.prove(prepared))
+        });
+}
+
+/// Times `verify` against a fresh proof of `size`, logging failing steps as [`bench_prove`].
+pub fn bench_verify<S: BenchmarkSuite>(bencher: Bencher, suite: &S, size: usize) {
+    bencher
+        .with_inputs(|| {
+            let prepared = logged("prepare", suite.prepare(size))?;
+            let proof = logged("prove", suite.prove(&prepared))?;
+            Some((prepared, proof))
+        })
+        .bench_local_refs(|input| {
+            let (prepared, proof) = input.as_ref()?;
+            logged("verify", suite.verify(prepared, proof))
+        });
+}
+
+fn logged<T>(step: &str, result: Result<T, BenchError>) -> Option<T> {
+    result
+        .map_err(|error| tracing::error!("{step} failed: {error}"))
+        .ok()
+}
@@ -0,0 +1,152 @@
+//! Errors of the prepare, prove and verify steps of a benchmark.
+//!
+//! The steps of every system crate return `Result<_, BenchError>` instead of panicking, so a
+//! failing (system, target, size) is recorded as a [`StepError`] in its metrics and the run moves
+//! on to the next size.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BenchError {
+    /// Compiling the circuit or guest, generating keys or encoding the input failed.
+    Setup { message: String },
+    /// An external tool (`nargo`, `go`, a guest toolchain) could not be run or exited with an
+    /// error.
+    Tool { tool: String, message: String },
+    /// Witness generation or proving failed.
+    Prove { message: String },
+    /// The verifier rejected the proof.
+    Verify { message: String },
+    /// The proof verified, but its public output is not the expected one (hex-encoded). For
+    /// guests committing several values, `output` names the value that differs.
+    WrongOutput {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl BenchError {
+    pub fn setup(error: impl fmt::Display) -> Self {
+        Self::Setup {
+            message: format!("{error:#}"),
+        }
+    }
+
+    pub fn tool(tool: &str, error: impl fmt::Display) -> Self {
+        Self::Tool {
+            tool: tool.to_string(),
+            message: format!("{error:#}"),
+        }
+    }
+
+    pub fn prove(error: impl fmt::Display) -> Self {
+        Self::Prove {
+            message: format!("{error:#}"),
+        }
+    }
+
+    pub fn verify(error: impl fmt::Display) -> Self {
+        Self::Verify {
+            message: format!("{error:#}"),
+        }
+    }
+
+    pub fn wrong_output(expected: impl AsRef<[u8]>, actual: impl AsRef<[u8]>) -> Self {
+        Self::WrongOutput {
+            output: None,
+            expected: hex::encode(expected),
+            actual: hex::encode(actual),
+        }
+    }
+
+    /// [`Self::wrong_output`] for the public value `output` of a guest committing several.
+    pub fn wrong_named_output(
+        output: &str,
+        expected: impl AsRef<[u8]>,
+        actual: impl AsRef<[u8]>,
+    ) -> Self {
+        Self::WrongOutput {
+            output: Some(output.to_string()),
+            expected: hex::encode(expected),
+            actual: hex::encode(actual),
+        }
+    }
+
+    /// `Err(`[`Self::verify`]`)` unless `valid`, for verifiers that return a `bool`.
+    pub fn check(valid: bool, message: &str) -> Result<(), Self> {
+        if valid {
+            Ok(())
+        } else {
+            Err(Self::verify(message))
+        }
+    }
+}
+
+impl fmt::Display for BenchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Setup { message } => write!(f, "setup failed: {message}"),
+            Self::Tool { tool, message } => write!(f, "{tool} failed: {message}"),
+            Self::Prove { message } => write!(f, "proving failed: {message}"),
+            Self::Verify { message } => write!(f, "verification failed: {message}"),
+            Self::WrongOutput {
+                output,
+                expected,
+                actual,
+            } => {
+                write!(f, "wrong public output")?;
+                if let Some(output) = output {
+                    write!(f, " {output}")?;
+                }
+                write!(f, ": expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+impl From<std::io::Error> for BenchError {
+    fn from(error: std::io::Error) -> Self {
+        Self::setup(error)
+    }
+}
+
+/// Step of the metrics pass that returned an error, recorded in the metrics in place of the
+/// measurements of its input size. A step failing later, in the Criterion timing loop, is
+/// recorded next to the measurements of the metrics pass.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepError {
+    /// `prepare`, `prove`, `verify`, `proof_size`, `execute`, `export_artifacts`, `write_spans` or
+    /// `measure_ram`.
+    pub step: String,
+    pub error: BenchError,
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.step, self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_round_trips_with_its_kind() {
+        let error = BenchError::wrong_output([0xab], [0xcd]);
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["kind"], "wrong_output");
+        assert_eq!(json["expected"], "ab");
+        assert_eq!(serde_json::from_value::<BenchError>(json).unwrap(), error);
+        assert_eq!(
+            BenchError::check(false, "proof rejected").unwrap_err(),
+            BenchError::verify("proof rejected")
+        );
+    }
+}
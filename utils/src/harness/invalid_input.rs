@@ -0,0 +1,178 @@
+//! Invalid-input robustness: how quickly a system's pipeline rejects an input whose statement is
+//! false, and at which step.
+//!
+//! With [`INVALID_INPUT_ENV`] set, the harness re-executes the bench binary once per size (see
+//! `harness::child`). The child selects [`InputKind::Invalid`] before preparing, and the input
+//! generators (`utils::generate_sha256_input`, `generate_keccak_input`, `generate_blake2s_input`,
+//! the `generate_ecdsa*` inputs and batches, `generate_receipt_proof_input`,
+//! `generate_merkle_update_input`, `generate_multisig_input`) return a wrong digest, a bad
+//! signature, wrong log fields, a wrong new root or too few valid signatures, and the child runs
+//! prepare, prove and verify until one of them returns an error or panics. A system that builds
+//! its witness while preparing rejects there; one whose prover generates the witness or checks
+//! constraints rejects while proving; a proof that is produced and only fails verification or the
+//! output check is rejected late.
+
+use super::{BenchError, BenchmarkSuite, child};
+use crate::bench::failure::ProverFailure;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Enables the invalid-input measurement when set to `1`/`true`.
+pub const INVALID_INPUT_ENV: &str = "BENCH_INVALID_INPUT";
+
+const CHILD_SIZE_ENV: &str = "BENCH_INVALID_INPUT_CHILD_SIZE";
+
+const RESULT_PREFIX: &str = "BENCH_INVALID_INPUT_RESULT ";
+
+/// Step that rejected the invalid input.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectionStage {
+    /// Input or witness construction.
+    Prepare,
+    /// Witness generation inside the prover, or a failed constraint check.
+    Prove,
+    /// A proof was produced but failed verification or the expected-output check.
+    Verify,
+    /// Every step succeeded: the invalid input was accepted.
+    NotRejected,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct InvalidInputRejection {
+    pub stage: RejectionStage,
+    /// From the start of prepare until the rejecting step gave up (or verify returned).
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub time_to_reject: Duration,
+    /// Panic message of the rejecting step.
+    pub message: Option<String>,
+}
+
+/// Whether the input generators produce the benchmarked statement or a false one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputKind {
+    #[default]
+    Valid,
+    Invalid,
+}
+
+static INPUT_KIND: OnceLock<InputKind> = OnceLock::new();
+
+impl InputKind {
+    /// The kind selected for this process, [`InputKind::Valid`] unless the invalid-input child
+    /// selected [`InputKind::Invalid`].
+    pub fn selected() -> InputKind {
+        INPUT_KIND.get().copied().unwrap_or_default()
+    }
+}
+
+/// Selects the kind of input the generators produce for the rest of the process. The kind can
+/// only be selected once, before any input is generated.
+pub(crate) fn select_input_kind(kind: InputKind) {
+    assert_eq!(
+        *INPUT_KIND.get_or_init(|| kind),
+        kind,
+        "input kind already selected"
+    );
+}
+
+pub fn enabled() -> bool {
+    child::flag_enabled(INVALID_INPUT_ENV)
+}
+
+/// Flips the lowest bit of the last byte: a wrong digest, or a signature whose `s` is still in
+/// range (and normalized) but does not verify.
+pub fn corrupt(bytes: &mut [u8]) {
+    if let Some(last) = bytes.last_mut() {
+        *last ^= 1;
+    }
+}
+
+/// In a re-executed child, runs the pipeline on an invalid input and exits. No-op otherwise.
+pub(crate) fn run_child_if_requested<S: BenchmarkSuite>(suite: &S) {
+    let Some(size) = child::requested_size(CHILD_SIZE_ENV) else {
+        return;
+    };
+    select_input_kind(InputKind::Invalid);
+
+    let start = Instant::now();
+    let result = (|| {
+        let prepared = rejected(
+            RejectionStage::Prepare,
+            start,
+            panic::catch_unwind(AssertUnwindSafe(|| suite.prepare(size))),
+        )?;
+        let proof = rejected(
+            RejectionStage::Prove,
+            start,
+            panic::catch_unwind(AssertUnwindSafe(|| suite.prove(&prepared))),
+        )?;
+        rejected(
+            RejectionStage::Verify,
+            start,
+            panic::catch_unwind(AssertUnwindSafe(|| suite.verify(&prepared, &proof))),
+        )
+    })();
+
+    let rejection = result.err().unwrap_or_else(|| InvalidInputRejection {
+        stage: RejectionStage::NotRejected,
+        time_to_reject: start.elapsed(),
+        message: None,
+    });
+    child::report(RESULT_PREFIX, &rejection);
+}
+
+/// Value of a step, or the rejection if the step returned an error or panicked.
+fn rejected<T>(
+    stage: RejectionStage,
+    start: Instant,
+    result: thread::Result<Result<T, BenchError>>,
+) -> Result<T, InvalidInputRejection> {
+    let message = match result {
+        Ok(Ok(value)) => return Ok(value),
+        Ok(Err(error)) => Some(error.to_string()),
+        Err(payload) => child::panic_message(payload.as_ref()),
+    };
+    Err(InvalidInputRejection {
+        stage,
+        time_to_reject: start.elapsed(),
+        message,
+    })
+}
+
+/// Re-executes the current bench binary to run `size` on an invalid input. A child that dies
+/// instead of panicking (e.g. aborts) is returned as a failure record.
+pub(crate) fn measure(size: usize) -> Result<InvalidInputRejection, ProverFailure> {
+    child::spawn("invalid_input", CHILD_SIZE_ENV, size, RESULT_PREFIX, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::hazmat::PrehashVerifier;
+    use p256::ecdsa::{Signature, VerifyingKey};
+    use p256::{EncodedPoint, FieldBytes};
+
+    #[test]
+    fn test_corrupted_signature_no_longer_verifies() {
+        let (digest, (x, y), mut signature) = crate::generate_ecdsa_input();
+        let point = EncodedPoint::from_affine_coordinates(
+            FieldBytes::from_slice(&x),
+            FieldBytes::from_slice(&y),
+            false,
+        );
+        let key = VerifyingKey::from_encoded_point(&point).unwrap();
+
+        corrupt(&mut signature);
+        // Still a well-formed, normalized signature, so it is only rejected by verification.
+        let signature = Signature::from_slice(&signature).unwrap();
+        assert!(signature.normalize_s().is_none());
+        assert!(key.verify_prehash(&digest, &signature).is_err());
+    }
+}
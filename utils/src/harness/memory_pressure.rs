@@ -0,0 +1,93 @@
+//! Proving under memory pressure: how much slower a system proves when its memory is capped,
+//! i.e. how gracefully it degrades when it has to page or chunk.
+//!
+//! With [`MEMORY_PRESSURE_ENV`] set, the harness proves each size twice in fresh processes (the
+//! cold-start child, see `harness::cold_start`): once uncapped and once in a transient systemd
+//! scope whose cgroup limits memory to the cap (`MemoryMax`, Linux only). Above the cap the
+//! kernel reclaims and swaps the prover's pages; without swap it is OOM-killed, which is
+//! recorded as a failure.
+
+use super::{child, cold_start};
+use crate::bench::failure::ProverFailure;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::env;
+use std::time::Duration;
+
+/// Enables the memory-pressure measurement when set to `1`/`true`.
+pub const MEMORY_PRESSURE_ENV: &str = "BENCH_MEMORY_PRESSURE";
+
+/// Memory cap in MiB; defaults to [`DEFAULT_MEMORY_CAP_MB`].
+pub const MEMORY_CAP_ENV: &str = "BENCH_MEMORY_CAP_MB";
+
+pub const DEFAULT_MEMORY_CAP_MB: u64 = 16 * 1024;
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct MemoryPressure {
+    /// Memory cap of the capped run, in bytes.
+    pub memory_cap: u64,
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub uncapped_proof_duration: Duration,
+    /// Proving time under the cap; `None` if the capped run did not complete.
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub capped_proof_duration: Option<Duration>,
+    /// `capped_proof_duration / uncapped_proof_duration`.
+    pub slowdown: Option<f64>,
+}
+
+pub fn enabled() -> bool {
+    child::flag_enabled(MEMORY_PRESSURE_ENV)
+}
+
+fn memory_cap_bytes() -> u64 {
+    env::var(MEMORY_CAP_ENV)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_MEMORY_CAP_MB)
+        * 1024
+        * 1024
+}
+
+/// Runs the bench binary in a transient user scope limited to `memory_cap` bytes.
+fn capped_launcher(memory_cap: u64) -> Vec<String> {
+    [
+        "systemd-run",
+        "--user",
+        "--scope",
+        "--quiet",
+        "--collect",
+        &format!("--property=MemoryMax={memory_cap}"),
+    ]
+    .map(str::to_string)
+    .to_vec()
+}
+
+/// Proves `size` uncapped and capped in fresh processes. `None` if the cap cannot be enforced on
+/// this platform or the uncapped run failed; failed runs are returned as failure records.
+pub(crate) fn measure(size: usize) -> (Option<MemoryPressure>, Vec<ProverFailure>) {
+    if !cfg!(target_os = "linux") {
+        tracing::warn!("Memory-pressure measurement needs cgroups (Linux); skipping");
+        return (None, Vec::new());
+    }
+
+    let uncapped = match cold_start::measure_via(&[], "memory_pressure_uncapped", size) {
+        Ok(uncapped) => uncapped.proof_duration,
+        Err(failure) => return (None, vec![failure]),
+    };
+    let memory_cap = memory_cap_bytes();
+    let (capped, failures) =
+        match cold_start::measure_via(&capped_launcher(memory_cap), "memory_pressure", size) {
+            Ok(capped) => (Some(capped.proof_duration), Vec::new()),
+            Err(failure) => (None, vec![failure]),
+        };
+
+    let pressure = MemoryPressure {
+        memory_cap,
+        uncapped_proof_duration: uncapped,
+        capped_proof_duration: capped,
+        slowdown: capped.map(|capped| capped.as_secs_f64() / uncapped.as_secs_f64()),
+    };
+    (Some(pressure), failures)
+}
@@ -0,0 +1,19 @@
+//! Quick mode for smoke benchmarking a new integration: `cargo bench -- --quick` (or
+//! [`QUICK_ENV`]) runs the metrics pass only, so every size is proven and verified exactly once
+//! and timed with a wall clock instead of Criterion samples. RAM is not measured, since one run
+//! of the memory binary is no peak to report. The metrics are flagged `low_confidence`, and
+//! `collect_benchmarks ingest` rejects them.
+
+use super::child;
+use std::env;
+
+/// Enables quick mode when set to `1`/`true`.
+pub const QUICK_ENV: &str = "BENCH_QUICK";
+
+/// Bench binary argument enabling quick mode. Criterion accepts it too (for its own, shorter
+/// sampling), so it can be passed after `cargo bench --`.
+const QUICK_FLAG: &str = "--quick";
+
+pub fn enabled() -> bool {
+    child::flag_enabled(QUICK_ENV) || env::args().any(|arg| arg == QUICK_FLAG)
+}
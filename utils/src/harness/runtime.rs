@@ -0,0 +1,82 @@
+//! Applies the [`RuntimeConfig`] of a measurement from the bench matrix.
+//!
+//! Environment variables are set for the duration of one input size and restored afterwards, so
+//! they also reach the memory-measurement and cold-start child processes. The Rayon global pool
+//! can only be configured once per process: the first size that sets `threads` or `stack_size`
+//! wins, and later mismatching requests are reported rather than silently ignored.
+
+use crate::matrix::RuntimeConfig;
+use std::env;
+use std::ffi::OsString;
+
+const RAYON_NUM_THREADS_ENV: &str = "RAYON_NUM_THREADS";
+
+/// Restores the environment variables changed by [`apply`] on drop.
+pub(crate) struct RuntimeGuard {
+    previous_env: Vec<(String, Option<OsString>)>,
+}
+
+/// Applies `config` and returns the settings in effect, for recording in the metrics file.
+pub(crate) fn apply(config: &RuntimeConfig) -> (RuntimeGuard, RuntimeConfig) {
+    configure_thread_pool(config);
+
+    let mut vars = config.env.clone();
+    if let Some(threads) = config.threads {
+        vars.insert(RAYON_NUM_THREADS_ENV.to_string(), threads.to_string());
+    }
+    let previous_env = vars
+        .iter()
+        .map(|(key, value)| {
+            let previous = env::var_os(key);
+            // SAFETY: the harness drives measurements from the main thread and the prover is not
+            // running while variables change.
+            unsafe { env::set_var(key, value) };
+            (key.clone(), previous)
+        })
+        .collect();
+
+    let applied = RuntimeConfig {
+        threads: Some(rayon::current_num_threads()),
+        stack_size: config.stack_size,
+        env: config.env.clone(),
+    };
+    (RuntimeGuard { previous_env }, applied)
+}
+
+fn configure_thread_pool(config: &RuntimeConfig) {
+    if config.threads.is_none() && config.stack_size.is_none() {
+        return;
+    }
+    let mut builder = rayon::ThreadPoolBuilder::new();
+    if let Some(threads) = config.threads {
+        builder = builder.num_threads(threads);
+    }
+    if let Some(stack_size) = config.stack_size {
+        builder = builder.stack_size(stack_size);
+    }
+    if builder.build_global().is_err()
+        && config
+            .threads
+            .is_some_and(|threads| threads != rayon::current_num_threads())
+    {
+        tracing::warn!(
+            requested_threads = config.threads.unwrap_or_default(),
+            actual_threads = rayon::current_num_threads(),
+            "Rayon pool already initialized"
+        );
+    }
+}
+
+impl Drop for RuntimeGuard {
+    fn drop(&mut self) {
+        for (key, previous) in self.previous_env.drain(..) {
+            // SAFETY: see `apply`.
+            unsafe {
+                match previous {
+                    Some(value) => env::set_var(&key, value),
+                    None => env::remove_var(&key),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Soak (stability) runs: proving the same input over and over for a fixed wall-clock time, to
+//! surface leaks, throughput drift and intermittent failures that single-shot runs never hit.
+//!
+//! With [`SOAK_SECS_ENV`] set, the harness re-executes the bench binary once per size (see
+//! `harness::child`). The child prepares once, then proves until the time is up, sampling its
+//! resident memory after every proof. A panicking prove is counted and the loop continues; a
+//! child that dies is recorded as a failure.
+
+use super::{BenchmarkSuite, child};
+use crate::bench::failure::ProverFailure;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Wall-clock time to keep proving each size, in seconds; unset or `0` disables the run.
+pub const SOAK_SECS_ENV: &str = "BENCH_SOAK_SECS";
+
+const CHILD_SIZE_ENV: &str = "BENCH_SOAK_CHILD_SIZE";
+
+const RESULT_PREFIX: &str = "BENCH_SOAK_RESULT ";
+
+/// Share of the proofs averaged at the start and at the end to compute the drift.
+const WINDOW_FRACTION: f64 = 0.1;
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Soak {
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub duration: Duration,
+    /// Completed proofs.
+    pub iterations: usize,
+    /// Proofs that panicked.
+    pub failed_iterations: usize,
+    /// Panic message of the first failed proof.
+    pub first_failure: Option<String>,
+    /// Mean proving time of the first and of the last tenth of the proofs.
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub first_window_proof_duration: Option<Duration>,
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub last_window_proof_duration: Option<Duration>,
+    /// Relative change of the proving time from the first to the last window, e.g. `0.05` for
+    /// proofs getting 5% slower.
+    pub throughput_drift: Option<f64>,
+    /// Resident memory after the first and after the last proof, in bytes (Linux only).
+    pub first_rss: Option<u64>,
+    pub last_rss: Option<u64>,
+    /// `last_rss - first_rss`; negative if memory was released.
+    pub rss_growth: Option<i64>,
+}
+
+pub fn soak_duration() -> Option<Duration> {
+    env::var(SOAK_SECS_ENV)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .filter(|&secs| secs > 0)
+        .map(Duration::from_secs)
+}
+
+/// In a re-executed child, proves until the soak time is up and exits. No-op otherwise.
+pub(crate) fn run_child_if_requested<S: BenchmarkSuite>(suite: &S) {
+    let Some(size) = child::requested_size(CHILD_SIZE_ENV) else {
+        return;
+    };
+    let duration = soak_duration().expect("soak child started without a soak duration");
+
+    let prepared = child::expect_step("prepare", suite.prepare(size));
+    let mut proof_durations = Vec::new();
+    let mut rss = Vec::new();
+    let mut failed_iterations = 0;
+    let mut first_failure = None;
+    let start = Instant::now();
+    while start.elapsed() < duration {
+        let proof_start = Instant::now();
+        match panic::catch_unwind(AssertUnwindSafe(|| suite.prove(&prepared))) {
+            Ok(Ok(_proof)) => proof_durations.push(proof_start.elapsed()),
+            Ok(Err(error)) => {
+                failed_iterations += 1;
+                first_failure = first_failure.or_else(|| Some(error.to_string()));
+            }
+            Err(payload) => {
+                failed_iterations += 1;
+                first_failure = first_failure.or_else(|| child::panic_message(payload.as_ref()));
+            }
+        }
+        rss.extend(current_rss());
+    }
+
+    child::report(
+        RESULT_PREFIX,
+        &summarize(
+            start.elapsed(),
+            &proof_durations,
+            &rss,
+            failed_iterations,
+            first_failure,
+        ),
+    );
+}
+
+fn summarize(
+    duration: Duration,
+    proof_durations: &[Duration],
+    rss: &[u64],
+    failed_iterations: usize,
+    first_failure: Option<String>,
+) -> Soak {
+    let window = ((proof_durations.len() as f64 * WINDOW_FRACTION).ceil() as usize).max(1);
+    let mean = |durations: &[Duration]| {
+        (!durations.is_empty()).then(|| durations.iter().sum::<Duration>() / durations.len() as u32)
+    };
+    let first_window = mean(&proof_durations[..window.min(proof_durations.len())]);
+    let last_window = mean(&proof_durations[proof_durations.len().saturating_sub(window)..]);
+    let (first_rss, last_rss) = (rss.first().copied(), rss.last().copied());
+
+    Soak {
+        duration,
+        iterations: proof_durations.len(),
+        failed_iterations,
+        first_failure,
+        first_window_proof_duration: first_window,
+        last_window_proof_duration: last_window,
+        throughput_drift: first_window
+            .zip(last_window)
+            .map(|(first, last)| last.as_secs_f64() / first.as_secs_f64() - 1.0),
+        first_rss,
+        last_rss,
+        rss_growth: first_rss
+            .zip(last_rss)
+            .map(|(first, last)| last as i64 - first as i64),
+    }
+}
+
+/// Current resident set size of this process.
+#[cfg(target_os = "linux")]
+fn current_rss() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn current_rss() -> Option<u64> {
+    None
+}
+
+/// Re-executes the current bench binary to soak `size`.
+pub(crate) fn measure(size: usize) -> Result<Soak, ProverFailure> {
+    child::spawn("soak", CHILD_SIZE_ENV, size, RESULT_PREFIX, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_drift_and_growth() {
+        let durations: Vec<Duration> = (0..20).map(|i| Duration::from_millis(100 + i)).collect();
+        let rss = [1000, 1500, 3000];
+        let soak = summarize(Duration::from_secs(3), &durations, &rss, 1, None);
+        assert_eq!(soak.iterations, 20);
+        // Windows of two proofs: 100/101 ms and 118/119 ms.
+        assert_eq!(
+            soak.first_window_proof_duration,
+            Some(Duration::from_micros(100_500))
+        );
+        assert!((soak.throughput_drift.unwrap() - 18.0 / 100.5).abs() < 1e-9);
+        assert_eq!(soak.rss_growth, Some(2000));
+
+        let empty = summarize(Duration::from_secs(3), &[], &[], 2, None);
+        assert_eq!(empty.throughput_drift, None);
+        assert_eq!(empty.rss_growth, None);
+    }
+}
@@ -0,0 +1,244 @@
+//! Phase-level timing through `tracing`.
+//!
+//! The harness wraps the prepare, prove and verify calls of the metrics pass in `tracing` spans
+//! and installs a subscriber ([`init`]) that prints harness events to stderr and, while a metrics
+//! pass is being recorded, aggregates every closed span by its path: the harness phases and the
+//! spans the proving system emits itself (e.g. `prove/generate_trace`). The aggregate is written
+//! to `{id}_spans.json` next to the metrics file, so phases can be compared without changing
+//! any system crate.
+//!
+//! Spans named after the elliptic-curve and polynomial kernels (`msm`, `fft`/`ntt`, matched
+//! case-insensitively anywhere in the name, e.g. `VariableBaseMSM::msm`) are also summed into the
+//! [`ProvePhases`] of the metrics. Backends that emit no spans (e.g. rapidsnark, which is C++)
+//! report none; a system crate that runs these kernels itself can wrap them in
+//! `tracing::info_span!("msm")`.
+
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tracing::span::{Attributes, Id};
+use tracing::{Level, Subscriber};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{Registry, fmt};
+
+/// Aggregated timing of all spans with the same path.
+#[serde_as]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SpanTiming {
+    /// Span names from the outermost span, joined by `/`.
+    pub path: String,
+    pub count: u64,
+    /// Time spent inside the spans, summed.
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub busy: Duration,
+    /// Time from creation to close of the spans, summed.
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub elapsed: Duration,
+}
+
+/// Time proving spent in the MSM and FFT kernels, as busy time summed over all threads, so it can
+/// exceed the proving wall-clock time when the kernels run in parallel.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProvePhases {
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub msm: Option<Duration>,
+    /// FFTs and NTTs.
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub fft: Option<Duration>,
+}
+
+const MSM_PATTERNS: &[&str] = &["msm"];
+const FFT_PATTERNS: &[&str] = &["fft", "ntt"];
+
+/// Harness phases whose spans are not part of proving.
+const NON_PROVING_ROOTS: &[&str] = &["prepare", "verify"];
+
+/// Sums the MSM and FFT spans of the proving phase. A matching span nested in another span of the
+/// same kind is not counted twice. Spans opened on worker threads without a parent are counted
+/// too. `None` if no span matched.
+pub fn prove_phases(spans: &[SpanTiming]) -> Option<ProvePhases> {
+    let sum = |patterns: &[&str]| {
+        let matches = |name: &str| {
+            let name = name.to_ascii_lowercase();
+            patterns.iter().any(|pattern| name.contains(pattern))
+        };
+        spans
+            .iter()
+            .filter(|span| {
+                let root = span.path.split('/').next().unwrap_or_default();
+                let (ancestors, name) = span.path.rsplit_once('/').unwrap_or(("", &span.path));
+                !NON_PROVING_ROOTS.contains(&root)
+                    && matches(name)
+                    && !ancestors.split('/').any(matches)
+            })
+            .map(|span| span.busy)
+            .reduce(|total, busy| total + busy)
+    };
+    let phases = ProvePhases {
+        msm: sum(MSM_PATTERNS),
+        fft: sum(FFT_PATTERNS),
+    };
+    (phases != ProvePhases::default()).then_some(phases)
+}
+
+static CAPTURING: AtomicBool = AtomicBool::new(false);
+static CAPTURED: Mutex<BTreeMap<String, SpanTiming>> = Mutex::new(BTreeMap::new());
+
+/// Per-span state kept in the registry's extensions.
+struct Timing {
+    path: String,
+    created: Instant,
+    entered: Option<Instant>,
+    busy: Duration,
+}
+
+/// Layer aggregating closed spans into [`CAPTURED`] while capturing.
+struct SpanTimingLayer;
+
+impl<S> Layer<S> for SpanTimingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let path = match span.parent() {
+            Some(parent) => match parent.extensions().get::<Timing>() {
+                Some(timing) => format!("{}/{}", timing.path, attrs.metadata().name()),
+                None => attrs.metadata().name().to_string(),
+            },
+            None => attrs.metadata().name().to_string(),
+        };
+        span.extensions_mut().insert(Timing {
+            path,
+            created: Instant::now(),
+            entered: None,
+            busy: Duration::ZERO,
+        });
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id)
+            && let Some(timing) = span.extensions_mut().get_mut::<Timing>()
+        {
+            timing.entered = Some(Instant::now());
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id)
+            && let Some(timing) = span.extensions_mut().get_mut::<Timing>()
+            && let Some(entered) = timing.entered.take()
+        {
+            timing.busy += entered.elapsed();
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if !CAPTURING.load(Ordering::Relaxed) {
+            return;
+        }
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<Timing>() else {
+            return;
+        };
+        let mut captured = CAPTURED.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = captured
+            .entry(timing.path.clone())
+            .or_insert_with(|| SpanTiming {
+                path: timing.path.clone(),
+                ..Default::default()
+            });
+        entry.count += 1;
+        entry.busy += timing.busy;
+        entry.elapsed += timing.created.elapsed();
+    }
+}
+
+/// Installs the global subscriber: harness events at `INFO` and other crates' at `WARN` go to
+/// stderr, and `INFO` spans are timed. A no-op if a subscriber is already installed.
+pub fn init() {
+    let events = fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_target(false)
+        .with_filter(
+            Targets::new()
+                .with_target("utils", Level::INFO)
+                .with_default(Level::WARN),
+        );
+    let subscriber = Registry::default()
+        .with(events)
+        .with(SpanTimingLayer.with_filter(LevelFilter::INFO));
+    let _ = tracing::subscriber::set_global_default(subscriber);
+}
+
+/// Starts aggregating closed spans, discarding anything captured before.
+pub(crate) fn start_capture() {
+    CAPTURED.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    CAPTURING.store(true, Ordering::Relaxed);
+}
+
+/// Stops aggregating and returns the spans closed since [`start_capture`], ordered by path.
+pub(crate) fn finish_capture() -> Vec<SpanTiming> {
+    CAPTURING.store(false, Ordering::Relaxed);
+    std::mem::take(&mut *CAPTURED.lock().unwrap_or_else(|e| e.into_inner()))
+        .into_values()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_spans_are_aggregated_by_path() {
+        let subscriber = Registry::default().with(SpanTimingLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("before").in_scope(|| {});
+            start_capture();
+            tracing::info_span!("prove").in_scope(|| {
+                for _ in 0..3 {
+                    tracing::info_span!("commit").in_scope(|| {});
+                }
+            });
+        });
+        let spans = finish_capture();
+        let paths: Vec<(&str, u64)> = spans.iter().map(|s| (s.path.as_str(), s.count)).collect();
+        assert_eq!(paths, [("prove", 1), ("prove/commit", 3)]);
+        assert!(spans[0].busy >= spans[1].busy);
+    }
+
+    #[test]
+    fn test_prove_phases_count_outermost_kernel_spans() {
+        let span = |path: &str, busy_ms| SpanTiming {
+            path: path.to_string(),
+            count: 1,
+            busy: Duration::from_millis(busy_ms),
+            elapsed: Duration::from_millis(busy_ms),
+        };
+        let spans = [
+            span("prepare/msm", 100),
+            span("prove", 1000),
+            span("prove/commit/VariableBaseMSM::msm", 300),
+            span("prove/commit/VariableBaseMSM::msm/msm_bucket", 200),
+            span("prove/quotient/coset_fft", 50),
+            span("ntt_worker", 20),
+            span("verify/msm", 10),
+        ];
+        let phases = prove_phases(&spans).unwrap();
+        assert_eq!(phases.msm, Some(Duration::from_millis(300)));
+        assert_eq!(phases.fft, Some(Duration::from_millis(70)));
+        assert_eq!(prove_phases(&spans[..2]), None);
+    }
+}
@@ -0,0 +1,75 @@
+//! Bulk verification: the amortized cost of verifying K proofs, as sequencers and bridges do.
+//!
+//! With [`VERIFY_BATCH_ENV`] set to K, the metrics pass verifies its proof K times one after
+//! another, and once more as a batch of K through `BenchmarkSuite::verify_batch` if the system
+//! supports batch verification (e.g. Groth16 with a random linear combination of the pairing
+//! checks, or batched FRI openings). The batch holds K copies of the same proof, which costs the
+//! batch verifier as much as K distinct proofs.
+
+use super::BenchmarkSuite;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::env;
+use std::time::{Duration, Instant};
+
+/// Number of proofs verified in bulk; unset or `0` disables the measurement.
+pub const VERIFY_BATCH_ENV: &str = "BENCH_VERIFY_BATCH";
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct VerifyBatch {
+    pub batch_size: usize,
+    /// Verifying the proofs one by one.
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub sequential_verify_duration: Duration,
+    /// Verifying them as one batch; `None` if the system has no batch verification.
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub batch_verify_duration: Option<Duration>,
+    /// Per-proof time of the faster of the two.
+    #[serde_as(as = "DurationNanoSeconds")]
+    pub amortized_verify_duration: Duration,
+    /// `sequential_verify_duration / batch_verify_duration`.
+    pub speedup: Option<f64>,
+}
+
+pub fn batch_size() -> Option<usize> {
+    env::var(VERIFY_BATCH_ENV)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .filter(|&k| k > 0)
+}
+
+pub(crate) fn measure<S: BenchmarkSuite>(
+    suite: &S,
+    prepared: &S::Prepared,
+    proof: &S::Proof,
+    batch_size: usize,
+) -> VerifyBatch {
+    let start = Instant::now();
+    for _ in 0..batch_size {
+        // The proof already verified in the metrics pass.
+        let _ = suite.verify(prepared, proof);
+    }
+    let sequential = start.elapsed();
+
+    let proofs = vec![proof; batch_size];
+    let start = Instant::now();
+    let batch = match suite.verify_batch(prepared, &proofs) {
+        Some(Ok(())) => Some(start.elapsed()),
+        Some(Err(error)) => {
+            tracing::warn!("Batch verification failed: {error}");
+            None
+        }
+        None => None,
+    };
+
+    VerifyBatch {
+        batch_size,
+        sequential_verify_duration: sequential,
+        batch_verify_duration: batch,
+        amortized_verify_duration: batch.map_or(sequential, |batch| batch.min(sequential))
+            / batch_size as u32,
+        speedup: batch.map(|batch| sequential.as_secs_f64() / batch.as_secs_f64()),
+    }
+}
@@ -0,0 +1,136 @@
+//! Light-client verification profile: verification on a single thread with a memory budget,
+//! approximating what a browser, phone or light client could afford.
+//!
+//! With [`VERIFY_BUDGET_ENV`] set, the harness re-executes the bench binary once per size (see
+//! `harness::child`) with `RAYON_NUM_THREADS=1`. The child prepares and proves, then caps its
+//! address space at the current size plus the budget (`RLIMIT_AS`, Linux only) and verifies.
+//! A verifier that panics, or aborts on a failed allocation, is recorded as unsuccessful.
+
+use super::{BenchmarkSuite, child};
+use crate::bench::failure::ProverFailure;
+use serde::{Deserialize, Serialize};
+use serde_with::{DurationNanoSeconds, serde_as, skip_serializing_none};
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Enables the light-client verification profile when set to `1`/`true`.
+pub const VERIFY_BUDGET_ENV: &str = "BENCH_VERIFY_BUDGET";
+
+/// Memory budget of the verifier in MiB; defaults to [`DEFAULT_MEMORY_BUDGET_MB`].
+pub const MEMORY_BUDGET_ENV: &str = "BENCH_VERIFY_BUDGET_MB";
+
+pub const DEFAULT_MEMORY_BUDGET_MB: u64 = 512;
+
+const CHILD_SIZE_ENV: &str = "BENCH_VERIFY_BUDGET_CHILD_SIZE";
+
+const RESULT_PREFIX: &str = "BENCH_VERIFY_BUDGET_RESULT ";
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct VerifyBudget {
+    pub threads: usize,
+    /// Memory available to the verifier, in bytes. `None` if it could not be enforced.
+    pub memory_budget: Option<u64>,
+    pub success: bool,
+    /// Verification time; `None` if verification did not complete.
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub verify_duration: Option<Duration>,
+}
+
+pub fn enabled() -> bool {
+    child::flag_enabled(VERIFY_BUDGET_ENV)
+}
+
+fn memory_budget_bytes() -> u64 {
+    env::var(MEMORY_BUDGET_ENV)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_MEMORY_BUDGET_MB)
+        * 1024
+        * 1024
+}
+
+/// In a re-executed child, verifies under the budget and exits the process. No-op otherwise.
+pub(crate) fn run_child_if_requested<S: BenchmarkSuite>(suite: &S) {
+    let Some(size) = child::requested_size(CHILD_SIZE_ENV) else {
+        return;
+    };
+
+    let prepared = child::expect_step("prepare", suite.prepare(size));
+    let proof = child::expect_step("prove", suite.prove(&prepared));
+    let memory_budget = limit_address_space(memory_budget_bytes());
+
+    let start = Instant::now();
+    let verified = panic::catch_unwind(AssertUnwindSafe(|| suite.verify(&prepared, &proof)))
+        .is_ok_and(|result| result.is_ok());
+    let verify_duration = start.elapsed();
+
+    child::report(
+        RESULT_PREFIX,
+        &VerifyBudget {
+            threads: 1,
+            memory_budget,
+            success: verified,
+            verify_duration: verified.then_some(verify_duration),
+        },
+    );
+}
+
+/// Re-executes the current bench binary to verify a proof of `size` under the budget.
+/// A child that dies without reporting (e.g. out of memory) counts as a failed verification;
+/// the failure record tells why.
+pub(crate) fn measure(size: usize) -> (VerifyBudget, Option<ProverFailure>) {
+    match child::spawn(
+        "light_client_verify",
+        CHILD_SIZE_ENV,
+        size,
+        RESULT_PREFIX,
+        &[("RAYON_NUM_THREADS", "1".to_string())],
+    ) {
+        Ok(budget) => (budget, None),
+        Err(failure) => (
+            VerifyBudget {
+                threads: 1,
+                memory_budget: Some(memory_budget_bytes()),
+                success: false,
+                verify_duration: None,
+            },
+            Some(failure),
+        ),
+    }
+}
+
+/// Caps the address space at its current size plus `budget`. Returns the budget if enforced.
+#[cfg(target_os = "linux")]
+fn limit_address_space(budget: u64) -> Option<u64> {
+    // First field of statm: total program size in pages.
+    let pages: u64 = std::fs::read_to_string("/proc/self/statm")
+        .ok()?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    // SAFETY: sysconf has no preconditions.
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+    let limit = pages * page_size + budget;
+    let rlimit = libc::rlimit {
+        rlim_cur: limit,
+        rlim_max: libc::RLIM_INFINITY,
+    };
+    // SAFETY: `rlimit` is a valid, initialized struct.
+    if unsafe { libc::setrlimit(libc::RLIMIT_AS, &rlimit) } != 0 {
+        tracing::warn!(
+            "Failed to limit verifier memory: {}",
+            std::io::Error::last_os_error()
+        );
+        return None;
+    }
+    Some(budget)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn limit_address_space(_budget: u64) -> Option<u64> {
+    None
+}
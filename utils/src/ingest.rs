@@ -0,0 +1,193 @@
+//! Validation of metrics files produced outside this repository's CI.
+//!
+//! `collect_benchmarks ingest` merges contributor-submitted metrics files into
+//! `collected_benchmarks.json`, tagged as third-party. Before that, [`validate`] checks that each
+//! file measured a system and target this repository benchmarks, on the golden input this
+//! version of `utils` generates, with the upstream version the dataset already records for the
+//! system, and that the measurement is complete.
+
+use crate::attestation;
+use crate::bench::Metrics;
+use crate::harness::{BenchProperties, BenchTarget, ProvingSystem};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Why a submitted measurement is not merged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// Neither a known proving system nor a system the dataset already has.
+    UnknownSystem(String),
+    UnknownTarget(String),
+    /// The file does not record the hash of its input: it predates `input_hash`, or was not
+    /// written by the harness.
+    MissingInputHash,
+    /// The input differs from the golden input, e.g. because `BENCH_SEED` or `BENCH_CORPUS` was
+    /// set, or the contributor's input generation has drifted.
+    InputHashMismatch {
+        expected: String,
+        found: String,
+    },
+    /// The dataset records a different upstream version for the system.
+    VersionMismatch {
+        expected: Option<String>,
+        found: Option<String>,
+    },
+    /// A metric the harness always fills in is missing, and no failure explains why.
+    Incomplete(&'static str),
+    /// Timed from a single run in quick mode (see `harness::quick`) rather than sampled.
+    LowConfidence,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let or_none = |version: &Option<String>| version.clone().unwrap_or("none".to_string());
+        match self {
+            Rejection::UnknownSystem(system) => write!(f, "unknown system {system}"),
+            Rejection::UnknownTarget(target) => write!(f, "unknown target {target}"),
+            Rejection::MissingInputHash => write!(f, "no input_hash recorded"),
+            Rejection::InputHashMismatch { expected, found } => {
+                write!(f, "input hash {found} is not the golden {expected}")
+            }
+            Rejection::VersionMismatch { expected, found } => write!(
+                f,
+                "version {} differs from the dataset's {}",
+                or_none(found),
+                or_none(expected)
+            ),
+            Rejection::Incomplete(field) => write!(f, "{field} is missing"),
+            Rejection::LowConfidence => write!(f, "timed from a single quick-mode run"),
+        }
+    }
+}
+
+/// Checks a submitted measurement against the `systems` of the dataset it is merged into, keyed
+/// by system key (`jolt_gpu`). Empty if it can be merged.
+pub fn validate(
+    metrics: &Metrics,
+    system_key: &str,
+    systems: &BTreeMap<String, BenchProperties>,
+) -> Vec<Rejection> {
+    let mut rejections = Vec::new();
+    let known = systems.get(system_key);
+    if known.is_none() && metrics.name.parse::<ProvingSystem>().is_err() {
+        rejections.push(Rejection::UnknownSystem(metrics.name.clone()));
+    }
+    if metrics.target.parse::<BenchTarget>().is_err() {
+        rejections.push(Rejection::UnknownTarget(metrics.target.clone()));
+    } else if let Some(expected) = attestation::input_hash(&metrics.target, metrics.input_size) {
+        match &metrics.input_hash {
+            None => rejections.push(Rejection::MissingInputHash),
+            Some(found) if *found != expected => rejections.push(Rejection::InputHashMismatch {
+                expected,
+                found: found.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    if let Some(known) = known
+        && known.version != metrics.bench_properties.version
+    {
+        rejections.push(Rejection::VersionMismatch {
+            expected: known.version.as_deref().map(str::to_string),
+            found: metrics
+                .bench_properties
+                .version
+                .as_deref()
+                .map(str::to_string),
+        });
+    }
+
+    // A crashed prover legitimately leaves these empty.
+    if metrics.failures.is_empty() {
+        if metrics.proof_duration.is_zero() {
+            rejections.push(Rejection::Incomplete("proof_duration"));
+        }
+        if metrics.verify_duration.is_zero() {
+            rejections.push(Rejection::Incomplete("verify_duration"));
+        }
+        if metrics.peak_memory == 0 {
+            rejections.push(Rejection::Incomplete("peak_memory"));
+        }
+    }
+    if metrics.low_confidence {
+        rejections.push(Rejection::LowConfidence);
+    }
+    rejections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::time::Duration;
+
+    fn submitted(version: Option<&'static str>) -> Metrics {
+        let mut metrics = Metrics::new(
+            "sp1".to_string(),
+            None,
+            "sha256".to_string(),
+            128,
+            BenchProperties {
+                version: version.map(Cow::Borrowed),
+                ..Default::default()
+            },
+        );
+        metrics.input_hash = attestation::input_hash("sha256", 128);
+        metrics.proof_duration = Duration::from_millis(100);
+        metrics.verify_duration = Duration::from_millis(1);
+        metrics.peak_memory = 1 << 20;
+        metrics
+    }
+
+    #[test]
+    fn test_complete_golden_measurement_is_accepted() {
+        let systems = BTreeMap::from([(
+            "sp1".to_string(),
+            BenchProperties {
+                version: Some(Cow::Borrowed("5.0.0")),
+                ..Default::default()
+            },
+        )]);
+        assert!(validate(&submitted(Some("5.0.0")), "sp1", &systems).is_empty());
+        // A system the dataset does not have yet only has to be one this repository benchmarks.
+        assert!(validate(&submitted(None), "sp1", &BTreeMap::new()).is_empty());
+
+        assert_eq!(
+            validate(&submitted(Some("4.2.0")), "sp1", &systems),
+            [Rejection::VersionMismatch {
+                expected: Some("5.0.0".to_string()),
+                found: Some("4.2.0".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_bad_submissions_are_rejected() {
+        let mut metrics = submitted(None);
+        metrics.name = "unknown-vm".to_string();
+        metrics.input_hash = Some("00".to_string());
+        metrics.peak_memory = 0;
+        let rejections = validate(&metrics, "unknown-vm", &BTreeMap::new());
+        assert_eq!(rejections.len(), 3);
+        assert_eq!(
+            rejections[0],
+            Rejection::UnknownSystem("unknown-vm".to_string())
+        );
+        assert!(matches!(rejections[1], Rejection::InputHashMismatch { .. }));
+        assert_eq!(rejections[2], Rejection::Incomplete("peak_memory"));
+
+        metrics.input_hash = None;
+        metrics.failures.push(Default::default());
+        assert_eq!(
+            validate(&metrics, "unknown-vm", &BTreeMap::new())[1..],
+            [Rejection::MissingInputHash]
+        );
+
+        let mut quick = submitted(None);
+        quick.low_confidence = true;
+        assert_eq!(
+            validate(&quick, "sp1", &BTreeMap::new()),
+            [Rejection::LowConfidence]
+        );
+    }
+}
@@ -7,18 +7,79 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+pub mod anonymize;
+pub mod artifacts;
+pub mod attestation;
 pub mod bench;
+pub mod bench_id;
+pub mod blake2s;
+pub mod bump;
+pub mod callgrind;
+pub mod caveat;
+pub mod compression;
+pub mod corpus;
+pub mod cost;
+pub mod coverage;
+pub mod criterion_layout;
+pub mod cycles;
+pub mod entropy;
+pub mod environment;
+pub mod features;
 pub mod harness;
+pub mod ingest;
 pub mod ligetron;
+pub mod manifest;
+pub mod matrix;
+pub mod merkle;
 pub mod metadata;
+pub mod multisig;
+pub mod noir;
+pub mod proof_size;
+pub mod receipt;
+pub mod results;
+pub mod sandbox;
+pub mod scaling;
+pub mod schedule;
+pub mod security;
+pub mod variance;
+pub mod verification;
+pub mod witness_size;
 pub mod zkvm;
 
 use k256::ecdsa::{Signature as K256Signature, SigningKey as K256SigningKey};
 use p256::ecdsa::{Signature, SigningKey, signature::hazmat::PrehashSigner};
 
+pub use bench_id::BenchId;
 pub use harness::{BenchHarnessConfig, BenchTarget, ProvingSystem};
 
-use crate::metadata::{selected_byte_inputs, selected_field_element_inputs};
+use crate::harness::invalid_input::{self, InputKind};
+
+#[cfg(feature = "divan")]
+#[doc(hidden)]
+pub use divan as __divan;
+
+use crate::metadata::{
+    selected_byte_inputs, selected_field_element_inputs, selected_keccak_inputs,
+    selected_merkle_update_leaves, selected_multisig_signers, selected_receipt_counts,
+};
+
+/// Seed of the ECDSA signing keys; other inputs are seeded with their input size.
+pub const ECDSA_KEY_SEED: u64 = 0xecd5a;
+
+/// Run seed mixed into every input seed, so that repeated runs (see `repeat_benchmarks.sh`) prove
+/// different inputs. Unset or `0` keeps the canonical inputs.
+pub const SEED_ENV: &str = "BENCH_SEED";
+
+pub fn run_seed() -> u64 {
+    std::env::var(SEED_ENV)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0)
+}
+
+fn input_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed ^ run_seed().rotate_left(32))
+}
 
 pub fn write_json<T: Serialize>(data: &T, output_path: &str) {
     let json_data = serde_json::to_string_pretty(&data).expect("Failed to serialize to JSON");
@@ -29,11 +90,29 @@ pub fn write_json<T: Serialize>(data: &T, output_path: &str) {
         .expect("Failed to write to file");
 }
 
-/// Generate a random message of `input_size` bytes and its sha256 digest.
-pub fn generate_sha256_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
+fn generate_message(input_size: usize) -> Vec<u8> {
+    if let Some(sample) = corpus::selected() {
+        return sample.message(input_size);
+    }
     let mut message_bytes = vec![0u8; input_size];
-    let mut rng = StdRng::seed_from_u64(input_size as u64);
+    let mut rng = input_rng(input_size as u64);
     rng.fill_bytes(&mut message_bytes);
+    message_bytes
+}
+
+/// Generate a message of `input_size` bytes and its sha256 digest. The message is random
+/// unless a real-world sample is selected (see [`corpus::CORPUS_ENV`]).
+/// In the invalid-input child (see [`harness::invalid_input`]) the digest is wrong.
+pub fn generate_sha256_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
+    let (message_bytes, mut digest_bytes) = sha256_input(input_size);
+    if InputKind::selected() == InputKind::Invalid {
+        invalid_input::corrupt(&mut digest_bytes);
+    }
+    (message_bytes, digest_bytes)
+}
+
+fn sha256_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
+    let message_bytes = generate_message(input_size);
 
     let mut hasher = Sha256::new();
     hasher.update(&message_bytes);
@@ -41,20 +120,35 @@ pub fn generate_sha256_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
     (message_bytes, digest_bytes)
 }
 
-/// Generate a random message of `input_size` bytes and its keccak256 digest.
+/// Generate a message of `input_size` bytes and its keccak256 digest. The message is random
+/// unless a real-world sample is selected (see [`corpus::CORPUS_ENV`]).
+/// In the invalid-input child (see [`harness::invalid_input`]) the digest is wrong.
 pub fn generate_keccak_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
-    let mut message_bytes = vec![0u8; input_size];
-    let mut rng = StdRng::seed_from_u64(input_size as u64);
-    rng.fill_bytes(&mut message_bytes);
+    let message_bytes = generate_message(input_size);
 
     let mut hasher = Keccak256::new();
     hasher.update(&message_bytes);
-    let digest_bytes = hasher.finalize().to_vec();
+    let mut digest_bytes = hasher.finalize().to_vec();
+    if InputKind::selected() == InputKind::Invalid {
+        invalid_input::corrupt(&mut digest_bytes);
+    }
+    (message_bytes, digest_bytes)
+}
+
+/// Generate a message of `input_size` bytes and its BLAKE2s-256 digest. The message is random
+/// unless a real-world sample is selected (see [`corpus::CORPUS_ENV`]).
+/// In the invalid-input child (see [`harness::invalid_input`]) the digest is wrong.
+pub fn generate_blake2s_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
+    let message_bytes = generate_message(input_size);
+    let mut digest_bytes = blake2s::blake2s(&message_bytes).to_vec();
+    if InputKind::selected() == InputKind::Invalid {
+        invalid_input::corrupt(&mut digest_bytes);
+    }
     (message_bytes, digest_bytes)
 }
 
 pub fn generate_poseidon_input(input_size: usize) -> Vec<[u8; 32]> {
-    let mut rng = StdRng::seed_from_u64(input_size as u64);
+    let mut rng = input_rng(input_size as u64);
 
     (0..input_size)
         .map(|_| {
@@ -74,14 +168,14 @@ pub fn generate_poseidon_input_strings(input_size: usize) -> Vec<String> {
 }
 
 pub fn generate_poseidon_input_m31(input_size: usize) -> Vec<u32> {
-    let mut rng = StdRng::seed_from_u64(input_size as u64);
+    let mut rng = input_rng(input_size as u64);
     let m31_mod: u32 = (1 << 31) - 1;
 
     (0..input_size).map(|_| rng.next_u32() % m31_mod).collect()
 }
 
 pub fn generate_poseidon_input_goldilocks(input_size: usize) -> Vec<u64> {
-    let mut rng = StdRng::seed_from_u64(input_size as u64);
+    let mut rng = input_rng(input_size as u64);
     const GOLDILOCKS_PRIME: u64 = 0xFFFFFFFF00000001;
 
     (0..input_size)
@@ -89,58 +183,190 @@ pub fn generate_poseidon_input_goldilocks(input_size: usize) -> Vec<u64> {
         .collect()
 }
 
+/// Bytes packed into each BN254 element of the Poseidon sponge input; 31 bytes stay below the
+/// 254-bit modulus.
+pub const POSEIDON_SPONGE_BN254_BYTES_PER_ELEMENT: usize = 31;
+
+/// Bytes packed into each Goldilocks element of the Poseidon sponge input.
+pub const POSEIDON_SPONGE_GOLDILOCKS_BYTES_PER_ELEMENT: usize = 7;
+
+/// Elements absorbed per permutation by the BN254 sponge, which chains circomlib's `Poseidon(12)`
+/// over the previous digest and the next 11 elements (the last block zero-padded), starting from
+/// a zero digest.
+pub const POSEIDON_SPONGE_BN254_RATE: usize = 11;
+
+/// Generate an inclusion proof of a receipt in a block of `num_receipts` receipts and the log
+/// fields the guest extracts from it (see [`receipt`]).
+/// In the invalid-input child (see [`harness::invalid_input`]) the expected fields are wrong.
+pub fn generate_receipt_proof_input(num_receipts: usize) -> (receipt::ReceiptProofInput, Vec<u8>) {
+    let (input, mut expected) =
+        receipt::generate(num_receipts, &mut input_rng(num_receipts as u64));
+    if InputKind::selected() == InputKind::Invalid {
+        invalid_input::corrupt(&mut expected);
+    }
+    (input, expected)
+}
+
+/// Generate a batch of `num_updates` leaf updates of a Poseidon Merkle tree (see [`merkle`]).
+/// In the invalid-input child (see [`harness::invalid_input`]) the claimed new root is wrong.
+pub fn generate_merkle_update_input(num_updates: usize) -> merkle::MerkleBatchUpdate {
+    let mut batch = merkle::generate(num_updates, &mut input_rng(num_updates as u64));
+    if InputKind::selected() == InputKind::Invalid {
+        batch.new_root += ark_bn254::Fr::from(1u64);
+    }
+    batch
+}
+
+/// Generate the signatures of a committee of `num_signers` secp256k1 keys over the ECDSA target's
+/// digest (see [`multisig`]).
+/// In the invalid-input child (see [`harness::invalid_input`]) just enough signatures are bad
+/// that the valid ones fall one short of the threshold.
+pub fn generate_multisig_input(num_signers: usize) -> multisig::MultisigInput {
+    let (_message, digest) = sha256_input(ECDSA_MESSAGE_LEN);
+    let mut input = multisig::generate(num_signers, digest, &mut input_rng(ECDSA_KEY_SEED));
+    if InputKind::selected() == InputKind::Invalid {
+        let failing = num_signers - input.threshold as usize + 1;
+        for signature in &mut input.signatures[..failing] {
+            invalid_input::corrupt(signature);
+        }
+    }
+    input
+}
+
+/// Generate the message of `input_size` bytes hashed by the Poseidon sponge target. The message
+/// is random unless a real-world sample is selected (see [`corpus::CORPUS_ENV`]).
+///
+/// Every system hashes the same bytes: they are packed little-endian into field elements of
+/// the system's field ([`poseidon_sponge_elements_bn254`], [`poseidon_sponge_elements_goldilocks`])
+/// and absorbed by its Poseidon sponge, so the work grows with the message like a real hash.
+pub fn generate_poseidon_sponge_input(input_size: usize) -> Vec<u8> {
+    generate_message(input_size)
+}
+
+/// Number of field elements a message of `input_size` bytes is packed into: the chunks of
+/// `bytes_per_element` bytes, then the byte length, which keeps messages that only differ in
+/// trailing zero bytes apart.
+pub fn poseidon_sponge_num_elements(input_size: usize, bytes_per_element: usize) -> usize {
+    input_size.div_ceil(bytes_per_element) + 1
+}
+
+/// `message` packed into BN254 elements, as decimal strings for circuit inputs.
+pub fn poseidon_sponge_elements_bn254(message: &[u8]) -> Vec<String> {
+    message
+        .chunks(POSEIDON_SPONGE_BN254_BYTES_PER_ELEMENT)
+        .map(|chunk| BigUint::from_bytes_le(chunk).to_string())
+        .chain(std::iter::once(message.len().to_string()))
+        .collect()
+}
+
+/// `message` packed into Goldilocks elements.
+pub fn poseidon_sponge_elements_goldilocks(message: &[u8]) -> Vec<u64> {
+    message
+        .chunks(POSEIDON_SPONGE_GOLDILOCKS_BYTES_PER_ELEMENT)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(bytes)
+        })
+        .chain(std::iter::once(message.len() as u64))
+        .collect()
+}
+
+/// One ECDSA signature input: (digest, (pub_key_x, pub_key_y), signature).
+pub type EcdsaInput = (Vec<u8>, (Vec<u8>, Vec<u8>), Vec<u8>);
+
+/// Length of the message whose SHA-256 digest the `ecdsa` target signs.
+pub const ECDSA_MESSAGE_LEN: usize = 128;
+
 /// Generate secp256r1 (p256) ECDSA test input: (digest, (pub_key_x, pub_key_y), signature).
-#[allow(clippy::type_complexity)]
-pub fn generate_ecdsa_input() -> (Vec<u8>, (Vec<u8>, Vec<u8>), Vec<u8>) {
-    let mut rng = StdRng::seed_from_u64(0xecd5a);
-    let signing_key = SigningKey::random(&mut rng);
-    let verifying_key = signing_key.verifying_key().to_encoded_point(false);
-    let (pub_key_x, pub_key_y) = (
-        verifying_key.x().unwrap().to_vec(),
-        verifying_key.y().unwrap().to_vec(),
-    );
-
-    let (_message, digest) = generate_sha256_input(128);
-    let signature: Signature = signing_key
-        .sign_prehash(&digest)
-        .expect("Failed to sign prehashed digest");
-
-    // Normalize "s" of the signature because it is not normalized by default.
-    // More importantly, the "noir::std::ecdsa_secp256r1::verify_signature" expects "s" to be normalized.
-    // normalize_s() returns None if the signature is already normalized, in which case we keep the original.
-    let signature = signature.normalize_s().unwrap_or(signature);
-
-    (
-        digest,
-        (pub_key_x, pub_key_y),
-        signature.to_bytes().to_vec(),
-    )
+/// In the invalid-input child (see [`harness::invalid_input`]) the signature does not verify.
+pub fn generate_ecdsa_input() -> EcdsaInput {
+    generate_ecdsa_batch(ECDSA_MESSAGE_LEN, 1).remove(0)
+}
+
+/// Generate `count` secp256r1 (p256) signatures over `message_len`-byte messages (see
+/// [`ecdsa_batch`]).
+pub fn generate_ecdsa_batch(message_len: usize, count: usize) -> Vec<EcdsaInput> {
+    ecdsa_batch(message_len, count, |rng, digest| {
+        let signing_key = SigningKey::random(rng);
+        let verifying_key = signing_key.verifying_key().to_encoded_point(false);
+        let signature: Signature = signing_key
+            .sign_prehash(digest)
+            .expect("Failed to sign prehashed digest");
+
+        // Normalize "s" of the signature because it is not normalized by default.
+        // More importantly, the "noir::std::ecdsa_secp256r1::verify_signature" expects "s" to be normalized.
+        // normalize_s() returns None if the signature is already normalized, in which case we keep the original.
+        let signature = signature.normalize_s().unwrap_or(signature);
+        (
+            (
+                verifying_key.x().unwrap().to_vec(),
+                verifying_key.y().unwrap().to_vec(),
+            ),
+            signature.to_bytes().to_vec(),
+        )
+    })
 }
 
 /// Generate secp256k1 (k256) ECDSA test input: (digest, (pub_key_x, pub_key_y), signature).
-#[allow(clippy::type_complexity)]
-pub fn generate_ecdsa_k256_input() -> (Vec<u8>, (Vec<u8>, Vec<u8>), Vec<u8>) {
-    let mut rng = StdRng::seed_from_u64(0xecd5a);
-    let signing_key = K256SigningKey::random(&mut rng);
-    let verifying_key = signing_key.verifying_key().to_encoded_point(false);
-    let (pub_key_x, pub_key_y) = (
-        verifying_key.x().unwrap().to_vec(),
-        verifying_key.y().unwrap().to_vec(),
-    );
-
-    let (_message, digest) = generate_sha256_input(128);
-    let signature: K256Signature = signing_key
-        .sign_prehash(&digest)
-        .expect("Failed to sign prehashed digest");
-
-    // Normalize "s" of the signature because it is not normalized by default.
-    let signature = signature.normalize_s().unwrap_or(signature);
-
-    (
-        digest,
-        (pub_key_x, pub_key_y),
-        signature.to_bytes().to_vec(),
-    )
+/// In the invalid-input child (see [`harness::invalid_input`]) the signature does not verify.
+pub fn generate_ecdsa_k256_input() -> EcdsaInput {
+    generate_ecdsa_k256_batch(ECDSA_MESSAGE_LEN, 1).remove(0)
+}
+
+/// Generate `count` secp256k1 (k256) signatures over `message_len`-byte messages (see
+/// [`ecdsa_batch`]).
+pub fn generate_ecdsa_k256_batch(message_len: usize, count: usize) -> Vec<EcdsaInput> {
+    ecdsa_batch(message_len, count, |rng, digest| {
+        let signing_key = K256SigningKey::random(rng);
+        let verifying_key = signing_key.verifying_key().to_encoded_point(false);
+        let signature: K256Signature = signing_key
+            .sign_prehash(digest)
+            .expect("Failed to sign prehashed digest");
+
+        // Normalize "s" of the signature because it is not normalized by default.
+        let signature = signature.normalize_s().unwrap_or(signature);
+        (
+            (
+                verifying_key.x().unwrap().to_vec(),
+                verifying_key.y().unwrap().to_vec(),
+            ),
+            signature.to_bytes().to_vec(),
+        )
+    })
+}
+
+/// `count` signatures, each by its own key drawn from [`ECDSA_KEY_SEED`] over the SHA-256 digest
+/// of its own `message_len`-byte message. The first message is the one [`generate_message`]
+/// returns and the first key the one the single-signature generators always used, so a batch of
+/// one is their input. In the invalid-input child (see [`harness::invalid_input`]) the last
+/// signature does not verify.
+fn ecdsa_batch(
+    message_len: usize,
+    count: usize,
+    mut sign: impl FnMut(&mut StdRng, &[u8]) -> ((Vec<u8>, Vec<u8>), Vec<u8>),
+) -> Vec<EcdsaInput> {
+    let mut rng = input_rng(ECDSA_KEY_SEED);
+    let mut batch: Vec<EcdsaInput> = (0..count)
+        .map(|i| {
+            let message = if i == 0 {
+                generate_message(message_len)
+            } else {
+                let mut message = vec![0u8; message_len];
+                rng.fill_bytes(&mut message);
+                message
+            };
+            let digest = Sha256::digest(&message).to_vec();
+            let (public_key, signature) = sign(&mut rng, &digest);
+            (digest, public_key, signature)
+        })
+        .collect();
+    if InputKind::selected() == InputKind::Invalid
+        && let Some((_, _, signature)) = batch.last_mut()
+    {
+        invalid_input::corrupt(signature);
+    }
+    batch
 }
 
 pub fn generate_poseidon2_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
@@ -156,9 +382,15 @@ pub fn generate_poseidon2_input(input_size: usize) -> (Vec<u8>, Vec<u8>) {
 
 pub fn input_sizes_for(target: BenchTarget) -> Vec<usize> {
     match target {
-        BenchTarget::Sha256 | BenchTarget::Keccak => selected_byte_inputs(),
+        BenchTarget::Sha256 | BenchTarget::Blake2s | BenchTarget::PoseidonSponge => {
+            selected_byte_inputs()
+        }
+        BenchTarget::Keccak => selected_keccak_inputs(),
         BenchTarget::Ecdsa => vec![32],
         BenchTarget::Poseidon | BenchTarget::Poseidon2 => selected_field_element_inputs(),
+        BenchTarget::ReceiptProof => selected_receipt_counts(),
+        BenchTarget::MerkleUpdate => selected_merkle_update_leaves(),
+        BenchTarget::Multisig => selected_multisig_signers(),
     }
 }
 
@@ -227,4 +459,58 @@ mod tests {
         let input2 = generate_ecdsa_k256_input();
         assert_eq!(input1, input2);
     }
+
+    #[test]
+    fn test_ecdsa_batch_extends_the_single_input() {
+        use p256::ecdsa::signature::hazmat::PrehashVerifier;
+
+        let batch = generate_ecdsa_batch(ECDSA_MESSAGE_LEN, 3);
+        assert_eq!(batch[0], generate_ecdsa_input());
+        assert_eq!(
+            generate_ecdsa_k256_batch(ECDSA_MESSAGE_LEN, 2)[0],
+            generate_ecdsa_k256_input()
+        );
+        for (digest, (x, y), signature) in &batch {
+            let point = EncodedPoint::from_affine_coordinates(
+                p256::FieldBytes::from_slice(x),
+                p256::FieldBytes::from_slice(y),
+                false,
+            );
+            let key = VerifyingKey::from_encoded_point(&point).unwrap();
+            let signature = Signature::from_slice(signature).unwrap();
+            assert!(key.verify_prehash(digest, &signature).is_ok());
+        }
+        assert_ne!(batch[1].0, batch[2].0);
+        assert_ne!(batch[1].1, batch[2].1);
+        assert_eq!(generate_ecdsa_batch(64, 1), generate_ecdsa_batch(64, 1));
+    }
+
+    #[test]
+    fn test_poseidon_sponge_packing() {
+        let message = generate_poseidon_sponge_input(64);
+        let bn254 = poseidon_sponge_elements_bn254(&message);
+        assert_eq!(
+            bn254.len(),
+            poseidon_sponge_num_elements(64, POSEIDON_SPONGE_BN254_BYTES_PER_ELEMENT)
+        );
+        assert_eq!(bn254.len(), 4);
+        assert_eq!(
+            bn254[2],
+            message[62..]
+                .iter()
+                .rev()
+                .fold(0u64, |acc, b| acc * 256 + *b as u64)
+                .to_string()
+        );
+        assert_eq!(bn254[3], "64");
+
+        let goldilocks = poseidon_sponge_elements_goldilocks(&message);
+        assert_eq!(
+            goldilocks.len(),
+            poseidon_sponge_num_elements(64, POSEIDON_SPONGE_GOLDILOCKS_BYTES_PER_ELEMENT)
+        );
+        assert_eq!(goldilocks[0].to_le_bytes()[..7], message[..7]);
+        assert_eq!(goldilocks[0] >> 56, 0);
+        assert_eq!(goldilocks.last(), Some(&64));
+    }
 }
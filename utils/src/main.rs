@@ -1,6 +1,7 @@
 use clap::{Parser, Subcommand};
 use hex::ToHex;
 use utils::BenchTarget;
+use utils::noir::NoirCircuit;
 
 /// CLI to generate benchmark inputs and query available sizes
 #[derive(Parser, Debug)]
@@ -26,8 +27,24 @@ enum Command {
         size: usize,
     },
 
-    /// Generate inputs for ecdsa: prints hex-encoded hashed message, public key, and signature
-    Ecdsa,
+    /// Generate inputs for ecdsa: prints the hex-encoded hashed message, public key x, y and
+    /// signature of each signature (four lines per signature)
+    Ecdsa {
+        /// Length of each signed message in bytes (default 128)
+        #[arg(long, short = 'm', default_value_t = utils::ECDSA_MESSAGE_LEN)]
+        message_len: usize,
+        /// Number of signatures (default 1)
+        #[arg(long, short = 'n', default_value_t = 1)]
+        count: usize,
+    },
+
+    /// Generate inputs for multisig: prints the hex-encoded hashed message and the threshold, then
+    /// the hex-encoded public key x, y and signature of each signer (one per line)
+    Multisig {
+        /// Number of signers (default 4)
+        #[arg(long, short = 'n', default_value_t = 4)]
+        size: usize,
+    },
 
     /// Generate inputs for poseidon: prints field elements as decimal strings (one per line)
     Poseidon {
@@ -43,6 +60,15 @@ enum Command {
         size: usize,
     },
 
+    /// Print the Noir `main.nr` of a circuit for the given input size
+    NoirMain {
+        /// Circuit entry point, e.g. sha256, keccak256-native, poseidon2, ecdsa-r1
+        circuit: NoirCircuit,
+        /// Input size in bytes, field elements, leaf updates or signers (default 128)
+        #[arg(long, short = 'n', default_value_t = 128)]
+        size: usize,
+    },
+
     /// Query available sha256 input sizes from metadata
     Sizes {
         #[command(subcommand)]
@@ -85,12 +111,27 @@ fn main() {
             println!("{}", message_bytes.encode_hex::<String>());
             println!("{}", digest.encode_hex::<String>());
         }
-        Command::Ecdsa => {
-            let (digest, (pub_key_x, pub_key_y), signature) = utils::generate_ecdsa_input();
-            println!("{}", digest.encode_hex::<String>());
-            println!("{}", pub_key_x.encode_hex::<String>());
-            println!("{}", pub_key_y.encode_hex::<String>());
-            println!("{}", signature.encode_hex::<String>());
+        Command::Ecdsa { message_len, count } => {
+            for (digest, (pub_key_x, pub_key_y), signature) in
+                utils::generate_ecdsa_batch(message_len, count)
+            {
+                println!("{}", digest.encode_hex::<String>());
+                println!("{}", pub_key_x.encode_hex::<String>());
+                println!("{}", pub_key_y.encode_hex::<String>());
+                println!("{}", signature.encode_hex::<String>());
+            }
+        }
+        Command::Multisig { size } => {
+            let input = utils::generate_multisig_input(size);
+            println!("{}", input.digest.encode_hex::<String>());
+            println!("{}", input.threshold);
+            for ((pub_key_x, pub_key_y), signature) in
+                input.public_keys.iter().zip(&input.signatures)
+            {
+                println!("{}", pub_key_x.encode_hex::<String>());
+                println!("{}", pub_key_y.encode_hex::<String>());
+                println!("{}", signature.encode_hex::<String>());
+            }
         }
         Command::Poseidon { size } => {
             let field_elements = utils::generate_poseidon_input_strings(size);
@@ -103,6 +144,9 @@ fn main() {
             println!("{}", input_bytes.encode_hex::<String>());
             println!("{}", digest.encode_hex::<String>());
         }
+        Command::NoirMain { circuit, size } => {
+            print!("{}", circuit.main_source(size));
+        }
         Command::Sizes {
             command: SizesCommand::List { target },
         } => {
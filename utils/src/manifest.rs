@@ -0,0 +1,218 @@
+//! Provenance of a benchmark run.
+//!
+//! Every bench process of a run records itself in `manifest.json` in the run directory (see
+//! [`crate::results`]): the bench matrix, input seeds, feature flags, `BENCH_*` settings and
+//! toolchain versions it ran with, and when the run started and last finished a bench.
+//! `collect_benchmarks` embeds the manifest in the collected metadata.
+
+use crate::matrix::BenchMatrix;
+use crate::results::Writer;
+use crate::sandbox::SandboxSettings;
+use crate::{ECDSA_KEY_SEED, corpus, run_seed};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+pub const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// Prefix of the environment variables that configure the harness.
+const BENCH_ENV_PREFIX: &str = "BENCH_";
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RunManifest {
+    pub run_id: String,
+    /// RFC 3339 timestamps of the start of the first bench and the end of the last one.
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub toolchain: Toolchain,
+    pub seeds: InputSeeds,
+    /// Bench matrix of the first bench of the run.
+    pub matrix: BenchMatrix,
+    /// Cargo features the harness was built with (`perf-counters`, `divan`).
+    pub harness_features: Vec<String>,
+    /// `BENCH_*` environment variables of the first bench of the run.
+    pub bench_env: BTreeMap<String, String>,
+    /// Sandbox the spawned processes ran in (see [`crate::sandbox`]).
+    #[serde(default)]
+    pub sandbox: SandboxSettings,
+    pub benches: Vec<BenchEntry>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Toolchain {
+    /// `rustc --version`.
+    pub rustc: Option<String>,
+    /// `cargo --version`.
+    pub cargo: Option<String>,
+}
+
+/// How the generated inputs are seeded.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct InputSeeds {
+    /// Messages and field-element inputs come from `StdRng` seeded with the input size.
+    pub messages: String,
+    /// Seed of the ECDSA signing keys.
+    pub ecdsa_key: u64,
+    /// Run seed mixed into both (see [`crate::SEED_ENV`]).
+    #[serde(default)]
+    pub run_seed: u64,
+    /// Real-world sample used instead of random messages (see [`corpus::CORPUS_ENV`]).
+    pub corpus: Option<String>,
+}
+
+/// One bench (system, target, feature tag) that ran in the run.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct BenchEntry {
+    pub system: String,
+    pub target: String,
+    pub feature: Option<String>,
+    pub sizes: Vec<usize>,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+}
+
+impl RunManifest {
+    /// Manifest of a run starting now.
+    pub fn capture(run_id: &str, matrix: BenchMatrix) -> Self {
+        Self {
+            run_id: run_id.to_string(),
+            started_at: now(),
+            finished_at: None,
+            toolchain: Toolchain {
+                rustc: command_version(&env::var("RUSTC").unwrap_or_else(|_| "rustc".into())),
+                cargo: command_version(&env::var("CARGO").unwrap_or_else(|_| "cargo".into())),
+            },
+            seeds: InputSeeds {
+                messages: "input_size".to_string(),
+                ecdsa_key: ECDSA_KEY_SEED,
+                run_seed: run_seed(),
+                corpus: corpus::selected_id(),
+            },
+            matrix,
+            harness_features: [
+                ("perf-counters", cfg!(feature = "perf-counters")),
+                ("divan", cfg!(feature = "divan")),
+            ]
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(feature, _)| feature.to_string())
+            .collect(),
+            bench_env: env::vars()
+                .filter(|(name, _)| name.starts_with(BENCH_ENV_PREFIX))
+                .collect(),
+            sandbox: SandboxSettings::from_env(),
+            benches: Vec::new(),
+        }
+    }
+}
+
+/// Reads the manifest of a run directory; `None` if the run has none.
+pub fn read_manifest(dir: &Path) -> io::Result<Option<RunManifest>> {
+    match fs::read_to_string(dir.join(MANIFEST_FILENAME)) {
+        Ok(contents) => Ok(Some(serde_json::from_str(&contents)?)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Adds a bench starting now to the manifest of the run, creating the manifest for the run's
+/// first bench.
+pub fn record_bench_start(
+    writer: &Writer,
+    matrix: &BenchMatrix,
+    system: &str,
+    target: &str,
+    feature: Option<&str>,
+    sizes: Vec<usize>,
+) -> io::Result<()> {
+    let mut manifest = match read_manifest(writer.dir())? {
+        Some(manifest) => manifest,
+        None => RunManifest::capture(writer.run_id(), matrix.clone()),
+    };
+    manifest.benches.push(BenchEntry {
+        system: system.to_string(),
+        target: target.to_string(),
+        feature: feature.map(str::to_string),
+        sizes,
+        started_at: now(),
+        finished_at: None,
+    });
+    write_manifest(writer, &manifest)
+}
+
+/// Marks the last started bench of `system`/`target`/`feature` and the run as finished now.
+pub fn record_bench_finish(
+    writer: &Writer,
+    system: &str,
+    target: &str,
+    feature: Option<&str>,
+) -> io::Result<()> {
+    let Some(mut manifest) = read_manifest(writer.dir())? else {
+        return Ok(());
+    };
+    let finished_at = now();
+    if let Some(bench) = manifest.benches.iter_mut().rev().find(|bench| {
+        bench.system == system && bench.target == target && bench.feature.as_deref() == feature
+    }) {
+        bench.finished_at = Some(finished_at.clone());
+    }
+    manifest.finished_at = Some(finished_at);
+    write_manifest(writer, &manifest)
+}
+
+fn write_manifest(writer: &Writer, manifest: &RunManifest) -> io::Result<()> {
+    writer.write_json(&writer.dir().join(MANIFEST_FILENAME), manifest)
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339()
+}
+
+/// First line of `<program> --version`, if it runs.
+fn command_version(program: &str) -> Option<String> {
+    let output = Command::new(program).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benches_are_appended_to_the_run_manifest() {
+        let root = env::temp_dir().join(format!("csp_manifest_{}", std::process::id()));
+        let writer = Writer::new(&root, "run-1");
+        let matrix = BenchMatrix::default();
+
+        record_bench_start(&writer, &matrix, "sp1", "sha256", None, vec![128, 256]).unwrap();
+        record_bench_finish(&writer, "sp1", "sha256", None).unwrap();
+        record_bench_start(&writer, &matrix, "risc0", "sha256", None, vec![128]).unwrap();
+
+        let manifest = read_manifest(writer.dir()).unwrap().unwrap();
+        assert_eq!(manifest.run_id, "run-1");
+        assert_eq!(manifest.seeds.ecdsa_key, ECDSA_KEY_SEED);
+        let systems: Vec<&str> = manifest.benches.iter().map(|b| b.system.as_str()).collect();
+        assert_eq!(systems, ["sp1", "risc0"]);
+        assert!(manifest.benches[0].finished_at.is_some());
+        assert!(manifest.benches[1].finished_at.is_none());
+        assert!(read_manifest(&root.join("missing")).unwrap().is_none());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
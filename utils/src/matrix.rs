@@ -0,0 +1,289 @@
+use crate::BenchId;
+use crate::cost::ProverPricing;
+use crate::harness::BenchTarget;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Path of the bench matrix config; defaults to [`DEFAULT_MATRIX_PATH`].
+pub const MATRIX_ENV: &str = "BENCH_MATRIX";
+
+/// Benches run from their crate directory, so the repo-root config is one level up.
+pub const DEFAULT_MATRIX_PATH: &str = "../bench_matrix.json";
+
+/// Criterion refuses fewer samples than this.
+const MIN_SAMPLE_SIZE: usize = 10;
+
+/// Per-measurement overrides of the harness defaults, keyed by (system, target, size).
+///
+/// Entries are applied in file order; an entry matches when every selector it sets matches, and
+/// later matches override the fields they set.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct BenchMatrix {
+    #[serde(default)]
+    pub overrides: Vec<MatrixEntry>,
+    /// Benches a complete run is expected to measure (see [`crate::coverage`]).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub benches: Vec<DeclaredBench>,
+}
+
+/// A bench (system, target, feature tag) declared in the matrix.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct DeclaredBench {
+    pub system: String,
+    pub target: String,
+    pub feature: Option<String>,
+    /// Input sizes; defaults to the sizes `BENCH_INPUT_PROFILE` selects for the target.
+    pub sizes: Option<Vec<usize>>,
+    /// Only run on request (GPU or optional toolchains), so a missing measurement is expected.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+}
+
+impl DeclaredBench {
+    /// Ids of the measurements this bench is expected to produce; none for an unknown target.
+    pub fn ids(&self) -> Vec<BenchId> {
+        let sizes = match (&self.sizes, self.target.parse::<BenchTarget>()) {
+            (Some(sizes), _) => sizes.clone(),
+            (None, Ok(target)) => crate::input_sizes_for(target),
+            (None, Err(_)) => Vec::new(),
+        };
+        sizes
+            .into_iter()
+            .map(|size| BenchId::new(&self.target, size, &self.system, self.feature.as_deref()))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct MatrixEntry {
+    /// Proving system name (`ProvingSystem::as_str`); any system if unset.
+    pub system: Option<String>,
+    /// Benchmark target (`BenchTarget::as_str`); any target if unset.
+    pub target: Option<String>,
+    /// Input sizes; any size if unset.
+    pub sizes: Option<Vec<usize>>,
+    #[serde(flatten)]
+    pub sampling: SamplingConfig,
+    #[serde(flatten)]
+    pub runtime: RuntimeConfig,
+    /// Hosted-prover price list used to estimate the cost per proof.
+    pub pricing: Option<ProverPricing>,
+}
+
+/// Criterion sampling parameters. Unset fields keep the harness defaults.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SamplingConfig {
+    pub sample_size: Option<usize>,
+    pub warm_up_secs: Option<f64>,
+    pub measurement_secs: Option<f64>,
+}
+
+/// Process runtime controls of a measurement. Recorded in the metrics file as applied.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RuntimeConfig {
+    /// Rayon worker threads; also exported as `RAYON_NUM_THREADS` to the memory and cold-start
+    /// child processes.
+    pub threads: Option<usize>,
+    /// Stack size of the Rayon worker threads, in bytes.
+    pub stack_size: Option<usize>,
+    /// Extra environment variables, e.g. prover-specific tuning knobs.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+impl RuntimeConfig {
+    fn merge(&mut self, other: &RuntimeConfig) {
+        self.threads = other.threads.or(self.threads);
+        self.stack_size = other.stack_size.or(self.stack_size);
+        self.env
+            .extend(other.env.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+}
+
+impl SamplingConfig {
+    pub fn warm_up_time(&self) -> Option<Duration> {
+        self.warm_up_secs.map(Duration::from_secs_f64)
+    }
+
+    pub fn measurement_time(&self) -> Option<Duration> {
+        self.measurement_secs.map(Duration::from_secs_f64)
+    }
+
+    fn merge(&mut self, other: &SamplingConfig) {
+        self.sample_size = other.sample_size.or(self.sample_size);
+        self.warm_up_secs = other.warm_up_secs.or(self.warm_up_secs);
+        self.measurement_secs = other.measurement_secs.or(self.measurement_secs);
+    }
+}
+
+impl MatrixEntry {
+    fn matches(&self, system: &str, target: &str, size: usize) -> bool {
+        self.system.as_deref().is_none_or(|s| s == system)
+            && self.target.as_deref().is_none_or(|t| t == target)
+            && self
+                .sizes
+                .as_ref()
+                .is_none_or(|sizes| sizes.contains(&size))
+    }
+}
+
+impl BenchMatrix {
+    /// Sampling parameters for one measurement, merged from all matching entries.
+    pub fn sampling(&self, system: &str, target: &str, size: usize) -> SamplingConfig {
+        let mut sampling = SamplingConfig::default();
+        for entry in self.matching(system, target, size) {
+            sampling.merge(&entry.sampling);
+        }
+        sampling
+    }
+
+    /// Runtime controls for one measurement, merged from all matching entries (environment
+    /// variables accumulate; later values win).
+    pub fn runtime(&self, system: &str, target: &str, size: usize) -> RuntimeConfig {
+        let mut runtime = RuntimeConfig::default();
+        for entry in self.matching(system, target, size) {
+            runtime.merge(&entry.runtime);
+        }
+        runtime
+    }
+
+    /// Price list of the last matching entry that sets one.
+    pub fn pricing(&self, system: &str, target: &str, size: usize) -> Option<&ProverPricing> {
+        self.matching(system, target, size)
+            .into_iter()
+            .filter_map(|entry| entry.pricing.as_ref())
+            .next_back()
+    }
+
+    fn matching(&self, system: &str, target: &str, size: usize) -> Vec<&MatrixEntry> {
+        self.overrides
+            .iter()
+            .filter(|entry| entry.matches(system, target, size))
+            .collect()
+    }
+
+    fn validate(&self) -> io::Result<()> {
+        for entry in &self.overrides {
+            if entry
+                .sampling
+                .sample_size
+                .is_some_and(|n| n < MIN_SAMPLE_SIZE)
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("sample_size must be at least {MIN_SAMPLE_SIZE}: {entry:?}"),
+                ));
+            }
+            if entry.runtime.threads == Some(0) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("threads must be positive: {entry:?}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn matrix_path() -> PathBuf {
+    env::var(MATRIX_ENV)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_MATRIX_PATH))
+}
+
+/// Reads a bench matrix config. A missing file yields the empty matrix (all defaults).
+pub fn read_matrix(path: &Path) -> io::Result<BenchMatrix> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(BenchMatrix::default()),
+        Err(e) => return Err(e),
+    };
+    let matrix: BenchMatrix = serde_json::from_str(&contents)?;
+    matrix.validate()?;
+    Ok(matrix)
+}
+
+/// Loads the bench matrix from [`matrix_path`], panicking on an invalid config so that a typo
+/// does not silently fall back to the defaults.
+pub fn load_matrix() -> BenchMatrix {
+    let path = matrix_path();
+    read_matrix(&path).unwrap_or_else(|e| panic!("Invalid bench matrix {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_later_entries_override_matching_fields() {
+        let matrix: BenchMatrix = serde_json::from_str(
+            r#"{
+                "overrides": [
+                    { "system": "sp1", "sample_size": 10, "measurement_secs": 300 },
+                    { "system": "sp1", "target": "sha256", "sizes": [2048], "measurement_secs": 1200 },
+                    { "target": "keccak", "warm_up_secs": 0.5 }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let large = matrix.sampling("sp1", "sha256", 2048);
+        assert_eq!(large.sample_size, Some(10));
+        assert_eq!(large.measurement_time(), Some(Duration::from_secs(1200)));
+        assert_eq!(large.warm_up_time(), None);
+
+        let small = matrix.sampling("sp1", "sha256", 128);
+        assert_eq!(small.measurement_time(), Some(Duration::from_secs(300)));
+
+        let other = matrix.sampling("risc0", "keccak", 128);
+        assert_eq!(other.sample_size, None);
+        assert_eq!(other.warm_up_time(), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_runtime_env_accumulates() {
+        let matrix: BenchMatrix = serde_json::from_str(
+            r#"{
+                "overrides": [
+                    { "system": "plonky2", "threads": 8, "env": { "A": "1", "B": "1" } },
+                    { "system": "plonky2", "sizes": [2048], "threads": 16, "env": { "B": "2" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let runtime = matrix.runtime("plonky2", "sha256", 2048);
+        assert_eq!(runtime.threads, Some(16));
+        assert_eq!(runtime.env["A"], "1");
+        assert_eq!(runtime.env["B"], "2");
+        assert_eq!(matrix.runtime("plonky2", "sha256", 128).threads, Some(8));
+        assert_eq!(
+            matrix.runtime("sp1", "sha256", 128),
+            RuntimeConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_read_matrix_rejects_small_sample_size() {
+        let dir = std::env::temp_dir().join(format!("csp_matrix_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bench_matrix.json");
+
+        assert_eq!(read_matrix(&path).unwrap(), BenchMatrix::default());
+
+        fs::write(&path, r#"{ "overrides": [{ "sample_size": 3 }] }"#).unwrap();
+        assert!(read_matrix(&path).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+//! Poseidon Merkle batch updates for the `merkle_update` target.
+//!
+//! Rollups keep their state in a Merkle tree and prove every block as a batch of leaf updates
+//! that moves the state root from an old to a new value. The target proves `input_size` such
+//! updates in a sparse tree of depth [`MERKLE_UPDATE_DEPTH`] hashed with circomlib's Poseidon
+//! over BN254 (`Poseidon(2)` in circomlib, `hash_2` in noir-lang/poseidon): each update checks
+//! the old leaf against the current root through its authentication path, then recomputes the
+//! root with the new leaf along the same path. Updates apply in order, so the path of a later
+//! update already reflects the earlier ones.
+
+use crate::zkvm::outputs::PublicOutputs;
+use ark_bn254::Fr;
+use ark_ff::{AdditiveGroup, BigInteger, Field, PrimeField};
+use num_bigint::BigUint;
+use rand::RngCore;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::LazyLock;
+
+/// Depth of the state tree, i.e. the number of hashes from a leaf to the root.
+pub const MERKLE_UPDATE_DEPTH: usize = 20;
+
+/// Bytes of the public output of the guests: the old root, then the new root.
+pub const MERKLE_UPDATE_OUTPUT_LEN: usize = 64;
+
+/// State width of `Poseidon(2)`: a capacity element and the two inputs.
+const WIDTH: usize = 3;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 57;
+
+/// Round constants and MDS matrix of `Poseidon(2)`.
+struct PoseidonParams {
+    round_constants: Vec<Fr>,
+    mds: [[Fr; WIDTH]; WIDTH],
+}
+
+static POSEIDON: LazyLock<PoseidonParams> = LazyLock::new(PoseidonParams::generate);
+
+impl PoseidonParams {
+    /// Derives the parameters the way circomlib's were, with the Grain LFSR of the reference
+    /// `generate_parameters_grain.sage`: rejection-sampled round constants, then a Cauchy MDS
+    /// matrix `1 / (x_i + y_j)`.
+    fn generate() -> Self {
+        let mut grain = Grain::new();
+        let modulus = BigUint::from(Fr::MODULUS);
+        let round_constants = (0..(FULL_ROUNDS + PARTIAL_ROUNDS) * WIDTH)
+            .map(|_| {
+                loop {
+                    let value = grain.bits(Fr::MODULUS_BIT_SIZE as usize);
+                    if value < modulus {
+                        break Fr::from(value);
+                    }
+                }
+            })
+            .collect();
+
+        let points: Vec<Fr> = (0..2 * WIDTH)
+            .map(|_| Fr::from(grain.bits(Fr::MODULUS_BIT_SIZE as usize)))
+            .collect();
+        let (xs, ys) = points.split_at(WIDTH);
+        let mds = std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (xs[i] + ys[j])
+                    .inverse()
+                    .expect("Cauchy points are distinct")
+            })
+        });
+        PoseidonParams {
+            round_constants,
+            mds,
+        }
+    }
+}
+
+/// The Grain LFSR in self-shrinking mode, seeded with the Poseidon instance.
+struct Grain {
+    state: VecDeque<bool>,
+}
+
+impl Grain {
+    fn new() -> Self {
+        let mut state = VecDeque::with_capacity(80);
+        let mut push = |value: u64, bits: u32| {
+            state.extend((0..bits).rev().map(|bit| (value >> bit) & 1 == 1));
+        };
+        push(1, 2); // prime field
+        push(0, 4); // x^alpha S-box
+        push(u64::from(Fr::MODULUS_BIT_SIZE), 12);
+        push(WIDTH as u64, 12);
+        push(FULL_ROUNDS as u64, 10);
+        push(PARTIAL_ROUNDS as u64, 10);
+        push((1 << 30) - 1, 30);
+
+        let mut grain = Grain { state };
+        for _ in 0..160 {
+            grain.step();
+        }
+        grain
+    }
+
+    fn step(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.pop_front();
+        self.state.push_back(bit);
+        bit
+    }
+
+    /// Of every pair of steps, keeps the second if the first is set.
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.step();
+            let bit = self.step();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// `count` bits, most significant first.
+    fn bits(&mut self, count: usize) -> BigUint {
+        (0..count).fold(BigUint::ZERO, |value, _| {
+            (value << 1u32) + u32::from(self.next_bit())
+        })
+    }
+}
+
+/// circomlib's `Poseidon(2)` over BN254.
+pub fn poseidon_hash_2(left: Fr, right: Fr) -> Fr {
+    let params = &*POSEIDON;
+    let mut state = [Fr::ZERO, left, right];
+    for round in 0..FULL_ROUNDS + PARTIAL_ROUNDS {
+        for (element, constant) in state
+            .iter_mut()
+            .zip(&params.round_constants[round * WIDTH..])
+        {
+            *element += constant;
+        }
+        let partial = (FULL_ROUNDS / 2..FULL_ROUNDS / 2 + PARTIAL_ROUNDS).contains(&round);
+        let sboxed = if partial { 1 } else { WIDTH };
+        for element in &mut state[..sboxed] {
+            *element = element.pow([5]);
+        }
+        state = std::array::from_fn(|i| (0..WIDTH).map(|j| params.mds[i][j] * state[j]).sum());
+    }
+    state[0]
+}
+
+/// Parent of `node` and its `sibling` at a level where bit `is_right` of the leaf index is set
+/// if `node` is the right child.
+fn parent(node: Fr, sibling: Fr, is_right: bool) -> Fr {
+    if is_right {
+        poseidon_hash_2(sibling, node)
+    } else {
+        poseidon_hash_2(node, sibling)
+    }
+}
+
+/// One leaf update with the authentication path it was checked against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeafUpdate {
+    pub index: u32,
+    pub old_leaf: Fr,
+    pub new_leaf: Fr,
+    /// Siblings from the leaf level up, before the update.
+    pub siblings: Vec<Fr>,
+}
+
+/// A batch of leaf updates moving the tree from `old_root` to `new_root`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleBatchUpdate {
+    pub old_root: Fr,
+    pub new_root: Fr,
+    pub updates: Vec<LeafUpdate>,
+}
+
+impl MerkleBatchUpdate {
+    /// Serialization read by the guests, with counts and indices as little-endian `u32` and
+    /// field elements as 32 big-endian bytes:
+    /// `depth || update_count || old_root || (index || old_leaf || new_leaf || siblings)*`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(MERKLE_UPDATE_DEPTH as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.updates.len() as u32).to_le_bytes());
+        bytes.extend(field_bytes(self.old_root));
+        for update in &self.updates {
+            bytes.extend_from_slice(&update.index.to_le_bytes());
+            bytes.extend(field_bytes(update.old_leaf));
+            bytes.extend(field_bytes(update.new_leaf));
+            for sibling in &update.siblings {
+                bytes.extend(field_bytes(*sibling));
+            }
+        }
+        bytes
+    }
+
+    /// The guests' public output: `old_root || new_root`.
+    pub fn public_output(&self) -> Vec<u8> {
+        self.public_outputs().as_bytes().to_vec()
+    }
+
+    /// [`Self::public_output`] as named values.
+    pub fn public_outputs(&self) -> PublicOutputs {
+        PublicOutputs::new()
+            .with("old_root", field_bytes(self.old_root))
+            .with("new_root", field_bytes(self.new_root))
+    }
+}
+
+/// 32 big-endian bytes of `element`.
+pub fn field_bytes(element: Fr) -> Vec<u8> {
+    element.into_bigint().to_bytes_be()
+}
+
+/// Sparse tree whose unset leaves are zero.
+struct SparseTree {
+    nodes: HashMap<(usize, u32), Fr>,
+    /// Root of an empty subtree of each height.
+    empty: Vec<Fr>,
+}
+
+impl SparseTree {
+    fn new() -> Self {
+        let mut empty = vec![Fr::ZERO];
+        for height in 0..MERKLE_UPDATE_DEPTH {
+            empty.push(poseidon_hash_2(empty[height], empty[height]));
+        }
+        SparseTree {
+            nodes: HashMap::new(),
+            empty,
+        }
+    }
+
+    fn node(&self, level: usize, index: u32) -> Fr {
+        self.nodes
+            .get(&(level, index))
+            .copied()
+            .unwrap_or(self.empty[level])
+    }
+
+    fn root(&self) -> Fr {
+        self.node(MERKLE_UPDATE_DEPTH, 0)
+    }
+
+    fn siblings(&self, index: u32) -> Vec<Fr> {
+        (0..MERKLE_UPDATE_DEPTH)
+            .map(|level| self.node(level, (index >> level) ^ 1))
+            .collect()
+    }
+
+    fn set(&mut self, index: u32, leaf: Fr) {
+        let mut node = leaf;
+        for level in 0..MERKLE_UPDATE_DEPTH {
+            self.nodes.insert((level, index >> level), node);
+            let sibling = self.node(level, (index >> level) ^ 1);
+            node = parent(node, sibling, (index >> level) & 1 == 1);
+        }
+        self.nodes.insert((MERKLE_UPDATE_DEPTH, 0), node);
+    }
+}
+
+/// A random element below 2^253, like the Poseidon target's inputs.
+fn random_field_element(rng: &mut impl RngCore) -> Fr {
+    let mut bytes = [0u8; 32];
+    rng.fill_bytes(&mut bytes);
+    bytes[31] &= 0x1f;
+    Fr::from_le_bytes_mod_order(&bytes)
+}
+
+/// Fills a tree with `num_updates` random leaves at distinct random indices and updates each of
+/// them to a new random value, in index-draw order.
+pub fn generate(num_updates: usize, rng: &mut impl RngCore) -> MerkleBatchUpdate {
+    assert!(num_updates > 0, "a batch needs at least one update");
+    assert!(
+        num_updates <= 1 << MERKLE_UPDATE_DEPTH,
+        "more updates than leaves"
+    );
+    let mut indices = Vec::with_capacity(num_updates);
+    let mut seen = HashSet::new();
+    while indices.len() < num_updates {
+        let index = rng.next_u32() & ((1 << MERKLE_UPDATE_DEPTH) - 1);
+        if seen.insert(index) {
+            indices.push(index);
+        }
+    }
+
+    let mut tree = SparseTree::new();
+    let old_leaves: Vec<Fr> = indices.iter().map(|_| random_field_element(rng)).collect();
+    for (&index, &leaf) in indices.iter().zip(&old_leaves) {
+        tree.set(index, leaf);
+    }
+    let old_root = tree.root();
+
+    let updates = indices
+        .into_iter()
+        .zip(old_leaves)
+        .map(|(index, old_leaf)| {
+            let update = LeafUpdate {
+                index,
+                old_leaf,
+                new_leaf: random_field_element(rng),
+                siblings: tree.siblings(index),
+            };
+            tree.set(index, update.new_leaf);
+            update
+        })
+        .collect();
+    MerkleBatchUpdate {
+        old_root,
+        new_root: tree.root(),
+        updates,
+    }
+}
+
+/// Reference implementation of the circuits and guests: the root after the updates if every old
+/// leaf is in the tree it updates, `None` otherwise.
+pub fn verify_merkle_update(batch: &MerkleBatchUpdate) -> Option<Fr> {
+    let root_of = |leaf: Fr, update: &LeafUpdate| {
+        (update.siblings.iter().enumerate()).fold(leaf, |node, (level, &sibling)| {
+            parent(node, sibling, (update.index >> level) & 1 == 1)
+        })
+    };
+    let mut root = batch.old_root;
+    for update in &batch.updates {
+        if update.siblings.len() != MERKLE_UPDATE_DEPTH
+            || update.index >> MERKLE_UPDATE_DEPTH != 0
+            || root_of(update.old_leaf, update) != root
+        {
+            return None;
+        }
+        root = root_of(update.new_leaf, update);
+    }
+    Some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_poseidon_matches_circomlib() {
+        // Test vector of circomlib's `Poseidon(2)`.
+        assert_eq!(
+            poseidon_hash_2(Fr::from(1u64), Fr::from(2u64)).to_string(),
+            "7853200120776062878684798364095072458815029376092732009249414926327459813530"
+        );
+    }
+
+    #[test]
+    fn test_generated_batches_verify() {
+        for num_updates in [1, 4, 16] {
+            let mut rng = StdRng::seed_from_u64(num_updates as u64);
+            let batch = generate(num_updates, &mut rng);
+            assert_eq!(batch.updates.len(), num_updates);
+            assert_ne!(batch.old_root, batch.new_root);
+            assert_eq!(verify_merkle_update(&batch), Some(batch.new_root));
+
+            let mut tampered = batch.clone();
+            tampered.updates[num_updates - 1].old_leaf += Fr::ONE;
+            assert_eq!(verify_merkle_update(&tampered), None);
+        }
+    }
+
+    #[test]
+    fn test_serialization_layout() {
+        let batch = generate(2, &mut StdRng::seed_from_u64(2));
+        let bytes = batch.to_bytes();
+        assert_eq!(
+            bytes.len(),
+            4 + 4 + 32 + 2 * (4 + 32 + 32 + 32 * MERKLE_UPDATE_DEPTH)
+        );
+        assert_eq!(bytes[..4], (MERKLE_UPDATE_DEPTH as u32).to_le_bytes());
+        assert_eq!(bytes[8..40], field_bytes(batch.old_root)[..]);
+        assert_eq!(batch.public_output().len(), MERKLE_UPDATE_OUTPUT_LEN);
+    }
+}
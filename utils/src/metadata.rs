@@ -1,18 +1,78 @@
+const BYTE_INPUTS_MINIMAL: [usize; 1] = [128];
 const BYTE_INPUTS_REDUCED: [usize; 2] = [128, 256];
 const BYTE_INPUTS_FULL: [usize; 5] = [128, 256, 512, 1024, 2048];
 
 pub fn selected_byte_inputs() -> Vec<usize> {
     match std::env::var("BENCH_INPUT_PROFILE").ok().as_deref() {
+        Some("minimal") => BYTE_INPUTS_MINIMAL.to_vec(),
         Some("reduced") => BYTE_INPUTS_REDUCED.to_vec(),
         _ => BYTE_INPUTS_FULL.to_vec(),
     }
 }
 
+/// Sizes around the Keccak-256 rate (136 bytes), added to the full profile of the `keccak`
+/// target: 135 bytes still pad into one block, 136, 137 and 271 take two and 272 three, so the
+/// per-block steps show up next to the power-of-two sizes.
+pub const KECCAK_BOUNDARY_INPUTS: [usize; 5] = [135, 136, 137, 271, 272];
+
+/// [`selected_byte_inputs`], with [`KECCAK_BOUNDARY_INPUTS`] in the full profile.
+pub fn selected_keccak_inputs() -> Vec<usize> {
+    match std::env::var("BENCH_INPUT_PROFILE").ok().as_deref() {
+        Some("minimal" | "reduced") => selected_byte_inputs(),
+        _ => {
+            let mut sizes = [BYTE_INPUTS_FULL.as_slice(), &KECCAK_BOUNDARY_INPUTS].concat();
+            sizes.sort_unstable();
+            sizes
+        }
+    }
+}
+
+/// Receipts per block of the `receipt_proof` target; the proof depth grows with the count.
+const RECEIPT_COUNTS_MINIMAL: [usize; 1] = [16];
+const RECEIPT_COUNTS_REDUCED: [usize; 2] = [16, 256];
+const RECEIPT_COUNTS_FULL: [usize; 4] = [16, 64, 256, 1024];
+
+pub fn selected_receipt_counts() -> Vec<usize> {
+    match std::env::var("BENCH_INPUT_PROFILE").ok().as_deref() {
+        Some("minimal") => RECEIPT_COUNTS_MINIMAL.to_vec(),
+        Some("reduced") => RECEIPT_COUNTS_REDUCED.to_vec(),
+        _ => RECEIPT_COUNTS_FULL.to_vec(),
+    }
+}
+
+/// Leaves updated per batch of the `merkle_update` target.
+const MERKLE_UPDATE_LEAVES_MINIMAL: [usize; 1] = [4];
+const MERKLE_UPDATE_LEAVES_REDUCED: [usize; 2] = [4, 16];
+const MERKLE_UPDATE_LEAVES_FULL: [usize; 3] = [4, 16, 64];
+
+pub fn selected_merkle_update_leaves() -> Vec<usize> {
+    match std::env::var("BENCH_INPUT_PROFILE").ok().as_deref() {
+        Some("minimal") => MERKLE_UPDATE_LEAVES_MINIMAL.to_vec(),
+        Some("reduced") => MERKLE_UPDATE_LEAVES_REDUCED.to_vec(),
+        _ => MERKLE_UPDATE_LEAVES_FULL.to_vec(),
+    }
+}
+
+/// Committee sizes (signatures verified) of the `multisig` target.
+const MULTISIG_SIGNERS_MINIMAL: [usize; 1] = [4];
+const MULTISIG_SIGNERS_REDUCED: [usize; 2] = [4, 16];
+const MULTISIG_SIGNERS_FULL: [usize; 3] = [4, 16, 64];
+
+pub fn selected_multisig_signers() -> Vec<usize> {
+    match std::env::var("BENCH_INPUT_PROFILE").ok().as_deref() {
+        Some("minimal") => MULTISIG_SIGNERS_MINIMAL.to_vec(),
+        Some("reduced") => MULTISIG_SIGNERS_REDUCED.to_vec(),
+        _ => MULTISIG_SIGNERS_FULL.to_vec(),
+    }
+}
+
+const FIELD_ELEMENT_INPUTS_MINIMAL: [usize; 1] = [2];
 const FIELD_ELEMENT_INPUTS_REDUCED: [usize; 2] = [2, 8];
 const FIELD_ELEMENT_INPUTS_FULL: [usize; 5] = [2, 4, 8, 12, 16];
 
 pub fn selected_field_element_inputs() -> Vec<usize> {
     match std::env::var("BENCH_INPUT_PROFILE").ok().as_deref() {
+        Some("minimal") => FIELD_ELEMENT_INPUTS_MINIMAL.to_vec(),
         Some("reduced") => FIELD_ELEMENT_INPUTS_REDUCED.to_vec(),
         _ => FIELD_ELEMENT_INPUTS_FULL.to_vec(),
     }
@@ -0,0 +1,171 @@
+//! Threshold ECDSA signatures for the `multisig` target.
+//!
+//! Multisig wallets and bridge committees accept a message once a quorum of known signers has
+//! signed it. The target verifies `input_size` secp256k1 signatures over the same digest, one per
+//! committee member, counts the valid ones and checks the count against
+//! [`multisig_threshold`]. A signature that does not verify is not counted rather than aborting
+//! the proof, so the statement only fails below the threshold.
+
+use crate::zkvm::ecdsa::encode_public_key;
+use crate::zkvm::outputs::PublicOutputs;
+use k256::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+use k256::ecdsa::{Signature, SigningKey, VerifyingKey};
+use k256::{EncodedPoint, FieldBytes};
+use rand::{CryptoRng, RngCore};
+
+/// Signatures needed out of `signers`: more than two thirds, the quorum of BFT committees.
+pub fn multisig_threshold(signers: usize) -> usize {
+    signers * 2 / 3 + 1
+}
+
+/// A committee's signatures over one digest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigInput {
+    /// SHA-256 digest every member signs.
+    pub digest: Vec<u8>,
+    pub threshold: u32,
+    /// Affine coordinates `(x, y)` of each member's key.
+    pub public_keys: Vec<(Vec<u8>, Vec<u8>)>,
+    /// `r || s` of each member, `s` normalized, in key order.
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl MultisigInput {
+    /// Serialization read by the guests, with counts as little-endian `u32` and keys in
+    /// uncompressed SEC1 form: `signer_count || threshold || digest || (key || signature)*`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(self.public_keys.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.threshold.to_le_bytes());
+        bytes.extend_from_slice(&self.digest);
+        for (key, signature) in self.encoded_keys().zip(&self.signatures) {
+            bytes.extend(key);
+            bytes.extend_from_slice(signature);
+        }
+        bytes
+    }
+
+    /// The guests' public output, the statement's public inputs:
+    /// `digest || threshold || keys`.
+    pub fn public_output(&self) -> Vec<u8> {
+        self.public_outputs().as_bytes().to_vec()
+    }
+
+    /// [`Self::public_output`] as named values.
+    pub fn public_outputs(&self) -> PublicOutputs {
+        PublicOutputs::new()
+            .with("digest", &self.digest)
+            .with("threshold", self.threshold.to_le_bytes())
+            .with("keys", self.encoded_keys().flatten().collect::<Vec<u8>>())
+    }
+
+    fn encoded_keys(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        self.public_keys
+            .iter()
+            .map(|(x, y)| encode_public_key(x, y).expect("32-byte coordinates"))
+    }
+}
+
+/// Draws a committee of `num_signers` keys from `rng` and has every member sign `digest`. The
+/// keys are drawn in order, so a smaller committee is a prefix of a larger one.
+pub fn generate(
+    num_signers: usize,
+    digest: Vec<u8>,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> MultisigInput {
+    assert!(num_signers > 0, "a committee needs at least one signer");
+    let (public_keys, signatures) = (0..num_signers)
+        .map(|_| {
+            let signing_key = SigningKey::random(&mut *rng);
+            let point = signing_key.verifying_key().to_encoded_point(false);
+            let signature: Signature = signing_key
+                .sign_prehash(&digest)
+                .expect("Failed to sign prehashed digest");
+            // Noir's `verify_signature` expects a normalized `s`.
+            let signature = signature.normalize_s().unwrap_or(signature);
+            (
+                (point.x().unwrap().to_vec(), point.y().unwrap().to_vec()),
+                signature.to_bytes().to_vec(),
+            )
+        })
+        .unzip();
+    MultisigInput {
+        digest,
+        threshold: multisig_threshold(num_signers) as u32,
+        public_keys,
+        signatures,
+    }
+}
+
+/// Reference implementation of the circuits and guests: the number of members whose signature
+/// over the digest verifies.
+pub fn valid_signatures(input: &MultisigInput) -> usize {
+    input
+        .public_keys
+        .iter()
+        .zip(&input.signatures)
+        .filter(|((x, y), signature)| {
+            let point = EncodedPoint::from_affine_coordinates(
+                FieldBytes::from_slice(x),
+                FieldBytes::from_slice(y),
+                false,
+            );
+            let (Ok(key), Ok(signature)) = (
+                VerifyingKey::from_encoded_point(&point),
+                Signature::from_slice(signature),
+            ) else {
+                return false;
+            };
+            key.verify_prehash(&input.digest, &signature).is_ok()
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::invalid_input;
+    use crate::zkvm::ecdsa::{ENCODED_PUBLIC_KEY_SIZE, SIGNATURE_SIZE};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_threshold_is_a_two_thirds_quorum() {
+        assert_eq!(multisig_threshold(1), 1);
+        assert_eq!(multisig_threshold(3), 3);
+        assert_eq!(multisig_threshold(4), 3);
+        assert_eq!(multisig_threshold(16), 11);
+        assert_eq!(multisig_threshold(64), 43);
+    }
+
+    #[test]
+    fn test_generated_committees_reach_the_threshold() {
+        let small = generate(4, vec![7; 32], &mut StdRng::seed_from_u64(1));
+        let large = generate(16, vec![7; 32], &mut StdRng::seed_from_u64(1));
+        assert_eq!(valid_signatures(&large), 16);
+        assert_eq!(large.public_keys[..4], small.public_keys[..]);
+
+        let mut tampered = large.clone();
+        let failing = 16 - large.threshold as usize + 1;
+        for signature in &mut tampered.signatures[..failing] {
+            invalid_input::corrupt(signature);
+        }
+        assert_eq!(valid_signatures(&tampered), large.threshold as usize - 1);
+    }
+
+    #[test]
+    fn test_serialization_layout() {
+        let input = generate(2, vec![7; 32], &mut StdRng::seed_from_u64(2));
+        let bytes = input.to_bytes();
+        assert_eq!(
+            bytes.len(),
+            4 + 4 + 32 + 2 * (ENCODED_PUBLIC_KEY_SIZE + SIGNATURE_SIZE)
+        );
+        assert_eq!(bytes[..4], 2u32.to_le_bytes());
+        assert_eq!(bytes[4..8], input.threshold.to_le_bytes());
+        assert_eq!(bytes[40], 0x04);
+        assert_eq!(
+            input.public_output().len(),
+            32 + 4 + 2 * ENCODED_PUBLIC_KEY_SIZE
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Entry points of the Noir benchmark circuits, shared by every Noir backend.
+//!
+//! The hash and signature implementations live in Noir library packages (noir-lang `sha256`,
+//! `keccak256`, `poseidon`, the Noir stdlib, or backend-local libraries such as ProveKit's native
+//! SHA-256, Keccak and BLAKE2s). Only `main.nr`, whose array lengths must be known at compile time, depends
+//! on the input size; it is generated here instead of being patched in place by each backend.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Comment heading every generated `main.nr`.
+pub const GENERATED_HEADER: &str =
+    "// Generated by `utils noir-main`; edit utils/src/noir.rs instead.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoirCircuit {
+    /// `sha256_var` from noir-lang/sha256.
+    Sha256,
+    /// ProveKit's `noir_native_sha256` library.
+    Sha256Native,
+    /// `keccak256` from noir-lang/keccak256.
+    Keccak256,
+    /// ProveKit's `keccak_native` library (32-bit lanes).
+    Keccak256Native,
+    /// ProveKit's `blake2s_native` library (see [`crate::blake2s`]).
+    Blake2sNative,
+    /// `hash_N` from noir-lang/poseidon (BN254).
+    Poseidon,
+    /// `hash_N` from the `poseidon2` library (BN254).
+    Poseidon2,
+    /// Sponge of `hash_12` from noir-lang/poseidon over a byte stream (see
+    /// [`crate::generate_poseidon_sponge_input`]).
+    PoseidonSponge,
+    /// Batch of leaf updates in a Poseidon Merkle tree with `hash_2` from noir-lang/poseidon (see
+    /// [`crate::merkle`]).
+    MerkleUpdate,
+    /// secp256r1 signature verification from the Noir stdlib.
+    EcdsaR1,
+    /// secp256k1 signature verification from the Noir stdlib.
+    EcdsaK1,
+    /// Threshold count of secp256k1 signatures from the Noir stdlib (see [`crate::multisig`]).
+    Multisig,
+}
+
+impl NoirCircuit {
+    pub const ALL: [NoirCircuit; 12] = [
+        NoirCircuit::Sha256,
+        NoirCircuit::Sha256Native,
+        NoirCircuit::Keccak256,
+        NoirCircuit::Keccak256Native,
+        NoirCircuit::Blake2sNative,
+        NoirCircuit::Poseidon,
+        NoirCircuit::Poseidon2,
+        NoirCircuit::PoseidonSponge,
+        NoirCircuit::MerkleUpdate,
+        NoirCircuit::EcdsaR1,
+        NoirCircuit::EcdsaK1,
+        NoirCircuit::Multisig,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoirCircuit::Sha256 => "sha256",
+            NoirCircuit::Sha256Native => "sha256-native",
+            NoirCircuit::Keccak256 => "keccak256",
+            NoirCircuit::Keccak256Native => "keccak256-native",
+            NoirCircuit::Blake2sNative => "blake2s-native",
+            NoirCircuit::Poseidon => "poseidon",
+            NoirCircuit::Poseidon2 => "poseidon2",
+            NoirCircuit::PoseidonSponge => "poseidon-sponge",
+            NoirCircuit::MerkleUpdate => "merkle-update",
+            NoirCircuit::EcdsaR1 => "ecdsa-r1",
+            NoirCircuit::EcdsaK1 => "ecdsa-k1",
+            NoirCircuit::Multisig => "multisig",
+        }
+    }
+
+    /// Source of `main.nr` for `size` input bytes (hashes over bytes, including the Poseidon
+    /// sponge), field elements (Poseidon, Poseidon2), leaf updates (Merkle update) or signers
+    /// (multisig). ECDSA circuits take a fixed-size digest and ignore `size`.
+    pub fn main_source(&self, size: usize) -> String {
+        let body = match self {
+            NoirCircuit::Sha256 => format!(
+                "use sha256::sha256_var;
+
+fn main(input: [u8; {size}], input_len: u64) -> pub [u8; 32] {{
+    sha256_var(input, input_len)
+}}
+"
+            ),
+            NoirCircuit::Sha256Native => format!(
+                "use noir_native_sha256::ryan_sha256_noir;
+
+fn main(input: [u8; {size}], input_len: u64) -> pub [u8; 32] {{
+    ryan_sha256_noir::sha256_var(input, input_len)
+}}
+"
+            ),
+            NoirCircuit::Keccak256 => format!(
+                "use keccak256::keccak256;
+
+fn main(input: [u8; {size}], message_size: u32) -> pub [u8; 32] {{
+    keccak256::<{size}>(input, message_size)
+}}
+"
+            ),
+            NoirCircuit::Keccak256Native => format!(
+                "use keccak_native::keccak256;
+
+fn main(msg: [u8; {size}], message_size: u64, result: [u8; 32]) {{
+    let digest = keccak256(msg, message_size as u32);
+    assert(digest == result);
+}}
+"
+            ),
+            NoirCircuit::Blake2sNative => format!(
+                "use blake2s_native::blake2s;
+
+fn main(input: [u8; {size}], result: [u8; 32]) {{
+    let digest = blake2s(input);
+    assert(digest == result);
+}}
+"
+            ),
+            NoirCircuit::Poseidon => format!(
+                "use poseidon::poseidon::bn254::hash_{size};
+
+fn main(inputs: [Field; {size}]) -> pub Field {{
+    hash_{size}(inputs)
+}}
+"
+            ),
+            NoirCircuit::Poseidon2 => format!(
+                "use poseidon2::bn254::hash_{size};
+
+fn main(inputs: [Field; {size}]) -> pub Field {{
+    hash_{size}(inputs)
+}}
+"
+            ),
+            NoirCircuit::PoseidonSponge => poseidon_sponge_main(size),
+            NoirCircuit::MerkleUpdate => merkle_update_main(size),
+            NoirCircuit::EcdsaR1 => ecdsa_main("ecdsa_secp256r1"),
+            NoirCircuit::EcdsaK1 => ecdsa_main("ecdsa_secp256k1"),
+            NoirCircuit::Multisig => multisig_main(size),
+        };
+        format!("{GENERATED_HEADER}\n\n{body}")
+    }
+
+    /// Writes [`Self::main_source`] to `src/main.nr` of the Noir package at `package_dir`.
+    pub fn write_main(&self, package_dir: &Path, size: usize) -> io::Result<PathBuf> {
+        let path = package_dir.join("src").join("main.nr");
+        fs::write(&path, self.main_source(size))?;
+        Ok(path)
+    }
+}
+
+fn poseidon_sponge_main(size: usize) -> String {
+    let rate = crate::POSEIDON_SPONGE_BN254_RATE;
+    let elements =
+        crate::poseidon_sponge_num_elements(size, crate::POSEIDON_SPONGE_BN254_BYTES_PER_ELEMENT);
+    let blocks = elements.div_ceil(rate);
+    let padded = blocks * rate;
+    format!(
+        "use poseidon::poseidon::bn254::hash_12;
+
+fn main(inputs: [Field; {elements}]) -> pub Field {{
+    let mut padded = [0; {padded}];
+    for j in 0..{elements} {{
+        padded[j] = inputs[j];
+    }}
+    let mut digest = 0;
+    for block in 0..{blocks} {{
+        let mut state = [0; 12];
+        state[0] = digest;
+        for i in 0..{rate} {{
+            state[i + 1] = padded[block * {rate} + i];
+        }}
+        digest = hash_12(state);
+    }}
+    digest
+}}
+"
+    )
+}
+
+fn merkle_update_main(size: usize) -> String {
+    let depth = crate::merkle::MERKLE_UPDATE_DEPTH;
+    format!(
+        "use poseidon::poseidon::bn254::hash_2;
+
+fn main(
+    old_root: pub Field,
+    new_root: pub Field,
+    indices: [Field; {size}],
+    old_leaves: [Field; {size}],
+    new_leaves: [Field; {size}],
+    siblings: [[Field; {depth}]; {size}],
+) {{
+    let mut root = old_root;
+    for i in 0..{size} {{
+        let bits: [u1; {depth}] = indices[i].to_le_bits();
+        assert(merkle_root(old_leaves[i], bits, siblings[i]) == root);
+        root = merkle_root(new_leaves[i], bits, siblings[i]);
+    }}
+    assert(root == new_root);
+}}
+
+fn merkle_root(leaf: Field, bits: [u1; {depth}], siblings: [Field; {depth}]) -> Field {{
+    let mut node = leaf;
+    for level in 0..{depth} {{
+        // A set bit puts the node on the right of its sibling.
+        let left = node + (bits[level] as Field) * (siblings[level] - node);
+        node = hash_2([left, node + siblings[level] - left]);
+    }}
+    node
+}}
+"
+    )
+}
+
+fn ecdsa_main(module: &str) -> String {
+    format!(
+        "use std::{module}::verify_signature;
+
+fn main(public_key_x: [u8; 32], public_key_y: [u8; 32], signature: [u8; 64], message_hash: [u8; 32]) -> pub bool {{
+    verify_signature(public_key_x, public_key_y, signature, message_hash)
+}}
+"
+    )
+}
+
+fn multisig_main(signers: usize) -> String {
+    format!(
+        "use std::ecdsa_secp256k1::verify_signature;
+
+fn main(
+    public_keys_x: pub [[u8; 32]; {signers}],
+    public_keys_y: pub [[u8; 32]; {signers}],
+    signatures: [[u8; 64]; {signers}],
+    message_hash: pub [u8; 32],
+    threshold: pub u32,
+) {{
+    let mut valid: u32 = 0;
+    for i in 0..{signers} {{
+        if verify_signature(public_keys_x[i], public_keys_y[i], signatures[i], message_hash) {{
+            valid += 1;
+        }}
+    }}
+    assert(valid >= threshold);
+}}
+"
+    )
+}
+
+impl fmt::Display for NoirCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NoirCircuit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<NoirCircuit, String> {
+        NoirCircuit::ALL
+            .into_iter()
+            .find(|circuit| circuit.as_str() == s)
+            .ok_or_else(|| format!("Invalid Noir circuit: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_main_source_uses_size_everywhere() {
+        let keccak = NoirCircuit::Keccak256.main_source(137);
+        assert!(keccak.contains("input: [u8; 137]"));
+        assert!(keccak.contains("keccak256::<137>("));
+        // The native circuit absorbs N / 136 + 1 blocks, so each size around a block boundary
+        // needs its own capacity.
+        for size in [135, 136, 137] {
+            assert!(
+                NoirCircuit::Keccak256Native
+                    .main_source(size)
+                    .contains(&format!("fn main(msg: [u8; {size}], message_size: u64"))
+            );
+        }
+
+        assert!(
+            NoirCircuit::Blake2sNative
+                .main_source(128)
+                .contains("fn main(input: [u8; 128], result: [u8; 32])")
+        );
+
+        let poseidon = NoirCircuit::Poseidon.main_source(4);
+        assert!(poseidon.contains("use poseidon::poseidon::bn254::hash_4;"));
+        assert!(poseidon.contains("inputs: [Field; 4]"));
+        assert!(poseidon.contains("    hash_4(inputs)"));
+
+        // 64 bytes are three 31-byte elements and the length, absorbed in one block.
+        let sponge = NoirCircuit::PoseidonSponge.main_source(64);
+        assert!(sponge.contains("inputs: [Field; 4]"));
+        assert!(sponge.contains("for block in 0..1 {"));
+        assert!(
+            NoirCircuit::PoseidonSponge
+                .main_source(1024)
+                .contains("for block in 0..4 {")
+        );
+
+        let merkle = NoirCircuit::MerkleUpdate.main_source(16);
+        assert!(merkle.contains(&format!(
+            "siblings: [[Field; {}]; 16],",
+            crate::merkle::MERKLE_UPDATE_DEPTH
+        )));
+        assert!(merkle.contains("for i in 0..16 {"));
+
+        assert_eq!(
+            NoirCircuit::EcdsaR1.main_source(32),
+            NoirCircuit::EcdsaR1.main_source(64)
+        );
+        assert!(
+            NoirCircuit::EcdsaK1
+                .main_source(32)
+                .contains("std::ecdsa_secp256k1")
+        );
+
+        let multisig = NoirCircuit::Multisig.main_source(16);
+        assert!(multisig.contains("signatures: [[u8; 64]; 16],"));
+        assert!(multisig.contains("for i in 0..16 {"));
+    }
+
+    #[test]
+    fn test_circuit_names_round_trip() {
+        for circuit in NoirCircuit::ALL {
+            assert_eq!(circuit.as_str().parse::<NoirCircuit>(), Ok(circuit));
+            assert!(circuit.main_source(8).starts_with(GENERATED_HEADER));
+        }
+        assert!("sha512".parse::<NoirCircuit>().is_err());
+    }
+}
@@ -0,0 +1,48 @@
+//! Breakdown of a proof's size into its components.
+//!
+//! Two proofs of similar size can spend their bytes very differently: Merkle caps and FRI query
+//! paths in a STARK, group elements in a SNARK, a journal of public values next to a zkVM
+//! receipt. Where the backend exposes its proof structure, a bench's `proof_size` function
+//! returns a [`ProofSize`] with those parts, stored as `proof_size_breakdown` in the metrics.
+
+use std::collections::BTreeMap;
+
+/// Component sizes in bytes, keyed by component: `commitments`, `openings`, `fri`, `sumcheck`,
+/// `public_values`, or `proof` for the opaque remainder. Public values are listed even where
+/// they travel next to the proof rather than inside it, so the parts need not sum to
+/// `proof_size`.
+pub type ProofSizeBreakdown = BTreeMap<String, usize>;
+
+/// Proof size returned by a bench's `proof_size` function: a plain `usize` total, or the total
+/// with its breakdown.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProofSize {
+    pub total: usize,
+    pub breakdown: Option<ProofSizeBreakdown>,
+}
+
+impl ProofSize {
+    pub fn with_breakdown<'a>(
+        total: usize,
+        parts: impl IntoIterator<Item = (&'a str, usize)>,
+    ) -> Self {
+        Self {
+            total,
+            breakdown: Some(
+                parts
+                    .into_iter()
+                    .map(|(part, size)| (part.to_string(), size))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<usize> for ProofSize {
+    fn from(total: usize) -> Self {
+        Self {
+            total,
+            breakdown: None,
+        }
+    }
+}
@@ -0,0 +1,496 @@
+//! Ethereum receipt inclusion proofs for the `receipt_proof` target.
+//!
+//! A block commits to its transaction receipts in a Merkle-Patricia trie keyed by the RLP-encoded
+//! transaction index. The target mixes hashing with parsing the way bridge and rollup circuits
+//! do: walk a proof from the receipts root down to a receipt, checking the Keccak-256 hash of
+//! every node against the reference in its parent, RLP-decode the receipt and output the emitter
+//! address, first topic and first data word of one of its logs.
+//!
+//! `input_size` is the number of receipts in the block, which sets the depth of the proof. The
+//! receipts are EIP-1559 receipts of ERC-20 transfers with random participants and amounts.
+
+use crate::zkvm::outputs::PublicOutputs;
+use rand::{Rng, RngCore};
+use sha3::{Digest, Keccak256};
+
+/// Bytes of the extracted log fields: address, first topic and first data word.
+pub const EXTRACTED_LOG_LEN: usize = 20 + 32 + 32;
+
+/// Transaction type of EIP-1559 receipts, prepended to their RLP encoding.
+const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// Proof that a receipt is included under a receipts root, and the log to extract from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptProofInput {
+    pub receipts_root: [u8; 32],
+    /// Trie key of the receipt: the RLP encoding of its transaction index.
+    pub key: Vec<u8>,
+    /// RLP-encoded trie nodes from the root down to the leaf holding the receipt.
+    pub proof: Vec<Vec<u8>>,
+    /// Index of the extracted log within the receipt.
+    pub log_index: u32,
+}
+
+impl ReceiptProofInput {
+    /// Serialization read by the guests: the receipts root, then the log index, the key and the
+    /// proof nodes, with every count and length a little-endian `u32`:
+    /// `root || log_index || key_len || key || node_count || (node_len || node)*`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.receipts_root.to_vec();
+        bytes.extend_from_slice(&self.log_index.to_le_bytes());
+        push_with_len(&mut bytes, &self.key);
+        bytes.extend_from_slice(&len_u32(self.proof.len()).to_le_bytes());
+        for node in &self.proof {
+            push_with_len(&mut bytes, node);
+        }
+        bytes
+    }
+}
+
+/// The extracted log `fields` as named values: `address || topic0 || data0`.
+pub fn public_outputs(fields: &[u8]) -> PublicOutputs {
+    let (address, rest) = fields.split_at(20.min(fields.len()));
+    let (topic, data) = rest.split_at(32.min(rest.len()));
+    PublicOutputs::new()
+        .with("address", address)
+        .with("topic0", topic)
+        .with("data0", data)
+}
+
+fn push_with_len(bytes: &mut Vec<u8>, field: &[u8]) {
+    bytes.extend_from_slice(&len_u32(field.len()).to_le_bytes());
+    bytes.extend_from_slice(field);
+}
+
+fn len_u32(len: usize) -> u32 {
+    u32::try_from(len).expect("length exceeds u32::MAX")
+}
+
+/// Builds a block of `num_receipts` receipts, proves the one in the middle, and returns the proof
+/// with the log fields the guest must output (see [`verify_receipt_proof`]).
+pub fn generate(num_receipts: usize, rng: &mut impl RngCore) -> (ReceiptProofInput, Vec<u8>) {
+    assert!(num_receipts > 0, "a block needs at least one receipt");
+    let receipts: Vec<Receipt> = (0..num_receipts)
+        .scan(0u64, |cumulative_gas, _| {
+            let receipt = Receipt::random(rng, *cumulative_gas);
+            *cumulative_gas = receipt.cumulative_gas;
+            Some(receipt)
+        })
+        .collect();
+
+    let index = num_receipts / 2;
+    let log_index = receipts[index].logs.len() - 1;
+    let expected = receipts[index].logs[log_index].extracted_fields();
+
+    let key = rlp_uint(index as u64);
+    let entries: Vec<(Vec<u8>, Vec<u8>)> = receipts
+        .iter()
+        .enumerate()
+        .map(|(i, receipt)| (nibbles(&rlp_uint(i as u64)), receipt.encode()))
+        .collect();
+    let mut sorted: Vec<&(Vec<u8>, Vec<u8>)> = entries.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut proof = Vec::new();
+    let root = build_node(&sorted, 0, Some(&nibbles(&key)), &mut proof);
+    proof.reverse();
+
+    let input = ReceiptProofInput {
+        receipts_root: keccak(&root),
+        key,
+        proof,
+        log_index: len_u32(log_index),
+    };
+    (input, expected.to_vec())
+}
+
+/// Reference implementation of the guest program: the extracted log fields if `input` proves
+/// a receipt under its root and the receipt has the requested log, `None` otherwise.
+///
+/// Receipt tries never embed a node in its parent (every leaf holds a receipt longer than 32
+/// bytes), so every reference must be a hash.
+pub fn verify_receipt_proof(input: &ReceiptProofInput) -> Option<[u8; EXTRACTED_LOG_LEN]> {
+    let path = nibbles(&input.key);
+    let mut position = 0;
+    let mut expected_hash = input.receipts_root.to_vec();
+    let mut value = None;
+    for node in &input.proof {
+        if value.is_some() || keccak(node).as_slice() != expected_hash {
+            return None;
+        }
+        let items = rlp_list_items(rlp_payload(node, true)?)?;
+        match items.len() {
+            17 if position == path.len() => value = Some(rlp_payload(items[16], false)?),
+            17 => {
+                expected_hash = rlp_payload(items[usize::from(path[position])], false)?.to_vec();
+                position += 1;
+            }
+            2 => {
+                let (segment, is_leaf) = decode_hex_prefix(rlp_payload(items[0], false)?)?;
+                if !path[position..].starts_with(&segment) {
+                    return None;
+                }
+                position += segment.len();
+                if is_leaf {
+                    if position != path.len() {
+                        return None;
+                    }
+                    value = Some(rlp_payload(items[1], false)?);
+                } else {
+                    expected_hash = rlp_payload(items[1], false)?.to_vec();
+                }
+            }
+            _ => return None,
+        }
+        if value.is_none() && expected_hash.len() != 32 {
+            return None;
+        }
+    }
+    extract_log(value?, input.log_index as usize)
+}
+
+/// Address, first topic and first data word of log `log_index` of an encoded receipt.
+fn extract_log(receipt: &[u8], log_index: usize) -> Option<[u8; EXTRACTED_LOG_LEN]> {
+    // Typed receipts are the transaction type followed by the RLP list; legacy ones are the list.
+    let receipt = match receipt.first()? {
+        0..=0x7f => &receipt[1..],
+        _ => receipt,
+    };
+    let fields = rlp_list_items(rlp_payload(receipt, true)?)?;
+    let logs = rlp_list_items(rlp_payload(fields.get(3)?, true)?)?;
+    let log = rlp_list_items(rlp_payload(logs.get(log_index)?, true)?)?;
+    let [address, topics, data] = log.as_slice() else {
+        return None;
+    };
+    let address = rlp_payload(address, false)?;
+    let topic = rlp_payload(rlp_list_items(rlp_payload(topics, true)?)?.first()?, false)?;
+    let data = rlp_payload(data, false)?;
+    if address.len() != 20 || topic.len() != 32 || data.len() < 32 {
+        return None;
+    }
+
+    let mut fields = [0u8; EXTRACTED_LOG_LEN];
+    fields[..20].copy_from_slice(address);
+    fields[20..52].copy_from_slice(topic);
+    fields[52..].copy_from_slice(&data[..32]);
+    Some(fields)
+}
+
+struct Log {
+    address: [u8; 20],
+    topics: Vec<[u8; 32]>,
+    data: Vec<u8>,
+}
+
+impl Log {
+    /// An ERC-20 `Transfer(from, to, amount)` event.
+    fn random_transfer(rng: &mut impl RngCore) -> Self {
+        let mut address = [0u8; 20];
+        rng.fill_bytes(&mut address);
+        let mut topics = vec![keccak(b"Transfer(address,address,uint256)")];
+        for _ in 0..2 {
+            let mut participant = [0u8; 32];
+            rng.fill_bytes(&mut participant[12..]);
+            topics.push(participant);
+        }
+        let mut amount = [0u8; 32];
+        rng.fill_bytes(&mut amount[16..]);
+        Log {
+            address,
+            topics,
+            data: amount.to_vec(),
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let topics: Vec<Vec<u8>> = self.topics.iter().map(|topic| rlp_bytes(topic)).collect();
+        rlp_list(&[
+            rlp_bytes(&self.address),
+            rlp_list(&topics),
+            rlp_bytes(&self.data),
+        ])
+    }
+
+    fn extracted_fields(&self) -> [u8; EXTRACTED_LOG_LEN] {
+        let mut fields = [0u8; EXTRACTED_LOG_LEN];
+        fields[..20].copy_from_slice(&self.address);
+        fields[20..52].copy_from_slice(&self.topics[0]);
+        fields[52..].copy_from_slice(&self.data[..32]);
+        fields
+    }
+}
+
+struct Receipt {
+    cumulative_gas: u64,
+    logs: Vec<Log>,
+}
+
+impl Receipt {
+    /// A successful transaction emitting one to three transfers.
+    fn random(rng: &mut impl RngCore, cumulative_gas: u64) -> Self {
+        let num_logs = rng.gen_range(1..=3);
+        Receipt {
+            cumulative_gas: cumulative_gas + rng.gen_range(21_000..200_000),
+            logs: (0..num_logs).map(|_| Log::random_transfer(rng)).collect(),
+        }
+    }
+
+    /// `0x02 || rlp([status, cumulative_gas, logs_bloom, logs])`.
+    fn encode(&self) -> Vec<u8> {
+        let logs: Vec<Vec<u8>> = self.logs.iter().map(Log::encode).collect();
+        let mut encoded = vec![EIP1559_TX_TYPE];
+        encoded.extend(rlp_list(&[
+            rlp_uint(1),
+            rlp_uint(self.cumulative_gas),
+            rlp_bytes(&self.bloom()),
+            rlp_list(&logs),
+        ]));
+        encoded
+    }
+
+    /// 2048-bit bloom filter over the addresses and topics of the logs.
+    fn bloom(&self) -> [u8; 256] {
+        let mut bloom = [0u8; 256];
+        let entries = self.logs.iter().flat_map(|log| {
+            std::iter::once(&log.address[..]).chain(log.topics.iter().map(|t| &t[..]))
+        });
+        for entry in entries {
+            let hash = keccak(entry);
+            for pair in hash[..6].chunks(2) {
+                let bit = usize::from(u16::from_be_bytes([pair[0], pair[1]]) & 0x7ff);
+                bloom[255 - bit / 8] |= 1 << (bit % 8);
+            }
+        }
+        bloom
+    }
+}
+
+/// Encodes the trie over `entries` (sorted by key, as nibbles) below `depth` and returns the
+/// node's RLP encoding. Nodes on the path to `target` are appended to `proof`, deepest first.
+fn build_node(
+    entries: &[&(Vec<u8>, Vec<u8>)],
+    depth: usize,
+    target: Option<&[u8]>,
+    proof: &mut Vec<Vec<u8>>,
+) -> Vec<u8> {
+    let node = if let [(key, value)] = entries {
+        rlp_list(&[
+            rlp_bytes(&hex_prefix(&key[depth..], true)),
+            rlp_bytes(value),
+        ])
+    } else {
+        let first = &entries[0].0;
+        let shared = entries[1..]
+            .iter()
+            .map(|(key, _)| common_prefix(&first[depth..], &key[depth..]))
+            .min()
+            .unwrap_or_default();
+        if shared > 0 {
+            let target = target.filter(|t| t[depth..].starts_with(&first[depth..depth + shared]));
+            let child = build_node(entries, depth + shared, target, proof);
+            rlp_list(&[
+                rlp_bytes(&hex_prefix(&first[depth..depth + shared], false)),
+                reference(&child),
+            ])
+        } else {
+            let mut items = Vec::with_capacity(17);
+            let mut value = rlp_bytes(&[]);
+            for nibble in 0..16u8 {
+                let children: Vec<_> = entries
+                    .iter()
+                    .filter(|(key, _)| key.get(depth) == Some(&nibble))
+                    .copied()
+                    .collect();
+                if children.is_empty() {
+                    items.push(rlp_bytes(&[]));
+                    continue;
+                }
+                let target = target.filter(|t| t.get(depth) == Some(&nibble));
+                items.push(reference(&build_node(&children, depth + 1, target, proof)));
+            }
+            if let Some((_, v)) = entries.iter().find(|(key, _)| key.len() == depth) {
+                value = rlp_bytes(v);
+            }
+            items.push(value);
+            rlp_list(&items)
+        }
+    };
+    if target.is_some() {
+        proof.push(node.clone());
+    }
+    node
+}
+
+/// How a parent refers to an encoded child: inline if shorter than a hash, by hash otherwise.
+fn reference(node: &[u8]) -> Vec<u8> {
+    if node.len() < 32 {
+        node.to_vec()
+    } else {
+        rlp_bytes(&keccak(node))
+    }
+}
+
+fn common_prefix(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+fn nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// Compact (hex-prefix) encoding of a nibble path with the leaf flag.
+fn hex_prefix(path: &[u8], is_leaf: bool) -> Vec<u8> {
+    let flag = if is_leaf { 2 } else { 0 };
+    let mut encoded = if path.len() % 2 == 1 {
+        vec![((flag + 1) << 4) | path[0]]
+    } else {
+        vec![flag << 4]
+    };
+    let rest = &path[path.len() % 2..];
+    encoded.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+    encoded
+}
+
+fn decode_hex_prefix(encoded: &[u8]) -> Option<(Vec<u8>, bool)> {
+    let (&first, rest) = encoded.split_first()?;
+    let flag = first >> 4;
+    if flag > 3 {
+        return None;
+    }
+    let mut path = if flag % 2 == 1 {
+        vec![first & 0x0f]
+    } else {
+        Vec::new()
+    };
+    path.extend(nibbles(rest));
+    Some((path, flag >= 2))
+}
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn rlp_header(offset: u8, len: usize) -> Vec<u8> {
+    if len < 56 {
+        return vec![offset + len as u8];
+    }
+    let len_bytes = len.to_be_bytes();
+    let len_bytes = &len_bytes[len_bytes.iter().take_while(|&&b| b == 0).count()..];
+    let mut header = vec![offset + 55 + len_bytes.len() as u8];
+    header.extend_from_slice(len_bytes);
+    header
+}
+
+fn rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    if let [byte @ 0..=0x7f] = bytes {
+        return vec![*byte];
+    }
+    let mut encoded = rlp_header(0x80, bytes.len());
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+fn rlp_uint(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    rlp_bytes(&bytes[bytes.iter().take_while(|&&b| b == 0).count()..])
+}
+
+fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut encoded = rlp_header(0xc0, payload.len());
+    encoded.extend(payload);
+    encoded
+}
+
+/// Splits the RLP item at the front of `data` into (is list, payload, rest).
+fn rlp_split(data: &[u8]) -> Option<(bool, &[u8], &[u8])> {
+    let (&prefix, rest) = data.split_first()?;
+    let (is_list, offset, len) = match prefix {
+        0..=0x7f => return Some((false, &data[..1], rest)),
+        0x80..=0xb7 => (false, 0, usize::from(prefix - 0x80)),
+        0xb8..=0xbf => (false, usize::from(prefix - 0xb7), 0),
+        0xc0..=0xf7 => (true, 0, usize::from(prefix - 0xc0)),
+        0xf8..=0xff => (true, usize::from(prefix - 0xf7), 0),
+    };
+    let len = if offset > 0 {
+        let len_bytes = rest.get(..offset)?;
+        len_bytes.iter().try_fold(0usize, |len, &b| {
+            len.checked_mul(256)?.checked_add(usize::from(b))
+        })?
+    } else {
+        len
+    };
+    let payload = rest.get(offset..offset.checked_add(len)?)?;
+    Some((is_list, payload, &rest[offset + len..]))
+}
+
+/// Payload of `item`, which must be a single RLP item of the given kind.
+fn rlp_payload(item: &[u8], list: bool) -> Option<&[u8]> {
+    match rlp_split(item)? {
+        (is_list, payload, []) if is_list == list => Some(payload),
+        _ => None,
+    }
+}
+
+/// The encoded items of a list payload.
+fn rlp_list_items(mut payload: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let (_, _, rest) = rlp_split(payload)?;
+        items.push(&payload[..payload.len() - rest.len()]);
+        payload = rest;
+    }
+    Some(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_rlp_known_encodings() {
+        assert_eq!(rlp_uint(0), [0x80]);
+        assert_eq!(rlp_uint(127), [0x7f]);
+        assert_eq!(rlp_uint(128), [0x81, 0x80]);
+        assert_eq!(rlp_bytes(b"dog"), [0x83, b'd', b'o', b'g']);
+        assert_eq!(
+            rlp_list(&[rlp_bytes(b"cat"), rlp_bytes(b"dog")]),
+            [0xc8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']
+        );
+        let long = rlp_bytes(&[0xaa; 60]);
+        assert_eq!(long[..2], [0xb8, 60]);
+        assert_eq!(rlp_payload(&long, false), Some(&[0xaa; 60][..]));
+    }
+
+    #[test]
+    fn test_single_receipt_root() {
+        // The root of a one-entry trie is the hash of its leaf.
+        let mut rng = StdRng::seed_from_u64(1);
+        let (input, _) = generate(1, &mut rng);
+        assert_eq!(input.proof.len(), 1);
+        assert_eq!(input.receipts_root, keccak(&input.proof[0]));
+    }
+
+    #[test]
+    fn test_generated_proofs_verify() {
+        for num_receipts in [1, 2, 16, 130, 1024] {
+            let mut rng = StdRng::seed_from_u64(num_receipts as u64);
+            let (input, expected) = generate(num_receipts, &mut rng);
+            assert_eq!(
+                verify_receipt_proof(&input).map(|fields| fields.to_vec()),
+                Some(expected),
+                "{num_receipts} receipts"
+            );
+
+            let mut tampered = input.clone();
+            let last = tampered.proof.last_mut().unwrap();
+            *last.last_mut().unwrap() ^= 1;
+            assert!(verify_receipt_proof(&tampered).is_none());
+
+            let mut wrong_key = input.clone();
+            wrong_key.key = rlp_uint(num_receipts as u64 + 1);
+            assert!(verify_receipt_proof(&wrong_key).is_none());
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Where benchmark runs write their result files.
+//!
+//! Metrics, memory reports and span timings of a run go to `results/<run-id>/` at the workspace
+//! root instead of the directory the bench happens to run in, so `collect_benchmarks` reads one
+//! directory and two runs with different [`RUN_ID_ENV`] values never overwrite each other. File
+//! names inside the run directory are the [`BenchId`] ones, which are unique across systems.
+
+use crate::BenchId;
+use crate::bench::mem::Phase;
+use serde::Serialize;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Root of the run directories; defaults to `results/` at the workspace root.
+pub const RESULTS_DIR_ENV: &str = "BENCH_RESULTS_DIR";
+
+/// Name of the run directory; defaults to [`DEFAULT_RUN_ID`].
+pub const RUN_ID_ENV: &str = "BENCH_RUN_ID";
+
+pub const DEFAULT_RUN_ID: &str = "local";
+
+/// Writes and locates the result files of one run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Writer {
+    run_id: String,
+    dir: PathBuf,
+}
+
+impl Writer {
+    /// A `run_id` with slashes nests the run, e.g. `<campaign>/r1` for the repeats of a
+    /// campaign.
+    pub fn new(root: impl AsRef<Path>, run_id: &str) -> Self {
+        Self {
+            run_id: run_id.to_string(),
+            dir: root.as_ref().join(run_id),
+        }
+    }
+
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// The run selected by [`RESULTS_DIR_ENV`] and [`RUN_ID_ENV`].
+    pub fn from_env() -> Self {
+        let root = env::var_os(RESULTS_DIR_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(default_root, PathBuf::from);
+        let run_id = env::var(RUN_ID_ENV)
+            .ok()
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(|| DEFAULT_RUN_ID.to_string());
+        Self::new(root, &run_id)
+    }
+
+    /// The run directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn metrics_path(&self, id: &BenchId) -> PathBuf {
+        self.dir.join(id.metrics_filename())
+    }
+
+    pub fn mem_report_path(&self, id: &BenchId) -> PathBuf {
+        self.dir.join(id.mem_report_filename())
+    }
+
+    pub fn phase_mem_report_path(&self, id: &BenchId, phase: Phase) -> PathBuf {
+        self.dir.join(id.phase_mem_report_filename(phase))
+    }
+
+    pub fn spans_path(&self, id: &BenchId) -> PathBuf {
+        self.dir.join(id.spans_filename())
+    }
+
+    /// Creates the run directory if needed.
+    pub fn create_dir(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)
+    }
+
+    /// Writes `data` as pretty JSON to `path`, creating the run directory if needed.
+    pub fn write_json<T: Serialize>(&self, path: &Path, data: &T) -> io::Result<()> {
+        self.create_dir()?;
+        fs::write(path, serde_json::to_string_pretty(data)?)
+    }
+}
+
+/// `results/` at the workspace root (the parent of this crate).
+fn default_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("utils is inside the workspace")
+        .join("results")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_directories_are_separate() {
+        let id = BenchId::new("sha256", 128, "sp1", None);
+        let first = Writer::new("/tmp/results", "1234-1");
+        let second = Writer::new("/tmp/results", "1234-2");
+        assert_eq!(
+            first.metrics_path(&id),
+            Path::new("/tmp/results/1234-1/sha256_128_sp1_metrics.json")
+        );
+        assert_eq!(
+            second.mem_report_path(&id),
+            Path::new("/tmp/results/1234-2/sha256_128_sp1_mem_report.json")
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Optional confinement of the processes the harness spawns.
+//!
+//! The harness builds and runs memory binaries, and some crates shell out to external tools
+//! (`nargo`, `go`, the compilers pulled by ere crates). With [`SANDBOX_ENV`] set, those processes
+//! run without network access, so upstream build scripts cannot reach the network and background
+//! downloads cannot perturb a measurement, and with per-process resource limits. `bwrap` also
+//! mounts the filesystem read-only except for the workspace, `/tmp` and the paths in
+//! [`SANDBOX_WRITABLE_ENV`]. Dependencies have to be fetched beforehand (`cargo fetch`), since
+//! builds run `--offline` in the sandbox. The settings are recorded in the run manifest (see
+//! [`crate::manifest`]).
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::env;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// `none` (default), `no-network` (`unshare --net`) or `bwrap` (bubblewrap).
+pub const SANDBOX_ENV: &str = "BENCH_SANDBOX";
+/// Address-space limit of every sandboxed process, in MiB.
+pub const SANDBOX_MEMORY_ENV: &str = "BENCH_SANDBOX_MEMORY_MB";
+/// CPU-time limit of every sandboxed process, in seconds.
+pub const SANDBOX_CPU_ENV: &str = "BENCH_SANDBOX_CPU_SECONDS";
+/// Open-file limit of every sandboxed process.
+pub const SANDBOX_NOFILE_ENV: &str = "BENCH_SANDBOX_NOFILE";
+/// Colon-separated paths `bwrap` mounts writable besides the workspace and `/tmp`.
+pub const SANDBOX_WRITABLE_ENV: &str = "BENCH_SANDBOX_WRITABLE";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SandboxMode {
+    #[default]
+    None,
+    NoNetwork,
+    Bwrap,
+}
+
+impl SandboxMode {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "" | "none" | "0" => Some(Self::None),
+            "no-network" => Some(Self::NoNetwork),
+            "bwrap" => Some(Self::Bwrap),
+            _ => None,
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    pub memory_mb: Option<u64>,
+    pub cpu_seconds: Option<u64>,
+    pub open_files: Option<u64>,
+}
+
+impl ResourceLimits {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandboxSettings {
+    pub mode: SandboxMode,
+    #[serde(default, skip_serializing_if = "ResourceLimits::is_empty")]
+    pub limits: ResourceLimits,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub writable: Vec<PathBuf>,
+}
+
+impl SandboxSettings {
+    /// Settings of the `BENCH_SANDBOX*` variables; panics on an invalid value so that a typo
+    /// does not silently run unconfined.
+    pub fn from_env() -> Self {
+        let mode = env::var(SANDBOX_ENV).map_or(SandboxMode::None, |value| {
+            SandboxMode::parse(value.trim())
+                .unwrap_or_else(|| panic!("Invalid {SANDBOX_ENV} mode: {value:?}"))
+        });
+        Self {
+            mode,
+            limits: ResourceLimits {
+                memory_mb: limit(SANDBOX_MEMORY_ENV),
+                cpu_seconds: limit(SANDBOX_CPU_ENV),
+                open_files: limit(SANDBOX_NOFILE_ENV),
+            },
+            writable: env::var_os(SANDBOX_WRITABLE_ENV)
+                .map(|paths| env::split_paths(&paths).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Whether spawned processes cannot reach the network.
+    pub fn offline(&self) -> bool {
+        self.mode != SandboxMode::None
+    }
+
+    /// Program and arguments that run the command appended to them in the sandbox.
+    pub fn launcher(&self) -> Vec<String> {
+        let mut launcher: Vec<String> = match self.mode {
+            SandboxMode::None => Vec::new(),
+            SandboxMode::NoNetwork => ["unshare", "--net", "--map-root-user", "--"]
+                .map(String::from)
+                .to_vec(),
+            SandboxMode::Bwrap => [
+                "bwrap",
+                "--die-with-parent",
+                "--unshare-net",
+                "--ro-bind",
+                "/",
+                "/",
+                "--dev",
+                "/dev",
+                "--proc",
+                "/proc",
+                "--bind",
+                "/tmp",
+                "/tmp",
+            ]
+            .map(String::from)
+            .to_vec(),
+        };
+        if self.mode == SandboxMode::Bwrap {
+            for path in std::iter::once(workspace_root()).chain(self.writable.iter().cloned()) {
+                let path = path.display().to_string();
+                launcher.extend(["--bind".to_string(), path.clone(), path]);
+            }
+            launcher.push("--".to_string());
+        }
+        launcher
+    }
+
+    /// `program` run in the sandbox, with the resource limits applied.
+    pub fn command(&self, program: impl AsRef<OsStr>) -> Command {
+        let mut command = match self.launcher().split_first() {
+            Some((launcher, args)) => {
+                let mut command = Command::new(launcher);
+                command.args(args).arg(program);
+                command
+            }
+            None => Command::new(program),
+        };
+        self.apply_limits(&mut command);
+        command
+    }
+
+    /// Applies the resource limits to `command`, which inherits them into its children.
+    #[cfg(unix)]
+    pub(crate) fn apply_limits(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        if self.limits.is_empty() {
+            return;
+        }
+        let limits = [
+            (libc::RLIMIT_AS, self.limits.memory_mb.map(|mb| mb << 20)),
+            (libc::RLIMIT_CPU, self.limits.cpu_seconds),
+            (libc::RLIMIT_NOFILE, self.limits.open_files),
+        ];
+        // SAFETY: the closure only calls setrlimit, which is async-signal-safe.
+        unsafe {
+            command.pre_exec(move || {
+                for (resource, limit) in limits {
+                    let Some(limit) = limit else { continue };
+                    let rlimit = libc::rlimit {
+                        rlim_cur: limit as libc::rlim_t,
+                        rlim_max: limit as libc::rlim_t,
+                    };
+                    if libc::setrlimit(resource, &rlimit) != 0 {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                }
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn apply_limits(&self, _command: &mut Command) {}
+}
+
+/// `program` run in the sandbox configured by the environment.
+pub fn command(program: impl AsRef<OsStr>) -> Command {
+    SandboxSettings::from_env().command(program)
+}
+
+fn limit(name: &str) -> Option<u64> {
+    let value = env::var(name).ok().filter(|value| !value.is_empty())?;
+    Some(
+        value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&limit| limit > 0)
+            .unwrap_or_else(|| panic!("Invalid {name} limit: {value:?}")),
+    )
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("utils is inside the workspace")
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launchers_wrap_the_program() {
+        let unconfined = SandboxSettings::default();
+        assert!(unconfined.launcher().is_empty());
+        assert_eq!(unconfined.command("nargo").get_program(), "nargo");
+
+        let bwrap = SandboxSettings {
+            mode: SandboxMode::Bwrap,
+            writable: vec![PathBuf::from("/cache")],
+            ..Default::default()
+        };
+        let command = bwrap.command("nargo");
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(command.get_program(), "bwrap");
+        assert!(args.contains(&OsStr::new("--unshare-net")));
+        assert!(args.windows(3).any(|w| w == ["--bind", "/cache", "/cache"]));
+        assert_eq!(args[args.len() - 2..], ["--", "nargo"]);
+    }
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+/// Fewer distinct sizes than this cannot distinguish the candidate models.
+pub const MIN_SIZES: usize = 3;
+
+/// Candidate growth models, fitted as `y = a + b * f(n)`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingModel {
+    Linear,
+    NLogN,
+    Quadratic,
+}
+
+impl ScalingModel {
+    pub const ALL: [ScalingModel; 3] = [
+        ScalingModel::Linear,
+        ScalingModel::NLogN,
+        ScalingModel::Quadratic,
+    ];
+
+    fn basis(self, n: f64) -> f64 {
+        match self {
+            ScalingModel::Linear => n,
+            ScalingModel::NLogN => n * n.ln(),
+            ScalingModel::Quadratic => n * n,
+        }
+    }
+}
+
+/// Best-fitting growth model of one metric over input size.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ScalingFit {
+    pub model: ScalingModel,
+    /// Coefficient of determination of `model`.
+    pub r_squared: f64,
+    /// Empirical exponent `k` of `y ~ n^k`, from a log-log least-squares fit.
+    pub exponent: f64,
+    /// Coefficient of determination of the log-log fit.
+    pub exponent_r_squared: f64,
+}
+
+/// Fits `(input_size, value)` samples. Repeated sizes are kept as separate samples; returns `None`
+/// with fewer than [`MIN_SIZES`] distinct sizes or when sizes/values are not positive.
+pub fn fit_scaling(samples: &[(f64, f64)]) -> Option<ScalingFit> {
+    let mut sizes: Vec<f64> = samples.iter().map(|&(n, _)| n).collect();
+    sizes.sort_by(f64::total_cmp);
+    sizes.dedup();
+    if sizes.len() < MIN_SIZES || samples.iter().any(|&(n, y)| n <= 0.0 || y <= 0.0) {
+        return None;
+    }
+
+    let ys: Vec<f64> = samples.iter().map(|&(_, y)| y).collect();
+    let (model, r_squared) = ScalingModel::ALL
+        .into_iter()
+        .filter_map(|model| {
+            let xs: Vec<f64> = samples.iter().map(|&(n, _)| model.basis(n)).collect();
+            Some((model, linear_fit(&xs, &ys)?.r_squared))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let log_ns: Vec<f64> = samples.iter().map(|&(n, _)| n.ln()).collect();
+    let log_ys: Vec<f64> = ys.iter().map(|y| y.ln()).collect();
+    let power = linear_fit(&log_ns, &log_ys)?;
+
+    Some(ScalingFit {
+        model,
+        r_squared,
+        exponent: power.slope,
+        exponent_r_squared: power.r_squared,
+    })
+}
+
+struct LinearFit {
+    slope: f64,
+    r_squared: f64,
+}
+
+/// Ordinary least squares `y = a + b * x`.
+fn linear_fit(xs: &[f64], ys: &[f64]) -> Option<LinearFit> {
+    let len = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / len;
+    let mean_y = ys.iter().sum::<f64>() / len;
+    let sxx: f64 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = xs
+        .iter()
+        .zip(ys)
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let syy: f64 = ys.iter().map(|y| (y - mean_y).powi(2)).sum();
+    if sxx == 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    // Constant values are explained perfectly by any model.
+    let r_squared = if syy == 0.0 {
+        1.0
+    } else {
+        (sxy * sxy) / (sxx * syy)
+    };
+    Some(LinearFit { slope, r_squared })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(f: impl Fn(f64) -> f64) -> Vec<(f64, f64)> {
+        [128.0, 256.0, 512.0, 1024.0, 2048.0]
+            .into_iter()
+            .map(|n| (n, f(n)))
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_identifies_model_and_exponent() {
+        let linear = fit_scaling(&samples(|n| 5.0 + 3.0 * n)).unwrap();
+        assert_eq!(linear.model, ScalingModel::Linear);
+        assert!((linear.r_squared - 1.0).abs() < 1e-9);
+
+        let quadratic = fit_scaling(&samples(|n| 2.0 * n * n)).unwrap();
+        assert_eq!(quadratic.model, ScalingModel::Quadratic);
+        assert!((quadratic.exponent - 2.0).abs() < 1e-9);
+
+        let n_log_n = fit_scaling(&samples(|n| n * n.ln())).unwrap();
+        assert_eq!(n_log_n.model, ScalingModel::NLogN);
+        assert!(n_log_n.exponent > 1.0 && n_log_n.exponent < 1.2);
+    }
+
+    #[test]
+    fn test_fit_requires_enough_sizes() {
+        assert!(fit_scaling(&[(128.0, 1.0), (256.0, 2.0), (256.0, 2.1)]).is_none());
+        assert!(fit_scaling(&[(128.0, 0.0), (256.0, 2.0), (512.0, 4.0)]).is_none());
+    }
+}
@@ -0,0 +1,417 @@
+//! Time-budgeted runs of a subset of the bench matrix, for nightly benchmarking of a matrix that
+//! no longer fits in one night.
+//!
+//! The `schedule` binary ranks every measurement declared in the bench matrix against earlier
+//! collections: systems whose upstream version changed since they were last measured come first,
+//! then measurements never made or that failed, then the rest from the least recently measured.
+//! It estimates each measurement's wall time from the last recorded prove and verify durations
+//! and greedily fills the budget in that order, writing the selected and deferred measurements as
+//! a [`SchedulePlan`]. With [`SCHEDULE_ENV`] pointing at the plan, the harness only runs the
+//! selected sizes, so the run's results are partial and `collect_benchmarks coverage` lists the
+//! deferred ones as not run.
+
+use crate::BenchId;
+use crate::harness::SAMPLE_SIZE;
+use crate::matrix::{BenchMatrix, DeclaredBench, SamplingConfig};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Path of a [`SchedulePlan`]; when set, benches only run the sizes it selects.
+pub const SCHEDULE_ENV: &str = "BENCH_SCHEDULE";
+
+/// Criterion's default warm-up and measurement times.
+const CRITERION_WARM_UP: Duration = Duration::from_secs(3);
+const CRITERION_MEASUREMENT: Duration = Duration::from_secs(5);
+
+/// Why a measurement is scheduled; variants are ranked in declaration order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// The system's upstream version differs from the one it was last measured at.
+    VersionChanged,
+    /// No earlier collection has a successful measurement.
+    Missing,
+    /// Measured before; older measurements rank first.
+    Stale,
+}
+
+/// Latest successful measurement of one id across the earlier collections.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasurementHistory {
+    /// Time of the collection it comes from.
+    pub measured_at: DateTime<Utc>,
+    /// Upstream version of the system at the time (`BenchProperties.version`).
+    pub version: Option<String>,
+    pub proof_duration: Duration,
+    pub verify_duration: Duration,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PlannedMeasurement {
+    /// Measurement id, `{target}_{size}_{system}[_{feature}]`.
+    pub id: String,
+    pub system: String,
+    pub target: String,
+    pub feature: Option<String>,
+    pub input_size: usize,
+    pub priority: Priority,
+    /// RFC 3339 time of the last successful measurement.
+    pub last_measured: Option<String>,
+    /// Estimated wall time of the measurement.
+    pub estimate_secs: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SchedulePlan {
+    /// RFC 3339 time the plan was made.
+    pub created_at: String,
+    pub budget_secs: f64,
+    /// Total estimate of the selected measurements.
+    pub estimated_secs: f64,
+    /// Measurements to run, in priority order.
+    pub selected: Vec<PlannedMeasurement>,
+    /// Measurements that did not fit in the budget, in priority order.
+    pub deferred: Vec<PlannedMeasurement>,
+}
+
+impl SchedulePlan {
+    pub fn is_selected(&self, id: &BenchId) -> bool {
+        let id = id.to_string();
+        self.selected.iter().any(|m| m.id == id)
+    }
+
+    /// The benches to run for the selected measurements, one per (system, target, feature).
+    pub fn benches(&self) -> Vec<DeclaredBench> {
+        let mut benches: BTreeMap<_, DeclaredBench> = BTreeMap::new();
+        for m in &self.selected {
+            benches
+                .entry((m.system.clone(), m.target.clone(), m.feature.clone()))
+                .or_insert_with(|| DeclaredBench {
+                    system: m.system.clone(),
+                    target: m.target.clone(),
+                    feature: m.feature.clone(),
+                    sizes: Some(Vec::new()),
+                    optional: false,
+                })
+                .sizes
+                .get_or_insert_with(Vec::new)
+                .push(m.input_size);
+        }
+        for bench in benches.values_mut() {
+            bench.sizes.get_or_insert_with(Vec::new).sort_unstable();
+        }
+        benches.into_values().collect()
+    }
+}
+
+/// Estimated wall time of one measurement with the given Criterion `sampling`: the metrics pass
+/// and the memory binary prove once each, and Criterion warms up and then runs each step
+/// `sample_size` times or for the measurement time, whichever takes longer.
+pub fn estimate(sampling: &SamplingConfig, prove: Duration, verify: Duration) -> Duration {
+    let samples = sampling.sample_size.unwrap_or(SAMPLE_SIZE) as u32;
+    let warm_up = sampling.warm_up_time().unwrap_or(CRITERION_WARM_UP);
+    let measurement = sampling.measurement_time().unwrap_or(CRITERION_MEASUREMENT);
+    let criterion = |step: Duration| warm_up.max(step) + measurement.max(step * samples);
+    prove * 2 + verify + criterion(prove) + criterion(verify)
+}
+
+/// Ranks the measurements of the `declared` benches and selects them in order while the
+/// estimates fit in `budget`. `versions` are the current upstream versions by system name; a
+/// measurement without `history` is estimated at `default_estimate`.
+pub fn plan(
+    declared: &[DeclaredBench],
+    matrix: &BenchMatrix,
+    history: &BTreeMap<String, MeasurementHistory>,
+    versions: &BTreeMap<String, String>,
+    budget: Duration,
+    default_estimate: Duration,
+    now: DateTime<Utc>,
+) -> SchedulePlan {
+    let mut ranked = Vec::new();
+    for bench in declared {
+        let current_version = versions.get(&bench.system);
+        for id in bench.ids() {
+            let key = id.to_string();
+            let last = history.get(&key);
+            let priority = match last {
+                None => Priority::Missing,
+                Some(last) if current_version.is_some_and(|v| last.version.as_ref() != Some(v)) => {
+                    Priority::VersionChanged
+                }
+                Some(_) => Priority::Stale,
+            };
+            let estimate = last.map_or(default_estimate, |last| {
+                estimate(
+                    &matrix.sampling(&bench.system, &bench.target, id.input_size),
+                    last.proof_duration,
+                    last.verify_duration,
+                )
+            });
+            ranked.push((
+                (
+                    priority,
+                    last.map(|last| last.measured_at),
+                    estimate,
+                    key.clone(),
+                ),
+                PlannedMeasurement {
+                    id: key,
+                    system: bench.system.clone(),
+                    target: bench.target.clone(),
+                    feature: bench.feature.clone(),
+                    input_size: id.input_size,
+                    priority,
+                    last_measured: last.map(|last| last.measured_at.to_rfc3339()),
+                    estimate_secs: estimate.as_secs_f64(),
+                },
+            ));
+        }
+    }
+    // Ties (e.g. everything from the same collection) go to the cheaper measurement.
+    ranked.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut spent = Duration::ZERO;
+    let mut selected = Vec::new();
+    let mut deferred = Vec::new();
+    for ((_, _, estimate, _), measurement) in ranked {
+        if spent + estimate <= budget {
+            spent += estimate;
+            selected.push(measurement);
+        } else {
+            deferred.push(measurement);
+        }
+    }
+    SchedulePlan {
+        created_at: now.to_rfc3339(),
+        budget_secs: budget.as_secs_f64(),
+        estimated_secs: spent.as_secs_f64(),
+        selected,
+        deferred,
+    }
+}
+
+#[derive(Deserialize)]
+struct Collected {
+    metadata: CollectedMetadata,
+    #[serde(default)]
+    systems: BTreeMap<String, CollectedSystem>,
+    measurements: Vec<CollectedMeasurement>,
+}
+
+#[derive(Deserialize)]
+struct CollectedMetadata {
+    timestamp: String,
+}
+
+#[derive(Deserialize)]
+struct CollectedSystem {
+    version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CollectedMeasurement {
+    /// System key, including the feature tag (`jolt_gpu`).
+    system: String,
+    target: String,
+    input_size: usize,
+    proof_duration: u64,
+    verify_duration: u64,
+    #[serde(default)]
+    failures: Vec<serde::de::IgnoredAny>,
+    third_party: Option<serde::de::IgnoredAny>,
+}
+
+/// Latest successful measurement of every id across the `collected_benchmarks.json` files at
+/// `paths`, which may come in any order. Third-party measurements are left out.
+pub fn read_history(
+    paths: &[impl AsRef<Path>],
+) -> io::Result<BTreeMap<String, MeasurementHistory>> {
+    let mut history: BTreeMap<String, MeasurementHistory> = BTreeMap::new();
+    for path in paths {
+        let path = path.as_ref();
+        let collected: Collected = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::other(format!("{}: {}", path.display(), e)))?;
+        let measured_at = DateTime::parse_from_rfc3339(&collected.metadata.timestamp)
+            .map_err(|e| io::Error::other(format!("{}: {}", path.display(), e)))?
+            .with_timezone(&Utc);
+        for m in collected.measurements {
+            if !m.failures.is_empty() || m.third_party.is_some() {
+                continue;
+            }
+            let id = BenchId::new(&m.target, m.input_size, &m.system, None).to_string();
+            if history
+                .get(&id)
+                .is_some_and(|h| h.measured_at >= measured_at)
+            {
+                continue;
+            }
+            let version = collected
+                .systems
+                .get(&m.system)
+                .and_then(|system| system.version.clone());
+            history.insert(
+                id,
+                MeasurementHistory {
+                    measured_at,
+                    version,
+                    proof_duration: Duration::from_nanos(m.proof_duration),
+                    verify_duration: Duration::from_nanos(m.verify_duration),
+                },
+            );
+        }
+    }
+    Ok(history)
+}
+
+/// Loads the plan [`SCHEDULE_ENV`] points at, if set, panicking on an unreadable plan so that a
+/// scheduled run does not silently measure everything.
+pub fn load_schedule() -> Option<SchedulePlan> {
+    let path = env::var(SCHEDULE_ENV).ok().filter(|s| !s.is_empty())?;
+    let plan = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| panic!("Invalid schedule plan {}: {}", path, e));
+    Some(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declared(system: &str, sizes: &[usize]) -> DeclaredBench {
+        DeclaredBench {
+            system: system.to_string(),
+            target: "sha256".to_string(),
+            sizes: Some(sizes.to_vec()),
+            ..DeclaredBench::default()
+        }
+    }
+
+    fn measured(days_ago: i64, version: &str, prove_secs: u64) -> MeasurementHistory {
+        MeasurementHistory {
+            measured_at: DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc)
+                - chrono::Duration::days(days_ago),
+            version: Some(version.to_string()),
+            proof_duration: Duration::from_secs(prove_secs),
+            verify_duration: Duration::from_millis(10),
+        }
+    }
+
+    #[test]
+    fn test_estimate_covers_the_longer_of_samples_and_measurement_time() {
+        let defaults = SamplingConfig::default();
+        let fast = estimate(&defaults, Duration::from_millis(100), Duration::ZERO);
+        // 0.2 s outside Criterion, 3 s warm-up and 5 s measurement per step.
+        assert_eq!(fast, Duration::from_millis(200 + 2 * 8000));
+
+        let slow = estimate(&defaults, Duration::from_secs(10), Duration::ZERO);
+        assert_eq!(slow, Duration::from_secs(20 + 10 + 100 + 3 + 5));
+    }
+
+    #[test]
+    fn test_plan_ranks_version_changes_then_missing_then_oldest() {
+        let history = BTreeMap::from([
+            ("sha256_128_jolt".to_string(), measured(1, "0.2", 1)),
+            ("sha256_256_jolt".to_string(), measured(1, "0.2", 1)),
+            ("sha256_128_sp1".to_string(), measured(1, "4.0", 1)),
+            ("sha256_256_sp1".to_string(), measured(9, "4.0", 1)),
+        ]);
+        let versions = BTreeMap::from([
+            ("jolt".to_string(), "0.3".to_string()),
+            ("sp1".to_string(), "4.0".to_string()),
+        ]);
+        let declared = [
+            declared("sp1", &[128, 256]),
+            declared("jolt", &[128, 256]),
+            declared("risc0", &[128]),
+        ];
+        let now = Utc::now();
+        let one = estimate(
+            &SamplingConfig::default(),
+            Duration::from_secs(1),
+            Duration::from_millis(10),
+        );
+
+        let plan = plan(
+            &declared,
+            &BenchMatrix::default(),
+            &history,
+            &versions,
+            one * 4,
+            one,
+            now,
+        );
+        let ids: Vec<_> = plan.selected.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "sha256_128_jolt",
+                "sha256_256_jolt",
+                "sha256_128_risc0",
+                "sha256_256_sp1"
+            ]
+        );
+        assert_eq!(plan.selected[0].priority, Priority::VersionChanged);
+        assert_eq!(plan.selected[2].priority, Priority::Missing);
+        assert_eq!(plan.deferred.len(), 1);
+        assert_eq!(plan.deferred[0].id, "sha256_128_sp1");
+        assert_eq!(plan.estimated_secs, (one * 4).as_secs_f64());
+
+        assert!(plan.is_selected(&BenchId::new("sha256", 256, "sp1", None)));
+        assert!(!plan.is_selected(&BenchId::new("sha256", 128, "sp1", None)));
+        let benches = plan.benches();
+        assert_eq!(benches.len(), 3);
+        assert_eq!(benches[0].system, "jolt");
+        assert_eq!(benches[0].sizes, Some(vec![128, 256]));
+    }
+
+    #[test]
+    fn test_read_history_keeps_the_latest_successful_measurement() {
+        let dir = std::env::temp_dir().join(format!("csp_schedule_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let collected = |timestamp: &str, version: &str, prove: u64, failed: bool| {
+            serde_json::json!({
+                "metadata": { "timestamp": timestamp },
+                "systems": { "sp1": { "version": version } },
+                "measurements": [{
+                    "system": "sp1",
+                    "target": "sha256",
+                    "input_size": 128,
+                    "proof_duration": prove,
+                    "verify_duration": 1,
+                    "failures": if failed { vec![serde_json::json!({})] } else { vec![] },
+                }],
+            })
+        };
+        let paths = [
+            dir.join("new.json"),
+            dir.join("old.json"),
+            dir.join("failed.json"),
+        ];
+        let files = [
+            collected("2026-02-02T00:00:00Z", "4.1", 2_000, false),
+            collected("2026-02-01T00:00:00Z", "4.0", 1_000, false),
+            collected("2026-02-03T00:00:00Z", "4.2", 3_000, true),
+        ];
+        for (path, file) in paths.iter().zip(files) {
+            fs::write(path, file.to_string()).unwrap();
+        }
+
+        let history = read_history(&paths).unwrap();
+        let last = &history["sha256_128_sp1"];
+        assert_eq!(last.version.as_deref(), Some("4.1"));
+        assert_eq!(last.proof_duration, Duration::from_nanos(2_000));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+//! Security-level estimates of FRI-based proofs from their parameters.
+//!
+//! The `security_bits` of a [`BenchProperties`](crate::harness::BenchProperties) is only as good as
+//! the parameters it was derived from, so systems with a configurable FRI (or Circle-FRI) PCS
+//! state those parameters as a [`FriParams`] and compute the bits instead of hardcoding them.
+//!
+//! Systems whose bench honors [`SECURITY_BITS_ENV`] (see
+//! [`ProvingSystem::configurable_security`](crate::harness::ProvingSystem::configurable_security))
+//! derive their PCS parameters from [`FriParams::for_security_level`], and the harness measures
+//! them once per requested level under a `sec<bits>` feature tag.
+
+use crate::harness::ProvingSystem;
+use std::env;
+
+/// Comma-separated target security levels in bits (e.g. `80,100,128`) to measure the systems with
+/// a configurable PCS at; unset measures their default parameters only.
+pub const SECURITY_BITS_ENV: &str = "BENCH_SECURITY_BITS";
+
+/// Parameters of a FRI or Circle-FRI low-degree test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FriParams {
+    /// `log2` of the inverse code rate.
+    pub log_blowup: u32,
+    /// Number of FRI queries.
+    pub queries: u32,
+    /// Grinding (proof-of-work) bits before the queries are drawn.
+    pub pow_bits: u32,
+    /// Size in bits of the field challenges are drawn from (the extension field, if any).
+    pub field_bits: u32,
+}
+
+impl FriParams {
+    /// Security under the usual FRI conjecture (each query contributes `log_blowup` bits), as
+    /// reported by Plonky2 and Stwo, capped by the challenge field size.
+    pub const fn conjectured_security_bits(&self) -> u64 {
+        let query_bits = self.log_blowup * self.queries + self.pow_bits;
+        min(query_bits, self.field_bits) as u64
+    }
+
+    /// Provable security up to the Johnson bound, where each query contributes only
+    /// `log_blowup / 2` bits, capped by the challenge field size.
+    pub const fn provable_security_bits(&self) -> u64 {
+        let query_bits = self.log_blowup * self.queries / 2 + self.pow_bits;
+        min(query_bits, self.field_bits) as u64
+    }
+}
+
+impl FriParams {
+    /// The same parameters with the fewest queries reaching `bits` of conjectured security, or
+    /// `None` if the challenge field is too small for it.
+    pub const fn with_security_bits(self, bits: u32) -> Option<FriParams> {
+        if bits > self.field_bits {
+            return None;
+        }
+        let query_bits = bits.saturating_sub(self.pow_bits);
+        let queries = query_bits.div_ceil(self.log_blowup);
+        Some(FriParams {
+            queries: if queries == 0 { 1 } else { queries },
+            ..self
+        })
+    }
+
+    /// The parameters for the [`security_level`] being measured, or `self` if none is requested.
+    pub fn for_security_level(self) -> FriParams {
+        match security_level() {
+            Some(bits) => self.with_security_bits(bits).unwrap_or_else(|| {
+                panic!(
+                    "{bits} bits of security exceed the {}-bit challenge field",
+                    self.field_bits
+                )
+            }),
+            None => self,
+        }
+    }
+}
+
+/// Levels listed in [`SECURITY_BITS_ENV`], sorted and deduplicated; panics on an invalid value so
+/// that a typo does not silently measure the defaults.
+pub fn requested_security_levels() -> Vec<u32> {
+    let Ok(value) = env::var(SECURITY_BITS_ENV) else {
+        return Vec::new();
+    };
+    let mut levels: Vec<u32> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|level| !level.is_empty())
+        .map(|level| {
+            level
+                .parse()
+                .ok()
+                .filter(|&bits| bits > 0)
+                .unwrap_or_else(|| panic!("Invalid {SECURITY_BITS_ENV} level: {level:?}"))
+        })
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+    levels
+}
+
+/// The level being measured. The harness narrows [`SECURITY_BITS_ENV`] to one level at a time,
+/// so this is only ambiguous outside of it, e.g. when a memory binary is run by hand.
+pub fn security_level() -> Option<u32> {
+    match requested_security_levels()[..] {
+        [] => None,
+        [bits] => Some(bits),
+        _ => panic!("{SECURITY_BITS_ENV} lists several levels; measure one at a time"),
+    }
+}
+
+/// Runs `measure` once per requested level if `system` honors [`SECURITY_BITS_ENV`], with the
+/// variable narrowed to that level so that the bench, its memory binary and child processes all
+/// configure the same parameters; otherwise runs it once with `None`.
+pub fn for_each_security_level(system: ProvingSystem, mut measure: impl FnMut(Option<u32>)) {
+    let levels = requested_security_levels();
+    if levels.is_empty() || !system.configurable_security() {
+        if !levels.is_empty() {
+            tracing::warn!(
+                "{} ignores {SECURITY_BITS_ENV}, measuring its default parameters",
+                system.as_str()
+            );
+        }
+        return measure(None);
+    }
+
+    let previous = env::var_os(SECURITY_BITS_ENV);
+    for bits in levels {
+        // SAFETY: the harness drives measurements from the main thread and the prover is not
+        // running while the variable changes.
+        unsafe { env::set_var(SECURITY_BITS_ENV, bits.to_string()) };
+        measure(Some(bits));
+    }
+    // SAFETY: see above.
+    unsafe {
+        match previous {
+            Some(value) => env::set_var(SECURITY_BITS_ENV, value),
+            None => env::remove_var(SECURITY_BITS_ENV),
+        }
+    }
+}
+
+/// Feature tag of a measurement at `level`: `sec<bits>`, appended to the bench's own tag.
+pub fn level_feature(feature: Option<&str>, level: Option<u32>) -> Option<String> {
+    match (feature, level) {
+        (Some(feature), Some(bits)) => Some(format!("{feature}_sec{bits}")),
+        (None, Some(bits)) => Some(format!("sec{bits}")),
+        (feature, None) => feature.map(str::to_string),
+    }
+}
+
+const fn min(a: u32, b: u32) -> u32 {
+    if a < b { a } else { b }
+}
+
+/// Bits of the degree-4 extension of M31 (QM31) Stwo draws challenges from.
+pub const QM31_BITS: u32 = 124;
+
+/// Bits of the degree-4 extension of BabyBear Plonky3's uni-stark draws challenges from.
+pub const BABYBEAR_EXT4_BITS: u32 = 124;
+
+/// Bits of the quadratic extension of Goldilocks Plonky2 draws challenges from.
+pub const GOLDILOCKS_EXT2_BITS: u32 = 128;
+
+/// Bits of the 252-bit STARK field Stone draws challenges from, without an extension.
+pub const STARK252_BITS: u32 = 251;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fri_security_bits() {
+        // Plonky2's `standard_recursion_config`, documented as 100 bits.
+        let plonky2 = FriParams {
+            log_blowup: 3,
+            queries: 28,
+            pow_bits: 16,
+            field_bits: GOLDILOCKS_EXT2_BITS,
+        };
+        assert_eq!(plonky2.conjectured_security_bits(), 100);
+        assert_eq!(plonky2.provable_security_bits(), 58);
+
+        let capped = FriParams {
+            log_blowup: 4,
+            queries: 100,
+            pow_bits: 20,
+            field_bits: QM31_BITS,
+        };
+        assert_eq!(capped.conjectured_security_bits(), 124);
+    }
+
+    #[test]
+    fn test_queries_for_security_level() {
+        let stwo = FriParams {
+            log_blowup: 1,
+            queries: 70,
+            pow_bits: 26,
+            field_bits: QM31_BITS,
+        };
+        let params = stwo.with_security_bits(80).unwrap();
+        assert_eq!(params.queries, 54);
+        assert_eq!(params.conjectured_security_bits(), 80);
+        assert_eq!(stwo.with_security_bits(100).unwrap().queries, 74);
+        assert_eq!(stwo.with_security_bits(20).unwrap().queries, 1);
+        assert_eq!(stwo.with_security_bits(128), None);
+
+        let wide = FriParams {
+            log_blowup: 3,
+            ..stwo
+        };
+        // 33 bits of queries need 11 queries at 3 bits each.
+        assert_eq!(wide.with_security_bits(59).unwrap().queries, 11);
+        assert_eq!(
+            level_feature(Some("gpu"), Some(100)).as_deref(),
+            Some("gpu_sec100")
+        );
+        assert_eq!(level_feature(None, Some(80)).as_deref(), Some("sec80"));
+        assert_eq!(level_feature(None, None), None);
+    }
+}
@@ -0,0 +1,68 @@
+//! Run-to-run spread of repeated measurements.
+//!
+//! `repeat_benchmarks.sh` runs the benchmarks R times with different input seeds into
+//! `results/<campaign>/r<i>/`; `collect_benchmarks` then reports each measurement with the mean
+//! of its runs and the [`RepeatStats`] of the metrics that vary between runs, so a difference
+//! between systems can be told apart from machine noise.
+
+use serde::{Deserialize, Serialize};
+
+/// Mean, extremes and sample standard deviation of one metric over the runs.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Spread {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Sample standard deviation (`n - 1`); `0` for a single run.
+    pub stddev: f64,
+}
+
+impl Spread {
+    /// `None` for no samples.
+    pub fn of(samples: &[f64]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = if samples.len() > 1 {
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        Some(Self {
+            mean,
+            min: samples.iter().copied().fold(f64::INFINITY, f64::min),
+            max: samples.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            stddev: variance.sqrt(),
+        })
+    }
+}
+
+/// Spread of the metrics of one measurement over the runs of a campaign.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RepeatStats {
+    pub runs: usize,
+    /// Nanoseconds.
+    pub proof_duration: Spread,
+    /// Nanoseconds.
+    pub verify_duration: Spread,
+    /// Bytes.
+    pub peak_memory: Spread,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spread_of_samples() {
+        let spread = Spread::of(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+        assert_eq!(spread.mean, 5.0);
+        assert_eq!((spread.min, spread.max), (2.0, 9.0));
+        assert!((spread.stddev - (32.0f64 / 7.0).sqrt()).abs() < 1e-12);
+
+        assert_eq!(Spread::of(&[3.0]).unwrap().stddev, 0.0);
+        assert_eq!(Spread::of(&[]), None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Verification paths of a proof, for systems that can verify it in more than one way: with the
+//! proving system's native verifier, or through the succinct proof a recursion or wrapping step
+//! turns it into (e.g. ProveKit's Gnark Groth16 wrapper). Suites report them through
+//! `BenchmarkSuite::verification_paths`, so the verification cost of such systems can be
+//! compared with the receipt options of the zkVMs.
+//!
+//! Systems with a second, independent verifier implementation (e.g. ark-groth16 next to
+//! rapidsnark for circom's Groth16 proofs) also check every proof with it through
+//! `BenchmarkSuite::cross_verify`. The harness records whether it agrees with the timed verifier,
+//! which accepted the proof, as a [`CrossVerification`].
+
+use crate::harness::BenchError;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use serde_with::{DurationNanoSeconds, serde_as};
+use std::time::{Duration, Instant};
+
+/// Name of the path that verifies the proof as the proving system produced it.
+pub const NATIVE_PATH: &str = "native";
+
+/// One way of verifying a proof.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct VerificationPath {
+    /// [`NATIVE_PATH`], or the outer proof system of a wrapper (e.g. `groth16`).
+    pub name: String,
+    /// Size of the proof this path verifies.
+    pub proof_size: usize,
+    /// Time of one verification; `None` if the path has no standalone verifier to time (the
+    /// wrapper only checks its proof in the process that made it).
+    #[serde_as(as = "Option<DurationNanoSeconds>")]
+    pub verify_duration: Option<Duration>,
+}
+
+impl VerificationPath {
+    /// Times `verify` once as the native path of a proof of `proof_size` bytes.
+    pub fn native(proof_size: usize, verify: impl FnOnce()) -> Self {
+        let start = Instant::now();
+        verify();
+        Self {
+            name: NATIVE_PATH.to_string(),
+            proof_size,
+            verify_duration: Some(start.elapsed()),
+        }
+    }
+
+    /// A wrapped proof of `proof_size` bytes, verified by `name` in `verify_duration` if timed.
+    pub fn wrapped(name: &str, proof_size: usize, verify_duration: Option<Duration>) -> Self {
+        Self {
+            name: name.to_string(),
+            proof_size,
+            verify_duration,
+        }
+    }
+}
+
+/// Verdict of a second verifier implementation on a proof the timed verifier accepted.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CrossVerification {
+    /// The second implementation, e.g. `ark-groth16`.
+    pub verifier: String,
+    /// Whether it accepted the proof too.
+    pub agrees: bool,
+    /// Why it rejected the proof or could not check it.
+    pub error: Option<String>,
+}
+
+impl CrossVerification {
+    /// Runs `verify` with the `verifier` implementation.
+    pub fn check(verifier: &str, verify: impl FnOnce() -> Result<(), BenchError>) -> Self {
+        let error = verify().err();
+        Self {
+            verifier: verifier.to_string(),
+            agrees: error.is_none(),
+            error: error.map(|e| e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_untimed_wrapper_omits_duration() {
+        let native = VerificationPath::native(1024, || {});
+        assert_eq!(native.name, NATIVE_PATH);
+        assert!(native.verify_duration.is_some());
+
+        let json = serde_json::to_value(VerificationPath::wrapped("groth16", 256, None)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "name": "groth16", "proof_size": 256 })
+        );
+    }
+
+    #[test]
+    fn test_cross_verification_records_disagreement() {
+        assert!(CrossVerification::check("ark-groth16", || Ok(())).agrees);
+
+        let rejected = CrossVerification::check("ark-groth16", || {
+            BenchError::check(false, "pairing check failed")
+        });
+        assert!(!rejected.agrees);
+        assert_eq!(
+            rejected.error.as_deref(),
+            Some("verification failed: pairing check failed")
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Size of the witness a prover commits to.
+//!
+//! `num_constraints` alone does not explain a prover's memory: an R1CS system holds one field
+//! element per witness variable (Spartan2's witness length), a Plonkish or AIR system one per
+//! trace cell (Halo2's advice area, a STARK's trace), and the element is 4 bytes for BabyBear or
+//! M31 but 32 for BN254. A [`WitnessSize`] records the element count and the element size, and
+//! is stored as `witness_size` in the metrics. Circuit benches report it with their
+//! `num_constraints` function ([`ConstraintCount`]); for zkVMs it is derived from the trace
+//! area of their `circuit_size`.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WitnessSize {
+    /// Witness variables, or committed trace cells for systems that prove a trace.
+    pub elements: u64,
+    /// Bytes of one element of the field the witness is over.
+    pub element_bytes: u64,
+    /// `elements * element_bytes`.
+    pub bytes: u64,
+}
+
+impl WitnessSize {
+    pub fn new(elements: u64, element_bytes: u64) -> Self {
+        Self {
+            elements,
+            element_bytes,
+            bytes: elements * element_bytes,
+        }
+    }
+
+    /// `elements` of the field named `field_curve` (see `BenchProperties::field_curve`); `None`
+    /// if the size of its elements is not known.
+    pub fn over_field(elements: u64, field_curve: &str) -> Option<Self> {
+        field_element_bytes(field_curve).map(|bytes| Self::new(elements, bytes))
+    }
+}
+
+/// Bytes of an element of the field named `field_curve`, for the fields of the benchmarked
+/// systems. Extension fields used only for challenges do not count: the witness is over the base
+/// field.
+pub fn field_element_bytes(field_curve: &str) -> Option<u64> {
+    match field_curve.to_ascii_lowercase().as_str() {
+        "bn254" | "bls12-381" | "stark252" | "p256" | "t256" | "pallas" => Some(32),
+        "goldilocks" => Some(8),
+        "babybear" | "koalabear" | "m31" => Some(4),
+        "ghash binary field" => Some(16),
+        _ => None,
+    }
+}
+
+/// Constraint count returned by a bench's `num_constraints` function: a plain `usize`, or the
+/// count with the witness size.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConstraintCount {
+    pub total: usize,
+    pub witness_size: Option<WitnessSize>,
+}
+
+impl ConstraintCount {
+    pub fn with_witness(total: usize, witness_size: WitnessSize) -> Self {
+        Self {
+            total,
+            witness_size: Some(witness_size),
+        }
+    }
+}
+
+impl From<usize> for ConstraintCount {
+    fn from(total: usize) -> Self {
+        Self {
+            total,
+            witness_size: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_witness_bytes_follow_the_field() {
+        assert_eq!(WitnessSize::new(1000, 32).bytes, 32_000);
+        assert_eq!(
+            WitnessSize::over_field(1000, "BabyBear"),
+            Some(WitnessSize::new(1000, 4))
+        );
+        assert_eq!(
+            WitnessSize::over_field(1000, "BN254"),
+            WitnessSize::over_field(1000, "Bn254")
+        );
+        assert_eq!(WitnessSize::over_field(1000, "unknown"), None);
+        assert_eq!(ConstraintCount::from(7).witness_size, None);
+    }
+}
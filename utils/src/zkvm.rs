@@ -1,18 +1,29 @@
+pub mod abi;
 pub mod ecdsa;
 pub mod hash;
 pub mod helpers;
 pub mod instance;
 pub mod keccak;
+pub mod merkle_update;
+pub mod multisig;
+pub mod outputs;
+pub mod receipt_proof;
 pub mod sha256;
 pub mod traits;
 
+pub use abi::InputAbi;
 pub use ecdsa::{ECDSA_BENCH, PreparedEcdsa, build_ecdsa_input, encode_public_key};
 pub use helpers::{
-    execution_cycles, guest_dir, preprocessing_size, proof_size, prove, prove_ecdsa, prove_sha256,
-    verify_ecdsa, verify_keccak, verify_sha256,
+    execution_cycles, export_artifacts, guest_dir, preprocessing_size, proof_size, prove,
+    prove_ecdsa, prove_sha256, verify_artifacts, verify_ecdsa, verify_keccak, verify_merkle_update,
+    verify_multisig, verify_receipt_proof, verify_sha256,
 };
 pub use instance::{CompiledProgram, ProofArtifacts, compile_guest_program};
 pub use keccak::{KECCAK_BENCH, PreparedKeccak};
+pub use merkle_update::{MERKLE_UPDATE_BENCH, PreparedMerkleUpdate};
+pub use multisig::{MULTISIG_BENCH, PreparedMultisig};
+pub use outputs::PublicOutputs;
+pub use receipt_proof::{PreparedReceiptProof, RECEIPT_PROOF_BENCH};
 pub use sha256::{PreparedSha256, SHA256_BENCH, build_input};
 pub use traits::{
     BenchmarkConfig, DataGenerator, InputBuilder, PreparedBenchmark, Program, ZkVMBuilder,
@@ -0,0 +1,224 @@
+//! Canonical input ABI of the zkVM guest programs.
+//!
+//! Every guest reads one or more byte payloads (the message to hash, or a serialized ECDSA
+//! input) from stdin. How a payload is laid out depends on what the guest's runtime can read;
+//! each layout is an [`InputAbi`] variant, so the host side of every zkVM crate encodes inputs
+//! the same way and a framing bug cannot silently change the hashed message (and cycle count)
+//! of one system.
+
+use ere_zkvm_interface::Input;
+
+/// Miden advice words are grouped in blocks of four (one 16-byte digest-sized word).
+const MIDEN_BLOCK_WORDS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputAbi {
+    /// The payload as-is; the zkVM's own stdin carries its length (SP1, OpenVM, Nexus, ZisK).
+    Raw,
+    /// `u32` little-endian length, then the payload (RISC Zero, Jolt).
+    LengthPrefixed,
+    /// Miden advice tape for variable-length messages: `u64` little-endian length, then the
+    /// payload as big-endian `u32` words zero-padded to 16-byte blocks, with the words of each
+    /// block in reverse order. Every word is widened to a `u64` little-endian field element.
+    /// The length need not be block-aligned; the guest rejects non-zero padding, and so does
+    /// [`InputAbi::decode`].
+    MidenBeWords,
+    /// Miden advice tape for fixed-size fields: the payload as little-endian `u32` words
+    /// zero-padded to 16-byte blocks, each widened to a `u64` little-endian field element.
+    /// Carries no length, so it decodes to the zero-padded payload.
+    MidenLeWords,
+}
+
+impl InputAbi {
+    /// Appends one encoded payload to `stdin`.
+    pub fn encode_into(self, stdin: &mut Vec<u8>, payload: &[u8]) {
+        match self {
+            InputAbi::Raw => stdin.extend_from_slice(payload),
+            InputAbi::LengthPrefixed => {
+                let len = u32::try_from(payload.len()).expect("payload longer than u32::MAX");
+                stdin.extend_from_slice(&len.to_le_bytes());
+                stdin.extend_from_slice(payload);
+            }
+            InputAbi::MidenBeWords => {
+                stdin.extend_from_slice(&(payload.len() as u64).to_le_bytes());
+                for block in miden_blocks(payload, u32::from_be_bytes) {
+                    for &word in block.iter().rev() {
+                        stdin.extend_from_slice(&u64::from(word).to_le_bytes());
+                    }
+                }
+            }
+            InputAbi::MidenLeWords => {
+                for block in miden_blocks(payload, u32::from_le_bytes) {
+                    for &word in &block {
+                        stdin.extend_from_slice(&u64::from(word).to_le_bytes());
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn encode(self, payload: &[u8]) -> Vec<u8> {
+        let mut stdin = Vec::new();
+        self.encode_into(&mut stdin, payload);
+        stdin
+    }
+
+    /// zkVM input holding a single payload.
+    pub fn input(self, payload: &[u8]) -> Input {
+        Input::new().with_stdin(self.encode(payload))
+    }
+
+    /// zkVM input holding `payloads` one after another, as read by multi-field guests.
+    pub fn input_from_fields(self, payloads: &[&[u8]]) -> Input {
+        let mut stdin = Vec::new();
+        for payload in payloads {
+            self.encode_into(&mut stdin, payload);
+        }
+        Input::new().with_stdin(stdin)
+    }
+
+    /// Reads one payload from the front of `stdin` and advances it, mirroring what the guest
+    /// reads. [`InputAbi::Raw`] consumes the rest of `stdin`; [`InputAbi::MidenLeWords`] consumes
+    /// one block and returns it zero-padded. `None` if `stdin` is truncated or malformed.
+    pub fn decode(self, stdin: &mut &[u8]) -> Option<Vec<u8>> {
+        match self {
+            InputAbi::Raw => Some(std::mem::take(stdin).to_vec()),
+            InputAbi::LengthPrefixed => {
+                let len = u32::from_le_bytes(take(stdin, 4)?.try_into().ok()?) as usize;
+                Some(take(stdin, len)?.to_vec())
+            }
+            InputAbi::MidenBeWords => {
+                let len =
+                    usize::try_from(u64::from_le_bytes(take(stdin, 8)?.try_into().ok()?)).ok()?;
+                let mut payload = Vec::with_capacity(len.next_multiple_of(16));
+                for _ in 0..len.div_ceil(16) {
+                    let block = read_miden_block(stdin)?;
+                    for &word in block.iter().rev() {
+                        payload.extend_from_slice(&word.to_be_bytes());
+                    }
+                }
+                if payload[len..].iter().any(|&byte| byte != 0) {
+                    return None;
+                }
+                payload.truncate(len);
+                Some(payload)
+            }
+            InputAbi::MidenLeWords => Some(
+                read_miden_block(stdin)?
+                    .iter()
+                    .flat_map(|word| word.to_le_bytes())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Splits `payload` into zero-padded blocks of four `u32` words.
+fn miden_blocks(
+    payload: &[u8],
+    to_word: fn([u8; 4]) -> u32,
+) -> impl Iterator<Item = [u32; MIDEN_BLOCK_WORDS]> + '_ {
+    payload.chunks(16).map(move |chunk| {
+        let mut block = [0u32; MIDEN_BLOCK_WORDS];
+        for (word, bytes) in block.iter_mut().zip(chunk.chunks(4)) {
+            let mut padded = [0u8; 4];
+            padded[..bytes.len()].copy_from_slice(bytes);
+            *word = to_word(padded);
+        }
+        block
+    })
+}
+
+fn read_miden_block(stdin: &mut &[u8]) -> Option<[u32; MIDEN_BLOCK_WORDS]> {
+    let mut block = [0u32; MIDEN_BLOCK_WORDS];
+    for word in &mut block {
+        let element = u64::from_le_bytes(take(stdin, 8)?.try_into().ok()?);
+        *word = u32::try_from(element).ok()?;
+    }
+    Some(block)
+}
+
+fn take<'a>(stdin: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if stdin.len() < len {
+        return None;
+    }
+    let (head, rest) = stdin.split_at(len);
+    *stdin = rest;
+    Some(head)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_every_abi() {
+        for len in [0, 1, 4, 15, 16, 17, 135, 136, 137, 2048] {
+            let payload: Vec<u8> = (0..len).map(|i| (i * 7 + 3) as u8).collect();
+            for abi in [
+                InputAbi::Raw,
+                InputAbi::LengthPrefixed,
+                InputAbi::MidenBeWords,
+            ] {
+                let stdin = abi.encode(&payload);
+                let mut cursor = stdin.as_slice();
+                assert_eq!(
+                    abi.decode(&mut cursor).as_ref(),
+                    Some(&payload),
+                    "{abi:?} {len}"
+                );
+                assert!(cursor.is_empty(), "{abi:?} {len}: trailing bytes");
+            }
+        }
+    }
+
+    #[test]
+    fn test_miden_le_words_fields() {
+        // Compressed public key, digest and recoverable signature, as read by the Miden guest.
+        let fields: [&[u8]; 3] = [&[0x02; 33], &[0xab; 32], &[0x11; 65]];
+        let mut stdin = Vec::new();
+        for field in fields {
+            InputAbi::MidenLeWords.encode_into(&mut stdin, field);
+        }
+        assert_eq!(stdin.len(), (48 + 32 + 80) / 4 * 8);
+
+        let mut cursor = stdin.as_slice();
+        for field in fields {
+            let mut decoded = Vec::new();
+            while decoded.len() < field.len() {
+                decoded.extend(InputAbi::MidenLeWords.decode(&mut cursor).unwrap());
+            }
+            assert_eq!(&decoded[..field.len()], field);
+            assert!(decoded[field.len()..].iter().all(|&b| b == 0));
+        }
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn test_known_layouts() {
+        assert_eq!(
+            InputAbi::LengthPrefixed.encode(b"abc"),
+            [3, 0, 0, 0, b'a', b'b', b'c']
+        );
+
+        // Length, then one block whose words are stored last-to-first.
+        let stdin = InputAbi::MidenBeWords.encode(&[1, 2, 3, 4, 5]);
+        let elements: Vec<u64> = stdin
+            .chunks_exact(8)
+            .map(|c| u64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert_eq!(elements, [5, 0, 0, 0x0500_0000, 0x0102_0304]);
+
+        let mut truncated: &[u8] = &[8, 0, 0, 0, 1, 2];
+        assert!(InputAbi::LengthPrefixed.decode(&mut truncated).is_none());
+
+        // A non-zero byte past the length is not a canonical encoding.
+        let mut padded = stdin.clone();
+        padded[3 * 8] = 1;
+        assert!(
+            InputAbi::MidenBeWords
+                .decode(&mut padded.as_slice())
+                .is_none()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::harness::BenchError;
+use crate::zkvm::abi::InputAbi;
 use crate::zkvm::instance::ProofArtifacts;
 use crate::zkvm::traits::PreparedBenchmark;
 use ere_zkvm_interface::{Input, Proof, ProofKind, PublicValues, zkVM};
@@ -100,11 +102,11 @@ where
         self.vm.verify(proof)
     }
 
-    pub fn verify_with_expected(&self, proof: &ProofArtifacts) -> Result<(), anyhow::Error> {
-        let public_values = self.vm.verify(&proof.proof)?;
+    pub fn verify_with_expected(&self, proof: &ProofArtifacts) -> Result<(), BenchError> {
+        let public_values = self.vm.verify(&proof.proof).map_err(BenchError::verify)?;
 
         if public_values != proof.public_values {
-            return Err(anyhow::anyhow!("public values mismatch"));
+            return Err(BenchError::verify("public values mismatch"));
         }
 
         // Validate expected values if provided
@@ -115,18 +117,26 @@ where
             use bincode::Options;
             let (committed_key, committed_msg): (Vec<u8>, Vec<u8>) = bincode::options()
                 .deserialize(&public_values)
-                .map_err(|_| anyhow::anyhow!("failed to deserialize public values"))?;
+                .map_err(|_| BenchError::verify("failed to deserialize public values"))?;
 
             // Reconstruct expected encoded key from x,y coordinates
-            let expected_encoded = encode_public_key(&expected_key.0, &expected_key.1)
-                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            let expected_encoded =
+                encode_public_key(&expected_key.0, &expected_key.1).map_err(BenchError::verify)?;
 
             if committed_key != expected_encoded {
-                return Err(anyhow::anyhow!("public key mismatch"));
+                return Err(BenchError::wrong_named_output(
+                    "public_key",
+                    expected_encoded,
+                    committed_key,
+                ));
             }
 
             if committed_msg != *expected_msg {
-                return Err(anyhow::anyhow!("message mismatch"));
+                return Err(BenchError::wrong_named_output(
+                    "message",
+                    expected_msg,
+                    committed_msg,
+                ));
             }
         }
 
@@ -183,7 +193,7 @@ pub fn build_ecdsa_input(
         .serialize(&data)
         .expect("failed to serialize ECDSA input");
 
-    Ok(Input::new().with_stdin(serialized))
+    Ok(InputAbi::Raw.input(&serialized))
 }
 
 #[cfg(test)]
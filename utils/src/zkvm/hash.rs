@@ -1,13 +1,17 @@
+use crate::harness::BenchError;
+use crate::zkvm::abi::InputAbi;
 use crate::zkvm::instance::ProofArtifacts;
+use crate::zkvm::outputs::PublicOutputs;
 use crate::zkvm::traits::PreparedBenchmark;
 use ere_zkvm_interface::{Input, Proof, ProofKind, PublicValues, zkVM};
 
-/// Common preparation data for zkVM hash benchmarks.
+/// Common preparation data for zkVM benchmarks whose guest commits a digest, or several public
+/// values (see [`PublicOutputs`]).
 pub struct PreparedHash<V> {
     vm: V,
     input: Input,
     compiled_size: usize,
-    expected_digest: Option<Vec<u8>>,
+    expected: Option<PublicOutputs>,
 }
 
 impl<V> PreparedHash<V> {
@@ -16,7 +20,7 @@ impl<V> PreparedHash<V> {
             vm,
             input,
             compiled_size,
-            expected_digest: None,
+            expected: None,
         }
     }
 
@@ -25,12 +29,26 @@ impl<V> PreparedHash<V> {
         input: Input,
         compiled_size: usize,
         expected_digest: Vec<u8>,
+    ) -> Self {
+        Self::with_expected_outputs(
+            vm,
+            input,
+            compiled_size,
+            PublicOutputs::digest(expected_digest),
+        )
+    }
+
+    pub fn with_expected_outputs(
+        vm: V,
+        input: Input,
+        compiled_size: usize,
+        expected: PublicOutputs,
     ) -> Self {
         Self {
             vm,
             input,
             compiled_size,
-            expected_digest: Some(expected_digest),
+            expected: Some(expected),
         }
     }
 
@@ -38,8 +56,13 @@ impl<V> PreparedHash<V> {
         self.compiled_size
     }
 
+    /// The expected commitment, all public values back to back.
     pub fn expected_digest(&self) -> Option<&[u8]> {
-        self.expected_digest.as_deref()
+        self.expected.as_ref().map(PublicOutputs::as_bytes)
+    }
+
+    pub fn expected_outputs(&self) -> Option<&PublicOutputs> {
+        self.expected.as_ref()
     }
 
     pub fn vm(&self) -> &V {
@@ -64,23 +87,17 @@ where
         self.vm.verify(proof)
     }
 
-    pub fn verify_with_digest(&self, proof: &ProofArtifacts) -> Result<(), anyhow::Error> {
-        let public_values = self.vm.verify(&proof.proof)?;
+    pub fn verify_with_expected(&self, proof: &ProofArtifacts) -> Result<(), BenchError> {
+        let public_values = self.vm.verify(&proof.proof).map_err(BenchError::verify)?;
 
         if public_values != proof.public_values {
-            return Err(anyhow::anyhow!("public values mismatch"));
+            return Err(BenchError::verify("public values mismatch"));
         }
 
-        match &self.expected_digest {
-            None => {}
-            Some(expected) => {
-                if public_values != *expected {
-                    return Err(anyhow::anyhow!("digest mismatch"));
-                }
-            }
+        match &self.expected {
+            Some(expected) => expected.check(&public_values),
+            None => Ok(()),
         }
-
-        Ok(())
     }
 
     pub fn execution_cycles(&self) -> Result<u64, anyhow::Error> {
@@ -113,7 +130,7 @@ impl<V: zkVM> PreparedBenchmark for PreparedHash<V> {
     }
 }
 
-/// Builds default zkVM input from raw message bytes.
+/// Builds default zkVM input from raw message bytes ([`InputAbi::Raw`]).
 pub fn build_input(message_bytes: Vec<u8>) -> Input {
-    Input::new().with_stdin(message_bytes)
+    InputAbi::Raw.input(&message_bytes)
 }
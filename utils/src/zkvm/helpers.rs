@@ -1,16 +1,23 @@
+use crate::artifacts::ExportedArtifacts;
+use crate::harness::BenchError;
+use crate::proof_size::ProofSize;
 use crate::zkvm::ecdsa::PreparedEcdsa;
 use crate::zkvm::hash::PreparedHash;
 use crate::zkvm::instance::{CompiledProgram, ProofArtifacts, compile_guest_program};
 use crate::zkvm::traits::PreparedBenchmark;
+use anyhow::{Context, ensure};
 use bincode::Options;
 use ere_zkvm_interface::Compiler;
-use ere_zkvm_interface::zkVM;
+use ere_zkvm_interface::{Proof, zkVM};
 use std::fs;
 use std::path::PathBuf;
 
 /// Prove any benchmark using the prepared zkVM instance.
-pub fn prove<P: PreparedBenchmark, SharedState>(prepared: &P, _: &SharedState) -> ProofArtifacts {
-    prepared.prove().expect("prove failed")
+pub fn prove<P: PreparedBenchmark, SharedState>(
+    prepared: &P,
+    _: &SharedState,
+) -> Result<ProofArtifacts, BenchError> {
+    prepared.prove().map_err(BenchError::prove)
 }
 
 /// Prove a SHA-256 benchmark
@@ -20,7 +27,7 @@ pub use prove as prove_sha256;
 pub fn prove_ecdsa<V: zkVM, SharedState>(
     prepared: &PreparedEcdsa<V>,
     shared_state: &SharedState,
-) -> ProofArtifacts {
+) -> Result<ProofArtifacts, BenchError> {
     prove(prepared, shared_state)
 }
 
@@ -29,8 +36,8 @@ pub fn verify_hash<V: zkVM, SharedState>(
     prepared: &PreparedHash<V>,
     proof: &ProofArtifacts,
     _: &SharedState,
-) {
-    prepared.verify_with_digest(proof).expect("verify failed");
+) -> Result<(), BenchError> {
+    prepared.verify_with_expected(proof)
 }
 
 /// Verify a SHA-256 proof with digest checking.
@@ -39,18 +46,27 @@ pub use verify_hash as verify_sha256;
 /// Verify a Keccak proof with digest checking.
 pub use verify_hash as verify_keccak;
 
+/// Verify a receipt proof, checking the extracted log fields.
+pub use verify_hash as verify_receipt_proof;
+
+/// Verify a Merkle batch update proof, checking the old and new roots.
+pub use verify_hash as verify_merkle_update;
+
+/// Verify a multisig proof, checking the committed digest, threshold and keys.
+pub use verify_hash as verify_multisig;
+
 /// Verify an ECDSA proof with expected values checking.
 pub fn verify_ecdsa<V: zkVM, SharedState>(
     prepared: &PreparedEcdsa<V>,
     proof: &ProofArtifacts,
     _: &SharedState,
-) {
-    prepared.verify_with_expected(proof).expect("verify failed");
+) -> Result<(), BenchError> {
+    prepared.verify_with_expected(proof)
 }
 
 /// Get the execution cycles for any prepared benchmark.
-pub fn execution_cycles<P: PreparedBenchmark>(prepared: &P) -> u64 {
-    prepared.execution_cycles().expect("execute failed")
+pub fn execution_cycles<P: PreparedBenchmark>(prepared: &P) -> Result<u64, BenchError> {
+    prepared.execution_cycles().map_err(BenchError::prove)
 }
 
 /// Get the preprocessing (compiled program) size for any prepared benchmark.
@@ -61,9 +77,45 @@ pub fn preprocessing_size<P: PreparedBenchmark, SharedState>(
     prepared.compiled_size()
 }
 
-/// Get the proof size from proof artifacts.
-pub fn proof_size<SharedState>(proof: &ProofArtifacts, _: &SharedState) -> usize {
-    proof.proof_size()
+/// Get the proof size, with the size of the public values, from proof artifacts.
+pub fn proof_size<SharedState>(proof: &ProofArtifacts, _: &SharedState) -> ProofSize {
+    proof.size_breakdown()
+}
+
+/// Export the proof, public values and compiled program of a zkVM benchmark.
+/// The compiled guest program acts as the verification key: it is all a verifier needs
+/// besides the proof to re-derive the program commitment.
+pub fn export_artifacts<P: PreparedBenchmark, C: Compiler>(
+    _: &P,
+    proof: &ProofArtifacts,
+    compiled: &&CompiledProgram<C>,
+) -> Result<ExportedArtifacts, BenchError> {
+    let verification_key = bincode::options()
+        .serialize(&compiled.program)
+        .map_err(|e| BenchError::setup(format!("failed to serialize compiled program: {e}")))?;
+    Ok(ExportedArtifacts {
+        proof: proof.proof.as_bytes().to_vec(),
+        verification_key,
+        public_inputs: proof.public_values.clone(),
+        encoding: "ere-zkvm",
+    })
+}
+
+/// Verify artifacts produced by [`export_artifacts`]: rebuild the zkVM from the exported
+/// program, verify the proof and check the committed public values.
+pub fn verify_artifacts<C: Compiler, V: zkVM>(
+    build_vm: impl FnOnce(C::Program) -> anyhow::Result<V>,
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let program: C::Program = bincode::options()
+        .deserialize(verification_key)
+        .context("failed to deserialize compiled program")?;
+    let vm = build_vm(program)?;
+    let public_values = vm.verify(&Proof::Compressed(proof.to_vec()))?;
+    ensure!(public_values == public_inputs, "public values mismatch");
+    Ok(())
 }
 
 /// Get the guest program directory path for a benchmark.
@@ -82,35 +134,46 @@ pub fn compiled_program_path(benchmark_name: &str) -> PathBuf {
         .join(format!("{}.bin", benchmark_name))
 }
 
-/// Load a compiled program, panicking if it is missing.
+/// Load a compiled program, failing if it is missing.
 /// Used by RAM measurement binaries which must never trigger compilation.
-pub fn load_compiled_program<C: Compiler>(benchmark_name: &str) -> CompiledProgram<C> {
+pub fn load_compiled_program<C: Compiler>(
+    benchmark_name: &str,
+) -> Result<CompiledProgram<C>, BenchError> {
     let compiled_path = compiled_program_path(benchmark_name);
-    let program_bin = fs::read(&compiled_path)
-        .expect("missing compiled guest; the harness should have compiled it already");
+    let program_bin = fs::read(&compiled_path).map_err(|e| {
+        BenchError::setup(format!(
+            "missing compiled guest {}, the harness should have compiled it already: {e}",
+            compiled_path.display()
+        ))
+    })?;
     let program: C::Program = bincode::options()
         .deserialize(&program_bin)
-        .expect("failed to deserialize compiled program");
+        .map_err(|e| BenchError::setup(format!("failed to deserialize compiled program: {e}")))?;
     let byte_size = program_bin.len();
-    CompiledProgram { program, byte_size }
+    Ok(CompiledProgram { program, byte_size })
 }
 
 /// Load a compiled program if present, otherwise compile and persist it.
 pub fn load_or_compile_program<C: Compiler>(
     compiler: &C,
     benchmark_name: &str,
-) -> CompiledProgram<C> {
+) -> Result<CompiledProgram<C>, BenchError> {
     let compiled_path = compiled_program_path(benchmark_name);
     if compiled_path.exists() {
-        load_compiled_program(benchmark_name)
-    } else {
-        let program = compile_guest_program(compiler, &guest_dir(benchmark_name))
-            .expect("failed to compile guest program");
-        let bytes = bincode::options()
-            .serialize(&program.program)
-            .expect("failed to serialize compiled program");
-        fs::create_dir_all(compiled_path.parent().unwrap()).expect("failed to create directory");
-        fs::write(&compiled_path, bytes).expect("failed to write compiled program file");
-        program
+        return load_compiled_program(benchmark_name);
     }
+    let program = compile_guest_program(compiler, &guest_dir(benchmark_name))
+        .map_err(|e| BenchError::setup(format!("failed to compile guest program: {e:#}")))?;
+    let bytes = bincode::options()
+        .serialize(&program.program)
+        .map_err(|e| BenchError::setup(format!("failed to serialize compiled program: {e}")))?;
+    let dir = compiled_path
+        .parent()
+        .expect("compiled program path has a parent");
+    fs::create_dir_all(dir)
+        .and_then(|()| fs::write(&compiled_path, bytes))
+        .map_err(|e| {
+            BenchError::setup(format!("failed to write {}: {e}", compiled_path.display()))
+        })?;
+    Ok(program)
 }
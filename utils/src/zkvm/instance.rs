@@ -1,3 +1,4 @@
+use crate::proof_size::ProofSize;
 use bincode::Options;
 use ere_zkvm_interface::{Compiler, ProgramProvingReport, Proof, PublicValues};
 use std::path::Path;
@@ -28,6 +29,18 @@ impl ProofArtifacts {
     pub fn proof_size(&self) -> usize {
         self.proof.as_bytes().len()
     }
+
+    /// The receipt and the public values (journal) it commits to; ere keeps them apart, so
+    /// only the receipt counts towards [`Self::proof_size`].
+    pub fn size_breakdown(&self) -> ProofSize {
+        ProofSize::with_breakdown(
+            self.proof_size(),
+            [
+                ("proof", self.proof_size()),
+                ("public_values", self.public_values.len()),
+            ],
+        )
+    }
 }
 
 /// Compiles a guest program located at `guest_dir` and tracks its serialized size.
@@ -35,7 +48,7 @@ pub fn compile_guest_program<C: Compiler>(
     compiler: &C,
     guest_dir: &Path,
 ) -> Result<CompiledProgram<C>, C::Error> {
-    println!("Compiling guest program at {:?}", guest_dir);
+    tracing::info!("Compiling guest program at {:?}", guest_dir);
     let program = compiler.compile(guest_dir)?;
     let cfg = bincode::options();
     let byte_size = cfg
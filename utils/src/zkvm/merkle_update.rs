@@ -0,0 +1,3 @@
+pub use crate::zkvm::hash::PreparedHash as PreparedMerkleUpdate;
+
+pub const MERKLE_UPDATE_BENCH: &str = "merkle_update";
@@ -0,0 +1,3 @@
+pub use crate::zkvm::hash::PreparedHash as PreparedMultisig;
+
+pub const MULTISIG_BENCH: &str = "multisig";
@@ -0,0 +1,91 @@
+//! Expected public outputs of a guest program.
+//!
+//! Guests commit their public outputs as one byte string: the digest for the hash targets, or
+//! several values back to back for the application targets (the old and new roots of a Merkle
+//! update, the extracted fields of a receipt). [`PublicOutputs`] records the layout of that string
+//! as named values, so that a wrong output is reported by name instead of as a differing blob.
+
+use crate::harness::BenchError;
+use std::ops::Range;
+
+/// Named values a guest commits, in commitment order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PublicOutputs {
+    bytes: Vec<u8>,
+    values: Vec<(&'static str, Range<usize>)>,
+}
+
+impl PublicOutputs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A single `digest` value, the output of the hash targets.
+    pub fn digest(digest: Vec<u8>) -> Self {
+        Self::new().with("digest", digest)
+    }
+
+    /// Appends the value `name`.
+    pub fn with(mut self, name: &'static str, value: impl AsRef<[u8]>) -> Self {
+        let start = self.bytes.len();
+        self.bytes.extend_from_slice(value.as_ref());
+        self.values.push((name, start..self.bytes.len()));
+        self
+    }
+
+    /// The committed byte string.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The value `name`, if recorded.
+    pub fn get(&self, name: &str) -> Option<&[u8]> {
+        self.values
+            .iter()
+            .find(|(value, _)| *value == name)
+            .map(|(_, range)| &self.bytes[range.clone()])
+    }
+
+    /// Checks `committed` against the expected values. A commitment of another length is wrong
+    /// as a whole; otherwise the first differing value is reported by name.
+    pub fn check(&self, committed: &[u8]) -> Result<(), BenchError> {
+        if committed.len() != self.bytes.len() {
+            return Err(BenchError::wrong_output(&self.bytes, committed));
+        }
+        match self
+            .values
+            .iter()
+            .find(|(_, range)| committed[range.clone()] != self.bytes[range.clone()])
+        {
+            Some((name, range)) => Err(BenchError::wrong_named_output(
+                name,
+                &self.bytes[range.clone()],
+                &committed[range.clone()],
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrong_value_is_reported_by_name() {
+        let outputs = PublicOutputs::new()
+            .with("old_root", [1, 2])
+            .with("new_root", [3, 4]);
+        assert_eq!(outputs.as_bytes(), [1, 2, 3, 4]);
+        assert_eq!(outputs.get("new_root"), Some(&[3, 4][..]));
+        assert!(outputs.check(&[1, 2, 3, 4]).is_ok());
+        assert_eq!(
+            outputs.check(&[1, 2, 3, 5]).unwrap_err(),
+            BenchError::wrong_named_output("new_root", [3, 4], [3, 5])
+        );
+        assert_eq!(
+            outputs.check(&[1, 2]).unwrap_err(),
+            BenchError::wrong_output([1, 2, 3, 4], [1, 2])
+        );
+    }
+}
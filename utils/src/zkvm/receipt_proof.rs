@@ -0,0 +1,3 @@
+pub use crate::zkvm::hash::PreparedHash as PreparedReceiptProof;
+
+pub const RECEIPT_PROOF_BENCH: &str = "receipt_proof";
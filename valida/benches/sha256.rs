@@ -0,0 +1,6 @@
+use utils::harness::BenchTarget;
+use valida::ValidaSuite;
+
+utils::define_benchmark_suite!(ValidaSuite {
+    target: BenchTarget::Sha256,
+});
@@ -0,0 +1,15 @@
+use std::io::{Read, Write};
+use tiny_keccak::{Hasher, Keccak};
+
+// Valida's Rust target has `std`: stdin is the input `valida run` and `valida prove` are given,
+// stdout the output the proof commits to.
+fn main() {
+    let mut message = Vec::new();
+    std::io::stdin().read_to_end(&mut message).unwrap();
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&message);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    std::io::stdout().write_all(&hash).unwrap();
+}
@@ -0,0 +1,12 @@
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
+
+// Valida's Rust target has `std`: stdin is the input `valida run` and `valida prove` are given,
+// stdout the output the proof commits to.
+fn main() {
+    let mut message = Vec::new();
+    std::io::stdin().read_to_end(&mut message).unwrap();
+
+    let hash: [u8; 32] = Sha256::digest(&message).into();
+    std::io::stdout().write_all(&hash).unwrap();
+}
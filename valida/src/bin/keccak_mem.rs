@@ -0,0 +1,7 @@
+use valida::{prepare_keccak, prove, verify};
+
+fn main() {
+    utils::bench::mem::run_with_verify(prepare_keccak, prove, |prepared, proof| {
+        verify(prepared, proof)
+    });
+}
@@ -0,0 +1,303 @@
+//! Rust guests compiled for the Valida ISA and proved with the `valida` CLI, run as subprocesses.
+//!
+//! ere, which the other zkVM crates get their backends from, has no Valida backend, so this crate
+//! drives the toolchain directly, the way `cairo` drives cairo-lang and Stone. `prepare` builds
+//! the guest with `cargo +valida` and runs it with `valida run`, checking its output against the
+//! expected digest. `prove` runs `valida prove` and `verify` runs `valida verify` on the proof,
+//! the program and the expected output.
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use utils::artifacts::ExportedArtifacts;
+use utils::harness::{
+    AuditStatus, BenchError, BenchHarnessConfig, BenchProperties, BenchTarget, BenchmarkSuite,
+    ProvingSystem,
+};
+
+/// Rust target of the Valida toolchain.
+const GUEST_TARGET: &str = "valida-unknown-baremetal-gnu";
+
+pub fn valida_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "STARK",
+        "BabyBear", // 2^31 - 2^27 + 1; https://github.com/lita-xyz/valida
+        "STARK",    // Plonky3-based
+        Some("FRI"),
+        "AIR",
+        false,                   // the traces are not blinded
+        true,                    // zkVM
+        100,                     // conjectured FRI soundness, as for the other Plonky3-based zkVMs
+        true,                    // hash-based PCS
+        true,                    // https://github.com/lita-xyz/valida-releases
+        AuditStatus::NotAudited, // no published audit of the prover
+        Some("Valida"),          // its own ISA, compiled to from LLVM
+    )
+}
+
+/// A guest run on the input of one size, with the files the CLI reads and writes in `dir`.
+pub struct PreparedValida {
+    pub dir: PathBuf,
+    pub program: PathBuf,
+    /// Cycles `valida run` reported, if it printed them.
+    pub cycles: Option<u64>,
+}
+
+impl PreparedValida {
+    fn file(&self, name: &str) -> PathBuf {
+        self.dir.join(name)
+    }
+
+    /// The input, fed to the guest on stdin.
+    fn input(&self) -> Result<Stdio, BenchError> {
+        Ok(File::open(self.file("input.bin"))?.into())
+    }
+}
+
+/// Runs the `valida` CLI with `stdin` as its standard input, returning its stdout.
+fn run(tool: &str, args: &[&Path], stdin: Stdio) -> Result<String, BenchError> {
+    let output = utils::sandbox::command(tool)
+        .args(args)
+        .stdin(stdin)
+        .output()
+        .map_err(|e| BenchError::tool(tool, e))?;
+    if !output.status.success() {
+        return Err(BenchError::tool(
+            tool,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Builds `guests/{name}` with the Valida toolchain into `target/valida/guests`, returning the
+/// path of the program. Cargo skips the build when the guest has not changed.
+fn build_guest(name: &str) -> Result<PathBuf, BenchError> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("../target/valida/guests");
+    let output = utils::sandbox::command("cargo")
+        .args(["+valida", "build", "--release", "--target", GUEST_TARGET])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .current_dir(manifest_dir.join("guests").join(name))
+        .output()
+        .map_err(|e| BenchError::tool("cargo +valida", e))?;
+    if !output.status.success() {
+        return Err(BenchError::tool(
+            "cargo +valida",
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+    Ok(target_dir
+        .join(GUEST_TARGET)
+        .join("release")
+        .join(format!("valida-{name}"))
+        .canonicalize()?)
+}
+
+/// Builds the guest of `target` and runs it on `message` in a work directory of its own under
+/// `target/valida`. Rejects the input if the guest does not output `digest`.
+fn prepare(
+    target: BenchTarget,
+    input_size: usize,
+    message: &[u8],
+    digest: &[u8],
+) -> Result<PreparedValida, BenchError> {
+    let name = target.as_str();
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/valida")
+        .join(format!("{name}_{input_size}"));
+    fs::create_dir_all(&dir)?;
+    let mut prepared = PreparedValida {
+        dir: dir.canonicalize()?,
+        program: build_guest(name)?,
+        cycles: None,
+    };
+    fs::write(prepared.file("input.bin"), message)?;
+    fs::write(prepared.file("expected_output.bin"), digest)?;
+
+    let output = prepared.file("output.bin");
+    let stdout = run(
+        "valida",
+        &[Path::new("run"), &prepared.program, &output],
+        prepared.input()?,
+    )?;
+    let actual = fs::read(output)?;
+    if actual != digest {
+        return Err(BenchError::wrong_output(digest, actual));
+    }
+    prepared.cycles = parse_cycles(&stdout);
+    Ok(prepared)
+}
+
+/// Cycle count from a line such as `Total cycles: 123456`, if `valida run` printed one.
+fn parse_cycles(stdout: &str) -> Option<u64> {
+    stdout
+        .lines()
+        .find(|line| line.to_ascii_lowercase().contains("cycles"))?
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse().ok())
+        .next_back()
+}
+
+pub fn prepare_sha256(input_size: usize) -> Result<PreparedValida, BenchError> {
+    let (message, digest) = utils::generate_sha256_input(input_size);
+    prepare(BenchTarget::Sha256, input_size, &message, &digest)
+}
+
+pub fn prepare_keccak(input_size: usize) -> Result<PreparedValida, BenchError> {
+    let (message, digest) = utils::generate_keccak_input(input_size);
+    prepare(BenchTarget::Keccak, input_size, &message, &digest)
+}
+
+/// The proof `valida prove` writes.
+pub fn prove(prepared: &PreparedValida) -> Result<Vec<u8>, BenchError> {
+    let proof = prepared.file("proof.bin");
+    run(
+        "valida",
+        &[Path::new("prove"), &prepared.program, &proof],
+        prepared.input()?,
+    )
+    .map_err(BenchError::prove)?;
+    Ok(fs::read(proof)?)
+}
+
+/// Runs `valida verify` on the proof it is given, checking it against the program and the
+/// expected digest as the output.
+pub fn verify(prepared: &PreparedValida, proof: &[u8]) -> Result<(), BenchError> {
+    let path = prepared.file("verified_proof.bin");
+    fs::write(&path, proof)?;
+    run(
+        "valida",
+        &[
+            Path::new("verify"),
+            &prepared.program,
+            &path,
+            &prepared.file("expected_output.bin"),
+        ],
+        Stdio::null(),
+    )
+    .map(drop)
+    .map_err(BenchError::verify)
+}
+
+/// The proof, the compiled program, which `valida verify` checks the proof against, and the
+/// expected output as the public inputs.
+pub fn export_artifacts(
+    prepared: &PreparedValida,
+    proof: &[u8],
+) -> Result<ExportedArtifacts, BenchError> {
+    Ok(ExportedArtifacts {
+        proof: proof.to_vec(),
+        verification_key: fs::read(&prepared.program)?,
+        public_inputs: fs::read(prepared.file("expected_output.bin"))?,
+        encoding: "valida-raw",
+    })
+}
+
+/// Verifies artifacts exported with [`export_artifacts`] by running `valida verify` on them,
+/// written to a directory of this process under `target/valida/artifacts`.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../target/valida/artifacts")
+        .join(std::process::id().to_string());
+    fs::create_dir_all(&dir)?;
+    let (program, proof_path, output) = (
+        dir.join("program"),
+        dir.join("proof.bin"),
+        dir.join("expected_output.bin"),
+    );
+    fs::write(&program, verification_key)?;
+    fs::write(&proof_path, proof)?;
+    fs::write(&output, public_inputs)?;
+    let verified = run(
+        "valida",
+        &[Path::new("verify"), &program, &proof_path, &output],
+        Stdio::null(),
+    );
+    fs::remove_dir_all(&dir)?;
+    verified?;
+    Ok(())
+}
+
+/// Size of the compiled program.
+pub fn preprocessing_size(prepared: &PreparedValida) -> Result<usize, BenchError> {
+    Ok(fs::metadata(&prepared.program)?.len() as usize)
+}
+
+/// SHA-256 or Keccak-256 of a message, hashed in software by the guest.
+pub struct ValidaSuite {
+    pub target: BenchTarget,
+}
+
+impl BenchmarkSuite for ValidaSuite {
+    type Prepared = PreparedValida;
+    type Proof = Vec<u8>;
+
+    fn config(&self) -> BenchHarnessConfig<'_> {
+        BenchHarnessConfig {
+            target: self.target,
+            system: ProvingSystem::Valida,
+            feature: None,
+            mem_binary_name: match self.target {
+                BenchTarget::Keccak => "keccak_mem_valida",
+                _ => "sha256_mem_valida",
+            },
+        }
+    }
+
+    fn properties(&self) -> BenchProperties {
+        valida_bench_properties()
+    }
+
+    fn prepare(&self, size: usize) -> Result<PreparedValida, BenchError> {
+        match self.target {
+            BenchTarget::Keccak => prepare_keccak(size),
+            _ => prepare_sha256(size),
+        }
+    }
+
+    fn prove(&self, prepared: &PreparedValida) -> Result<Vec<u8>, BenchError> {
+        prove(prepared)
+    }
+
+    fn verify(&self, prepared: &PreparedValida, proof: &Vec<u8>) -> Result<(), BenchError> {
+        verify(prepared, proof)
+    }
+
+    fn preprocessing_size(&self, prepared: &PreparedValida) -> Result<usize, BenchError> {
+        preprocessing_size(prepared)
+    }
+
+    fn proof_size(&self, proof: &Vec<u8>) -> usize {
+        proof.len()
+    }
+
+    fn execution_cycles(&self, prepared: &PreparedValida) -> Option<u64> {
+        prepared.cycles
+    }
+
+    fn export_artifacts(
+        &self,
+        prepared: &PreparedValida,
+        proof: &Vec<u8>,
+    ) -> Result<Option<ExportedArtifacts>, BenchError> {
+        export_artifacts(prepared, proof).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_cycles_of_valida_run() {
+        assert_eq!(parse_cycles("Total cycles: 123456\n"), Some(123456));
+        assert_eq!(parse_cycles("loaded program\ncycles 42\n"), Some(42));
+        assert_eq!(parse_cycles("loaded program\n"), None);
+    }
+}
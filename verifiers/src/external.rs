@@ -0,0 +1,48 @@
+//! Verifiers of the crates kept out of the workspace for their pinned toolchains or conflicting
+//! dependencies. They cannot be linked here, so each ships a `verify_artifacts_<system>` binary
+//! (see `utils::artifacts::verify_artifacts_main`) that is run with the toolchain of its crate.
+
+use anyhow::{Context, Result, ensure};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use utils::artifacts::{PROOF_FILE, PUBLIC_INPUTS_FILE, VERIFICATION_KEY_FILE};
+
+/// Writes the artifacts to a directory of this process under `target/verifiers/artifacts` and
+/// runs `cargo run --release --bin <bin>` of the crate at `<repo>/<crate_dir>` on it. The first
+/// run builds the crate.
+pub(crate) fn verify_in_crate(
+    crate_dir: &str,
+    bin: &str,
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> Result<()> {
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let dir = repo
+        .join("target/verifiers/artifacts")
+        .join(std::process::id().to_string());
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(PROOF_FILE), proof)?;
+    fs::write(dir.join(VERIFICATION_KEY_FILE), verification_key)?;
+    fs::write(dir.join(PUBLIC_INPUTS_FILE), public_inputs)?;
+
+    // Run from the crate so that rustup picks its `rust-toolchain.toml`, not the one of the
+    // caller, and let it build in its own target directory.
+    let output = Command::new("cargo")
+        .args(["run", "--release", "--quiet", "--bin", bin, "--"])
+        .arg(&dir)
+        .current_dir(repo.join(crate_dir))
+        .env_remove("RUSTUP_TOOLCHAIN")
+        .env_remove("CARGO_TARGET_DIR")
+        .output()
+        .with_context(|| format!("failed to run cargo for {crate_dir}"));
+    fs::remove_dir_all(&dir)?;
+    let output = output?;
+    ensure!(
+        output.status.success(),
+        "{bin} rejected the artifacts: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(())
+}
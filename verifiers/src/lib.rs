@@ -0,0 +1,394 @@
+//! Single entry point for verifying proof artifacts exported by the benchmark harness
+//! (`BENCH_EXPORT_ARTIFACTS`, see `utils::artifacts`). Each system is gated behind a feature of
+//! the same name so that tooling can depend on the subset of provers it needs.
+//!
+//! Every system exports its artifacts and has a verifier behind its feature. The zkVMs and most
+//! other provers pull in their whole proving stacks, so enable the ones you need. [`verify`]
+//! rejects a system whose feature is disabled and [`has_verifier`] returns `false` for it.
+//!
+//! stwo, rookie-numbers, cairo-m and nexus are kept out of the workspace, so their features do not
+//! link them: they run the `verify_artifacts_<system>` binary of their crate with its own
+//! toolchain, which the first verification builds.
+//!
+//! `fixtures/` holds golden exports, one per system at the minimal input size, made with an
+//! earlier prover version. [`check_fixtures`] re-verifies them with the current verifiers, so a
+//! version bump that silently breaks proof compatibility fails a test instead of going unnoticed.
+//! Every enabled verifier must have a fixture ([`missing_fixtures`]), so the default features are
+//! the systems that have one.
+
+#[cfg(any(
+    feature = "stwo",
+    feature = "rookie-numbers",
+    feature = "cairo-m",
+    feature = "nexus"
+))]
+mod external;
+
+use anyhow::{Context, Result, bail, ensure};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use utils::artifacts::{ArtifactFile, ArtifactManifest, MANIFEST_FILE};
+use utils::harness::ProvingSystem;
+
+/// Verifies a serialized proof against its verification key and public inputs, using the
+/// encodings produced by the system's `export_artifacts` function.
+#[allow(unused_variables)] // when every system feature is disabled
+pub fn verify(
+    system: ProvingSystem,
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> Result<()> {
+    match system {
+        #[cfg(feature = "plonky2")]
+        ProvingSystem::Plonky2 => {
+            plonky2_circuits::bench::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "spartan2")]
+        ProvingSystem::Spartan2 => {
+            spartan2_bench::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "circom")]
+        ProvingSystem::Circom => circom::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "risc0")]
+        ProvingSystem::Risc0 => risc0::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "sp1")]
+        ProvingSystem::Sp1 => sp1::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "pico")]
+        ProvingSystem::Pico => pico::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "zisk")]
+        ProvingSystem::Zisk => zisk::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "jolt")]
+        ProvingSystem::Jolt => jolt::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "openvm")]
+        ProvingSystem::OpenVM => openvm::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "miden")]
+        ProvingSystem::Miden => miden::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "ark-groth16")]
+        ProvingSystem::ArkGroth16 => {
+            ark_groth16_bench::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "halo2-axiom")]
+        ProvingSystem::Halo2Axiom => {
+            halo2_axiom_bench::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "hypernova")]
+        ProvingSystem::HyperNova => {
+            hypernova::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "plonky3")]
+        ProvingSystem::Plonky3 => plonky3::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "nova")]
+        ProvingSystem::Nova => nova::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "cairo")]
+        ProvingSystem::Cairo => cairo::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "valida")]
+        ProvingSystem::Valida => valida::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "gnark")]
+        ProvingSystem::Gnark => gnark::verify_artifacts(proof, verification_key, public_inputs),
+        #[cfg(feature = "ligetron")]
+        ProvingSystem::Ligetron => {
+            ligetron::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "binius64")]
+        ProvingSystem::Binius64 => {
+            binius64::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "provekit")]
+        ProvingSystem::Provekit => {
+            provekit::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "expander")]
+        ProvingSystem::Expander => {
+            sha256_expander_benchmark::verify_artifacts(proof, verification_key, public_inputs)
+        }
+        #[cfg(feature = "stwo")]
+        ProvingSystem::Stwo => external::verify_in_crate(
+            "stwo",
+            "verify_artifacts_stwo",
+            proof,
+            verification_key,
+            public_inputs,
+        ),
+        #[cfg(feature = "rookie-numbers")]
+        ProvingSystem::RookieNumbers => external::verify_in_crate(
+            "rookie-numbers",
+            "verify_artifacts_rookie_numbers",
+            proof,
+            verification_key,
+            public_inputs,
+        ),
+        #[cfg(feature = "cairo-m")]
+        ProvingSystem::CairoM => external::verify_in_crate(
+            "cairo-m",
+            "verify_artifacts_cairo_m",
+            proof,
+            verification_key,
+            public_inputs,
+        ),
+        #[cfg(feature = "nexus")]
+        ProvingSystem::Nexus => external::verify_in_crate(
+            "nexus",
+            "verify_artifacts_nexus",
+            proof,
+            verification_key,
+            public_inputs,
+        ),
+        #[allow(unreachable_patterns)] // when every system feature is enabled
+        _ => bail!(
+            "no verifier available for {} (unsupported system or feature disabled)",
+            system.as_str()
+        ),
+    }
+}
+
+/// Whether [`verify`] supports `system` with the enabled features.
+pub fn has_verifier(system: ProvingSystem) -> bool {
+    match system {
+        ProvingSystem::Plonky2 => cfg!(feature = "plonky2"),
+        ProvingSystem::Spartan2 => cfg!(feature = "spartan2"),
+        ProvingSystem::Circom => cfg!(feature = "circom"),
+        ProvingSystem::Risc0 => cfg!(feature = "risc0"),
+        ProvingSystem::Sp1 => cfg!(feature = "sp1"),
+        ProvingSystem::Pico => cfg!(feature = "pico"),
+        ProvingSystem::Zisk => cfg!(feature = "zisk"),
+        ProvingSystem::Jolt => cfg!(feature = "jolt"),
+        ProvingSystem::OpenVM => cfg!(feature = "openvm"),
+        ProvingSystem::Miden => cfg!(feature = "miden"),
+        ProvingSystem::ArkGroth16 => cfg!(feature = "ark-groth16"),
+        ProvingSystem::Halo2Axiom => cfg!(feature = "halo2-axiom"),
+        ProvingSystem::HyperNova => cfg!(feature = "hypernova"),
+        ProvingSystem::Plonky3 => cfg!(feature = "plonky3"),
+        ProvingSystem::Nova => cfg!(feature = "nova"),
+        ProvingSystem::Cairo => cfg!(feature = "cairo"),
+        ProvingSystem::Valida => cfg!(feature = "valida"),
+        ProvingSystem::Gnark => cfg!(feature = "gnark"),
+        ProvingSystem::Ligetron => cfg!(feature = "ligetron"),
+        ProvingSystem::Binius64 => cfg!(feature = "binius64"),
+        ProvingSystem::Provekit => cfg!(feature = "provekit"),
+        ProvingSystem::Expander => cfg!(feature = "expander"),
+        ProvingSystem::Stwo => cfg!(feature = "stwo"),
+        ProvingSystem::RookieNumbers => cfg!(feature = "rookie-numbers"),
+        ProvingSystem::CairoM => cfg!(feature = "cairo-m"),
+        ProvingSystem::Nexus => cfg!(feature = "nexus"),
+    }
+}
+
+/// Verifies an exported measurement directory: checks every file against the digests recorded
+/// in its `manifest.json`, then verifies the proof with the system named in the manifest.
+pub fn verify_exported(dir: &Path) -> Result<()> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest: ArtifactManifest = serde_json::from_str(
+        &fs::read_to_string(&manifest_path)
+            .with_context(|| format!("failed to read {}", manifest_path.display()))?,
+    )
+    .with_context(|| format!("failed to parse {}", manifest_path.display()))?;
+
+    let system: ProvingSystem = manifest.name.parse().map_err(anyhow::Error::msg)?;
+    let proof = read_checked(dir, &manifest.proof)?;
+    let verification_key = read_checked(dir, &manifest.verification_key)?;
+    let public_inputs = read_checked(dir, &manifest.public_inputs)?;
+
+    verify(system, &proof, &verification_key, &public_inputs)
+        .with_context(|| format!("verification failed for {}", dir.display()))
+}
+
+/// The committed golden fixtures.
+pub fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FixtureStatus {
+    /// The current verifier accepts the fixture.
+    Accepted,
+    /// The fixture no longer verifies, or its files do not match the manifest.
+    Rejected,
+    /// No verifier for the system is enabled.
+    Unsupported,
+}
+
+/// Outcome of re-verifying one golden fixture.
+#[derive(Clone, Debug, Serialize)]
+pub struct FixtureCheck {
+    /// Directory name, `{target}_{input}_{system}[_{feature}]`.
+    pub fixture: String,
+    pub system: String,
+    /// Prover version the fixture was made with, from its manifest.
+    pub proved_with: Option<String>,
+    pub status: FixtureStatus,
+    pub error: Option<String>,
+}
+
+/// Re-verifies every exported measurement directory under `root`, in name order. A missing
+/// `root` has no fixtures.
+pub fn check_fixtures(root: &Path) -> Result<Vec<FixtureCheck>> {
+    let mut dirs = match fs::read_dir(root) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", root.display())),
+    };
+    dirs.retain(|dir| dir.join(MANIFEST_FILE).is_file());
+    dirs.sort();
+    dirs.iter().map(|dir| check_fixture(dir)).collect()
+}
+
+/// Systems with an enabled verifier but no fixture among `checks`, which must not happen: every
+/// verifier is pinned by a golden fixture.
+pub fn missing_fixtures(checks: &[FixtureCheck]) -> Vec<&'static str> {
+    ProvingSystem::ALL
+        .into_iter()
+        .filter(|&system| has_verifier(system))
+        .map(|system| system.as_str())
+        .filter(|&name| !checks.iter().any(|check| check.system == name))
+        .collect()
+}
+
+fn check_fixture(dir: &Path) -> Result<FixtureCheck> {
+    let manifest_path = dir.join(MANIFEST_FILE);
+    let manifest: ArtifactManifest = serde_json::from_str(&fs::read_to_string(&manifest_path)?)
+        .with_context(|| format!("failed to parse {}", manifest_path.display()))?;
+    let supported = manifest
+        .name
+        .parse::<ProvingSystem>()
+        .is_ok_and(has_verifier);
+    let (status, error) = if !supported {
+        (FixtureStatus::Unsupported, None)
+    } else {
+        match verify_exported(dir) {
+            Ok(()) => (FixtureStatus::Accepted, None),
+            Err(e) => (FixtureStatus::Rejected, Some(format!("{e:#}"))),
+        }
+    };
+    Ok(FixtureCheck {
+        fixture: dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        system: manifest.name,
+        proved_with: manifest
+            .bench_properties
+            .version
+            .map(|version| version.into_owned()),
+        status,
+        error,
+    })
+}
+
+fn read_checked(dir: &Path, file: &ArtifactFile) -> Result<Vec<u8>> {
+    let path = dir.join(&file.path);
+    let bytes = fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    ensure!(
+        bytes.len() == file.size && hex::encode(Sha256::digest(&bytes)) == file.sha256,
+        "{} does not match the manifest",
+        path.display()
+    );
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::artifacts::{ExportedArtifacts, PROOF_FILE, write_artifacts};
+    use utils::bench::Metrics;
+    use utils::harness::BenchProperties;
+
+    fn export(root: &Path, system: &str) -> std::path::PathBuf {
+        let metrics = Metrics::new(
+            system.to_string(),
+            None,
+            "sha256".to_string(),
+            128,
+            BenchProperties::default(),
+        );
+        let artifacts = ExportedArtifacts {
+            proof: vec![1, 2, 3],
+            verification_key: vec![4, 5, 6],
+            public_inputs: vec![7],
+            encoding: "test",
+        };
+        write_artifacts(root, &metrics, &artifacts).unwrap()
+    }
+
+    #[test]
+    fn test_tampered_artifacts_are_rejected() {
+        let root = std::env::temp_dir().join(format!("csp_verifiers_{}", std::process::id()));
+        let dir = export(&root, "binius64");
+
+        let err = verify_exported(&dir).unwrap_err();
+        if !has_verifier(ProvingSystem::Binius64) {
+            assert!(
+                format!("{err:#}").contains("no verifier available"),
+                "{err:#}"
+            );
+        }
+
+        fs::write(dir.join(PROOF_FILE), [0u8; 3]).unwrap();
+        let err = verify_exported(&dir).unwrap_err();
+        assert!(format!("{err:#}").contains("does not match"), "{err:#}");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_fixture_statuses() {
+        let root = std::env::temp_dir().join(format!("csp_fixtures_{}", std::process::id()));
+        export(&root, "binius64");
+        let tampered = export(&root, "plonky2");
+        fs::write(tampered.join(PROOF_FILE), [0u8; 3]).unwrap();
+
+        let checks = check_fixtures(&root).unwrap();
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].fixture, "sha256_128_binius64");
+        if !has_verifier(ProvingSystem::Binius64) {
+            assert_eq!(checks[0].status, FixtureStatus::Unsupported);
+        }
+        if has_verifier(ProvingSystem::Plonky2) {
+            assert_eq!(checks[1].status, FixtureStatus::Rejected);
+        }
+        assert!(check_fixtures(&root.join("missing")).unwrap().is_empty());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_golden_fixtures_still_verify() {
+        let checks = check_fixtures(&fixtures_dir()).unwrap();
+        assert!(
+            checks
+                .iter()
+                .any(|check| check.status != FixtureStatus::Unsupported),
+            "no golden fixture in {} has an enabled verifier",
+            fixtures_dir().display()
+        );
+        let missing = missing_fixtures(&checks);
+        assert!(
+            missing.is_empty(),
+            "enabled verifiers without a golden fixture in {}: {}",
+            fixtures_dir().display(),
+            missing.join(", ")
+        );
+        let rejected: Vec<_> = checks
+            .into_iter()
+            .filter(|check| check.status == FixtureStatus::Rejected)
+            .map(|check| {
+                format!(
+                    "{} (proved with {}): {}",
+                    check.fixture,
+                    check.proved_with.as_deref().unwrap_or("unknown version"),
+                    check.error.unwrap_or_default()
+                )
+            })
+            .collect();
+        assert!(
+            rejected.is_empty(),
+            "golden fixtures no longer verify:\n{}",
+            rejected.join("\n")
+        );
+    }
+}
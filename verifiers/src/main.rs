@@ -0,0 +1,89 @@
+use anyhow::{Context, Result, bail};
+use clap::Parser;
+use std::fs;
+use std::path::PathBuf;
+use verifiers::FixtureStatus;
+
+/// Verify proof artifacts exported with BENCH_EXPORT_ARTIFACTS
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Exported measurement directories (each containing a manifest.json)
+    #[arg(required_unless_present = "fixtures")]
+    dirs: Vec<PathBuf>,
+
+    /// Re-verify the committed golden fixtures instead
+    #[arg(long, conflicts_with = "dirs")]
+    fixtures: bool,
+
+    /// With --fixtures, write the outcome of every fixture as JSON to this file
+    #[arg(long, requires = "fixtures")]
+    report: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if cli.fixtures {
+        return check_fixtures(cli.report);
+    }
+
+    let mut failures = 0;
+    for dir in &cli.dirs {
+        match verifiers::verify_exported(dir) {
+            Ok(()) => println!("OK   {}", dir.display()),
+            Err(e) => {
+                failures += 1;
+                eprintln!("FAIL {}: {:#}", dir.display(), e);
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!(
+            "{} of {} exports failed verification",
+            failures,
+            cli.dirs.len()
+        );
+    }
+    Ok(())
+}
+
+fn check_fixtures(report: Option<PathBuf>) -> Result<()> {
+    let checks = verifiers::check_fixtures(&verifiers::fixtures_dir())?;
+    for check in &checks {
+        let version = check.proved_with.as_deref().unwrap_or("unknown version");
+        match check.status {
+            FixtureStatus::Accepted => println!("OK   {} ({version})", check.fixture),
+            FixtureStatus::Unsupported => println!("SKIP {} (no verifier)", check.fixture),
+            FixtureStatus::Rejected => eprintln!(
+                "FAIL {} ({version}): {}",
+                check.fixture,
+                check.error.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+    if let Some(path) = report {
+        fs::write(&path, serde_json::to_string_pretty(&checks)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+
+    let rejected = checks
+        .iter()
+        .filter(|check| check.status == FixtureStatus::Rejected)
+        .count();
+    if rejected > 0 {
+        bail!(
+            "{} of {} golden fixtures no longer verify",
+            rejected,
+            checks.len()
+        );
+    }
+    let missing = verifiers::missing_fixtures(&checks);
+    if !missing.is_empty() {
+        bail!(
+            "enabled verifiers without a golden fixture: {}",
+            missing.join(", ")
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use ere_zisk::compiler::RustRv64imaCustomized;
+use utils::harness::ProvingSystem;
+use utils::zkvm::KECCAK_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+use zisk::{
+    execution_cycles, export_artifacts, prepare_keccak, preprocessing_size, proof_size, prove,
+    verify_keccak, zisk_bench_properties,
+};
+
+utils::define_benchmark_harness!(
+    BenchTarget::Keccak,
+    ProvingSystem::Zisk,
+    None,
+    "keccak_mem_zisk",
+    zisk_bench_properties(),
+    { load_or_compile_program(&RustRv64imaCustomized, KECCAK_BENCH)? },
+    prepare_keccak,
+    |_, _| 0,
+    prove,
+    verify_keccak,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,25 @@
+use ere_zisk::compiler::RustRv64imaCustomized;
+use utils::harness::ProvingSystem;
+use utils::zkvm::SHA256_BENCH;
+use utils::zkvm::helpers::load_or_compile_program;
+use zisk::{
+    execution_cycles, export_artifacts, prepare_sha256, preprocessing_size, proof_size,
+    prove_sha256, verify_sha256, zisk_bench_properties,
+};
+
+utils::define_benchmark_harness!(
+    BenchTarget::Sha256,
+    ProvingSystem::Zisk,
+    None,
+    "sha256_mem_zisk",
+    zisk_bench_properties(),
+    { load_or_compile_program(&RustRv64imaCustomized, SHA256_BENCH)? },
+    prepare_sha256,
+    |_, _| 0,
+    prove_sha256,
+    verify_sha256,
+    preprocessing_size,
+    proof_size,
+    execution_cycles,
+    export_artifacts = export_artifacts
+);
@@ -0,0 +1,19 @@
+#![no_main]
+
+use tiny_keccak::{Hasher, Keccak};
+
+ziskos::entrypoint!(main);
+
+pub fn main() {
+    let input_bytes = ziskos::read_input();
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&input_bytes);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    // ZisK's public outputs are `u32` slots; little-endian words keep the digest bytes in order.
+    for (i, word) in hash.chunks_exact(4).enumerate() {
+        ziskos::set_output(i, u32::from_le_bytes(word.try_into().unwrap()));
+    }
+}
@@ -0,0 +1,18 @@
+#![no_main]
+
+use sha2::{Digest, Sha256};
+
+ziskos::entrypoint!(main);
+
+pub fn main() {
+    let input_bytes = ziskos::read_input();
+
+    let mut hasher = Sha256::new();
+    hasher.update(input_bytes);
+    let hash: [u8; 32] = hasher.finalize().into();
+
+    // ZisK's public outputs are `u32` slots; little-endian words keep the digest bytes in order.
+    for (i, word) in hash.chunks_exact(4).enumerate() {
+        ziskos::set_output(i, u32::from_le_bytes(word.try_into().unwrap()));
+    }
+}
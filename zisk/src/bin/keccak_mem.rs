@@ -0,0 +1,15 @@
+use ere_zisk::compiler::RustRv64imaCustomized;
+use utils::zkvm::KECCAK_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+use zisk::{prepare_keccak, prove, verify_keccak};
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv64imaCustomized>(
+        KECCAK_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_keccak(input_size, &program),
+        |prepared| prove(prepared, &()),
+        |prepared, proof| verify_keccak(prepared, proof, &()),
+    );
+}
@@ -0,0 +1,15 @@
+use ere_zisk::compiler::RustRv64imaCustomized;
+use utils::zkvm::SHA256_BENCH;
+use utils::zkvm::helpers::load_compiled_program;
+use zisk::{prepare_sha256, prove_sha256, verify_sha256};
+
+fn main() {
+    let program = utils::bench::mem::shared_state(load_compiled_program::<RustRv64imaCustomized>(
+        SHA256_BENCH,
+    ));
+    utils::bench::mem::run_with_verify(
+        |input_size| prepare_sha256(input_size, &program),
+        |prepared| prove_sha256(prepared, &()),
+        |prepared, proof| verify_sha256(prepared, proof, &()),
+    );
+}
@@ -0,0 +1,78 @@
+use ere_zisk::{EreZisk, compiler::RustRv64imaCustomized};
+use ere_zkvm_interface::ProverResource;
+use utils::harness::{AuditStatus, BenchError, BenchProperties};
+use utils::zkvm::{CompiledProgram, PreparedKeccak, PreparedSha256, build_input};
+
+pub use utils::zkvm::{
+    execution_cycles, export_artifacts, preprocessing_size, proof_size, prove, prove_sha256,
+    verify_keccak, verify_sha256,
+};
+
+pub fn zisk_bench_properties() -> BenchProperties {
+    BenchProperties::new(
+        "STARK",
+        "Goldilocks", // 2^64 - 2^32 + 1; https://github.com/0xPolygonHermez/zisk
+        "STARK",      // PIL2 / proofman STARKs with recursive aggregation
+        Some("FRI"),
+        "AIR",
+        false,                   // the STARK proofs do not blind their traces
+        true,                    // zkVM
+        128,                     // conjectured FRI soundness targeted by the ZisK setup
+        true,                    // STARK is PQ-safe (the final SNARK wrapper is not)
+        true,                    // https://github.com/0xPolygonHermez/zisk/releases
+        AuditStatus::NotAudited, // no published audit of the prover
+        Some("RISC-V RV64IMA"),
+    )
+}
+
+/// Prepares a SHA256 hash benchmark.
+pub fn prepare_sha256(
+    input_size: usize,
+    program: &CompiledProgram<RustRv64imaCustomized>,
+) -> Result<PreparedSha256<EreZisk>, BenchError> {
+    let vm =
+        EreZisk::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (message_bytes, digest) = utils::generate_sha256_input(input_size);
+    let input = build_input(message_bytes);
+
+    Ok(PreparedSha256::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Prepares a Keccak256 hash benchmark.
+pub fn prepare_keccak(
+    input_size: usize,
+    program: &CompiledProgram<RustRv64imaCustomized>,
+) -> Result<PreparedKeccak<EreZisk>, BenchError> {
+    let vm =
+        EreZisk::new(program.program.clone(), ProverResource::Cpu).map_err(BenchError::setup)?;
+
+    let (message_bytes, digest) = utils::generate_keccak_input(input_size);
+    let input = build_input(message_bytes);
+
+    Ok(PreparedKeccak::with_expected_digest(
+        vm,
+        input,
+        program.byte_size,
+        digest,
+    ))
+}
+
+/// Verifies a proof exported with [`export_artifacts`] against the exported program.
+pub fn verify_artifacts(
+    proof: &[u8],
+    verification_key: &[u8],
+    public_inputs: &[u8],
+) -> anyhow::Result<()> {
+    utils::zkvm::verify_artifacts::<RustRv64imaCustomized, _>(
+        |program| Ok(EreZisk::new(program, ProverResource::Cpu)?),
+        proof,
+        verification_key,
+        public_inputs,
+    )
+}